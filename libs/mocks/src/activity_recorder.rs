@@ -0,0 +1,28 @@
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use cfg_traits::activity::{ActivityKind, ActivityRecorder};
+	use frame_support::pallet_prelude::*;
+
+	use crate::{execute_generic_call, register_generic_call};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	type CallIds<T: Config> = StorageMap<_, _, String, mock_builder::CallId>;
+
+	impl<T: Config> Pallet<T> {
+		pub fn mock_record<Id: Encode>(f: impl Fn(ActivityKind, &Id) + 'static) {
+			register_generic_call!(record::<Id>, move |(a, b)| f(a, b));
+		}
+	}
+
+	impl<T: Config> ActivityRecorder for Pallet<T> {
+		fn record<Id: Encode>(a: ActivityKind, b: &Id) {
+			execute_generic_call!(record::<Id>, (a, b))
+		}
+	}
+}
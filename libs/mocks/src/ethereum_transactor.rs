@@ -1,7 +1,10 @@
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
-	use cfg_traits::ethereum::EthereumTransactor;
-	use frame_support::pallet_prelude::*;
+	use cfg_traits::ethereum::{AccessListItem, EthereumTransactor, GasPrice};
+	use frame_support::{
+		dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
+		pallet_prelude::*,
+	};
 	use mock_builder::{execute_call, register_call};
 	use sp_core::{H160, U256};
 
@@ -16,7 +19,37 @@ pub mod pallet {
 
 	impl<T: Config> Pallet<T> {
 		pub fn mock_call(
-			func: impl Fn(H160, H160, &[u8], U256, U256, U256) -> DispatchResultWithPostInfo + 'static,
+			func: impl Fn(H160, H160, &[u8], U256, GasPrice, U256) -> DispatchResultWithPostInfo
+				+ 'static,
+		) {
+			register_call!(move |(a, b, c, d, e, f)| func(a, b, c, d, e, f));
+		}
+
+		pub fn mock_call_with_access_list(
+			func: impl Fn(
+					H160,
+					H160,
+					&[u8],
+					U256,
+					GasPrice,
+					U256,
+					Vec<AccessListItem>,
+				) -> DispatchResultWithPostInfo
+				+ 'static,
+		) {
+			register_call!(move |(a, b, c, d, e, f, g)| func(a, b, c, d, e, f, g));
+		}
+
+		pub fn mock_create(
+			func: impl Fn(
+					H160,
+					&[u8],
+					U256,
+					GasPrice,
+					U256,
+					Vec<AccessListItem>,
+				) -> Result<(H160, PostDispatchInfo), DispatchErrorWithPostInfo>
+				+ 'static,
 		) {
 			register_call!(move |(a, b, c, d, e, f)| func(a, b, c, d, e, f));
 		}
@@ -28,10 +61,33 @@ pub mod pallet {
 			b: H160,
 			c: &[u8],
 			d: U256,
-			e: U256,
+			e: GasPrice,
 			f: U256,
 		) -> DispatchResultWithPostInfo {
 			execute_call!((a, b, c, d, e, f))
 		}
+
+		fn call_with_access_list(
+			a: H160,
+			b: H160,
+			c: &[u8],
+			d: U256,
+			e: GasPrice,
+			f: U256,
+			g: Vec<AccessListItem>,
+		) -> DispatchResultWithPostInfo {
+			execute_call!((a, b, c, d, e, f, g))
+		}
+
+		fn create(
+			a: H160,
+			b: &[u8],
+			c: U256,
+			d: GasPrice,
+			e: U256,
+			f: Vec<AccessListItem>,
+		) -> Result<(H160, PostDispatchInfo), DispatchErrorWithPostInfo> {
+			execute_call!((a, b, c, d, e, f))
+		}
 	}
 }
@@ -1,6 +1,6 @@
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
-	use cfg_traits::investments::ForeignInvestment;
+	use cfg_traits::investments::{ForeignInvestment, ForeignInvestmentPosition};
 	use frame_support::pallet_prelude::*;
 	use mock_builder::{execute_call, register_call};
 
@@ -54,6 +54,17 @@ pub mod pallet {
 		) {
 			register_call!(move |(a, b, c)| f(a, b, c));
 		}
+
+		pub fn mock_foreign_position(
+			f: impl Fn(
+					&T::AccountId,
+					T::InvestmentId,
+				)
+					-> Result<ForeignInvestmentPosition<T::Amount, T::TrancheAmount>, DispatchError>
+				+ 'static,
+		) {
+			register_call!(move |(a, b)| f(a, b));
+		}
 	}
 
 	impl<T: Config> ForeignInvestment<T::AccountId> for Pallet<T> {
@@ -95,5 +106,12 @@ pub mod pallet {
 		) -> Result<T::TrancheAmount, DispatchError> {
 			execute_call!((a, b, c))
 		}
+
+		fn foreign_position(
+			a: &T::AccountId,
+			b: Self::InvestmentId,
+		) -> Result<ForeignInvestmentPosition<T::Amount, T::TrancheAmount>, DispatchError> {
+			execute_call!((a, b))
+		}
 	}
 }
@@ -0,0 +1,30 @@
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use cfg_traits::ismp::{IsmpDispatcher, PostRequest};
+	use frame_support::pallet_prelude::*;
+	use mock_builder::{execute_call, register_call};
+	use sp_core::H256;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	type CallIds<T: Config> = StorageMap<_, _, String, mock_builder::CallId>;
+
+	impl<T: Config> Pallet<T> {
+		pub fn mock_dispatch_post(
+			func: impl Fn(PostRequest) -> Result<H256, DispatchError> + 'static,
+		) {
+			register_call!(func);
+		}
+	}
+
+	impl<T: Config> IsmpDispatcher for Pallet<T> {
+		fn dispatch_post(a: PostRequest) -> Result<H256, DispatchError> {
+			execute_call!(a)
+		}
+	}
+}
@@ -0,0 +1,34 @@
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use cfg_traits::keeper::KeeperRewards;
+	use frame_support::pallet_prelude::*;
+	use mock_builder::{execute_call, register_call};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Action;
+		type Balance;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	type CallIds<T: Config> = StorageMap<_, _, String, mock_builder::CallId>;
+
+	impl<T: Config> Pallet<T> {
+		pub fn mock_reward(f: impl Fn(&T::AccountId, T::Action) -> T::Balance + 'static) {
+			register_call!(move |(a, b)| f(a, b));
+		}
+	}
+
+	impl<T: Config> KeeperRewards for Pallet<T> {
+		type AccountId = T::AccountId;
+		type Action = T::Action;
+		type Balance = T::Balance;
+
+		fn reward(a: &Self::AccountId, b: Self::Action) -> Self::Balance {
+			execute_call!((a, b))
+		}
+	}
+}
@@ -1,3 +1,4 @@
+pub mod activity_recorder;
 pub mod asset_registry;
 pub mod change_guard;
 pub mod converter;
@@ -8,26 +9,32 @@ pub mod fees;
 pub mod foreign_investment;
 pub mod foreign_investment_hooks;
 pub mod investment;
+pub mod ismp_dispatcher;
+pub mod keeper_rewards;
 pub mod liquidity_pools;
 pub mod liquidity_pools_gateway;
 pub mod pay_fee;
 pub mod permissions;
 pub mod pools;
 pub mod pre_conditions;
+pub mod price_update_handler;
 pub mod queue;
 pub mod rewards;
 pub mod router_message;
 pub mod status_notification;
 pub mod time;
 pub mod token_swaps;
+pub mod value_feeder;
 pub mod value_provider;
 pub mod write_off_policy;
 
+pub use activity_recorder::pallet as pallet_mock_activity_recorder;
 pub use change_guard::pallet as pallet_mock_change_guard;
 pub use currency_conversion::pallet as pallet_mock_currency_conversion;
 pub use data::pallet as pallet_mock_data;
 pub use fees::pallet as pallet_mock_fees;
 pub use investment::pallet as pallet_mock_investment;
+pub use keeper_rewards::pallet as pallet_mock_keeper_rewards;
 pub use liquidity_pools::pallet as pallet_mock_liquidity_pools;
 pub use liquidity_pools_gateway::pallet as pallet_mock_liquidity_pools_gateway;
 pub use pay_fee::pallet as pallet_mock_pay_fee;
@@ -54,6 +61,54 @@ pub mod reexport {
 	pub use sp_runtime;
 }
 
+/// Identifies an instantiation of a generic mocked method by the method name
+/// and the monomorphized type names of its generic parameters
+pub fn generic_call_id<G: ?Sized>(method: &str) -> String {
+	format!("{method}::<{}>", core::any::type_name::<G>())
+}
+
+/// Registers a mock for an instantiation of a generic trait method.
+///
+/// `register_call!` derives the call id from the method path, so every
+/// instantiation of a generic method shares it. This macro derives it with
+/// `generic_call_id()` instead, so each instantiation can be mocked on its own.
+/// The method must call `execute_generic_call!` with the same generic
+/// parameters.
+///
+/// ```ignore
+/// pub fn mock_record<Id: Encode>(f: impl Fn(ActivityKind, &Id) + 'static) {
+///     register_generic_call!(record::<Id>, move |(a, b)| f(a, b));
+/// }
+/// ```
+#[macro_export]
+macro_rules! register_generic_call {
+	($method:ident::<$($generic:ty),+>, $f:expr) => {{
+		CallIds::<T>::insert(
+			$crate::generic_call_id::<($($generic,)+)>(stringify!($method)),
+			mock_builder::storage::register_call($f),
+		);
+	}};
+}
+
+/// Executes the mock registered with `register_generic_call!` for an
+/// instantiation of a generic trait method.
+///
+/// ```ignore
+/// fn record<Id: Encode>(a: ActivityKind, b: &Id) {
+///     execute_generic_call!(record::<Id>, (a, b))
+/// }
+/// ```
+#[macro_export]
+macro_rules! execute_generic_call {
+	($method:ident::<$($generic:ty),+>, $input:expr) => {{
+		let call_id = $crate::generic_call_id::<($($generic,)+)>(stringify!($method));
+		let id = CallIds::<T>::get(&call_id)
+			.unwrap_or_else(|| panic!("Called to {call_id}, but mock was not found"));
+		mock_builder::storage::execute_call(id, $input)
+			.unwrap_or_else(|_| panic!("Called to {call_id} with the wrong input or output types"))
+	}};
+}
+
 /// Creates a runtime with a pallet mock to make isolated tests
 /// See tests below of this same file
 #[macro_export]
@@ -103,3 +158,58 @@ mod test {
 		});
 	}
 }
+
+#[cfg(test)]
+mod test_generic_calls {
+	use std::{cell::RefCell, rc::Rc};
+
+	use cfg_traits::activity::{ActivityKind, ActivityRecorder};
+
+	use super::*;
+
+	make_runtime_for_mock!(
+		Runtime,
+		MockActivity,
+		pallet_mock_activity_recorder,
+		new_test_ext
+	);
+
+	impl pallet_mock_activity_recorder::Config for Runtime {}
+
+	#[test]
+	fn instantiations_are_mocked_independently() {
+		new_test_ext().execute_with(|| {
+			let recorded = Rc::new(RefCell::new(vec![]));
+
+			let pools = recorded.clone();
+			MockActivity::mock_record::<u64>(move |kind, pool_id| {
+				assert_eq!(*pool_id, 1);
+				pools.borrow_mut().push(kind);
+			});
+
+			let loans = recorded.clone();
+			MockActivity::mock_record::<(u64, u64)>(move |kind, loan_id| {
+				assert_eq!(*loan_id, (1, 2));
+				loans.borrow_mut().push(kind);
+			});
+
+			MockActivity::record(ActivityKind::Pool, &1u64);
+			MockActivity::record(ActivityKind::Loan, &(1u64, 2u64));
+
+			assert_eq!(
+				*recorded.borrow(),
+				vec![ActivityKind::Pool, ActivityKind::Loan]
+			);
+		});
+	}
+
+	#[test]
+	#[should_panic]
+	fn unmocked_instantiation_panics() {
+		new_test_ext().execute_with(|| {
+			MockActivity::mock_record::<u64>(|_, _| {});
+
+			MockActivity::record(ActivityKind::Pool, &1u32);
+		});
+	}
+}
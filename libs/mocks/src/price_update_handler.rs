@@ -0,0 +1,31 @@
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use cfg_traits::{liquidity_pools::PriceUpdateHandler, Seconds};
+	use frame_support::pallet_prelude::*;
+	use mock_builder::{execute_call, register_call};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Sender;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	type CallIds<T: Config> = StorageMap<_, _, String, mock_builder::CallId>;
+
+	impl<T: Config> Pallet<T> {
+		pub fn mock_handle_price(
+			f: impl Fn(T::Sender, [u8; 32], u128, u8, Seconds) -> DispatchResult + 'static,
+		) {
+			register_call!(move |(a, b, c, d, e)| f(a, b, c, d, e));
+		}
+	}
+
+	impl<T: Config> PriceUpdateHandler<T::Sender> for Pallet<T> {
+		fn handle_price(a: T::Sender, b: [u8; 32], c: u128, d: u8, e: Seconds) -> DispatchResult {
+			execute_call!((a, b, c, d, e))
+		}
+	}
+}
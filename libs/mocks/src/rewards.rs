@@ -1,6 +1,6 @@
 #[frame_support::pallet(dev_mode)]
 pub mod pallet {
-	use cfg_traits::rewards::{AccountRewards, CurrencyGroupChange, GroupRewards};
+	use cfg_traits::rewards::{AccountRewards, CurrencyGroupChange, GroupRewards, StakeLocks};
 	use frame_support::pallet_prelude::*;
 	use mock_builder::{execute_call, register_call};
 
@@ -62,6 +62,26 @@ pub mod pallet {
 			register_call!(move |(a, b)| f(a, b));
 		}
 
+		pub fn mock_currency_stake(f: impl Fn(T::CurrencyId) -> T::Balance + 'static) {
+			register_call!(f);
+		}
+
+		pub fn mock_lock_stake(
+			f: impl Fn(T::CurrencyId, &T::AccountId, T::Balance, u32) -> DispatchResult + 'static,
+		) {
+			register_call!(move |(a, b, c, d)| f(a, b, c, d));
+		}
+
+		pub fn mock_unlock_stake(
+			f: impl Fn(T::CurrencyId, &T::AccountId) -> Result<T::Balance, DispatchError> + 'static,
+		) {
+			register_call!(move |(a, b)| f(a, b));
+		}
+
+		pub fn mock_is_lock_expired(f: impl Fn(T::CurrencyId, &T::AccountId) -> bool + 'static) {
+			register_call!(move |(a, b)| f(a, b));
+		}
+
 		pub fn mock_attach_currency(
 			f: impl Fn(T::CurrencyId, T::GroupId) -> DispatchResult + 'static,
 		) {
@@ -113,6 +133,24 @@ pub mod pallet {
 		fn account_stake(a: T::CurrencyId, b: &T::AccountId) -> T::Balance {
 			execute_call!((a, b))
 		}
+
+		fn currency_stake(a: T::CurrencyId) -> T::Balance {
+			execute_call!(a)
+		}
+	}
+
+	impl<T: Config> StakeLocks<T::AccountId> for Pallet<T> {
+		fn lock_stake(a: T::CurrencyId, b: &T::AccountId, c: T::Balance, d: u32) -> DispatchResult {
+			execute_call!((a, b, c, d))
+		}
+
+		fn unlock_stake(a: T::CurrencyId, b: &T::AccountId) -> Result<T::Balance, DispatchError> {
+			execute_call!((a, b))
+		}
+
+		fn is_lock_expired(a: T::CurrencyId, b: &T::AccountId) -> bool {
+			execute_call!((a, b))
+		}
 	}
 
 	impl<T: Config> CurrencyGroupChange for Pallet<T> {
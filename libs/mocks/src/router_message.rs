@@ -25,7 +25,7 @@ pub mod pallet {
 		}
 
 		pub fn mock_send(
-			f: impl Fn(T::Middleware, T::Origin, T::Message) -> DispatchResult + 'static,
+			f: impl Fn(T::Middleware, T::Origin, T::Message) -> DispatchResultWithPostInfo + 'static,
 		) -> CallHandler {
 			register_call_instance!(move |(a, b, c)| f(a, b, c))
 		}
@@ -46,7 +46,11 @@ pub mod pallet {
 		type Middleware = T::Middleware;
 		type Origin = T::Origin;
 
-		fn send(a: Self::Middleware, b: Self::Origin, c: Self::Message) -> DispatchResult {
+		fn send(
+			a: Self::Middleware,
+			b: Self::Origin,
+			c: Self::Message,
+		) -> DispatchResultWithPostInfo {
 			execute_call_instance!((a, b, c))
 		}
 	}
@@ -0,0 +1,33 @@
+#[frame_support::pallet(dev_mode)]
+pub mod pallet {
+	use cfg_traits::ValueFeeder;
+	use frame_support::pallet_prelude::*;
+	use mock_builder::{execute_call, register_call};
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Source;
+		type Key;
+		type Value;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::storage]
+	type CallIds<T: Config> = StorageMap<_, _, String, mock_builder::CallId>;
+
+	impl<T: Config> Pallet<T> {
+		pub fn mock_feed(f: impl Fn(T::Source, T::Key, T::Value) -> DispatchResult + 'static) {
+			register_call!(move |(a, b, c)| f(a, b, c));
+		}
+	}
+
+	impl<T: Config> ValueFeeder<T::Source, T::Key> for Pallet<T> {
+		type Value = T::Value;
+
+		fn feed(a: T::Source, b: T::Key, c: T::Value) -> DispatchResult {
+			execute_call!((a, b, c))
+		}
+	}
+}
@@ -357,4 +357,18 @@ pub mod liquidity_pools {
 				.expect("Should not fail to trim 16-length byte array to length 12")
 		}
 	}
+	/// Key of the relayers submitting inbound messages to the gateway.
+	pub mod relayer {
+		use sp_runtime::{
+			app_crypto::{app_crypto, sr25519},
+			KeyTypeId,
+		};
+
+		pub const RELAYER_KEY_TYPE: KeyTypeId = KeyTypeId(*b"lprl");
+
+		app_crypto!(sr25519, RELAYER_KEY_TYPE);
+	}
+
+	/// Identifier of a gateway relayer.
+	pub type RelayerId = relayer::Public;
 }
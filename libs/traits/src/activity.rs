@@ -0,0 +1,40 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::pallet_prelude::{RuntimeDebug, TypeInfo};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+/// Kind of business identifier touched in a block
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub enum ActivityKind {
+	/// Identified by the pool id
+	Pool,
+
+	/// Identified by the tuple of pool id and loan id
+	Loan,
+
+	/// Identified by the liquidity pools message hash
+	Message,
+}
+
+/// A trait used to record the business identifiers touched in the current
+/// block, so they can be cheaply looked up afterwards.
+pub trait ActivityRecorder {
+	/// Record that the entity of kind `kind` identified by `id` was touched
+	/// in the current block.
+	fn record<Id: Encode>(kind: ActivityKind, id: &Id);
+}
+
+impl ActivityRecorder for () {
+	fn record<Id: Encode>(_: ActivityKind, _: &Id) {}
+}
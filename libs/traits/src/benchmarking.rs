@@ -70,3 +70,17 @@ pub trait PoolFeesBenchmarkHelper {
 	/// 1000u128 per second
 	fn get_default_charged_fee_info() -> Self::PoolFeeInfo;
 }
+
+/// Benchmark utility to prepare swaps between currencies
+pub trait SwapsBenchmarkHelper {
+	type AccountId;
+	type Balance;
+	type CurrencyId;
+
+	/// Register both currencies if needed and feed a market ratio of one
+	/// between them in both directions.
+	fn bench_setup_pair(currency_a: Self::CurrencyId, currency_b: Self::CurrencyId);
+
+	/// Mint an amount of the given currency into the account.
+	fn bench_mint(currency: Self::CurrencyId, account: &Self::AccountId, amount: Self::Balance);
+}
@@ -34,6 +34,12 @@ pub trait DataRegistry<DataId, CollectionId> {
 
 	/// Start listening data changes for a data id in a collection id
 	fn unregister_id(data_id: &DataId, collection_id: &CollectionId) -> DispatchResult;
+
+	/// Return `true` if the data of a data id in a collection violates the
+	/// protection rules of the registry and should not be trusted
+	fn is_flagged(_data_id: &DataId, _collection_id: &CollectionId) -> bool {
+		false
+	}
 }
 
 /// Abstration to represent a collection of data in memory
@@ -1,5 +1,53 @@
-use frame_support::dispatch::DispatchResultWithPostInfo;
-use sp_runtime::app_crypto::sp_core::{H160, U256};
+use frame_support::{
+	dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo, PostDispatchInfo},
+	pallet_prelude::TypeInfo,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::app_crypto::sp_core::{H160, H256, U256};
+use sp_std::vec::Vec;
+
+/// How the gas of an EVM transaction is priced
+#[derive(Debug, Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum GasPrice {
+	/// Legacy transaction paying a fixed price per gas.
+	Legacy(U256),
+
+	/// EIP-1559 transaction paying the base fee of the block plus a priority
+	/// fee, up to a maximum fee per gas.
+	Eip1559 {
+		max_fee_per_gas: U256,
+		max_priority_fee_per_gas: U256,
+	},
+}
+
+impl GasPrice {
+	/// Maximum amount of native currency that can be paid per gas.
+	pub fn max_fee_per_gas(&self) -> U256 {
+		match self {
+			GasPrice::Legacy(gas_price) => *gas_price,
+			GasPrice::Eip1559 {
+				max_fee_per_gas, ..
+			} => *max_fee_per_gas,
+		}
+	}
+}
+
+impl From<U256> for GasPrice {
+	fn from(gas_price: U256) -> Self {
+		GasPrice::Legacy(gas_price)
+	}
+}
+
+/// Storage slots of a contract declared as accessed by a transaction, as
+/// defined by EIP-2930. Accessing declared slots is charged as warm access.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo)]
+pub struct AccessListItem {
+	/// Address of the contract.
+	pub address: H160,
+
+	/// Storage slots of the contract.
+	pub storage_keys: Vec<H256>,
+}
 
 /// Something capable of managing transactions in an EVM/Ethereum context
 pub trait EthereumTransactor {
@@ -10,7 +58,55 @@ pub trait EthereumTransactor {
 		to: H160,
 		data: &[u8],
 		value: U256,
-		gas_price: U256,
+		gas_price: GasPrice,
 		gas_limit: U256,
+	) -> DispatchResultWithPostInfo {
+		Self::call_with_access_list(from, to, data, value, gas_price, gas_limit, Vec::new())
+	}
+
+	/// Transacts the specified call in the EVM context, declaring the storage
+	/// slots it accesses.
+	fn call_with_access_list(
+		from: H160,
+		to: H160,
+		data: &[u8],
+		value: U256,
+		gas_price: GasPrice,
+		gas_limit: U256,
+		access_list: Vec<AccessListItem>,
 	) -> DispatchResultWithPostInfo;
+
+	/// Deploys a contract in the EVM context by executing its init code.
+	/// Returns the address of the deployed contract.
+	fn create(
+		from: H160,
+		init_code: &[u8],
+		value: U256,
+		gas_price: GasPrice,
+		gas_limit: U256,
+		access_list: Vec<AccessListItem>,
+	) -> Result<(H160, PostDispatchInfo), DispatchErrorWithPostInfo>;
+}
+
+/// Reference to an Ethereum transaction included in the current block
+#[derive(Debug, Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct TransactionRef {
+	/// Hash of the transaction.
+	pub hash: H256,
+
+	/// Index of the transaction in the Ethereum block.
+	pub index: u32,
+}
+
+/// Something capable of inspecting the Ethereum transactions executed in the
+/// current block
+pub trait EthereumTransactionInspect {
+	/// Returns the last transaction executed in the current block.
+	fn last_transaction() -> Option<TransactionRef>;
+}
+
+impl EthereumTransactionInspect for () {
+	fn last_transaction() -> Option<TransactionRef> {
+		None
+	}
 }
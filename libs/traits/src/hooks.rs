@@ -0,0 +1,48 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::{storage::StorageValue, weights::Weight};
+use parity_scale_codec::FullCodec;
+
+/// Storage of the weight consumed by the hooks of a pallet, together with the
+/// block it was consumed in. Pallets reporting it declare a
+/// `StorageValue<_, (BlockNumberFor<T>, Weight), OptionQuery>`, which
+/// implements this trait.
+pub trait HookWeightStorage<BlockNumber: FullCodec + PartialEq>:
+	StorageValue<(BlockNumber, Weight), Query = Option<(BlockNumber, Weight)>>
+{
+	/// Records the weight consumed by the hooks in block `now`. Nothing is
+	/// written if no weight was consumed. Returns whether it was recorded.
+	fn record(now: BlockNumber, weight: Weight) -> bool {
+		if weight.is_zero() {
+			return false;
+		}
+
+		Self::put((now, weight));
+
+		true
+	}
+
+	/// Weight consumed by the hooks in block `now`
+	fn consumed_in(now: BlockNumber) -> Weight {
+		Self::get()
+			.filter(|(block, _)| *block == now)
+			.map(|(_, weight)| weight)
+			.unwrap_or_default()
+	}
+}
+
+impl<BlockNumber: FullCodec + PartialEq, Storage> HookWeightStorage<BlockNumber> for Storage where
+	Storage: StorageValue<(BlockNumber, Weight), Query = Option<(BlockNumber, Weight)>>
+{
+}
@@ -1,4 +1,4 @@
-use cfg_primitives::SECONDS_PER_YEAR;
+use cfg_primitives::{SECONDS_PER_DAY, SECONDS_PER_YEAR};
 use frame_support::{
 	dispatch::DispatchResult,
 	pallet_prelude::{RuntimeDebug, TypeInfo},
@@ -6,7 +6,7 @@ use frame_support::{
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_arithmetic::{
-	traits::{EnsureAdd, EnsureDiv, EnsureSub},
+	traits::{checked_pow, EnsureAdd, EnsureDiv, EnsureInto, EnsureMul, EnsureSub},
 	ArithmeticError, FixedPointNumber,
 };
 use sp_runtime::{
@@ -20,8 +20,109 @@ use crate::Seconds;
 pub enum CompoundingSchedule {
 	/// Interest compounds every second
 	Secondly,
+
+	/// Interest compounds every day, using an actual/360 day count. The rate
+	/// per day is the rate per year divided by 360.
+	Daily,
+
+	/// Interest compounds every 30 days, using a 30/360 day count. The rate
+	/// per month is the rate per year divided by 12.
+	Monthly,
+}
+
+impl CompoundingSchedule {
+	/// Length of a compounding period
+	pub fn period(&self) -> Seconds {
+		match self {
+			CompoundingSchedule::Secondly => 1,
+			CompoundingSchedule::Daily => SECONDS_PER_DAY,
+			CompoundingSchedule::Monthly => 30 * SECONDS_PER_DAY,
+		}
+	}
+
+	/// Number of compounding periods the rate per year is divided into
+	pub fn periods_per_year(&self) -> u64 {
+		match self {
+			CompoundingSchedule::Secondly => SECONDS_PER_YEAR,
+			CompoundingSchedule::Daily => 360,
+			CompoundingSchedule::Monthly => 12,
+		}
+	}
+
+	/// Rate per compounding period for the given rate per year, as a growth
+	/// factor (`1 + rate`)
+	pub fn rate_per_period<Rate: FixedPointNumber>(
+		&self,
+		rate_per_year: Rate,
+	) -> Result<Rate, ArithmeticError> {
+		rate_per_year
+			.ensure_div(Rate::saturating_from_integer(self.periods_per_year()))?
+			.ensure_add(One::one())
+	}
+
+	/// Rate per compounding period for the given negative rate per year, as a
+	/// decay factor (`1 - rate`). Fails if the debt would vanish within a
+	/// single period.
+	pub fn negative_rate_per_period<Rate: FixedPointNumber>(
+		&self,
+		rate_per_year: Rate,
+	) -> Result<Rate, ArithmeticError> {
+		let rate_per_period = Rate::one().ensure_sub(
+			rate_per_year.ensure_div(Rate::saturating_from_integer(self.periods_per_year()))?,
+		)?;
+
+		match rate_per_period.is_zero() {
+			true => Err(ArithmeticError::Underflow),
+			false => Ok(rate_per_period),
+		}
+	}
+
+	/// Growth of a value between `from` and `to` accruing at
+	/// `rate_per_period`.
+	///
+	/// Periods are aligned to multiples of `period()`. Every complete period
+	/// compounds, while the elapsed part of an incomplete period accrues
+	/// linearly. This makes the growth the same whether it is computed in one
+	/// step or in several consecutive ones.
+	pub fn accrual_factor<Rate: FixedPointNumber>(
+		&self,
+		rate_per_period: Rate,
+		from: Seconds,
+		to: Seconds,
+	) -> Result<Rate, ArithmeticError> {
+		let period = self.period();
+		let periods = (to / period).ensure_sub(from / period)?;
+		let compounded = checked_pow(rate_per_period, periods.ensure_into()?)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		if period == 1 {
+			return Ok(compounded);
+		}
+
+		// A factor below one decays, so the elapsed part of the period is
+		// subtracted instead of added to avoid underflowing `rate`
+		let partial = |at: Seconds| -> Result<Rate, ArithmeticError> {
+			let elapsed = Rate::saturating_from_rational(at % period, period);
+			if rate_per_period >= One::one() {
+				elapsed
+					.ensure_mul(rate_per_period.ensure_sub(One::one())?)?
+					.ensure_add(One::one())
+			} else {
+				Rate::one()
+					.ensure_sub(elapsed.ensure_mul(Rate::one().ensure_sub(rate_per_period)?)?)
+			}
+		};
+
+		compounded
+			.ensure_mul(partial(to)?)?
+			.ensure_div(partial(from)?)
+	}
 }
 
+/// Identifier of an externally provided reference rate, e.g. `b"SOFR"` padded
+/// with zeros.
+pub type ReferenceRateId = [u8; 16];
+
 /// Interest rate method with compounding schedule information
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 pub enum InterestRate<Rate> {
@@ -30,19 +131,76 @@ pub enum InterestRate<Rate> {
 		rate_per_year: Rate,
 		compounding: CompoundingSchedule,
 	},
+
+	/// Interest accrues at the current value of a reference rate plus a
+	/// spread
+	Floating {
+		reference: ReferenceRateId,
+		spread: Rate,
+		compounding: CompoundingSchedule,
+	},
+
+	/// Debt decreases at a fixed rate, e.g. to amortize a premium or to model
+	/// a negative-yield instrument. `rate_per_year` is the absolute value of
+	/// the negative rate.
+	FixedNegative {
+		rate_per_year: Rate,
+		compounding: CompoundingSchedule,
+	},
+}
+
+impl<Rate> InterestRate<Rate> {
+	pub fn compounding(&self) -> &CompoundingSchedule {
+		match self {
+			InterestRate::Fixed { compounding, .. }
+			| InterestRate::Floating { compounding, .. }
+			| InterestRate::FixedNegative { compounding, .. } => compounding,
+		}
+	}
 }
 
 impl<Rate: FixedPointNumber> InterestRate<Rate> {
-	pub fn per_year(&self) -> Rate {
+	/// Rate per year of a fixed interest rate. Floating rates depend on the
+	/// current value of their reference and have to be resolved with
+	/// [`InterestAccrual::fixed_rate()`] first. Negative rates can not be
+	/// represented as an unsigned rate per year.
+	pub fn per_year(&self) -> Result<Rate, DispatchError> {
 		match self {
-			InterestRate::Fixed { rate_per_year, .. } => *rate_per_year,
+			InterestRate::Fixed { rate_per_year, .. } => Ok(*rate_per_year),
+			InterestRate::Floating { .. } => {
+				Err(DispatchError::Other("Unresolved floating interest rate"))
+			}
+			InterestRate::FixedNegative { .. } => {
+				Err(DispatchError::Other("Negative interest rate"))
+			}
 		}
 	}
 
-	pub fn per_sec(&self) -> Result<Rate, ArithmeticError> {
-		self.per_year()
+	pub fn per_sec(&self) -> Result<Rate, DispatchError> {
+		Ok(self
+			.per_year()?
 			.ensure_div(Rate::saturating_from_integer(SECONDS_PER_YEAR))?
-			.ensure_add(One::one())
+			.ensure_add(One::one())?)
+	}
+
+	/// Rate per compounding period of a fixed interest rate, positive or
+	/// negative
+	pub fn per_period(&self) -> Result<Rate, DispatchError> {
+		match self {
+			InterestRate::FixedNegative {
+				rate_per_year,
+				compounding,
+			} => Ok(compounding.negative_rate_per_period(*rate_per_year)?),
+			_ => Ok(self.compounding().rate_per_period(self.per_year()?)?),
+		}
+	}
+
+	/// Growth of a debt accruing at a fixed interest rate between `from` and
+	/// `to`, following its compounding schedule.
+	pub fn accrual_factor(&self, from: Seconds, to: Seconds) -> Result<Rate, DispatchError> {
+		Ok(self
+			.compounding()
+			.accrual_factor(self.per_period()?, from, to)?)
 	}
 }
 
@@ -59,17 +217,65 @@ impl<Rate> InterestRate<Rate> {
 				rate_per_year: f(rate_per_year)?,
 				compounding,
 			},
+			Self::Floating {
+				reference,
+				spread,
+				compounding,
+			} => Self::Floating {
+				reference,
+				spread: f(spread)?,
+				compounding,
+			},
+			Self::FixedNegative {
+				rate_per_year,
+				compounding,
+			} => Self::FixedNegative {
+				rate_per_year: f(rate_per_year)?,
+				compounding,
+			},
 		})
 	}
 }
 
-impl<Rate: EnsureAdd + EnsureSub> InterestRate<Rate> {
+impl<Rate: EnsureAdd + EnsureSub + PartialOrd> InterestRate<Rate> {
+	/// Adds `rate` to the rate per year. A negative rate becomes a positive
+	/// one if `rate` exceeds its absolute value.
 	pub fn ensure_add(self, rate: Rate) -> Result<InterestRate<Rate>, ArithmeticError> {
-		self.try_map_rate(|r| r.ensure_add(rate))
+		match self {
+			Self::FixedNegative {
+				rate_per_year,
+				compounding,
+			} if rate > rate_per_year => Ok(Self::Fixed {
+				rate_per_year: rate.ensure_sub(rate_per_year)?,
+				compounding,
+			}),
+			Self::FixedNegative { .. } => self.try_map_rate(|r| r.ensure_sub(rate)),
+			_ => self.try_map_rate(|r| r.ensure_add(rate)),
+		}
 	}
 
+	/// Subtracts `rate` from the rate per year. Fails with an underflow if
+	/// `rate` exceeds a fixed or floating rate.
 	pub fn ensure_sub(self, rate: Rate) -> Result<InterestRate<Rate>, ArithmeticError> {
-		self.try_map_rate(|r| r.ensure_sub(rate))
+		match self {
+			Self::FixedNegative { .. } => self.try_map_rate(|r| r.ensure_add(rate)),
+			_ => self.try_map_rate(|r| r.ensure_sub(rate)),
+		}
+	}
+
+	/// Subtracts `rate` from the rate per year. A fixed rate becomes a
+	/// negative one if `rate` exceeds it.
+	pub fn ensure_signed_sub(self, rate: Rate) -> Result<InterestRate<Rate>, ArithmeticError> {
+		match self {
+			Self::Fixed {
+				rate_per_year,
+				compounding,
+			} if rate > rate_per_year => Ok(Self::FixedNegative {
+				rate_per_year: rate.ensure_sub(rate_per_year)?,
+				compounding,
+			}),
+			_ => self.ensure_sub(rate),
+		}
 	}
 }
 
@@ -112,6 +318,10 @@ pub trait InterestAccrual<Rate, Balance, Adjustment> {
 	/// Ask if the rate is valid to use by the implementation
 	fn validate_rate(interest_rate: &InterestRate<Rate>) -> DispatchResult;
 
+	/// Returns the fixed rate currently equivalent to the given one. A floating
+	/// rate is resolved using the current value of its reference.
+	fn fixed_rate(interest_rate: &InterestRate<Rate>) -> Result<InterestRate<Rate>, DispatchError>;
+
 	/// Returns a collection of pre-computed rates to perform multiple
 	/// operations with
 	fn rates() -> Self::Rates;
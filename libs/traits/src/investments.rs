@@ -11,7 +11,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use sp_runtime::{DispatchError, DispatchResult};
+use sp_runtime::{DispatchError, DispatchResult, RuntimeDebug};
 use sp_std::fmt::Debug;
 
 /// A trait for converting from a PoolId and a TranchId
@@ -273,6 +273,27 @@ pub trait ForeignInvestment<AccountId> {
 		investment_id: Self::InvestmentId,
 		foreign_payout_currency: Self::CurrencyId,
 	) -> Result<Self::TrancheAmount, DispatchError>;
+
+	/// Returns the current position of who in the investment class.
+	fn foreign_position(
+		who: &AccountId,
+		investment_id: Self::InvestmentId,
+	) -> Result<ForeignInvestmentPosition<Self::Amount, Self::TrancheAmount>, DispatchError>;
+}
+
+/// The position of an investor in a foreign investment class.
+#[derive(Clone, Copy, Default, PartialEq, Eq, RuntimeDebug)]
+pub struct ForeignInvestmentPosition<Amount, TrancheAmount> {
+	/// Foreign amount invested and not yet collected, including the amount
+	/// still being swapped into pool currency.
+	pub pending_investment: Amount,
+
+	/// Tranche tokens pending to be redeemed.
+	pub pending_redemption: TrancheAmount,
+
+	/// Foreign amount already swapped from collected redemptions which is
+	/// sent once the remaining swap is fulfilled.
+	pub claimable_redemption: Amount,
 }
 
 /// Trait used to receive information asynchronously from a ForeignInvestment
@@ -0,0 +1,42 @@
+use frame_support::pallet_prelude::TypeInfo;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{
+	app_crypto::sp_core::{H256, U256},
+	DispatchError,
+};
+use sp_std::vec::Vec;
+
+/// A state machine reachable through ISMP
+#[derive(Debug, Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum StateMachine {
+	/// An EVM chain identified by its chain id
+	Evm(u32),
+}
+
+/// A POST request sent to a module of another state machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PostRequest {
+	/// The state machine receiving the request.
+	pub dest: StateMachine,
+
+	/// The module receiving the request in the destination state machine.
+	pub to: Vec<u8>,
+
+	/// The payload of the request.
+	pub body: Vec<u8>,
+
+	/// Seconds after which the request times out if not delivered.
+	pub timeout: u64,
+
+	/// The fee paid to the relayer delivering the request.
+	pub fee: U256,
+}
+
+/// Something capable of dispatching ISMP requests, as `pallet-ismp`.
+///
+/// The requests received from other state machines are verified against the
+/// consensus proofs of the source state machine before reaching the modules.
+pub trait IsmpDispatcher {
+	/// Dispatches a POST request, returning the commitment identifying it.
+	fn dispatch_post(request: PostRequest) -> Result<H256, DispatchError>;
+}
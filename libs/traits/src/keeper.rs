@@ -0,0 +1,40 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::pallet_prelude::{RuntimeDebug, TypeInfo};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+
+/// Permissionless maintenance work rewarded to the account performing it
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub enum KeeperAction {
+	/// Two crossing orders of the order book were matched
+	MatchOrders,
+
+	/// A loan was written off according to the write off policy of its pool
+	WriteOffLoan,
+}
+
+/// A trait used to reward keepers for permissionless maintenance work
+pub trait KeeperRewards {
+	type AccountId;
+	type Action;
+	type Balance;
+
+	/// Pay the reward of an already performed action to the keeper.
+	/// Returns the amount paid.
+	///
+	/// Rewards are best effort: if nothing can be paid, for example because
+	/// the funds to pay from are exhausted, zero is returned and the action
+	/// must not fail because of it.
+	fn reward(keeper: &Self::AccountId, action: Self::Action) -> Self::Balance;
+}
@@ -30,13 +30,17 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_runtime::{traits::Member, DispatchError};
 use sp_std::{fmt::Debug, marker::PhantomData, vec::Vec};
 
+pub mod activity;
 pub mod changes;
 pub mod data;
 pub mod ethereum;
 pub mod fee;
 pub mod fees;
+pub mod hooks;
 pub mod interest;
 pub mod investments;
+pub mod ismp;
+pub mod keeper;
 pub mod liquidity_pools;
 pub mod rewards;
 pub mod swaps;
@@ -174,6 +178,15 @@ pub trait PoolMetadata<Balance, VersionedMultiLocation> {
 	) -> DispatchResult;
 }
 
+/// A trait to query the acknowledgements of the notices published for a pool.
+pub trait PoolNotices<AccountId, PoolId> {
+	type NoticeId;
+
+	/// Returns the last notice of the pool requiring an acknowledgement if the
+	/// given account has not acknowledged it yet.
+	fn pending_acknowledgement(pool_id: PoolId, who: &AccountId) -> Option<Self::NoticeId>;
+}
+
 /// A trait that support pool reserve operations such as withdraw and deposit
 pub trait PoolReserve<AccountId, CurrencyId>: PoolInspect<AccountId, CurrencyId> {
 	type Balance;
@@ -225,6 +238,13 @@ pub trait Properties {
 	fn rm(&mut self, property: Self::Property) -> Result<Self::Ok, Self::Error>;
 
 	fn add(&mut self, property: Self::Property) -> Result<Self::Ok, Self::Error>;
+
+	/// Drops all properties whose validity ran out and returns how many were
+	/// dropped.
+	fn purge_expired(&mut self) -> u32;
+
+	/// Lists all properties that are currently valid.
+	fn properties(&self) -> Vec<Self::Property>;
 }
 
 pub trait PoolUpdateGuard {
@@ -299,6 +319,51 @@ pub trait TransferAllowance<AccountId> {
 	) -> Result<Option<Self::Location>, DispatchError>;
 }
 
+/// Transfer filter specific to some currencies, checked on top of the
+/// filters applied to the transfers of every currency.
+pub trait CurrencyTransferHook<AccountId, CurrencyId, Balance> {
+	/// Whether the hook filters the transfers of `currency`.
+	fn applies_to(currency: &CurrencyId) -> bool;
+
+	/// Checks whether `send` can transfer `amount` of `currency` to `recv`.
+	/// Only called for the currencies the hook applies to.
+	fn check(
+		send: &AccountId,
+		recv: &AccountId,
+		currency: &CurrencyId,
+		amount: Balance,
+	) -> DispatchResult;
+}
+
+#[impl_for_tuples(10)]
+impl<AccountId, CurrencyId, Balance: Copy> CurrencyTransferHook<AccountId, CurrencyId, Balance>
+	for Tuple
+{
+	fn applies_to(currency: &CurrencyId) -> bool {
+		let hooks: &[fn(&CurrencyId) -> bool] = &[for_tuples!( #( Tuple::applies_to ),* )];
+
+		hooks.iter().any(|applies_to| applies_to(currency))
+	}
+
+	fn check(
+		send: &AccountId,
+		recv: &AccountId,
+		currency: &CurrencyId,
+		amount: Balance,
+	) -> DispatchResult {
+		#[allow(clippy::type_complexity)]
+		let hooks: &[(
+			fn(&CurrencyId) -> bool,
+			fn(&AccountId, &AccountId, &CurrencyId, Balance) -> DispatchResult,
+		)] = &[for_tuples!( #( (Tuple::applies_to, Tuple::check) ),* )];
+
+		hooks
+			.iter()
+			.filter(|(applies_to, _)| applies_to(currency))
+			.try_for_each(|(_, check)| check(send, recv, currency, amount))
+	}
+}
+
 /// Trait to retrieve information about currencies.
 pub trait CurrencyInspect {
 	type CurrencyId;
@@ -408,6 +473,13 @@ pub trait ValueProvider<Source, Key> {
 	fn set(_source: &Source, _key: &Key, _value: Self::Value) {}
 }
 
+/// A storage where sources can feed values
+pub trait ValueFeeder<Source, Key> {
+	type Value;
+
+	fn feed(source: Source, key: Key, value: Self::Value) -> DispatchResult;
+}
+
 /// A provider that never returns a value
 pub struct NoProvider<Value>(PhantomData<Value>);
 impl<Source, Key, Value> ValueProvider<Source, Key> for NoProvider<Value> {
@@ -11,21 +11,87 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use frame_support::{dispatch::DispatchResult, weights::Weight};
+use frame_support::{
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
+	weights::Weight,
+};
 use sp_runtime::{app_crypto::sp_core::H160, DispatchError};
 use sp_std::vec::Vec;
 
+use crate::Seconds;
+
 /// Type that represents the hash of an LP message.
 pub type MessageHash = [u8; 32];
 
 /// An encoding & decoding trait for the purpose of meeting the
 /// LiquidityPools General Message Passing Format
 pub trait LpMessageSerializer: Sized {
+	/// Latest version of the serialization format this chain can produce
+	/// and decode.
+	const LATEST_VERSION: MessageVersion = LEGACY_MESSAGE_VERSION;
+
 	/// Serialize the message
 	fn serialize(&self) -> Vec<u8>;
 
 	/// Deserialize the message
 	fn deserialize(input: &[u8]) -> Result<Self, DispatchError>;
+
+	/// Serialize the message for a domain decoding up to the given version.
+	/// Legacy messages are sent without envelope, otherwise the payload is
+	/// prefixed with `VERSIONED_MESSAGE_MARKER` and the version.
+	fn serialize_versioned(&self, version: MessageVersion) -> Vec<u8> {
+		match version.min(Self::LATEST_VERSION) {
+			LEGACY_MESSAGE_VERSION => self.serialize(),
+			version => [VERSIONED_MESSAGE_MARKER, version]
+				.into_iter()
+				.chain(self.serialize())
+				.collect(),
+		}
+	}
+
+	/// Deserialize a message in the legacy or in the versioned format,
+	/// returning the version it was serialized with.
+	fn deserialize_versioned(input: &[u8]) -> Result<(Self, MessageVersion), DispatchError> {
+		match input {
+			[VERSIONED_MESSAGE_MARKER, version, payload @ ..] => {
+				if *version == LEGACY_MESSAGE_VERSION || *version > Self::LATEST_VERSION {
+					return Err(DispatchError::Other("Unsupported LP message version"));
+				}
+
+				Ok((Self::deserialize(payload)?, *version))
+			}
+			_ => Ok((Self::deserialize(input)?, LEGACY_MESSAGE_VERSION)),
+		}
+	}
+}
+
+/// Version of the serialization format of a message.
+pub type MessageVersion = u8;
+
+/// Version of the messages sent without envelope, understood by every
+/// deployment.
+pub const LEGACY_MESSAGE_VERSION: MessageVersion = 0;
+
+/// First byte of a versioned message. No message type uses it, which tells
+/// versioned messages apart from legacy ones.
+pub const VERSIONED_MESSAGE_MARKER: u8 = 0xFF;
+
+/// The versions of the message format negotiated with each domain.
+pub trait MessageVersions<Domain> {
+	/// Version used to serialize the messages sent to the domain.
+	fn outbound_version(domain: &Domain) -> MessageVersion;
+
+	/// Notifies the version of a message successfully received from the
+	/// domain.
+	fn note_inbound_version(domain: &Domain, version: MessageVersion);
+}
+
+impl<Domain> MessageVersions<Domain> for () {
+	fn outbound_version(_: &Domain) -> MessageVersion {
+		LEGACY_MESSAGE_VERSION
+	}
+
+	fn note_inbound_version(_: &Domain, _: MessageVersion) {}
 }
 
 /// Behavior or a message that can be batched
@@ -90,6 +156,16 @@ pub trait LpMessageForwarded: Sized {
 	) -> Result<Self, DispatchError>;
 }
 
+/// Behavior of a message that can carry a sequence number of its domain.
+pub trait LpMessageSequence: Sized {
+	/// Splits a message into its sequence number, if any, and the message
+	/// without the sequence.
+	fn split_sequence(self) -> (Option<u64>, Self);
+
+	/// Attempts to wrap a message with a sequence number.
+	fn try_wrap_sequenced(nonce: u64, message: Self) -> Result<Self, DispatchError>;
+}
+
 pub trait RouterProvider<Domain>: Sized {
 	/// The router identifier.
 	type RouterId;
@@ -109,12 +185,38 @@ pub trait MessageSender {
 	/// The type of the message
 	type Message;
 
-	/// Sends a message for origin to destination
+	/// Sends a message for origin to destination.
+	/// The post info contains the actual weight consumed by the sending, if
+	/// known.
 	fn send(
 		middleware: Self::Middleware,
 		origin: Self::Origin,
 		message: Self::Message,
-	) -> DispatchResult;
+	) -> DispatchResultWithPostInfo;
+
+	/// Returns the maximum size in bytes of a serialized message that can be
+	/// sent through the middleware, if limited.
+	fn max_payload_size(_middleware: &Self::Middleware) -> Option<u32> {
+		None
+	}
+}
+
+/// The behavior of an entity that can estimate the cost of sending messages
+pub trait MessageFeeEstimator {
+	/// The middleware by where this message is sent
+	type Middleware;
+
+	/// The type of the message
+	type Message;
+
+	/// The type of the estimated fee
+	type Fee;
+
+	/// Estimates the fee charged for sending a message through the middleware
+	fn estimate_fee(
+		middleware: Self::Middleware,
+		message: &Self::Message,
+	) -> Result<Self::Fee, DispatchError>;
 }
 
 /// The behavior of an entity that can receive messages
@@ -186,3 +288,35 @@ pub trait InboundMessageHandler {
 	/// Handle an inbound message.
 	fn handle(sender: Self::Sender, msg: Self::Message) -> DispatchResult;
 }
+
+/// The trait required for tracking outbound LP messages when they are sent.
+pub trait OutboundMessageTracker<Destination, Message> {
+	/// Called right before an outbound message is sent through a router.
+	/// An error prevents the message from being sent.
+	fn on_send(destination: Destination, msg: &Message) -> DispatchResult;
+}
+
+impl<Destination, Message> OutboundMessageTracker<Destination, Message> for () {
+	fn on_send(_: Destination, _: &Message) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// The trait required for handling prices pushed by oracles of other domains.
+pub trait PriceUpdateHandler<Sender> {
+	/// Handle the price of an oracle feed of the sender domain. The price is
+	/// an integer with `decimals` decimals computed at `computed_at`.
+	fn handle_price(
+		sender: Sender,
+		feed: [u8; 32],
+		price: u128,
+		decimals: u8,
+		computed_at: Seconds,
+	) -> DispatchResult;
+}
+
+impl<Sender> PriceUpdateHandler<Sender> for () {
+	fn handle_price(_: Sender, _: [u8; 32], _: u128, _: u8, _: Seconds) -> DispatchResult {
+		Err(DispatchError::Other("Price updates are not supported"))
+	}
+}
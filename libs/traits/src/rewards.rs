@@ -163,7 +163,40 @@ pub trait AccountRewards<AccountId> {
 	) -> Result<Self::Balance, DispatchError>;
 
 	/// Retrieve the total staked amount of currency in an account.
+	/// If the stake is locked, see [`StakeLocks`], it includes the boost.
 	fn account_stake(currency_id: Self::CurrencyId, account_id: &AccountId) -> Self::Balance;
+
+	/// Retrieve the total staked amount of currency among all accounts,
+	/// including the boosts of the locked stakes.
+	fn currency_stake(currency_id: Self::CurrencyId) -> Self::Balance;
+}
+
+/// Support for locking the stake of an account to boost its rewards.
+pub trait StakeLocks<AccountId>: AccountRewards<AccountId> {
+	/// Lock an amount of the stake of an account_id associated to a
+	/// currency_id with a lock-up option. The locked amount can not be
+	/// withdrawn, and the stake weighting the rewards of the account is
+	/// boosted by the multiplier of the option until it's unlocked.
+	/// An account can have only one lock per currency.
+	fn lock_stake(
+		currency_id: Self::CurrencyId,
+		account_id: &AccountId,
+		amount: Self::Balance,
+		option: u32,
+	) -> DispatchResult;
+
+	/// Unlock the stake of an account_id associated to a currency_id,
+	/// removing its boost. If the lock did not expire yet, the pending reward
+	/// of the account is claimed and a part of it is forfeited to the
+	/// remaining stakers. The method returns the forfeited reward.
+	fn unlock_stake(
+		currency_id: Self::CurrencyId,
+		account_id: &AccountId,
+	) -> Result<Self::Balance, DispatchError>;
+
+	/// Check if the stake of an account_id associated to a currency_id is
+	/// locked and the lock expired.
+	fn is_lock_expired(currency_id: Self::CurrencyId, account_id: &AccountId) -> bool;
 }
 
 /// Support for change currencies among groups.
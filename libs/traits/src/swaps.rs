@@ -8,6 +8,12 @@ use sp_std::fmt::Debug;
 pub enum OrderRatio<Ratio> {
 	Market,
 	Custom(Ratio),
+	/// Same as `Market`, but the ratio obtained at fulfillment time can not be
+	/// lower than the market ratio at placement time by more than
+	/// `max_slippage`.
+	MarketWithSlippage {
+		max_slippage: Ratio,
+	},
 }
 
 /// A simple representation of a currency swap.
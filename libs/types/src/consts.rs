@@ -12,10 +12,23 @@
 
 // Rewards-related constants
 pub mod rewards {
-	use cfg_primitives::{Balance, CFG};
+	use cfg_primitives::{Balance, BlockNumber, CFG, DAYS};
+	use sp_runtime::{FixedU128, Perbill};
 
 	/// The default amount of stake for
 	/// CurrencyId::Staking(StakingCurrency::BlockRewards) which is inherently
 	/// assigned to any member of the only group in block rewards.
 	pub const DEFAULT_COLLATOR_STAKE: Balance = CFG;
+
+	/// The lock-up options of the liquidity rewards stakes. Locking a stake for
+	/// 3, 6 or 12 months boosts its reward by 1.2x, 1.5x or 2x.
+	pub const LIQUIDITY_LOCK_OPTIONS: [(BlockNumber, FixedU128); 3] = [
+		(90 * DAYS, FixedU128::from_rational(6, 5)),
+		(180 * DAYS, FixedU128::from_rational(3, 2)),
+		(365 * DAYS, FixedU128::from_rational(2, 1)),
+	];
+
+	/// The part of the pending liquidity rewards forfeited when unlocking a
+	/// stake before its lock expires.
+	pub const LIQUIDITY_EARLY_UNLOCK_PENALTY: Perbill = Perbill::from_percent(50);
 }
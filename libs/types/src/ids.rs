@@ -31,8 +31,12 @@ pub const TREASURY_PALLET_ID: PalletId = PalletId(*b"py/trsry");
 pub const STAKE_POT_PALLET_ID: PalletId = PalletId(*b"PotStake");
 pub const BLOCK_REWARDS_PALLET_ID: PalletId = PalletId(*b"cfg/blrw");
 pub const LIQUIDITY_REWARDS_PALLET_ID: PalletId = PalletId(*b"cfg/lqrw");
+pub const LIQUIDITY_CO_INCENTIVES_PALLET_ID: PalletId = PalletId(*b"cfg/lqci");
 pub const POOL_FEES_PALLET_ID: PalletId = PalletId(*b"cfg/plfs");
 pub const TOKEN_MUX_PALLET_ID: PalletId = PalletId(*b"cfg/tmux");
+pub const KEEPER_REWARDS_PALLET_ID: PalletId = PalletId(*b"cfg/kprw");
+pub const LP_GATEWAY_FEE_POT_PALLET_ID: PalletId = PalletId(*b"cfg/lpgw");
+pub const INVESTOR_REWARDS_PALLET_ID: PalletId = PalletId(*b"cfg/inrw");
 
 // Other ids
 pub const CHAIN_BRIDGE_HASH_ID: [u8; 13] = *b"cent_nft_hash";
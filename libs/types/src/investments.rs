@@ -20,7 +20,7 @@ use sp_runtime::{
 };
 use sp_std::cmp::PartialEq;
 
-use crate::orders::Order;
+use crate::{domain_address::Domain, orders::Order};
 
 /// A representation of a investment identifier that can be converted to an
 /// account address
@@ -206,3 +206,47 @@ impl<Balance: Default, CurrencyId> InvestmentPortfolio<Balance, CurrencyId> {
 		self
 	}
 }
+
+/// A currency accepted to invest in a pool, with the constraints that apply
+/// to investments in it.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AcceptedCurrency<Balance, CurrencyId, Ratio> {
+	/// The identifier of the currency
+	pub currency_id: CurrencyId,
+	/// The only domain investments in this currency can come from, if the
+	/// currency is a liquidity pools wrapped token
+	pub domain: Option<Domain>,
+	/// Whether investments in this currency are swapped into the pool
+	/// currency
+	pub requires_swap: bool,
+	/// The min amount of an investment swap, zero if not swapped
+	pub min_amount: Balance,
+	/// The current market ratio used to swap the currency into the pool
+	/// currency, if swapped and known
+	pub market_ratio: Option<Ratio>,
+}
+
+/// The exposure of a pool to a currency other than its pool currency, held in
+/// its reserve or pending in swaps of foreign investments.
+#[derive(Encode, Decode, Default, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CurrencyExposure<Balance, CurrencyId> {
+	/// The identifier of the currency
+	pub currency_id: CurrencyId,
+	/// The balance of the pool reserve held in this currency
+	pub reserve: Balance,
+	/// The amount of this currency pending to be swapped into pool currency
+	pub pending_to_pool_currency: Balance,
+	/// The amount of pool currency pending to be swapped into this currency
+	pub pending_from_pool_currency: Balance,
+}
+
+impl<Balance: Default, CurrencyId> CurrencyExposure<Balance, CurrencyId> {
+	pub fn new(currency_id: CurrencyId) -> Self {
+		Self {
+			currency_id,
+			reserve: Balance::default(),
+			pending_to_pool_currency: Balance::default(),
+			pending_from_pool_currency: Balance::default(),
+		}
+	}
+}
@@ -15,10 +15,11 @@ use frame_support::RuntimeDebugNoBound;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::crypto::AccountId32;
+use sp_runtime::traits::Convert;
 use sp_std::boxed::Box;
 use staging_xcm::VersionedLocation;
 
-use crate::domain_address::DomainAddress;
+use crate::domain_address::{Domain, DomainAddress};
 
 /// Location types for destinations that can receive restricted transfers
 #[derive(Clone, RuntimeDebugNoBound, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
@@ -29,6 +30,8 @@ pub enum RestrictedTransferLocation {
 	Xcm(Box<VersionedLocation>),
 	/// DomainAddress sending location from a liquidity pools' instance
 	Address(DomainAddress),
+	/// Any address of a domain, only used as wildcard location.
+	Domain(Domain),
 }
 
 impl From<AccountId32> for RestrictedTransferLocation {
@@ -36,3 +39,21 @@ impl From<AccountId32> for RestrictedTransferLocation {
 		Self::Local(value)
 	}
 }
+
+/// Converts a location into the domain wildcard covering it. Local accounts are
+/// covered by the Centrifuge domain.
+pub struct DomainWildcard;
+
+impl Convert<RestrictedTransferLocation, Option<RestrictedTransferLocation>> for DomainWildcard {
+	fn convert(location: RestrictedTransferLocation) -> Option<RestrictedTransferLocation> {
+		match location {
+			RestrictedTransferLocation::Local(_) => {
+				Some(RestrictedTransferLocation::Domain(Domain::Centrifuge))
+			}
+			RestrictedTransferLocation::Address(address) => {
+				Some(RestrictedTransferLocation::Domain(address.domain()))
+			}
+			RestrictedTransferLocation::Xcm(_) | RestrictedTransferLocation::Domain(_) => None,
+		}
+	}
+}
@@ -1,4 +1,5 @@
 use cfg_primitives::{LoanId, PoolId};
+use cfg_traits::interest::ReferenceRateId;
 use frame_support::pallet_prelude::RuntimeDebug;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
@@ -39,6 +40,11 @@ pub enum OracleKey {
 	/// given oracle.
 	#[codec(index = 2)]
 	PoolLoanId(PoolId, LoanId),
+
+	/// Identify the value per year of a reference rate used by floating
+	/// interest rates
+	#[codec(index = 3)]
+	ReferenceRate(ReferenceRateId),
 }
 
 impl From<(CurrencyId, CurrencyId)> for OracleKey {
@@ -19,13 +19,14 @@ use serde::{Deserialize, Serialize};
 use sp_std::{
 	cmp::{Ord, PartialEq, PartialOrd},
 	marker::PhantomData,
+	vec::Vec,
 };
 
 /// PoolRole can hold any type of role specific functions a user can do on a
 /// given pool.
 // NOTE: In order to not carry around the TrancheId type all the time, we give it a
 // default.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PoolRole<TrancheId = [u8; 16]> {
 	PoolAdmin,
@@ -39,7 +40,7 @@ pub enum PoolRole<TrancheId = [u8; 16]> {
 	FrozenTrancheInvestor(TrancheId),
 }
 
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum PermissionedCurrencyRole {
 	/// This role can hold & transfer tokens
@@ -53,7 +54,7 @@ pub enum PermissionedCurrencyRole {
 /// The Role enum is used by the permissions pallet,
 /// to specify which role an account has within a
 /// specific scope.
-#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug)]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, Debug, MaxEncodedLen)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Role<TrancheId = [u8; 16]> {
 	/// Roles that apply to a specific pool.
@@ -194,7 +195,7 @@ impl<Now, MinDelay, TrancheId, MaxTranches> Properties
 where
 	Now: TimeAsSecs,
 	MinDelay: Get<Seconds>,
-	TrancheId: PartialEq + PartialOrd,
+	TrancheId: Clone + PartialEq + PartialOrd,
 	MaxTranches: Get<u32>,
 {
 	type Error = ();
@@ -310,6 +311,72 @@ where
 			}
 		}
 	}
+
+	fn purge_expired(&mut self) -> u32 {
+		self.permissioned_asset_holder
+			.purge_expired()
+			.saturating_add(self.tranche_investor.purge_expired())
+	}
+
+	/// Time-bound roles are listed with the moment until which they are
+	/// valid, not with the delta they were added with.
+	fn properties(&self) -> Vec<Self::Property> {
+		let pool_admin = [
+			(PoolAdminRoles::POOL_ADMIN, PoolRole::PoolAdmin),
+			(PoolAdminRoles::BORROWER, PoolRole::Borrower),
+			(PoolAdminRoles::PRICING_ADMIN, PoolRole::PricingAdmin),
+			(PoolAdminRoles::LIQUIDITY_ADMIN, PoolRole::LiquidityAdmin),
+			(PoolAdminRoles::INVESTOR_ADMIN, PoolRole::InvestorAdmin),
+			(PoolAdminRoles::RISK_ADMIN, PoolRole::LoanAdmin),
+			(PoolAdminRoles::POD_READ_ACCESS, PoolRole::PODReadAccess),
+		];
+		let currency_admin = [
+			(
+				CurrencyAdminRoles::PERMISSIONED_ASSET_MANAGER,
+				PermissionedCurrencyRole::Manager,
+			),
+			(
+				CurrencyAdminRoles::PERMISSIONED_ASSET_ISSUER,
+				PermissionedCurrencyRole::Issuer,
+			),
+		];
+
+		let mut roles: Vec<Self::Property> = pool_admin
+			.into_iter()
+			.filter(|(flag, _)| self.pool_admin.contains(*flag))
+			.map(|(_, role)| Role::PoolRole(role))
+			.collect();
+
+		roles.extend(
+			currency_admin
+				.into_iter()
+				.filter(|(flag, _)| self.currency_admin.contains(*flag))
+				.map(|(_, role)| Role::PermissionedCurrencyRole(role)),
+		);
+
+		if let Some(till) = self.permissioned_asset_holder.valid_till() {
+			roles.push(Role::PermissionedCurrencyRole(
+				PermissionedCurrencyRole::Holder(till),
+			));
+		}
+
+		let now = <Now as TimeAsSecs>::now();
+		for info in self.tranche_investor.info.iter() {
+			if info.permissioned_till >= now {
+				roles.push(Role::PoolRole(PoolRole::TrancheInvestor(
+					info.tranche_id.clone(),
+					info.permissioned_till,
+				)));
+			}
+			if info.is_frozen {
+				roles.push(Role::PoolRole(PoolRole::FrozenTrancheInvestor(
+					info.tranche_id.clone(),
+				)));
+			}
+		}
+
+		roles
+	}
 }
 
 impl<Now, MinDelay> PermissionedCurrencyHolders<Now, MinDelay>
@@ -338,10 +405,25 @@ where
 	}
 
 	pub fn contains(&self) -> bool {
-		if let Some(info) = &self.info {
-			info.permissioned_till >= <Now as TimeAsSecs>::now()
+		self.valid_till().is_some()
+	}
+
+	/// The moment until which the holder role is valid, if it still is.
+	pub fn valid_till(&self) -> Option<Seconds> {
+		self.info
+			.as_ref()
+			.map(|info| info.permissioned_till)
+			.filter(|till| *till >= <Now as TimeAsSecs>::now())
+	}
+
+	/// Drops the holder role if it already expired. Returns the number of
+	/// dropped roles.
+	pub fn purge_expired(&mut self) -> u32 {
+		if self.info.is_some() && self.valid_till().is_none() {
+			self.info = None;
+			1
 		} else {
-			false
+			0
 		}
 	}
 
@@ -421,6 +503,17 @@ where
 			.any(|info| info.tranche_id == tranche && info.is_frozen)
 	}
 
+	/// Drops the expired investor roles. Frozen investors are kept, as the
+	/// freeze must be lifted explicitly. Returns the number of dropped roles.
+	pub fn purge_expired(&mut self) -> u32 {
+		let now = <Now as TimeAsSecs>::now();
+		let before = self.info.len();
+		self.info
+			.retain(|info| info.is_frozen || info.permissioned_till >= now);
+
+		(before - self.info.len()) as u32
+	}
+
 	#[allow(clippy::result_unit_err)]
 	pub fn remove(&mut self, tranche: TrancheId, delta: Seconds) -> Result<(), ()> {
 		if let Some(index) = self.info.iter().position(|info| info.tranche_id == tranche) {
@@ -678,6 +771,47 @@ mod tests {
 		assert!(!roles.exists(Role::PoolRole(PoolRole::LiquidityAdmin)));
 		assert!(!roles.exists(Role::PoolRole(PoolRole::InvestorAdmin)));
 		assert!(!roles.exists(Role::PoolRole(PoolRole::PODReadAccess)));
+
+		// Only valid roles are listed
+		assert_eq!(
+			roles.properties(),
+			vec![
+				Role::PoolRole(PoolRole::TrancheInvestor(into_tranche_id(30), 11)),
+				Role::PoolRole(PoolRole::TrancheInvestor(into_tranche_id(0), 4)),
+				Role::PoolRole(PoolRole::TrancheInvestor(into_tranche_id(1), 4)),
+				Role::PoolRole(PoolRole::TrancheInvestor(into_tranche_id(2), 4)),
+				Role::PoolRole(PoolRole::TrancheInvestor(into_tranche_id(8), 6)),
+			]
+		);
+
+		// Nothing is purged while roles are still valid
+		assert_eq!(roles.purge_expired(), 0);
+
+		// Purging drops expired roles but keeps frozen ones
+		assert!(roles
+			.add(Role::PoolRole(PoolRole::FrozenTrancheInvestor(
+				into_tranche_id(1)
+			)))
+			.is_ok());
+		Now::set(7);
+		assert_eq!(
+			roles.properties(),
+			vec![
+				Role::PoolRole(PoolRole::TrancheInvestor(into_tranche_id(30), 11)),
+				Role::PoolRole(PoolRole::FrozenTrancheInvestor(into_tranche_id(1))),
+			]
+		);
+		assert_eq!(roles.purge_expired(), 3);
+		assert_eq!(roles.purge_expired(), 0);
+		assert!(roles
+			.rm(Role::PoolRole(PoolRole::FrozenTrancheInvestor(
+				into_tranche_id(1)
+			)))
+			.is_ok());
+		Now::set(12);
+		assert_eq!(roles.purge_expired(), 2);
+		assert!(roles.empty());
+		Now::set(0);
 	}
 }
 
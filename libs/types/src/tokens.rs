@@ -276,6 +276,10 @@ pub struct CustomMetadata {
 	/// are receiving the same asset from multiple domains and unify the asset
 	/// under a common local representation.
 	pub local_representation: Option<LocalAssetId>,
+
+	/// Whether the asset is blocked from leaving the chain through XCM
+	/// reserve transfers or teleports, regardless of its `transferability`.
+	pub xcm_restricted: bool,
 }
 
 #[cfg(feature = "std")]
@@ -626,6 +626,7 @@ fn asset_registry_assets() -> Vec<(CurrencyId, Vec<u8>)> {
 					pool_currency: true,
 					transferability: CrossChainTransferability::Xcm(Default::default()),
 					local_representation: None,
+					xcm_restricted: false,
 				},
 			}
 			.encode(),
@@ -656,6 +657,7 @@ fn asset_registry_assets() -> Vec<(CurrencyId, Vec<u8>)> {
 					pool_currency: true,
 					transferability: CrossChainTransferability::Xcm(Default::default()),
 					local_representation: None,
+					xcm_restricted: false,
 				},
 			}
 			.encode(),
@@ -680,6 +682,7 @@ fn asset_registry_assets() -> Vec<(CurrencyId, Vec<u8>)> {
 					pool_currency: true,
 					transferability: CrossChainTransferability::None,
 					local_representation: None,
+					xcm_restricted: false,
 				},
 			}
 			.encode(),
@@ -716,6 +719,7 @@ fn asset_registry_assets() -> Vec<(CurrencyId, Vec<u8>)> {
 					permissioned: false,
 					pool_currency: true,
 					local_representation: Some(usdc::LOCAL_ASSET_ID),
+					xcm_restricted: false,
 				},
 			}
 			.encode(),
@@ -0,0 +1,122 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! # Activity Bloom Pallet
+//!
+//! Maintains a bloom filter of the business identifiers (pool ids, loan ids,
+//! message hashes) touched in the current block. Light clients and the EVM
+//! side can query the bloom of a block to cheaply detect whether it is
+//! relevant to them before downloading its events.
+//!
+//! The bloom follows the Ethereum logs bloom layout: 2048 bits where each
+//! identifier sets 3 bits taken from the `blake2_256` hash of the SCALE
+//! encoded `(ActivityKind, id)` tuple.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use cfg_traits::activity::{ActivityKind, ActivityRecorder};
+use frame_support::pallet_prelude::*;
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use sp_io::hashing::blake2_256;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Size of the bloom in bytes
+pub const BLOOM_BYTES: usize = 256;
+
+/// Number of bits set per identifier
+const BITS_PER_ID: usize = 3;
+
+/// Bloom filter of the identifiers touched in a block
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ActivityBloom(pub [u8; BLOOM_BYTES]);
+
+impl Default for ActivityBloom {
+	fn default() -> Self {
+		Self([0; BLOOM_BYTES])
+	}
+}
+
+impl ActivityBloom {
+	/// Add an identifier to the bloom
+	pub fn accrue<Id: Encode>(&mut self, kind: ActivityKind, id: &Id) {
+		for (byte, mask) in Self::positions(kind, id) {
+			self.0[byte] |= mask;
+		}
+	}
+
+	/// Check if an identifier may have been added to the bloom.
+	/// False positives are possible, false negatives are not.
+	pub fn contains<Id: Encode>(&self, kind: ActivityKind, id: &Id) -> bool {
+		Self::positions(kind, id)
+			.into_iter()
+			.all(|(byte, mask)| self.0[byte] & mask != 0)
+	}
+
+	fn positions<Id: Encode>(kind: ActivityKind, id: &Id) -> [(usize, u8); BITS_PER_ID] {
+		let hash = blake2_256(&(kind, id).encode());
+
+		let mut positions = [(0, 0); BITS_PER_ID];
+		for (i, position) in positions.iter_mut().enumerate() {
+			let bit =
+				u16::from_be_bytes([hash[2 * i], hash[2 * i + 1]]) as usize % (BLOOM_BYTES * 8);
+			*position = (bit / 8, 1 << (bit % 8));
+		}
+
+		positions
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {}
+
+	/// Bloom of the identifiers touched in a block, along with that block
+	/// number. A bloom of a previous block is considered empty.
+	///
+	/// Lifetime: Until an identifier is recorded in a later block.
+	#[pallet::storage]
+	pub(crate) type BlockBloom<T: Config> =
+		StorageValue<_, (BlockNumberFor<T>, ActivityBloom), OptionQuery>;
+
+	impl<T: Config> Pallet<T> {
+		/// Bloom of the identifiers touched in the current block
+		pub fn block_bloom() -> ActivityBloom {
+			BlockBloom::<T>::get()
+				.filter(|(number, _)| *number == frame_system::Pallet::<T>::block_number())
+				.map(|(_, bloom)| bloom)
+				.unwrap_or_default()
+		}
+	}
+
+	impl<T: Config> ActivityRecorder for Pallet<T> {
+		fn record<Id: Encode>(kind: ActivityKind, id: &Id) {
+			let mut bloom = Self::block_bloom();
+			bloom.accrue(kind, id);
+
+			BlockBloom::<T>::put((frame_system::Pallet::<T>::block_number(), bloom));
+		}
+	}
+}
@@ -0,0 +1,34 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::derive_impl;
+
+use crate::{self as pallet_activity_bloom, Config};
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		ActivityBloom: pallet_activity_bloom,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Runtime>;
+}
+
+impl Config for Runtime {}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	System::externalities()
+}
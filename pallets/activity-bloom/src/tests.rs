@@ -0,0 +1,51 @@
+use cfg_traits::activity::{ActivityKind, ActivityRecorder};
+
+use crate::{mock::*, ActivityBloom as Bloom};
+
+#[test]
+fn empty_bloom() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ActivityBloom::block_bloom(), Bloom::default());
+		assert!(!ActivityBloom::block_bloom().contains(ActivityKind::Pool, &1u64));
+	});
+}
+
+#[test]
+fn recorded_ids_are_contained() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		ActivityBloom::record(ActivityKind::Pool, &1u64);
+		ActivityBloom::record(ActivityKind::Loan, &(1u64, 2u64));
+
+		let bloom = ActivityBloom::block_bloom();
+		assert!(bloom.contains(ActivityKind::Pool, &1u64));
+		assert!(bloom.contains(ActivityKind::Loan, &(1u64, 2u64)));
+	});
+}
+
+#[test]
+fn kinds_are_distinguished() {
+	let mut bloom = Bloom::default();
+	bloom.accrue(ActivityKind::Pool, &1u64);
+
+	assert!(bloom.contains(ActivityKind::Pool, &1u64));
+	assert!(!bloom.contains(ActivityKind::Message, &1u64));
+}
+
+#[test]
+fn bloom_is_reset_every_block() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		ActivityBloom::record(ActivityKind::Pool, &1u64);
+
+		System::set_block_number(2);
+		assert_eq!(ActivityBloom::block_bloom(), Bloom::default());
+
+		ActivityBloom::record(ActivityKind::Pool, &2u64);
+
+		let bloom = ActivityBloom::block_bloom();
+		assert!(bloom.contains(ActivityKind::Pool, &2u64));
+		assert!(!bloom.contains(ActivityKind::Pool, &1u64));
+	});
+}
@@ -0,0 +1,222 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! # Admin Action Log Pallet
+//!
+//! Keeps an auditable log of the privileged calls dispatched successfully on
+//! chain, so auditors can query them instead of scraping every block.
+//!
+//! Each action records the caller, the hash of the call and the block it was
+//! dispatched in. The log is a ring buffer of `MaxActions` entries where the
+//! oldest action is overwritten by a new one, and every action is also
+//! deposited as an event.
+//!
+//! Actions are recorded by the `RecordAdminActions` signed extension for the
+//! calls matched by `PrivilegedCalls`, i.e. council motions closed or executed
+//! by a member, sudo calls or sensitive pool admin extrinsics. Only the outer
+//! call of the extrinsic is matched.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	pallet_prelude::*,
+	traits::Contains,
+	RuntimeDebugNoBound,
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, Hash, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::TransactionValidityError,
+};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// A privileged call dispatched successfully.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct AdminAction<AccountId, Hash, BlockNumber> {
+	/// Position of the action since the log was started.
+	pub index: u64,
+
+	/// The account that dispatched the call.
+	pub caller: AccountId,
+
+	/// Hash of the dispatched call.
+	pub call_hash: Hash,
+
+	/// The block the call was dispatched in.
+	pub block: BlockNumber,
+}
+
+pub type AdminActionOf<T> = AdminAction<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::Hash,
+	BlockNumberFor<T>,
+>;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The calls recorded when dispatched successfully.
+		type PrivilegedCalls: Contains<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// Maximum number of actions kept in the log. Once reached, the
+		/// oldest action is overwritten by each new one.
+		#[pallet::constant]
+		type MaxActions: Get<u32>;
+	}
+
+	/// Number of actions recorded since the log was started, which is the
+	/// index of the next action.
+	///
+	/// Lifetime: Forever.
+	#[pallet::storage]
+	pub(crate) type ActionCount<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// The recorded actions, stored in the slot given by their index modulo
+	/// `MaxActions`.
+	///
+	/// Lifetime: Until overwritten by a newer action.
+	#[pallet::storage]
+	pub(crate) type Actions<T: Config> =
+		StorageMap<_, Twox64Concat, u32, AdminActionOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A privileged call was dispatched successfully.
+		AdminActionRecorded {
+			index: u64,
+			caller: T::AccountId,
+			call_hash: T::Hash,
+		},
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Records a privileged call dispatched successfully by `caller`.
+		pub fn record(caller: T::AccountId, call_hash: T::Hash) {
+			let index = ActionCount::<T>::get();
+
+			Actions::<T>::insert(
+				Self::slot(index),
+				AdminAction {
+					index,
+					caller: caller.clone(),
+					call_hash,
+					block: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+			ActionCount::<T>::put(index.saturating_add(1));
+
+			Self::deposit_event(Event::<T>::AdminActionRecorded {
+				index,
+				caller,
+				call_hash,
+			});
+		}
+
+		/// Actions still in the log with an index equal or greater than
+		/// `from`, oldest first.
+		pub fn actions(from: u64) -> Vec<AdminActionOf<T>> {
+			let count = ActionCount::<T>::get();
+			let oldest = count.saturating_sub(Self::max_actions().into());
+
+			(from.max(oldest)..count)
+				.filter_map(|index| Actions::<T>::get(Self::slot(index)))
+				.collect()
+		}
+
+		fn max_actions() -> u32 {
+			T::MaxActions::get().max(1)
+		}
+
+		fn slot(index: u64) -> u32 {
+			(index % u64::from(Self::max_actions())) as u32
+		}
+	}
+}
+
+/// Signed extension recording the privileged calls of the extrinsics
+/// dispatched successfully.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct RecordAdminActions<T: Config>(PhantomData<T>);
+
+#[allow(clippy::new_without_default)]
+impl<T: Config> RecordAdminActions<T> {
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for RecordAdminActions<T>
+where
+	<T as frame_system::Config>::RuntimeCall:
+		Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	type AccountId = T::AccountId;
+	type AdditionalSigned = ();
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type Pre = Option<(T::AccountId, T::Hash, DispatchClass)>;
+
+	const IDENTIFIER: &'static str = "RecordAdminActions";
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Ok(T::PrivilegedCalls::contains(call)
+			.then(|| (who.clone(), T::Hashing::hash_of(call), info.class)))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		_: &DispatchInfoOf<Self::Call>,
+		_: &PostDispatchInfoOf<Self::Call>,
+		_: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		if let (Some(Some((caller, call_hash, class))), Ok(())) = (pre, result) {
+			Pallet::<T>::record(caller, call_hash);
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::DbWeight::get().reads_writes(1, 2),
+				class,
+			);
+		}
+
+		Ok(())
+	}
+}
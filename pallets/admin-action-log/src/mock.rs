@@ -0,0 +1,52 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::{derive_impl, traits::Contains};
+
+use crate::{self as pallet_admin_action_log, Config};
+
+pub const MAX_ACTIONS: u32 = 3;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		AdminActionLog: pallet_admin_action_log,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Runtime>;
+}
+
+/// Remarks are considered privileged calls in the tests.
+pub struct PrivilegedCalls;
+impl Contains<RuntimeCall> for PrivilegedCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(frame_system::Call::remark { .. }))
+	}
+}
+
+frame_support::parameter_types! {
+	pub const MaxActions: u32 = MAX_ACTIONS;
+}
+
+impl Config for Runtime {
+	type MaxActions = MaxActions;
+	type PrivilegedCalls = PrivilegedCalls;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	System::externalities()
+}
@@ -0,0 +1,120 @@
+use frame_support::dispatch::{DispatchInfo, PostDispatchInfo};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash, SignedExtension},
+	DispatchError,
+};
+
+use crate::{mock::*, AdminAction, Event, RecordAdminActions};
+
+const CALLER: u64 = 1;
+
+fn remark() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![1] })
+}
+
+fn dispatch_signed(call: RuntimeCall, result: Result<(), DispatchError>) {
+	let info = DispatchInfo::default();
+	let pre = RecordAdminActions::<Runtime>::new()
+		.pre_dispatch(&CALLER, &call, &info, 0)
+		.unwrap();
+
+	RecordAdminActions::<Runtime>::post_dispatch(
+		Some(pre),
+		&info,
+		&PostDispatchInfo::default(),
+		0,
+		&result,
+	)
+	.unwrap();
+}
+
+#[test]
+fn records_actions() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AdminActionLog::record(CALLER, [1; 32].into());
+
+		System::set_block_number(2);
+		AdminActionLog::record(CALLER + 1, [2; 32].into());
+
+		assert_eq!(
+			AdminActionLog::actions(0),
+			vec![
+				AdminAction {
+					index: 0,
+					caller: CALLER,
+					call_hash: [1; 32].into(),
+					block: 1,
+				},
+				AdminAction {
+					index: 1,
+					caller: CALLER + 1,
+					call_hash: [2; 32].into(),
+					block: 2,
+				},
+			]
+		);
+		assert_eq!(AdminActionLog::actions(1).len(), 1);
+
+		System::assert_last_event(
+			Event::<Runtime>::AdminActionRecorded {
+				index: 1,
+				caller: CALLER + 1,
+				call_hash: [2; 32].into(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn oldest_actions_are_overwritten() {
+	new_test_ext().execute_with(|| {
+		for i in 0..5u8 {
+			AdminActionLog::record(CALLER, [i; 32].into());
+		}
+
+		let indexes = |from| {
+			AdminActionLog::actions(from)
+				.into_iter()
+				.map(|action| action.index)
+				.collect::<Vec<_>>()
+		};
+
+		assert_eq!(indexes(0), vec![2, 3, 4]);
+		assert_eq!(indexes(4), vec![4]);
+		assert_eq!(indexes(5), Vec::<u64>::new());
+	});
+}
+
+#[test]
+fn extension_records_successful_privileged_calls() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+
+		dispatch_signed(remark(), Ok(()));
+
+		assert_eq!(
+			AdminActionLog::actions(0),
+			vec![AdminAction {
+				index: 0,
+				caller: CALLER,
+				call_hash: BlakeTwo256::hash_of(&remark()),
+				block: 1,
+			}]
+		);
+	});
+}
+
+#[test]
+fn extension_skips_failed_and_unprivileged_calls() {
+	new_test_ext().execute_with(|| {
+		dispatch_signed(remark(), Err(DispatchError::Other("err")));
+		dispatch_signed(
+			RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1] }),
+			Ok(()),
+		);
+
+		assert!(AdminActionLog::actions(0).is_empty());
+	});
+}
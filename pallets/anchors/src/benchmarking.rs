@@ -102,6 +102,20 @@ benchmarks! {
 		assert!(<AnchorEvictDates<T>>::get(anchor_id).is_some());
 	}
 
+	commit_batch {
+		#[cfg(test)]
+		config_mocks();
+
+		let caller = whitelisted_caller();
+
+		let pre_image = T::Hashing::hash_of(&0);
+		let anchor_id = pre_image.using_encoded(T::Hashing::hash);
+
+	}: _(RawOrigin::Signed(caller), pre_image, DOC_ROOT.into(), day(1))
+	verify {
+		assert!(<AnchorEvictDates<T>>::get(anchor_id).is_some());
+	}
+
 	evict_pre_commits {
 		#[cfg(test)]
 		config_mocks();
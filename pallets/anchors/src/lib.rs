@@ -14,6 +14,12 @@
 //! # Anchors Pallet
 //!
 //! This pallet provides functionality of Storing anchors on Chain.
+//!
+//! Documents can also be anchored in batches: a batch anchor is a regular
+//! anchor whose `doc_root` is the merkle root of the document hashes in the
+//! batch, so a single state rent is paid for all of them. The membership of a
+//! document in a batch is verified with a merkle proof whose pairs of nodes
+//! are hashed in sorted order.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 // This pallet is getting a big refactor soon, so no sense doing clippy cleanups
@@ -73,6 +79,13 @@ const STORAGE_MAX_DAYS: u32 = 376200;
 /// Child trie prefix
 const ANCHOR_PREFIX: &[u8; 6] = b"anchor";
 
+/// Prefix of the hashed leaves of a batch merkle tree
+pub const BATCH_LEAF_PREFIX: u8 = 0x00;
+
+/// Prefix of the hashed inner nodes of a batch merkle tree, so they can not be
+/// passed off as leaves
+pub const BATCH_NODE_PREFIX: u8 = 0x01;
+
 /// Determines the max size of the input list used in the precommit eviction.
 const EVICT_PRE_COMMIT_LIST_SIZE: u32 = 100;
 
@@ -98,8 +111,15 @@ pub struct AnchorData<Hash, BlockNumber> {
 #[frame_support::pallet]
 pub mod pallet {
 	// Import various types used to declare pallet in scope.
-	use cfg_traits::fees::{Fee, Fees};
-	use frame_support::{pallet_prelude::*, storage::child, traits::ReservableCurrency};
+	use cfg_traits::{
+		fees::{Fee, Fees},
+		Millis, TimeAsSecs,
+	};
+	use frame_support::{
+		pallet_prelude::*,
+		storage::child,
+		traits::{ReservableCurrency, UnixTime},
+	};
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::{
 		traits::{CheckedAdd, CheckedMul, Hash},
@@ -134,6 +154,9 @@ pub mod pallet {
 
 		/// Currency as viewed from this pallet
 		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Time provider used to compute the eviction dates of the anchors
+		type Time: TimeAsSecs;
 	}
 
 	/// PreCommits store the map of anchor Id to the pre-commit, which is a lock
@@ -210,6 +233,9 @@ pub mod pallet {
 
 		/// Failed to convert epoch in MS to days
 		FailedToConvertEpochToDays,
+
+		/// A batch can not be committed over a valid pre-commit
+		PreCommitExistsForBatch,
 	}
 
 	#[pallet::call]
@@ -294,24 +320,8 @@ pub mod pallet {
 			stored_until_date: T::Moment,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			// validate the eviction date
-			let eviction_date_u64 = TryInto::<u64>::try_into(stored_until_date)
-				.or(Err(Error::<T>::EvictionDateTooBig))?;
-			let nowt = <pallet_timestamp::Pallet<T>>::get();
-			let now: u64 =
-				TryInto::<u64>::try_into(nowt).or(Err(Error::<T>::EvictionDateTooBig))?;
-
-			ensure!(
-				now + common::MILLISECS_PER_DAY < eviction_date_u64,
-				Error::<T>::AnchorStoreDateInPast
-			);
-
-			let stored_until_date_from_epoch = common::get_days_since_epoch(eviction_date_u64)
-				.ok_or(Error::<T>::EvictionDateTooBig)?;
-			ensure!(
-				stored_until_date_from_epoch <= STORAGE_MAX_DAYS,
-				Error::<T>::AnchorStoreDateAboveMaxLimit
-			);
+			let (now, stored_until_date_from_epoch) =
+				Self::validate_eviction_date(stored_until_date)?;
 
 			let anchor_id =
 				(anchor_id_preimage).using_encoded(<T as frame_system::Config>::Hashing::hash);
@@ -331,39 +341,12 @@ pub mod pallet {
 				);
 			}
 
-			// pay the state rent
-			let now_u64 = TryInto::<u64>::try_into(<pallet_timestamp::Pallet<T>>::get())
-				.or(Err(ArithmeticError::Overflow))?;
-			let today_in_days_from_epoch = common::get_days_since_epoch(now_u64)
-				.ok_or(Error::<T>::FailedToConvertEpochToDays)?;
-
-			let multiplier = stored_until_date_from_epoch
-				.checked_sub(today_in_days_from_epoch)
-				.ok_or(ArithmeticError::Underflow)?;
-
-			// TODO(dev): move the fee to treasury account once its integrated instead of
-			// burning fee we use the fee config setup on genesis for anchoring to calculate
-			// the state rent
-			let fee = T::Fees::fee_value(T::CommitAnchorFeeKey::get())
-				.checked_mul(&multiplier.into())
-				.ok_or(ArithmeticError::Overflow)?;
-
-			// pay state rent to block author
-			T::Fees::fee_to_author(&who, Fee::Balance(fee))?;
-
-			let anchored_block = <frame_system::Pallet<T>>::block_number();
-			let anchor_data = AnchorData {
-				id: anchor_id,
-				doc_root,
-				anchored_block,
-			};
-
-			let prefixed_key = Self::anchor_storage_key(&stored_until_date_from_epoch.encode());
-			Self::store_anchor(
+			Self::pay_rent_and_store_anchor(
+				&who,
 				anchor_id,
-				&prefixed_key,
+				doc_root,
+				now,
 				stored_until_date_from_epoch,
-				&anchor_data.encode(),
 			)?;
 
 			Self::evict_pre_commit(anchor_id, false);
@@ -371,6 +354,48 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Commits the `batch_root` of a merkle tree whose leaves are the
+		/// hashes of many documents, prefixed with [`BATCH_LEAF_PREFIX`], and
+		/// whose inner nodes are prefixed with [`BATCH_NODE_PREFIX`], as a
+		/// single anchor with the id obtained by
+		/// hashing `anchor_id_preimage`. The state rent until
+		/// `stored_until_date` is charged once for the whole batch, like for a
+		/// [`Pallet::commit()`] call. Batches do not support pre-commits, so
+		/// the call fails if a valid pre-commit exists for the anchor id. The
+		/// membership of a document in the batch can be verified later on with
+		/// [`Pallet::verify_batch_membership()`].
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::commit_batch())]
+		#[pallet::call_index(4)]
+		pub fn commit_batch(
+			origin: OriginFor<T>,
+			anchor_id_preimage: T::Hash,
+			batch_root: T::Hash,
+			stored_until_date: T::Moment,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let (now, stored_until_date_from_epoch) =
+				Self::validate_eviction_date(stored_until_date)?;
+
+			let anchor_id =
+				(anchor_id_preimage).using_encoded(<T as frame_system::Config>::Hashing::hash);
+			ensure!(
+				Self::get_anchor_by_id(anchor_id).is_none(),
+				Error::<T>::AnchorAlreadyExists
+			);
+			ensure!(
+				Self::get_valid_pre_commit(anchor_id).is_none(),
+				Error::<T>::PreCommitExistsForBatch
+			);
+
+			Self::pay_rent_and_store_anchor(
+				&who,
+				anchor_id,
+				batch_root,
+				now,
+				stored_until_date_from_epoch,
+			)
+		}
+
 		/// Initiates eviction of pre-commits that has expired given a list on
 		/// anchor ids. For each evicted pre-commits, the deposit holded by
 		/// [`Pallet::pre_commit()`] call will be returned to the same account
@@ -402,9 +427,7 @@ pub mod pallet {
 
 			// get the today counting epoch, so that we can remove the corresponding child
 			// trie
-			let now_u64 = TryInto::<u64>::try_into(<pallet_timestamp::Pallet<T>>::get())
-				.or(Err(ArithmeticError::Overflow))?;
-			let today_in_days_from_epoch = common::get_days_since_epoch(now_u64)
+			let today_in_days_from_epoch = common::get_days_since_epoch(Self::now_millis()?)
 				.ok_or(Error::<T>::FailedToConvertEpochToDays)?;
 
 			let evict_date = <LatestEvictedDate<T>>::get()
@@ -433,6 +456,79 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Current time in milliseconds, the unit of the anchor dates.
+		fn now_millis() -> Result<Millis, DispatchError> {
+			u64::try_from(<T::Time as UnixTime>::now().as_millis())
+				.map_err(|_| ArithmeticError::Overflow.into())
+		}
+
+		/// Checks that `stored_until_date` is at least a day in the future and
+		/// not above [`STORAGE_MAX_DAYS`], returning the current time in
+		/// milliseconds and the eviction date in days since epoch.
+		fn validate_eviction_date(
+			stored_until_date: T::Moment,
+		) -> Result<(Millis, u32), DispatchError> {
+			let eviction_date_u64 = TryInto::<u64>::try_into(stored_until_date)
+				.or(Err(Error::<T>::EvictionDateTooBig))?;
+			let now = Self::now_millis()?;
+
+			ensure!(
+				now + common::MILLISECS_PER_DAY < eviction_date_u64,
+				Error::<T>::AnchorStoreDateInPast
+			);
+
+			let stored_until_date_from_epoch = common::get_days_since_epoch(eviction_date_u64)
+				.ok_or(Error::<T>::EvictionDateTooBig)?;
+			ensure!(
+				stored_until_date_from_epoch <= STORAGE_MAX_DAYS,
+				Error::<T>::AnchorStoreDateAboveMaxLimit
+			);
+
+			Ok((now, stored_until_date_from_epoch))
+		}
+
+		/// Charges `who` the state rent from today until
+		/// `stored_until_date_from_epoch` and stores the anchor.
+		fn pay_rent_and_store_anchor(
+			who: &T::AccountId,
+			anchor_id: T::Hash,
+			doc_root: T::Hash,
+			now: Millis,
+			stored_until_date_from_epoch: u32,
+		) -> DispatchResult {
+			let today_in_days_from_epoch =
+				common::get_days_since_epoch(now).ok_or(Error::<T>::FailedToConvertEpochToDays)?;
+
+			let multiplier = stored_until_date_from_epoch
+				.checked_sub(today_in_days_from_epoch)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			// TODO(dev): move the fee to treasury account once its integrated instead of
+			// burning fee we use the fee config setup on genesis for anchoring to calculate
+			// the state rent
+			let fee = T::Fees::fee_value(T::CommitAnchorFeeKey::get())
+				.checked_mul(&multiplier.into())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			// pay state rent to block author
+			T::Fees::fee_to_author(who, Fee::Balance(fee))?;
+
+			let anchored_block = <frame_system::Pallet<T>>::block_number();
+			let anchor_data = AnchorData {
+				id: anchor_id,
+				doc_root,
+				anchored_block,
+			};
+
+			let prefixed_key = Self::anchor_storage_key(&stored_until_date_from_epoch.encode());
+			Self::store_anchor(
+				anchor_id,
+				&prefixed_key,
+				stored_until_date_from_epoch,
+				&anchor_data.encode(),
+			)
+		}
+
 		/// Checks if the given `anchor_id` has a valid pre-commit, i.e it has a
 		/// pre-commit with `expiration_block` < `current_block_number`.
 		fn get_valid_pre_commit(
@@ -554,6 +650,40 @@ pub mod pallet {
 				.and_then(|data| AnchorData::decode(&mut &*data).ok())
 		}
 
+		/// Checks if `document_hash` is a leaf of the merkle tree anchored by
+		/// `anchor_id`, given the sibling hashes from the leaf up to the root.
+		/// The leaf is hashed with [`BATCH_LEAF_PREFIX`] and each pair of
+		/// nodes is hashed in sorted order with [`BATCH_NODE_PREFIX`], so
+		/// inner nodes of the tree are not accepted as documents. A batch of a
+		/// single document is verified with an empty proof.
+		pub fn verify_batch_membership(
+			anchor_id: T::Hash,
+			document_hash: T::Hash,
+			proof: Vec<T::Hash>,
+		) -> bool {
+			let Some(anchor) = Self::get_anchor_by_id(anchor_id) else {
+				return false;
+			};
+
+			let leaf = <T as frame_system::Config>::Hashing::hash(
+				&[&[BATCH_LEAF_PREFIX], document_hash.as_ref()].concat(),
+			);
+
+			let calculated_root = proof.into_iter().fold(leaf, |node, sibling| {
+				let (left, right) = if node <= sibling {
+					(node, sibling)
+				} else {
+					(sibling, node)
+				};
+
+				<T as frame_system::Config>::Hashing::hash(
+					&[&[BATCH_NODE_PREFIX], left.as_ref(), right.as_ref()].concat(),
+				)
+			});
+
+			anchor.doc_root == calculated_root
+		}
+
 		pub fn anchor_storage_key(storage_key: &[u8]) -> Vec<u8> {
 			let mut prefixed_key = Vec::with_capacity(ANCHOR_PREFIX.len() + storage_key.len());
 			prefixed_key.extend_from_slice(ANCHOR_PREFIX);
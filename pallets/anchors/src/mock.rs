@@ -77,6 +77,7 @@ impl Config for Runtime {
 	type Currency = Balances;
 	type Fees = MockFees;
 	type PreCommitDepositFeeKey = ConstU8<PRE_COMMIT_FEE_KEY>;
+	type Time = Timestamp;
 	type WeightInfo = ();
 }
 
@@ -13,6 +13,7 @@
 
 use std::time::Instant;
 
+use cfg_traits::fees::Fee;
 use frame_support::{assert_noop, assert_ok};
 use frame_system::ensure_signed;
 use parity_scale_codec::Encode;
@@ -343,6 +344,216 @@ fn commit_fail_anchor_exists() {
 	});
 }
 
+fn hash_leaf(doc: H256) -> H256 {
+	<Runtime as frame_system::Config>::Hashing::hash(&[&[BATCH_LEAF_PREFIX], doc.as_ref()].concat())
+}
+
+fn hash_sorted_pair(a: H256, b: H256) -> H256 {
+	let (left, right) = if a <= b { (a, b) } else { (b, a) };
+	<Runtime as frame_system::Config>::Hashing::hash(
+		&[&[BATCH_NODE_PREFIX], left.as_ref(), right.as_ref()].concat(),
+	)
+}
+
+#[test]
+fn basic_commit_batch() {
+	new_test_ext().execute_with(|| {
+		let pre_image = <Runtime as frame_system::Config>::Hashing::hash_of(&0);
+		let anchor_id = (pre_image).using_encoded(<Runtime as frame_system::Config>::Hashing::hash);
+
+		let docs: Vec<H256> = (0..4)
+			.map(|i| <Runtime as frame_system::Config>::Hashing::hash_of(&i))
+			.collect();
+		let leaves: Vec<H256> = docs.iter().copied().map(hash_leaf).collect();
+		let node_01 = hash_sorted_pair(leaves[0], leaves[1]);
+		let node_23 = hash_sorted_pair(leaves[2], leaves[3]);
+		let batch_root = hash_sorted_pair(node_01, node_23);
+
+		// reject unsigned
+		assert_noop!(
+			Anchors::commit_batch(
+				RuntimeOrigin::none(),
+				pre_image,
+				batch_root,
+				common::MILLISECS_PER_DAY + 1
+			),
+			BadOrigin
+		);
+
+		// the state rent of two days is paid once for the whole batch
+		MockFees::mock_fee_to_author(|who, fee| {
+			assert_eq!(*who, 1);
+			assert!(matches!(fee, Fee::Balance(amount) if amount == COMMIT_FEE_VALUE * 2));
+			Ok(())
+		});
+
+		assert_ok!(Anchors::commit_batch(
+			RuntimeOrigin::signed(1),
+			pre_image,
+			batch_root,
+			common::MILLISECS_PER_DAY + 1
+		));
+
+		let a = Anchors::get_anchor_by_id(anchor_id).unwrap();
+		assert_eq!(a.id, anchor_id);
+		assert_eq!(a.doc_root, batch_root);
+		assert_eq!(Anchors::get_anchor_evict_date(anchor_id).unwrap(), 2);
+		assert_eq!(Anchors::get_anchor_id_by_index(1).unwrap(), anchor_id);
+
+		assert!(Anchors::verify_batch_membership(
+			anchor_id,
+			docs[0],
+			vec![leaves[1], node_23]
+		));
+		assert!(Anchors::verify_batch_membership(
+			anchor_id,
+			docs[3],
+			vec![leaves[2], node_01]
+		));
+
+		// wrong proof
+		assert!(!Anchors::verify_batch_membership(
+			anchor_id,
+			docs[0],
+			vec![leaves[2], node_23]
+		));
+
+		// document outside the batch
+		assert!(!Anchors::verify_batch_membership(
+			anchor_id,
+			<Runtime as frame_system::Config>::Hashing::hash_of(&4),
+			vec![leaves[1], node_23]
+		));
+
+		// unknown anchor
+		assert!(!Anchors::verify_batch_membership(
+			<Runtime as frame_system::Config>::Hashing::hash_of(&1),
+			docs[0],
+			vec![leaves[1], node_23]
+		));
+
+		// inner nodes are not documents of the batch
+		assert!(!Anchors::verify_batch_membership(
+			anchor_id,
+			node_01,
+			vec![node_23]
+		));
+		assert!(!Anchors::verify_batch_membership(
+			anchor_id,
+			batch_root,
+			vec![]
+		));
+	});
+}
+
+#[test]
+fn commit_batch_of_single_document() {
+	new_test_ext().execute_with(|| {
+		let pre_image = <Runtime as frame_system::Config>::Hashing::hash_of(&0);
+		let anchor_id = (pre_image).using_encoded(<Runtime as frame_system::Config>::Hashing::hash);
+		let doc = <Runtime as frame_system::Config>::Hashing::hash_of(&1);
+
+		MockFees::mock_fee_to_author(|_, _| Ok(()));
+
+		assert_ok!(Anchors::commit_batch(
+			RuntimeOrigin::signed(1),
+			pre_image,
+			hash_leaf(doc),
+			common::MILLISECS_PER_DAY + 1
+		));
+
+		assert!(Anchors::verify_batch_membership(anchor_id, doc, vec![]));
+		assert!(!Anchors::verify_batch_membership(
+			anchor_id,
+			hash_leaf(doc),
+			vec![]
+		));
+	});
+}
+
+#[test]
+fn commit_batch_fail_anchor_exists() {
+	new_test_ext().execute_with(|| {
+		let pre_image = <Runtime as frame_system::Config>::Hashing::hash_of(&0);
+		let batch_root = <Runtime as frame_system::Config>::Hashing::hash_of(&0);
+
+		assert_ok!(Anchors::commit(
+			RuntimeOrigin::signed(1),
+			pre_image,
+			batch_root,
+			<Runtime as frame_system::Config>::Hashing::hash_of(&0),
+			common::MILLISECS_PER_DAY + 1
+		));
+
+		assert_noop!(
+			Anchors::commit_batch(
+				RuntimeOrigin::signed(2),
+				pre_image,
+				batch_root,
+				common::MILLISECS_PER_DAY + 1
+			),
+			Error::<Runtime>::AnchorAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn commit_batch_fail_pre_commit_exists() {
+	new_test_ext().execute_with(|| {
+		let pre_image = <Runtime as frame_system::Config>::Hashing::hash_of(&0);
+		let anchor_id = (pre_image).using_encoded(<Runtime as frame_system::Config>::Hashing::hash);
+		let batch_root = <Runtime as frame_system::Config>::Hashing::hash_of(&0);
+
+		assert_ok!(Anchors::pre_commit(
+			RuntimeOrigin::signed(1),
+			anchor_id,
+			<Runtime as frame_system::Config>::Hashing::hash_of(&0)
+		));
+
+		assert_noop!(
+			Anchors::commit_batch(
+				RuntimeOrigin::signed(1),
+				pre_image,
+				batch_root,
+				common::MILLISECS_PER_DAY + 1
+			),
+			Error::<Runtime>::PreCommitExistsForBatch
+		);
+
+		// the batch can be committed once the pre-commit expired
+		System::set_block_number(PRE_COMMIT_EXPIRATION_DURATION_BLOCKS as u64);
+		assert_ok!(Anchors::commit_batch(
+			RuntimeOrigin::signed(1),
+			pre_image,
+			batch_root,
+			common::MILLISECS_PER_DAY + 1
+		));
+	});
+}
+
+#[test]
+fn commit_batch_fail_store_date() {
+	new_test_ext().execute_with(|| {
+		let pre_image = <Runtime as frame_system::Config>::Hashing::hash_of(&0);
+		let batch_root = <Runtime as frame_system::Config>::Hashing::hash_of(&0);
+
+		assert_noop!(
+			Anchors::commit_batch(RuntimeOrigin::signed(1), pre_image, batch_root, 2),
+			Error::<Runtime>::AnchorStoreDateInPast
+		);
+
+		assert_noop!(
+			Anchors::commit_batch(
+				RuntimeOrigin::signed(1),
+				pre_image,
+				batch_root,
+				371085174374358017 // triggers overflow
+			),
+			Error::<Runtime>::EvictionDateTooBig
+		);
+	});
+}
+
 #[test]
 fn basic_pre_commit_commit() {
 	new_test_ext().execute_with(|| {
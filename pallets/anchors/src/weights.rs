@@ -16,6 +16,7 @@ use frame_support::weights::Weight;
 pub trait WeightInfo {
 	fn pre_commit() -> Weight;
 	fn commit() -> Weight;
+	fn commit_batch() -> Weight;
 	fn evict_pre_commits() -> Weight;
 	fn evict_anchors() -> Weight;
 }
@@ -29,6 +30,10 @@ impl WeightInfo for () {
 		Weight::zero()
 	}
 
+	fn commit_batch() -> Weight {
+		Weight::zero()
+	}
+
 	fn evict_pre_commits() -> Weight {
 		Weight::zero()
 	}
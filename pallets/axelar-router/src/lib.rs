@@ -16,14 +16,15 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use cfg_traits::{
-	ethereum::EthereumTransactor,
-	liquidity_pools::{MessageReceiver, MessageSender},
+	ethereum::{EthereumTransactor, GasPrice},
+	liquidity_pools::{MessageFeeEstimator, MessageReceiver, MessageSender},
 	PreConditions,
 };
 use cfg_types::{domain_address::DomainAddress, EVMChainId};
 use ethabi::{Contract, Function, Param, ParamType, Token};
 use fp_evm::PrecompileHandle;
 use frame_support::{
+	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
 	pallet_prelude::*,
 	weights::{constants::RocksDbWeight, Weight},
 	BoundedVec,
@@ -45,7 +46,7 @@ mod tests;
 /// string, as found below:
 /// <https://docs.axelar.dev/dev/reference/mainnet-chain-names>
 /// <https://docs.axelar.dev/dev/reference/testnet-chain-names>
-const MAX_AXELAR_EVM_CHAIN_SIZE: u32 = 16;
+const MAX_AXELAR_EVM_CHAIN_SIZE: u32 = 32;
 
 const MAX_SOURCE_CHAIN_BYTES: u32 = 128;
 // Ensure we allow enough to support a hex encoded address with the `0x` prefix.
@@ -72,6 +73,15 @@ pub struct AxelarConfig {
 	pub domain: DomainConfig,
 }
 
+impl AxelarConfig {
+	/// Axelar id of the chain this configuration targets
+	pub fn id(&self) -> AxelarId {
+		match &self.domain {
+			DomainConfig::Evm(evm_config) => AxelarId::Evm(evm_config.chain_id),
+		}
+	}
+}
+
 /// Specific domain configuration
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
 pub enum DomainConfig {
@@ -89,8 +99,8 @@ pub struct EvmConfig {
 
 	/// The `BlakeTwo256` hash of the target contract code.
 	///
-	/// This is used during router initialization to ensure that the correct
-	/// contract code is used.
+	/// This is used during router initialization and target rotations to
+	/// ensure that the correct contract code is used.
 	pub target_contract_hash: H256,
 
 	/// The values used when executing the EVM call.
@@ -104,12 +114,37 @@ pub struct FeeValues {
 	pub value: U256,
 
 	/// The gas price used when executing the EVM call.
+	///
+	/// NOTE: Unused if EIP-1559 fees are set for the chain.
 	pub gas_price: U256,
 
 	/// The gas limit used when executing the EVM call.
 	pub gas_limit: U256,
 }
 
+/// EIP-1559 fees used when executing the EVM calls to a chain, instead of
+/// the legacy gas price of its `FeeValues`.
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct Eip1559FeeValues {
+	/// The fee paid per gas on top of the base fee.
+	pub max_priority_fee_per_gas: U256,
+
+	/// The maximum fee paid per gas. If not set, it's computed from the
+	/// current base fee as `2 * base_fee + max_priority_fee_per_gas`, so the
+	/// call stays valid if the base fee raises in the next blocks.
+	pub max_fee_per_gas: Option<U256>,
+}
+
+/// Gas prepayment to the Axelar gas service for outbound messages
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct GasPayment {
+	/// The address of the `AxelarGasService` contract.
+	pub gas_service_address: H160,
+
+	/// The amount of native currency paid for relaying each message.
+	pub amount: U256,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -140,6 +175,14 @@ pub mod pallet {
 
 		/// Checker to ensure an evm account code is registered
 		type EvmAccountCodeChecker: PreConditions<(H160, H256), Result = bool>;
+
+		/// The current base fee of our EVM, used to compute the maximum fee
+		/// per gas of the chains using EIP-1559 fees without a fixed one.
+		type BaseFee: Get<U256>;
+
+		/// Maximum size of a message payload sent through Axelar.
+		#[pallet::constant]
+		type MaxPayloadSize: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -148,6 +191,26 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type ChainNameById<T: Config> = StorageMap<_, Twox64Concat, AxelarId, ChainName>;
 
+	/// Gas prepayment done along with each outbound message to a chain.
+	#[pallet::storage]
+	pub type GasPayments<T: Config> = StorageMap<_, Twox64Concat, ChainName, GasPayment>;
+
+	/// EIP-1559 fees used when executing the EVM calls to a chain.
+	#[pallet::storage]
+	pub type Eip1559Fees<T: Config> = StorageMap<_, Twox64Concat, ChainName, Eip1559FeeValues>;
+
+	/// Previous target contract hash still accepted for a chain while its
+	/// target contract is being migrated.
+	#[pallet::storage]
+	pub type TargetMigrations<T: Config> = StorageMap<_, Twox64Concat, ChainName, H256>;
+
+	/// Cumulative amount of native currency reserved for sending outbound
+	/// messages through each Axelar destination. This is an upper bound of
+	/// the fees spent, as each EVM call is accounted at its gas limit and
+	/// maximum fee per gas, while the unused gas is refunded.
+	#[pallet::storage]
+	pub type FeesReserved<T: Config> = StorageMap<_, Twox64Concat, AxelarId, U256, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -155,6 +218,42 @@ pub mod pallet {
 			name: ChainName,
 			config: Box<AxelarConfig>,
 		},
+
+		/// The configuration of a chain was removed.
+		ConfigRemoved { name: ChainName },
+
+		/// The gas prepayment of a chain was set or removed.
+		GasPaymentSet {
+			name: ChainName,
+			gas_payment: Option<GasPayment>,
+		},
+
+		/// The EIP-1559 fees of a chain were set or removed.
+		Eip1559FeesSet {
+			name: ChainName,
+			fees: Option<Eip1559FeeValues>,
+		},
+
+		/// The target contract of a chain was rotated. If
+		/// `previous_hash` is set, the chain is in grace mode accepting
+		/// either hash until the migration is finished.
+		TargetContractRotated {
+			name: ChainName,
+			address: H160,
+			hash: H256,
+			previous_hash: Option<H256>,
+		},
+
+		/// The target contract migration of a chain was finished.
+		TargetMigrationFinished { name: ChainName },
+
+		/// Fees were reserved for sending an outbound message, as an upper
+		/// bound of the fees spent.
+		FeesReserved {
+			id: AxelarId,
+			amount: U256,
+			total: U256,
+		},
 	}
 
 	#[pallet::error]
@@ -173,6 +272,12 @@ pub mod pallet {
 
 		/// Emit when a message is received from a non LP caller
 		ContractCallerMismatch,
+
+		/// Emit when there is no target contract migration for the chain.
+		TargetMigrationNotFound,
+
+		/// Emit when the message payload exceeds the maximum payload size.
+		PayloadTooLarge,
 	}
 
 	#[pallet::call]
@@ -186,6 +291,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AdminOrigin::ensure_origin(origin)?;
 
+			// The chain could have been configured with a different id before
+			if let Some(previous) = Configuration::<T>::get(&chain_name) {
+				ChainNameById::<T>::remove(previous.id());
+			}
+
 			match &config.domain {
 				DomainConfig::Evm(evm_config) => {
 					ensure!(
@@ -204,6 +314,7 @@ pub mod pallet {
 			}
 
 			Configuration::<T>::insert(chain_name.clone(), config.clone());
+			TargetMigrations::<T>::remove(&chain_name);
 
 			Self::deposit_event(Event::<T>::ConfigSet {
 				name: chain_name,
@@ -212,6 +323,161 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().reads_writes(1, 2)))]
+		#[pallet::call_index(1)]
+		pub fn remove_config(origin: OriginFor<T>, chain_name: ChainName) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let config = Configuration::<T>::take(&chain_name)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			ChainNameById::<T>::remove(config.id());
+			GasPayments::<T>::remove(&chain_name);
+			Eip1559Fees::<T>::remove(&chain_name);
+			TargetMigrations::<T>::remove(&chain_name);
+
+			Self::deposit_event(Event::<T>::ConfigRemoved { name: chain_name });
+
+			Ok(())
+		}
+
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().reads_writes(1, 1)))]
+		#[pallet::call_index(2)]
+		pub fn set_gas_payment(
+			origin: OriginFor<T>,
+			chain_name: ChainName,
+			gas_payment: Option<GasPayment>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Configuration::<T>::contains_key(&chain_name),
+				Error::<T>::RouterConfigurationNotFound
+			);
+
+			GasPayments::<T>::set(&chain_name, gas_payment.clone());
+
+			Self::deposit_event(Event::<T>::GasPaymentSet {
+				name: chain_name,
+				gas_payment,
+			});
+
+			Ok(())
+		}
+
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().reads_writes(1, 1)))]
+		#[pallet::call_index(3)]
+		pub fn set_eip1559_fees(
+			origin: OriginFor<T>,
+			chain_name: ChainName,
+			fees: Option<Eip1559FeeValues>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Configuration::<T>::contains_key(&chain_name),
+				Error::<T>::RouterConfigurationNotFound
+			);
+
+			Eip1559Fees::<T>::set(&chain_name, fees.clone());
+
+			Self::deposit_event(Event::<T>::Eip1559FeesSet {
+				name: chain_name,
+				fees,
+			});
+
+			Ok(())
+		}
+
+		/// Rotates the target contract of a chain in place, keeping the rest
+		/// of its configuration.
+		///
+		/// With `grace`, the current target hash is still accepted until
+		/// `finish_target_migration()` is called, allowing to rotate before
+		/// the contract code is upgraded. Otherwise the new contract code must
+		/// already match `hash`.
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().reads_writes(1, 2)))]
+		#[pallet::call_index(4)]
+		pub fn rotate_target_contract(
+			origin: OriginFor<T>,
+			chain_name: ChainName,
+			address: H160,
+			hash: H256,
+			grace: bool,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let mut config = Configuration::<T>::get(&chain_name)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			let previous_hash = match &mut config.domain {
+				DomainConfig::Evm(evm_config) => {
+					let previous_hash = grace.then_some(evm_config.target_contract_hash);
+
+					ensure!(
+						T::EvmAccountCodeChecker::check((address, hash))
+							|| previous_hash.is_some_and(|previous_hash| {
+								T::EvmAccountCodeChecker::check((address, previous_hash))
+							}),
+						Error::<T>::ContractCodeMismatch
+					);
+
+					evm_config.target_contract_address = address;
+					evm_config.target_contract_hash = hash;
+
+					previous_hash
+				}
+			};
+
+			Configuration::<T>::insert(&chain_name, config);
+			TargetMigrations::<T>::set(&chain_name, previous_hash);
+
+			Self::deposit_event(Event::<T>::TargetContractRotated {
+				name: chain_name,
+				address,
+				hash,
+				previous_hash,
+			});
+
+			Ok(())
+		}
+
+		/// Finishes the target contract migration of a chain, no longer
+		/// accepting the previous target hash. The target contract code must
+		/// match the current target hash.
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().reads_writes(2, 1)))]
+		#[pallet::call_index(5)]
+		pub fn finish_target_migration(
+			origin: OriginFor<T>,
+			chain_name: ChainName,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				TargetMigrations::<T>::contains_key(&chain_name),
+				Error::<T>::TargetMigrationNotFound
+			);
+
+			let config = Configuration::<T>::get(&chain_name)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			match config.domain {
+				DomainConfig::Evm(evm_config) => ensure!(
+					T::EvmAccountCodeChecker::check((
+						evm_config.target_contract_address,
+						evm_config.target_contract_hash,
+					)),
+					Error::<T>::ContractCodeMismatch
+				),
+			}
+
+			TargetMigrations::<T>::remove(&chain_name);
+
+			Self::deposit_event(Event::<T>::TargetMigrationFinished { name: chain_name });
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -247,6 +513,47 @@ pub mod pallet {
 				}
 			}
 		}
+
+		/// Gas price used for the EVM calls to a chain, using its EIP-1559
+		/// fees if set.
+		fn gas_price(chain_name: &ChainName, fee_values: &FeeValues) -> GasPrice {
+			match Eip1559Fees::<T>::get(chain_name) {
+				Some(fees) => GasPrice::Eip1559 {
+					max_fee_per_gas: fees.max_fee_per_gas.unwrap_or_else(|| {
+						T::BaseFee::get()
+							.saturating_mul(2.into())
+							.saturating_add(fees.max_priority_fee_per_gas)
+					}),
+					max_priority_fee_per_gas: fees.max_priority_fee_per_gas,
+				},
+				None => GasPrice::Legacy(fee_values.gas_price),
+			}
+		}
+
+		/// Maximum native fees spent to send a message through an EVM chain,
+		/// including the gas payment call if configured.
+		fn evm_message_cost(chain_name: &ChainName, evm_config: &EvmConfig) -> U256 {
+			let call_cost = Self::gas_price(chain_name, &evm_config.fee_values)
+				.max_fee_per_gas()
+				.saturating_mul(evm_config.fee_values.gas_limit);
+			let cost = evm_config.fee_values.value.saturating_add(call_cost);
+
+			match GasPayments::<T>::get(chain_name) {
+				Some(gas_payment) => {
+					cost.saturating_add(gas_payment.amount.saturating_add(call_cost))
+				}
+				None => cost,
+			}
+		}
+
+		fn note_fees_reserved(id: AxelarId, amount: U256) {
+			let total = FeesReserved::<T>::mutate(&id, |total| {
+				*total = total.saturating_add(amount);
+				*total
+			});
+
+			Self::deposit_event(Event::<T>::FeesReserved { id, amount, total });
+		}
 	}
 
 	#[precompile_utils::precompile]
@@ -315,6 +622,25 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> MessageFeeEstimator for Pallet<T> {
+		type Fee = U256;
+		type Message = Vec<u8>;
+		type Middleware = AxelarId;
+
+		fn estimate_fee(axelar_id: AxelarId, _: &Self::Message) -> Result<U256, DispatchError> {
+			let chain_name = ChainNameById::<T>::get(&axelar_id)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+			let config = Configuration::<T>::get(&chain_name)
+				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			match config.domain {
+				DomainConfig::Evm(evm_config) => {
+					Ok(Self::evm_message_cost(&chain_name, &evm_config))
+				}
+			}
+		}
+	}
+
 	impl<T: Config> MessageSender for Pallet<T> {
 		type Message = Vec<u8>;
 		type Middleware = AxelarId;
@@ -324,14 +650,48 @@ pub mod pallet {
 			axelar_id: AxelarId,
 			origin: Self::Origin,
 			message: Self::Message,
-		) -> DispatchResult {
-			let chain_name = ChainNameById::<T>::get(axelar_id)
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				message.len() <= T::MaxPayloadSize::get() as usize,
+				Error::<T>::PayloadTooLarge
+			);
+
+			let chain_name = ChainNameById::<T>::get(&axelar_id)
 				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
 			let config = Configuration::<T>::get(&chain_name)
 				.ok_or(Error::<T>::RouterConfigurationNotFound)?;
 
+			// Storage accesses of this method, the EVM weight is added per call
+			let mut weight = T::DbWeight::get().reads_writes(4, 1);
+
 			match config.domain {
 				DomainConfig::Evm(evm_config) => {
+					let gas_price = Self::gas_price(&chain_name, &evm_config.fee_values);
+					let cost = Self::evm_message_cost(&chain_name, &evm_config);
+
+					if let Some(gas_payment) = GasPayments::<T>::get(&chain_name) {
+						let payment = wrap_into_axelar_gas_payment(
+							origin.h160(),
+							chain_name.clone().into_inner(),
+							config.liquidity_pools_contract_address,
+							message.clone(),
+							origin.h160(),
+						)
+						.map_err(DispatchError::Other)?;
+
+						let post_info = T::Transactor::call(
+							origin.h160(),
+							gas_payment.gas_service_address,
+							payment.as_slice(),
+							gas_payment.amount,
+							gas_price,
+							evm_config.fee_values.gas_limit,
+						)
+						.map_err(|e| with_evm_weight(e, weight))?;
+
+						weight.saturating_accrue(evm_weight(&post_info));
+					}
+
 					let message = wrap_into_axelar_msg(
 						message,
 						chain_name.into_inner(),
@@ -339,22 +699,45 @@ pub mod pallet {
 					)
 					.map_err(DispatchError::Other)?;
 
-					T::Transactor::call(
+					let post_info = T::Transactor::call(
 						origin.h160(),
 						evm_config.target_contract_address,
 						message.as_slice(),
 						evm_config.fee_values.value,
-						evm_config.fee_values.gas_price,
+						gas_price,
 						evm_config.fee_values.gas_limit,
 					)
-					.map(|_| ())
-					.map_err(|e| e.error)
+					.map_err(|e| with_evm_weight(e, weight))?;
+
+					weight.saturating_accrue(evm_weight(&post_info));
+
+					Self::note_fees_reserved(axelar_id, cost);
+
+					Ok(Some(weight).into())
 				}
 			}
 		}
+
+		fn max_payload_size(_: &AxelarId) -> Option<u32> {
+			Some(T::MaxPayloadSize::get())
+		}
 	}
 }
 
+/// Weight consumed by an EVM call, zero if not reported.
+fn evm_weight(post_info: &PostDispatchInfo) -> Weight {
+	post_info.actual_weight.unwrap_or_default()
+}
+
+/// Accounts the weight consumed before a failed EVM call into its error.
+fn with_evm_weight(
+	mut error: DispatchErrorWithPostInfo,
+	consumed: Weight,
+) -> DispatchErrorWithPostInfo {
+	error.post_info.actual_weight = Some(consumed.saturating_add(evm_weight(&error.post_info)));
+	error
+}
+
 /// Encodes the provided message into the format required for submitting it
 /// to the Axelar contract which in turn calls the LiquidityPools
 /// contract with the serialized LP message as `payload`.
@@ -426,6 +809,71 @@ pub fn wrap_into_axelar_msg(
 	Ok(encoded_axelar_contract)
 }
 
+/// Encodes the prepayment of the gas needed by Axelar to relay a message
+/// sent with [`wrap_into_axelar_msg`] to the target chain.
+///
+/// Axelar gas service call:
+/// <https://github.com/axelarnetwork/axelar-cgp-solidity/blob/v4.3.2/contracts/gas-service/AxelarGasService.sol#L98>
+pub fn wrap_into_axelar_gas_payment(
+	sender: H160,
+	target_chain: Vec<u8>,
+	target_contract: H160,
+	serialized_msg: Vec<u8>,
+	refund_address: H160,
+) -> Result<Vec<u8>, &'static str> {
+	const AXELAR_FUNCTION_NAME: &str = "payNativeGasForContractCall";
+	const AXELAR_SENDER_PARAM: &str = "sender";
+	const AXELAR_DESTINATION_CHAIN_PARAM: &str = "destinationChain";
+	const AXELAR_DESTINATION_ADDRESS_PARAM: &str = "destinationAddress";
+	const AXELAR_PAYLOAD_PARAM: &str = "payload";
+	const AXELAR_REFUND_ADDRESS_PARAM: &str = "refundAddress";
+
+	let param = |name: &str, kind: ParamType| Param {
+		name: name.into(),
+		kind,
+		internal_type: None,
+	};
+
+	#[allow(deprecated)]
+	let encoded_gas_payment = Contract {
+		constructor: None,
+		functions: BTreeMap::<String, Vec<Function>>::from([(
+			AXELAR_FUNCTION_NAME.into(),
+			vec![Function {
+				name: AXELAR_FUNCTION_NAME.into(),
+				inputs: vec![
+					param(AXELAR_SENDER_PARAM, ParamType::Address),
+					param(AXELAR_DESTINATION_CHAIN_PARAM, ParamType::String),
+					param(AXELAR_DESTINATION_ADDRESS_PARAM, ParamType::String),
+					param(AXELAR_PAYLOAD_PARAM, ParamType::Bytes),
+					param(AXELAR_REFUND_ADDRESS_PARAM, ParamType::Address),
+				],
+				outputs: vec![],
+				constant: Some(false),
+				state_mutability: ethabi::StateMutability::Payable,
+			}],
+		)]),
+		events: Default::default(),
+		errors: Default::default(),
+		receive: false,
+		fallback: false,
+	}
+	.function(AXELAR_FUNCTION_NAME)
+	.map_err(|_| "cannot retrieve Axelar gas service function")?
+	.encode_input(&[
+		Token::Address(sender),
+		Token::String(
+			String::from_utf8(target_chain).map_err(|_| "target chain conversion error")?,
+		),
+		Token::String(format!("0x{}", hex::encode(target_contract.0))),
+		Token::Bytes(serialized_msg),
+		Token::Address(refund_address),
+	])
+	.map_err(|_| "cannot encode input for Axelar gas service function")?;
+
+	Ok(encoded_gas_payment)
+}
+
 /// Decodes the source address which can be:
 /// - a 20 bytes array
 /// - an hexadecimal character secuence (40 characters)
@@ -1,7 +1,7 @@
 use cfg_types::domain_address::DomainAddress;
-use frame_support::{derive_impl, traits::EitherOfDiverse};
+use frame_support::{derive_impl, parameter_types, traits::EitherOfDiverse};
 use frame_system::{EnsureRoot, EnsureSigned};
-use sp_core::{H160, H256};
+use sp_core::{H160, H256, U256};
 use sp_io::TestExternalities;
 
 use crate::{pallet as pallet_axelar_router, AxelarId};
@@ -45,9 +45,16 @@ impl cfg_mocks::pre_conditions::pallet::Config for Runtime {
 	type Result = bool;
 }
 
+parameter_types! {
+	pub const BaseFee: U256 = U256([1_000, 0, 0, 0]);
+	pub const MaxPayloadSize: u32 = 8;
+}
+
 impl pallet_axelar_router::Config for Runtime {
 	type AdminOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSigned<AccountId>>;
+	type BaseFee = BaseFee;
 	type EvmAccountCodeChecker = AccountCodeChecker;
+	type MaxPayloadSize = MaxPayloadSize;
 	type Middleware = Middleware;
 	type Receiver = Receiver;
 	type RuntimeEvent = RuntimeEvent;
@@ -14,6 +14,8 @@ const MESSAGE: &[u8] = &[1, 2, 3];
 const FEE_VALUE: U256 = U256::zero();
 const GAS_LIMIT: U256 = U256::one();
 const GAS_PRICE: U256 = U256::max_value();
+const GAS_SERVICE_ADDRESS: H160 = H160::repeat_byte(4);
+const GAS_PAYMENT: U256 = U256([10, 0, 0, 0]);
 
 fn config() -> AxelarConfig {
 	AxelarConfig {
@@ -80,6 +82,197 @@ mod configuration {
 			);
 		});
 	}
+
+	#[test]
+	fn reconfigure_with_other_chain_id() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			let mut new_config = config();
+			let DomainConfig::Evm(evm_config) = &mut new_config.domain;
+			evm_config.chain_id = CHAIN_ID + 1;
+
+			assert_ok!(Router::set_config(
+				RuntimeOrigin::root(),
+				CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+				Box::new(new_config)
+			));
+
+			assert!(ChainNameById::<Runtime>::get(AxelarId::Evm(CHAIN_ID)).is_none());
+			assert!(ChainNameById::<Runtime>::get(AxelarId::Evm(CHAIN_ID + 1)).is_some());
+		});
+	}
+
+	#[test]
+	fn remove() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_ok!(Router::remove_config(
+				RuntimeOrigin::root(),
+				CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+			));
+
+			assert!(ChainNameById::<Runtime>::get(AxelarId::Evm(CHAIN_ID)).is_none());
+
+			assert_noop!(
+				Router::remove_config(
+					RuntimeOrigin::root(),
+					CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+				),
+				Error::<Runtime>::RouterConfigurationNotFound
+			);
+		});
+	}
+}
+
+mod target_rotation {
+	use super::*;
+
+	const NEW_CONTRACT_ADDRESS: H160 = H160::repeat_byte(5);
+	const NEW_CONTRACT_HASH: H256 = H256::repeat_byte(43);
+
+	fn chain_name() -> ChainName {
+		CHAIN_NAME.as_bytes().to_vec().try_into().unwrap()
+	}
+
+	fn target_of(chain_name: &ChainName) -> (H160, H256) {
+		match Configuration::<Runtime>::get(chain_name).unwrap().domain {
+			DomainConfig::Evm(evm_config) => (
+				evm_config.target_contract_address,
+				evm_config.target_contract_hash,
+			),
+		}
+	}
+
+	#[test]
+	fn rotate_without_grace() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			AccountCodeChecker::mock_check(|(address, hash)| {
+				address == NEW_CONTRACT_ADDRESS && hash == NEW_CONTRACT_HASH
+			});
+
+			assert_ok!(Router::rotate_target_contract(
+				RuntimeOrigin::root(),
+				chain_name(),
+				NEW_CONTRACT_ADDRESS,
+				NEW_CONTRACT_HASH,
+				false,
+			));
+
+			assert_eq!(
+				target_of(&chain_name()),
+				(NEW_CONTRACT_ADDRESS, NEW_CONTRACT_HASH)
+			);
+			assert!(TargetMigrations::<Runtime>::get(chain_name()).is_none());
+		});
+	}
+
+	#[test]
+	fn rotate_without_grace_and_old_code() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			// The contract is not upgraded yet
+			AccountCodeChecker::mock_check(|(_, hash)| hash == AXELAR_CONTRACT_HASH);
+
+			assert_noop!(
+				Router::rotate_target_contract(
+					RuntimeOrigin::root(),
+					chain_name(),
+					AXELAR_CONTRACT_ADDRESS,
+					NEW_CONTRACT_HASH,
+					false,
+				),
+				Error::<Runtime>::ContractCodeMismatch
+			);
+		});
+	}
+
+	#[test]
+	fn rotate_with_grace() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			// The contract is not upgraded yet
+			AccountCodeChecker::mock_check(|(_, hash)| hash == AXELAR_CONTRACT_HASH);
+
+			assert_ok!(Router::rotate_target_contract(
+				RuntimeOrigin::root(),
+				chain_name(),
+				AXELAR_CONTRACT_ADDRESS,
+				NEW_CONTRACT_HASH,
+				true,
+			));
+
+			assert_eq!(
+				target_of(&chain_name()),
+				(AXELAR_CONTRACT_ADDRESS, NEW_CONTRACT_HASH)
+			);
+			assert_eq!(
+				TargetMigrations::<Runtime>::get(chain_name()),
+				Some(AXELAR_CONTRACT_HASH)
+			);
+
+			assert_noop!(
+				Router::finish_target_migration(RuntimeOrigin::root(), chain_name()),
+				Error::<Runtime>::ContractCodeMismatch
+			);
+
+			// The contract is upgraded
+			AccountCodeChecker::mock_check(|(_, hash)| hash == NEW_CONTRACT_HASH);
+
+			assert_ok!(Router::finish_target_migration(
+				RuntimeOrigin::root(),
+				chain_name()
+			));
+
+			assert!(TargetMigrations::<Runtime>::get(chain_name()).is_none());
+
+			assert_noop!(
+				Router::finish_target_migration(RuntimeOrigin::root(), chain_name()),
+				Error::<Runtime>::TargetMigrationNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn rotate_with_grace_and_unknown_code() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			AccountCodeChecker::mock_check(|_| false);
+
+			assert_noop!(
+				Router::rotate_target_contract(
+					RuntimeOrigin::root(),
+					chain_name(),
+					NEW_CONTRACT_ADDRESS,
+					NEW_CONTRACT_HASH,
+					true,
+				),
+				Error::<Runtime>::ContractCodeMismatch
+			);
+		});
+	}
+
+	#[test]
+	fn rotate_without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Router::rotate_target_contract(
+					RuntimeOrigin::root(),
+					chain_name(),
+					NEW_CONTRACT_ADDRESS,
+					NEW_CONTRACT_HASH,
+					true,
+				),
+				Error::<Runtime>::RouterConfigurationNotFound
+			);
+		});
+	}
 }
 
 mod send {
@@ -96,7 +289,7 @@ mod send {
 				assert_eq!(data, &wrap_message(MESSAGE.to_vec()));
 				assert_eq!(value, FEE_VALUE);
 				assert_eq!(gas_limit, GAS_LIMIT);
-				assert_eq!(gas_price, GAS_PRICE);
+				assert_eq!(gas_price, GasPrice::Legacy(GAS_PRICE));
 				Ok(().into())
 			});
 
@@ -108,6 +301,253 @@ mod send {
 		});
 	}
 
+	#[test]
+	fn evm_weight_is_reported() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+			assert_ok!(Router::set_gas_payment(
+				RuntimeOrigin::root(),
+				CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+				Some(GasPayment {
+					gas_service_address: GAS_SERVICE_ADDRESS,
+					amount: GAS_PAYMENT,
+				})
+			));
+
+			Transactor::mock_call(|_, _, _, _, _, _| Ok(Some(Weight::from_parts(10, 1)).into()));
+
+			let post_info =
+				Router::send(AxelarId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec()).unwrap();
+
+			// Gas payment and message calls
+			assert_eq!(post_info.actual_weight, Some(Weight::from_parts(20, 2)));
+		});
+	}
+
+	#[test]
+	fn evm_weight_is_reported_on_error() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			Transactor::mock_call(|_, _, _, _, _, _| {
+				Err(DispatchErrorWithPostInfo {
+					post_info: Some(Weight::from_parts(10, 1)).into(),
+					error: DispatchError::Other("err"),
+				})
+			});
+
+			let err = Router::send(AxelarId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec()).unwrap_err();
+
+			assert_eq!(err.post_info.actual_weight, Some(Weight::from_parts(10, 1)));
+		});
+	}
+
+	#[test]
+	fn fees_reserved_are_accumulated() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			Transactor::mock_call(|_, _, _, _, _, _| Ok(().into()));
+
+			let cost = FEE_VALUE.saturating_add(GAS_PRICE.saturating_mul(GAS_LIMIT));
+
+			assert_ok!(Router::send(
+				AxelarId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+
+			assert_eq!(FeesReserved::<Runtime>::get(AxelarId::Evm(CHAIN_ID)), cost);
+
+			System::assert_last_event(
+				Event::<Runtime>::FeesReserved {
+					id: AxelarId::Evm(CHAIN_ID),
+					amount: cost,
+					total: cost,
+				}
+				.into(),
+			);
+
+			assert_ok!(Router::send(
+				AxelarId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+
+			assert_eq!(
+				FeesReserved::<Runtime>::get(AxelarId::Evm(CHAIN_ID)),
+				cost.saturating_add(cost)
+			);
+		});
+	}
+
+	#[test]
+	fn with_gas_payment() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			let gas_payment = GasPayment {
+				gas_service_address: GAS_SERVICE_ADDRESS,
+				amount: GAS_PAYMENT,
+			};
+
+			assert_ok!(Router::set_gas_payment(
+				RuntimeOrigin::root(),
+				CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+				Some(gas_payment)
+			));
+
+			Transactor::mock_call(move |from, to, data, value, _, _| {
+				assert_eq!(from, SENDER.h160());
+				if to == GAS_SERVICE_ADDRESS {
+					assert_eq!(
+						data,
+						&wrap_into_axelar_gas_payment(
+							SENDER.h160(),
+							CHAIN_NAME.as_bytes().to_vec(),
+							LP_CONTRACT_ADDRESS,
+							MESSAGE.to_vec(),
+							SENDER.h160(),
+						)
+						.unwrap()
+					);
+					assert_eq!(value, GAS_PAYMENT);
+				} else {
+					assert_eq!(to, AXELAR_CONTRACT_ADDRESS);
+					assert_eq!(data, &wrap_message(MESSAGE.to_vec()));
+				}
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send(
+				AxelarId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+
+			let call_cost = FEE_VALUE.saturating_add(GAS_PRICE.saturating_mul(GAS_LIMIT));
+			assert_eq!(
+				FeesReserved::<Runtime>::get(AxelarId::Evm(CHAIN_ID)),
+				call_cost
+					.saturating_add(call_cost)
+					.saturating_add(GAS_PAYMENT)
+			);
+		});
+	}
+
+	#[test]
+	fn with_eip1559_fees() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			let max_priority_fee_per_gas = U256::from(2);
+			let max_fee_per_gas = BaseFee::get() * 2 + max_priority_fee_per_gas;
+
+			assert_ok!(Router::set_eip1559_fees(
+				RuntimeOrigin::root(),
+				CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+				Some(Eip1559FeeValues {
+					max_priority_fee_per_gas,
+					max_fee_per_gas: None,
+				})
+			));
+
+			Transactor::mock_call(move |_, _, _, _, gas_price, gas_limit| {
+				assert_eq!(gas_limit, GAS_LIMIT);
+				assert_eq!(
+					gas_price,
+					GasPrice::Eip1559 {
+						max_fee_per_gas,
+						max_priority_fee_per_gas,
+					}
+				);
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send(
+				AxelarId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+
+			assert_eq!(
+				FeesReserved::<Runtime>::get(AxelarId::Evm(CHAIN_ID)),
+				FEE_VALUE.saturating_add(max_fee_per_gas.saturating_mul(GAS_LIMIT))
+			);
+		});
+	}
+
+	#[test]
+	fn with_fixed_eip1559_max_fee() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			let fees = Eip1559FeeValues {
+				max_priority_fee_per_gas: U256::from(2),
+				max_fee_per_gas: Some(U256::from(5_000)),
+			};
+
+			assert_ok!(Router::set_eip1559_fees(
+				RuntimeOrigin::root(),
+				CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+				Some(fees.clone())
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::Eip1559FeesSet {
+					name: CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+					fees: Some(fees),
+				}
+				.into(),
+			);
+
+			Transactor::mock_call(move |_, _, _, _, gas_price, _| {
+				assert_eq!(
+					gas_price,
+					GasPrice::Eip1559 {
+						max_fee_per_gas: U256::from(5_000),
+						max_priority_fee_per_gas: U256::from(2),
+					}
+				);
+				Ok(().into())
+			});
+
+			assert_ok!(Router::send(
+				AxelarId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+		});
+	}
+
+	#[test]
+	fn set_eip1559_fees_without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Router::set_eip1559_fees(
+					RuntimeOrigin::root(),
+					CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+					None
+				),
+				Error::<Runtime>::RouterConfigurationNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn set_gas_payment_without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Router::set_gas_payment(
+					RuntimeOrigin::root(),
+					CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+					None
+				),
+				Error::<Runtime>::RouterConfigurationNotFound
+			);
+		});
+	}
+
 	#[test]
 	fn without_configuration() {
 		new_test_ext().execute_with(|| {
@@ -118,6 +558,25 @@ mod send {
 		});
 	}
 
+	#[test]
+	fn with_payload_too_large() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			let message = vec![1; MaxPayloadSize::get() as usize + 1];
+
+			assert_err!(
+				Router::send(AxelarId::Evm(CHAIN_ID), SENDER, message),
+				Error::<Runtime>::PayloadTooLarge,
+			);
+
+			assert_eq!(
+				Router::max_payload_size(&AxelarId::Evm(CHAIN_ID)),
+				Some(MaxPayloadSize::get())
+			);
+		});
+	}
+
 	#[test]
 	fn with_ethereum_error() {
 		new_test_ext().execute_with(|| {
@@ -126,9 +585,60 @@ mod send {
 			Transactor::mock_call(move |_, _, _, _, _, _| Err(DispatchError::Other("err").into()));
 
 			assert_err!(
-				Router::send(AxelarId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec()),
+				Router::send(AxelarId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec())
+					.map_err(|e| e.error),
 				DispatchError::Other("err")
 			);
+
+			assert_eq!(
+				FeesReserved::<Runtime>::get(AxelarId::Evm(CHAIN_ID)),
+				U256::zero()
+			);
+		});
+	}
+}
+
+mod estimate_fee {
+	use super::*;
+
+	#[test]
+	fn matches_fees_reserved() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_ok!(Router::set_gas_payment(
+				RuntimeOrigin::root(),
+				CHAIN_NAME.as_bytes().to_vec().try_into().unwrap(),
+				Some(GasPayment {
+					gas_service_address: GAS_SERVICE_ADDRESS,
+					amount: GAS_PAYMENT,
+				})
+			));
+
+			let estimation = Router::estimate_fee(AxelarId::Evm(CHAIN_ID), &MESSAGE.to_vec());
+
+			Transactor::mock_call(|_, _, _, _, _, _| Ok(().into()));
+
+			assert_ok!(Router::send(
+				AxelarId::Evm(CHAIN_ID),
+				SENDER,
+				MESSAGE.to_vec()
+			));
+
+			assert_eq!(
+				estimation,
+				Ok(FeesReserved::<Runtime>::get(AxelarId::Evm(CHAIN_ID)))
+			);
+		});
+	}
+
+	#[test]
+	fn without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				Router::estimate_fee(AxelarId::Evm(CHAIN_ID), &MESSAGE.to_vec()),
+				Error::<Runtime>::RouterConfigurationNotFound,
+			);
 		});
 	}
 }
@@ -89,6 +89,18 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_vested_reward_ratio() -> Result<(), BenchmarkError> {
+		let ratio = Perbill::from_percent(50);
+
+		#[extrinsic_call]
+		set_vested_reward_ratio(RawOrigin::Root, ratio);
+
+		assert_eq!(VestedRewardRatio::<T>::get(), ratio);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		BlockRewards,
 		crate::mock::ExtBuilder::default().build(),
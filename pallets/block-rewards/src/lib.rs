@@ -19,12 +19,20 @@
 //! a provider. Thus, when new collators join, they will automatically be staked
 //! and vice-versa when collators leave, they are unstaked.
 //!
+//! Since there is no delegation to collators, the stake is not backed by user
+//! funds and there is no liquid representation of it. A liquid staking token
+//! requires delegation to be supported first.
+//!
 //! The BlockRewards pallet provides functions for:
 //!
 //! - Claiming the reward given for a staked currency. The reward will be the
 //!   native network's token.
 //! - Admin methods to configure the reward amount for collators and the annual
 //!   treasury inflation.
+//!
+//! A configurable part of each claimed reward is not liquid at once but
+//! locked in a vesting schedule of `RewardVestingPeriod` blocks. The part is
+//! set by [`Pallet::set_vested_reward_ratio()`] and is zero by default.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(test)]
@@ -52,7 +60,7 @@ use frame_support::{
 		fungible::{Inspect as FungibleInspect, Mutate as FungibleMutate},
 		fungibles::{Inspect, Mutate},
 		tokens::{Balance, Fortitude, Precision},
-		OneSessionHandler,
+		Currency, OneSessionHandler, VestingSchedule,
 	},
 	DefaultNoBound, PalletId,
 };
@@ -60,8 +68,8 @@ use frame_system::pallet_prelude::*;
 use num_traits::sign::Unsigned;
 pub use pallet::*;
 use sp_runtime::{
-	traits::{AccountIdConversion, EnsureAdd, EnsureMul, Zero},
-	FixedPointNumber, FixedPointOperand, SaturatedConversion, Saturating,
+	traits::{AccountIdConversion, EnsureAdd, EnsureMul, One, Zero},
+	FixedPointNumber, FixedPointOperand, PerThing, Perbill, SaturatedConversion, Saturating,
 };
 use sp_std::{mem, vec::Vec};
 pub use weights::WeightInfo;
@@ -192,6 +200,21 @@ pub mod pallet {
 		/// The source of truth for the current time in seconds
 		type Time: TimeAsSecs;
 
+		/// The native currency the rewards are paid in.
+		type NativeCurrency: Currency<Self::AccountId, Balance = Self::Balance>;
+
+		/// Used to lock the vested part of the claimed rewards.
+		type Vesting: VestingSchedule<
+			Self::AccountId,
+			Moment = BlockNumberFor<Self>,
+			Currency = Self::NativeCurrency,
+		>;
+
+		/// Number of blocks over which the vested part of a claimed reward is
+		/// unlocked.
+		#[pallet::constant]
+		type RewardVestingPeriod: Get<BlockNumberFor<Self>>;
+
 		/// Information of runtime weights
 		type WeightInfo: WeightInfo;
 	}
@@ -211,6 +234,11 @@ pub mod pallet {
 	#[pallet::getter(fn next_session_changes)]
 	pub(super) type NextSessionChanges<T: Config> = StorageValue<_, SessionChanges<T>, ValueQuery>;
 
+	/// Part of each claimed reward which is vested instead of being liquid at
+	/// once.
+	#[pallet::storage]
+	pub(super) type VestedRewardRatio<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -222,6 +250,13 @@ pub mod pallet {
 		SessionAdvancementFailed {
 			error: DispatchError,
 		},
+		VestedRewardRatioUpdated {
+			ratio: Perbill,
+		},
+		RewardVested {
+			account_id: T::AccountId,
+			amount: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -261,13 +296,19 @@ pub mod pallet {
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Claims the reward the associated to a currency.
-		/// The reward will be transferred to the target account.
+		/// The reward will be transferred to the target account, where the
+		/// `VestedRewardRatio` part of it is locked in a vesting schedule.
+		///
+		/// NOTE: Fails if the target account already has the maximum number
+		/// of vesting schedules. They can be merged through pallet-vesting.
 		#[pallet::weight(T::WeightInfo::claim_reward())]
 		#[pallet::call_index(0)]
 		pub fn claim_reward(origin: OriginFor<T>, account_id: T::AccountId) -> DispatchResult {
 			ensure_signed(origin)?;
 
-			T::Rewards::claim_reward(T::StakeCurrencyId::get(), &account_id).map(|_| ())
+			let reward = T::Rewards::claim_reward(T::StakeCurrencyId::get(), &account_id)?;
+
+			Self::vest_reward(&account_id, reward)
 		}
 
 		/// Admin method to set the reward amount for a collator used for the
@@ -303,6 +344,20 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Admin method to set the part of the claimed rewards which is
+		/// vested. Rewards claimed before are not affected by this call.
+		#[pallet::weight(T::WeightInfo::set_vested_reward_ratio())]
+		#[pallet::call_index(3)]
+		pub fn set_vested_reward_ratio(origin: OriginFor<T>, ratio: Perbill) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			VestedRewardRatio::<T>::put(ratio);
+
+			Self::deposit_event(Event::VestedRewardRatioUpdated { ratio });
+
+			Ok(())
+		}
 	}
 }
 
@@ -348,6 +403,34 @@ impl<T: Config> Pallet<T> {
 		.map(|_| ())
 	}
 
+	/// Locks the `VestedRewardRatio` part of a reward already paid to `who` in
+	/// a vesting schedule of `RewardVestingPeriod` blocks starting now.
+	pub(crate) fn vest_reward(who: &T::AccountId, reward: T::Balance) -> DispatchResult {
+		let locked = VestedRewardRatio::<T>::get().mul_floor(reward);
+		if locked.is_zero() {
+			return Ok(());
+		}
+
+		let period: T::Balance = T::RewardVestingPeriod::get()
+			.saturated_into::<u128>()
+			.saturated_into();
+		let per_block = (locked / period.max(One::one())).max(One::one());
+
+		T::Vesting::add_vesting_schedule(
+			who,
+			locked,
+			per_block,
+			frame_system::Pallet::<T>::block_number(),
+		)?;
+
+		Self::deposit_event(Event::RewardVested {
+			account_id: who.clone(),
+			amount: locked,
+		});
+
+		Ok(())
+	}
+
 	/// Calculates the inflation proration based on the annual configuration and
 	/// the session duration in seconds
 	pub(crate) fn calculate_epoch_treasury_inflation(
@@ -6,8 +6,8 @@ use cfg_types::{
 use frame_support::{
 	derive_impl, parameter_types,
 	traits::{
-		fungibles::Inspect, tokens::WithdrawConsequence, ConstU128, ConstU32, OnFinalize,
-		OnInitialize,
+		fungibles::Inspect, tokens::WithdrawConsequence, ConstU128, ConstU32, ConstU64, OnFinalize,
+		OnInitialize, WithdrawReasons,
 	},
 	PalletId,
 };
@@ -19,6 +19,7 @@ use crate::{self as pallet_block_rewards, Config};
 
 pub(crate) const MAX_COLLATORS: u32 = 10;
 pub(crate) const SESSION_DURATION: BlockNumber = 5;
+pub(crate) const REWARD_VESTING_PERIOD: BlockNumber = 10;
 
 pub(crate) type AccountId = u64;
 type Balance = u128;
@@ -34,6 +35,7 @@ frame_support::construct_runtime!(
 		Rewards: pallet_rewards::<Instance1>,
 		Session: pallet_session,
 		MockTime: cfg_mocks::pallet_mock_time,
+		Vesting: pallet_vesting,
 		BlockRewards: pallet_block_rewards,
 	}
 );
@@ -142,6 +144,7 @@ impl pallet_restricted_tokens::Config for Test {
 	type PreReservableCurrency = cfg_traits::Always;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TransferHooks = ();
 	type WeightInfo = ();
 }
 
@@ -156,7 +159,9 @@ frame_support::parameter_types! {
 impl pallet_rewards::Config<pallet_rewards::Instance1> for Test {
 	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
+	type EarlyUnlockPenalty = ();
 	type GroupId = u32;
+	type LockOptions = ();
 	type PalletId = RewardsPalletId;
 	type RewardCurrency = NativeToken;
 	type RewardIssuance =
@@ -170,6 +175,23 @@ impl pallet_rewards::Config<pallet_rewards::Instance1> for Test {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+frame_support::parameter_types! {
+	pub UnvestedFundsAllowedWithdrawReasons: WithdrawReasons =
+		WithdrawReasons::except(WithdrawReasons::TRANSFER | WithdrawReasons::RESERVE);
+}
+
+impl pallet_vesting::Config for Test {
+	type BlockNumberProvider = System;
+	type BlockNumberToBalance = ConvertInto;
+	type Currency = Balances;
+	type MinVestedTransfer = ConstU128<1>;
+	type RuntimeEvent = RuntimeEvent;
+	type UnvestedFundsAllowedWithdrawReasons = UnvestedFundsAllowedWithdrawReasons;
+	type WeightInfo = ();
+
+	const MAX_VESTING_SCHEDULES: u32 = 3;
+}
+
 impl cfg_mocks::pallet_mock_time::Config for Test {
 	type Moment = Seconds;
 }
@@ -191,7 +213,9 @@ impl pallet_block_rewards::Config for Test {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type MaxCollators = MaxCollators;
+	type NativeCurrency = Balances;
 	type Rate = Rate;
+	type RewardVestingPeriod = ConstU64<REWARD_VESTING_PERIOD>;
 	type Rewards = Rewards;
 	type RuntimeEvent = RuntimeEvent;
 	type StakeAmount = StakeAmount;
@@ -200,6 +224,7 @@ impl pallet_block_rewards::Config for Test {
 	type Time = MockTime;
 	type Tokens = Tokens;
 	type TreasuryPalletId = TreasuryPalletId;
+	type Vesting = Vesting;
 	type Weight = u64;
 	type WeightInfo = ();
 }
@@ -3,7 +3,10 @@ use cfg_types::{
 	fixed_point::Rate,
 	tokens::{CurrencyId, StakingCurrency},
 };
-use frame_support::{assert_noop, assert_ok, traits::fungibles};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{fungibles, VestingSchedule},
+};
 use num_traits::One;
 use sp_runtime::traits::BadOrigin;
 
@@ -26,6 +29,13 @@ fn check_special_privileges() {
 			BlockRewards::set_annual_treasury_inflation_rate(RuntimeOrigin::signed(2), Rate::one()),
 			BadOrigin
 		);
+		assert_noop!(
+			BlockRewards::set_vested_reward_ratio(
+				RuntimeOrigin::signed(2),
+				Perbill::from_percent(50)
+			),
+			BadOrigin
+		);
 	});
 }
 
@@ -228,6 +238,54 @@ fn single_claim_reward() {
 		});
 }
 
+#[test]
+fn claim_reward_vests_ratio() {
+	ExtBuilder::default()
+		.set_collator_reward(REWARD)
+		.build()
+		.execute_with(|| {
+			assert_ok!(BlockRewards::set_vested_reward_ratio(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(50)
+			));
+			System::assert_last_event(RuntimeEvent::BlockRewards(
+				Event::VestedRewardRatioUpdated {
+					ratio: Perbill::from_percent(50),
+				},
+			));
+
+			// EPOCH 0 -> EPOCH 1
+			advance_session();
+
+			assert_ok!(BlockRewards::claim_reward(RuntimeOrigin::signed(2), 1));
+			System::assert_last_event(RuntimeEvent::BlockRewards(Event::RewardVested {
+				account_id: 1,
+				amount: REWARD / 2,
+			}));
+			assert_eq!(Balances::free_balance(&1), REWARD);
+			assert_eq!(Vesting::vesting_balance(&1), Some(REWARD / 2));
+
+			// Fully unlocked once the vesting period has passed
+			System::set_block_number(System::block_number() + REWARD_VESTING_PERIOD);
+			assert_eq!(Vesting::vesting_balance(&1), Some(0));
+		});
+}
+
+#[test]
+fn claim_reward_without_vesting() {
+	ExtBuilder::default()
+		.set_collator_reward(REWARD)
+		.build()
+		.execute_with(|| {
+			// EPOCH 0 -> EPOCH 1
+			advance_session();
+
+			assert_ok!(BlockRewards::claim_reward(RuntimeOrigin::signed(2), 1));
+			assert_eq!(Balances::free_balance(&1), REWARD);
+			assert_eq!(Vesting::vesting_balance(&1), None);
+		});
+}
+
 #[test]
 fn collator_rewards_greater_than_remainder() {
 	let rate = Rate::saturating_from_rational(1, 10);
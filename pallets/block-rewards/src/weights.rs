@@ -36,6 +36,7 @@ pub trait WeightInfo {
 	fn claim_reward() -> Weight;
 	fn set_collator_reward_per_session() -> Weight;
 	fn set_annual_treasury_inflation_rate() -> Weight;
+	fn set_vested_reward_ratio() -> Weight;
 }
 
 /// Weights for pallet_block_rewards using the Substrate node and recommended
@@ -45,8 +46,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	fn claim_reward() -> Weight {
 		Weight::from_parts(90_960_000, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 
 	fn set_collator_reward_per_session() -> Weight {
@@ -62,6 +63,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+
+	fn set_vested_reward_ratio() -> Weight {
+		Weight::from_parts(8_025_000, 0).saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 
 // For backwards compatibility and tests
@@ -69,8 +74,8 @@ impl WeightInfo for () {
 	fn claim_reward() -> Weight {
 		Weight::from_parts(90_960_000, 0)
 			.saturating_add(Weight::from_parts(0, 6196))
-			.saturating_add(RocksDbWeight::get().reads(5))
-			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(5))
 	}
 
 	fn set_collator_reward_per_session() -> Weight {
@@ -86,4 +91,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+
+	fn set_vested_reward_ratio() -> Weight {
+		Weight::from_parts(8_025_000, 0).saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
@@ -18,13 +18,24 @@
 //! the provided call.
 //!
 //! The execution fees are charged by the Ethereum pallet, the only other extra
-//! fee is be the one from the nonce read operation.
+//! fee is be the one from the nonce read operation. Calls can be priced with a
+//! legacy gas price or with EIP-1559 fees.
+//!
+//! Besides calls to existing contracts, contracts can be deployed from their
+//! init code. Both can declare an EIP-2930 access list, so the storage slots
+//! they touch are charged as warm accesses.
+//!
+//! The hash and index of the transactions executed in the current block can
+//! be inspected, i.e. to correlate them with the operations that sent them.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use cfg_primitives::TRANSACTION_RECOVERY_ID;
-use cfg_traits::ethereum::EthereumTransactor;
+use cfg_traits::ethereum::{
+	AccessListItem, EthereumTransactionInspect, EthereumTransactor, GasPrice, TransactionRef,
+};
 use ethereum::{
-	LegacyTransaction, ReceiptV3, TransactionAction, TransactionSignature, TransactionV2,
+	EIP1559Transaction, EIP2930Transaction, LegacyTransaction, ReceiptV3, TransactionAction,
+	TransactionSignature, TransactionV2,
 };
 use frame_support::{
 	dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
@@ -32,6 +43,7 @@ use frame_support::{
 };
 pub use pallet::*;
 use sp_core::{H160, H256, U256};
+use sp_std::vec::Vec;
 
 #[cfg(test)]
 mod mock;
@@ -95,21 +107,84 @@ pub mod pallet {
 
 			code == 1
 		}
-	}
 
-	impl<T: Config> EthereumTransactor for Pallet<T>
-	where
-		OriginFor<T>:
-			From<pallet_ethereum::Origin> + Into<Result<pallet_ethereum::Origin, OriginFor<T>>>,
-	{
-		fn call(
+		#[allow(clippy::too_many_arguments)]
+		fn build_transaction(
+			nonce: U256,
+			action: TransactionAction,
+			data: &[u8],
+			value: U256,
+			gas_price: GasPrice,
+			gas_limit: U256,
+			access_list: Vec<AccessListItem>,
+			signature: TransactionSignature,
+		) -> TransactionV2 {
+			let access_list = access_list
+				.into_iter()
+				.map(|item| ethereum::AccessListItem {
+					address: item.address,
+					storage_keys: item.storage_keys,
+				})
+				.collect::<Vec<_>>();
+
+			match gas_price {
+				GasPrice::Legacy(gas_price) if access_list.is_empty() => {
+					TransactionV2::Legacy(LegacyTransaction {
+						nonce,
+						gas_price,
+						gas_limit,
+						action,
+						value,
+						input: data.into(),
+						signature,
+					})
+				}
+				// Legacy transactions can not declare an access list, so they
+				// are sent as EIP-2930 transactions with the same gas price.
+				GasPrice::Legacy(gas_price) => TransactionV2::EIP2930(EIP2930Transaction {
+					chain_id: <T as pallet_evm::Config>::ChainId::get(),
+					nonce,
+					gas_price,
+					gas_limit,
+					action,
+					value,
+					input: data.into(),
+					access_list,
+					odd_y_parity: false,
+					r: *signature.r(),
+					s: *signature.s(),
+				}),
+				GasPrice::Eip1559 {
+					max_fee_per_gas,
+					max_priority_fee_per_gas,
+				} => TransactionV2::EIP1559(EIP1559Transaction {
+					chain_id: <T as pallet_evm::Config>::ChainId::get(),
+					nonce,
+					max_priority_fee_per_gas,
+					max_fee_per_gas,
+					gas_limit,
+					action,
+					value,
+					input: data.into(),
+					access_list,
+					odd_y_parity: false,
+					r: *signature.r(),
+					s: *signature.s(),
+				}),
+			}
+		}
+
+		/// Executes the transaction, returning the address of the deployed
+		/// contract if it was a contract creation.
+		fn transact(
 			from: H160,
-			to: H160,
+			action: TransactionAction,
 			data: &[u8],
 			value: U256,
-			gas_price: U256,
+			gas_price: GasPrice,
 			gas_limit: U256,
-		) -> DispatchResultWithPostInfo {
+			access_list: Vec<AccessListItem>,
+		) -> Result<(PostDispatchInfo, Option<H160>), DispatchErrorWithPostInfo> {
 			let nonce = Nonce::<T>::get();
 			let read_weight = T::DbWeight::get().reads(1);
 
@@ -122,15 +197,16 @@ pub mod pallet {
 					error: DispatchError::Other("Failed to create transaction signature"),
 				})?;
 
-			let transaction = TransactionV2::Legacy(LegacyTransaction {
+			let transaction = Pallet::<T>::build_transaction(
 				nonce,
+				action,
+				data,
+				value,
 				gas_price,
 				gas_limit,
-				action: TransactionAction::Call(to),
-				value,
-				input: data.into(),
+				access_list,
 				signature,
-			});
+			);
 
 			Nonce::<T>::put(nonce.saturating_add(U256::one()));
 
@@ -162,15 +238,83 @@ pub mod pallet {
 			//       if the execution failed. But we can check that manually by
 			//       querying the `Pending` storage of the pallet-ethereum.
 			let pending = pallet_ethereum::Pending::<T>::get();
-			let (_, _, receipt) = pending.last().ok_or(DispatchError::Other(
+			let (_, status, receipt) = pending.last().ok_or(DispatchError::Other(
 				"Ethereum not adding pending storage. Unexpected.",
 			))?;
 
 			if Pallet::<T>::valid_code(receipt) {
-				Ok(info)
+				Ok((info, status.contract_address))
 			} else {
 				Err(Error::<T>::EvmExecutionFailed.into())
 			}
 		}
 	}
+
+	impl<T: Config> EthereumTransactor for Pallet<T>
+	where
+		OriginFor<T>:
+			From<pallet_ethereum::Origin> + Into<Result<pallet_ethereum::Origin, OriginFor<T>>>,
+	{
+		fn call_with_access_list(
+			from: H160,
+			to: H160,
+			data: &[u8],
+			value: U256,
+			gas_price: GasPrice,
+			gas_limit: U256,
+			access_list: Vec<AccessListItem>,
+		) -> DispatchResultWithPostInfo {
+			Self::transact(
+				from,
+				TransactionAction::Call(to),
+				data,
+				value,
+				gas_price,
+				gas_limit,
+				access_list,
+			)
+			.map(|(info, _)| info)
+		}
+
+		fn create(
+			from: H160,
+			init_code: &[u8],
+			value: U256,
+			gas_price: GasPrice,
+			gas_limit: U256,
+			access_list: Vec<AccessListItem>,
+		) -> Result<(H160, PostDispatchInfo), DispatchErrorWithPostInfo> {
+			let (info, contract_address) = Self::transact(
+				from,
+				TransactionAction::Create,
+				init_code,
+				value,
+				gas_price,
+				gas_limit,
+				access_list,
+			)?;
+
+			let address = contract_address.ok_or(DispatchErrorWithPostInfo {
+				post_info: info,
+				error: Error::<T>::EvmExecutionFailed.into(),
+			})?;
+
+			Ok((address, info))
+		}
+	}
+
+	impl<T: Config> EthereumTransactionInspect for Pallet<T>
+	where
+		OriginFor<T>:
+			From<pallet_ethereum::Origin> + Into<Result<pallet_ethereum::Origin, OriginFor<T>>>,
+	{
+		fn last_transaction() -> Option<TransactionRef> {
+			pallet_ethereum::Pending::<T>::get()
+				.last()
+				.map(|(_, status, _)| TransactionRef {
+					hash: status.transaction_hash,
+					index: status.transaction_index,
+				})
+		}
+	}
 }
@@ -1,7 +1,10 @@
-use cfg_traits::ethereum::EthereumTransactor;
+use cfg_traits::ethereum::{
+	AccessListItem, EthereumTransactionInspect, EthereumTransactor, GasPrice,
+};
+use ethereum::{TransactionAction, TransactionV2};
 use frame_support::{assert_ok, traits::fungible::Mutate};
 use pallet_evm::{AddressMapping, Error::BalanceLow};
-use sp_core::{crypto::AccountId32, H160, U256};
+use sp_core::{crypto::AccountId32, H160, H256, U256};
 
 use super::mock::*;
 use crate::{pallet::Nonce, Error};
@@ -9,7 +12,7 @@ use crate::{pallet::Nonce, Error};
 mod utils {
 	use super::*;
 
-	pub fn get_test_call_params() -> (H160, AccountId32, H160, [u8; 10], U256, U256) {
+	pub fn get_test_call_params() -> (H160, AccountId32, H160, [u8; 10], U256, GasPrice) {
 		let sender: AccountId32 = [0u8; 32].into();
 		let sender_h160: H160 =
 			H160::from_slice(&<AccountId32 as AsRef<[u8; 32]>>::as_ref(&sender)[0..20]);
@@ -18,7 +21,7 @@ mod utils {
 		let to = H160::from_low_u64_be(2);
 		let data = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
 		let value = U256::from(10);
-		let gas_price = U256::from(10);
+		let gas_price = GasPrice::Legacy(U256::from(10));
 
 		(sender_h160, derived_sender, to, data, value, gas_price)
 	}
@@ -57,6 +60,35 @@ mod call {
 		});
 	}
 
+	#[test]
+	fn success_with_eip1559_fees() {
+		new_test_ext().execute_with(|| {
+			let (sender, derived_sender, to, data, value, _) = get_test_call_params();
+
+			Balances::mint_into(&derived_sender.into(), 1_000_000_000_000_000).unwrap();
+
+			let transaction_call_cost =
+				<Runtime as pallet_evm::Config>::config().gas_transaction_call;
+
+			// Ensure that the gas limit is enough to cover for executing a call.
+			let gas_limit = U256::from(transaction_call_cost + 10_000);
+
+			assert_ok!(<EthereumTransaction as EthereumTransactor>::call(
+				sender,
+				to,
+				data.as_slice(),
+				value,
+				GasPrice::Eip1559 {
+					max_fee_per_gas: U256::from(10),
+					max_priority_fee_per_gas: U256::from(1),
+				},
+				gas_limit
+			));
+
+			assert_eq!(Nonce::<Runtime>::get(), U256::from(1));
+		});
+	}
+
 	#[test]
 	fn insufficient_balance() {
 		new_test_ext().execute_with(|| {
@@ -115,3 +147,236 @@ mod call {
 		});
 	}
 }
+
+mod call_with_access_list {
+	use super::*;
+
+	fn access_list(to: H160) -> Vec<AccessListItem> {
+		vec![AccessListItem {
+			address: to,
+			storage_keys: vec![H256::from_low_u64_be(1)],
+		}]
+	}
+
+	#[test]
+	fn legacy_gas_price_sends_eip2930_transaction() {
+		new_test_ext().execute_with(|| {
+			let (sender, derived_sender, to, data, value, gas_price) = get_test_call_params();
+
+			Balances::mint_into(&derived_sender.into(), 1_000_000_000_000_000).unwrap();
+
+			let transaction_call_cost =
+				<Runtime as pallet_evm::Config>::config().gas_transaction_call;
+			let gas_limit = U256::from(transaction_call_cost + 10_000);
+
+			assert_ok!(EthereumTransaction::call_with_access_list(
+				sender,
+				to,
+				data.as_slice(),
+				value,
+				gas_price,
+				gas_limit,
+				access_list(to),
+			));
+
+			let (transaction, _, _) = pallet_ethereum::Pending::<Runtime>::get()
+				.last()
+				.cloned()
+				.unwrap();
+
+			match transaction {
+				TransactionV2::EIP2930(transaction) => {
+					assert_eq!(transaction.gas_price, U256::from(10));
+					assert_eq!(transaction.access_list.len(), 1);
+					assert_eq!(transaction.access_list[0].address, to);
+				}
+				_ => panic!("Expected an EIP-2930 transaction"),
+			}
+		});
+	}
+
+	#[test]
+	fn eip1559_transaction_carries_access_list() {
+		new_test_ext().execute_with(|| {
+			let (sender, derived_sender, to, data, value, _) = get_test_call_params();
+
+			Balances::mint_into(&derived_sender.into(), 1_000_000_000_000_000).unwrap();
+
+			let transaction_call_cost =
+				<Runtime as pallet_evm::Config>::config().gas_transaction_call;
+			let gas_limit = U256::from(transaction_call_cost + 10_000);
+
+			assert_ok!(EthereumTransaction::call_with_access_list(
+				sender,
+				to,
+				data.as_slice(),
+				value,
+				GasPrice::Eip1559 {
+					max_fee_per_gas: U256::from(10),
+					max_priority_fee_per_gas: U256::from(1),
+				},
+				gas_limit,
+				access_list(to),
+			));
+
+			let (transaction, _, _) = pallet_ethereum::Pending::<Runtime>::get()
+				.last()
+				.cloned()
+				.unwrap();
+
+			match transaction {
+				TransactionV2::EIP1559(transaction) => {
+					assert_eq!(transaction.access_list.len(), 1);
+				}
+				_ => panic!("Expected an EIP-1559 transaction"),
+			}
+		});
+	}
+
+	#[test]
+	fn legacy_transaction_without_access_list() {
+		new_test_ext().execute_with(|| {
+			let (sender, derived_sender, to, data, value, gas_price) = get_test_call_params();
+
+			Balances::mint_into(&derived_sender.into(), 1_000_000_000_000_000).unwrap();
+
+			let transaction_call_cost =
+				<Runtime as pallet_evm::Config>::config().gas_transaction_call;
+			let gas_limit = U256::from(transaction_call_cost + 10_000);
+
+			assert_ok!(EthereumTransaction::call_with_access_list(
+				sender,
+				to,
+				data.as_slice(),
+				value,
+				gas_price,
+				gas_limit,
+				Vec::new(),
+			));
+
+			let (transaction, _, _) = pallet_ethereum::Pending::<Runtime>::get()
+				.last()
+				.cloned()
+				.unwrap();
+
+			assert!(matches!(transaction, TransactionV2::Legacy(_)));
+		});
+	}
+}
+
+mod create {
+	use super::*;
+
+	/// Init code deploying a contract whose code is the single byte `0x01`.
+	const INIT_CODE: [u8; 10] = [0x60, 0x01, 0x60, 0x00, 0x53, 0x60, 0x01, 0x60, 0x00, 0xf3];
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			let (sender, derived_sender, _, _, _, gas_price) = get_test_call_params();
+
+			Balances::mint_into(&derived_sender.into(), 1_000_000_000_000_000).unwrap();
+
+			let transaction_create_cost =
+				<Runtime as pallet_evm::Config>::config().gas_transaction_create;
+			let gas_limit = U256::from(transaction_create_cost + 100_000);
+
+			let (address, _) = EthereumTransaction::create(
+				sender,
+				INIT_CODE.as_slice(),
+				U256::zero(),
+				gas_price,
+				gas_limit,
+				Vec::new(),
+			)
+			.unwrap();
+
+			assert_eq!(
+				pallet_evm::AccountCodes::<Runtime>::get(address),
+				vec![0x01]
+			);
+			assert_eq!(Nonce::<Runtime>::get(), U256::from(1));
+
+			let (transaction, status, _) = pallet_ethereum::Pending::<Runtime>::get()
+				.last()
+				.cloned()
+				.unwrap();
+
+			assert_eq!(status.contract_address, Some(address));
+			match transaction {
+				TransactionV2::Legacy(transaction) => {
+					assert_eq!(transaction.action, TransactionAction::Create);
+				}
+				_ => panic!("Expected a legacy transaction"),
+			}
+		});
+	}
+
+	#[test]
+	fn out_of_gas() {
+		new_test_ext().execute_with(|| {
+			let (sender, derived_sender, _, _, _, gas_price) = get_test_call_params();
+
+			Balances::mint_into(&derived_sender.into(), 1_000_000_000_000_000).unwrap();
+
+			let transaction_call_cost =
+				<Runtime as pallet_evm::Config>::config().gas_transaction_call;
+
+			// Enough for a call but not for a contract creation
+			let gas_limit = U256::from(transaction_call_cost + 10_000);
+
+			assert_eq!(
+				EthereumTransaction::create(
+					sender,
+					INIT_CODE.as_slice(),
+					U256::zero(),
+					gas_price,
+					gas_limit,
+					Vec::new(),
+				)
+				.map(|(address, _)| address)
+				.map_err(|e| e.error),
+				Err(Error::<Runtime>::EvmExecutionFailed.into())
+			);
+		});
+	}
+}
+
+mod last_transaction {
+	use super::*;
+
+	#[test]
+	fn tracks_executed_transactions() {
+		new_test_ext().execute_with(|| {
+			let (sender, derived_sender, to, data, value, gas_price) = get_test_call_params();
+
+			Balances::mint_into(&derived_sender.into(), 1_000_000_000_000_000).unwrap();
+
+			let transaction_call_cost =
+				<Runtime as pallet_evm::Config>::config().gas_transaction_call;
+			let gas_limit = U256::from(transaction_call_cost + 10_000);
+
+			assert_eq!(EthereumTransaction::last_transaction(), None);
+
+			for expected_index in 0..2 {
+				assert_ok!(<EthereumTransaction as EthereumTransactor>::call(
+					sender,
+					to,
+					data.as_slice(),
+					value,
+					gas_price,
+					gas_limit
+				));
+
+				let (transaction, _, _) = pallet_ethereum::Pending::<Runtime>::get()
+					.last()
+					.cloned()
+					.unwrap();
+				let last = EthereumTransaction::last_transaction().unwrap();
+
+				assert_eq!(last.hash, transaction.hash());
+				assert_eq!(last.index, expected_index);
+			}
+		});
+	}
+}
@@ -0,0 +1,186 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::{
+	benchmarking::{FundedPoolBenchmarkHelper, InvestmentIdBenchmarkHelper, SwapsBenchmarkHelper},
+	investments::{ForeignInvestment, TrancheCurrency},
+	swaps::TokenSwaps,
+	StatusNotificationHook,
+};
+use cfg_types::investments::CollectedAmount;
+use frame_benchmarking::{account, v2::*};
+
+use crate::{pallet::*, pool_currency_of, Action, CollectedRedemptionHook, PoolIdOf, TrancheIdOf};
+
+const FOREIGN_CURRENCY: u32 = 1001;
+const AMOUNT: u128 = 1_000_000_000_000;
+
+struct Helper<T>(sp_std::marker::PhantomData<T>);
+impl<T: Config> Helper<T>
+where
+	T::PoolInspect: FundedPoolBenchmarkHelper<
+			AccountId = T::AccountId,
+			Balance = T::PoolBalance,
+			PoolId = PoolIdOf<T>,
+		> + InvestmentIdBenchmarkHelper<InvestmentId = T::InvestmentId, PoolId = PoolIdOf<T>>,
+	T::OrderBook: SwapsBenchmarkHelper<
+		AccountId = T::AccountId,
+		Balance = T::SwapBalance,
+		CurrencyId = T::CurrencyId,
+	>,
+	T::CurrencyId: From<u32>,
+	T::PoolBalance: From<u128>,
+	T::SwapBalance: From<u128>,
+	PoolIdOf<T>: Default,
+{
+	/// Creates a funded pool whose currency can be swapped with the foreign
+	/// currency, and an investor of the pool holding both currencies.
+	fn setup() -> (T::AccountId, T::InvestmentId) {
+		let pool_id = Default::default();
+		let pool_admin = account("pool_admin", 0, 0);
+		<T::PoolInspect as FundedPoolBenchmarkHelper>::bench_create_funded_pool(
+			pool_id,
+			&pool_admin,
+		);
+
+		let investment_id = T::PoolInspect::bench_default_investment_id(pool_id);
+		let pool_currency = pool_currency_of::<T>(investment_id).unwrap();
+		T::OrderBook::bench_setup_pair(FOREIGN_CURRENCY.into(), pool_currency);
+
+		let investor = account::<T::AccountId>("investor", 0, 0);
+		T::PoolInspect::bench_investor_setup(pool_id, investor.clone(), AMOUNT.into());
+		T::OrderBook::bench_mint(FOREIGN_CURRENCY.into(), &investor, AMOUNT.into());
+
+		(investor, investment_id)
+	}
+
+	/// Fills `amount` of the swap of the investor from a third party account.
+	fn fill_swap(
+		investor: &T::AccountId,
+		investment_id: T::InvestmentId,
+		action: Action,
+		amount: u128,
+	) {
+		let order_id = Pallet::<T>::order_id(investor, investment_id, action).unwrap();
+		let order = T::OrderBook::get_order_details(order_id).unwrap();
+
+		let fulfiller = account::<T::AccountId>("fulfiller", 0, 0);
+		T::OrderBook::bench_mint(order.swap.currency_in, &fulfiller, (amount * 2).into());
+		T::OrderBook::fill_order(fulfiller, order_id, amount.into()).unwrap();
+	}
+}
+
+#[benchmarks(
+	where
+		T::PoolInspect: FundedPoolBenchmarkHelper<
+				AccountId = T::AccountId,
+				Balance = T::PoolBalance,
+				PoolId = PoolIdOf<T>,
+			> + InvestmentIdBenchmarkHelper<InvestmentId = T::InvestmentId, PoolId = PoolIdOf<T>>,
+		T::OrderBook: SwapsBenchmarkHelper<
+			AccountId = T::AccountId,
+			Balance = T::SwapBalance,
+			CurrencyId = T::CurrencyId,
+		>,
+		T::CurrencyId: From<u32> + From<(PoolIdOf<T>, TrancheIdOf<T>)>,
+		T::ForeignBalance: From<u128>,
+		T::PoolBalance: From<u128>,
+		T::TrancheBalance: From<u128>,
+		T::SwapBalance: From<u128>,
+		PoolIdOf<T>: Default,
+)]
+mod benchmarks {
+	use super::*;
+
+	/// The investment needs to be swapped into pool currency, placing a swap
+	/// order.
+	#[benchmark]
+	fn increase_foreign_investment() -> Result<(), BenchmarkError> {
+		let (investor, investment_id) = Helper::<T>::setup();
+
+		#[block]
+		{
+			Pallet::<T>::increase_foreign_investment(
+				&investor,
+				investment_id,
+				AMOUNT.into(),
+				FOREIGN_CURRENCY.into(),
+			)?;
+		}
+
+		assert!(Pallet::<T>::order_id(&investor, investment_id, Action::Investment).is_some());
+
+		Ok(())
+	}
+
+	/// Half of the investment was swapped and invested. The cancellation
+	/// cancels the pending swap, decreases the investment and places a swap
+	/// order back into foreign currency.
+	#[benchmark]
+	fn cancel_foreign_investment() -> Result<(), BenchmarkError> {
+		let (investor, investment_id) = Helper::<T>::setup();
+
+		Pallet::<T>::increase_foreign_investment(
+			&investor,
+			investment_id,
+			AMOUNT.into(),
+			FOREIGN_CURRENCY.into(),
+		)?;
+		Helper::<T>::fill_swap(&investor, investment_id, Action::Investment, AMOUNT / 2);
+
+		#[block]
+		{
+			Pallet::<T>::cancel_foreign_investment(
+				&investor,
+				investment_id,
+				FOREIGN_CURRENCY.into(),
+			)?;
+		}
+
+		assert!(Pallet::<T>::order_id(&investor, investment_id, Action::Investment).is_some());
+
+		Ok(())
+	}
+
+	/// The collected pool currency needs to be swapped into foreign currency,
+	/// placing a swap order.
+	#[benchmark]
+	fn collect_foreign_redemption() -> Result<(), BenchmarkError> {
+		let (investor, investment_id) = Helper::<T>::setup();
+
+		let tranche_currency = (investment_id.of_pool(), investment_id.of_tranche()).into();
+		T::OrderBook::bench_mint(tranche_currency, &investor, AMOUNT.into());
+
+		Pallet::<T>::increase_foreign_redemption(
+			&investor,
+			investment_id,
+			AMOUNT.into(),
+			FOREIGN_CURRENCY.into(),
+		)?;
+
+		#[block]
+		{
+			CollectedRedemptionHook::<T>::notify_status_change(
+				(investor.clone(), investment_id),
+				CollectedAmount {
+					amount_collected: AMOUNT.into(),
+					amount_payment: AMOUNT.into(),
+				},
+			)?;
+		}
+
+		assert!(Pallet::<T>::order_id(&investor, investment_id, Action::Redemption).is_some());
+
+		Ok(())
+	}
+}
@@ -1,15 +1,20 @@
 //! Trait implementations. Higher level file.
 
-use cfg_traits::{investments::ForeignInvestment, swaps::SwapInfo, StatusNotificationHook};
+use cfg_traits::{
+	investments::{ForeignInvestment, ForeignInvestmentPosition, Investment},
+	swaps::SwapInfo,
+	StatusNotificationHook,
+};
 use cfg_types::investments::CollectedAmount;
 use frame_support::pallet_prelude::*;
+use sp_runtime::traits::EnsureAdd;
 use sp_std::marker::PhantomData;
 
 use crate::{
 	entities::{InvestmentInfo, RedemptionInfo},
 	pallet::{Config, Error, ForeignInvestmentInfo, ForeignRedemptionInfo, Pallet},
 	pool_currency_of,
-	swaps::fulfilled_order,
+	swaps::{fulfilled_order, get_swap},
 	Action,
 };
 
@@ -83,6 +88,37 @@ impl<T: Config> ForeignInvestment<T::AccountId> for Pallet<T> {
 			Ok(cancelled)
 		})
 	}
+
+	fn foreign_position(
+		who: &T::AccountId,
+		investment_id: T::InvestmentId,
+	) -> Result<ForeignInvestmentPosition<T::ForeignBalance, T::TrancheBalance>, DispatchError> {
+		let pending_investment = match ForeignInvestmentInfo::<T>::get(who, investment_id) {
+			Some(info) => {
+				// Only increasing swaps are pending to be invested, decreasing ones are
+				// swapped back into foreign currency
+				let swapping: T::ForeignBalance = info
+					.order_id
+					.and_then(|order_id| get_swap::<T>(&order_id))
+					.filter(|order| order.swap.currency_out == info.foreign_currency)
+					.map(|order| order.swap.amount_out.into())
+					.unwrap_or_default();
+
+				info.foreign_amount.ensure_add(swapping)?
+			}
+			None => T::ForeignBalance::default(),
+		};
+
+		let claimable_redemption = ForeignRedemptionInfo::<T>::get(who, investment_id)
+			.map(|info| info.swapped_amount)
+			.unwrap_or_default();
+
+		Ok(ForeignInvestmentPosition {
+			pending_investment,
+			pending_redemption: T::Investment::redemption(who, investment_id)?,
+			claimable_redemption,
+		})
+	}
 }
 
 impl<T: Config> StatusNotificationHook for Pallet<T> {
@@ -40,6 +40,7 @@ pub use impls::{CollectedInvestmentHook, CollectedRedemptionHook};
 pub use pallet::*;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+pub use weights::WeightInfo;
 
 #[cfg(test)]
 mod mock;
@@ -47,9 +48,13 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
 mod entities;
 mod impls;
 mod swaps;
+pub mod weights;
 
 #[derive(
 	Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, Encode, Decode, TypeInfo, MaxEncodedLen,
@@ -187,6 +192,10 @@ pub mod pallet {
 
 		/// The source of truth for pool currencies.
 		type PoolInspect: PoolInspect<Self::AccountId, Self::CurrencyId>;
+
+		/// Weights of the foreign investment state transitions, to be
+		/// accounted by the callers of this pallet.
+		type WeightInfo: WeightInfo;
 	}
 
 	/// Contains the information about the foreign investment process.
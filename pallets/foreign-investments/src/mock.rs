@@ -71,6 +71,7 @@ impl pallet_foreign_investments::Config for Runtime {
 	type SwapBalance = Balance;
 	type SwapRatio = Ratio;
 	type TrancheBalance = Balance;
+	type WeightInfo = ();
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
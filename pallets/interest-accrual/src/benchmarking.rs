@@ -33,7 +33,35 @@ benchmarks! {
 		let n in 1..25;
 		let now: Seconds = (1 << n) - 1;
 		let rate = interest_rate_per_sec(T::Rate::saturating_from_rational(10, 100)).unwrap();
-	}: { Pallet::<T>::calculate_accumulated_rate(rate, One::one(), 0, now).unwrap() }
+	}: { Pallet::<T>::calculate_accumulated_rate(rate, &CompoundingSchedule::Secondly, One::one(), 0, now).unwrap() }
+	verify {
+	}
+
+	// Same as above for schedules compounding once per period, which
+	// additionally accrue the incomplete periods at both ends.
+	calculate_periodic_accumulated_rate {
+		let n in 1..25;
+		let now: Seconds = (1 << n) - 1;
+		let compounding = CompoundingSchedule::Daily;
+		let rate = compounding.rate_per_period(T::Rate::saturating_from_rational(10, 100)).unwrap();
+	}: { Pallet::<T>::calculate_accumulated_rate(rate, &compounding, One::one(), 1, now).unwrap() }
+	verify {
+	}
+
+	// Rates are accrued when read. As they are stored together, reading one
+	// depends on the number of rates, while the accrual itself is covered by
+	// the benchmarks above.
+	get_rate {
+		let n in 1..T::MaxRateCount::get();
+		let rate = |i: u32| InterestRate::Fixed {
+			rate_per_year: T::Rate::saturating_from_rational(i, 10000),
+			compounding: CompoundingSchedule::Secondly,
+		};
+		for i in 1..=n {
+			Pallet::<T>::reference_interest_rate(&rate(i)).unwrap();
+		}
+		let last = rate(n);
+	}: { Pallet::<T>::get_rate(&last).unwrap() }
 	verify {
 	}
 }
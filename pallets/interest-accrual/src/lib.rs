@@ -90,8 +90,8 @@
 //! normalized_debt = debt_base_value / rate_per_second.pow(rate_age_at_time_of_debt_creation);
 //! ```
 //!
-//! In the actual code, `rate_per_second.pow(...)` will be precomputed
-//! for us at block initialize and is just queried as the "accrued
+//! In the actual code, `rate_per_second.pow(...)` is computed from the
+//! last time the rate was updated and is just queried as the "accrued
 //! rate".
 //!
 //! The case of `rate_age_at_time_of_debt_creation == 0` creates a
@@ -119,29 +119,81 @@
 //! * If `new_interest_rate` is newly created (and thus its age is `0`), the
 //!   correction factor is `1` just as for any other rate.  See the note above
 //!   regarding zero-age rates.
+//!
+//! ## Lazy accrual
+//!
+//! Rates are not updated every block. Each rate stores the moment its
+//! accumulated rate was last updated, and is accrued until the current time
+//! when it is read. The accrued value is only written back when a debt using
+//! the rate is adjusted or renormalized, so the cost of accrual is paid by the
+//! operations using a rate instead of by every block.
+//!
+//! ## Floating rates
+//!
+//! A floating rate accrues at the value of an external reference rate plus a
+//! spread. Each `(reference, spread)` combination has its own accumulated
+//! rate. The value of the reference is read from `Config::ReferenceRates`
+//! whenever the rate is updated, and is used to accrue the rate until its
+//! next update. Rates whose reference changed are also updated when the block
+//! leaves enough idle weight, so a new value of the reference applies from the
+//! block it is observed in even if no debt using the rate is adjusted. Debts
+//! using a floating rate are normalized in the same way as for fixed rates.
+//!
+//! ## Compounding schedules
+//!
+//! Rates are bucketed by their compounding schedule as well. A rate compounds
+//! once per period of its schedule (every second, day or 30 days), where the
+//! rate per period is the rate per year divided by the number of periods in a
+//! year (`SECONDS_PER_YEAR`, 360 or 12). Periods are aligned to multiples of
+//! the period length, and the elapsed part of an incomplete period accrues
+//! linearly:
+//!
+//! ```text
+//! rate_per_period.pow(complete_periods) * (1 + rate * elapsed / period)
+//! ```
+//!
+//! ## Negative rates
+//!
+//! A `FixedNegative` rate decreases the debt over time, e.g. to amortize a
+//! premium. Its rate per period is a decay factor `1 - rate_per_year / N`
+//! below one, so it is bucketed with the fixed rates without colliding with
+//! any of them. Negative rates are bounded to `-100%` per year, and an
+//! accumulated rate decaying to zero fails with an underflow instead of being
+//! used to normalize debts.
+//!
+//! ## Historical rates
+//!
+//! At the first update of a rate in every `Config::SnapshotPeriod`, when a
+//! rate is created, and when a floating rate starts accruing at a new
+//! reference value, a snapshot of its accumulated rate is stored. The
+//! accumulated rate at a past moment is reconstructed by accruing the closest
+//! previous snapshot until that moment, which allows to compute the debt of a
+//! loan at any time since the oldest of the last `Config::MaxRateSnapshots`
+//! snapshots of its rate.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use cfg_primitives::SECONDS_PER_YEAR;
 use cfg_traits::{
-	interest::{InterestAccrual, InterestRate, RateCollection},
-	Seconds, TimeAsSecs,
+	interest::{
+		CompoundingSchedule, InterestAccrual, InterestRate, RateCollection, ReferenceRateId,
+	},
+	Seconds, TimeAsSecs, ValueProvider,
 };
 use cfg_types::adjustments::Adjustment;
-use frame_support::{pallet_prelude::RuntimeDebug, BoundedVec};
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_support::pallet_prelude::RuntimeDebug;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_arithmetic::traits::{checked_pow, One, Zero};
+use sp_arithmetic::traits::{One, Zero};
 use sp_runtime::{
 	traits::{
 		AtLeast32BitUnsigned, CheckedAdd, CheckedSub, EnsureAdd, EnsureAddAssign, EnsureDiv,
-		EnsureInto, EnsureMul, EnsureSub, Saturating,
+		EnsureMul, Saturating,
 	},
 	ArithmeticError, DispatchError, FixedPointNumber, FixedPointOperand,
 };
 use sp_std::{cmp::Ordering, vec::Vec};
 
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -162,14 +214,108 @@ pub use pallet::*;
 // Which in the worst cases could be near to 2.
 const MAX_INTEREST_RATE: u32 = 2; // Which corresponds to 200%.
 
+// Negative rates are bounded to keep the decay factor of every compounding
+// period far from zero, which the accumulated rate could otherwise underflow
+// to.
+const MAX_NEGATIVE_INTEREST_RATE: u32 = 1; // Which corresponds to -100%.
+
 // Type aliases
 type RateDetailsOf<T> = RateDetails<<T as Config>::Rate>;
+type FloatingRateDetailsOf<T> = FloatingRateDetails<<T as Config>::Rate>;
+type RateIdOf<T> = RateId<<T as Config>::Rate>;
+type RateSnapshotOf<T> = RateSnapshot<<T as Config>::Rate>;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct RateDetails<Rate> {
-	pub interest_rate_per_sec: Rate,
+	/// Growth factor per compounding period. For `Secondly` compounding this
+	/// is the rate per second.
+	pub interest_rate_per_period: Rate,
+	pub compounding: CompoundingSchedule,
+	pub accumulated_rate: Rate,
+	pub reference_count: u32,
+	/// Moment until which the accumulated rate was accrued
+	pub last_updated: Seconds,
+}
+
+impl<Rate: Copy> RateDetails<Rate> {
+	fn id(&self) -> RateId<Rate> {
+		RateId::Fixed {
+			interest_rate_per_period: self.interest_rate_per_period,
+			compounding: self.compounding.clone(),
+		}
+	}
+}
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct FloatingRateDetails<Rate> {
+	pub reference: ReferenceRateId,
+	pub spread: Rate,
+	pub compounding: CompoundingSchedule,
+	pub interest_rate_per_period: Rate,
 	pub accumulated_rate: Rate,
 	pub reference_count: u32,
+	/// Moment until which the accumulated rate was accrued
+	pub last_updated: Seconds,
+}
+
+impl<Rate: Copy + PartialEq> FloatingRateDetails<Rate> {
+	fn matches(
+		&self,
+		reference: &ReferenceRateId,
+		spread: Rate,
+		compounding: &CompoundingSchedule,
+	) -> bool {
+		self.reference == *reference && self.spread == spread && self.compounding == *compounding
+	}
+
+	fn details(&self) -> RateDetails<Rate> {
+		RateDetails {
+			interest_rate_per_period: self.interest_rate_per_period,
+			compounding: self.compounding.clone(),
+			accumulated_rate: self.accumulated_rate,
+			reference_count: self.reference_count,
+			last_updated: self.last_updated,
+		}
+	}
+
+	fn id(&self) -> RateId<Rate> {
+		RateId::Floating {
+			reference: self.reference,
+			spread: self.spread,
+			compounding: self.compounding.clone(),
+		}
+	}
+}
+
+/// Identifies the bucket of an interest rate
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RateId<Rate> {
+	Fixed {
+		interest_rate_per_period: Rate,
+		compounding: CompoundingSchedule,
+	},
+	Floating {
+		reference: ReferenceRateId,
+		spread: Rate,
+		compounding: CompoundingSchedule,
+	},
+}
+
+impl<Rate> RateId<Rate> {
+	fn compounding(&self) -> &CompoundingSchedule {
+		match self {
+			RateId::Fixed { compounding, .. } | RateId::Floating { compounding, .. } => compounding,
+		}
+	}
+}
+
+/// Accumulated rate of a bucket at a given moment
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RateSnapshot<Rate> {
+	pub timestamp: Seconds,
+	/// Rate per period the bucket accrued at after the snapshot
+	pub interest_rate_per_period: Rate,
+	pub accumulated_rate: Rate,
 }
 
 #[frame_support::pallet]
@@ -179,7 +325,7 @@ pub mod pallet {
 	use super::*;
 	use crate::weights::WeightInfo;
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -213,8 +359,22 @@ pub mod pallet {
 
 		type Time: TimeAsSecs;
 
+		/// Provider of the current value per year of the reference rates used
+		/// by floating rates.
+		type ReferenceRates: ValueProvider<(), ReferenceRateId, Value = Self::Rate>;
+
+		/// Maximum number of rates, fixed and floating together.
 		type MaxRateCount: Get<u32>;
 
+		/// Time between two snapshots of the accumulated rates
+		#[pallet::constant]
+		type SnapshotPeriod: Get<Seconds>;
+
+		/// Maximum number of snapshots kept for each rate. The oldest one is
+		/// dropped when a new one is taken.
+		#[pallet::constant]
+		type MaxRateSnapshots: Get<u32>;
+
 		type Weights: WeightInfo;
 	}
 
@@ -224,12 +384,42 @@ pub mod pallet {
 		StorageValue<_, BoundedVec<RateDetailsOf<T>, T::MaxRateCount>, ValueQuery>;
 
 	#[pallet::storage]
-	#[pallet::getter(fn last_updated)]
-	pub(super) type LastUpdated<T: Config> = StorageValue<_, Seconds, ValueQuery>;
+	pub(super) type FloatingRates<T: Config> =
+		StorageValue<_, BoundedVec<FloatingRateDetailsOf<T>, T::MaxRateCount>, ValueQuery>;
+
+	/// Last snapshots of the accumulated rate of each rate, oldest first.
+	#[pallet::storage]
+	pub(super) type RateSnapshots<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		RateIdOf<T>,
+		BoundedVec<RateSnapshotOf<T>, T::MaxRateSnapshots>,
+		ValueQuery,
+	>;
 
 	#[pallet::event]
 	pub enum Event<T: Config> {}
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let count_weight = T::DbWeight::get().reads(1);
+			if remaining_weight.any_lt(count_weight) {
+				return Weight::zero();
+			}
+
+			let count = FloatingRates::<T>::decode_len().unwrap_or(0) as u32;
+			let refresh_weight = T::Weights::refresh_floating_rates(count);
+			if count == 0 || remaining_weight.any_lt(count_weight.saturating_add(refresh_weight)) {
+				return count_weight;
+			}
+
+			Self::refresh_floating_rates();
+
+			count_weight.saturating_add(refresh_weight)
+		}
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Emits when the debt calculation failed
@@ -242,61 +432,16 @@ pub mod pallet {
 		InvalidRate,
 		/// Emits when adding a new rate would exceed the storage limits
 		TooManyRates,
-	}
-
-	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
-			let then = LastUpdated::<T>::get();
-			let now = T::Time::now();
-			LastUpdated::<T>::set(now);
-			let delta = now - then;
-			let bits = Seconds::BITS - delta.leading_zeros();
-
-			// reads: timestamp, last updated, rates vec
-			// writes: last updated, rates vec
-			let mut weight = T::DbWeight::get().reads_writes(3, 2);
-
-			let rates = Rates::<T>::get();
-			let rates: Vec<_> = rates
-				.into_iter()
-				.filter_map(|rate| {
-					weight.saturating_accrue(T::Weights::calculate_accumulated_rate(bits));
-
-					let RateDetailsOf::<T> {
-						interest_rate_per_sec,
-						accumulated_rate,
-						reference_count,
-					} = rate;
-
-					Self::calculate_accumulated_rate(
-						interest_rate_per_sec,
-						accumulated_rate,
-						then,
-						now,
-					)
-					.ok()
-					.map(|accumulated_rate| RateDetailsOf::<T> {
-						interest_rate_per_sec,
-						accumulated_rate,
-						reference_count,
-					})
-				})
-				.collect();
-
-			Rates::<T>::set(
-				rates
-					.try_into()
-					.expect("We got this vec from a bounded vec to begin with"),
-			);
-			weight
-		}
+		/// Emits when the reference of a floating rate has no value
+		ReferenceRateNotFound,
+		/// Emits when there is no snapshot of a rate before a given moment
+		NoRateSnapshot,
 	}
 
 	impl<T: Config> Pallet<T> {
 		/// Calculate fastly the current debt using normalized debt * cumulative
-		/// rate if `when` is exactly `now` (same block). If when is in the past
-		/// it recomputes the previous cumulative rate.
+		/// rate if `when` is exactly `now`. If when is in the past it
+		/// recomputes the previous cumulative rate.
 		///
 		/// If `when` is further in the past than the last time the
 		/// normalized debt was adjusted, this will return nonsense
@@ -308,15 +453,16 @@ pub mod pallet {
 			when: Seconds,
 		) -> Result<T::Balance, DispatchError> {
 			let rate = Self::get_rate(interest_rate_per_year)?;
-			let now = LastUpdated::<T>::get();
+			let now = T::Time::now();
 
 			let acc_rate = match when.cmp(&now) {
 				Ordering::Equal => rate.accumulated_rate,
 				Ordering::Less => {
-					let delta = now.ensure_sub(when)?;
-					let rate_adjustment =
-						checked_pow(rate.interest_rate_per_sec, delta.ensure_into()?)
-							.ok_or(ArithmeticError::Overflow)?;
+					let rate_adjustment = rate.compounding.accrual_factor(
+						rate.interest_rate_per_period,
+						when,
+						now,
+					)?;
 					rate.accumulated_rate.ensure_div(rate_adjustment)?
 				}
 				Ordering::Greater => {
@@ -334,7 +480,7 @@ pub mod pallet {
 			normalized_debt: T::Balance,
 			adjustment: Adjustment<T::Balance>,
 		) -> Result<T::Balance, DispatchError> {
-			let rate = Self::get_rate(interest_rate_per_year)?;
+			let rate = Self::accrue_interest_rate(interest_rate_per_year)?;
 
 			let debt = Self::calculate_debt(normalized_debt, rate.accumulated_rate)
 				.ok_or(Error::<T>::DebtCalculationFailed)?;
@@ -359,8 +505,8 @@ pub mod pallet {
 			new_interest_rate: &InterestRate<T::Rate>,
 			normalized_debt: T::Balance,
 		) -> Result<T::Balance, DispatchError> {
-			let old_rate = Self::get_rate(old_interest_rate)?;
-			let new_rate = Self::get_rate(new_interest_rate)?;
+			let old_rate = Self::accrue_interest_rate(old_interest_rate)?;
+			let new_rate = Self::accrue_interest_rate(new_interest_rate)?;
 
 			let debt = Self::calculate_debt(normalized_debt, old_rate.accumulated_rate)
 				.ok_or(Error::<T>::DebtCalculationFailed)?;
@@ -382,39 +528,53 @@ pub mod pallet {
 		}
 
 		pub fn calculate_accumulated_rate<Rate: FixedPointNumber>(
-			interest_rate_per_sec: Rate,
+			interest_rate_per_period: Rate,
+			compounding: &CompoundingSchedule,
 			accumulated_rate: Rate,
 			last_updated: Seconds,
 			now: Seconds,
 		) -> Result<Rate, ArithmeticError> {
-			// accumulated_rate * interest_rate_per_sec ^ (now - last_updated)
-			let time_difference_secs = now.ensure_sub(last_updated)?;
-			checked_pow(interest_rate_per_sec, time_difference_secs as usize)
-				.ok_or(ArithmeticError::Overflow)? // TODO: This line can be remove once #1241 be merged
-				.ensure_mul(accumulated_rate)
+			// accumulated_rate * interest_rate_per_period ^ (periods elapsed), with
+			// incomplete periods accruing linearly
+			let accumulated_rate = compounding
+				.accrual_factor(interest_rate_per_period, last_updated, now)?
+				.ensure_mul(accumulated_rate)?;
+
+			// A negative rate decays the accumulated rate, which can not be used to
+			// normalize debts once it reaches zero
+			match accumulated_rate.is_zero() {
+				true => Err(ArithmeticError::Underflow),
+				false => Ok(accumulated_rate),
+			}
 		}
 
 		pub fn reference_interest_rate(
 			interest_rate_per_year: &InterestRate<T::Rate>,
 		) -> DispatchResult {
 			match interest_rate_per_year {
-				InterestRate::Fixed { rate_per_year, .. } => {
-					let interest_rate_per_sec = unchecked_conversion(*rate_per_year)?;
+				InterestRate::Fixed { compounding, .. }
+				| InterestRate::FixedNegative { compounding, .. } => {
+					let interest_rate_per_period = interest_rate_per_year.per_period()?;
 					Rates::<T>::try_mutate(|rates| {
-						let rate = rates
-							.iter_mut()
-							.find(|rate| rate.interest_rate_per_sec == interest_rate_per_sec);
+						let rate = rates.iter_mut().find(|rate| {
+							rate.interest_rate_per_period == interest_rate_per_period
+								&& rate.compounding == *compounding
+						});
 
 						match rate {
 							Some(rate) => Ok(rate.reference_count.ensure_add_assign(1)?),
 							None => {
 								Self::validate_interest_rate(interest_rate_per_year)?;
+								Self::ensure_rate_capacity()?;
 
 								let new_rate = RateDetailsOf::<T> {
-									interest_rate_per_sec,
+									interest_rate_per_period,
+									compounding: compounding.clone(),
 									accumulated_rate: One::one(),
 									reference_count: 1,
+									last_updated: T::Time::now(),
 								};
+								Self::reset_snapshots(new_rate.id(), interest_rate_per_period);
 
 								rates
 									.try_push(new_rate)
@@ -425,6 +585,44 @@ pub mod pallet {
 						}
 					})
 				}
+				InterestRate::Floating {
+					reference,
+					spread,
+					compounding,
+				} => FloatingRates::<T>::try_mutate(|rates| {
+					let rate = rates
+						.iter_mut()
+						.find(|rate| rate.matches(reference, *spread, compounding));
+
+					match rate {
+						Some(rate) => Ok(rate.reference_count.ensure_add_assign(1)?),
+						None => {
+							Self::validate_interest_rate(interest_rate_per_year)?;
+							Self::ensure_rate_capacity()?;
+
+							let new_rate = FloatingRateDetailsOf::<T> {
+								reference: *reference,
+								spread: *spread,
+								compounding: compounding.clone(),
+								interest_rate_per_period: Self::floating_rate_per_period(
+									reference,
+									*spread,
+									compounding,
+								)?,
+								accumulated_rate: One::one(),
+								reference_count: 1,
+								last_updated: T::Time::now(),
+							};
+							Self::reset_snapshots(new_rate.id(), new_rate.interest_rate_per_period);
+
+							rates
+								.try_push(new_rate)
+								.map_err(|_| Error::<T>::TooManyRates)?;
+
+							Ok(())
+						}
+					}
+				}),
 			}
 		}
 
@@ -432,13 +630,17 @@ pub mod pallet {
 			interest_rate_per_year: &InterestRate<T::Rate>,
 		) -> DispatchResult {
 			match interest_rate_per_year {
-				InterestRate::Fixed { rate_per_year, .. } => {
-					let interest_rate_per_sec = unchecked_conversion(*rate_per_year)?;
+				InterestRate::Fixed { compounding, .. }
+				| InterestRate::FixedNegative { compounding, .. } => {
+					let interest_rate_per_period = interest_rate_per_year.per_period()?;
 					Rates::<T>::try_mutate(|rates| {
 						let idx = rates
 							.iter()
 							.enumerate()
-							.find(|(_, rate)| rate.interest_rate_per_sec == interest_rate_per_sec)
+							.find(|(_, rate)| {
+								rate.interest_rate_per_period == interest_rate_per_period
+									&& rate.compounding == *compounding
+							})
 							.ok_or(Error::<T>::NoSuchRate)?
 							.0;
 						rates[idx].reference_count = rates[idx].reference_count.saturating_sub(1);
@@ -448,39 +650,396 @@ pub mod pallet {
 						Ok(())
 					})
 				}
+				InterestRate::Floating {
+					reference,
+					spread,
+					compounding,
+				} => FloatingRates::<T>::try_mutate(|rates| {
+					let idx = rates
+						.iter()
+						.position(|rate| rate.matches(reference, *spread, compounding))
+						.ok_or(Error::<T>::NoSuchRate)?;
+					rates[idx].reference_count = rates[idx].reference_count.saturating_sub(1);
+					if rates[idx].reference_count == 0 {
+						rates.swap_remove(idx);
+					}
+					Ok(())
+				}),
 			}
 		}
 
+		/// Returns the details of a rate accrued until now. Nothing is
+		/// written, see `accrue_interest_rate()`.
 		pub fn get_rate(
 			interest_rate_per_year: &InterestRate<T::Rate>,
 		) -> Result<RateDetailsOf<T>, DispatchError> {
+			let now = T::Time::now();
+
 			match interest_rate_per_year {
-				InterestRate::Fixed { rate_per_year, .. } => {
-					let interest_rate_per_sec = unchecked_conversion(*rate_per_year)?;
-					Rates::<T>::get()
+				InterestRate::Fixed { compounding, .. }
+				| InterestRate::FixedNegative { compounding, .. } => {
+					let interest_rate_per_period = interest_rate_per_year.per_period()?;
+					let rate = Rates::<T>::get()
 						.into_iter()
-						.find(|rate| rate.interest_rate_per_sec == interest_rate_per_sec)
-						.ok_or_else(|| Error::<T>::NoSuchRate.into())
+						.find(|rate| {
+							rate.interest_rate_per_period == interest_rate_per_period
+								&& rate.compounding == *compounding
+						})
+						.ok_or(Error::<T>::NoSuchRate)?;
+
+					Ok(Self::accrue_fixed(rate, now)?)
+				}
+				InterestRate::Floating {
+					reference,
+					spread,
+					compounding,
+				} => {
+					let rate = FloatingRates::<T>::get()
+						.into_iter()
+						.find(|rate| rate.matches(reference, *spread, compounding))
+						.ok_or(Error::<T>::NoSuchRate)?;
+
+					Ok(Self::accrue_floating(rate, now)?.details())
 				}
 			}
 		}
 
+		/// Accrues a rate until now and stores it, taking a snapshot if it is
+		/// its first update of the current `SnapshotPeriod` or if it accrues at
+		/// a new rate from now on. Returns the details of the accrued rate.
+		pub fn accrue_interest_rate(
+			interest_rate_per_year: &InterestRate<T::Rate>,
+		) -> Result<RateDetailsOf<T>, DispatchError> {
+			let now = T::Time::now();
+
+			let (id, previous, rate) = match interest_rate_per_year {
+				InterestRate::Fixed { compounding, .. }
+				| InterestRate::FixedNegative { compounding, .. } => {
+					let interest_rate_per_period = interest_rate_per_year.per_period()?;
+					Rates::<T>::try_mutate(|rates| -> Result<_, DispatchError> {
+						let rate = rates
+							.iter_mut()
+							.find(|rate| {
+								rate.interest_rate_per_period == interest_rate_per_period
+									&& rate.compounding == *compounding
+							})
+							.ok_or(Error::<T>::NoSuchRate)?;
+
+						let previous = rate.clone();
+						*rate = Self::accrue_fixed(rate.clone(), now)?;
+
+						Ok((rate.id(), previous, rate.clone()))
+					})?
+				}
+				InterestRate::Floating {
+					reference,
+					spread,
+					compounding,
+				} => FloatingRates::<T>::try_mutate(|rates| -> Result<_, DispatchError> {
+					let rate = rates
+						.iter_mut()
+						.find(|rate| rate.matches(reference, *spread, compounding))
+						.ok_or(Error::<T>::NoSuchRate)?;
+
+					let previous = rate.details();
+					*rate = Self::accrue_floating(rate.clone(), now)?;
+
+					Ok((rate.id(), previous, rate.details()))
+				})?,
+			};
+
+			Self::snapshot_if_due(id, &previous, &rate);
+
+			Ok(rate)
+		}
+
+		/// Accrues until now the floating rates whose reference changed since
+		/// their last update, so they accrue at the new value from now on.
+		pub(crate) fn refresh_floating_rates() {
+			let now = T::Time::now();
+			let mut rates = FloatingRates::<T>::get();
+			let mut refreshed = false;
+
+			for rate in rates.iter_mut() {
+				let changed =
+					Self::floating_rate_per_period(&rate.reference, rate.spread, &rate.compounding)
+						.map_or(false, |per_period| {
+							per_period != rate.interest_rate_per_period
+						});
+				if !changed {
+					continue;
+				}
+
+				let previous = rate.details();
+				let Ok(accrued) = Self::accrue_floating(rate.clone(), now) else {
+					continue;
+				};
+
+				*rate = accrued;
+				Self::snapshot_if_due(rate.id(), &previous, &rate.details());
+				refreshed = true;
+			}
+
+			if refreshed {
+				FloatingRates::<T>::put(rates);
+			}
+		}
+
+		/// Accrues a fixed rate until `now`
+		fn accrue_fixed(
+			rate: RateDetailsOf<T>,
+			now: Seconds,
+		) -> Result<RateDetailsOf<T>, ArithmeticError> {
+			Ok(RateDetailsOf::<T> {
+				accumulated_rate: Self::calculate_accumulated_rate(
+					rate.interest_rate_per_period,
+					&rate.compounding,
+					rate.accumulated_rate,
+					rate.last_updated,
+					now,
+				)?,
+				last_updated: now,
+				..rate
+			})
+		}
+
+		/// Accrues a floating rate until `now` at the reference value of its
+		/// last update, and reads the value it accrues at from now on. If the
+		/// reference has no value, the last known one is kept.
+		fn accrue_floating(
+			rate: FloatingRateDetailsOf<T>,
+			now: Seconds,
+		) -> Result<FloatingRateDetailsOf<T>, ArithmeticError> {
+			let accumulated_rate = Self::calculate_accumulated_rate(
+				rate.interest_rate_per_period,
+				&rate.compounding,
+				rate.accumulated_rate,
+				rate.last_updated,
+				now,
+			)?;
+
+			let interest_rate_per_period =
+				Self::floating_rate_per_period(&rate.reference, rate.spread, &rate.compounding)
+					.unwrap_or(rate.interest_rate_per_period);
+
+			Ok(FloatingRateDetailsOf::<T> {
+				interest_rate_per_period,
+				accumulated_rate,
+				last_updated: now,
+				..rate
+			})
+		}
+
 		pub(crate) fn validate_interest_rate(
 			interest_rate_per_year: &InterestRate<T::Rate>,
 		) -> DispatchResult {
+			let (rate, maximum) = match interest_rate_per_year {
+				InterestRate::Fixed { rate_per_year, .. } => (
+					rate_per_year,
+					T::Rate::saturating_from_integer(MAX_INTEREST_RATE),
+				),
+				InterestRate::Floating {
+					reference, spread, ..
+				} => {
+					Self::floating_rate_per_year(reference, *spread)?;
+					(spread, T::Rate::saturating_from_integer(MAX_INTEREST_RATE))
+				}
+				InterestRate::FixedNegative { rate_per_year, .. } => (
+					rate_per_year,
+					T::Rate::saturating_from_integer(MAX_NEGATIVE_INTEREST_RATE),
+				),
+			};
+
+			let four_decimals = T::Rate::saturating_from_integer(10000);
+			ensure!(
+				*rate <= maximum
+					&& *rate >= Zero::zero()
+					&& (rate.saturating_mul(four_decimals)).frac() == Zero::zero(),
+				Error::<T>::InvalidRate
+			);
+			Ok(())
+		}
+
+		/// Returns the fixed rate currently equivalent to the given one.
+		pub fn fixed_interest_rate(
+			interest_rate_per_year: &InterestRate<T::Rate>,
+		) -> Result<InterestRate<T::Rate>, DispatchError> {
 			match interest_rate_per_year {
-				InterestRate::Fixed { rate_per_year, .. } => {
-					let four_decimals = T::Rate::saturating_from_integer(10000);
-					let maximum = T::Rate::saturating_from_integer(MAX_INTEREST_RATE);
-					ensure!(
-						*rate_per_year <= maximum
-							&& *rate_per_year >= Zero::zero()
-							&& (rate_per_year.saturating_mul(four_decimals)).frac() == Zero::zero(),
-						Error::<T>::InvalidRate
-					);
-					Ok(())
+				InterestRate::Fixed { .. } | InterestRate::FixedNegative { .. } => {
+					Ok(interest_rate_per_year.clone())
+				}
+				InterestRate::Floating {
+					reference,
+					spread,
+					compounding,
+				} => Ok(InterestRate::Fixed {
+					rate_per_year: Self::floating_rate_per_year(reference, *spread)?,
+					compounding: compounding.clone(),
+				}),
+			}
+		}
+
+		/// Current value per year of a floating rate
+		fn floating_rate_per_year(
+			reference: &ReferenceRateId,
+			spread: T::Rate,
+		) -> Result<T::Rate, DispatchError> {
+			let reference_rate =
+				T::ReferenceRates::get(&(), reference)?.ok_or(Error::<T>::ReferenceRateNotFound)?;
+
+			Ok(reference_rate.ensure_add(spread)?)
+		}
+
+		fn floating_rate_per_period(
+			reference: &ReferenceRateId,
+			spread: T::Rate,
+			compounding: &CompoundingSchedule,
+		) -> Result<T::Rate, DispatchError> {
+			Ok(compounding.rate_per_period(Self::floating_rate_per_year(reference, spread)?)?)
+		}
+
+		/// Accumulated rate of `interest_rate` at `timestamp`. Past values are
+		/// reconstructed from the closest previous snapshot.
+		pub fn rate_at(
+			interest_rate: &InterestRate<T::Rate>,
+			timestamp: Seconds,
+		) -> Result<T::Rate, DispatchError> {
+			let stored = Self::stored_rate(interest_rate)?;
+			match stored {
+				Some(rate) if timestamp >= rate.last_updated => {
+					return Ok(Self::calculate_accumulated_rate(
+						rate.interest_rate_per_period,
+						&rate.compounding,
+						rate.accumulated_rate,
+						rate.last_updated,
+						timestamp,
+					)?)
 				}
+				None if timestamp >= T::Time::now() => return Err(Error::<T>::NoSuchRate.into()),
+				_ => (),
 			}
+
+			// Rates are only snapshotted in the periods they were updated, so the
+			// closest snapshot can be several periods before
+			let id = Self::rate_id(interest_rate)?;
+			let snapshot = RateSnapshots::<T>::get(&id)
+				.into_iter()
+				.rev()
+				.find(|snapshot| snapshot.timestamp <= timestamp)
+				.ok_or(Error::<T>::NoRateSnapshot)?;
+
+			Ok(Self::calculate_accumulated_rate(
+				snapshot.interest_rate_per_period,
+				id.compounding(),
+				snapshot.accumulated_rate,
+				snapshot.timestamp,
+				timestamp,
+			)?)
+		}
+
+		/// Returns the stored details of a rate, without accruing it.
+		fn stored_rate(
+			interest_rate: &InterestRate<T::Rate>,
+		) -> Result<Option<RateDetailsOf<T>>, DispatchError> {
+			Ok(match interest_rate {
+				InterestRate::Fixed { compounding, .. }
+				| InterestRate::FixedNegative { compounding, .. } => {
+					let interest_rate_per_period = interest_rate.per_period()?;
+					Rates::<T>::get().into_iter().find(|rate| {
+						rate.interest_rate_per_period == interest_rate_per_period
+							&& rate.compounding == *compounding
+					})
+				}
+				InterestRate::Floating {
+					reference,
+					spread,
+					compounding,
+				} => FloatingRates::<T>::get()
+					.into_iter()
+					.find(|rate| rate.matches(reference, *spread, compounding))
+					.map(|rate| rate.details()),
+			})
+		}
+
+		fn rate_id(interest_rate: &InterestRate<T::Rate>) -> Result<RateIdOf<T>, DispatchError> {
+			Ok(match interest_rate {
+				InterestRate::Fixed { compounding, .. }
+				| InterestRate::FixedNegative { compounding, .. } => RateId::Fixed {
+					interest_rate_per_period: interest_rate.per_period()?,
+					compounding: compounding.clone(),
+				},
+				InterestRate::Floating {
+					reference,
+					spread,
+					compounding,
+				} => RateId::Floating {
+					reference: *reference,
+					spread: *spread,
+					compounding: compounding.clone(),
+				},
+			})
+		}
+
+		fn snapshot_period() -> Seconds {
+			T::SnapshotPeriod::get().max(1)
+		}
+
+		/// Takes a snapshot of a rate accrued since its `previous` state if it
+		/// is its first update of the current `SnapshotPeriod`, or if its rate
+		/// per period changed.
+		fn snapshot_if_due(id: RateIdOf<T>, previous: &RateDetailsOf<T>, rate: &RateDetailsOf<T>) {
+			let snapshot_period = Self::snapshot_period();
+			if rate.last_updated / snapshot_period > previous.last_updated / snapshot_period
+				|| rate.interest_rate_per_period != previous.interest_rate_per_period
+			{
+				Self::take_snapshot(
+					id,
+					rate.last_updated,
+					rate.interest_rate_per_period,
+					rate.accumulated_rate,
+				);
+			}
+		}
+
+		fn take_snapshot(
+			id: RateIdOf<T>,
+			now: Seconds,
+			interest_rate_per_period: T::Rate,
+			accumulated_rate: T::Rate,
+		) {
+			RateSnapshots::<T>::mutate(id, |snapshots| {
+				// Drops the oldest snapshot if there is no room for the new one
+				let _ = snapshots.force_insert_keep_right(
+					snapshots.len(),
+					RateSnapshot {
+						timestamp: now,
+						interest_rate_per_period,
+						accumulated_rate,
+					},
+				);
+			});
+		}
+
+		/// Replaces the snapshots of a previous bucket with the same id by the
+		/// first one of the new bucket.
+		fn reset_snapshots(id: RateIdOf<T>, interest_rate_per_period: T::Rate) {
+			RateSnapshots::<T>::remove(&id);
+			Self::take_snapshot(id, T::Time::now(), interest_rate_per_period, One::one());
+		}
+
+		/// Ensures a new rate can be added without exceeding `MaxRateCount`
+		/// between fixed and floating rates.
+		fn ensure_rate_capacity() -> DispatchResult {
+			let count = Rates::<T>::decode_len()
+				.unwrap_or(0)
+				.saturating_add(FloatingRates::<T>::decode_len().unwrap_or(0));
+
+			ensure!(
+				count < T::MaxRateCount::get() as usize,
+				Error::<T>::TooManyRates
+			);
+
+			Ok(())
 		}
 	}
 }
@@ -530,12 +1089,33 @@ impl<T: Config> InterestAccrual<T::Rate, T::Balance, Adjustment<T::Balance>> for
 		Pallet::<T>::validate_interest_rate(interest_rate_per_year)
 	}
 
+	fn fixed_rate(
+		interest_rate_per_year: &InterestRate<T::Rate>,
+	) -> Result<InterestRate<T::Rate>, DispatchError> {
+		Pallet::<T>::fixed_interest_rate(interest_rate_per_year)
+	}
+
 	fn rates() -> Self::Rates {
-		RateVec(Rates::<T>::get())
+		let now = T::Time::now();
+
+		RateVec {
+			fixed: Rates::<T>::get()
+				.into_iter()
+				.filter_map(|rate| Pallet::<T>::accrue_fixed(rate, now).ok())
+				.collect(),
+			floating: FloatingRates::<T>::get()
+				.into_iter()
+				.filter_map(|rate| Pallet::<T>::accrue_floating(rate, now).ok())
+				.collect(),
+		}
 	}
 }
 
-pub struct RateVec<T: Config>(BoundedVec<RateDetailsOf<T>, T::MaxRateCount>);
+/// Rates accrued until the moment they were collected
+pub struct RateVec<T: Config> {
+	fixed: Vec<RateDetailsOf<T>>,
+	floating: Vec<FloatingRateDetailsOf<T>>,
+}
 
 impl<T: Config> RateCollection<T::Rate, T::Balance, T::Balance> for RateVec<T> {
 	fn current_debt(
@@ -543,23 +1123,31 @@ impl<T: Config> RateCollection<T::Rate, T::Balance, T::Balance> for RateVec<T> {
 		interest_rate: &InterestRate<T::Rate>,
 		normalized_debt: T::Balance,
 	) -> Result<T::Balance, DispatchError> {
-		let interest_rate_per_sec = unchecked_conversion(interest_rate.per_year())?;
-		self.0
-			.iter()
-			.find(|rate| rate.interest_rate_per_sec == interest_rate_per_sec)
-			.ok_or(Error::<T>::NoSuchRate)
-			.and_then(|rate| {
-				Pallet::<T>::calculate_debt(normalized_debt, rate.accumulated_rate)
-					.ok_or(Error::<T>::DebtCalculationFailed)
-			})
-			.map_err(Into::into)
-	}
-}
+		let accumulated_rate = match interest_rate {
+			InterestRate::Fixed { compounding, .. }
+			| InterestRate::FixedNegative { compounding, .. } => {
+				let interest_rate_per_period = interest_rate.per_period()?;
+				self.fixed
+					.iter()
+					.find(|rate| {
+						rate.interest_rate_per_period == interest_rate_per_period
+							&& rate.compounding == *compounding
+					})
+					.map(|rate| rate.accumulated_rate)
+			}
+			InterestRate::Floating {
+				reference,
+				spread,
+				compounding,
+			} => self
+				.floating
+				.iter()
+				.find(|rate| rate.matches(reference, *spread, compounding))
+				.map(|rate| rate.accumulated_rate),
+		}
+		.ok_or(Error::<T>::NoSuchRate)?;
 
-fn unchecked_conversion<R: FixedPointNumber + EnsureAdd>(
-	interest_rate_per_year: R,
-) -> Result<R, ArithmeticError> {
-	interest_rate_per_year
-		.ensure_div(R::saturating_from_integer(SECONDS_PER_YEAR))?
-		.ensure_add(One::one())
+		Pallet::<T>::calculate_debt(normalized_debt, accumulated_rate)
+			.ok_or_else(|| Error::<T>::DebtCalculationFailed.into())
+	}
 }
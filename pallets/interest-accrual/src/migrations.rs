@@ -0,0 +1,261 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+pub mod v4 {
+	use cfg_traits::interest::{CompoundingSchedule, ReferenceRateId};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Get, OnRuntimeUpgrade},
+	};
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::TryRuntimeError;
+	use sp_std::vec::Vec;
+
+	use crate::{Config, Pallet};
+
+	const LOG_PREFIX: &str = "InterestAccrualV4";
+
+	#[frame_support::storage_alias]
+	pub(super) type Rates<T: Config> = StorageValue<
+		Pallet<T>,
+		BoundedVec<RateDetailsV4<<T as Config>::Rate>, <T as Config>::MaxRateCount>,
+		ValueQuery,
+	>;
+
+	#[frame_support::storage_alias]
+	pub(super) type FloatingRates<T: Config> = StorageValue<
+		Pallet<T>,
+		BoundedVec<FloatingRateDetailsV4<<T as Config>::Rate>, <T as Config>::MaxRateCount>,
+		ValueQuery,
+	>;
+
+	#[derive(Decode)]
+	struct RateDetailsV3<Rate> {
+		interest_rate_per_sec: Rate,
+		accumulated_rate: Rate,
+		reference_count: u32,
+	}
+
+	#[derive(Decode)]
+	struct FloatingRateDetailsV3<Rate> {
+		reference: ReferenceRateId,
+		spread: Rate,
+		interest_rate_per_sec: Rate,
+		accumulated_rate: Rate,
+		reference_count: u32,
+	}
+
+	#[derive(Encode, Decode)]
+	pub(super) struct RateDetailsV4<Rate> {
+		pub interest_rate_per_period: Rate,
+		pub compounding: CompoundingSchedule,
+		pub accumulated_rate: Rate,
+		pub reference_count: u32,
+	}
+
+	#[derive(Encode, Decode)]
+	pub(super) struct FloatingRateDetailsV4<Rate> {
+		pub reference: ReferenceRateId,
+		pub spread: Rate,
+		pub compounding: CompoundingSchedule,
+		pub interest_rate_per_period: Rate,
+		pub accumulated_rate: Rate,
+		pub reference_count: u32,
+	}
+
+	/// Adds the compounding schedule to the stored rates. All existing rates
+	/// compound every second, so their rate per period is their rate per
+	/// second.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let fixed = Rates::<T>::translate::<
+				BoundedVec<RateDetailsV3<T::Rate>, T::MaxRateCount>,
+				_,
+			>(|old| {
+				let rates: Vec<_> = old?
+					.into_iter()
+					.map(|rate| RateDetailsV4 {
+						interest_rate_per_period: rate.interest_rate_per_sec,
+						compounding: CompoundingSchedule::Secondly,
+						accumulated_rate: rate.accumulated_rate,
+						reference_count: rate.reference_count,
+					})
+					.collect();
+
+				rates.try_into().ok()
+			});
+
+			let floating = FloatingRates::<T>::translate::<
+				BoundedVec<FloatingRateDetailsV3<T::Rate>, T::MaxRateCount>,
+				_,
+			>(|old| {
+				let rates: Vec<_> = old?
+					.into_iter()
+					.map(|rate| FloatingRateDetailsV4 {
+						reference: rate.reference,
+						spread: rate.spread,
+						compounding: CompoundingSchedule::Secondly,
+						interest_rate_per_period: rate.interest_rate_per_sec,
+						accumulated_rate: rate.accumulated_rate,
+						reference_count: rate.reference_count,
+					})
+					.collect();
+
+				rates.try_into().ok()
+			});
+
+			if fixed.is_err() || floating.is_err() {
+				log::error!("{LOG_PREFIX}: Failed to decode the stored rates!");
+			} else {
+				log::info!("{LOG_PREFIX}: Migrated rates!");
+			}
+
+			T::DbWeight::get().reads_writes(2, 2)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let fixed = Rates::<T>::decode_len().unwrap_or(0) as u32;
+			let floating = FloatingRates::<T>::decode_len().unwrap_or(0) as u32;
+
+			Ok((fixed, floating).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (fixed, floating): (u32, u32) = Decode::decode(&mut pre_state.as_slice())
+				.expect("pre_upgrade provides a valid state; qed");
+
+			assert_eq!(
+				Rates::<T>::get().len() as u32,
+				fixed,
+				"{LOG_PREFIX}: Mismatching number of rates after migration!"
+			);
+			assert_eq!(
+				FloatingRates::<T>::get().len() as u32,
+				floating,
+				"{LOG_PREFIX}: Mismatching number of floating rates after migration!"
+			);
+
+			log::info!("{LOG_PREFIX}: Post checks done!");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v5 {
+	use cfg_traits::Seconds;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Get, OnRuntimeUpgrade},
+	};
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::TryRuntimeError;
+	use sp_std::vec::Vec;
+
+	use super::v4;
+	use crate::{Config, FloatingRateDetailsOf, FloatingRates, Pallet, RateDetailsOf, Rates};
+
+	const LOG_PREFIX: &str = "InterestAccrualV5";
+
+	#[frame_support::storage_alias]
+	type LastUpdated<T: Config> = StorageValue<Pallet<T>, Seconds, ValueQuery>;
+
+	/// Stores the moment each rate was last updated, which until now was the
+	/// same for all rates, and removes the global one as rates are no longer
+	/// updated every block.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let last_updated = LastUpdated::<T>::take();
+
+			let fixed: Vec<_> = v4::Rates::<T>::take()
+				.into_iter()
+				.map(|rate| RateDetailsOf::<T> {
+					interest_rate_per_period: rate.interest_rate_per_period,
+					compounding: rate.compounding,
+					accumulated_rate: rate.accumulated_rate,
+					reference_count: rate.reference_count,
+					last_updated,
+				})
+				.collect();
+
+			let floating: Vec<_> = v4::FloatingRates::<T>::take()
+				.into_iter()
+				.map(|rate| FloatingRateDetailsOf::<T> {
+					reference: rate.reference,
+					spread: rate.spread,
+					compounding: rate.compounding,
+					interest_rate_per_period: rate.interest_rate_per_period,
+					accumulated_rate: rate.accumulated_rate,
+					reference_count: rate.reference_count,
+					last_updated,
+				})
+				.collect();
+
+			Rates::<T>::put(BoundedVec::truncate_from(fixed));
+			FloatingRates::<T>::put(BoundedVec::truncate_from(floating));
+
+			log::info!("{LOG_PREFIX}: Migrated rates!");
+
+			T::DbWeight::get().reads_writes(3, 3)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let fixed = v4::Rates::<T>::decode_len().unwrap_or(0) as u32;
+			let floating = v4::FloatingRates::<T>::decode_len().unwrap_or(0) as u32;
+
+			Ok((fixed, floating, LastUpdated::<T>::get()).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (fixed, floating, last_updated): (u32, u32, Seconds) =
+				Decode::decode(&mut pre_state.as_slice())
+					.expect("pre_upgrade provides a valid state; qed");
+
+			let rates = Rates::<T>::get();
+			let floating_rates = FloatingRates::<T>::get();
+
+			assert_eq!(
+				rates.len() as u32,
+				fixed,
+				"{LOG_PREFIX}: Mismatching number of rates after migration!"
+			);
+			assert_eq!(
+				floating_rates.len() as u32,
+				floating,
+				"{LOG_PREFIX}: Mismatching number of floating rates after migration!"
+			);
+			assert!(
+				rates.iter().all(|rate| rate.last_updated == last_updated)
+					&& floating_rates
+						.iter()
+						.all(|rate| rate.last_updated == last_updated),
+				"{LOG_PREFIX}: Rates must keep the last update moment!"
+			);
+			assert!(
+				!LastUpdated::<T>::exists(),
+				"{LOG_PREFIX}: LastUpdated must be removed!"
+			);
+
+			log::info!("{LOG_PREFIX}: Post checks done!");
+
+			Ok(())
+		}
+	}
+}
@@ -1,4 +1,5 @@
-use cfg_traits::Millis;
+use cfg_primitives::SECONDS_PER_DAY;
+use cfg_traits::{interest::ReferenceRateId, Millis};
 use frame_support::{derive_impl, parameter_types, traits::Hooks};
 use sp_io::TestExternalities;
 use sp_runtime::BuildStorage;
@@ -12,6 +13,7 @@ frame_support::construct_runtime!(
 	pub enum Runtime {
 		System: frame_system,
 		Timestamp: pallet_timestamp,
+		MockReferenceRates: cfg_mocks::value_provider::pallet,
 		InterestAccrual: crate,
 	}
 );
@@ -28,15 +30,26 @@ impl pallet_timestamp::Config for Runtime {
 	type WeightInfo = ();
 }
 
+impl cfg_mocks::value_provider::pallet::Config for Runtime {
+	type Key = ReferenceRateId;
+	type Source = ();
+	type Value = Rate;
+}
+
 parameter_types! {
 	pub const MaxRateCount: u32 = 100;
+	pub const SnapshotPeriod: u64 = SECONDS_PER_DAY;
+	pub const MaxRateSnapshots: u32 = 3;
 }
 
 impl Config for Runtime {
 	type Balance = Balance;
 	type MaxRateCount = MaxRateCount;
+	type MaxRateSnapshots = MaxRateSnapshots;
 	type Rate = Rate;
+	type ReferenceRates = MockReferenceRates;
 	type RuntimeEvent = RuntimeEvent;
+	type SnapshotPeriod = SnapshotPeriod;
 	type Time = Timestamp;
 	type Weights = ();
 }
@@ -11,15 +11,18 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::interest::{CompoundingSchedule, InterestRate};
+use cfg_traits::interest::{CompoundingSchedule, InterestRate, ReferenceRateId};
+use cfg_types::adjustments::Adjustment;
+use frame_support::{assert_err, assert_ok, traits::Hooks, weights::Weight};
+use sp_arithmetic::traits::checked_pow;
 use sp_runtime::{
 	traits::{One, Zero},
-	FixedPointNumber,
+	ArithmeticError, FixedPointNumber,
 };
 
 use crate::{
-	mock::{Rate, Runtime},
-	Pallet,
+	mock::{new_test_ext, InterestAccrual, MockReferenceRates, Rate, Runtime, System, Timestamp},
+	Error, Pallet, Rates,
 };
 
 #[test]
@@ -72,3 +75,761 @@ fn test_rate_validation() {
 		.is_err()
 	);
 }
+
+mod floating {
+	use super::*;
+
+	const SOFR: ReferenceRateId = *b"SOFR\0\0\0\0\0\0\0\0\0\0\0\0";
+
+	fn floating_rate(spread: Rate) -> InterestRate<Rate> {
+		InterestRate::Floating {
+			reference: SOFR,
+			spread,
+			compounding: CompoundingSchedule::Secondly,
+		}
+	}
+
+	fn fixed_rate(rate_per_year: Rate) -> InterestRate<Rate> {
+		InterestRate::Fixed {
+			rate_per_year,
+			compounding: CompoundingSchedule::Secondly,
+		}
+	}
+
+	fn set_reference(rate: Option<Rate>) {
+		MockReferenceRates::mock_get(move |_, reference| {
+			assert_eq!(*reference, SOFR);
+			Ok(rate)
+		});
+	}
+
+	fn advance_secs(secs: u64) {
+		Timestamp::set_timestamp(Timestamp::now() + secs * 1000);
+	}
+
+	#[test]
+	fn accrues_reference_plus_spread() {
+		new_test_ext().execute_with(|| {
+			set_reference(Some(Rate::saturating_from_rational(3, 100)));
+			advance_secs(0);
+
+			let floating = floating_rate(Rate::saturating_from_rational(2, 100));
+			let fixed = fixed_rate(Rate::saturating_from_rational(5, 100));
+
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&floating));
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&fixed));
+
+			advance_secs(1000);
+
+			let floating_details = Pallet::<Runtime>::get_rate(&floating).unwrap();
+			let fixed_details = Pallet::<Runtime>::get_rate(&fixed).unwrap();
+			assert!(floating_details.accumulated_rate > One::one());
+			assert_eq!(
+				floating_details.accumulated_rate,
+				fixed_details.accumulated_rate
+			);
+		});
+	}
+
+	#[test]
+	fn follows_reference_changes() {
+		new_test_ext().execute_with(|| {
+			set_reference(Some(Rate::saturating_from_rational(3, 100)));
+			advance_secs(0);
+
+			let floating = floating_rate(Rate::saturating_from_rational(2, 100));
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&floating));
+			let start = Timestamp::now() / 1000;
+
+			advance_secs(1000);
+			set_reference(Some(Rate::saturating_from_rational(8, 100)));
+
+			// The new reference value applies from the block it is observed in, even
+			// without any debt adjusted
+			InterestAccrual::on_idle(System::block_number(), Weight::MAX);
+			advance_secs(1000);
+
+			let per_period =
+				|percent| fixed_rate(Rate::saturating_from_rational(percent, 100)).per_period();
+			let accrued_before_change = Pallet::<Runtime>::calculate_accumulated_rate(
+				per_period(5).unwrap(),
+				&CompoundingSchedule::Secondly,
+				One::one(),
+				start,
+				start + 1000,
+			)
+			.unwrap();
+			let accrued = Pallet::<Runtime>::calculate_accumulated_rate(
+				per_period(10).unwrap(),
+				&CompoundingSchedule::Secondly,
+				accrued_before_change,
+				start + 1000,
+				start + 2000,
+			)
+			.unwrap();
+
+			let details = Pallet::<Runtime>::get_rate(&floating).unwrap();
+			assert_eq!(details.accumulated_rate, accrued);
+			assert_eq!(details.interest_rate_per_period, per_period(10).unwrap());
+
+			// Without reference value, the last known one is kept
+			assert_ok!(Pallet::<Runtime>::accrue_interest_rate(&floating));
+			set_reference(None);
+			advance_secs(1000);
+
+			assert_eq!(
+				Pallet::<Runtime>::get_rate(&floating)
+					.unwrap()
+					.interest_rate_per_period,
+				fixed_rate(Rate::saturating_from_rational(10, 100))
+					.per_period()
+					.unwrap()
+			);
+		});
+	}
+
+	#[test]
+	fn past_rates_follow_reference_changes() {
+		new_test_ext().execute_with(|| {
+			set_reference(Some(Rate::saturating_from_rational(3, 100)));
+			advance_secs(0);
+
+			let floating = floating_rate(Rate::saturating_from_rational(2, 100));
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&floating));
+			let start = Timestamp::now() / 1000;
+
+			advance_secs(1000);
+			set_reference(Some(Rate::saturating_from_rational(8, 100)));
+			InterestAccrual::on_idle(System::block_number(), Weight::MAX);
+			let at_change = Pallet::<Runtime>::get_rate(&floating)
+				.unwrap()
+				.accumulated_rate;
+
+			advance_secs(1000);
+			assert_ok!(Pallet::<Runtime>::accrue_interest_rate(&floating));
+
+			// The change happened within the snapshot period of the creation, but
+			// was snapshotted as well
+			assert_eq!(
+				Pallet::<Runtime>::rate_at(&floating, start + 1500),
+				Ok(Pallet::<Runtime>::calculate_accumulated_rate(
+					fixed_rate(Rate::saturating_from_rational(10, 100))
+						.per_period()
+						.unwrap(),
+					&CompoundingSchedule::Secondly,
+					at_change,
+					start + 1000,
+					start + 1500,
+				)
+				.unwrap())
+			);
+		});
+	}
+
+	#[test]
+	fn reference_without_value() {
+		new_test_ext().execute_with(|| {
+			set_reference(None);
+
+			assert_err!(
+				Pallet::<Runtime>::reference_interest_rate(&floating_rate(Zero::zero())),
+				Error::<Runtime>::ReferenceRateNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn invalid_spread() {
+		new_test_ext().execute_with(|| {
+			set_reference(Some(Rate::saturating_from_rational(3, 100)));
+
+			assert_err!(
+				Pallet::<Runtime>::reference_interest_rate(&floating_rate(
+					Rate::saturating_from_rational(55, 100000)
+				)),
+				Error::<Runtime>::InvalidRate
+			);
+		});
+	}
+
+	#[test]
+	fn resolve_fixed_rate() {
+		new_test_ext().execute_with(|| {
+			set_reference(Some(Rate::saturating_from_rational(3, 100)));
+
+			assert_eq!(
+				Pallet::<Runtime>::fixed_interest_rate(&floating_rate(
+					Rate::saturating_from_rational(2, 100)
+				)),
+				Ok(fixed_rate(Rate::saturating_from_rational(5, 100)))
+			);
+		});
+	}
+
+	#[test]
+	fn unreference() {
+		new_test_ext().execute_with(|| {
+			set_reference(Some(Rate::saturating_from_rational(3, 100)));
+
+			let floating = floating_rate(Rate::saturating_from_rational(2, 100));
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&floating));
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&floating));
+
+			assert_ok!(Pallet::<Runtime>::unreference_interest_rate(&floating));
+			assert_ok!(Pallet::<Runtime>::get_rate(&floating));
+
+			assert_ok!(Pallet::<Runtime>::unreference_interest_rate(&floating));
+			assert_err!(
+				Pallet::<Runtime>::get_rate(&floating),
+				Error::<Runtime>::NoSuchRate
+			);
+		});
+	}
+}
+
+mod compounding {
+	use cfg_primitives::SECONDS_PER_DAY;
+
+	use super::*;
+
+	fn rate(rate_per_year: Rate, compounding: CompoundingSchedule) -> InterestRate<Rate> {
+		InterestRate::Fixed {
+			rate_per_year,
+			compounding,
+		}
+	}
+
+	/// Moves the time to the next multiple of `period`
+	fn advance_to_period_start(period: u64) {
+		let now = Timestamp::now() / 1000;
+		let start = (now / period + 1) * period;
+		Timestamp::set_timestamp(start * 1000);
+	}
+
+	fn advance_secs(secs: u64) {
+		Timestamp::set_timestamp(Timestamp::now() + secs * 1000);
+	}
+
+	fn accumulated_rate(interest_rate: &InterestRate<Rate>) -> Rate {
+		Pallet::<Runtime>::get_rate(interest_rate)
+			.unwrap()
+			.accumulated_rate
+	}
+
+	fn assert_close(a: Rate, b: Rate) {
+		assert!(
+			a.into_inner().abs_diff(b.into_inner()) <= 1000,
+			"{a:?} != {b:?}"
+		);
+	}
+
+	#[test]
+	fn daily_compounds_once_per_day() {
+		new_test_ext().execute_with(|| {
+			// 36% per year is 0.1% per day using actual/360
+			let daily = rate(
+				Rate::saturating_from_rational(36, 100),
+				CompoundingSchedule::Daily,
+			);
+
+			advance_to_period_start(SECONDS_PER_DAY);
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&daily));
+
+			advance_secs(SECONDS_PER_DAY);
+			assert_eq!(
+				accumulated_rate(&daily),
+				Rate::saturating_from_rational(1001, 1000)
+			);
+
+			// An incomplete day accrues linearly
+			advance_secs(SECONDS_PER_DAY / 2);
+			assert_eq!(
+				accumulated_rate(&daily),
+				Rate::saturating_from_rational(1001, 1000)
+					* Rate::saturating_from_rational(10005, 10000)
+			);
+
+			// Completing the day compounds it
+			advance_secs(SECONDS_PER_DAY / 2);
+			assert_close(
+				accumulated_rate(&daily),
+				Rate::saturating_from_rational(1001, 1000)
+					* Rate::saturating_from_rational(1001, 1000),
+			);
+		});
+	}
+
+	#[test]
+	fn monthly_compounds_once_per_30_days() {
+		new_test_ext().execute_with(|| {
+			// 12% per year is 1% per month using 30/360
+			let monthly = rate(
+				Rate::saturating_from_rational(12, 100),
+				CompoundingSchedule::Monthly,
+			);
+
+			advance_to_period_start(30 * SECONDS_PER_DAY);
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&monthly));
+
+			advance_secs(30 * SECONDS_PER_DAY);
+			assert_eq!(
+				accumulated_rate(&monthly),
+				Rate::saturating_from_rational(101, 100)
+			);
+
+			advance_secs(60 * SECONDS_PER_DAY);
+			assert_eq!(
+				accumulated_rate(&monthly),
+				Rate::saturating_from_rational(101, 100)
+					* Rate::saturating_from_rational(101, 100)
+					* Rate::saturating_from_rational(101, 100)
+			);
+		});
+	}
+
+	#[test]
+	fn accrual_does_not_depend_on_update_frequency() {
+		new_test_ext().execute_with(|| {
+			let interest_rate = Rate::saturating_from_rational(36, 100);
+
+			advance_to_period_start(SECONDS_PER_DAY);
+			let daily = rate(interest_rate, CompoundingSchedule::Daily);
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&daily));
+
+			for _ in 0..10 {
+				advance_secs(SECONDS_PER_DAY / 3);
+				assert_ok!(Pallet::<Runtime>::accrue_interest_rate(&daily));
+			}
+
+			let expected = CompoundingSchedule::Daily
+				.accrual_factor(daily.per_period().unwrap(), 0, 10 * SECONDS_PER_DAY / 3)
+				.unwrap();
+
+			assert_close(accumulated_rate(&daily), expected);
+		});
+	}
+
+	#[test]
+	fn rates_are_bucketed_by_compounding() {
+		new_test_ext().execute_with(|| {
+			let interest_rate = Rate::saturating_from_rational(36, 100);
+			let secondly = rate(interest_rate, CompoundingSchedule::Secondly);
+			let daily = rate(interest_rate, CompoundingSchedule::Daily);
+
+			advance_to_period_start(SECONDS_PER_DAY);
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&secondly));
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&daily));
+
+			advance_secs(SECONDS_PER_DAY);
+
+			assert_eq!(
+				accumulated_rate(&secondly),
+				checked_pow(secondly.per_sec().unwrap(), SECONDS_PER_DAY as usize).unwrap()
+			);
+			assert_eq!(
+				accumulated_rate(&daily),
+				Rate::saturating_from_rational(1001, 1000)
+			);
+
+			assert_ok!(Pallet::<Runtime>::unreference_interest_rate(&daily));
+			assert_ok!(Pallet::<Runtime>::get_rate(&secondly));
+			assert_err!(
+				Pallet::<Runtime>::get_rate(&daily),
+				Error::<Runtime>::NoSuchRate
+			);
+		});
+	}
+
+	#[test]
+	fn debt_in_the_past() {
+		new_test_ext().execute_with(|| {
+			let daily = rate(
+				Rate::saturating_from_rational(36, 100),
+				CompoundingSchedule::Daily,
+			);
+
+			advance_to_period_start(SECONDS_PER_DAY);
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&daily));
+			let start = Timestamp::now() / 1000;
+
+			advance_secs(2 * SECONDS_PER_DAY);
+
+			assert_eq!(
+				Pallet::<Runtime>::get_debt(&daily, 1_000_000, start + SECONDS_PER_DAY),
+				Ok(1_001_000)
+			);
+		});
+	}
+}
+
+mod snapshots {
+	use cfg_primitives::SECONDS_PER_DAY;
+
+	use super::*;
+
+	fn daily_rate() -> InterestRate<Rate> {
+		// 36% per year is 0.1% per day using actual/360
+		InterestRate::Fixed {
+			rate_per_year: Rate::saturating_from_rational(36, 100),
+			compounding: CompoundingSchedule::Daily,
+		}
+	}
+
+	/// Moves the time to the start of the next day
+	fn advance_to_day_start() -> u64 {
+		let now = Timestamp::now() / 1000;
+		let start = (now / SECONDS_PER_DAY + 1) * SECONDS_PER_DAY;
+		Timestamp::set_timestamp(start * 1000);
+		start
+	}
+
+	fn advance_secs(secs: u64) {
+		Timestamp::set_timestamp(Timestamp::now() + secs * 1000);
+	}
+
+	#[test]
+	fn past_rates_are_reconstructed() {
+		new_test_ext().execute_with(|| {
+			let rate = daily_rate();
+
+			let start = advance_to_day_start();
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate));
+
+			advance_secs(SECONDS_PER_DAY);
+			let after_one_day = Pallet::<Runtime>::accrue_interest_rate(&rate)
+				.unwrap()
+				.accumulated_rate;
+
+			// Updates within a period do not take snapshots
+			advance_secs(SECONDS_PER_DAY / 4);
+			assert_ok!(Pallet::<Runtime>::accrue_interest_rate(&rate));
+			advance_secs(SECONDS_PER_DAY / 4);
+			assert_ok!(Pallet::<Runtime>::accrue_interest_rate(&rate));
+
+			assert_eq!(Pallet::<Runtime>::rate_at(&rate, start), Ok(One::one()));
+			assert_eq!(
+				Pallet::<Runtime>::rate_at(&rate, start + SECONDS_PER_DAY / 2),
+				Ok(Rate::saturating_from_rational(10005, 10000))
+			);
+			assert_eq!(
+				Pallet::<Runtime>::rate_at(&rate, start + SECONDS_PER_DAY),
+				Ok(after_one_day)
+			);
+
+			// The current moment uses the current rate
+			assert_eq!(
+				Pallet::<Runtime>::rate_at(&rate, Timestamp::now() / 1000),
+				Ok(Pallet::<Runtime>::get_rate(&rate).unwrap().accumulated_rate)
+			);
+		});
+	}
+
+	#[test]
+	fn rates_not_updated_for_several_periods() {
+		new_test_ext().execute_with(|| {
+			let rate = daily_rate();
+
+			let start = advance_to_day_start();
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate));
+
+			advance_secs(2 * SECONDS_PER_DAY);
+			assert_ok!(Pallet::<Runtime>::accrue_interest_rate(&rate));
+
+			// The closest snapshot is the one taken when the rate was created
+			assert_eq!(
+				Pallet::<Runtime>::rate_at(&rate, start + SECONDS_PER_DAY),
+				Ok(Rate::saturating_from_rational(1001, 1000))
+			);
+		});
+	}
+
+	#[test]
+	fn snapshots_are_pruned() {
+		new_test_ext().execute_with(|| {
+			let rate = daily_rate();
+
+			let start = advance_to_day_start();
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate));
+
+			for _ in 0..5 {
+				advance_secs(SECONDS_PER_DAY);
+				assert_ok!(Pallet::<Runtime>::accrue_interest_rate(&rate));
+			}
+
+			// Only the last `MaxRateSnapshots` days are kept
+			assert_err!(
+				Pallet::<Runtime>::rate_at(&rate, start + SECONDS_PER_DAY),
+				Error::<Runtime>::NoRateSnapshot
+			);
+			assert_ok!(Pallet::<Runtime>::rate_at(
+				&rate,
+				start + 3 * SECONDS_PER_DAY
+			));
+		});
+	}
+
+	#[test]
+	fn unknown_rate() {
+		new_test_ext().execute_with(|| {
+			let start = advance_to_day_start();
+			advance_secs(SECONDS_PER_DAY);
+
+			assert_err!(
+				Pallet::<Runtime>::rate_at(&daily_rate(), start),
+				Error::<Runtime>::NoRateSnapshot
+			);
+			assert_err!(
+				Pallet::<Runtime>::rate_at(&daily_rate(), start + SECONDS_PER_DAY),
+				Error::<Runtime>::NoSuchRate
+			);
+		});
+	}
+}
+
+mod lazy_accrual {
+	use super::*;
+
+	fn rate() -> InterestRate<Rate> {
+		InterestRate::Fixed {
+			rate_per_year: Rate::saturating_from_rational(5, 100),
+			compounding: CompoundingSchedule::Secondly,
+		}
+	}
+
+	fn advance_secs(secs: u64) {
+		Timestamp::set_timestamp(Timestamp::now() + secs * 1000);
+	}
+
+	#[test]
+	fn reading_does_not_update() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate()));
+			let stored = Rates::<Runtime>::get();
+
+			advance_secs(1000);
+
+			let details = Pallet::<Runtime>::get_rate(&rate()).unwrap();
+			assert!(details.accumulated_rate > One::one());
+			assert_eq!(details.last_updated, Timestamp::now() / 1000);
+			assert_eq!(Rates::<Runtime>::get(), stored);
+		});
+	}
+
+	#[test]
+	fn accruing_stores_the_read_value() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate()));
+
+			advance_secs(1000);
+
+			let details = Pallet::<Runtime>::get_rate(&rate()).unwrap();
+			assert_eq!(
+				Pallet::<Runtime>::accrue_interest_rate(&rate()),
+				Ok(details.clone())
+			);
+			assert_eq!(Rates::<Runtime>::get().into_inner(), vec![details]);
+		});
+	}
+
+	#[test]
+	fn adjusting_a_debt_accrues_its_rate() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate()));
+			let normalized_debt = Pallet::<Runtime>::do_adjust_normalized_debt(
+				&rate(),
+				0,
+				Adjustment::Increase(1000),
+			)
+			.unwrap();
+
+			advance_secs(1000);
+			let now = Timestamp::now() / 1000;
+			let debt = Pallet::<Runtime>::get_debt(&rate(), normalized_debt, now).unwrap();
+
+			assert_ok!(Pallet::<Runtime>::do_adjust_normalized_debt(
+				&rate(),
+				normalized_debt,
+				Adjustment::Increase(0)
+			));
+
+			assert_eq!(Rates::<Runtime>::get()[0].last_updated, now);
+			assert_eq!(
+				Pallet::<Runtime>::get_debt(&rate(), normalized_debt, now),
+				Ok(debt)
+			);
+		});
+	}
+}
+
+mod negative_rates {
+	use cfg_primitives::{SECONDS_PER_DAY, SECONDS_PER_YEAR};
+
+	use super::*;
+
+	fn negative_rate(rate_per_year: Rate, compounding: CompoundingSchedule) -> InterestRate<Rate> {
+		InterestRate::FixedNegative {
+			rate_per_year,
+			compounding,
+		}
+	}
+
+	fn advance_to_day_start() {
+		let now = Timestamp::now() / 1000;
+		let start = (now / SECONDS_PER_DAY + 1) * SECONDS_PER_DAY;
+		Timestamp::set_timestamp(start * 1000);
+	}
+
+	fn advance_secs(secs: u64) {
+		Timestamp::set_timestamp(Timestamp::now() + secs * 1000);
+	}
+
+	#[test]
+	fn validation() {
+		let secondly = CompoundingSchedule::Secondly;
+
+		assert_ok!(Pallet::<Runtime>::validate_interest_rate(&negative_rate(
+			One::one(),
+			secondly.clone()
+		)));
+		assert_err!(
+			Pallet::<Runtime>::validate_interest_rate(&negative_rate(
+				Rate::saturating_from_rational(10001, 10000),
+				secondly.clone()
+			)),
+			Error::<Runtime>::InvalidRate
+		);
+		assert_err!(
+			Pallet::<Runtime>::validate_interest_rate(&negative_rate(
+				Rate::saturating_from_rational(1, 100000),
+				secondly
+			)),
+			Error::<Runtime>::InvalidRate
+		);
+	}
+
+	#[test]
+	fn debt_decreases() {
+		new_test_ext().execute_with(|| {
+			let rate = negative_rate(
+				Rate::saturating_from_rational(10, 100),
+				CompoundingSchedule::Secondly,
+			);
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate));
+			let normalized_debt = Pallet::<Runtime>::do_adjust_normalized_debt(
+				&rate,
+				0,
+				Adjustment::Increase(1_000_000),
+			)
+			.unwrap();
+
+			advance_secs(SECONDS_PER_YEAR);
+			let now = Timestamp::now() / 1000;
+
+			// Close to 1_000_000 * e^-0.1
+			let debt = Pallet::<Runtime>::get_debt(&rate, normalized_debt, now).unwrap();
+			assert!(debt > 904_800 && debt < 904_900, "{debt}");
+		});
+	}
+
+	#[test]
+	fn daily_decays_once_per_day() {
+		new_test_ext().execute_with(|| {
+			// 0.1% per day
+			let rate = negative_rate(
+				Rate::saturating_from_rational(36, 100),
+				CompoundingSchedule::Daily,
+			);
+			advance_to_day_start();
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate));
+
+			advance_secs(SECONDS_PER_DAY / 2);
+			assert_eq!(
+				Pallet::<Runtime>::get_rate(&rate).unwrap().accumulated_rate,
+				Rate::saturating_from_rational(9995, 10000)
+			);
+
+			advance_secs(SECONDS_PER_DAY / 2);
+			assert_eq!(
+				Pallet::<Runtime>::get_rate(&rate).unwrap().accumulated_rate,
+				Rate::saturating_from_rational(999, 1000)
+			);
+		});
+	}
+
+	#[test]
+	fn bucketed_apart_from_positive_rates() {
+		new_test_ext().execute_with(|| {
+			let per_year = Rate::saturating_from_rational(5, 100);
+			let positive = InterestRate::Fixed {
+				rate_per_year: per_year,
+				compounding: CompoundingSchedule::Secondly,
+			};
+			let negative = negative_rate(per_year, CompoundingSchedule::Secondly);
+
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&positive));
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&negative));
+			assert_eq!(Rates::<Runtime>::get().len(), 2);
+
+			advance_secs(1000);
+			assert!(
+				Pallet::<Runtime>::get_rate(&positive)
+					.unwrap()
+					.accumulated_rate
+					> One::one()
+			);
+			assert!(
+				Pallet::<Runtime>::get_rate(&negative)
+					.unwrap()
+					.accumulated_rate
+					< One::one()
+			);
+		});
+	}
+
+	#[test]
+	fn decrease_beyond_decayed_debt() {
+		new_test_ext().execute_with(|| {
+			let rate = negative_rate(
+				Rate::saturating_from_rational(50, 100),
+				CompoundingSchedule::Secondly,
+			);
+			assert_ok!(Pallet::<Runtime>::reference_interest_rate(&rate));
+			let normalized_debt =
+				Pallet::<Runtime>::do_adjust_normalized_debt(&rate, 0, Adjustment::Increase(1000))
+					.unwrap();
+
+			advance_secs(SECONDS_PER_DAY);
+
+			assert_err!(
+				Pallet::<Runtime>::do_adjust_normalized_debt(
+					&rate,
+					normalized_debt,
+					Adjustment::Decrease(1000)
+				),
+				Error::<Runtime>::DebtAdjustmentFailed
+			);
+		});
+	}
+
+	#[test]
+	fn signed_adding_and_subtracting_crosses_zero() {
+		let fixed = |rate_per_year| InterestRate::Fixed {
+			rate_per_year,
+			compounding: CompoundingSchedule::Secondly,
+		};
+		let negative = |rate_per_year| negative_rate(rate_per_year, CompoundingSchedule::Secondly);
+		let rate = |percent| Rate::saturating_from_rational(percent, 100);
+
+		assert_eq!(
+			fixed(rate(5)).ensure_sub(rate(8)),
+			Err(ArithmeticError::Underflow)
+		);
+		assert_eq!(
+			fixed(rate(5)).ensure_signed_sub(rate(8)),
+			Ok(negative(rate(3)))
+		);
+		assert_eq!(negative(rate(3)).ensure_sub(rate(2)), Ok(negative(rate(5))));
+		assert_eq!(negative(rate(5)).ensure_add(rate(2)), Ok(negative(rate(3))));
+		assert_eq!(negative(rate(3)).ensure_add(rate(8)), Ok(fixed(rate(5))));
+	}
+}
@@ -15,10 +15,25 @@ use frame_support::weights::Weight;
 
 pub trait WeightInfo {
 	fn calculate_accumulated_rate(n: u32) -> Weight;
+	fn calculate_periodic_accumulated_rate(n: u32) -> Weight;
+	fn get_rate(n: u32) -> Weight;
+	fn refresh_floating_rates(n: u32) -> Weight;
 }
 
 impl WeightInfo for () {
 	fn calculate_accumulated_rate(_: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn calculate_periodic_accumulated_rate(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn get_rate(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn refresh_floating_rates(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
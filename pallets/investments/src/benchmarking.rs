@@ -151,6 +151,29 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn transfer_orders() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		crate::mock::configure_accountant_mock();
+
+		let caller: T::AccountId = whitelisted_caller();
+		let recipient: T::AccountId = account("recipient", 0, 0);
+		let investment_id = Helper::<T>::get_investment_id();
+		let payment_currency = T::Accountant::info(investment_id)?.payment_currency;
+		let tranche_currency: CurrencyOf<T> = investment_id.into();
+
+		T::Tokens::mint_into(payment_currency, &caller, 100_000_000_000_000u128.into())?;
+		T::Tokens::mint_into(tranche_currency, &caller, 100_000_000_000_000u128.into())?;
+
+		Pallet::<T>::update_investment(&caller, investment_id, 1u32.into())?;
+		Pallet::<T>::update_redemption(&caller, investment_id, 1u32.into())?;
+
+		#[extrinsic_call]
+		transfer_orders(RawOrigin::Signed(caller), investment_id, recipient);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::mock::TestExternalitiesBuilder::build(),
@@ -14,6 +14,12 @@
 //! # Investments Pallet
 //!
 //! Provides orders for assets and allows user to collect these orders.
+//!
+//! Investors can transfer their orders of an investment, including the
+//! fulfilled parts not yet collected, to another investor of the same
+//! investment. Both investors must satisfy the `PreConditions` of the
+//! investment, which allows secondary trades of positions before collecting
+//! them.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use cfg_primitives::OrderId;
@@ -273,20 +279,30 @@ pub mod pallet {
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		/// Fulfilled orders were collected.
-		/// [investment_id, who, collected_orders, Collection, CollectOutcome]
+		/// [investment_id, who, collected_orders, fulfillments, Collection,
+		/// CollectOutcome]
+		///
+		/// `fulfillments` contains the fulfillment ratio and execution price
+		/// of each collected order, in the same order as `processed_orders`.
 		InvestOrdersCollected {
 			investment_id: T::InvestmentId,
 			who: T::AccountId,
 			processed_orders: Vec<OrderId>,
+			fulfillments: Vec<FulfillmentWithPrice<T::BalanceRatio>>,
 			collection: InvestCollection<T::Amount>,
 			outcome: CollectOutcome,
 		},
 		/// Fulfilled orders were collected.
-		/// [investment_id, who, collected_orders, Collection, CollectOutcome]
+		/// [investment_id, who, collected_orders, fulfillments, Collection,
+		/// CollectOutcome]
+		///
+		/// `fulfillments` contains the fulfillment ratio and execution price
+		/// of each collected order, in the same order as `processed_orders`.
 		RedeemOrdersCollected {
 			investment_id: T::InvestmentId,
 			who: T::AccountId,
 			processed_orders: Vec<OrderId>,
+			fulfillments: Vec<FulfillmentWithPrice<T::BalanceRatio>>,
 			collection: RedeemCollection<T::Amount>,
 			outcome: CollectOutcome,
 		},
@@ -356,6 +372,26 @@ pub mod pallet {
 			who: T::AccountId,
 			investment_id: T::InvestmentId,
 		},
+		/// An invest order, including its not yet collected fulfillments, was
+		/// transferred to another investor. [investment_id, order_id, from,
+		/// to, amount]
+		InvestOrderTransferred {
+			investment_id: T::InvestmentId,
+			submitted_at: OrderId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Amount,
+		},
+		/// A redeem order, including its not yet collected fulfillments, was
+		/// transferred to another investor. [investment_id, order_id, from,
+		/// to, amount]
+		RedeemOrderTransferred {
+			investment_id: T::InvestmentId,
+			submitted_at: OrderId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: T::Amount,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -387,6 +423,13 @@ pub mod pallet {
 		NoActiveInvestOrder,
 		/// User has currently no redeem orders active and can not collect
 		NoActiveRedeemOrder,
+		/// User has neither an invest nor a redeem order to transfer
+		NoOrderToTransfer,
+		/// Orders can not be transferred to the account owning them
+		TransferToSelf,
+		/// The recipient already has an order of the transferred kind for
+		/// this investment and must collect or cancel it first
+		RecipientHasOrder,
 	}
 
 	#[pallet::call]
@@ -488,6 +531,25 @@ pub mod pallet {
 
 			Self::do_collect_redeem(who, investment_id)
 		}
+
+		/// Transfer the invest and redeem orders of the caller for the given
+		/// investment to another investor, including the fulfilled amounts
+		/// which were not yet collected.
+		///
+		/// Both the caller and the recipient must satisfy the pre-conditions
+		/// of the investment, and the recipient must not have an order of the
+		/// transferred kind for it.
+		#[pallet::weight(T::WeightInfo::transfer_orders())]
+		#[pallet::call_index(6)]
+		pub fn transfer_orders(
+			origin: OriginFor<T>,
+			investment_id: T::InvestmentId,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_transfer_orders(who, to, investment_id)
+		}
 	}
 }
 
@@ -609,6 +671,90 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	pub(crate) fn do_transfer_orders(
+		from: T::AccountId,
+		to: T::AccountId,
+		investment_id: T::InvestmentId,
+	) -> DispatchResult {
+		ensure!(from != to, Error::<T>::TransferToSelf);
+
+		let _ = T::Accountant::info(investment_id).map_err(|_| Error::<T>::UnknownInvestment)?;
+		let invest_order = InvestOrders::<T>::get(&from, investment_id);
+		let redeem_order = RedeemOrders::<T>::get(&from, investment_id);
+
+		ensure!(
+			invest_order.is_some() || redeem_order.is_some(),
+			Error::<T>::NoOrderToTransfer
+		);
+
+		if let Some(order) = &invest_order {
+			ensure!(
+				!InvestOrders::<T>::contains_key(&to, investment_id),
+				Error::<T>::RecipientHasOrder
+			);
+
+			// Frozen investors must neither hand over nor receive positions
+			T::PreConditions::check(OrderType::Investment {
+				who: from.clone(),
+				investment_id,
+				amount: Default::default(),
+			})?;
+			T::PreConditions::check(OrderType::Investment {
+				who: to.clone(),
+				investment_id,
+				amount: order.amount(),
+			})?;
+		}
+
+		if let Some(order) = &redeem_order {
+			ensure!(
+				!RedeemOrders::<T>::contains_key(&to, investment_id),
+				Error::<T>::RecipientHasOrder
+			);
+
+			T::PreConditions::check(OrderType::Redemption {
+				who: from.clone(),
+				investment_id,
+				amount: Default::default(),
+			})?;
+			T::PreConditions::check(OrderType::Redemption {
+				who: to.clone(),
+				investment_id,
+				amount: order.amount(),
+			})?;
+		}
+
+		// NOTE: The ordered amounts stay in the investment account, so the total
+		// orders are not affected by moving the orders between investors.
+		if let Some(order) = invest_order {
+			InvestOrders::<T>::remove(&from, investment_id);
+			InvestOrders::<T>::insert(&to, investment_id, &order);
+
+			Self::deposit_event(Event::InvestOrderTransferred {
+				investment_id,
+				submitted_at: order.submitted_at(),
+				from: from.clone(),
+				to: to.clone(),
+				amount: order.amount(),
+			});
+		}
+
+		if let Some(order) = redeem_order {
+			RedeemOrders::<T>::remove(&from, investment_id);
+			RedeemOrders::<T>::insert(&to, investment_id, &order);
+
+			Self::deposit_event(Event::RedeemOrderTransferred {
+				investment_id,
+				submitted_at: order.submitted_at(),
+				from,
+				to,
+				amount: order.amount(),
+			});
+		}
+
+		Ok(())
+	}
+
 	fn rm_empty(amount: T::Amount, storage_order: &mut Option<OrderOf<T>>, on_not_empty: Event<T>) {
 		if !amount.is_zero() {
 			Self::deposit_event(on_not_empty);
@@ -656,6 +802,7 @@ impl<T: Config> Pallet<T> {
 
 				let mut collection = InvestCollection::<T::Amount>::from_order(order);
 				let mut collected_ids = Vec::new();
+				let mut fulfillments = Vec::new();
 				let cur_order_id = InvestOrderId::<T>::get(investment_id);
 				let last_processed_order_id = min(
 					order
@@ -683,6 +830,7 @@ impl<T: Config> Pallet<T> {
 						Pallet::<T>::acc_payout_invest(&mut collection, &fulfillment)?;
 					Pallet::<T>::acc_remaining_invest(&mut collection, &fulfillment)?;
 					collected_ids.push(order_id);
+					fulfillments.push(fulfillment);
 
 					amount_payment.ensure_add_assign(
 						fulfillment
@@ -725,6 +873,7 @@ impl<T: Config> Pallet<T> {
 					investment_id,
 					who: who.clone(),
 					processed_orders: collected_ids,
+					fulfillments,
 					collection,
 					outcome: if last_processed_order_id == cur_order_id {
 						CollectOutcome::FullyCollected
@@ -778,6 +927,7 @@ impl<T: Config> Pallet<T> {
 
 				let mut collection = RedeemCollection::<T::Amount>::from_order(order);
 				let mut collected_ids = Vec::new();
+				let mut fulfillments = Vec::new();
 				let cur_order_id = RedeemOrderId::<T>::get(investment_id);
 				let last_processed_order_id = min(
 					order
@@ -805,6 +955,7 @@ impl<T: Config> Pallet<T> {
 						Pallet::<T>::acc_payout_redeem(&mut collection, &fulfillment)?;
 					Pallet::<T>::acc_remaining_redeem(&mut collection, &fulfillment)?;
 					collected_ids.push(order_id);
+					fulfillments.push(fulfillment);
 
 					// TODO(@mustermeiszer): We actually want the reciprocal without rounding, is
 					// this sufficient or should we use something like
@@ -856,6 +1007,7 @@ impl<T: Config> Pallet<T> {
 					investment_id,
 					who: who.clone(),
 					processed_orders: collected_ids,
+					fulfillments,
 					collection,
 					outcome: if last_processed_order_id == cur_order_id {
 						CollectOutcome::FullyCollected
@@ -987,6 +987,7 @@ fn fulfillment_partially_works_low_price() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorA::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![INVEST_FULFILLMENT],
 					collection: InvestCollection {
 						payout_investment_invest: PRICE
 							.reciprocal_floor()
@@ -1084,6 +1085,7 @@ fn fulfillment_partially_works_low_price() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorB::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![INVEST_FULFILLMENT],
 					collection: InvestCollection {
 						payout_investment_invest: PRICE
 							.reciprocal_floor()
@@ -1212,6 +1214,7 @@ fn fulfillment_partially_works_low_price() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderA::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![REDEEM_FULFILLMENT],
 					collection: RedeemCollection {
 						payout_investment_redeem: PRICE
 							.checked_mul_int_floor(
@@ -1675,6 +1678,7 @@ fn fulfillment_partially_works_high_price() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorA::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![INVEST_FULFILLMENT],
 					collection: InvestCollection {
 						payout_investment_invest: PRICE
 							.reciprocal_floor()
@@ -1772,6 +1776,7 @@ fn fulfillment_partially_works_high_price() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorB::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![INVEST_FULFILLMENT],
 					collection: InvestCollection {
 						payout_investment_invest: PRICE
 							.reciprocal_floor()
@@ -1900,6 +1905,7 @@ fn fulfillment_partially_works_high_price() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderA::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![REDEEM_FULFILLMENT],
 					collection: RedeemCollection {
 						payout_investment_redeem: PRICE
 							.checked_mul_int_floor(
@@ -2248,6 +2254,7 @@ fn fulfillment_of_zero_works() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorA::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![ZERO_FULFILL],
 					collection: InvestCollection {
 						payout_investment_invest: 0,
 						remaining_investment_invest: SINGLE_INVEST_AMOUNT
@@ -2293,6 +2300,7 @@ fn fulfillment_of_zero_works() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorB::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![ZERO_FULFILL],
 					collection: InvestCollection {
 						payout_investment_invest: 0,
 						remaining_investment_invest: SINGLE_INVEST_AMOUNT
@@ -2338,6 +2346,7 @@ fn fulfillment_of_zero_works() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorC::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![ZERO_FULFILL],
 					collection: InvestCollection {
 						payout_investment_invest: 0,
 						remaining_investment_invest: SINGLE_INVEST_AMOUNT
@@ -2391,6 +2400,7 @@ fn fulfillment_of_zero_works() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderA::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![ZERO_FULFILL],
 					collection: RedeemCollection {
 						payout_investment_redeem: 0,
 						remaining_investment_redeem: SINGLE_REDEEM_AMOUNT
@@ -2436,6 +2446,7 @@ fn fulfillment_of_zero_works() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderB::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![ZERO_FULFILL],
 					collection: RedeemCollection {
 						payout_investment_redeem: 0,
 						remaining_investment_redeem: SINGLE_REDEEM_AMOUNT
@@ -2481,6 +2492,7 @@ fn fulfillment_of_zero_works() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderC::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![ZERO_FULFILL],
 					collection: RedeemCollection {
 						payout_investment_redeem: 0,
 						remaining_investment_redeem: SINGLE_REDEEM_AMOUNT
@@ -2592,6 +2604,7 @@ fn collecting_fully_works() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorA::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![FULL_FULFILL],
 					collection: InvestCollection {
 						payout_investment_invest: invest_return(SINGLE_INVEST_AMOUNT_A),
 						remaining_investment_invest: 0
@@ -2630,6 +2643,7 @@ fn collecting_fully_works() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorB::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![FULL_FULFILL],
 					collection: InvestCollection {
 						payout_investment_invest: invest_return(SINGLE_INVEST_AMOUNT_B),
 						remaining_investment_invest: 0
@@ -2668,6 +2682,7 @@ fn collecting_fully_works() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorC::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![FULL_FULFILL],
 					collection: InvestCollection {
 						payout_investment_invest: invest_return(SINGLE_INVEST_AMOUNT_C),
 						remaining_investment_invest: 0
@@ -2716,6 +2731,7 @@ fn collecting_fully_works() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderA::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![FULL_FULFILL],
 					collection: RedeemCollection {
 						payout_investment_redeem: redeem_return(SINGLE_REDEEM_AMOUNT_A),
 						remaining_investment_redeem: 0
@@ -2754,6 +2770,7 @@ fn collecting_fully_works() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderB::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![FULL_FULFILL],
 					collection: RedeemCollection {
 						payout_investment_redeem: redeem_return(SINGLE_REDEEM_AMOUNT_B),
 						remaining_investment_redeem: 0,
@@ -2792,6 +2809,7 @@ fn collecting_fully_works() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderC::get(),
 					processed_orders: vec![0],
+					fulfillments: vec![FULL_FULFILL],
 					collection: RedeemCollection {
 						payout_investment_redeem: redeem_return(SINGLE_REDEEM_AMOUNT_C),
 						remaining_investment_redeem: 0
@@ -2856,6 +2874,7 @@ fn collecting_over_max_works() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorA::get(),
 					processed_orders: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+					fulfillments: vec![PARTIAL_FULFILL; 10],
 					collection: InvestCollection {
 						payout_investment_invest: 23999169253290529658,
 						remaining_investment_invest: 5368709120000000000
@@ -2881,6 +2900,7 @@ fn collecting_over_max_works() {
 					investment_id: INVESTMENT_0_0,
 					who: InvestorA::get(),
 					processed_orders: vec![10],
+					fulfillments: vec![FULL_FULFILL],
 					collection: InvestCollection {
 						payout_investment_invest: 2886866059711075437,
 						remaining_investment_invest: 0
@@ -2911,6 +2931,7 @@ fn collecting_over_max_works() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderA::get(),
 					processed_orders: vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+					fulfillments: vec![PARTIAL_FULFILL; 10],
 					collection: RedeemCollection {
 						payout_investment_redeem: 83000878263402985078,
 						remaining_investment_redeem: 5368709120000000000
@@ -2936,6 +2957,7 @@ fn collecting_over_max_works() {
 					investment_id: INVESTMENT_0_0,
 					who: TrancheHolderA::get(),
 					processed_orders: vec![10],
+					fulfillments: vec![FULL_FULFILL],
 					collection: RedeemCollection {
 						payout_investment_redeem: 9984196363462686568,
 						remaining_investment_redeem: 0
@@ -2976,3 +2998,128 @@ fn collecting_redemption_without_preconditions_success() {
 		),);
 	})
 }
+
+#[test]
+fn transfer_orders_moves_uncollected_positions() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		let invest_amount = 50 * CURRENCY;
+		let redeem_amount = 30 * CURRENCY;
+
+		assert_ok!(Investments::update_invest_order(
+			RuntimeOrigin::signed(InvestorA::get()),
+			INVESTMENT_0_0,
+			invest_amount,
+		));
+		assert_ok!(Investments::update_redeem_order(
+			RuntimeOrigin::signed(TrancheHolderA::get()),
+			INVESTMENT_0_0,
+			redeem_amount,
+		));
+		assert_ok!(fulfill_invest_x(fulfillment_of(
+			Perquintill::from_percent(50),
+			Quantity::one()
+		)));
+
+		assert_ok!(Investments::transfer_orders(
+			RuntimeOrigin::signed(InvestorA::get()),
+			INVESTMENT_0_0,
+			InvestorD::get(),
+		));
+		assert_eq!(
+			last_event(),
+			Event::InvestOrderTransferred {
+				investment_id: INVESTMENT_0_0,
+				submitted_at: 0,
+				from: InvestorA::get(),
+				to: InvestorD::get(),
+				amount: invest_amount,
+			}
+			.into()
+		);
+
+		assert_ok!(Investments::transfer_orders(
+			RuntimeOrigin::signed(TrancheHolderA::get()),
+			INVESTMENT_0_0,
+			InvestorD::get(),
+		));
+		assert_eq!(
+			last_event(),
+			Event::RedeemOrderTransferred {
+				investment_id: INVESTMENT_0_0,
+				submitted_at: 0,
+				from: TrancheHolderA::get(),
+				to: InvestorD::get(),
+				amount: redeem_amount,
+			}
+			.into()
+		);
+
+		assert_eq!(
+			InvestOrders::<Runtime>::get(InvestorA::get(), INVESTMENT_0_0),
+			None
+		);
+		assert_eq!(
+			RedeemOrders::<Runtime>::get(TrancheHolderA::get(), INVESTMENT_0_0),
+			None
+		);
+		assert_eq!(
+			RedeemOrders::<Runtime>::get(InvestorD::get(), INVESTMENT_0_0),
+			Some(Order::new(redeem_amount, 0))
+		);
+
+		// The recipient collects the fulfilled part of the transferred order
+		assert_ok!(Investments::collect_investments(
+			RuntimeOrigin::signed(InvestorD::get()),
+			INVESTMENT_0_0
+		));
+		assert_eq!(
+			free_balance_of(InvestorD::get(), INVESTMENT_0_0.into()),
+			invest_amount / 2
+		);
+		assert_eq!(
+			InvestOrders::<Runtime>::get(InvestorD::get(), INVESTMENT_0_0),
+			Some(Order::new(invest_amount / 2, 1))
+		);
+	})
+}
+
+#[test]
+fn transfer_orders_fails() {
+	TestExternalitiesBuilder::build().execute_with(|| {
+		assert_noop!(
+			Investments::transfer_orders(
+				RuntimeOrigin::signed(InvestorA::get()),
+				INVESTMENT_0_0,
+				InvestorA::get(),
+			),
+			Error::<Runtime>::TransferToSelf
+		);
+		assert_noop!(
+			Investments::transfer_orders(
+				RuntimeOrigin::signed(InvestorA::get()),
+				INVESTMENT_0_0,
+				InvestorD::get(),
+			),
+			Error::<Runtime>::NoOrderToTransfer
+		);
+
+		assert_ok!(invest_x_per_investor(50 * CURRENCY));
+
+		assert_noop!(
+			Investments::transfer_orders(
+				RuntimeOrigin::signed(InvestorA::get()),
+				INVESTMENT_0_0,
+				InvestorB::get(),
+			),
+			Error::<Runtime>::RecipientHasOrder
+		);
+		assert_noop!(
+			Investments::transfer_orders(
+				RuntimeOrigin::signed(InvestorA::get()),
+				INVESTMENT_0_0,
+				NOT_INVESTOR,
+			),
+			ERR_PRE_CONDITION
+		);
+	})
+}
@@ -18,6 +18,7 @@ pub trait WeightInfo {
 	fn update_redeem_order() -> Weight;
 	fn collect_investments(n: u32) -> Weight;
 	fn collect_redemptions(n: u32) -> Weight;
+	fn transfer_orders() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -36,4 +37,8 @@ impl WeightInfo for () {
 	fn collect_redemptions(_: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn transfer_orders() -> Weight {
+		Weight::zero()
+	}
 }
@@ -0,0 +1,617 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+//! # Investor Rewards Pallet
+//!
+//! Incentive programs distributing a reward currency to the holders of a
+//! tranche token, proportionally to the amount they hold and for how long they
+//! hold it.
+//!
+//! A pool admin creates a program for a tranche of the pool, funding it upfront
+//! with the rewards of the whole program period. Rewards are released linearly
+//! between the start and the end of the program.
+//!
+//! Tranche token holders join a program by staking their tokens, which stay in
+//! their account but are locked while staked. Released rewards are split among
+//! the stakers proportionally to their stake. The rewards per staked token are
+//! accumulated in a checkpoint each time the program is touched, so no
+//! iteration per block or per staker is required.
+//!
+//! A pool has at most one active program. The pool admin closes the program
+//! once it ended, or earlier to stop it, getting back the rewards that were not
+//! released or were released while nobody was staking. Stakers can always
+//! unstake and claim their rewards, even after the program is closed.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use cfg_traits::Seconds;
+use frame_support::pallet_prelude::*;
+pub use pallet::*;
+use sp_runtime::FixedU128;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// Identifier of a reward program.
+pub type ProgramId = u32;
+
+/// A reward program for the holders of a tranche token.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Program<AccountId, PoolId, TrancheId, CurrencyId, Balance> {
+	/// The pool of the tranche.
+	pub pool_id: PoolId,
+
+	/// The tranche whose token holders are rewarded.
+	pub tranche_id: TrancheId,
+
+	/// The currency the rewards are paid in.
+	pub reward_currency: CurrencyId,
+
+	/// The amount of rewards released per second.
+	pub reward_per_sec: Balance,
+
+	/// When rewards start being released.
+	pub start: Seconds,
+
+	/// When rewards stop being released.
+	pub end: Seconds,
+
+	/// The account that funded the program, receiving the refunds.
+	pub funder: AccountId,
+
+	/// The amount funded and not refunded yet.
+	pub funded: Balance,
+
+	/// The amount of rewards released to stakers.
+	pub distributed: Balance,
+
+	/// The total amount of tranche tokens staked.
+	pub total_staked: Balance,
+
+	/// The rewards released per staked token since the program start.
+	pub reward_per_token: FixedU128,
+
+	/// Time until which rewards were accounted in `reward_per_token`.
+	pub last_update: Seconds,
+}
+
+/// The stake of an account in a program.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct Stake<Balance> {
+	/// The amount of tranche tokens staked.
+	pub amount: Balance,
+
+	/// The rewards per staked token when the stake was last settled.
+	pub reward_per_token_paid: FixedU128,
+
+	/// Rewards settled but not claimed yet.
+	pub pending: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use cfg_traits::{PoolInspect, PreConditions, TimeAsSecs};
+	use frame_support::{
+		traits::{tokens, LockIdentifier},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use orml_traits::{MultiCurrency, MultiLockableCurrency};
+	use sp_runtime::{
+		traits::{
+			AccountIdConversion, EnsureAdd, EnsureAddAssign, EnsureMul, EnsureSub, EnsureSubAssign,
+			Zero,
+		},
+		ArithmeticError, FixedPointNumber, FixedPointOperand,
+	};
+
+	use super::*;
+
+	pub type ProgramOf<T> = Program<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::PoolId,
+		<T as Config>::TrancheId,
+		<T as Config>::CurrencyId,
+		<T as Config>::Balance,
+	>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		type Balance: tokens::Balance + FixedPointOperand + From<Seconds>;
+
+		type PoolId: Member + Parameter + Copy + MaxEncodedLen;
+
+		type TrancheId: Member + Parameter + Copy + MaxEncodedLen;
+
+		type CurrencyId: Member
+			+ Parameter
+			+ Copy
+			+ MaxEncodedLen
+			+ From<(Self::PoolId, Self::TrancheId)>;
+
+		/// The tokens used to lock the stakes and to pay the rewards.
+		type Tokens: MultiLockableCurrency<
+			Self::AccountId,
+			CurrencyId = Self::CurrencyId,
+			Balance = Self::Balance,
+		>;
+
+		/// The source of truth for pool and tranche existence.
+		type Pools: PoolInspect<
+			Self::AccountId,
+			Self::CurrencyId,
+			PoolId = Self::PoolId,
+			TrancheId = Self::TrancheId,
+		>;
+
+		/// Used to verify pool admin permissions.
+		type IsPoolAdmin: PreConditions<(Self::AccountId, Self::PoolId), Result = bool>;
+
+		/// Fetching method for the time of the current block.
+		type Time: TimeAsSecs;
+
+		/// Identifier of this pallet, used to derive the account of each
+		/// program holding its rewards.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The identifier of the next program.
+	#[pallet::storage]
+	pub type NextProgramId<T: Config> = StorageValue<_, ProgramId, ValueQuery>;
+
+	#[pallet::storage]
+	pub type Programs<T: Config> = StorageMap<_, Twox64Concat, ProgramId, ProgramOf<T>>;
+
+	/// The program of each pool that is not closed yet.
+	#[pallet::storage]
+	pub type ActiveProgram<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, ProgramId>;
+
+	#[pallet::storage]
+	pub type Stakes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProgramId,
+		Blake2_128Concat,
+		T::AccountId,
+		Stake<T::Balance>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A program was created and funded.
+		ProgramCreated {
+			program_id: ProgramId,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			reward_currency: T::CurrencyId,
+			reward_per_sec: T::Balance,
+			start: Seconds,
+			end: Seconds,
+		},
+
+		/// A program was closed, refunding the rewards not distributed.
+		ProgramClosed {
+			program_id: ProgramId,
+			refunded: T::Balance,
+		},
+
+		/// An account staked tranche tokens in a program.
+		Staked {
+			program_id: ProgramId,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+
+		/// An account unstaked tranche tokens from a program.
+		Unstaked {
+			program_id: ProgramId,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+
+		/// An account claimed its rewards of a program.
+		Claimed {
+			program_id: ProgramId,
+			who: T::AccountId,
+			amount: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The tranche does not exist.
+		TrancheNotFound,
+
+		/// The program does not exist.
+		ProgramNotFound,
+
+		/// The pool already has a program that is not closed.
+		ProgramAlreadyActive,
+
+		/// The program was closed.
+		ProgramClosed,
+
+		/// The program already ended.
+		ProgramEnded,
+
+		/// The program period is not in the future or is empty.
+		InvalidPeriod,
+
+		/// The reward rate must be greater than zero.
+		InvalidRewardRate,
+
+		/// The account does not hold enough unstaked tranche tokens.
+		InsufficientBalance,
+
+		/// The account did not stake enough tranche tokens.
+		InsufficientStake,
+
+		/// The account has no rewards to claim.
+		NothingToClaim,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Creates a program rewarding the holders of a tranche token between
+		/// `start` and `end`. The caller funds the program with the rewards of
+		/// the whole period.
+		///
+		/// The caller must be admin of the pool.
+		#[pallet::weight(T::WeightInfo::create_program())]
+		#[pallet::call_index(0)]
+		pub fn create_program(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			reward_currency: T::CurrencyId,
+			reward_per_sec: T::Balance,
+			start: Seconds,
+			end: Seconds,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who.clone(), pool_id)),
+				DispatchError::BadOrigin
+			);
+			ensure!(
+				T::Pools::tranche_exists(pool_id, tranche_id),
+				Error::<T>::TrancheNotFound
+			);
+			ensure!(
+				!ActiveProgram::<T>::contains_key(pool_id),
+				Error::<T>::ProgramAlreadyActive
+			);
+			ensure!(
+				start >= T::Time::now() && end > start,
+				Error::<T>::InvalidPeriod
+			);
+			ensure!(!reward_per_sec.is_zero(), Error::<T>::InvalidRewardRate);
+
+			let funded = reward_per_sec.ensure_mul(end.ensure_sub(start)?.into())?;
+
+			let program_id = NextProgramId::<T>::mutate(|next| {
+				let program_id = *next;
+				*next = next.ensure_add(1)?;
+				Ok::<_, ArithmeticError>(program_id)
+			})?;
+
+			T::Tokens::transfer(
+				reward_currency,
+				&who,
+				&Self::program_account(program_id),
+				funded,
+			)?;
+
+			Programs::<T>::insert(
+				program_id,
+				Program {
+					pool_id,
+					tranche_id,
+					reward_currency,
+					reward_per_sec,
+					start,
+					end,
+					funder: who,
+					funded,
+					distributed: Zero::zero(),
+					total_staked: Zero::zero(),
+					reward_per_token: FixedU128::zero(),
+					last_update: start,
+				},
+			);
+			ActiveProgram::<T>::insert(pool_id, program_id);
+
+			Self::deposit_event(Event::ProgramCreated {
+				program_id,
+				pool_id,
+				tranche_id,
+				reward_currency,
+				reward_per_sec,
+				start,
+				end,
+			});
+
+			Ok(())
+		}
+
+		/// Closes the active program of the pool. If the program did not end
+		/// yet, it ends now. The rewards not distributed to stakers are
+		/// refunded to the funder.
+		///
+		/// The caller must be admin of the pool.
+		#[pallet::weight(T::WeightInfo::close_program())]
+		#[pallet::call_index(1)]
+		pub fn close_program(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				DispatchError::BadOrigin
+			);
+
+			let program_id =
+				ActiveProgram::<T>::take(pool_id).ok_or(Error::<T>::ProgramNotFound)?;
+
+			let refunded = Programs::<T>::try_mutate(program_id, |program| {
+				let program = program.as_mut().ok_or(Error::<T>::ProgramNotFound)?;
+
+				Self::checkpoint(program)?;
+
+				let now = T::Time::now().max(program.start);
+				if now < program.end {
+					program.end = now;
+				}
+
+				let refunded = program.funded.ensure_sub(program.distributed)?;
+				program.funded = program.distributed;
+
+				T::Tokens::transfer(
+					program.reward_currency,
+					&Self::program_account(program_id),
+					&program.funder,
+					refunded,
+				)?;
+
+				Ok::<_, DispatchError>(refunded)
+			})?;
+
+			Self::deposit_event(Event::ProgramClosed {
+				program_id,
+				refunded,
+			});
+
+			Ok(())
+		}
+
+		/// Stakes tranche tokens in a program. The tokens are locked in the
+		/// account of the caller until unstaked.
+		#[pallet::weight(T::WeightInfo::stake())]
+		#[pallet::call_index(2)]
+		pub fn stake(
+			origin: OriginFor<T>,
+			program_id: ProgramId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::update_stake(program_id, &who, |program, stake| {
+				ensure!(
+					ActiveProgram::<T>::get(program.pool_id) == Some(program_id),
+					Error::<T>::ProgramClosed
+				);
+				ensure!(T::Time::now() < program.end, Error::<T>::ProgramEnded);
+
+				stake.amount.ensure_add_assign(amount)?;
+				program.total_staked.ensure_add_assign(amount)?;
+
+				let currency = (program.pool_id, program.tranche_id).into();
+				ensure!(
+					T::Tokens::free_balance(currency, &who) >= stake.amount,
+					Error::<T>::InsufficientBalance
+				);
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Staked {
+				program_id,
+				who,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Unstakes tranche tokens from a program, unlocking them.
+		#[pallet::weight(T::WeightInfo::unstake())]
+		#[pallet::call_index(3)]
+		pub fn unstake(
+			origin: OriginFor<T>,
+			program_id: ProgramId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::update_stake(program_id, &who, |program, stake| {
+				stake.amount = stake
+					.amount
+					.ensure_sub(amount)
+					.map_err(|_| Error::<T>::InsufficientStake)?;
+				program.total_staked.ensure_sub_assign(amount)?;
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::Unstaked {
+				program_id,
+				who,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Claims the rewards of the caller in a program.
+		#[pallet::weight(T::WeightInfo::claim())]
+		#[pallet::call_index(4)]
+		pub fn claim(origin: OriginFor<T>, program_id: ProgramId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut amount = Zero::zero();
+			Self::update_stake(program_id, &who, |program, stake| {
+				amount = sp_std::mem::take(&mut stake.pending);
+				ensure!(!amount.is_zero(), Error::<T>::NothingToClaim);
+
+				T::Tokens::transfer(
+					program.reward_currency,
+					&Self::program_account(program_id),
+					&who,
+					amount,
+				)
+			})?;
+
+			Self::deposit_event(Event::Claimed {
+				program_id,
+				who,
+				amount,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the rewards of a program.
+		pub fn program_account(program_id: ProgramId) -> T::AccountId {
+			T::PalletId::get().into_sub_account_truncating(program_id)
+		}
+
+		/// The identifier of the lock of the stakes of a program.
+		pub fn lock_id(program_id: ProgramId) -> LockIdentifier {
+			let mut id = *b"invr\0\0\0\0";
+			id[4..].copy_from_slice(&program_id.to_le_bytes());
+			id
+		}
+
+		/// The rewards of an account not claimed yet, including the ones
+		/// released since the last checkpoint.
+		pub fn pending_rewards(
+			program_id: ProgramId,
+			who: &T::AccountId,
+		) -> Result<T::Balance, DispatchError> {
+			let mut program = Programs::<T>::get(program_id).ok_or(Error::<T>::ProgramNotFound)?;
+			let mut stake = Stakes::<T>::get(program_id, who);
+
+			Self::checkpoint(&mut program)?;
+			Self::settle(&program, &mut stake)?;
+
+			Ok(stake.pending)
+		}
+
+		/// Accounts the rewards released since the last checkpoint in the
+		/// rewards per staked token. Rewards released while nothing is staked
+		/// are not distributed and can be refunded.
+		fn checkpoint(program: &mut ProgramOf<T>) -> DispatchResult {
+			let now = T::Time::now().min(program.end);
+			if now <= program.last_update {
+				return Ok(());
+			}
+
+			if !program.total_staked.is_zero() {
+				let released = program
+					.reward_per_sec
+					.ensure_mul(now.ensure_sub(program.last_update)?.into())?;
+
+				let reward_per_token =
+					FixedU128::checked_from_rational(released, program.total_staked)
+						.ok_or(ArithmeticError::Overflow)?;
+
+				program
+					.reward_per_token
+					.ensure_add_assign(reward_per_token)?;
+				program.distributed.ensure_add_assign(released)?;
+			}
+
+			program.last_update = now;
+
+			Ok(())
+		}
+
+		/// Moves the rewards of a stake since its last settlement to its
+		/// pending rewards.
+		fn settle(program: &ProgramOf<T>, stake: &mut Stake<T::Balance>) -> DispatchResult {
+			let reward_per_token = program
+				.reward_per_token
+				.ensure_sub(stake.reward_per_token_paid)?;
+
+			stake
+				.pending
+				.ensure_add_assign(reward_per_token.saturating_mul_int(stake.amount))?;
+			stake.reward_per_token_paid = program.reward_per_token;
+
+			Ok(())
+		}
+
+		/// Checkpoints the program and settles the stake of an account before
+		/// applying `f`, updating the lock of the account afterwards.
+		fn update_stake(
+			program_id: ProgramId,
+			who: &T::AccountId,
+			f: impl FnOnce(&mut ProgramOf<T>, &mut Stake<T::Balance>) -> DispatchResult,
+		) -> DispatchResult {
+			Programs::<T>::try_mutate(program_id, |program| {
+				let program = program.as_mut().ok_or(Error::<T>::ProgramNotFound)?;
+
+				Stakes::<T>::try_mutate_exists(program_id, who, |maybe_stake| {
+					let mut stake = maybe_stake.take().unwrap_or_default();
+
+					Self::checkpoint(program)?;
+					Self::settle(program, &mut stake)?;
+
+					f(program, &mut stake)?;
+
+					let currency = (program.pool_id, program.tranche_id).into();
+					if stake.amount.is_zero() {
+						T::Tokens::remove_lock(Self::lock_id(program_id), currency, who)?;
+					} else {
+						T::Tokens::set_lock(
+							Self::lock_id(program_id),
+							currency,
+							who,
+							stake.amount,
+						)?;
+					}
+
+					if !stake.amount.is_zero() || !stake.pending.is_zero() {
+						*maybe_stake = Some(stake);
+					}
+
+					Ok(())
+				})
+			})
+		}
+	}
+}
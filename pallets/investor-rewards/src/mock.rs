@@ -0,0 +1,116 @@
+use cfg_mocks::{pallet_mock_pools, pre_conditions::pallet as pallet_mock_pre_conditions};
+use cfg_primitives::{Balance, PoolId, TrancheId};
+use cfg_types::{fixed_point::Ratio, tokens::CurrencyId};
+use frame_support::{derive_impl, parameter_types, PalletId};
+use orml_traits::MultiCurrency;
+use sp_io::TestExternalities;
+
+use crate::pallet as pallet_investor_rewards;
+
+pub type AccountId = u64;
+
+pub const ADMIN: AccountId = 1;
+pub const INVESTOR_A: AccountId = 2;
+pub const INVESTOR_B: AccountId = 3;
+
+pub const POOL_A: PoolId = 1;
+pub const TRANCHE_A: TrancheId = [1; 16];
+pub const TRANCHE_CURRENCY: CurrencyId = CurrencyId::Tranche(POOL_A, TRANCHE_A);
+pub const REWARD_CURRENCY: CurrencyId = CurrencyId::ForeignAsset(1);
+
+pub const REWARD_PER_SEC: Balance = 100;
+pub const START: u64 = 100;
+pub const END: u64 = 200;
+pub const FUNDS: Balance = REWARD_PER_SEC * (END - START) as Balance;
+pub const INITIAL_BALANCE: Balance = 1_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		MockTime: cfg_mocks::pallet_mock_time,
+		MockPools: pallet_mock_pools,
+		MockIsAdmin: pallet_mock_pre_conditions,
+		OrmlTokens: orml_tokens,
+		InvestorRewards: pallet_investor_rewards,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Runtime>;
+}
+
+impl cfg_mocks::pallet_mock_time::Config for Runtime {
+	type Moment = u64;
+}
+
+impl pallet_mock_pools::Config for Runtime {
+	type Balance = Balance;
+	type BalanceRatio = Ratio;
+	type CurrencyId = CurrencyId;
+	type PoolId = PoolId;
+	type TrancheId = TrancheId;
+}
+
+impl pallet_mock_pre_conditions::Config for Runtime {
+	type Conditions = (AccountId, PoolId);
+	type Result = bool;
+}
+
+orml_traits::parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		1
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Amount = i64;
+	type Balance = Balance;
+	type CurrencyHooks = ();
+	type CurrencyId = CurrencyId;
+	type DustRemovalWhitelist = frame_support::traits::Nothing;
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = frame_support::traits::ConstU32<10>;
+	type MaxReserves = frame_support::traits::ConstU32<10>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const InvestorRewardsPalletId: PalletId = PalletId(*b"cfg/inrw");
+}
+
+impl pallet_investor_rewards::Config for Runtime {
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+	type IsPoolAdmin = MockIsAdmin;
+	type PalletId = InvestorRewardsPalletId;
+	type PoolId = PoolId;
+	type Pools = MockPools;
+	type RuntimeEvent = RuntimeEvent;
+	type Time = MockTime;
+	type Tokens = OrmlTokens;
+	type TrancheId = TrancheId;
+	type WeightInfo = ();
+}
+
+pub fn set_now(secs: u64) {
+	MockTime::mock_now(move || secs * 1000);
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut ext = System::externalities();
+	ext.execute_with(|| {
+		MockIsAdmin::mock_check(|(who, pool_id)| who == ADMIN && pool_id == POOL_A);
+		MockPools::mock_tranche_exists(|pool_id, tranche_id| {
+			pool_id == POOL_A && tranche_id == TRANCHE_A
+		});
+		set_now(0);
+
+		OrmlTokens::deposit(REWARD_CURRENCY, &ADMIN, INITIAL_BALANCE).unwrap();
+		OrmlTokens::deposit(TRANCHE_CURRENCY, &INVESTOR_A, INITIAL_BALANCE).unwrap();
+		OrmlTokens::deposit(TRANCHE_CURRENCY, &INVESTOR_B, INITIAL_BALANCE).unwrap();
+	});
+	ext
+}
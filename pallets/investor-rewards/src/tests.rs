@@ -0,0 +1,474 @@
+use cfg_primitives::Balance;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError;
+
+use super::*;
+use crate::mock::*;
+
+fn create_program() -> ProgramId {
+	assert_ok!(InvestorRewards::create_program(
+		RuntimeOrigin::signed(ADMIN),
+		POOL_A,
+		TRANCHE_A,
+		REWARD_CURRENCY,
+		REWARD_PER_SEC,
+		START,
+		END,
+	));
+
+	NextProgramId::<Runtime>::get() - 1
+}
+
+fn reward_balance(who: AccountId) -> Balance {
+	OrmlTokens::free_balance(REWARD_CURRENCY, &who)
+}
+
+mod create_program {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			assert_eq!(ActiveProgram::<Runtime>::get(POOL_A), Some(program_id));
+			assert_eq!(reward_balance(ADMIN), INITIAL_BALANCE - FUNDS);
+			assert_eq!(
+				reward_balance(InvestorRewards::program_account(program_id)),
+				FUNDS
+			);
+
+			System::assert_last_event(
+				Event::<Runtime>::ProgramCreated {
+					program_id,
+					pool_id: POOL_A,
+					tranche_id: TRANCHE_A,
+					reward_currency: REWARD_CURRENCY,
+					reward_per_sec: REWARD_PER_SEC,
+					start: START,
+					end: END,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn not_admin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				InvestorRewards::create_program(
+					RuntimeOrigin::signed(INVESTOR_A),
+					POOL_A,
+					TRANCHE_A,
+					REWARD_CURRENCY,
+					REWARD_PER_SEC,
+					START,
+					END,
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn tranche_not_found() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				InvestorRewards::create_program(
+					RuntimeOrigin::signed(ADMIN),
+					POOL_A,
+					[2; 16],
+					REWARD_CURRENCY,
+					REWARD_PER_SEC,
+					START,
+					END,
+				),
+				Error::<Runtime>::TrancheNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn invalid_period() {
+		new_test_ext().execute_with(|| {
+			set_now(START + 1);
+
+			assert_noop!(
+				InvestorRewards::create_program(
+					RuntimeOrigin::signed(ADMIN),
+					POOL_A,
+					TRANCHE_A,
+					REWARD_CURRENCY,
+					REWARD_PER_SEC,
+					START,
+					END,
+				),
+				Error::<Runtime>::InvalidPeriod
+			);
+
+			assert_noop!(
+				InvestorRewards::create_program(
+					RuntimeOrigin::signed(ADMIN),
+					POOL_A,
+					TRANCHE_A,
+					REWARD_CURRENCY,
+					REWARD_PER_SEC,
+					END,
+					END,
+				),
+				Error::<Runtime>::InvalidPeriod
+			);
+		});
+	}
+
+	#[test]
+	fn invalid_reward_rate() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				InvestorRewards::create_program(
+					RuntimeOrigin::signed(ADMIN),
+					POOL_A,
+					TRANCHE_A,
+					REWARD_CURRENCY,
+					0,
+					START,
+					END,
+				),
+				Error::<Runtime>::InvalidRewardRate
+			);
+		});
+	}
+
+	#[test]
+	fn already_active() {
+		new_test_ext().execute_with(|| {
+			create_program();
+
+			assert_noop!(
+				InvestorRewards::create_program(
+					RuntimeOrigin::signed(ADMIN),
+					POOL_A,
+					TRANCHE_A,
+					REWARD_CURRENCY,
+					REWARD_PER_SEC,
+					START,
+					END,
+				),
+				Error::<Runtime>::ProgramAlreadyActive
+			);
+		});
+	}
+}
+
+mod stake {
+	use super::*;
+
+	#[test]
+	fn locks_tranche_tokens() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			assert_ok!(InvestorRewards::stake(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id,
+				1000
+			));
+
+			assert_eq!(Stakes::<Runtime>::get(program_id, INVESTOR_A).amount, 1000);
+			assert_eq!(
+				OrmlTokens::accounts(INVESTOR_A, TRANCHE_CURRENCY).frozen,
+				1000
+			);
+
+			assert_ok!(InvestorRewards::unstake(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id,
+				1000
+			));
+
+			assert!(!Stakes::<Runtime>::contains_key(program_id, INVESTOR_A));
+			assert!(OrmlTokens::locks(INVESTOR_A, TRANCHE_CURRENCY).is_empty());
+		});
+	}
+
+	#[test]
+	fn insufficient_balance() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			assert_noop!(
+				InvestorRewards::stake(
+					RuntimeOrigin::signed(INVESTOR_A),
+					program_id,
+					INITIAL_BALANCE + 1
+				),
+				Error::<Runtime>::InsufficientBalance
+			);
+		});
+	}
+
+	#[test]
+	fn insufficient_stake() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			assert_noop!(
+				InvestorRewards::unstake(RuntimeOrigin::signed(INVESTOR_A), program_id, 1),
+				Error::<Runtime>::InsufficientStake
+			);
+		});
+	}
+
+	#[test]
+	fn after_end() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+			set_now(END);
+
+			assert_noop!(
+				InvestorRewards::stake(RuntimeOrigin::signed(INVESTOR_A), program_id, 1000),
+				Error::<Runtime>::ProgramEnded
+			);
+		});
+	}
+
+	#[test]
+	fn after_close() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+			assert_ok!(InvestorRewards::close_program(
+				RuntimeOrigin::signed(ADMIN),
+				POOL_A
+			));
+
+			assert_noop!(
+				InvestorRewards::stake(RuntimeOrigin::signed(INVESTOR_A), program_id, 1000),
+				Error::<Runtime>::ProgramClosed
+			);
+		});
+	}
+}
+
+mod rewards {
+	use super::*;
+
+	#[test]
+	fn nothing_released_before_start() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			assert_ok!(InvestorRewards::stake(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id,
+				1000
+			));
+
+			set_now(START);
+			assert_eq!(
+				InvestorRewards::pending_rewards(program_id, &INVESTOR_A),
+				Ok(0)
+			);
+
+			assert_noop!(
+				InvestorRewards::claim(RuntimeOrigin::signed(INVESTOR_A), program_id),
+				Error::<Runtime>::NothingToClaim
+			);
+		});
+	}
+
+	#[test]
+	fn weighted_by_amount_and_duration() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			// A stakes alone during the first half
+			set_now(START);
+			assert_ok!(InvestorRewards::stake(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id,
+				1000
+			));
+
+			// B stakes three times the amount of A during the second half
+			set_now(START + 50);
+			assert_ok!(InvestorRewards::stake(
+				RuntimeOrigin::signed(INVESTOR_B),
+				program_id,
+				3000
+			));
+
+			set_now(END + 10);
+
+			let first_half = REWARD_PER_SEC * 50;
+			let second_half = REWARD_PER_SEC * 50;
+
+			assert_eq!(
+				InvestorRewards::pending_rewards(program_id, &INVESTOR_A),
+				Ok(first_half + second_half / 4)
+			);
+			assert_eq!(
+				InvestorRewards::pending_rewards(program_id, &INVESTOR_B),
+				Ok(second_half * 3 / 4)
+			);
+
+			assert_ok!(InvestorRewards::claim(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id
+			));
+			assert_ok!(InvestorRewards::claim(
+				RuntimeOrigin::signed(INVESTOR_B),
+				program_id
+			));
+
+			assert_eq!(reward_balance(INVESTOR_A), first_half + second_half / 4);
+			assert_eq!(reward_balance(INVESTOR_B), second_half * 3 / 4);
+			assert_eq!(
+				reward_balance(InvestorRewards::program_account(program_id)),
+				0
+			);
+
+			System::assert_last_event(
+				Event::<Runtime>::Claimed {
+					program_id,
+					who: INVESTOR_B,
+					amount: second_half * 3 / 4,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn kept_after_unstake() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			set_now(START);
+			assert_ok!(InvestorRewards::stake(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id,
+				1000
+			));
+
+			set_now(START + 10);
+			assert_ok!(InvestorRewards::unstake(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id,
+				1000
+			));
+
+			set_now(END);
+			assert_eq!(
+				Stakes::<Runtime>::get(program_id, INVESTOR_A).pending,
+				REWARD_PER_SEC * 10
+			);
+
+			assert_ok!(InvestorRewards::claim(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id
+			));
+
+			assert_eq!(reward_balance(INVESTOR_A), REWARD_PER_SEC * 10);
+			assert!(!Stakes::<Runtime>::contains_key(program_id, INVESTOR_A));
+		});
+	}
+}
+
+mod close_program {
+	use super::*;
+
+	#[test]
+	fn refunds_everything_without_stakers() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			set_now(START + 50);
+			assert_ok!(InvestorRewards::close_program(
+				RuntimeOrigin::signed(ADMIN),
+				POOL_A
+			));
+
+			assert_eq!(ActiveProgram::<Runtime>::get(POOL_A), None);
+			assert_eq!(reward_balance(ADMIN), INITIAL_BALANCE);
+
+			System::assert_last_event(
+				Event::<Runtime>::ProgramClosed {
+					program_id,
+					refunded: FUNDS,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn keeps_distributed_rewards() {
+		new_test_ext().execute_with(|| {
+			let program_id = create_program();
+
+			set_now(START + 20);
+			assert_ok!(InvestorRewards::stake(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id,
+				1000
+			));
+
+			set_now(START + 50);
+			assert_ok!(InvestorRewards::close_program(
+				RuntimeOrigin::signed(ADMIN),
+				POOL_A
+			));
+
+			let distributed = REWARD_PER_SEC * 30;
+			assert_eq!(reward_balance(ADMIN), INITIAL_BALANCE - distributed);
+			assert_eq!(
+				Programs::<Runtime>::get(program_id).unwrap().end,
+				START + 50
+			);
+
+			// No more rewards are released after closing
+			set_now(END);
+			assert_eq!(
+				InvestorRewards::pending_rewards(program_id, &INVESTOR_A),
+				Ok(distributed)
+			);
+
+			assert_ok!(InvestorRewards::claim(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id
+			));
+			assert_ok!(InvestorRewards::unstake(
+				RuntimeOrigin::signed(INVESTOR_A),
+				program_id,
+				1000
+			));
+
+			assert_eq!(reward_balance(INVESTOR_A), distributed);
+			assert!(OrmlTokens::locks(INVESTOR_A, TRANCHE_CURRENCY).is_empty());
+		});
+	}
+
+	#[test]
+	fn not_admin() {
+		new_test_ext().execute_with(|| {
+			create_program();
+
+			assert_noop!(
+				InvestorRewards::close_program(RuntimeOrigin::signed(INVESTOR_A), POOL_A),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn without_program() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				InvestorRewards::close_program(RuntimeOrigin::signed(ADMIN), POOL_A),
+				Error::<Runtime>::ProgramNotFound
+			);
+		});
+	}
+}
@@ -0,0 +1,43 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+	fn create_program() -> Weight;
+	fn close_program() -> Weight;
+	fn stake() -> Weight;
+	fn unstake() -> Weight;
+	fn claim() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn create_program() -> Weight {
+		Weight::zero()
+	}
+
+	fn close_program() -> Weight {
+		Weight::zero()
+	}
+
+	fn stake() -> Weight {
+		Weight::zero()
+	}
+
+	fn unstake() -> Weight {
+		Weight::zero()
+	}
+
+	fn claim() -> Weight {
+		Weight::zero()
+	}
+}
@@ -0,0 +1,243 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+//! # ISMP Router
+//!
+//! Pallet that sends and receive message with other chains using ISMP, as an
+//! alternative transport to Axelar.
+//!
+//! Outbound messages are dispatched as POST requests to the liquidity pools
+//! contract of the target chain. Inbound messages reach the pallet through
+//! `receive()`, which the ISMP module of the runtime calls once the request
+//! was verified against the consensus proofs of its source chain.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use cfg_traits::{
+	ismp::{IsmpDispatcher, PostRequest, StateMachine},
+	liquidity_pools::{MessageFeeEstimator, MessageReceiver, MessageSender},
+};
+use cfg_types::{domain_address::DomainAddress, EVMChainId};
+use frame_support::{
+	pallet_prelude::*,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use frame_system::pallet_prelude::*;
+pub use pallet::*;
+use sp_core::{H160, H256, U256};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// Identification of a chain reached through ISMP
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub enum IsmpId {
+	Evm(EVMChainId),
+}
+
+impl From<StateMachine> for IsmpId {
+	fn from(state_machine: StateMachine) -> Self {
+		match state_machine {
+			StateMachine::Evm(chain_id) => IsmpId::Evm(chain_id.into()),
+		}
+	}
+}
+
+/// Configuration for messages sent and received though ISMP
+#[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct IsmpConfig {
+	/// Address of liquidity pool contract in the target chain
+	pub liquidity_pools_contract_address: H160,
+
+	/// Seconds after which an outbound message times out if not delivered.
+	pub timeout: u64,
+
+	/// The fee paid to the relayer delivering each outbound message.
+	pub relayer_fee: U256,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin that is allowed to configure the chains we send
+		/// messages to and accept messages from
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The target of the messages coming from other chains
+		type Receiver: MessageReceiver<
+			Middleware = Self::Middleware,
+			Origin = DomainAddress,
+			Message = Vec<u8>,
+		>;
+
+		/// Middleware used by the gateway
+		type Middleware: From<IsmpId>;
+
+		/// The target of the messages coming from this chain
+		type Dispatcher: IsmpDispatcher;
+	}
+
+	#[pallet::storage]
+	pub type Configuration<T: Config> = StorageMap<_, Twox64Concat, IsmpId, IsmpConfig>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The configuration of a chain was set.
+		ConfigSet { id: IsmpId, config: IsmpConfig },
+
+		/// The configuration of a chain was removed.
+		ConfigRemoved { id: IsmpId },
+
+		/// An outbound message was dispatched as an ISMP request.
+		MessageDispatched { id: IsmpId, commitment: H256 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Emit when the router configuration is not found.
+		RouterConfigurationNotFound,
+
+		/// Emit when the chain id can not be represented as an ISMP state
+		/// machine.
+		UnsupportedChainId,
+
+		/// Emit when the source address can not be recognized
+		InvalidSourceAddress,
+
+		/// Emit when a message is received from a non LP contract
+		ContractCallerMismatch,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().writes(1)))]
+		#[pallet::call_index(0)]
+		pub fn set_config(origin: OriginFor<T>, id: IsmpId, config: IsmpConfig) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::state_machine(&id)?;
+
+			Configuration::<T>::insert(&id, config.clone());
+
+			Self::deposit_event(Event::<T>::ConfigSet { id, config });
+
+			Ok(())
+		}
+
+		#[pallet::weight(Weight::from_parts(50_000_000, 512).saturating_add(RocksDbWeight::get().reads_writes(1, 1)))]
+		#[pallet::call_index(1)]
+		pub fn remove_config(origin: OriginFor<T>, id: IsmpId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Configuration::<T>::take(&id).ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			Self::deposit_event(Event::<T>::ConfigRemoved { id });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Receives a message sent by the module `from` of the `source` state
+		/// machine. The request must have been verified by ISMP beforehand.
+		pub fn receive(source: StateMachine, from: &[u8], body: &[u8]) -> DispatchResult {
+			let id = IsmpId::from(source);
+
+			let config =
+				Configuration::<T>::get(&id).ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			let source_address: [u8; 20] = from
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidSourceAddress)?;
+			let source_address = H160::from(source_address);
+
+			ensure!(
+				source_address == config.liquidity_pools_contract_address,
+				Error::<T>::ContractCallerMismatch,
+			);
+
+			match id {
+				IsmpId::Evm(chain_id) => T::Receiver::receive(
+					id.into(),
+					DomainAddress::Evm(chain_id, source_address),
+					body.to_vec(),
+				),
+			}
+		}
+
+		/// ISMP state machine of a chain
+		fn state_machine(id: &IsmpId) -> Result<StateMachine, DispatchError> {
+			match id {
+				IsmpId::Evm(chain_id) => u32::try_from(*chain_id)
+					.map(StateMachine::Evm)
+					.map_err(|_| Error::<T>::UnsupportedChainId.into()),
+			}
+		}
+	}
+
+	impl<T: Config> MessageFeeEstimator for Pallet<T> {
+		type Fee = U256;
+		type Message = Vec<u8>;
+		type Middleware = IsmpId;
+
+		fn estimate_fee(ismp_id: IsmpId, _: &Self::Message) -> Result<U256, DispatchError> {
+			let config =
+				Configuration::<T>::get(&ismp_id).ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			Ok(config.relayer_fee)
+		}
+	}
+
+	impl<T: Config> MessageSender for Pallet<T> {
+		type Message = Vec<u8>;
+		type Middleware = IsmpId;
+		type Origin = DomainAddress;
+
+		fn send(
+			ismp_id: IsmpId,
+			_origin: Self::Origin,
+			message: Self::Message,
+		) -> DispatchResultWithPostInfo {
+			let config =
+				Configuration::<T>::get(&ismp_id).ok_or(Error::<T>::RouterConfigurationNotFound)?;
+
+			let commitment = T::Dispatcher::dispatch_post(PostRequest {
+				dest: Self::state_machine(&ismp_id)?,
+				to: config.liquidity_pools_contract_address.as_bytes().to_vec(),
+				body: message,
+				timeout: config.timeout,
+				fee: config.relayer_fee,
+			})?;
+
+			Self::deposit_event(Event::<T>::MessageDispatched {
+				id: ismp_id,
+				commitment,
+			});
+
+			// The weight of the dispatcher is unknown, so it's not reported and
+			// the caller charges it defensively.
+			Ok(().into())
+		}
+	}
+}
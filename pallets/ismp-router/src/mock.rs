@@ -0,0 +1,51 @@
+use cfg_types::domain_address::DomainAddress;
+use frame_support::{derive_impl, traits::EitherOfDiverse};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_io::TestExternalities;
+
+use crate::{pallet as pallet_ismp_router, IsmpId};
+
+pub type AccountId = u64;
+
+#[derive(Debug, PartialEq)]
+pub struct Middleware(pub IsmpId);
+
+impl From<IsmpId> for Middleware {
+	fn from(id: IsmpId) -> Self {
+		Middleware(id)
+	}
+}
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		Receiver: cfg_mocks::router_message::pallet,
+		Dispatcher: cfg_mocks::ismp_dispatcher::pallet,
+		Router: pallet_ismp_router,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Runtime>;
+}
+
+impl cfg_mocks::router_message::pallet::Config for Runtime {
+	type Message = Vec<u8>;
+	type Middleware = Middleware;
+	type Origin = DomainAddress;
+}
+
+impl cfg_mocks::ismp_dispatcher::pallet::Config for Runtime {}
+
+impl pallet_ismp_router::Config for Runtime {
+	type AdminOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureSigned<AccountId>>;
+	type Dispatcher = Dispatcher;
+	type Middleware = Middleware;
+	type Receiver = Receiver;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	System::externalities()
+}
@@ -0,0 +1,215 @@
+use cfg_traits::liquidity_pools::{MessageFeeEstimator, MessageSender};
+use frame_support::{assert_err, assert_noop, assert_ok};
+use sp_core::crypto::AccountId32;
+use sp_runtime::DispatchError;
+
+use crate::{mock::*, *};
+
+const CHAIN_ID: EVMChainId = 1;
+const LP_CONTRACT_ADDRESS: H160 = H160::repeat_byte(1);
+const SENDER: DomainAddress = DomainAddress::Centrifuge(AccountId32::new([0; 32]));
+const MESSAGE: &[u8] = &[1, 2, 3];
+const TIMEOUT: u64 = 3600;
+const RELAYER_FEE: U256 = U256([10, 0, 0, 0]);
+const COMMITMENT: H256 = H256::repeat_byte(42);
+
+fn config() -> IsmpConfig {
+	IsmpConfig {
+		liquidity_pools_contract_address: LP_CONTRACT_ADDRESS,
+		timeout: TIMEOUT,
+		relayer_fee: RELAYER_FEE,
+	}
+}
+
+fn correct_configuration() {
+	assert_ok!(Router::set_config(
+		RuntimeOrigin::root(),
+		IsmpId::Evm(CHAIN_ID),
+		config()
+	));
+}
+
+mod configuration {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_eq!(
+				Configuration::<Runtime>::get(IsmpId::Evm(CHAIN_ID)),
+				Some(config())
+			);
+
+			assert_ok!(Router::remove_config(
+				RuntimeOrigin::root(),
+				IsmpId::Evm(CHAIN_ID)
+			));
+
+			assert_eq!(Configuration::<Runtime>::get(IsmpId::Evm(CHAIN_ID)), None);
+		});
+	}
+
+	#[test]
+	fn with_unsupported_chain_id() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Router::set_config(
+					RuntimeOrigin::root(),
+					IsmpId::Evm(u32::MAX as EVMChainId + 1),
+					config()
+				),
+				Error::<Runtime>::UnsupportedChainId
+			);
+		});
+	}
+
+	#[test]
+	fn remove_without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Router::remove_config(RuntimeOrigin::root(), IsmpId::Evm(CHAIN_ID)),
+				Error::<Runtime>::RouterConfigurationNotFound
+			);
+		});
+	}
+}
+
+mod send {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			Dispatcher::mock_dispatch_post(|request| {
+				assert_eq!(
+					request,
+					PostRequest {
+						dest: StateMachine::Evm(CHAIN_ID as u32),
+						to: LP_CONTRACT_ADDRESS.as_bytes().to_vec(),
+						body: MESSAGE.to_vec(),
+						timeout: TIMEOUT,
+						fee: RELAYER_FEE,
+					}
+				);
+				Ok(COMMITMENT)
+			});
+
+			let post_info = Router::send(IsmpId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec()).unwrap();
+
+			assert_eq!(post_info.actual_weight, None);
+			System::assert_last_event(
+				Event::<Runtime>::MessageDispatched {
+					id: IsmpId::Evm(CHAIN_ID),
+					commitment: COMMITMENT,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Router::send(IsmpId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec()),
+				Error::<Runtime>::RouterConfigurationNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn with_dispatcher_error() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			Dispatcher::mock_dispatch_post(|_| Err(DispatchError::Other("dispatch error")));
+
+			assert_err!(
+				Router::send(IsmpId::Evm(CHAIN_ID), SENDER, MESSAGE.to_vec()),
+				DispatchError::Other("dispatch error")
+			);
+		});
+	}
+
+	#[test]
+	fn estimate_fee() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_eq!(
+				Router::estimate_fee(IsmpId::Evm(CHAIN_ID), &MESSAGE.to_vec()),
+				Ok(RELAYER_FEE)
+			);
+		});
+	}
+}
+
+mod receive {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			Receiver::mock_receive(|middleware, origin, message| {
+				assert_eq!(middleware, Middleware(IsmpId::Evm(CHAIN_ID)));
+				assert_eq!(origin, DomainAddress::Evm(CHAIN_ID, LP_CONTRACT_ADDRESS));
+				assert_eq!(&message, MESSAGE);
+				Ok(())
+			});
+
+			assert_ok!(Router::receive(
+				StateMachine::Evm(CHAIN_ID as u32),
+				LP_CONTRACT_ADDRESS.as_bytes(),
+				MESSAGE
+			));
+		});
+	}
+
+	#[test]
+	fn without_configuration() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				Router::receive(
+					StateMachine::Evm(CHAIN_ID as u32),
+					LP_CONTRACT_ADDRESS.as_bytes(),
+					MESSAGE
+				),
+				Error::<Runtime>::RouterConfigurationNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn with_invalid_source_address() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_err!(
+				Router::receive(StateMachine::Evm(CHAIN_ID as u32), &[1; 32], MESSAGE),
+				Error::<Runtime>::InvalidSourceAddress
+			);
+		});
+	}
+
+	#[test]
+	fn with_wrong_caller() {
+		new_test_ext().execute_with(|| {
+			correct_configuration();
+
+			assert_err!(
+				Router::receive(
+					StateMachine::Evm(CHAIN_ID as u32),
+					H160::repeat_byte(2).as_bytes(),
+					MESSAGE
+				),
+				Error::<Runtime>::ContractCallerMismatch
+			);
+		});
+	}
+}
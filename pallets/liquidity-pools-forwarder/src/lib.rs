@@ -208,7 +208,7 @@ pub mod pallet {
 			router_id: T::RouterId,
 			origin: DomainAddress,
 			message: T::Message,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let msg = RouterForwarding::<T>::get(&router_id)
 				.map(|info| {
 					T::Message::try_wrap_forward(info.source_domain, info.contract, message.clone())
@@ -220,6 +220,12 @@ pub mod pallet {
 
 			T::MessageSender::send(router_id, origin, msg)
 		}
+
+		/// NOTE: The forwarding envelope is not deducted, the underlying
+		/// router rejects messages exceeding its limit once wrapped.
+		fn max_payload_size(router_id: &T::RouterId) -> Option<u32> {
+			T::MessageSender::max_payload_size(router_id)
+		}
 	}
 
 	impl<T: Config> MessageReceiver for Pallet<T> {
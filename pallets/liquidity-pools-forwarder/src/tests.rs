@@ -126,7 +126,7 @@ mod send_message {
 			assert_eq!(sender, FORWARDER_DOMAIN_ADDRESS);
 			assert_eq!(message, msg);
 
-			Ok(())
+			Ok(().into())
 		});
 
 		if set_forwarding_info {
@@ -248,7 +248,7 @@ mod send_message {
 		fn non_forward_with_message_receiver_err() {
 			System::externalities().execute_with(|| {
 				config_mocks(Message::Forward, true);
-				MockSenderReceiver::mock_send(|_, _, _| Err(ERROR));
+				MockSenderReceiver::mock_send(|_, _, _| Err(ERROR.into()));
 
 				assert_noop!(
 					LiquidityPoolsForwarder::send(
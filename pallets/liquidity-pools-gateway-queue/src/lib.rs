@@ -14,7 +14,10 @@
 
 use core::fmt::Debug;
 
-use cfg_traits::liquidity_pools::{MessageProcessor, MessageQueue as MessageQueueT};
+use cfg_traits::{
+	hooks::HookWeightStorage,
+	liquidity_pools::{MessageProcessor, MessageQueue as MessageQueueT},
+};
 use frame_support::{dispatch::PostDispatchInfo, pallet_prelude::*};
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
@@ -77,6 +80,11 @@ pub mod pallet {
 	pub type FailedMessageQueue<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::MessageNonce, (T::Message, DispatchError)>;
 
+	/// Weight consumed by the hooks of this pallet and the block it was
+	/// consumed in.
+	#[pallet::storage]
+	pub type LastHookWeight<T: Config> = StorageValue<_, (BlockNumberFor<T>, Weight), OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -113,8 +121,14 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_idle(_now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
-			Self::service_message_queue(max_weight)
+		fn on_idle(now: BlockNumberFor<T>, max_weight: Weight) -> Weight {
+			let record_weight = T::DbWeight::get().writes(1);
+			let weight = Self::service_message_queue(max_weight.saturating_sub(record_weight));
+			if !LastHookWeight::<T>::record(now, weight) {
+				return weight;
+			}
+
+			weight.saturating_add(record_weight)
 		}
 	}
 
@@ -187,6 +201,11 @@ pub mod pallet {
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Weight consumed by the hooks of this pallet in the current block.
+		pub fn hook_weight() -> Weight {
+			LastHookWeight::<T>::consumed_in(frame_system::Pallet::<T>::block_number())
+		}
+
 		fn process_message_and_deposit_event(
 			nonce: T::MessageNonce,
 			message: T::Message,
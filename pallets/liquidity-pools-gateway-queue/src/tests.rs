@@ -285,6 +285,26 @@ mod on_idle {
 		});
 	}
 
+	#[test]
+	fn hook_weight_recorded() {
+		new_test_ext().execute_with(|| {
+			(1..=3).for_each(|i| Queue::queue(i * 10).unwrap());
+
+			Processor::mock_max_processing_weight(|_| PROCESS_LIMIT_WEIGHT);
+			Processor::mock_process(|_| (Ok(()), PROCESS_WEIGHT));
+
+			let now = frame_system::Pallet::<Runtime>::block_number();
+			let weight = Queue::on_idle(now, TOTAL_WEIGHT);
+
+			assert_eq!(Queue::hook_weight(), weight);
+
+			// Next block
+			frame_system::Pallet::<Runtime>::set_block_number(now + 1);
+
+			assert_eq!(Queue::hook_weight(), Weight::zero());
+		});
+	}
+
 	#[test]
 	fn with_no_messages() {
 		new_test_ext().execute_with(|| {
@@ -24,29 +24,82 @@
 //! gets finalized. Each message in the `OutboundMessageQueue` has a `Domain`
 //! assigned to it, and that `Domain` should have a corresponding `DomainRouter`
 //! which should be set prior to sending the message.
+//!
+//! Inbound messages can carry a sequence number of their source domain. These
+//! messages are executed strictly in order: duplicated or already executed
+//! sequence numbers are rejected, and messages that arrive ahead of their turn
+//! wait in `PendingSequencedMessages` until the gap is filled.
+//!
+//! Inbound and outbound processing can be paused per `Domain`, or globally in
+//! an emergency. Messages processed while paused are held in `PausedMessages`
+//! and queued again with `resume_paused_messages()` once unpaused.
+//!
+//! A single message can be quarantined by its hash with `quarantine_message()`
+//! while it's investigated. Messages with that hash are held in
+//! `QuarantinedMessages` instead of being executed or delivered, until an
+//! admin releases them to the queue again or discards them.
+//!
+//! Registered relayers can submit inbound messages as unsigned transactions
+//! with `receive_relayed_message()`, signing them with their relayer key
+//! instead of paying fees from a funded account. Relayers with a higher
+//! reputation, earned by each accepted message, get a higher priority in the
+//! transaction pool.
+//!
+//! Relayers approved by an admin become active once an account bonds
+//! `RelayerBond` for them with `register_relayer()`. Each delivered message
+//! pays the configured `RelayerFee` to that account, either from the gateway
+//! fee pot or from the account of the message originator. A signed message
+//! that fails to be received is a provably invalid submission: the bond is
+//! slashed into the fee pot and the relayer must be bonded again.
+//!
+//! Messages are serialized with the version negotiated with their domain in
+//! `DomainMessageVersion`, starting from the legacy format without envelope.
+//! The version is raised when a domain sends a newer supported version, or set
+//! by an admin with `set_domain_message_version()`.
+//!
+//! The EVM transaction executed to send an outbound message through a router
+//! is recorded in `OutboundEvmTransactions`, so failures can be correlated
+//! end-to-end by relayer monitoring.
+//!
+//! Routers can limit the size of the messages they send. Outbound messages
+//! exceeding the smallest limit of the routers of their domain are rejected
+//! when handled, and queued batches exceeding it are split into smaller
+//! batches.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use core::fmt::Debug;
 
 use cfg_primitives::LP_DEFENSIVE_WEIGHT;
-use cfg_traits::liquidity_pools::{
-	InboundMessageHandler, LpMessageBatch, LpMessageProof, LpMessageRecovery, LpMessageSerializer,
-	MessageHash, MessageProcessor, MessageQueue, MessageReceiver, MessageSender,
-	OutboundMessageHandler, RouterProvider,
+use cfg_traits::{
+	activity::{ActivityKind, ActivityRecorder},
+	ethereum::{EthereumTransactionInspect, TransactionRef},
+	hooks::HookWeightStorage,
+	liquidity_pools::{
+		InboundMessageHandler, LpMessageBatch, LpMessageHash, LpMessageProof, LpMessageRecovery,
+		LpMessageSequence, LpMessageSerializer, MessageHash, MessageProcessor, MessageQueue,
+		MessageReceiver, MessageSender, MessageVersion, MessageVersions, OutboundMessageHandler,
+		OutboundMessageTracker, RouterProvider,
+	},
 };
 use cfg_types::domain_address::{Domain, DomainAddress};
 use frame_support::{
-	dispatch::DispatchResult,
+	dispatch::{DispatchErrorWithPostInfo, DispatchResult, PostDispatchInfo},
 	pallet_prelude::*,
 	storage::{with_transaction, TransactionOutcome},
+	traits::{BalanceStatus, Currency, ExistenceRequirement, Imbalance, ReservableCurrency},
+	PalletId,
 };
-use frame_system::pallet_prelude::{ensure_signed, OriginFor};
+use frame_system::pallet_prelude::{ensure_none, ensure_signed, BlockNumberFor, OriginFor};
 use message::GatewayMessage;
 use orml_traits::GetByKey;
 pub use pallet::*;
 use parity_scale_codec::FullCodec;
-use sp_arithmetic::traits::{BaseArithmetic, EnsureAddAssign, One};
-use sp_std::convert::TryInto;
+use sp_arithmetic::traits::{BaseArithmetic, EnsureAddAssign, One, Zero};
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating},
+	AccountId32, RuntimeAppPublic,
+};
+use sp_std::{convert::TryInto, vec::Vec};
 
 use crate::{
 	message_processing::{InboundEntry, ProofEntry},
@@ -65,18 +118,198 @@ mod mock;
 #[cfg(test)]
 mod tests;
 
+/// Directions in which the processing of messages of a domain is paused.
+#[derive(Debug, Default, Encode, Decode, Clone, Copy, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct PauseState {
+	/// Messages received from the domain are held.
+	pub inbound: bool,
+
+	/// Messages sent to the domain are held.
+	pub outbound: bool,
+}
+
+/// A registered relayer of inbound messages.
+#[derive(Debug, Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct RelayerInfo<RouterId, AccountId, Balance> {
+	/// The router on behalf of which the relayer submits messages.
+	pub router_id: RouterId,
+
+	/// The nonce expected for the next message of the relayer.
+	pub nonce: u64,
+
+	/// The number of messages accepted from the relayer.
+	pub reputation: u32,
+
+	/// The account that bonded the relayer and receives its fees. Messages
+	/// are only accepted once the relayer is bonded.
+	pub account: Option<AccountId>,
+
+	/// The amount reserved from `account` for the relayer.
+	pub bond: Balance,
+}
+
+/// The account paying the relayer fee of a delivered message.
+#[derive(Debug, Encode, Decode, Clone, Copy, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub enum RelayerFeePayer {
+	/// The gateway fee pot.
+	FeePot,
+
+	/// The account of the address that sent the message in its source domain.
+	Originator,
+}
+
+/// The fee paid to a relayer for each delivered inbound message.
+#[derive(Debug, Encode, Decode, Clone, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct RelayerFee<Balance> {
+	pub amount: Balance,
+	pub payer: RelayerFeePayer,
+}
+
+/// The EVM transaction that sent an outbound message through a router.
+#[derive(Debug, Encode, Decode, Clone, Copy, Eq, MaxEncodedLen, PartialEq, TypeInfo)]
+pub struct OutboundEvmTransaction<BlockNumber> {
+	/// The block in which the transaction was executed.
+	pub block_number: BlockNumber,
+
+	/// The hash and index of the transaction in the Ethereum block.
+	pub transaction: TransactionRef,
+}
+
+/// Prefix of the payload signed by a relayer for a relayed message, so the
+/// signature can not be used for anything else signed with the relayer key.
+pub const RELAYED_MESSAGE_SIGNING_CONTEXT: &[u8] = b"cfg-lp-gateway/relayed-message";
+
+/// An inbound message submitted by a relayer, signed with its relayer key.
+///
+/// The relayer signs the payload of `Pallet::relayed_message_signing_payload()`
+/// instead of the bare message.
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+pub struct RelayedMessage<RelayerId> {
+	/// The relayer that signed the message.
+	pub relayer: RelayerId,
+
+	/// The nonce of the relayer, preventing the message from being replayed.
+	pub nonce: u64,
+
+	/// The address that sent the message in its source domain.
+	pub domain_address: DomainAddress,
+
+	/// The serialized message.
+	pub payload: Vec<u8>,
+}
+
+/// The gateway state of a domain, exported and imported by an admin to
+/// reconstruct it after a storage reset or under a renamed domain.
+///
+/// NOTE: Routers are shared by all domains and are not part of this state.
+#[derive(Debug, Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+pub struct DomainState<Message, RouterId> {
+	/// The allowlisted instances of the domain.
+	pub instances: Vec<DomainAddress>,
+
+	/// The hook address of the domain.
+	pub hook_address: Option<[u8; 20]>,
+
+	/// The last sequence number executed.
+	pub inbound_nonce: u64,
+
+	/// The sequenced inbound messages that arrived ahead of their turn.
+	pub pending_sequenced_messages: Vec<(u64, DomainAddress, Message)>,
+
+	/// Whether outbound messages are batched.
+	pub outbound_batching: bool,
+
+	/// The batch of outbound messages not yet queued.
+	pub pending_outbound_batch: Option<Message>,
+
+	/// The directions in which the domain is paused.
+	pub pause_state: PauseState,
+
+	/// The range of indexes of the messages held while paused.
+	pub paused_message_range: (u64, u64),
+
+	/// The messages held while paused, by arrival index.
+	pub paused_messages: Vec<(u64, GatewayMessage<Message, RouterId>)>,
+
+	/// The number of routers required to execute an inbound message.
+	pub inbound_threshold: Option<u32>,
+
+	/// The negotiated message version.
+	pub message_version: MessageVersion,
+}
+
+impl<Message: PartialEq, RouterId: PartialEq> DomainState<Message, RouterId> {
+	/// Whether the state is the one of a domain with no gateway storage.
+	pub fn is_empty(&self) -> bool {
+		self.instances.is_empty()
+			&& self.hook_address.is_none()
+			&& self.inbound_nonce == 0
+			&& self.pending_sequenced_messages.is_empty()
+			&& !self.outbound_batching
+			&& self.pending_outbound_batch.is_none()
+			&& self.pause_state == PauseState::default()
+			&& self.paused_message_range == (0, 0)
+			&& self.paused_messages.is_empty()
+			&& self.inbound_threshold.is_none()
+			&& self.message_version == MessageVersion::default()
+	}
+
+	/// Binds the addresses of the state to another domain, keeping their
+	/// bytes.
+	pub fn rebind(self, domain: Domain) -> Self {
+		let rebind = |address: DomainAddress| DomainAddress::new(domain, address.bytes());
+
+		Self {
+			instances: self.instances.into_iter().map(rebind).collect(),
+			pending_sequenced_messages: self
+				.pending_sequenced_messages
+				.into_iter()
+				.map(|(nonce, address, message)| (nonce, rebind(address), message))
+				.collect(),
+			paused_messages: self
+				.paused_messages
+				.into_iter()
+				.map(|(index, message)| {
+					let message = match message {
+						GatewayMessage::Inbound {
+							domain_address,
+							message,
+							router_id,
+						} => GatewayMessage::Inbound {
+							domain_address: rebind(domain_address),
+							message,
+							router_id,
+						},
+						outbound => outbound,
+					};
+					(index, message)
+				})
+				.collect(),
+			..self
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type RelayerInfoOf<T> =
+		RelayerInfo<<T as Config>::RouterId, <T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+	pub type DomainStateOf<T> = DomainState<<T as Config>::Message, <T as Config>::RouterId>;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
-	pub trait Config: frame_system::Config {
+	pub trait Config: frame_system::Config<AccountId = AccountId32> {
 		/// The event type.
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -89,6 +322,7 @@ pub mod pallet {
 			+ LpMessageBatch
 			+ LpMessageProof
 			+ LpMessageRecovery
+			+ LpMessageSequence
 			+ Clone
 			+ Debug
 			+ PartialEq
@@ -136,6 +370,40 @@ pub mod pallet {
 
 		/// Type for identifying sessions of inbound routers.
 		type SessionId: Parameter + Member + BaseArithmetic + Default + Copy + MaxEncodedLen;
+
+		/// The type notified when an outbound message is sent.
+		type OutboundMessageTracker: OutboundMessageTracker<Domain, Self::Message>;
+
+		/// Records the hashes of the messages sent or received in a block.
+		type ActivityRecorder: ActivityRecorder;
+
+		/// Maximum distance between the next expected sequence number of a
+		/// domain and the sequence number of a message waiting for its turn.
+		#[pallet::constant]
+		type MaxInboundNonceGap: Get<u64>;
+
+		/// The key type relayers sign their messages with.
+		type RelayerId: Member + Parameter + RuntimeAppPublic + MaxEncodedLen;
+
+		/// Base priority of the messages submitted by relayers. The reputation
+		/// of the relayer is added on top of it.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// The currency used for relayer bonds and fees.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved from the account registering a relayer.
+		#[pallet::constant]
+		type RelayerBond: Get<BalanceOf<Self>>;
+
+		/// Identifier of the fee pot paying relayer fees and receiving
+		/// slashed bonds.
+		#[pallet::constant]
+		type FeePotId: Get<PalletId>;
+
+		/// The Ethereum transactions executed by the routers.
+		type EthereumTransactions: EthereumTransactionInspect;
 	}
 
 	#[pallet::event]
@@ -207,6 +475,150 @@ pub mod pallet {
 			recovery_router: [u8; 32],
 			messaging_router: T::RouterId,
 		},
+
+		/// A sequenced inbound message arrived ahead of its turn and will be
+		/// executed once all previous sequence numbers are executed.
+		InboundMessageDeferred {
+			domain_address: DomainAddress,
+			message_hash: MessageHash,
+			nonce: u64,
+		},
+
+		/// A deferred inbound message failed when its turn came. It remains
+		/// pending and can be retried with `execute_pending_message()`.
+		PendingInboundMessageFailed {
+			domain: Domain,
+			nonce: u64,
+			error: DispatchError,
+		},
+
+		/// Automatic batching of outbound messages was enabled or disabled for
+		/// a domain.
+		OutboundBatchingSet { domain: Domain, enabled: bool },
+
+		/// The outbound batch of a domain could not be queued. It remains
+		/// pending and will be retried in the next block.
+		OutboundBatchQueueFailed {
+			domain: Domain,
+			error: DispatchError,
+		},
+
+		/// The pause state of a domain was updated.
+		DomainPauseSet { domain: Domain, state: PauseState },
+
+		/// The emergency pause of all domains was updated.
+		EmergencyPauseSet { paused: bool },
+
+		/// A message was held because its domain is paused.
+		MessageHeld { domain: Domain, index: u64 },
+
+		/// Messages held while a domain was paused were queued again.
+		PausedMessagesResumed { domain: Domain, count: u32 },
+
+		/// Messages with the given hash are held from now on.
+		MessageQuarantined { message_hash: MessageHash },
+
+		/// A message was held because its hash is quarantined.
+		QuarantinedMessageHeld {
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+		},
+
+		/// The quarantine of a message hash was lifted and its held messages
+		/// were queued again.
+		QuarantinedMessageReleased {
+			message_hash: MessageHash,
+			count: u32,
+		},
+
+		/// The quarantine of a message hash was lifted and its held messages
+		/// were dropped.
+		QuarantinedMessageDiscarded {
+			message_hash: MessageHash,
+			count: u32,
+		},
+
+		/// The inbound threshold of a domain was updated.
+		InboundThresholdSet {
+			domain: Domain,
+			threshold: Option<u32>,
+		},
+
+		/// A relayer was added for a router.
+		RelayerAdded {
+			relayer: T::RelayerId,
+			router_id: T::RouterId,
+		},
+
+		/// A relayer was removed.
+		RelayerRemoved { relayer: T::RelayerId },
+
+		/// A message submitted by a relayer was received.
+		RelayedMessageReceived {
+			relayer: T::RelayerId,
+			nonce: u64,
+			message_hash: MessageHash,
+		},
+
+		/// An account bonded a relayer.
+		RelayerRegistered {
+			relayer: T::RelayerId,
+			account: T::AccountId,
+			bond: BalanceOf<T>,
+		},
+
+		/// The message version of a domain was set by an admin.
+		DomainMessageVersionSet {
+			domain: Domain,
+			version: MessageVersion,
+		},
+
+		/// A domain sent a message with a newer version, which is used from
+		/// now on for the messages sent to it.
+		DomainMessageVersionNegotiated {
+			domain: Domain,
+			version: MessageVersion,
+		},
+
+		/// The account of a relayer withdrew its bond.
+		RelayerUnregistered { relayer: T::RelayerId },
+
+		/// The relayer fee was updated.
+		RelayerFeeSet {
+			fee: Option<RelayerFee<BalanceOf<T>>>,
+		},
+
+		/// The fee of a delivered message was paid to a relayer.
+		RelayerFeePaid {
+			relayer: T::RelayerId,
+			payer: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+
+		/// The fee of a delivered message could not be paid to a relayer.
+		RelayerFeeFailed {
+			relayer: T::RelayerId,
+			error: DispatchError,
+		},
+
+		/// A relayer submitted a message that could not be received. Its bond
+		/// was slashed and must be bonded again.
+		RelayerSlashed {
+			relayer: T::RelayerId,
+			amount: BalanceOf<T>,
+			error: DispatchError,
+		},
+
+		/// The gateway state of a domain was exported as a SCALE encoded
+		/// `DomainState`.
+		DomainStateExported {
+			domain: Domain,
+			state: Vec<u8>,
+			removed: bool,
+		},
+
+		/// The gateway state of a domain was imported.
+		DomainStateImported { domain: Domain },
 	}
 
 	/// Storage for routers.
@@ -220,6 +632,9 @@ pub mod pallet {
 	/// Storage that contains a limited number of whitelisted instances of
 	/// deployed liquidity pools for a particular domain.
 	///
+	/// Inbound messages are only accepted from the source addresses of a
+	/// domain stored here, regardless of the router submitting them.
+	///
 	/// This can only be modified by an admin.
 	#[pallet::storage]
 	#[pallet::getter(fn allowlist)]
@@ -258,6 +673,140 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type SessionIdStore<T: Config> = StorageValue<_, T::SessionId, ValueQuery>;
 
+	/// Stores the last sequence number executed for each domain.
+	///
+	/// Lifetime: Indefinitely.
+	#[pallet::storage]
+	pub type InboundNonce<T: Config> = StorageMap<_, Blake2_128Concat, Domain, u64, ValueQuery>;
+
+	/// Stores the sequenced inbound messages that arrived ahead of their turn.
+	///
+	/// Lifetime: Until all previous sequence numbers of the domain are
+	/// executed.
+	#[pallet::storage]
+	pub type PendingSequencedMessages<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Domain,
+		Twox64Concat,
+		u64,
+		(DomainAddress, T::Message),
+	>;
+
+	/// Stores the domains for which outbound messages sent within a block are
+	/// packed in a single batch message.
+	///
+	/// Lifetime: Indefinitely.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type OutboundBatching<T: Config> = StorageMap<_, Blake2_128Concat, Domain, ()>;
+
+	/// Stores the batch of outbound messages sent to a domain in the current
+	/// block.
+	///
+	/// Lifetime: Until the beginning of the next block, or until the batch is
+	/// full.
+	#[pallet::storage]
+	pub type PendingOutboundBatch<T: Config> = StorageMap<_, Blake2_128Concat, Domain, T::Message>;
+
+	/// Weight consumed by the hooks of this pallet and the block it was
+	/// consumed in.
+	#[pallet::storage]
+	pub type LastHookWeight<T: Config> = StorageValue<_, (BlockNumberFor<T>, Weight), OptionQuery>;
+
+	/// Stores the directions in which the processing of messages of a domain
+	/// is paused.
+	///
+	/// Lifetime: Until the domain is unpaused.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type PausedDomains<T: Config> =
+		StorageMap<_, Blake2_128Concat, Domain, PauseState, ValueQuery>;
+
+	/// Stores whether the processing of messages of all domains is paused.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type EmergencyPause<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Stores the range `[first, next)` of indexes of the messages held for a
+	/// paused domain.
+	///
+	/// Lifetime: Until all the held messages of the domain are resumed.
+	#[pallet::storage]
+	pub type PausedMessageRange<T: Config> =
+		StorageMap<_, Blake2_128Concat, Domain, (u64, u64), ValueQuery>;
+
+	/// Stores the messages held while their domain was paused, by arrival
+	/// index.
+	///
+	/// Lifetime: Until the domain is resumed.
+	#[pallet::storage]
+	pub type PausedMessages<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		Domain,
+		Twox64Concat,
+		u64,
+		GatewayMessage<T::Message, T::RouterId>,
+	>;
+
+	/// Stores the quarantined message hashes, together with the messages held
+	/// for each of them.
+	///
+	/// Lifetime: Until the message is released or discarded.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type QuarantinedMessages<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		MessageHash,
+		BoundedVec<GatewayMessage<T::Message, T::RouterId>, T::MaxRouterCount>,
+	>;
+
+	/// Stores the number of routers of a domain, including the one sending the
+	/// message, that must submit an inbound message before it's executed.
+	/// All the routers of the domain are required if not set.
+	///
+	/// Lifetime: Indefinitely.
+	///
+	/// NOTE: Must only be changeable via `AdminOrigin`.
+	#[pallet::storage]
+	pub type InboundThreshold<T: Config> = StorageMap<_, Blake2_128Concat, Domain, u32>;
+
+	/// Relayers allowed to submit inbound messages as unsigned transactions.
+	///
+	/// This can only be set by an admin.
+	#[pallet::storage]
+	pub type Relayers<T: Config> = StorageMap<_, Blake2_128Concat, T::RelayerId, RelayerInfoOf<T>>;
+
+	/// The fee paid to relayers for each delivered message. No fee is paid if
+	/// not set.
+	///
+	/// This can only be set by an admin.
+	#[pallet::storage]
+	pub type RelayerFeeStore<T: Config> = StorageValue<_, RelayerFee<BalanceOf<T>>>;
+
+	/// The EVM transactions that sent each outbound message, by router.
+	#[pallet::storage]
+	pub type OutboundEvmTransactions<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MessageHash,
+		Blake2_128Concat,
+		T::RouterId,
+		OutboundEvmTransaction<BlockNumberFor<T>>,
+	>;
+
+	/// The version of the message format negotiated with each domain. The
+	/// legacy format is used if not set.
+	#[pallet::storage]
+	pub type DomainMessageVersion<T: Config> =
+		StorageMap<_, Blake2_128Concat, Domain, MessageVersion, ValueQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// The origin of the message to be processed is invalid.
@@ -321,6 +870,82 @@ pub mod pallet {
 
 		/// The domain addresses of 2 inbound entries do not match.
 		InboundEntryDomainAddressMismatch,
+
+		/// The sequence number of the message was already executed.
+		InboundNonceAlreadyExecuted,
+
+		/// A message with the same sequence number is already pending.
+		InboundNonceAlreadyPending,
+
+		/// The sequence number of the message is too far ahead of the next
+		/// expected one.
+		InboundNonceTooFarAhead,
+
+		/// There is no pending message for the next expected sequence number.
+		PendingInboundMessageNotFound,
+
+		/// The domain, or all domains, are paused.
+		DomainPaused,
+
+		/// There are no messages held for the domain.
+		NoPausedMessages,
+
+		/// The inbound threshold must require at least one router.
+		InvalidInboundThreshold,
+
+		/// The relayer was already added.
+		RelayerAlreadyAdded,
+
+		/// The relayer is not registered.
+		UnknownRelayer,
+
+		/// The nonce of the relayed message was already used.
+		RelayedNonceAlreadyUsed,
+
+		/// The nonce of the relayed message is ahead of the expected one.
+		RelayedNonceTooHigh,
+
+		/// The signature of the relayed message is not valid for its relayer.
+		InvalidRelayerSignature,
+
+		/// The payload of the relayed message exceeds the maximum size.
+		RelayedMessageTooLarge,
+
+		/// The relayer has no bond yet.
+		RelayerNotBonded,
+
+		/// The relayer was already bonded by an account.
+		RelayerAlreadyBonded,
+
+		/// The caller is not the account that bonded the relayer.
+		NotRelayerAccount,
+
+		/// The message version is not supported by this chain.
+		UnsupportedMessageVersion,
+
+		/// The message hash is already quarantined.
+		MessageAlreadyQuarantined,
+
+		/// The message hash is not quarantined.
+		MessageNotQuarantined,
+
+		/// The maximum number of messages held for a quarantined message hash
+		/// was reached.
+		QuarantineFull,
+
+		/// The exported domain state can not be decoded.
+		InvalidDomainState,
+
+		/// The domain already has gateway state.
+		DomainStateNotEmpty,
+
+		/// The domain has more allowlisted instances, sequenced and paused
+		/// messages than the given maximum.
+		TooManyDomainStateItems,
+
+		/// The outbound message exceeds the maximum payload size of the
+		/// routers of its domain and can not be split.
+		OutboundMessageTooLarge,
 	}
 
 	#[pallet::call]
@@ -496,7 +1121,8 @@ pub mod pallet {
 				},
 			)?;
 
-			let expected_proof_count = Self::get_expected_proof_count(&router_ids)?;
+			let expected_proof_count =
+				Self::get_expected_proof_count(domain_address.domain(), &router_ids)?;
 
 			Self::execute_if_requirements_are_met(
 				message_hash,
@@ -518,21 +1144,21 @@ pub mod pallet {
 		/// messaging router.
 		///
 		/// Can only be called by `AdminOrigin`.
-		#[pallet::weight(T::WeightInfo::initiate_message_recovery())]
+		#[pallet::weight(T::WeightInfo::initiate_message_recovery().saturating_add(LP_DEFENSIVE_WEIGHT))]
 		#[pallet::call_index(12)]
 		pub fn initiate_message_recovery(
 			origin: OriginFor<T>,
 			message_hash: MessageHash,
 			recovery_router: [u8; 32],
 			messaging_router: T::RouterId,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			T::AdminOrigin::ensure_origin(origin)?;
 
 			let domain = messaging_router.clone().into();
 
 			let message = T::Message::initiate_recovery_message(message_hash, recovery_router);
 
-			Self::send_recovery_message(domain, message, messaging_router.clone())?;
+			let post_info = Self::send_recovery_message(domain, message, messaging_router.clone())?;
 
 			Self::deposit_event(Event::<T>::MessageRecoveryInitiated {
 				domain,
@@ -541,28 +1167,32 @@ pub mod pallet {
 				messaging_router,
 			});
 
-			Ok(())
+			Ok(Some(
+				T::WeightInfo::initiate_message_recovery()
+					.saturating_add(Self::sending_weight(&post_info)),
+			)
+			.into())
 		}
 
 		/// Sends a message that disputes a message recovery using the
 		/// messaging router.
 		///
 		/// Can only be called by `AdminOrigin`.
-		#[pallet::weight(T::WeightInfo::dispute_message_recovery())]
+		#[pallet::weight(T::WeightInfo::dispute_message_recovery().saturating_add(LP_DEFENSIVE_WEIGHT))]
 		#[pallet::call_index(13)]
 		pub fn dispute_message_recovery(
 			origin: OriginFor<T>,
 			message_hash: MessageHash,
 			recovery_router: [u8; 32],
 			messaging_router: T::RouterId,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			T::AdminOrigin::ensure_origin(origin)?;
 
 			let domain = messaging_router.clone().into();
 
 			let message = T::Message::dispute_recovery_message(message_hash, recovery_router);
 
-			Self::send_recovery_message(domain, message, messaging_router.clone())?;
+			let post_info = Self::send_recovery_message(domain, message, messaging_router.clone())?;
 
 			Self::deposit_event(Event::<T>::MessageRecoveryDisputed {
 				domain,
@@ -571,49 +1201,916 @@ pub mod pallet {
 				messaging_router,
 			});
 
-			Ok(())
+			Ok(Some(
+				T::WeightInfo::dispute_message_recovery()
+					.saturating_add(Self::sending_weight(&post_info)),
+			)
+			.into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		fn send_recovery_message(
+		/// Executes the pending message with the next expected sequence number
+		/// of a domain, and any following pending messages.
+		///
+		/// Used to retry a deferred message that failed when its turn came.
+		#[pallet::weight(T::WeightInfo::execute_pending_message())]
+		#[pallet::call_index(14)]
+		pub fn execute_pending_message(origin: OriginFor<T>, domain: Domain) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let nonce = Self::next_inbound_nonce(domain)?;
+			let (domain_address, message) = PendingSequencedMessages::<T>::take(domain, nonce)
+				.ok_or(Error::<T>::PendingInboundMessageNotFound)?;
+
+			Self::execute_inbound_message(domain_address, message.get_message_hash(), message)
+		}
+
+		/// Enables or disables the automatic batching of the outbound messages
+		/// sent to a domain within a block.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_outbound_batching())]
+		#[pallet::call_index(15)]
+		pub fn set_outbound_batching(
+			origin: OriginFor<T>,
 			domain: Domain,
-			message: T::Message,
-			messaging_router: T::RouterId,
+			enabled: bool,
 		) -> DispatchResult {
-			let router_ids = Self::get_router_ids_for_domain(domain)?;
+			T::AdminOrigin::ensure_origin(origin)?;
 
-			ensure!(
-				router_ids.iter().any(|x| x == &messaging_router),
-				Error::<T>::MessagingRouterNotFound
-			);
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
 
-			T::MessageSender::send(messaging_router, T::Sender::get(), message)
-		}
-	}
+			if enabled {
+				OutboundBatching::<T>::insert(domain, ());
+			} else {
+				OutboundBatching::<T>::remove(domain);
+			}
 
-	impl<T: Config> OutboundMessageHandler for Pallet<T> {
-		type Destination = Domain;
-		type Message = T::Message;
-		type Sender = T::AccountId;
+			Self::deposit_event(Event::OutboundBatchingSet { domain, enabled });
 
-		fn handle(
-			from: Self::Sender,
-			destination: Self::Destination,
-			message: Self::Message,
+			Ok(())
+		}
+
+		/// Pauses or unpauses the processing of inbound and outbound messages
+		/// of a domain. Messages processed while paused are held.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_domain_pause())]
+		#[pallet::call_index(16)]
+		pub fn set_domain_pause(
+			origin: OriginFor<T>,
+			domain: Domain,
+			state: PauseState,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+
+			if state == PauseState::default() {
+				PausedDomains::<T>::remove(domain);
+			} else {
+				PausedDomains::<T>::insert(domain, state);
+			}
+
+			Self::deposit_event(Event::DomainPauseSet { domain, state });
+
+			Ok(())
+		}
+
+		/// Pauses or unpauses the processing of messages of all domains in
+		/// both directions. Messages processed while paused are held.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_emergency_pause())]
+		#[pallet::call_index(17)]
+		pub fn set_emergency_pause(origin: OriginFor<T>, paused: bool) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			EmergencyPause::<T>::set(paused);
+
+			Self::deposit_event(Event::EmergencyPauseSet { paused });
+
+			Ok(())
+		}
+
+		/// Queues again, in arrival order, up to `max` messages held while the
+		/// domain was paused.
+		///
+		/// Fails if the domain is still paused in any direction.
+		#[pallet::weight(T::WeightInfo::resume_paused_messages(*max))]
+		#[pallet::call_index(18)]
+		pub fn resume_paused_messages(
+			origin: OriginFor<T>,
+			domain: Domain,
+			max: u32,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				!EmergencyPause::<T>::get()
+					&& PausedDomains::<T>::get(domain) == PauseState::default(),
+				Error::<T>::DomainPaused
+			);
+
+			let count = Self::resume_paused_messages_for(domain, max)?;
+
+			Self::deposit_event(Event::PausedMessagesResumed { domain, count });
+
+			Ok(())
+		}
+
+		/// Sets the number of routers of a domain, including the one sending
+		/// the message, that must submit an inbound message before it's
+		/// executed. Passing `None` requires all the routers of the domain.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_inbound_threshold())]
+		#[pallet::call_index(19)]
+		pub fn set_inbound_threshold(
+			origin: OriginFor<T>,
+			domain: Domain,
+			threshold: Option<u32>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+			ensure!(threshold != Some(0), Error::<T>::InvalidInboundThreshold);
+
+			InboundThreshold::<T>::set(domain, threshold);
+
+			Self::deposit_event(Event::InboundThresholdSet { domain, threshold });
+
+			Ok(())
+		}
+
+		/// Add a relayer that submits inbound messages on behalf of a router.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::add_relayer())]
+		#[pallet::call_index(20)]
+		pub fn add_relayer(
+			origin: OriginFor<T>,
+			relayer: T::RelayerId,
+			router_id: T::RouterId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Routers::<T>::get().contains(&router_id),
+				Error::<T>::UnknownRouter
+			);
+			ensure!(
+				!Relayers::<T>::contains_key(&relayer),
+				Error::<T>::RelayerAlreadyAdded
+			);
+
+			Relayers::<T>::insert(
+				&relayer,
+				RelayerInfo {
+					router_id: router_id.clone(),
+					nonce: 0,
+					reputation: 0,
+					account: None,
+					bond: Default::default(),
+				},
+			);
+
+			Self::deposit_event(Event::RelayerAdded { relayer, router_id });
+
+			Ok(())
+		}
+
+		/// Remove a relayer, releasing its bond.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::remove_relayer())]
+		#[pallet::call_index(21)]
+		pub fn remove_relayer(origin: OriginFor<T>, relayer: T::RelayerId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let info = Relayers::<T>::take(&relayer).ok_or(Error::<T>::UnknownRelayer)?;
+
+			if let Some(account) = info.account {
+				T::Currency::unreserve(&account, info.bond);
+			}
+
+			Self::deposit_event(Event::RelayerRemoved { relayer });
+
+			Ok(())
+		}
+
+		/// Receive an inbound message submitted by a registered relayer as an
+		/// unsigned transaction. The message is handled as if it was received
+		/// by the router of the relayer.
+		#[pallet::weight(T::WeightInfo::receive_message())]
+		#[pallet::call_index(22)]
+		pub fn receive_relayed_message(
+			origin: OriginFor<T>,
+			message: RelayedMessage<T::RelayerId>,
+			signature: <T::RelayerId as RuntimeAppPublic>::Signature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let mut info = Self::check_relayed_message(&message, &signature)?;
+			info.nonce.ensure_add_assign(1)?;
+
+			let RelayedMessage {
+				relayer,
+				nonce,
+				domain_address,
+				payload,
+			} = message;
+
+			let res = with_transaction(|| {
+				let res = Self::receive_relayed_payload(
+					info.router_id.clone(),
+					&domain_address,
+					&payload,
+				);
+
+				if res.is_ok() {
+					TransactionOutcome::Commit(res)
+				} else {
+					TransactionOutcome::Rollback(res)
+				}
+			});
+
+			// A failing message is still signed by the relayer, so the relayer
+			// is slashed instead of failing the extrinsic.
+			match res {
+				Ok(message_hash) => {
+					info.reputation = info.reputation.saturating_add(1);
+					Self::pay_relayer_fee(&relayer, &info, &domain_address);
+					Relayers::<T>::insert(&relayer, info);
+
+					Self::deposit_event(Event::RelayedMessageReceived {
+						relayer,
+						nonce,
+						message_hash,
+					});
+				}
+				Err(error) => Self::slash_relayer(relayer, info, error),
+			}
+
+			Ok(())
+		}
+
+		/// Bond a relayer approved by an admin, reserving `RelayerBond` from
+		/// the caller. The caller receives the fees of the relayer.
+		#[pallet::weight(T::WeightInfo::register_relayer())]
+		#[pallet::call_index(23)]
+		pub fn register_relayer(origin: OriginFor<T>, relayer: T::RelayerId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Relayers::<T>::try_mutate(&relayer, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::UnknownRelayer)?;
+				ensure!(info.account.is_none(), Error::<T>::RelayerAlreadyBonded);
+
+				let bond = T::RelayerBond::get();
+				T::Currency::reserve(&who, bond)?;
+
+				info.account = Some(who.clone());
+				info.bond = bond;
+
+				Self::deposit_event(Event::RelayerRegistered {
+					relayer: relayer.clone(),
+					account: who,
+					bond,
+				});
+
+				Ok(())
+			})
+		}
+
+		/// Withdraw the bond of a relayer. The relayer stays approved and can
+		/// be bonded again.
+		///
+		/// Can only be called by the account that bonded the relayer.
+		#[pallet::weight(T::WeightInfo::unregister_relayer())]
+		#[pallet::call_index(24)]
+		pub fn unregister_relayer(origin: OriginFor<T>, relayer: T::RelayerId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Relayers::<T>::try_mutate(&relayer, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::UnknownRelayer)?;
+				ensure!(
+					info.account.as_ref() == Some(&who),
+					Error::<T>::NotRelayerAccount
+				);
+
+				T::Currency::unreserve(&who, info.bond);
+
+				info.account = None;
+				info.bond = Default::default();
+
+				Self::deposit_event(Event::RelayerUnregistered {
+					relayer: relayer.clone(),
+				});
+
+				Ok(())
+			})
+		}
+
+		/// Set the fee paid to relayers for each delivered message. Passing
+		/// `None` stops paying relayers.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_relayer_fee())]
+		#[pallet::call_index(25)]
+		pub fn set_relayer_fee(
+			origin: OriginFor<T>,
+			fee: Option<RelayerFee<BalanceOf<T>>>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			RelayerFeeStore::<T>::set(fee.clone());
+
+			Self::deposit_event(Event::RelayerFeeSet { fee });
+
+			Ok(())
+		}
+
+		/// Set the version of the message format used with a domain, i.e. to
+		/// go back to an older version for a domain that can not decode the
+		/// negotiated one.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::set_domain_message_version())]
+		#[pallet::call_index(26)]
+		pub fn set_domain_message_version(
+			origin: OriginFor<T>,
+			domain: Domain,
+			version: MessageVersion,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+			ensure!(
+				version <= T::Message::LATEST_VERSION,
+				Error::<T>::UnsupportedMessageVersion
+			);
+
+			DomainMessageVersion::<T>::insert(domain, version);
+
+			Self::deposit_event(Event::DomainMessageVersionSet { domain, version });
+
+			Ok(())
+		}
+
+		/// Quarantines a message by its hash. Inbound and outbound messages
+		/// with that hash, including proofs of it, are held instead of being
+		/// executed or delivered.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::quarantine_message())]
+		#[pallet::call_index(27)]
+		pub fn quarantine_message(
+			origin: OriginFor<T>,
+			message_hash: MessageHash,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				!QuarantinedMessages::<T>::contains_key(message_hash),
+				Error::<T>::MessageAlreadyQuarantined
+			);
+
+			QuarantinedMessages::<T>::insert(message_hash, BoundedVec::default());
+
+			Self::deposit_event(Event::MessageQuarantined { message_hash });
+
+			Ok(())
+		}
+
+		/// Lifts the quarantine of a message hash, queuing again the messages
+		/// held for it.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::release_quarantined_message(T::MaxRouterCount::get()))]
+		#[pallet::call_index(28)]
+		pub fn release_quarantined_message(
+			origin: OriginFor<T>,
+			message_hash: MessageHash,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let messages = QuarantinedMessages::<T>::take(message_hash)
+				.ok_or(Error::<T>::MessageNotQuarantined)?;
+
+			let count = messages.len() as u32;
+			for message in messages {
+				T::MessageQueue::queue(message)?;
+			}
+
+			Self::deposit_event(Event::QuarantinedMessageReleased {
+				message_hash,
+				count,
+			});
+
+			Ok(())
+		}
+
+		/// Lifts the quarantine of a message hash, dropping the messages held
+		/// for it.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::discard_quarantined_message())]
+		#[pallet::call_index(29)]
+		pub fn discard_quarantined_message(
+			origin: OriginFor<T>,
+			message_hash: MessageHash,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let messages = QuarantinedMessages::<T>::take(message_hash)
+				.ok_or(Error::<T>::MessageNotQuarantined)?;
+
+			Self::deposit_event(Event::QuarantinedMessageDiscarded {
+				message_hash,
+				count: messages.len() as u32,
+			});
+
+			Ok(())
+		}
+
+		/// Exports the gateway state of a domain in a
+		/// `DomainStateExported` event, removing it from storage if `remove`
+		/// is set.
+		///
+		/// `max_items` bounds the number of allowlisted instances, sequenced
+		/// and paused messages of the domain. The export fails if the domain
+		/// has more of them.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::export_domain_state(*max_items))]
+		#[pallet::call_index(30)]
+		pub fn export_domain_state(
+			origin: OriginFor<T>,
+			domain: Domain,
+			remove: bool,
+			max_items: u32,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+
+			let state = Self::bounded_domain_state(domain, max_items)?;
+
+			if remove {
+				Self::remove_domain_state(domain, max_items);
+			}
+
+			Self::deposit_event(Event::DomainStateExported {
+				domain,
+				state: state.encode(),
+				removed: remove,
+			});
+
+			Ok(())
+		}
+
+		/// Imports a gateway state exported with `export_domain_state()` into
+		/// a domain without state. Addresses of the exported state are bound
+		/// to the given domain, which can differ from the exported one.
+		///
+		/// Can only be called by `AdminOrigin`.
+		#[pallet::weight(T::WeightInfo::import_domain_state(state.len() as u32))]
+		#[pallet::call_index(31)]
+		pub fn import_domain_state(
+			origin: OriginFor<T>,
+			domain: Domain,
+			state: Vec<u8>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(domain != Domain::Centrifuge, Error::<T>::DomainNotSupported);
+
+			let state = DomainStateOf::<T>::decode(&mut state.as_slice())
+				.map_err(|_| Error::<T>::InvalidDomainState)?;
+
+			ensure!(
+				Self::bounded_domain_state(domain, 0).map_or(false, |state| state.is_empty()),
+				Error::<T>::DomainStateNotEmpty
+			);
+
+			Self::insert_domain_state(domain, state.rebind(domain));
+
+			Self::deposit_event(Event::DomainStateImported { domain });
+
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			let Call::receive_relayed_message { message, signature } = call else {
+				return InvalidTransaction::Call.into();
+			};
+
+			let info = Self::check_relayed_message(message, signature).map_err(|e| match e {
+				Error::<T>::UnknownRelayer | Error::<T>::RelayerNotBonded => {
+					InvalidTransaction::BadSigner
+				}
+				Error::<T>::RelayedNonceAlreadyUsed => InvalidTransaction::Stale,
+				Error::<T>::RelayedNonceTooHigh => InvalidTransaction::Future,
+				Error::<T>::RelayedMessageTooLarge => InvalidTransaction::ExhaustsResources,
+				_ => InvalidTransaction::BadProof,
+			})?;
+
+			ValidTransaction::with_tag_prefix("LPGatewayRelayer")
+				.priority(T::UnsignedPriority::get().saturating_add(info.reputation.into()))
+				.and_provides((message.relayer.clone(), message.nonce))
+				.propagate(true)
+				.build()
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			// Reads the pending batches, even if there are none
+			let read_weight = T::DbWeight::get().reads(1);
+			let weight = Self::queue_pending_outbound_batches();
+			if weight.is_zero() {
+				return read_weight;
+			}
+
+			let weight = weight.saturating_add(read_weight);
+			LastHookWeight::<T>::record(now, weight);
+
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Weight consumed by the hooks of this pallet in the current block.
+		pub fn hook_weight() -> Weight {
+			LastHookWeight::<T>::consumed_in(frame_system::Pallet::<T>::block_number())
+		}
+
+		/// Collects the gateway state stored for a domain.
+		pub fn domain_state(domain: Domain) -> DomainStateOf<T> {
+			DomainState {
+				instances: Allowlist::<T>::iter_key_prefix(domain).collect(),
+				hook_address: DomainHookAddress::<T>::get(domain),
+				inbound_nonce: InboundNonce::<T>::get(domain),
+				pending_sequenced_messages: PendingSequencedMessages::<T>::iter_prefix(domain)
+					.map(|(nonce, (address, message))| (nonce, address, message))
+					.collect(),
+				outbound_batching: OutboundBatching::<T>::contains_key(domain),
+				pending_outbound_batch: PendingOutboundBatch::<T>::get(domain),
+				pause_state: PausedDomains::<T>::get(domain),
+				paused_message_range: PausedMessageRange::<T>::get(domain),
+				paused_messages: PausedMessages::<T>::iter_prefix(domain).collect(),
+				inbound_threshold: InboundThreshold::<T>::get(domain),
+				message_version: DomainMessageVersion::<T>::get(domain),
+			}
+		}
+
+		/// Collects the gateway state stored for a domain, failing if it has
+		/// more than `max_items` allowlisted instances, sequenced and paused
+		/// messages.
+		fn bounded_domain_state(
+			domain: Domain,
+			max_items: u32,
+		) -> Result<DomainStateOf<T>, DispatchError> {
+			// One more item than allowed is read to detect the excess
+			let limit = (max_items as usize).saturating_add(1);
+
+			let instances: Vec<_> = Allowlist::<T>::iter_key_prefix(domain)
+				.take(limit)
+				.collect();
+			let limit = limit.saturating_sub(instances.len());
+
+			let pending_sequenced_messages: Vec<_> =
+				PendingSequencedMessages::<T>::iter_prefix(domain)
+					.take(limit)
+					.map(|(nonce, (address, message))| (nonce, address, message))
+					.collect();
+			let limit = limit.saturating_sub(pending_sequenced_messages.len());
+
+			let paused_messages: Vec<_> = PausedMessages::<T>::iter_prefix(domain)
+				.take(limit)
+				.collect();
+
+			ensure!(
+				instances.len() + pending_sequenced_messages.len() + paused_messages.len()
+					<= max_items as usize,
+				Error::<T>::TooManyDomainStateItems
+			);
+
+			Ok(DomainState {
+				instances,
+				hook_address: DomainHookAddress::<T>::get(domain),
+				inbound_nonce: InboundNonce::<T>::get(domain),
+				pending_sequenced_messages,
+				outbound_batching: OutboundBatching::<T>::contains_key(domain),
+				pending_outbound_batch: PendingOutboundBatch::<T>::get(domain),
+				pause_state: PausedDomains::<T>::get(domain),
+				paused_message_range: PausedMessageRange::<T>::get(domain),
+				paused_messages,
+				inbound_threshold: InboundThreshold::<T>::get(domain),
+				message_version: DomainMessageVersion::<T>::get(domain),
+			})
+		}
+
+		/// Removes the gateway state of a domain with at most `max_items`
+		/// allowlisted instances, sequenced and paused messages.
+		fn remove_domain_state(domain: Domain, max_items: u32) {
+			let _ = Allowlist::<T>::clear_prefix(domain, max_items, None);
+			DomainHookAddress::<T>::remove(domain);
+			InboundNonce::<T>::remove(domain);
+			let _ = PendingSequencedMessages::<T>::clear_prefix(domain, max_items, None);
+			OutboundBatching::<T>::remove(domain);
+			PendingOutboundBatch::<T>::remove(domain);
+			PausedDomains::<T>::remove(domain);
+			PausedMessageRange::<T>::remove(domain);
+			let _ = PausedMessages::<T>::clear_prefix(domain, max_items, None);
+			InboundThreshold::<T>::remove(domain);
+			DomainMessageVersion::<T>::remove(domain);
+		}
+
+		fn insert_domain_state(domain: Domain, state: DomainStateOf<T>) {
+			for instance in state.instances {
+				Allowlist::<T>::insert(domain, instance, ());
+			}
+			DomainHookAddress::<T>::set(domain, state.hook_address);
+			InboundNonce::<T>::insert(domain, state.inbound_nonce);
+			for (nonce, address, message) in state.pending_sequenced_messages {
+				PendingSequencedMessages::<T>::insert(domain, nonce, (address, message));
+			}
+			if state.outbound_batching {
+				OutboundBatching::<T>::insert(domain, ());
+			}
+			PendingOutboundBatch::<T>::set(domain, state.pending_outbound_batch);
+			PausedDomains::<T>::insert(domain, state.pause_state);
+			PausedMessageRange::<T>::insert(domain, state.paused_message_range);
+			for (index, message) in state.paused_messages {
+				PausedMessages::<T>::insert(domain, index, message);
+			}
+			InboundThreshold::<T>::set(domain, state.inbound_threshold);
+			DomainMessageVersion::<T>::insert(domain, state.message_version);
+		}
+
+		/// The payload a relayer signs for a relayed message: the message
+		/// prefixed with `RELAYED_MESSAGE_SIGNING_CONTEXT` and the genesis hash
+		/// of the chain, so it can not be replayed on other chains where the
+		/// relayer is registered.
+		pub fn relayed_message_signing_payload(message: &RelayedMessage<T::RelayerId>) -> Vec<u8> {
+			(
+				RELAYED_MESSAGE_SIGNING_CONTEXT,
+				frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero()),
+				message,
+			)
+				.encode()
+		}
+
+		/// Checks that a relayed message comes from a bonded relayer, with
+		/// its next nonce and a valid signature, returning the relayer info.
+		fn check_relayed_message(
+			message: &RelayedMessage<T::RelayerId>,
+			signature: &<T::RelayerId as RuntimeAppPublic>::Signature,
+		) -> Result<RelayerInfoOf<T>, Error<T>> {
+			let info = Relayers::<T>::get(&message.relayer).ok_or(Error::<T>::UnknownRelayer)?;
+
+			ensure!(info.account.is_some(), Error::<T>::RelayerNotBonded);
+
+			ensure!(
+				message.nonce >= info.nonce,
+				Error::<T>::RelayedNonceAlreadyUsed
+			);
+			ensure!(message.nonce == info.nonce, Error::<T>::RelayedNonceTooHigh);
+			ensure!(
+				message.payload.len() <= T::MaxIncomingMessageSize::get() as usize,
+				Error::<T>::RelayedMessageTooLarge
+			);
+			ensure!(
+				message
+					.relayer
+					.verify(&Self::relayed_message_signing_payload(message), signature),
+				Error::<T>::InvalidRelayerSignature
+			);
+
+			Ok(info)
+		}
+
+		/// Receives the payload of a relayed message as if it was sent by the
+		/// router of the relayer, returning the message hash.
+		fn receive_relayed_payload(
+			router_id: T::RouterId,
+			domain_address: &DomainAddress,
+			payload: &[u8],
+		) -> Result<MessageHash, DispatchError> {
+			let (lp_message, version) = T::Message::deserialize_versioned(payload)?;
+			let message_hash = lp_message.get_message_hash();
+
+			<Self as MessageReceiver>::receive(router_id, domain_address.clone(), lp_message)?;
+			<Self as MessageVersions<Domain>>::note_inbound_version(
+				&domain_address.domain(),
+				version,
+			);
+
+			Ok(message_hash)
+		}
+
+		/// The account of the gateway fee pot.
+		pub fn fee_pot() -> T::AccountId {
+			T::FeePotId::get().into_account_truncating()
+		}
+
+		/// Pays the relayer fee of a delivered message, if any. A failing
+		/// payment does not affect the delivery of the message.
+		fn pay_relayer_fee(
+			relayer: &T::RelayerId,
+			info: &RelayerInfoOf<T>,
+			domain_address: &DomainAddress,
+		) {
+			let (Some(fee), Some(account)) = (RelayerFeeStore::<T>::get(), info.account.as_ref())
+			else {
+				return;
+			};
+
+			let payer = match fee.payer {
+				RelayerFeePayer::FeePot => Self::fee_pot(),
+				RelayerFeePayer::Originator => domain_address.account(),
+			};
+
+			match T::Currency::transfer(
+				&payer,
+				account,
+				fee.amount,
+				ExistenceRequirement::KeepAlive,
+			) {
+				Ok(()) => Self::deposit_event(Event::RelayerFeePaid {
+					relayer: relayer.clone(),
+					payer,
+					amount: fee.amount,
+				}),
+				Err(error) => Self::deposit_event(Event::RelayerFeeFailed {
+					relayer: relayer.clone(),
+					error,
+				}),
+			}
+		}
+
+		/// Slashes the bond of a relayer into the fee pot. The relayer keeps
+		/// its nonce, so its past messages can not be replayed, but must be
+		/// bonded again before submitting new ones.
+		fn slash_relayer(relayer: T::RelayerId, mut info: RelayerInfoOf<T>, error: DispatchError) {
+			let amount = match info.account.take() {
+				Some(account) => match T::Currency::repatriate_reserved(
+					&account,
+					&Self::fee_pot(),
+					info.bond,
+					BalanceStatus::Free,
+				) {
+					Ok(remaining) => info.bond.saturating_sub(remaining),
+					// The fee pot can not receive the bond, which is burnt instead.
+					Err(_) => T::Currency::slash_reserved(&account, info.bond).0.peek(),
+				},
+				None => Default::default(),
+			};
+
+			info.bond = Default::default();
+			Relayers::<T>::insert(&relayer, info);
+
+			Self::deposit_event(Event::RelayerSlashed {
+				relayer,
+				amount,
+				error,
+			});
+		}
+
+		fn send_recovery_message(
+			domain: Domain,
+			message: T::Message,
+			messaging_router: T::RouterId,
+		) -> DispatchResultWithPostInfo {
+			let router_ids = Self::get_router_ids_for_domain(domain)?;
+
+			ensure!(
+				router_ids.iter().any(|x| x == &messaging_router),
+				Error::<T>::MessagingRouterNotFound
+			);
+
+			T::MessageSender::send(messaging_router, T::Sender::get(), message)
+		}
+
+		/// Sends an outbound message through the router, returning the
+		/// weight consumed.
+		fn process_outbound_message(
+			router_id: T::RouterId,
+			message: T::Message,
+		) -> (DispatchResult, Weight) {
+			let message_hash = message.get_message_hash();
+			let last_transaction = T::EthereumTransactions::last_transaction();
+			let res = T::OutboundMessageTracker::on_send(router_id.clone().into(), &message)
+				.map_err(DispatchErrorWithPostInfo::from)
+				.and_then(|_| T::MessageSender::send(router_id.clone(), T::Sender::get(), message));
+
+			if res.is_ok() {
+				T::ActivityRecorder::record(ActivityKind::Message, &message_hash);
+				Self::record_evm_transaction(message_hash, router_id, last_transaction);
+			}
+
+			let post_info = match &res {
+				Ok(post_info) => *post_info,
+				Err(e) => e.post_info,
+			};
+
+			(
+				res.map(|_| ()).map_err(|e| e.error),
+				Self::sending_weight(&post_info),
+			)
+		}
+
+		/// Records the EVM transaction executed by the router to send a
+		/// message, if the router executed any.
+		fn record_evm_transaction(
+			message_hash: MessageHash,
+			router_id: T::RouterId,
+			previous: Option<TransactionRef>,
+		) {
+			let Some(transaction) = T::EthereumTransactions::last_transaction() else {
+				return;
+			};
+
+			if Some(transaction) != previous {
+				OutboundEvmTransactions::<T>::insert(
+					message_hash,
+					router_id,
+					OutboundEvmTransaction {
+						block_number: frame_system::Pallet::<T>::block_number(),
+						transaction,
+					},
+				);
+			}
+		}
+
+		/// Returns the EVM transactions that sent an outbound message.
+		pub fn outbound_evm_transactions(
+			message_hash: MessageHash,
+		) -> Vec<(T::RouterId, OutboundEvmTransaction<BlockNumberFor<T>>)> {
+			OutboundEvmTransactions::<T>::iter_prefix(message_hash).collect()
+		}
+
+		/// Weight of sending a message, including the tracking overhead.
+		/// Routers not reporting their weight are charged defensively.
+		fn sending_weight(post_info: &PostDispatchInfo) -> Weight {
+			post_info
+				.actual_weight
+				.map_or(LP_DEFENSIVE_WEIGHT, |weight| {
+					weight.saturating_add(T::DbWeight::get().reads_writes(4, 2))
+				})
+		}
+	}
+
+	impl<T: Config> OutboundMessageHandler for Pallet<T> {
+		type Destination = Domain;
+		type Message = T::Message;
+		type Sender = T::AccountId;
+
+		fn handle(
+			from: Self::Sender,
+			destination: Self::Destination,
+			message: Self::Message,
 		) -> DispatchResult {
 			ensure!(
 				destination != Domain::Centrifuge,
 				Error::<T>::DomainNotSupported
 			);
 
+			Self::ensure_outbound_message_size(destination, &message)?;
+
 			PackedMessage::<T>::mutate((&from, destination), |batch| match batch {
 				Some(batch) => batch.pack_with(message),
+				None if OutboundBatching::<T>::contains_key(destination) => {
+					Self::batch_outbound_message(destination, message)
+				}
 				None => Self::queue_outbound_message(destination, message),
 			})
 		}
 	}
 
+	impl<T: Config> MessageVersions<Domain> for Pallet<T> {
+		fn outbound_version(domain: &Domain) -> MessageVersion {
+			DomainMessageVersion::<T>::get(domain)
+		}
+
+		fn note_inbound_version(domain: &Domain, version: MessageVersion) {
+			if version > DomainMessageVersion::<T>::get(domain) {
+				DomainMessageVersion::<T>::insert(domain, version);
+
+				Self::deposit_event(Event::DomainMessageVersionNegotiated {
+					domain: *domain,
+					version,
+				});
+			}
+		}
+	}
+
 	impl<T: Config> GetByKey<Domain, Option<[u8; 20]>> for Pallet<T> {
 		fn get(domain: &Domain) -> Option<[u8; 20]> {
 			DomainHookAddress::<T>::get(domain)
@@ -624,6 +2121,24 @@ pub mod pallet {
 		type Message = GatewayMessage<T::Message, T::RouterId>;
 
 		fn process(msg: Self::Message) -> (DispatchResult, Weight) {
+			let message_hash = match &msg {
+				GatewayMessage::Inbound { message, .. } => message.get_message_hash(),
+				GatewayMessage::Outbound { message, .. } => message.get_message_hash(),
+			};
+
+			if QuarantinedMessages::<T>::contains_key(message_hash) {
+				return (
+					Self::hold_quarantined_message(message_hash, msg),
+					LP_DEFENSIVE_WEIGHT,
+				);
+			}
+
+			if let Some(domain) = Self::paused_domain_of(&msg) {
+				return (Self::hold_paused_message(domain, msg), LP_DEFENSIVE_WEIGHT);
+			}
+
+			let mut weight = LP_DEFENSIVE_WEIGHT;
+
 			// The #[transactional] macro only works for functions that return a
 			// `DispatchResult` therefore, we need to manually add this here.
 			let res = with_transaction(|| {
@@ -634,7 +2149,10 @@ pub mod pallet {
 						router_id,
 					} => Self::process_inbound_message(domain_address, message, router_id),
 					GatewayMessage::Outbound { message, router_id } => {
-						T::MessageSender::send(router_id, T::Sender::get(), message)
+						let (res, outbound_weight) =
+							Self::process_outbound_message(router_id, message);
+						weight = outbound_weight;
+						res
 					}
 				};
 
@@ -645,7 +2163,7 @@ pub mod pallet {
 				}
 			});
 
-			(res, LP_DEFENSIVE_WEIGHT)
+			(res, weight)
 		}
 
 		/// Returns the maximum weight for processing one message.
@@ -1,20 +1,27 @@
-use cfg_traits::liquidity_pools::{
-	InboundMessageHandler, LpMessageHash, LpMessageProof, MessageHash, MessageQueue, RouterProvider,
+use cfg_traits::{
+	activity::{ActivityKind, ActivityRecorder},
+	liquidity_pools::{
+		InboundMessageHandler, LpMessageBatch, LpMessageHash, LpMessageProof, LpMessageSequence,
+		LpMessageSerializer, MessageHash, MessageQueue, MessageSender, RouterProvider,
+	},
 };
 use cfg_types::domain_address::{Domain, DomainAddress};
 use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
-	pallet_prelude::{Decode, Encode, TypeInfo},
+	pallet_prelude::{Decode, Encode, Get, TypeInfo},
+	storage::{with_transaction, TransactionOutcome},
+	weights::Weight,
 };
 use parity_scale_codec::MaxEncodedLen;
-use sp_arithmetic::traits::{EnsureAddAssign, EnsureSub, SaturatedConversion};
+use sp_arithmetic::traits::{EnsureAdd, EnsureAddAssign, EnsureSub, SaturatedConversion};
 use sp_runtime::DispatchError;
 use sp_std::vec::Vec;
 
 use crate::{
-	message::GatewayMessage, Config, Error, Event, Pallet, PendingInboundEntries, Routers,
-	SessionIdStore,
+	message::GatewayMessage, Allowlist, Config, DomainMessageVersion, EmergencyPause, Error, Event,
+	InboundNonce, InboundThreshold, Pallet, PausedDomains, PausedMessageRange, PausedMessages,
+	PendingInboundEntries, PendingOutboundBatch, PendingSequencedMessages, Routers, SessionIdStore,
 };
 
 /// Type that holds the information needed for inbound message entries.
@@ -280,16 +287,23 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Calculates and returns the proof count required for processing one
-	/// inbound message.
+	/// inbound message of a domain. All the routers of the domain other than
+	/// the one sending the message must send a proof, unless an inbound
+	/// threshold is set for the domain.
 	pub(crate) fn get_expected_proof_count(
+		domain: Domain,
 		router_ids: &[T::RouterId],
 	) -> Result<u32, DispatchError> {
-		let expected_proof_count = router_ids
+		let proof_count: u32 = router_ids
 			.len()
 			.ensure_sub(1)
-			.map_err(|_| Error::<T>::NotEnoughRoutersForDomain)?;
+			.map_err(|_| Error::<T>::NotEnoughRoutersForDomain)?
+			.saturated_into();
 
-		Ok(expected_proof_count.saturated_into())
+		Ok(match InboundThreshold::<T>::get(domain) {
+			Some(threshold) => proof_count.min(threshold.saturating_sub(1)),
+			None => proof_count,
+		})
 	}
 
 	/// Upserts an inbound entry for a particular message, increasing the
@@ -316,6 +330,10 @@ impl<T: Config> Pallet<T> {
 	/// Checks if the number of proofs required for executing one message
 	/// were received, and if so, decreases the counts accordingly and executes
 	/// the message.
+	///
+	/// Only the entries of the message router and of the first
+	/// `expected_proof_count` voting routers are consumed, so the proofs of
+	/// the remaining routers count towards the next identical message.
 	pub(crate) fn execute_if_requirements_are_met(
 		message_hash: MessageHash,
 		router_ids: &[T::RouterId],
@@ -324,43 +342,200 @@ impl<T: Config> Pallet<T> {
 		domain_address: DomainAddress,
 	) -> DispatchResult {
 		let mut message = None;
-		let mut votes = 0;
+		let mut voters = Vec::new();
 
 		for router_id in router_ids {
 			match PendingInboundEntries::<T>::get(message_hash, router_id) {
-				// We expected one InboundEntry for each router, if that's not the case,
-				// we can return.
-				None => return Ok(()),
-				Some(stored_inbound_entry) => match stored_inbound_entry {
-					InboundEntry::Message(message_entry)
-						if message_entry.session_id == session_id =>
-					{
-						message = Some(message_entry.message)
-					}
-					InboundEntry::Proof(proof_entry)
-						if proof_entry.has_valid_vote_for_session(session_id) =>
-					{
-						votes.ensure_add_assign(1)?;
-					}
-					_ => {}
-				},
+				Some(InboundEntry::Message(message_entry))
+					if message_entry.session_id == session_id =>
+				{
+					message = Some((router_id.clone(), message_entry.message))
+				}
+				Some(InboundEntry::Proof(proof_entry))
+					if proof_entry.has_valid_vote_for_session(session_id) =>
+				{
+					voters.push(router_id.clone());
+				}
+				_ => {}
 			};
 		}
 
-		if votes < expected_proof_count {
+		if voters.len() < expected_proof_count.saturated_into() {
+			return Ok(());
+		}
+
+		if let Some((message_router_id, msg)) = message {
+			Self::execute_inbound_message(domain_address, message_hash, msg)?;
+
+			voters.truncate(expected_proof_count.saturated_into());
+			voters.insert(0, message_router_id);
+
+			Self::execute_post_voting_dispatch(message_hash, &voters, expected_proof_count)?;
+		}
+
+		Ok(())
+	}
+
+	/// Returns the next sequence number expected for a domain.
+	pub(crate) fn next_inbound_nonce(domain: Domain) -> Result<u64, DispatchError> {
+		Ok(InboundNonce::<T>::get(domain).ensure_add(1)?)
+	}
+
+	/// Returns the sequence numbers of a domain that have not been received
+	/// yet but are required to execute the pending messages.
+	pub fn inbound_nonce_gaps(domain: Domain) -> Vec<u64> {
+		let Ok(next_nonce) = Self::next_inbound_nonce(domain) else {
+			return Vec::new();
+		};
+
+		let pending = PendingSequencedMessages::<T>::iter_key_prefix(domain).collect::<Vec<_>>();
+
+		match pending.iter().max() {
+			Some(last_nonce) => (next_nonce..*last_nonce)
+				.filter(|nonce| !pending.contains(nonce))
+				.collect(),
+			None => Vec::new(),
+		}
+	}
+
+	/// Decodes an inbound message payload and dry-runs its execution as if it
+	/// was received from the given source and met the voting requirements.
+	/// Any state change is reverted.
+	pub fn simulate_inbound_message(
+		domain_address: DomainAddress,
+		payload: Vec<u8>,
+	) -> DispatchResult {
+		ensure!(
+			Allowlist::<T>::contains_key(domain_address.domain(), domain_address.clone()),
+			Error::<T>::UnknownInstance,
+		);
+
+		let (message, _) = T::Message::deserialize_versioned(&payload)?;
+
+		// Proofs only count as votes for a message, nothing is dispatched.
+		if message.is_proof_message() {
 			return Ok(());
 		}
 
-		if let Some(msg) = message {
-			T::InboundMessageHandler::handle(domain_address.clone(), msg)?;
+		with_transaction(|| {
+			TransactionOutcome::Rollback(Self::execute_inbound_message(
+				domain_address,
+				message.get_message_hash(),
+				message,
+			))
+		})
+	}
+
+	/// Executes an inbound message that met the voting requirements.
+	///
+	/// A sequenced message is only executed if it's the next one expected for
+	/// its domain, in which case, the following pending messages are also
+	/// executed. Otherwise, it waits in `PendingSequencedMessages` for its
+	/// turn.
+	pub(crate) fn execute_inbound_message(
+		domain_address: DomainAddress,
+		message_hash: MessageHash,
+		message: T::Message,
+	) -> DispatchResult {
+		let domain = domain_address.domain();
+		let (nonce, inner_message) = message.clone().split_sequence();
+
+		let Some(nonce) = nonce else {
+			return Self::handle_inbound_message(domain_address, message_hash, inner_message);
+		};
 
-			Self::execute_post_voting_dispatch(message_hash, router_ids, expected_proof_count)?;
+		let next_nonce = Self::next_inbound_nonce(domain)?;
 
-			Self::deposit_event(Event::<T>::InboundMessageExecuted {
+		ensure!(nonce >= next_nonce, Error::<T>::InboundNonceAlreadyExecuted);
+
+		if nonce > next_nonce {
+			ensure!(
+				nonce.ensure_sub(next_nonce)? <= T::MaxInboundNonceGap::get(),
+				Error::<T>::InboundNonceTooFarAhead
+			);
+
+			ensure!(
+				!PendingSequencedMessages::<T>::contains_key(domain, nonce),
+				Error::<T>::InboundNonceAlreadyPending
+			);
+
+			PendingSequencedMessages::<T>::insert(domain, nonce, (domain_address.clone(), message));
+
+			Self::deposit_event(Event::<T>::InboundMessageDeferred {
 				domain_address,
 				message_hash,
-			})
+				nonce,
+			});
+
+			return Ok(());
+		}
+
+		InboundNonce::<T>::insert(domain, nonce);
+
+		Self::handle_inbound_message(domain_address, message_hash, inner_message)?;
+
+		Self::execute_pending_sequenced_messages(domain)
+	}
+
+	/// Executes, in order, the pending messages of a domain whose turn has
+	/// come.
+	///
+	/// A failing message is kept pending, stopping the execution of the
+	/// following ones until it's retried with `execute_pending_message()`.
+	fn execute_pending_sequenced_messages(domain: Domain) -> DispatchResult {
+		loop {
+			let nonce = Self::next_inbound_nonce(domain)?;
+
+			let Some((domain_address, message)) = PendingSequencedMessages::<T>::get(domain, nonce)
+			else {
+				return Ok(());
+			};
+
+			let res = with_transaction(|| {
+				PendingSequencedMessages::<T>::remove(domain, nonce);
+				InboundNonce::<T>::insert(domain, nonce);
+
+				let message_hash = message.get_message_hash();
+				let (_, inner_message) = message.split_sequence();
+				let res = Self::handle_inbound_message(domain_address, message_hash, inner_message);
+
+				if res.is_ok() {
+					TransactionOutcome::Commit(res)
+				} else {
+					TransactionOutcome::Rollback(res)
+				}
+			});
+
+			if let Err(error) = res {
+				Self::deposit_event(Event::<T>::PendingInboundMessageFailed {
+					domain,
+					nonce,
+					error,
+				});
+
+				return Ok(());
+			}
 		}
+	}
+
+	/// Dispatches each submessage of a batch, or the message itself if it is
+	/// not a batch. Callers must run this within a transaction so that a
+	/// failing submessage reverts the whole batch.
+	fn handle_inbound_message(
+		domain_address: DomainAddress,
+		message_hash: MessageHash,
+		message: T::Message,
+	) -> DispatchResult {
+		for submessage in message.submessages() {
+			T::InboundMessageHandler::handle(domain_address.clone(), submessage)?;
+		}
+
+		T::ActivityRecorder::record(ActivityKind::Message, &message_hash);
+
+		Self::deposit_event(Event::<T>::InboundMessageExecuted {
+			domain_address,
+			message_hash,
+		});
 
 		Ok(())
 	}
@@ -378,7 +553,7 @@ impl<T: Config> Pallet<T> {
 					None => {
 						// This case cannot be reproduced in production since this function is
 						// called only if a message is submitted for further processing, which
-						// means that all the pending inbound entries are present.
+						// means that all the pending inbound entries of the voters are present.
 						Err::<(), DispatchError>(Error::<T>::PendingInboundEntryNotFound.into())
 					}
 					Some(stored_inbound_entry) => {
@@ -407,7 +582,8 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		let router_ids = Self::get_router_ids_for_domain(domain_address.domain())?;
 		let session_id = SessionIdStore::<T>::get();
-		let expected_proof_count = Self::get_expected_proof_count(&router_ids)?;
+		let expected_proof_count =
+			Self::get_expected_proof_count(domain_address.domain(), &router_ids)?;
 		let message_hash = message.get_message_hash();
 		let inbound_entry: InboundEntry<T> = InboundEntry::create(
 			message.clone(),
@@ -419,6 +595,7 @@ impl<T: Config> Pallet<T> {
 		inbound_entry.validate(&router_ids, &router_id.clone())?;
 
 		Self::upsert_pending_entry(message_hash, &router_id, inbound_entry)?;
+		T::ActivityRecorder::record(ActivityKind::Message, &message_hash);
 
 		Self::deposit_processing_event(
 			domain_address.clone(),
@@ -460,34 +637,275 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Retrieves the IDs of the routers set for a domain and queues the
-	/// message and proofs accordingly.
+	/// message and proofs accordingly. Messages exceeding the maximum payload
+	/// size of the routers are split into smaller batches first.
 	pub(crate) fn queue_outbound_message(
 		destination: Domain,
 		message: T::Message,
 	) -> DispatchResult {
 		let router_ids = Self::get_router_ids_for_domain(destination)?;
 
-		let proof_message = message.to_proof_message();
-		let mut message_opt = Some(message);
+		for message in Self::split_outbound_message(destination, &router_ids, message)? {
+			let proof_message = message.to_proof_message();
+			let mut message_opt = Some(message);
+
+			for router_id in router_ids.iter().cloned() {
+				// Ensure that we only send the actual message once, using one router.
+				// The remaining routers will send the message proof.
+				let router_msg = match message_opt.take() {
+					Some(m) => m,
+					None => proof_message.clone(),
+				};
+
+				// We are using the sender specified in the pallet config so that we can
+				// ensure that the account is funded
+				let gateway_message = GatewayMessage::<T::Message, T::RouterId>::Outbound {
+					message: router_msg,
+					router_id,
+				};
+
+				T::MessageQueue::queue(gateway_message)?;
+			}
+		}
 
-		for router_id in router_ids {
-			// Ensure that we only send the actual message once, using one router.
-			// The remaining routers will send the message proof.
-			let router_msg = match message_opt.take() {
-				Some(m) => m,
-				None => proof_message.clone(),
-			};
+		Ok(())
+	}
 
-			// We are using the sender specified in the pallet config so that we can
-			// ensure that the account is funded
-			let gateway_message = GatewayMessage::<T::Message, T::RouterId>::Outbound {
-				message: router_msg,
-				router_id,
-			};
+	/// Returns the smallest maximum payload size of the routers, if any of
+	/// them is limited.
+	///
+	/// NOTE: Any router can send the whole message when it is recovered, so
+	/// the message must fit all of them, not only the one sending it first.
+	fn max_payload_size(router_ids: &[T::RouterId]) -> Option<usize> {
+		router_ids
+			.iter()
+			.filter_map(T::MessageSender::max_payload_size)
+			.min()
+			.map(|size| size as usize)
+	}
+
+	/// Ensures that each message contained in the outbound message fits the
+	/// routers of the destination, so it can be sent once split.
+	///
+	/// NOTE: A domain without routers is reported when the message is queued.
+	pub(crate) fn ensure_outbound_message_size(
+		destination: Domain,
+		message: &T::Message,
+	) -> DispatchResult {
+		let router_ids = Self::get_router_ids_for_domain(destination).unwrap_or_default();
 
-			T::MessageQueue::queue(gateway_message)?;
+		let Some(max_size) = Self::max_payload_size(&router_ids) else {
+			return Ok(());
+		};
+
+		let version = DomainMessageVersion::<T>::get(destination);
+
+		for submessage in message.submessages() {
+			ensure!(
+				submessage.serialize_versioned(version).len() <= max_size,
+				Error::<T>::OutboundMessageTooLarge
+			);
 		}
 
 		Ok(())
 	}
+
+	/// Splits an outbound message exceeding the maximum payload size of the
+	/// routers into batches of its submessages that fit them.
+	pub(crate) fn split_outbound_message(
+		destination: Domain,
+		router_ids: &[T::RouterId],
+		message: T::Message,
+	) -> Result<Vec<T::Message>, DispatchError> {
+		let Some(max_size) = Self::max_payload_size(router_ids) else {
+			return Ok(sp_std::vec![message]);
+		};
+
+		let version = DomainMessageVersion::<T>::get(destination);
+		let fits = |message: &T::Message| message.serialize_versioned(version).len() <= max_size;
+
+		if fits(&message) {
+			return Ok(sp_std::vec![message]);
+		}
+
+		let mut chunks = Vec::new();
+		let mut chunk: Option<T::Message> = None;
+
+		for submessage in message.submessages() {
+			ensure!(fits(&submessage), Error::<T>::OutboundMessageTooLarge);
+
+			chunk = Some(match chunk.take() {
+				Some(current) => {
+					let mut extended = current.clone();
+					if extended.pack_with(submessage.clone()).is_ok() && fits(&extended) {
+						extended
+					} else {
+						chunks.push(current);
+						submessage
+					}
+				}
+				None => submessage,
+			});
+		}
+
+		chunks.extend(chunk);
+
+		Ok(chunks)
+	}
+
+	/// Packs the message into the batch sent to the destination in the
+	/// current block. If the batch can not hold more messages, it is queued
+	/// and a new batch is started with the message.
+	pub(crate) fn batch_outbound_message(
+		destination: Domain,
+		message: T::Message,
+	) -> DispatchResult {
+		// Ensures errors are reported to the sender instead of when the batch
+		// is queued.
+		Self::get_router_ids_for_domain(destination)?;
+
+		PendingOutboundBatch::<T>::try_mutate(destination, |batch| match batch {
+			Some(batch) => {
+				if batch.pack_with(message.clone()).is_err() {
+					let full_batch = sp_std::mem::replace(batch, message);
+					Self::queue_outbound_message(destination, full_batch)?;
+				}
+
+				Ok(())
+			}
+			None => {
+				*batch = Some(message);
+				Ok(())
+			}
+		})
+	}
+
+	/// Queues the outbound batches built in the previous block and returns
+	/// the consumed weight, which is zero if there was no batch. Reading
+	/// that there is no further batch is not accounted.
+	pub(crate) fn queue_pending_outbound_batches() -> Weight {
+		let batches = PendingOutboundBatch::<T>::drain().collect::<Vec<_>>();
+
+		let mut weight = Weight::zero();
+
+		for (domain, batch) in batches {
+			// Reads the batch, routers and session, removes the batch and writes
+			// it back on failure and one queue entry per router.
+			weight.saturating_accrue(
+				T::DbWeight::get()
+					.reads_writes(4, T::MaxRouterCount::get().saturating_add(2).into()),
+			);
+
+			let res = with_transaction(|| {
+				let res = Self::queue_outbound_message(domain, batch.clone());
+
+				if res.is_ok() {
+					TransactionOutcome::Commit(res)
+				} else {
+					TransactionOutcome::Rollback(res)
+				}
+			});
+
+			if let Err(error) = res {
+				PendingOutboundBatch::<T>::insert(domain, batch);
+
+				Self::deposit_event(Event::<T>::OutboundBatchQueueFailed { domain, error });
+			}
+		}
+
+		weight
+	}
+
+	/// Returns the domain of the message if processing it in its direction
+	/// is paused.
+	pub(crate) fn paused_domain_of(
+		message: &GatewayMessage<T::Message, T::RouterId>,
+	) -> Option<Domain> {
+		let (domain, paused) = match message {
+			GatewayMessage::Inbound { domain_address, .. } => {
+				let domain = domain_address.domain();
+				(domain, PausedDomains::<T>::get(domain).inbound)
+			}
+			GatewayMessage::Outbound { router_id, .. } => {
+				let domain = router_id.clone().into();
+				(domain, PausedDomains::<T>::get(domain).outbound)
+			}
+		};
+
+		(paused || EmergencyPause::<T>::get()).then_some(domain)
+	}
+
+	/// Holds a message of a paused domain until it's resumed.
+	pub(crate) fn hold_paused_message(
+		domain: Domain,
+		message: GatewayMessage<T::Message, T::RouterId>,
+	) -> DispatchResult {
+		let index = PausedMessageRange::<T>::try_mutate(domain, |(_, next)| {
+			let index = *next;
+			next.ensure_add_assign(1)?;
+			Ok::<_, DispatchError>(index)
+		})?;
+
+		PausedMessages::<T>::insert(domain, index, message);
+
+		Self::deposit_event(Event::<T>::MessageHeld { domain, index });
+
+		Ok(())
+	}
+
+	/// Holds a message whose hash is quarantined until it's released or
+	/// discarded.
+	pub(crate) fn hold_quarantined_message(
+		message_hash: MessageHash,
+		message: GatewayMessage<T::Message, T::RouterId>,
+	) -> DispatchResult {
+		let router_id = match &message {
+			GatewayMessage::Inbound { router_id, .. } => router_id.clone(),
+			GatewayMessage::Outbound { router_id, .. } => router_id.clone(),
+		};
+
+		QuarantinedMessages::<T>::try_mutate(message_hash, |messages| {
+			messages
+				.as_mut()
+				.ok_or(Error::<T>::MessageNotQuarantined)?
+				.try_push(message)
+				.map_err(|_| Error::<T>::QuarantineFull)
+		})?;
+
+		Self::deposit_event(Event::<T>::QuarantinedMessageHeld {
+			message_hash,
+			router_id,
+		});
+
+		Ok(())
+	}
+
+	/// Queues again, in arrival order, up to `max` messages held for the
+	/// domain. Returns the number of messages queued.
+	pub(crate) fn resume_paused_messages_for(
+		domain: Domain,
+		max: u32,
+	) -> Result<u32, DispatchError> {
+		let (mut first, next) = PausedMessageRange::<T>::get(domain);
+
+		ensure!(first < next, Error::<T>::NoPausedMessages);
+
+		let mut count = 0;
+		while first < next && count < max {
+			if let Some(message) = PausedMessages::<T>::take(domain, first) {
+				T::MessageQueue::queue(message)?;
+			}
+
+			first.ensure_add_assign(1)?;
+			count.ensure_add_assign(1)?;
+		}
+
+		if first == next {
+			PausedMessageRange::<T>::remove(domain);
+		} else {
+			PausedMessageRange::<T>::insert(domain, (first, next));
+		}
+
+		Ok(count)
+	}
 }
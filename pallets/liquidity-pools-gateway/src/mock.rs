@@ -1,20 +1,26 @@
 use std::fmt::{Debug, Formatter};
 
 use cfg_mocks::pallet_mock_liquidity_pools;
-use cfg_traits::liquidity_pools::{
-	LpMessageBatch, LpMessageHash, LpMessageProof, LpMessageRecovery, LpMessageSerializer,
-	MessageHash, RouterProvider,
+use cfg_traits::{
+	ethereum::{EthereumTransactionInspect, TransactionRef},
+	liquidity_pools::{
+		LpMessageBatch, LpMessageHash, LpMessageProof, LpMessageRecovery, LpMessageSequence,
+		LpMessageSerializer, MessageHash, MessageSender, MessageVersion, RouterProvider,
+	},
 };
 use cfg_types::{
 	domain_address::{Domain, DomainAddress},
 	EVMChainId,
 };
-use frame_support::{derive_impl, weights::constants::RocksDbWeight};
+use frame_support::{derive_impl, traits::ConstU128, weights::constants::RocksDbWeight, PalletId};
 use frame_system::EnsureRoot;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::{crypto::AccountId32, H160};
-use sp_runtime::{traits::IdentityLookup, DispatchError, DispatchResult};
+use sp_runtime::{
+	testing::UintAuthorityId, traits::IdentityLookup, transaction_validity::TransactionPriority,
+	DispatchError, DispatchResult, DispatchResultWithPostInfo,
+};
 
 use crate::{pallet as pallet_liquidity_pools_gateway, GatewayMessage};
 
@@ -30,6 +36,8 @@ pub const ROUTER_ID_3: RouterId = RouterId(3);
 
 pub const LP_ADMIN_ACCOUNT: AccountId32 = AccountId32::new([u8::MAX; 32]);
 
+pub const RELAYER_BOND: u128 = 100;
+
 pub const MAX_PACKED_MESSAGES_ERR: &str = "packed limit error";
 pub const MAX_PACKED_MESSAGES: usize = 10;
 
@@ -42,6 +50,7 @@ pub enum Message {
 	Proof([u8; 32]),
 	InitiateMessageRecovery(([u8; 32], [u8; 32])),
 	DisputeMessageRecovery(([u8; 32], [u8; 32])),
+	Sequenced(u64, Box<Message>),
 }
 
 impl Debug for Message {
@@ -50,6 +59,7 @@ impl Debug for Message {
 			Message::Simple => write!(f, "Simple"),
 			Message::Pack(p) => write!(f, "Pack - {:?}", p),
 			Message::Proof(_) => write!(f, "Proof"),
+			Message::Sequenced(nonce, m) => write!(f, "Sequenced - {} - {:?}", nonce, m),
 			other => write!(f, "{:?}", other),
 		}
 	}
@@ -63,6 +73,8 @@ impl MaxEncodedLen for Message {
 }
 
 impl LpMessageSerializer for Message {
+	const LATEST_VERSION: MessageVersion = 1;
+
 	fn serialize(&self) -> Vec<u8> {
 		match self {
 			Self::Pack(list) => list.iter().map(|_| 0x42).collect(),
@@ -137,6 +149,19 @@ impl LpMessageRecovery for Message {
 	}
 }
 
+impl LpMessageSequence for Message {
+	fn split_sequence(self) -> (Option<u64>, Self) {
+		match self {
+			Self::Sequenced(nonce, message) => (Some(nonce), *message),
+			message => (None, message),
+		}
+	}
+
+	fn try_wrap_sequenced(nonce: u64, message: Self) -> Result<Self, DispatchError> {
+		Ok(Self::Sequenced(nonce, Box::new(message)))
+	}
+}
+
 #[derive(Debug, Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, Hash)]
 pub struct RouterId(pub u32);
 
@@ -162,6 +187,7 @@ impl Into<Domain> for RouterId {
 frame_support::construct_runtime!(
 	pub enum Runtime {
 		System: frame_system,
+		Balances: pallet_balances,
 		MockLiquidityPools: pallet_mock_liquidity_pools,
 		MockLiquidityPoolsGatewayQueue: cfg_mocks::queue::pallet,
 		MockMessageSender: cfg_mocks::router_message::pallet,
@@ -171,12 +197,22 @@ frame_support::construct_runtime!(
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<u128>;
 	type AccountId = AccountId32;
 	type Block = frame_system::mocking::MockBlock<Runtime>;
 	type DbWeight = RocksDbWeight;
 	type Lookup = IdentityLookup<Self::AccountId>;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = u128;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type RuntimeHoldReason = ();
+}
+
 impl pallet_mock_liquidity_pools::Config for Runtime {
 	type DomainAddress = DomainAddress;
 	type Message = Message;
@@ -197,21 +233,63 @@ frame_support::parameter_types! {
 	pub const MaxIncomingMessageSize: u32 = 1024;
 	pub const LpAdminAccount: AccountId32 = LP_ADMIN_ACCOUNT;
 	pub const MaxRouterCount: u32 = 8;
+	pub const MaxInboundNonceGap: u64 = 4;
+	pub const UnsignedPriority: TransactionPriority = 100;
+	pub const RelayerBond: u128 = RELAYER_BOND;
+	pub const FeePotId: PalletId = PalletId(*b"lp/gwfee");
+	pub storage LastEthereumTransaction: Option<TransactionRef> = None;
+	pub storage MaxPayloadSize: Option<u32> = None;
+}
+
+/// Sends through `MockMessageSender`, limited by `MaxPayloadSize`.
+pub struct LimitedMessageSender;
+impl MessageSender for LimitedMessageSender {
+	type Message = Message;
+	type Middleware = RouterId;
+	type Origin = DomainAddress;
+
+	fn send(
+		router_id: RouterId,
+		origin: DomainAddress,
+		message: Message,
+	) -> DispatchResultWithPostInfo {
+		MockMessageSender::send(router_id, origin, message)
+	}
+
+	fn max_payload_size(_: &RouterId) -> Option<u32> {
+		MaxPayloadSize::get()
+	}
+}
+
+pub struct MockEthereumTransactions;
+impl EthereumTransactionInspect for MockEthereumTransactions {
+	fn last_transaction() -> Option<TransactionRef> {
+		LastEthereumTransaction::get()
+	}
 }
 
 impl pallet_liquidity_pools_gateway::Config for Runtime {
+	type ActivityRecorder = ();
 	type AdminOrigin = EnsureRoot<AccountId32>;
+	type Currency = Balances;
+	type EthereumTransactions = MockEthereumTransactions;
+	type FeePotId = FeePotId;
 	type InboundMessageHandler = MockLiquidityPools;
+	type MaxInboundNonceGap = MaxInboundNonceGap;
 	type MaxIncomingMessageSize = MaxIncomingMessageSize;
 	type MaxRouterCount = MaxRouterCount;
 	type Message = Message;
 	type MessageQueue = MockLiquidityPoolsGatewayQueue;
-	type MessageSender = MockMessageSender;
+	type MessageSender = LimitedMessageSender;
+	type OutboundMessageTracker = ();
+	type RelayerBond = RelayerBond;
+	type RelayerId = UintAuthorityId;
 	type RouterId = RouterId;
 	type RouterProvider = TestRouterProvider;
 	type RuntimeEvent = RuntimeEvent;
 	type Sender = Sender;
 	type SessionId = u32;
+	type UnsignedPriority = UnsignedPriority;
 	type WeightInfo = ();
 }
 
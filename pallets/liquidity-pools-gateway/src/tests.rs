@@ -1,16 +1,28 @@
-use std::collections::HashMap;
+use std::{
+	collections::HashMap,
+	sync::{Arc, Mutex},
+};
 
 use cfg_primitives::LP_DEFENSIVE_WEIGHT;
-use cfg_traits::liquidity_pools::{LpMessageHash, MessageProcessor, OutboundMessageHandler};
+use cfg_traits::{
+	ethereum::TransactionRef,
+	liquidity_pools::{
+		LpMessageHash, LpMessageProof, LpMessageSerializer, MessageProcessor, MessageVersions,
+		OutboundMessageHandler,
+	},
+};
 use cfg_types::domain_address::*;
 use frame_support::{assert_err, assert_noop, assert_ok};
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use sp_arithmetic::ArithmeticError::{Overflow, Underflow};
-use sp_core::{bounded::BoundedVec, crypto::AccountId32, ByteArray, H160};
+use sp_core::{bounded::BoundedVec, crypto::AccountId32, ByteArray, H160, H256};
 use sp_runtime::{
+	testing::UintAuthorityId,
+	transaction_validity::TransactionValidity,
 	DispatchError,
 	DispatchError::{Arithmetic, BadOrigin},
+	DispatchResult,
 };
 
 use super::{
@@ -19,7 +31,8 @@ use super::{
 };
 use crate::{
 	message_processing::{InboundEntry, MessageEntry, ProofEntry},
-	GatewayMessage,
+	GatewayMessage, OutboundEvmTransaction, PauseState, RelayedMessage, RelayerFee,
+	RelayerFeePayer, RelayerInfo, RELAYED_MESSAGE_SIGNING_CONTEXT,
 };
 
 mod utils {
@@ -684,7 +697,7 @@ mod extrinsics {
 						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
 					);
 
-					Ok(())
+					Ok(().into())
 				});
 
 				assert_ok!(LiquidityPoolsGateway::initiate_message_recovery(
@@ -752,7 +765,7 @@ mod extrinsics {
 						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
 					);
 
-					Ok(())
+					Ok(().into())
 				});
 
 				assert_noop!(
@@ -784,7 +797,7 @@ mod extrinsics {
 						Message::InitiateMessageRecovery((MESSAGE_HASH, recovery_router))
 					);
 
-					Err(err)
+					Err(err.into())
 				});
 
 				assert_noop!(
@@ -818,7 +831,7 @@ mod extrinsics {
 						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
 					);
 
-					Ok(())
+					Ok(().into())
 				});
 
 				assert_ok!(LiquidityPoolsGateway::dispute_message_recovery(
@@ -886,7 +899,7 @@ mod extrinsics {
 						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
 					);
 
-					Ok(())
+					Ok(().into())
 				});
 
 				assert_noop!(
@@ -918,7 +931,7 @@ mod extrinsics {
 						Message::DisputeMessageRecovery((MESSAGE_HASH, recovery_router))
 					);
 
-					Err(err)
+					Err(err.into())
 				});
 
 				assert_noop!(
@@ -933,763 +946,1496 @@ mod extrinsics {
 			});
 		}
 	}
-}
-
-mod implementations {
-	use super::*;
 
-	mod outbound_message_handler {
+	mod set_inbound_threshold {
 		use super::*;
 
 		#[test]
 		fn success() {
 			new_test_ext().execute_with(|| {
-				let domain = Domain::Evm(0);
-				let sender = get_test_account_id();
-				let msg = Message::Simple;
-				let message_hash = msg.get_message_hash();
-
-				assert_ok!(LiquidityPoolsGateway::set_routers(
+				assert_ok!(LiquidityPoolsGateway::set_inbound_threshold(
 					RuntimeOrigin::root(),
-					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+					TEST_DOMAIN,
+					Some(2),
 				));
 
-				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
-					match mock_msg {
-						GatewayMessage::Inbound { .. } => {
-							assert!(false, "expected outbound message")
-						}
-						GatewayMessage::Outbound { message, .. } => match message {
-							Message::Proof(p) => {
-								assert_eq!(p, message_hash);
-							}
-							_ => {}
-						},
-					}
-
-					Ok(())
+				assert_eq!(InboundThreshold::<Runtime>::get(TEST_DOMAIN), Some(2));
+				event_exists(Event::<Runtime>::InboundThresholdSet {
+					domain: TEST_DOMAIN,
+					threshold: Some(2),
 				});
 
-				assert_ok!(LiquidityPoolsGateway::handle(sender, domain, msg));
-				assert_eq!(handler.times(), 3);
+				assert_ok!(LiquidityPoolsGateway::set_inbound_threshold(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					None,
+				));
+
+				assert_eq!(InboundThreshold::<Runtime>::get(TEST_DOMAIN), None);
 			});
 		}
 
 		#[test]
-		fn domain_not_supported() {
+		fn bad_origin() {
 			new_test_ext().execute_with(|| {
-				let domain = Domain::Centrifuge;
-				let sender = get_test_account_id();
-				let msg = Message::Simple;
-
 				assert_noop!(
-					LiquidityPoolsGateway::handle(sender, domain, msg),
-					Error::<Runtime>::DomainNotSupported
+					LiquidityPoolsGateway::set_inbound_threshold(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						Some(2),
+					),
+					BadOrigin
 				);
 			});
 		}
 
 		#[test]
-		fn routers_not_found() {
+		fn invalid_threshold() {
 			new_test_ext().execute_with(|| {
-				let domain = Domain::Evm(0);
-				let sender = get_test_account_id();
-				let msg = Message::Simple;
+				assert_noop!(
+					LiquidityPoolsGateway::set_inbound_threshold(
+						RuntimeOrigin::root(),
+						TEST_DOMAIN,
+						Some(0),
+					),
+					Error::<Runtime>::InvalidInboundThreshold
+				);
+			});
+		}
 
+		#[test]
+		fn unsupported_domain() {
+			new_test_ext().execute_with(|| {
 				assert_noop!(
-					LiquidityPoolsGateway::handle(sender, domain, msg),
-					Error::<Runtime>::NotEnoughRoutersForDomain
+					LiquidityPoolsGateway::set_inbound_threshold(
+						RuntimeOrigin::root(),
+						Domain::Centrifuge,
+						Some(2),
+					),
+					Error::<Runtime>::DomainNotSupported
 				);
 			});
 		}
+	}
+
+	mod add_relayer {
+		use super::*;
+
+		const RELAYER: UintAuthorityId = UintAuthorityId(1);
 
 		#[test]
-		fn message_queue_error() {
+		fn success() {
 			new_test_ext().execute_with(|| {
-				let domain = Domain::Evm(0);
-				let sender = get_test_account_id();
-				let msg = Message::Simple;
+				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
 
-				assert_ok!(LiquidityPoolsGateway::set_routers(
+				assert_ok!(LiquidityPoolsGateway::add_relayer(
 					RuntimeOrigin::root(),
-					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+					RELAYER,
+					ROUTER_ID_1,
 				));
 
-				let gateway_message = GatewayMessage::Outbound {
-					message: msg.clone(),
+				assert_eq!(
+					Relayers::<Runtime>::get(RELAYER),
+					Some(RelayerInfo {
+						router_id: ROUTER_ID_1,
+						nonce: 0,
+						reputation: 0,
+						account: None,
+						bond: 0,
+					})
+				);
+				event_exists(Event::<Runtime>::RelayerAdded {
+					relayer: RELAYER,
 					router_id: ROUTER_ID_1,
-				};
+				});
+			});
+		}
 
-				let err = DispatchError::Unavailable;
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::add_relayer(
+						RuntimeOrigin::signed(get_test_account_id()),
+						RELAYER,
+						ROUTER_ID_1,
+					),
+					BadOrigin
+				);
+			});
+		}
 
-				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
-					assert_eq!(mock_msg, gateway_message);
+		#[test]
+		fn unknown_router() {
+			new_test_ext().execute_with(|| {
+				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
 
-					Err(err)
-				});
+				assert_noop!(
+					LiquidityPoolsGateway::add_relayer(RuntimeOrigin::root(), RELAYER, ROUTER_ID_2),
+					Error::<Runtime>::UnknownRouter
+				);
+			});
+		}
 
-				assert_noop!(LiquidityPoolsGateway::handle(sender, domain, msg), err);
-				assert_eq!(handler.times(), 1);
+		#[test]
+		fn already_added() {
+			new_test_ext().execute_with(|| {
+				Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+
+				assert_ok!(LiquidityPoolsGateway::add_relayer(
+					RuntimeOrigin::root(),
+					RELAYER,
+					ROUTER_ID_1,
+				));
+
+				assert_noop!(
+					LiquidityPoolsGateway::add_relayer(RuntimeOrigin::root(), RELAYER, ROUTER_ID_1),
+					Error::<Runtime>::RelayerAlreadyAdded
+				);
 			});
 		}
 	}
 
-	mod message_processor {
+	mod remove_relayer {
+		use frame_support::traits::{Currency, ReservableCurrency};
+
 		use super::*;
 
-		mod inbound {
-			use super::*;
+		const RELAYER: UintAuthorityId = UintAuthorityId(1);
+		const RELAYER_ACCOUNT: AccountId32 = AccountId32::new([5; 32]);
 
-			mod util {
-				use super::*;
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				Balances::make_free_balance_be(&RELAYER_ACCOUNT, RELAYER_BOND * 2);
+				assert_ok!(Balances::reserve(&RELAYER_ACCOUNT, RELAYER_BOND));
 
-				pub fn run_inbound_message_test_suite(suite: InboundMessageTestSuite) {
-					let test_routers = suite.routers;
+				Relayers::<Runtime>::insert(
+					RELAYER,
+					RelayerInfo {
+						router_id: ROUTER_ID_1,
+						nonce: 3,
+						reputation: 3,
+						account: Some(RELAYER_ACCOUNT),
+						bond: RELAYER_BOND,
+					},
+				);
 
-					for test in suite.tests {
-						println!("Executing test for - {:?}", test.router_messages);
+				assert_ok!(LiquidityPoolsGateway::remove_relayer(
+					RuntimeOrigin::root(),
+					RELAYER,
+				));
 
-						new_test_ext().execute_with(|| {
-							let session_id = TEST_SESSION_ID;
+				assert!(!Relayers::<Runtime>::contains_key(RELAYER));
+				assert_eq!(Balances::reserved_balance(&RELAYER_ACCOUNT), 0);
+				event_exists(Event::<Runtime>::RelayerRemoved { relayer: RELAYER });
+			});
+		}
 
-							Routers::<Runtime>::set(
-								BoundedVec::try_from(test_routers.clone()).unwrap(),
-							);
-							SessionIdStore::<Runtime>::set(
-								session_id,
-							);
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::remove_relayer(
+						RuntimeOrigin::signed(get_test_account_id()),
+						RELAYER,
+					),
+					BadOrigin
+				);
+			});
+		}
 
-							let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+		#[test]
+		fn unknown_relayer() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::remove_relayer(RuntimeOrigin::root(), RELAYER),
+					Error::<Runtime>::UnknownRelayer
+				);
+			});
+		}
+	}
 
-							for router_message in test.router_messages {
-								let gateway_message = GatewayMessage::Inbound {
-									domain_address: TEST_DOMAIN_ADDRESS,
-									message: router_message.1,
-									router_id: router_message.0,
-								};
+	mod register_relayer {
+		use frame_support::traits::{Currency, ReservableCurrency};
 
-								let (res, _) = LiquidityPoolsGateway::process(gateway_message);
-								assert_ok!(res);
-							}
+		use super::*;
 
-							let expected_message_submitted_times =
-								test.expected_test_result.message_submitted_times;
-							let message_submitted_times = handler.times();
+		const RELAYER: UintAuthorityId = UintAuthorityId(1);
+		const RELAYER_ACCOUNT: AccountId32 = AccountId32::new([5; 32]);
 
-							assert_eq!(
-								message_submitted_times,
-								expected_message_submitted_times,
-								"Expected message to be submitted {expected_message_submitted_times} times, was {message_submitted_times}"
-							);
+		fn setup() {
+			Balances::make_free_balance_be(&RELAYER_ACCOUNT, RELAYER_BOND * 2);
 
-							for expected_storage_entry in
-								test.expected_test_result.expected_storage_entries
-							{
-								let expected_storage_entry_router_id = expected_storage_entry.0;
-								let expected_inbound_entry = expected_storage_entry.1;
+			Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
 
-								let storage_entry = PendingInboundEntries::<Runtime>::get(
-									MESSAGE_HASH, expected_storage_entry_router_id,
-								);
-								assert_eq!(storage_entry, expected_inbound_entry, "Expected inbound entry {expected_inbound_entry:?}, found {storage_entry:?}");
-							}
-						});
-					}
-				}
+			assert_ok!(LiquidityPoolsGateway::add_relayer(
+				RuntimeOrigin::root(),
+				RELAYER,
+				ROUTER_ID_1,
+			));
+		}
 
-				/// Used for generating all `RouterMessage` combinations like:
-				///
-				/// vec![
-				/// 	(ROUTER_ID_1, Message::Simple),
-				/// 	(ROUTER_ID_1, Message::Simple),
-				/// ]
-				/// vec![
-				/// 	(ROUTER_ID_1, Message::Simple),
-				/// 	(ROUTER_ID_2, Message::Proof(MESSAGE_PROOF)),
-				/// ]
-				/// vec![
-				/// 	(ROUTER_ID_2, Message::Proof(MESSAGE_PROOF)),
-				/// 	(ROUTER_ID_1, Message::Simple),
-				/// ]
-				/// vec![
-				/// 	(ROUTER_ID_2, Message::Proof(MESSAGE_PROOF)),
-				/// 	(ROUTER_ID_2, Message::Proof(MESSAGE_PROOF)),
-				/// ]
-				pub fn generate_test_combinations<T>(
-					t: T,
-					count: usize,
-				) -> Vec<Vec<<T as IntoIterator>::Item>>
-				where
-					T: IntoIterator + Clone,
-					T::IntoIter: Clone,
-					T::Item: Clone,
-				{
-					std::iter::repeat(t.clone().into_iter())
-						.take(count)
-						.multi_cartesian_product()
-						.collect::<Vec<_>>()
-				}
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				setup();
 
-				/// Type used for mapping a message to a router hash.
-				pub type RouterMessage = (RouterId, Message);
+				assert_ok!(LiquidityPoolsGateway::register_relayer(
+					RuntimeOrigin::signed(RELAYER_ACCOUNT),
+					RELAYER,
+				));
 
-				/// Type used for aggregating tests for inbound messages.
-				pub struct InboundMessageTestSuite {
-					pub routers: Vec<RouterId>,
-					pub tests: Vec<InboundMessageTest>,
-				}
+				let info = Relayers::<Runtime>::get(RELAYER).unwrap();
+				assert_eq!(info.account, Some(RELAYER_ACCOUNT));
+				assert_eq!(info.bond, RELAYER_BOND);
+				assert_eq!(Balances::reserved_balance(&RELAYER_ACCOUNT), RELAYER_BOND);
+				event_exists(Event::<Runtime>::RelayerRegistered {
+					relayer: RELAYER,
+					account: RELAYER_ACCOUNT,
+					bond: RELAYER_BOND,
+				});
+			});
+		}
 
-				/// Type used for defining a test which contains a set of
-				/// `RouterMessage` combinations and the expected test result.
-				pub struct InboundMessageTest {
-					pub router_messages: Vec<RouterMessage>,
-					pub expected_test_result: ExpectedTestResult,
-				}
+		#[test]
+		fn unknown_relayer() {
+			new_test_ext().execute_with(|| {
+				Balances::make_free_balance_be(&RELAYER_ACCOUNT, RELAYER_BOND * 2);
 
-				/// Type used for defining the number of expected inbound
-				/// message submission and the exected storage state.
-				#[derive(Clone, Debug)]
-				pub struct ExpectedTestResult {
-					pub message_submitted_times: u32,
-					pub expected_storage_entries: Vec<(RouterId, Option<InboundEntry<Runtime>>)>,
-				}
+				assert_noop!(
+					LiquidityPoolsGateway::register_relayer(
+						RuntimeOrigin::signed(RELAYER_ACCOUNT),
+						RELAYER,
+					),
+					Error::<Runtime>::UnknownRelayer
+				);
+			});
+		}
 
-				/// Generates the combinations of `RouterMessage` used when
-				/// testing, maps the `ExpectedTestResult` for each and
-				/// creates the `InboundMessageTestSuite`.
-				pub fn generate_test_suite(
-					routers: Vec<RouterId>,
-					test_data: Vec<RouterMessage>,
-					expected_results: HashMap<Vec<RouterMessage>, ExpectedTestResult>,
-					message_count: usize,
-				) -> InboundMessageTestSuite {
-					let tests = generate_test_combinations(test_data, message_count);
+		#[test]
+		fn already_bonded() {
+			new_test_ext().execute_with(|| {
+				setup();
 
-					let tests = tests
-						.into_iter()
-						.map(|router_messages| {
-							let expected_test_result = expected_results
-								.get(&router_messages)
-								.expect(
-									format!("test for {router_messages:?} should be covered")
-										.as_str(),
-								)
-								.clone();
+				assert_ok!(LiquidityPoolsGateway::register_relayer(
+					RuntimeOrigin::signed(RELAYER_ACCOUNT),
+					RELAYER,
+				));
 
-							InboundMessageTest {
-								router_messages,
-								expected_test_result,
-							}
-						})
-						.collect::<Vec<_>>();
+				assert_noop!(
+					LiquidityPoolsGateway::register_relayer(
+						RuntimeOrigin::signed(get_test_account_id()),
+						RELAYER,
+					),
+					Error::<Runtime>::RelayerAlreadyBonded
+				);
+			});
+		}
 
-					InboundMessageTestSuite { routers, tests }
-				}
-			}
+		#[test]
+		fn insufficient_balance() {
+			new_test_ext().execute_with(|| {
+				setup();
 
-			use util::*;
+				assert!(LiquidityPoolsGateway::register_relayer(
+					RuntimeOrigin::signed(get_test_account_id()),
+					RELAYER,
+				)
+				.is_err());
+				assert_eq!(Relayers::<Runtime>::get(RELAYER).unwrap().account, None);
+			});
+		}
 
-			mod one_router {
-				use super::*;
+		#[test]
+		fn unregister() {
+			new_test_ext().execute_with(|| {
+				setup();
 
-				#[test]
-				fn success() {
-					new_test_ext().execute_with(|| {
-						let message = Message::Simple;
-						let message_hash = message.get_message_hash();
-						let session_id = 1;
-						let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
-						let router_id = ROUTER_ID_1;
-						let gateway_message = GatewayMessage::Inbound {
-							domain_address: domain_address.clone(),
-							message: message.clone(),
-							router_id: router_id.clone(),
-						};
+				assert_ok!(LiquidityPoolsGateway::register_relayer(
+					RuntimeOrigin::signed(RELAYER_ACCOUNT),
+					RELAYER,
+				));
 
-						Routers::<Runtime>::set(
-							BoundedVec::<_, _>::try_from(vec![router_id.clone()]).unwrap(),
-						);
-						SessionIdStore::<Runtime>::set(session_id);
+				assert_noop!(
+					LiquidityPoolsGateway::unregister_relayer(
+						RuntimeOrigin::signed(get_test_account_id()),
+						RELAYER,
+					),
+					Error::<Runtime>::NotRelayerAccount
+				);
 
-						let handler = MockLiquidityPools::mock_handle(
-							move |mock_domain_address, mock_message| {
-								assert_eq!(mock_domain_address, domain_address);
-								assert_eq!(mock_message, message);
+				assert_ok!(LiquidityPoolsGateway::unregister_relayer(
+					RuntimeOrigin::signed(RELAYER_ACCOUNT),
+					RELAYER,
+				));
 
-								Ok(())
-							},
-						);
+				let info = Relayers::<Runtime>::get(RELAYER).unwrap();
+				assert_eq!(info.account, None);
+				assert_eq!(info.bond, 0);
+				assert_eq!(Balances::reserved_balance(&RELAYER_ACCOUNT), 0);
+				event_exists(Event::<Runtime>::RelayerUnregistered { relayer: RELAYER });
+			});
+		}
+	}
 
-						let (res, _) = LiquidityPoolsGateway::process(gateway_message);
-						assert_ok!(res);
-						assert_eq!(handler.times(), 1);
+	mod set_relayer_fee {
+		use super::*;
 
-						assert!(
-							PendingInboundEntries::<Runtime>::get(message_hash, router_id)
-								.is_none()
-						);
-					});
-				}
+		const FEE: RelayerFee<u128> = RelayerFee {
+			amount: 10,
+			payer: RelayerFeePayer::FeePot,
+		};
 
-				#[test]
-				fn multi_router_not_found() {
-					new_test_ext().execute_with(|| {
-						let message = Message::Simple;
-						let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
-						let router_hash = ROUTER_ID_1;
-						let gateway_message = GatewayMessage::Inbound {
-							domain_address: domain_address.clone(),
-							message: message.clone(),
-							router_id: router_hash,
-						};
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_relayer_fee(
+					RuntimeOrigin::root(),
+					Some(FEE),
+				));
 
-						let (res, _) = LiquidityPoolsGateway::process(gateway_message);
-						assert_noop!(res, Error::<Runtime>::NotEnoughRoutersForDomain);
-					});
-				}
+				assert_eq!(RelayerFeeStore::<Runtime>::get(), Some(FEE));
+				event_exists(Event::<Runtime>::RelayerFeeSet { fee: Some(FEE) });
 
-				#[test]
-				fn unknown_inbound_message_router() {
-					new_test_ext().execute_with(|| {
-						let message = Message::Simple;
-						let session_id = 1;
-						let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
-						let router_hash = ROUTER_ID_1;
-						let gateway_message = GatewayMessage::Inbound {
-							domain_address: domain_address.clone(),
-							message: message.clone(),
-							// The router stored has a different hash, this should trigger the
-							// expected error.
-							router_id: ROUTER_ID_2,
-						};
+				assert_ok!(LiquidityPoolsGateway::set_relayer_fee(
+					RuntimeOrigin::root(),
+					None,
+				));
 
-						Routers::<Runtime>::set(
-							BoundedVec::<_, _>::try_from(vec![router_hash]).unwrap(),
-						);
-						SessionIdStore::<Runtime>::set(session_id);
+				assert_eq!(RelayerFeeStore::<Runtime>::get(), None);
+			});
+		}
 
-						let (res, _) = LiquidityPoolsGateway::process(gateway_message);
-						assert_noop!(res, Error::<Runtime>::UnknownRouter);
-					});
-				}
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_relayer_fee(
+						RuntimeOrigin::signed(get_test_account_id()),
+						Some(FEE),
+					),
+					BadOrigin
+				);
+			});
+		}
+	}
 
-				#[test]
-				fn expected_message_hash_type() {
-					new_test_ext().execute_with(|| {
-						let message = Message::Simple;
-						let message_hash = message.get_message_hash();
-						let session_id = 1;
-						let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
-						let router_id = ROUTER_ID_1;
-						let gateway_message = GatewayMessage::Inbound {
-							domain_address: domain_address.clone(),
-							message: message.clone(),
-							router_id: router_id.clone(),
-						};
+	mod set_domain_message_version {
+		use super::*;
 
-						Routers::<Runtime>::set(
-							BoundedVec::<_, _>::try_from(vec![router_id.clone()]).unwrap(),
-						);
-						SessionIdStore::<Runtime>::set(session_id);
-						PendingInboundEntries::<Runtime>::insert(
-							message_hash,
-							router_id,
-							InboundEntry::Proof(ProofEntry {
-								session_id,
-								current_count: 0,
-							}),
-						);
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
 
-						let (res, _) = LiquidityPoolsGateway::process(gateway_message);
-						assert_noop!(res, Error::<Runtime>::ExpectedMessageProofType);
-					});
-				}
-			}
+				assert_ok!(LiquidityPoolsGateway::set_domain_message_version(
+					RuntimeOrigin::root(),
+					domain,
+					1,
+				));
 
-			mod two_routers {
-				use super::*;
+				assert_eq!(DomainMessageVersion::<Runtime>::get(domain), 1);
+				event_exists(Event::<Runtime>::DomainMessageVersionSet { domain, version: 1 });
 
-				mod success {
-					use super::*;
+				assert_ok!(LiquidityPoolsGateway::set_domain_message_version(
+					RuntimeOrigin::root(),
+					domain,
+					0,
+				));
 
-					lazy_static! {
-						static ref TEST_DATA: Vec<RouterMessage> = vec![
-							(ROUTER_ID_1, Message::Simple),
-							(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-						];
-					}
+				assert_eq!(DomainMessageVersion::<Runtime>::get(domain), 0);
+			});
+		}
 
-					mod two_messages {
-						use super::*;
+		#[test]
+		fn unsupported_version() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_message_version(
+						RuntimeOrigin::root(),
+						Domain::Evm(0),
+						2,
+					),
+					Error::<Runtime>::UnsupportedMessageVersion
+				);
+			});
+		}
 
-						const MESSAGE_COUNT: usize = 2;
+		#[test]
+		fn local_domain() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_message_version(
+						RuntimeOrigin::root(),
+						Domain::Centrifuge,
+						1,
+					),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
 
-						#[test]
-						fn success() {
-							let expected_results: HashMap<Vec<RouterMessage>, ExpectedTestResult> =
-								HashMap::from([
-									(
-										vec![
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 0,
-											expected_storage_entries: vec![
-												(
-													ROUTER_ID_1,
-													Some(
-														MessageEntry {
-															session_id: TEST_SESSION_ID,
-															domain_address: TEST_DOMAIN_ADDRESS,
-															message: Message::Simple,
-															expected_proof_count: 2,
-														}
-														.into(),
-													),
-												),
-												(ROUTER_ID_2, None),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 0,
-											expected_storage_entries: vec![
-												(ROUTER_ID_1, None),
-												(
-													ROUTER_ID_2,
-													Some(
-														ProofEntry {
-															session_id: TEST_SESSION_ID,
-															current_count: 2,
-														}
-														.into(),
-													),
-												),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 1,
-											expected_storage_entries: vec![
-												(ROUTER_ID_1, None),
-												(ROUTER_ID_2, None),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_1, Message::Simple),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 1,
-											expected_storage_entries: vec![
-												(ROUTER_ID_1, None),
-												(ROUTER_ID_2, None),
-											],
-										},
-									),
-								]);
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_message_version(
+						RuntimeOrigin::signed(get_test_account_id()),
+						Domain::Evm(0),
+						1,
+					),
+					BadOrigin
+				);
+			});
+		}
+	}
 
-							let suite = generate_test_suite(
-								vec![ROUTER_ID_1, ROUTER_ID_2],
-								TEST_DATA.clone(),
-								expected_results,
-								MESSAGE_COUNT,
-							);
+	mod domain_state {
+		use parity_scale_codec::Encode;
 
-							run_inbound_message_test_suite(suite);
+		use super::*;
+		use crate::DomainState;
+
+		const OLD_DOMAIN: Domain = Domain::Evm(1);
+		const NEW_DOMAIN: Domain = Domain::Evm(2);
+
+		fn setup_domain_state() {
+			Allowlist::<Runtime>::insert(OLD_DOMAIN, TEST_DOMAIN_ADDRESS, ());
+			DomainHookAddress::<Runtime>::insert(OLD_DOMAIN, get_test_hook_bytes());
+			InboundNonce::<Runtime>::insert(OLD_DOMAIN, 3);
+			PendingSequencedMessages::<Runtime>::insert(
+				OLD_DOMAIN,
+				5,
+				(TEST_DOMAIN_ADDRESS, Message::Simple),
+			);
+			OutboundBatching::<Runtime>::insert(OLD_DOMAIN, ());
+			PausedDomains::<Runtime>::insert(
+				OLD_DOMAIN,
+				PauseState {
+					inbound: true,
+					outbound: false,
+				},
+			);
+			PausedMessageRange::<Runtime>::insert(OLD_DOMAIN, (0, 1));
+			PausedMessages::<Runtime>::insert(
+				OLD_DOMAIN,
+				0,
+				GatewayMessage::Inbound {
+					domain_address: TEST_DOMAIN_ADDRESS,
+					message: Message::Simple,
+					router_id: ROUTER_ID_1,
+				},
+			);
+			InboundThreshold::<Runtime>::insert(OLD_DOMAIN, 2);
+			DomainMessageVersion::<Runtime>::insert(OLD_DOMAIN, 1);
+		}
+
+		fn exported_state() -> Vec<u8> {
+			frame_system::Pallet::<Runtime>::events()
+				.into_iter()
+				.rev()
+				.find_map(|record| match record.event {
+					MockEvent::LiquidityPoolsGateway(Event::DomainStateExported {
+						state, ..
+					}) => Some(state),
+					_ => None,
+				})
+				.expect("state was exported")
+		}
+
+		#[test]
+		fn export_and_import_to_renamed_domain() {
+			new_test_ext().execute_with(|| {
+				setup_domain_state();
+				let old_state = LiquidityPoolsGateway::domain_state(OLD_DOMAIN);
+
+				assert_ok!(LiquidityPoolsGateway::export_domain_state(
+					RuntimeOrigin::root(),
+					OLD_DOMAIN,
+					true,
+					3,
+				));
+
+				assert!(LiquidityPoolsGateway::domain_state(OLD_DOMAIN).is_empty());
+
+				let state = exported_state();
+				assert_eq!(state, old_state.encode());
+
+				assert_ok!(LiquidityPoolsGateway::import_domain_state(
+					RuntimeOrigin::root(),
+					NEW_DOMAIN,
+					state,
+				));
+
+				let new_address = DomainAddress::new(NEW_DOMAIN, TEST_DOMAIN_ADDRESS.bytes());
+
+				assert_eq!(
+					LiquidityPoolsGateway::domain_state(NEW_DOMAIN),
+					DomainState {
+						instances: vec![new_address.clone()],
+						hook_address: Some(get_test_hook_bytes()),
+						inbound_nonce: 3,
+						pending_sequenced_messages: vec![(5, new_address.clone(), Message::Simple)],
+						outbound_batching: true,
+						pending_outbound_batch: None,
+						pause_state: PauseState {
+							inbound: true,
+							outbound: false,
+						},
+						paused_message_range: (0, 1),
+						paused_messages: vec![(
+							0,
+							GatewayMessage::Inbound {
+								domain_address: new_address,
+								message: Message::Simple,
+								router_id: ROUTER_ID_1,
+							}
+						)],
+						inbound_threshold: Some(2),
+						message_version: 1,
+					}
+				);
+				event_exists(Event::<Runtime>::DomainStateImported { domain: NEW_DOMAIN });
+			});
+		}
+
+		#[test]
+		fn export_without_remove() {
+			new_test_ext().execute_with(|| {
+				setup_domain_state();
+				let old_state = LiquidityPoolsGateway::domain_state(OLD_DOMAIN);
+
+				assert_ok!(LiquidityPoolsGateway::export_domain_state(
+					RuntimeOrigin::root(),
+					OLD_DOMAIN,
+					false,
+					3,
+				));
+
+				assert_eq!(LiquidityPoolsGateway::domain_state(OLD_DOMAIN), old_state);
+				event_exists(Event::<Runtime>::DomainStateExported {
+					domain: OLD_DOMAIN,
+					state: old_state.encode(),
+					removed: false,
+				});
+			});
+		}
+
+		#[test]
+		fn export_with_too_many_items() {
+			new_test_ext().execute_with(|| {
+				setup_domain_state();
+
+				for remove in [true, false] {
+					assert_noop!(
+						LiquidityPoolsGateway::export_domain_state(
+							RuntimeOrigin::root(),
+							OLD_DOMAIN,
+							remove,
+							2,
+						),
+						Error::<Runtime>::TooManyDomainStateItems
+					);
+				}
+			});
+		}
+
+		#[test]
+		fn import_into_domain_with_state() {
+			new_test_ext().execute_with(|| {
+				setup_domain_state();
+
+				assert_ok!(LiquidityPoolsGateway::export_domain_state(
+					RuntimeOrigin::root(),
+					OLD_DOMAIN,
+					false,
+					3,
+				));
+
+				assert_noop!(
+					LiquidityPoolsGateway::import_domain_state(
+						RuntimeOrigin::root(),
+						OLD_DOMAIN,
+						exported_state(),
+					),
+					Error::<Runtime>::DomainStateNotEmpty
+				);
+			});
+		}
+
+		#[test]
+		fn import_invalid_state() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::import_domain_state(
+						RuntimeOrigin::root(),
+						NEW_DOMAIN,
+						vec![1, 2, 3],
+					),
+					Error::<Runtime>::InvalidDomainState
+				);
+			});
+		}
+
+		#[test]
+		fn local_domain() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::export_domain_state(
+						RuntimeOrigin::root(),
+						Domain::Centrifuge,
+						false,
+						0,
+					),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
+
+		#[test]
+		fn bad_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::export_domain_state(
+						RuntimeOrigin::signed(get_test_account_id()),
+						OLD_DOMAIN,
+						false,
+						0,
+					),
+					BadOrigin
+				);
+				assert_noop!(
+					LiquidityPoolsGateway::import_domain_state(
+						RuntimeOrigin::signed(get_test_account_id()),
+						NEW_DOMAIN,
+						vec![],
+					),
+					BadOrigin
+				);
+			});
+		}
+	}
+}
+
+mod implementations {
+	use super::*;
+
+	mod outbound_message_handler {
+		use super::*;
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
+				let sender = get_test_account_id();
+				let msg = Message::Simple;
+				let message_hash = msg.get_message_hash();
+
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+				));
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
+					match mock_msg {
+						GatewayMessage::Inbound { .. } => {
+							assert!(false, "expected outbound message")
 						}
+						GatewayMessage::Outbound { message, .. } => match message {
+							Message::Proof(p) => {
+								assert_eq!(p, message_hash);
+							}
+							_ => {}
+						},
 					}
 
-					mod three_messages {
-						use super::*;
+					Ok(())
+				});
 
-						const MESSAGE_COUNT: usize = 3;
+				assert_ok!(LiquidityPoolsGateway::handle(sender, domain, msg));
+				assert_eq!(handler.times(), 3);
+			});
+		}
 
-						#[test]
-						fn success() {
-							let expected_results: HashMap<Vec<RouterMessage>, ExpectedTestResult> =
-								HashMap::from([
-									(
-										vec![
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 0,
-											expected_storage_entries: vec![
-												(
-													ROUTER_ID_1,
-													Some(
-														MessageEntry {
-															session_id: TEST_SESSION_ID,
-															domain_address: TEST_DOMAIN_ADDRESS,
-															message: Message::Simple,
-															expected_proof_count: 3,
-														}
-														.into(),
-													),
-												),
-												(ROUTER_ID_2, None),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 0,
-											expected_storage_entries: vec![
-												(ROUTER_ID_1, None),
-												(
-													ROUTER_ID_2,
-													Some(
-														ProofEntry {
-															session_id: TEST_SESSION_ID,
-															current_count: 3,
-														}
-														.into(),
-													),
-												),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 1,
-											expected_storage_entries: vec![
-												(
-													ROUTER_ID_1,
-													Some(
-														MessageEntry {
-															session_id: TEST_SESSION_ID,
-															domain_address: TEST_DOMAIN_ADDRESS,
-															message: Message::Simple,
-															expected_proof_count: 1,
-														}
-														.into(),
-													),
-												),
-												(ROUTER_ID_2, None),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_1, Message::Simple),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 1,
-											expected_storage_entries: vec![
-												(
-													ROUTER_ID_1,
-													Some(
-														MessageEntry {
-															session_id: TEST_SESSION_ID,
-															domain_address: TEST_DOMAIN_ADDRESS,
-															message: Message::Simple,
-															expected_proof_count: 1,
-														}
-														.into(),
-													),
-												),
-												(ROUTER_ID_2, None),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 1,
-											expected_storage_entries: vec![
-												(ROUTER_ID_1, None),
-												(
-													ROUTER_ID_2,
-													Some(
-														ProofEntry {
-															session_id: TEST_SESSION_ID,
-															current_count: 1,
-														}
-														.into(),
-													),
-												),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 1,
-											expected_storage_entries: vec![
-												(
-													ROUTER_ID_1,
-													Some(
-														MessageEntry {
-															session_id: TEST_SESSION_ID,
-															domain_address: TEST_DOMAIN_ADDRESS,
-															message: Message::Simple,
-															expected_proof_count: 1,
-														}
-														.into(),
-													),
-												),
-												(ROUTER_ID_2, None),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 1,
-											expected_storage_entries: vec![
-												(ROUTER_ID_1, None),
-												(
-													ROUTER_ID_2,
-													Some(
-														ProofEntry {
-															session_id: TEST_SESSION_ID,
-															current_count: 1,
-														}
-														.into(),
-													),
-												),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_1, Message::Simple),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 1,
-											expected_storage_entries: vec![
-												(ROUTER_ID_1, None),
-												(
-													ROUTER_ID_2,
-													Some(
-														ProofEntry {
-															session_id: TEST_SESSION_ID,
-															current_count: 1,
-														}
-														.into(),
-													),
-												),
-											],
-										},
-									),
-								]);
+		#[test]
+		fn domain_not_supported() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Centrifuge;
+				let sender = get_test_account_id();
+				let msg = Message::Simple;
 
-							let suite = generate_test_suite(
-								vec![ROUTER_ID_1, ROUTER_ID_2],
-								TEST_DATA.clone(),
-								expected_results,
-								MESSAGE_COUNT,
-							);
+				assert_noop!(
+					LiquidityPoolsGateway::handle(sender, domain, msg),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
 
-							run_inbound_message_test_suite(suite);
-						}
-					}
+		#[test]
+		fn routers_not_found() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
+				let sender = get_test_account_id();
+				let msg = Message::Simple;
 
-					mod four_messages {
-						use super::*;
+				assert_noop!(
+					LiquidityPoolsGateway::handle(sender, domain, msg),
+					Error::<Runtime>::NotEnoughRoutersForDomain
+				);
+			});
+		}
 
-						const MESSAGE_COUNT: usize = 4;
+		#[test]
+		fn message_queue_error() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
+				let sender = get_test_account_id();
+				let msg = Message::Simple;
 
-						#[test]
-						fn success() {
-							let expected_results: HashMap<Vec<RouterMessage>, ExpectedTestResult> =
-								HashMap::from([
-									(
-										vec![
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 0,
-											expected_storage_entries: vec![
-												(
-													ROUTER_ID_1,
-													Some(
-														MessageEntry {
-															session_id: TEST_SESSION_ID,
-															domain_address: TEST_DOMAIN_ADDRESS,
-															message: Message::Simple,
-															expected_proof_count: 4,
-														}
-														.into(),
-													),
-												),
-												(ROUTER_ID_2, None),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
-										],
-										ExpectedTestResult {
-											message_submitted_times: 0,
-											expected_storage_entries: vec![
-												(ROUTER_ID_1, None),
-												(
-													ROUTER_ID_2,
-													Some(
-														ProofEntry {
-															session_id: TEST_SESSION_ID,
-															current_count: 4,
-														}
-														.into(),
-													),
-												),
-											],
-										},
-									),
-									(
-										vec![
-											(ROUTER_ID_1, Message::Simple),
-											(ROUTER_ID_1, Message::Simple),
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+				));
+
+				let gateway_message = GatewayMessage::Outbound {
+					message: msg.clone(),
+					router_id: ROUTER_ID_1,
+				};
+
+				let err = DispatchError::Unavailable;
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
+					assert_eq!(mock_msg, gateway_message);
+
+					Err(err)
+				});
+
+				assert_noop!(LiquidityPoolsGateway::handle(sender, domain, msg), err);
+				assert_eq!(handler.times(), 1);
+			});
+		}
+
+		#[test]
+		fn message_too_large() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
+				let sender = get_test_account_id();
+
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap(),
+				));
+
+				MaxPayloadSize::set(&Some(0));
+
+				assert_noop!(
+					LiquidityPoolsGateway::handle(sender, domain, Message::Simple),
+					Error::<Runtime>::OutboundMessageTooLarge
+				);
+			});
+		}
+
+		#[test]
+		fn message_split_to_max_payload_size() {
+			new_test_ext().execute_with(|| {
+				let domain = Domain::Evm(0);
+				let sender = get_test_account_id();
+				let msg = Message::Pack(vec![Message::Simple; 5]);
+
+				assert_ok!(LiquidityPoolsGateway::set_routers(
+					RuntimeOrigin::root(),
+					BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2]).unwrap(),
+				));
+
+				MaxPayloadSize::set(&Some(2));
+
+				let queued = Arc::new(Mutex::new(Vec::new()));
+				let queued_inner = queued.clone();
+
+				MockLiquidityPoolsGatewayQueue::mock_queue(move |mock_msg| {
+					queued_inner.lock().unwrap().push(mock_msg);
+
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::handle(sender, domain, msg));
+
+				let pair = Message::Pack(vec![Message::Simple; 2]);
+				let outbound =
+					|message: Message, router_id| GatewayMessage::Outbound { message, router_id };
+
+				assert_eq!(
+					*queued.lock().unwrap(),
+					vec![
+						outbound(pair.clone(), ROUTER_ID_1),
+						outbound(pair.to_proof_message(), ROUTER_ID_2),
+						outbound(pair.clone(), ROUTER_ID_1),
+						outbound(pair.to_proof_message(), ROUTER_ID_2),
+						outbound(Message::Simple, ROUTER_ID_1),
+						outbound(Message::Simple.to_proof_message(), ROUTER_ID_2),
+					]
+				);
+			});
+		}
+	}
+
+	mod message_processor {
+		use super::*;
+
+		mod inbound {
+			use super::*;
+
+			mod util {
+				use super::*;
+
+				pub fn run_inbound_message_test_suite(suite: InboundMessageTestSuite) {
+					let test_routers = suite.routers;
+
+					for test in suite.tests {
+						println!("Executing test for - {:?}", test.router_messages);
+
+						new_test_ext().execute_with(|| {
+							let session_id = TEST_SESSION_ID;
+
+							Routers::<Runtime>::set(
+								BoundedVec::try_from(test_routers.clone()).unwrap(),
+							);
+							SessionIdStore::<Runtime>::set(
+								session_id,
+							);
+
+							let handler = MockLiquidityPools::mock_handle(move |_, _| Ok(()));
+
+							for router_message in test.router_messages {
+								let gateway_message = GatewayMessage::Inbound {
+									domain_address: TEST_DOMAIN_ADDRESS,
+									message: router_message.1,
+									router_id: router_message.0,
+								};
+
+								let (res, _) = LiquidityPoolsGateway::process(gateway_message);
+								assert_ok!(res);
+							}
+
+							let expected_message_submitted_times =
+								test.expected_test_result.message_submitted_times;
+							let message_submitted_times = handler.times();
+
+							assert_eq!(
+								message_submitted_times,
+								expected_message_submitted_times,
+								"Expected message to be submitted {expected_message_submitted_times} times, was {message_submitted_times}"
+							);
+
+							for expected_storage_entry in
+								test.expected_test_result.expected_storage_entries
+							{
+								let expected_storage_entry_router_id = expected_storage_entry.0;
+								let expected_inbound_entry = expected_storage_entry.1;
+
+								let storage_entry = PendingInboundEntries::<Runtime>::get(
+									MESSAGE_HASH, expected_storage_entry_router_id,
+								);
+								assert_eq!(storage_entry, expected_inbound_entry, "Expected inbound entry {expected_inbound_entry:?}, found {storage_entry:?}");
+							}
+						});
+					}
+				}
+
+				/// Used for generating all `RouterMessage` combinations like:
+				///
+				/// vec![
+				/// 	(ROUTER_ID_1, Message::Simple),
+				/// 	(ROUTER_ID_1, Message::Simple),
+				/// ]
+				/// vec![
+				/// 	(ROUTER_ID_1, Message::Simple),
+				/// 	(ROUTER_ID_2, Message::Proof(MESSAGE_PROOF)),
+				/// ]
+				/// vec![
+				/// 	(ROUTER_ID_2, Message::Proof(MESSAGE_PROOF)),
+				/// 	(ROUTER_ID_1, Message::Simple),
+				/// ]
+				/// vec![
+				/// 	(ROUTER_ID_2, Message::Proof(MESSAGE_PROOF)),
+				/// 	(ROUTER_ID_2, Message::Proof(MESSAGE_PROOF)),
+				/// ]
+				pub fn generate_test_combinations<T>(
+					t: T,
+					count: usize,
+				) -> Vec<Vec<<T as IntoIterator>::Item>>
+				where
+					T: IntoIterator + Clone,
+					T::IntoIter: Clone,
+					T::Item: Clone,
+				{
+					std::iter::repeat(t.clone().into_iter())
+						.take(count)
+						.multi_cartesian_product()
+						.collect::<Vec<_>>()
+				}
+
+				/// Type used for mapping a message to a router hash.
+				pub type RouterMessage = (RouterId, Message);
+
+				/// Type used for aggregating tests for inbound messages.
+				pub struct InboundMessageTestSuite {
+					pub routers: Vec<RouterId>,
+					pub tests: Vec<InboundMessageTest>,
+				}
+
+				/// Type used for defining a test which contains a set of
+				/// `RouterMessage` combinations and the expected test result.
+				pub struct InboundMessageTest {
+					pub router_messages: Vec<RouterMessage>,
+					pub expected_test_result: ExpectedTestResult,
+				}
+
+				/// Type used for defining the number of expected inbound
+				/// message submission and the exected storage state.
+				#[derive(Clone, Debug)]
+				pub struct ExpectedTestResult {
+					pub message_submitted_times: u32,
+					pub expected_storage_entries: Vec<(RouterId, Option<InboundEntry<Runtime>>)>,
+				}
+
+				/// Generates the combinations of `RouterMessage` used when
+				/// testing, maps the `ExpectedTestResult` for each and
+				/// creates the `InboundMessageTestSuite`.
+				pub fn generate_test_suite(
+					routers: Vec<RouterId>,
+					test_data: Vec<RouterMessage>,
+					expected_results: HashMap<Vec<RouterMessage>, ExpectedTestResult>,
+					message_count: usize,
+				) -> InboundMessageTestSuite {
+					let tests = generate_test_combinations(test_data, message_count);
+
+					let tests = tests
+						.into_iter()
+						.map(|router_messages| {
+							let expected_test_result = expected_results
+								.get(&router_messages)
+								.expect(
+									format!("test for {router_messages:?} should be covered")
+										.as_str(),
+								)
+								.clone();
+
+							InboundMessageTest {
+								router_messages,
+								expected_test_result,
+							}
+						})
+						.collect::<Vec<_>>();
+
+					InboundMessageTestSuite { routers, tests }
+				}
+			}
+
+			use util::*;
+
+			mod one_router {
+				use super::*;
+
+				#[test]
+				fn success() {
+					new_test_ext().execute_with(|| {
+						let message = Message::Simple;
+						let message_hash = message.get_message_hash();
+						let session_id = 1;
+						let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
+						let router_id = ROUTER_ID_1;
+						let gateway_message = GatewayMessage::Inbound {
+							domain_address: domain_address.clone(),
+							message: message.clone(),
+							router_id: router_id.clone(),
+						};
+
+						Routers::<Runtime>::set(
+							BoundedVec::<_, _>::try_from(vec![router_id.clone()]).unwrap(),
+						);
+						SessionIdStore::<Runtime>::set(session_id);
+
+						let handler = MockLiquidityPools::mock_handle(
+							move |mock_domain_address, mock_message| {
+								assert_eq!(mock_domain_address, domain_address);
+								assert_eq!(mock_message, message);
+
+								Ok(())
+							},
+						);
+
+						let (res, _) = LiquidityPoolsGateway::process(gateway_message);
+						assert_ok!(res);
+						assert_eq!(handler.times(), 1);
+
+						assert!(
+							PendingInboundEntries::<Runtime>::get(message_hash, router_id)
+								.is_none()
+						);
+					});
+				}
+
+				#[test]
+				fn multi_router_not_found() {
+					new_test_ext().execute_with(|| {
+						let message = Message::Simple;
+						let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
+						let router_hash = ROUTER_ID_1;
+						let gateway_message = GatewayMessage::Inbound {
+							domain_address: domain_address.clone(),
+							message: message.clone(),
+							router_id: router_hash,
+						};
+
+						let (res, _) = LiquidityPoolsGateway::process(gateway_message);
+						assert_noop!(res, Error::<Runtime>::NotEnoughRoutersForDomain);
+					});
+				}
+
+				#[test]
+				fn unknown_inbound_message_router() {
+					new_test_ext().execute_with(|| {
+						let message = Message::Simple;
+						let session_id = 1;
+						let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
+						let router_hash = ROUTER_ID_1;
+						let gateway_message = GatewayMessage::Inbound {
+							domain_address: domain_address.clone(),
+							message: message.clone(),
+							// The router stored has a different hash, this should trigger the
+							// expected error.
+							router_id: ROUTER_ID_2,
+						};
+
+						Routers::<Runtime>::set(
+							BoundedVec::<_, _>::try_from(vec![router_hash]).unwrap(),
+						);
+						SessionIdStore::<Runtime>::set(session_id);
+
+						let (res, _) = LiquidityPoolsGateway::process(gateway_message);
+						assert_noop!(res, Error::<Runtime>::UnknownRouter);
+					});
+				}
+
+				#[test]
+				fn expected_message_hash_type() {
+					new_test_ext().execute_with(|| {
+						let message = Message::Simple;
+						let message_hash = message.get_message_hash();
+						let session_id = 1;
+						let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
+						let router_id = ROUTER_ID_1;
+						let gateway_message = GatewayMessage::Inbound {
+							domain_address: domain_address.clone(),
+							message: message.clone(),
+							router_id: router_id.clone(),
+						};
+
+						Routers::<Runtime>::set(
+							BoundedVec::<_, _>::try_from(vec![router_id.clone()]).unwrap(),
+						);
+						SessionIdStore::<Runtime>::set(session_id);
+						PendingInboundEntries::<Runtime>::insert(
+							message_hash,
+							router_id,
+							InboundEntry::Proof(ProofEntry {
+								session_id,
+								current_count: 0,
+							}),
+						);
+
+						let (res, _) = LiquidityPoolsGateway::process(gateway_message);
+						assert_noop!(res, Error::<Runtime>::ExpectedMessageProofType);
+					});
+				}
+			}
+
+			mod two_routers {
+				use super::*;
+
+				mod success {
+					use super::*;
+
+					lazy_static! {
+						static ref TEST_DATA: Vec<RouterMessage> = vec![
+							(ROUTER_ID_1, Message::Simple),
+							(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+						];
+					}
+
+					mod two_messages {
+						use super::*;
+
+						const MESSAGE_COUNT: usize = 2;
+
+						#[test]
+						fn success() {
+							let expected_results: HashMap<Vec<RouterMessage>, ExpectedTestResult> =
+								HashMap::from([
+									(
+										vec![
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 0,
+											expected_storage_entries: vec![
+												(
+													ROUTER_ID_1,
+													Some(
+														MessageEntry {
+															session_id: TEST_SESSION_ID,
+															domain_address: TEST_DOMAIN_ADDRESS,
+															message: Message::Simple,
+															expected_proof_count: 2,
+														}
+														.into(),
+													),
+												),
+												(ROUTER_ID_2, None),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 0,
+											expected_storage_entries: vec![
+												(ROUTER_ID_1, None),
+												(
+													ROUTER_ID_2,
+													Some(
+														ProofEntry {
+															session_id: TEST_SESSION_ID,
+															current_count: 2,
+														}
+														.into(),
+													),
+												),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 1,
+											expected_storage_entries: vec![
+												(ROUTER_ID_1, None),
+												(ROUTER_ID_2, None),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_1, Message::Simple),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 1,
+											expected_storage_entries: vec![
+												(ROUTER_ID_1, None),
+												(ROUTER_ID_2, None),
+											],
+										},
+									),
+								]);
+
+							let suite = generate_test_suite(
+								vec![ROUTER_ID_1, ROUTER_ID_2],
+								TEST_DATA.clone(),
+								expected_results,
+								MESSAGE_COUNT,
+							);
+
+							run_inbound_message_test_suite(suite);
+						}
+					}
+
+					mod three_messages {
+						use super::*;
+
+						const MESSAGE_COUNT: usize = 3;
+
+						#[test]
+						fn success() {
+							let expected_results: HashMap<Vec<RouterMessage>, ExpectedTestResult> =
+								HashMap::from([
+									(
+										vec![
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 0,
+											expected_storage_entries: vec![
+												(
+													ROUTER_ID_1,
+													Some(
+														MessageEntry {
+															session_id: TEST_SESSION_ID,
+															domain_address: TEST_DOMAIN_ADDRESS,
+															message: Message::Simple,
+															expected_proof_count: 3,
+														}
+														.into(),
+													),
+												),
+												(ROUTER_ID_2, None),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 0,
+											expected_storage_entries: vec![
+												(ROUTER_ID_1, None),
+												(
+													ROUTER_ID_2,
+													Some(
+														ProofEntry {
+															session_id: TEST_SESSION_ID,
+															current_count: 3,
+														}
+														.into(),
+													),
+												),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 1,
+											expected_storage_entries: vec![
+												(
+													ROUTER_ID_1,
+													Some(
+														MessageEntry {
+															session_id: TEST_SESSION_ID,
+															domain_address: TEST_DOMAIN_ADDRESS,
+															message: Message::Simple,
+															expected_proof_count: 1,
+														}
+														.into(),
+													),
+												),
+												(ROUTER_ID_2, None),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_1, Message::Simple),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 1,
+											expected_storage_entries: vec![
+												(
+													ROUTER_ID_1,
+													Some(
+														MessageEntry {
+															session_id: TEST_SESSION_ID,
+															domain_address: TEST_DOMAIN_ADDRESS,
+															message: Message::Simple,
+															expected_proof_count: 1,
+														}
+														.into(),
+													),
+												),
+												(ROUTER_ID_2, None),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 1,
+											expected_storage_entries: vec![
+												(ROUTER_ID_1, None),
+												(
+													ROUTER_ID_2,
+													Some(
+														ProofEntry {
+															session_id: TEST_SESSION_ID,
+															current_count: 1,
+														}
+														.into(),
+													),
+												),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 1,
+											expected_storage_entries: vec![
+												(
+													ROUTER_ID_1,
+													Some(
+														MessageEntry {
+															session_id: TEST_SESSION_ID,
+															domain_address: TEST_DOMAIN_ADDRESS,
+															message: Message::Simple,
+															expected_proof_count: 1,
+														}
+														.into(),
+													),
+												),
+												(ROUTER_ID_2, None),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 1,
+											expected_storage_entries: vec![
+												(ROUTER_ID_1, None),
+												(
+													ROUTER_ID_2,
+													Some(
+														ProofEntry {
+															session_id: TEST_SESSION_ID,
+															current_count: 1,
+														}
+														.into(),
+													),
+												),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_1, Message::Simple),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 1,
+											expected_storage_entries: vec![
+												(ROUTER_ID_1, None),
+												(
+													ROUTER_ID_2,
+													Some(
+														ProofEntry {
+															session_id: TEST_SESSION_ID,
+															current_count: 1,
+														}
+														.into(),
+													),
+												),
+											],
+										},
+									),
+								]);
+
+							let suite = generate_test_suite(
+								vec![ROUTER_ID_1, ROUTER_ID_2],
+								TEST_DATA.clone(),
+								expected_results,
+								MESSAGE_COUNT,
+							);
+
+							run_inbound_message_test_suite(suite);
+						}
+					}
+
+					mod four_messages {
+						use super::*;
+
+						const MESSAGE_COUNT: usize = 4;
+
+						#[test]
+						fn success() {
+							let expected_results: HashMap<Vec<RouterMessage>, ExpectedTestResult> =
+								HashMap::from([
+									(
+										vec![
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 0,
+											expected_storage_entries: vec![
+												(
+													ROUTER_ID_1,
+													Some(
+														MessageEntry {
+															session_id: TEST_SESSION_ID,
+															domain_address: TEST_DOMAIN_ADDRESS,
+															message: Message::Simple,
+															expected_proof_count: 4,
+														}
+														.into(),
+													),
+												),
+												(ROUTER_ID_2, None),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
+										],
+										ExpectedTestResult {
+											message_submitted_times: 0,
+											expected_storage_entries: vec![
+												(ROUTER_ID_1, None),
+												(
+													ROUTER_ID_2,
+													Some(
+														ProofEntry {
+															session_id: TEST_SESSION_ID,
+															current_count: 4,
+														}
+														.into(),
+													),
+												),
+											],
+										},
+									),
+									(
+										vec![
+											(ROUTER_ID_1, Message::Simple),
+											(ROUTER_ID_1, Message::Simple),
 											(ROUTER_ID_1, Message::Simple),
 											(ROUTER_ID_2, Message::Proof(MESSAGE_HASH)),
 										],
@@ -3324,107 +4070,1531 @@ mod implementations {
 								),
 							]);
 
-						let suite = generate_test_suite(
-							vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3],
-							TEST_DATA.clone(),
-							expected_results,
-							MESSAGE_COUNT,
-						);
+						let suite = generate_test_suite(
+							vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3],
+							TEST_DATA.clone(),
+							expected_results,
+							MESSAGE_COUNT,
+						);
+
+						run_inbound_message_test_suite(suite);
+					}
+				}
+			}
+
+			#[test]
+			fn inbound_message_handler_error() {
+				new_test_ext().execute_with(|| {
+					let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
+
+					Routers::<Runtime>::set(
+						BoundedVec::try_from(vec![ROUTER_ID_1.clone()]).unwrap(),
+					);
+					SessionIdStore::<Runtime>::set(1);
+
+					let message = Message::Simple;
+					let gateway_message = GatewayMessage::Inbound {
+						domain_address: domain_address.clone(),
+						message: message.clone(),
+						router_id: ROUTER_ID_1,
+					};
+
+					let err = DispatchError::Unavailable;
+
+					MockLiquidityPools::mock_handle(move |mock_domain_address, mock_mesage| {
+						assert_eq!(mock_domain_address, domain_address);
+						assert_eq!(mock_mesage, message);
+
+						Err(err)
+					});
+
+					let (res, _) = LiquidityPoolsGateway::process(gateway_message);
+					assert_noop!(res, err);
+				});
+			}
+		}
+
+		mod outbound {
+			use frame_support::{traits::Get, weights::Weight};
+
+			use super::*;
+
+			#[test]
+			fn success() {
+				new_test_ext().execute_with(|| {
+					let message = Message::Simple;
+
+					let gateway_message = GatewayMessage::Outbound {
+						message: message.clone(),
+						router_id: ROUTER_ID_1,
+					};
+
+					let handler = MockMessageSender::mock_send(
+						move |mock_router_id, mock_sender, mock_message| {
+							assert_eq!(mock_router_id, ROUTER_ID_1);
+							assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
+							assert_eq!(mock_message, message);
+
+							Ok(().into())
+						},
+					);
+
+					let (res, weight) = LiquidityPoolsGateway::process(gateway_message);
+					assert_ok!(res);
+					assert!(weight.eq(&LP_DEFENSIVE_WEIGHT));
+					assert_eq!(handler.times(), 1);
+				});
+			}
+
+			#[test]
+			fn message_sender_error() {
+				new_test_ext().execute_with(|| {
+					let message = Message::Simple;
+
+					let gateway_message = GatewayMessage::Outbound {
+						message: message.clone(),
+						router_id: ROUTER_ID_1,
+					};
+
+					let router_err = DispatchError::Unavailable;
+
+					MockMessageSender::mock_send(
+						move |mock_router_id, mock_sender, mock_message| {
+							assert_eq!(mock_router_id, ROUTER_ID_1);
+							assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
+							assert_eq!(mock_message, message);
+
+							Err(router_err.into())
+						},
+					);
+
+					let (res, weight) = LiquidityPoolsGateway::process(gateway_message);
+					assert_noop!(res, router_err);
+					assert!(weight.eq(&LP_DEFENSIVE_WEIGHT));
+				});
+			}
+
+			#[test]
+			fn message_sender_weight() {
+				new_test_ext().execute_with(|| {
+					let router_weight = Weight::from_parts(100, 10);
+
+					MockMessageSender::mock_send(move |_, _, _| Ok(Some(router_weight).into()));
+
+					let (res, weight) = LiquidityPoolsGateway::process(GatewayMessage::Outbound {
+						message: Message::Simple,
+						router_id: ROUTER_ID_1,
+					});
+					assert_ok!(res);
+					assert_eq!(
+						weight,
+						router_weight.saturating_add(
+							<Runtime as frame_system::Config>::DbWeight::get().reads_writes(4, 2)
+						)
+					);
+				});
+			}
+
+			#[test]
+			fn evm_transaction() {
+				new_test_ext().execute_with(|| {
+					let transaction = TransactionRef {
+						hash: H256::repeat_byte(1),
+						index: 3,
+					};
+
+					LastEthereumTransaction::set(&Some(TransactionRef {
+						hash: H256::repeat_byte(2),
+						index: 2,
+					}));
+
+					MockMessageSender::mock_send(move |_, _, _| {
+						LastEthereumTransaction::set(&Some(transaction));
+						Ok(().into())
+					});
+
+					let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Outbound {
+						message: Message::Simple,
+						router_id: ROUTER_ID_1,
+					});
+					assert_ok!(res);
+
+					assert_eq!(
+						LiquidityPoolsGateway::outbound_evm_transactions(
+							Message::Simple.get_message_hash()
+						),
+						vec![(
+							ROUTER_ID_1,
+							OutboundEvmTransaction {
+								block_number: 1,
+								transaction,
+							}
+						)]
+					);
+				});
+			}
+
+			#[test]
+			fn no_evm_transaction() {
+				new_test_ext().execute_with(|| {
+					LastEthereumTransaction::set(&Some(TransactionRef {
+						hash: H256::repeat_byte(2),
+						index: 2,
+					}));
+
+					MockMessageSender::mock_send(|_, _, _| Ok(().into()));
+
+					let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Outbound {
+						message: Message::Simple,
+						router_id: ROUTER_ID_1,
+					});
+					assert_ok!(res);
+
+					assert!(LiquidityPoolsGateway::outbound_evm_transactions(
+						Message::Simple.get_message_hash()
+					)
+					.is_empty());
+				});
+			}
+		}
+	}
+
+	mod sequenced_inbound {
+		use frame_support::traits::Get;
+
+		use super::*;
+
+		const FAILING_MESSAGE: Message = Message::InitiateMessageRecovery(([2; 32], [3; 32]));
+
+		fn process_sequenced(nonce: u64, message: Message) -> DispatchResult {
+			let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Inbound {
+				domain_address: TEST_DOMAIN_ADDRESS,
+				message: Message::Sequenced(nonce, Box::new(message)),
+				router_id: ROUTER_ID_1,
+			});
+
+			res
+		}
+
+		fn setup() {
+			Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+			SessionIdStore::<Runtime>::set(TEST_SESSION_ID);
+		}
+
+		#[test]
+		fn in_order() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPools::mock_handle(|domain_address, message| {
+					assert_eq!(domain_address, TEST_DOMAIN_ADDRESS);
+					assert_eq!(message, Message::Simple);
+					Ok(())
+				});
+
+				assert_ok!(process_sequenced(1, Message::Simple));
+				assert_ok!(process_sequenced(2, Message::Simple));
+
+				assert_eq!(handler.times(), 2);
+				assert_eq!(InboundNonce::<Runtime>::get(TEST_DOMAIN), 2);
+			});
+		}
+
+		#[test]
+		fn out_of_order() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPools::mock_handle(|_, _| Ok(()));
+
+				assert_ok!(process_sequenced(3, Message::Simple));
+				assert_ok!(process_sequenced(2, Message::Simple));
+
+				event_exists(Event::<Runtime>::InboundMessageDeferred {
+					domain_address: TEST_DOMAIN_ADDRESS,
+					message_hash: MESSAGE_HASH,
+					nonce: 3,
+				});
+
+				assert_eq!(handler.times(), 0);
+				assert_eq!(
+					LiquidityPoolsGateway::inbound_nonce_gaps(TEST_DOMAIN),
+					vec![1]
+				);
+
+				assert_ok!(process_sequenced(1, Message::Simple));
+
+				assert_eq!(handler.times(), 3);
+				assert_eq!(InboundNonce::<Runtime>::get(TEST_DOMAIN), 3);
+				assert_eq!(
+					PendingSequencedMessages::<Runtime>::iter_prefix(TEST_DOMAIN).count(),
+					0
+				);
+				assert!(LiquidityPoolsGateway::inbound_nonce_gaps(TEST_DOMAIN).is_empty());
+			});
+		}
+
+		#[test]
+		fn gaps() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				MockLiquidityPools::mock_handle(|_, _| Ok(()));
+
+				assert_ok!(process_sequenced(2, Message::Simple));
+				assert_ok!(process_sequenced(5, Message::Simple));
+
+				assert_eq!(
+					LiquidityPoolsGateway::inbound_nonce_gaps(TEST_DOMAIN),
+					vec![1, 3, 4]
+				);
+			});
+		}
+
+		#[test]
+		fn already_executed() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				MockLiquidityPools::mock_handle(|_, _| Ok(()));
+
+				assert_ok!(process_sequenced(1, Message::Simple));
+
+				assert_noop!(
+					process_sequenced(1, Message::Simple),
+					Error::<Runtime>::InboundNonceAlreadyExecuted
+				);
+			});
+		}
+
+		#[test]
+		fn already_pending() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				MockLiquidityPools::mock_handle(|_, _| Ok(()));
+
+				assert_ok!(process_sequenced(2, Message::Simple));
+
+				assert_noop!(
+					process_sequenced(2, Message::Simple),
+					Error::<Runtime>::InboundNonceAlreadyPending
+				);
+			});
+		}
+
+		#[test]
+		fn too_far_ahead() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let too_far = 1 + MaxInboundNonceGap::get() + 1;
+
+				assert_noop!(
+					process_sequenced(too_far, Message::Simple),
+					Error::<Runtime>::InboundNonceTooFarAhead
+				);
+			});
+		}
+
+		#[test]
+		fn failing_pending_message() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let err = DispatchError::Other("handler error");
+
+				MockLiquidityPools::mock_handle(move |_, message| {
+					if message == FAILING_MESSAGE {
+						Err(err)
+					} else {
+						Ok(())
+					}
+				});
+
+				assert_ok!(process_sequenced(2, FAILING_MESSAGE));
+				assert_ok!(process_sequenced(3, Message::Simple));
+				assert_ok!(process_sequenced(1, Message::Simple));
+
+				event_exists(Event::<Runtime>::PendingInboundMessageFailed {
+					domain: TEST_DOMAIN,
+					nonce: 2,
+					error: err,
+				});
+
+				// The failing message blocks the following ones
+				assert_eq!(InboundNonce::<Runtime>::get(TEST_DOMAIN), 1);
+				assert!(PendingSequencedMessages::<Runtime>::contains_key(
+					TEST_DOMAIN,
+					2
+				));
+
+				MockLiquidityPools::mock_handle(|_, _| Ok(()));
+
+				assert_ok!(LiquidityPoolsGateway::execute_pending_message(
+					RuntimeOrigin::signed(get_test_account_id()),
+					TEST_DOMAIN,
+				));
+
+				assert_eq!(InboundNonce::<Runtime>::get(TEST_DOMAIN), 3);
+			});
+		}
+
+		#[test]
+		fn execute_pending_message_not_found() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				assert_ok!(process_sequenced(2, Message::Simple));
+
+				assert_noop!(
+					LiquidityPoolsGateway::execute_pending_message(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+					),
+					Error::<Runtime>::PendingInboundMessageNotFound
+				);
+			});
+		}
+	}
+
+	mod batched_inbound {
+		use super::*;
+
+		fn process_inbound(message: Message) -> DispatchResult {
+			let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Inbound {
+				domain_address: TEST_DOMAIN_ADDRESS,
+				message,
+				router_id: ROUTER_ID_1,
+			});
+
+			res
+		}
+
+		fn setup() {
+			Routers::<Runtime>::set(BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap());
+			SessionIdStore::<Runtime>::set(TEST_SESSION_ID);
+		}
+
+		#[test]
+		fn dispatches_each_submessage() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPools::mock_handle(|domain_address, message| {
+					assert_eq!(domain_address, TEST_DOMAIN_ADDRESS);
+					assert_eq!(message, Message::Simple);
+					Ok(())
+				});
+
+				assert_ok!(process_inbound(Message::Pack(vec![
+					Message::Simple,
+					Message::Simple,
+					Message::Simple
+				])));
+
+				assert_eq!(handler.times(), 3);
+				event_exists(Event::<Runtime>::InboundMessageExecuted {
+					domain_address: TEST_DOMAIN_ADDRESS,
+					message_hash: MESSAGE_HASH,
+				});
+			});
+		}
+
+		#[test]
+		fn failing_submessage_reverts_batch() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let err = DispatchError::Unavailable;
+
+				let handler = MockLiquidityPools::mock_handle(move |_, message| match message {
+					Message::Simple => {
+						DomainHookAddress::<Runtime>::insert(TEST_DOMAIN, get_test_hook_bytes());
+						Ok(())
+					}
+					_ => Err(err),
+				});
+
+				assert_err!(
+					process_inbound(Message::Pack(vec![
+						Message::Simple,
+						Message::Proof([2; 32])
+					])),
+					err
+				);
+
+				assert_eq!(handler.times(), 2);
+				assert_eq!(DomainHookAddress::<Runtime>::get(TEST_DOMAIN), None);
+			});
+		}
+	}
+
+	mod inbound_threshold {
+		use super::*;
+
+		fn process_inbound(message: Message, router_id: RouterId) -> DispatchResult {
+			let (res, _) = LiquidityPoolsGateway::process(GatewayMessage::Inbound {
+				domain_address: TEST_DOMAIN_ADDRESS,
+				message,
+				router_id,
+			});
+
+			res
+		}
+
+		fn setup() {
+			Routers::<Runtime>::set(
+				BoundedVec::try_from(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3]).unwrap(),
+			);
+			SessionIdStore::<Runtime>::set(TEST_SESSION_ID);
+			InboundThreshold::<Runtime>::insert(TEST_DOMAIN, 2);
+		}
+
+		#[test]
+		fn executes_with_threshold_reached() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPools::mock_handle(|_, message| {
+					assert_eq!(message, Message::Simple);
+					Ok(())
+				});
+
+				assert_ok!(process_inbound(Message::Simple, ROUTER_ID_1));
+				assert_eq!(handler.times(), 0);
+
+				assert_ok!(process_inbound(Message::Proof(MESSAGE_HASH), ROUTER_ID_3));
+				assert_eq!(handler.times(), 1);
+
+				assert!(PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_1).is_none());
+				assert!(PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_3).is_none());
+			});
+		}
+
+		#[test]
+		fn late_proof_counts_for_next_message() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPools::mock_handle(|_, _| Ok(()));
+
+				assert_ok!(process_inbound(Message::Simple, ROUTER_ID_1));
+				assert_ok!(process_inbound(Message::Proof(MESSAGE_HASH), ROUTER_ID_2));
+				assert_ok!(process_inbound(Message::Proof(MESSAGE_HASH), ROUTER_ID_3));
+				assert_eq!(handler.times(), 1);
+
+				assert_eq!(
+					PendingInboundEntries::<Runtime>::get(MESSAGE_HASH, ROUTER_ID_3),
+					Some(InboundEntry::Proof(ProofEntry {
+						session_id: TEST_SESSION_ID,
+						current_count: 1,
+					}))
+				);
+
+				assert_ok!(process_inbound(Message::Simple, ROUTER_ID_1));
+				assert_eq!(handler.times(), 2);
+			});
+		}
+
+		#[test]
+		fn message_router_is_always_required() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPools::mock_handle(|_, _| Ok(()));
+
+				assert_ok!(process_inbound(Message::Proof(MESSAGE_HASH), ROUTER_ID_2));
+				assert_ok!(process_inbound(Message::Proof(MESSAGE_HASH), ROUTER_ID_3));
+				assert_eq!(handler.times(), 0);
+
+				assert_ok!(process_inbound(Message::Simple, ROUTER_ID_1));
+				assert_eq!(handler.times(), 1);
+			});
+		}
+	}
+
+	mod relayed_message {
+		use frame_support::{
+			pallet_prelude::{InvalidTransaction, TransactionSource, ValidateUnsigned},
+			traits::{Currency, ReservableCurrency},
+		};
+		use parity_scale_codec::Encode;
+		use sp_runtime::RuntimeAppPublic;
+
+		use super::*;
+
+		const RELAYER: UintAuthorityId = UintAuthorityId(1);
+		const RELAYER_ACCOUNT: AccountId32 = AccountId32::new([5; 32]);
+		const FEE: u128 = 10;
+
+		fn setup() {
+			assert_ok!(LiquidityPoolsGateway::add_instance(
+				RuntimeOrigin::root(),
+				TEST_DOMAIN_ADDRESS,
+			));
+
+			Balances::make_free_balance_be(&RELAYER_ACCOUNT, RELAYER_BOND * 2);
+			assert_ok!(Balances::reserve(&RELAYER_ACCOUNT, RELAYER_BOND));
+
+			Relayers::<Runtime>::insert(
+				RELAYER,
+				RelayerInfo {
+					router_id: ROUTER_ID_1,
+					nonce: 2,
+					reputation: 5,
+					account: Some(RELAYER_ACCOUNT),
+					bond: RELAYER_BOND,
+				},
+			);
+		}
+
+		fn receive(message: RelayedMessage<UintAuthorityId>) -> DispatchResult {
+			let Call::receive_relayed_message { message, signature } = call_for(message, RELAYER)
+			else {
+				unreachable!()
+			};
+
+			LiquidityPoolsGateway::receive_relayed_message(
+				RuntimeOrigin::none(),
+				message,
+				signature,
+			)
+		}
+
+		fn relayed_message(nonce: u64) -> RelayedMessage<UintAuthorityId> {
+			RelayedMessage {
+				relayer: RELAYER,
+				nonce,
+				domain_address: TEST_DOMAIN_ADDRESS,
+				payload: Message::Simple.serialize(),
+			}
+		}
+
+		fn call_for(
+			message: RelayedMessage<UintAuthorityId>,
+			signer: UintAuthorityId,
+		) -> Call<Runtime> {
+			let signature = signer
+				.sign(&LiquidityPoolsGateway::relayed_message_signing_payload(
+					&message,
+				))
+				.unwrap();
+
+			Call::receive_relayed_message { message, signature }
+		}
+
+		fn validate(call: &Call<Runtime>) -> TransactionValidity {
+			LiquidityPoolsGateway::validate_unsigned(TransactionSource::External, call)
+		}
+
+		#[test]
+		fn success() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|message| {
+					assert_eq!(
+						message,
+						GatewayMessage::Inbound {
+							domain_address: TEST_DOMAIN_ADDRESS,
+							message: Message::Simple,
+							router_id: ROUTER_ID_1,
+						}
+					);
+					Ok(())
+				});
+
+				let Call::receive_relayed_message { message, signature } =
+					call_for(relayed_message(2), RELAYER)
+				else {
+					unreachable!()
+				};
+
+				assert_ok!(LiquidityPoolsGateway::receive_relayed_message(
+					RuntimeOrigin::none(),
+					message,
+					signature,
+				));
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(
+					Relayers::<Runtime>::get(RELAYER),
+					Some(RelayerInfo {
+						router_id: ROUTER_ID_1,
+						nonce: 3,
+						reputation: 6,
+						account: Some(RELAYER_ACCOUNT),
+						bond: RELAYER_BOND,
+					})
+				);
+				event_exists(Event::<Runtime>::RelayedMessageReceived {
+					relayer: RELAYER,
+					nonce: 2,
+					message_hash: Message::Simple.get_message_hash(),
+				});
+			});
+		}
+
+		#[test]
+		fn versioned_payload() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				let domain = TEST_DOMAIN_ADDRESS.domain();
+				assert_eq!(LiquidityPoolsGateway::outbound_version(&domain), 0);
+
+				assert_ok!(receive(RelayedMessage {
+					payload: Message::Simple.serialize_versioned(1),
+					..relayed_message(2)
+				}));
+
+				assert_eq!(LiquidityPoolsGateway::outbound_version(&domain), 1);
+				event_exists(Event::<Runtime>::DomainMessageVersionNegotiated {
+					domain,
+					version: 1,
+				});
+
+				// Older versions are still accepted without downgrading
+				assert_ok!(receive(relayed_message(3)));
+				assert_eq!(LiquidityPoolsGateway::outbound_version(&domain), 1);
+			});
+		}
+
+		#[test]
+		fn signed_origin() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let Call::receive_relayed_message { message, signature } =
+					call_for(relayed_message(2), RELAYER)
+				else {
+					unreachable!()
+				};
+
+				assert_noop!(
+					LiquidityPoolsGateway::receive_relayed_message(
+						RuntimeOrigin::signed(get_test_account_id()),
+						message,
+						signature,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn validate_priority_by_reputation() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let valid = validate(&call_for(relayed_message(2), RELAYER)).unwrap();
+
+				assert_eq!(valid.priority, UnsignedPriority::get() + 5);
+				assert_eq!(
+					valid.provides,
+					vec![("LPGatewayRelayer", (RELAYER, 2u64)).encode()]
+				);
+			});
+		}
+
+		#[test]
+		fn validate_failures() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				assert_eq!(
+					validate(&call_for(relayed_message(1), RELAYER)),
+					Err(InvalidTransaction::Stale.into())
+				);
+				assert_eq!(
+					validate(&call_for(relayed_message(3), RELAYER)),
+					Err(InvalidTransaction::Future.into())
+				);
+				assert_eq!(
+					validate(&call_for(relayed_message(2), UintAuthorityId(2))),
+					Err(InvalidTransaction::BadProof.into())
+				);
+
+				let mut message = relayed_message(2);
+				message.relayer = UintAuthorityId(2);
+				assert_eq!(
+					validate(&call_for(message, UintAuthorityId(2))),
+					Err(InvalidTransaction::BadSigner.into())
+				);
+
+				let mut message = relayed_message(2);
+				message.payload = vec![0x42; MaxIncomingMessageSize::get() as usize + 1];
+				assert_eq!(
+					validate(&call_for(message, RELAYER)),
+					Err(InvalidTransaction::ExhaustsResources.into())
+				);
+
+				assert_eq!(
+					validate(&Call::set_emergency_pause { paused: true }),
+					Err(InvalidTransaction::Call.into())
+				);
+			});
+		}
+
+		#[test]
+		fn validate_signature_for_other_chain() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let message = relayed_message(2);
+
+				let bare_signature = message.using_encoded(|bytes| RELAYER.sign(&bytes)).unwrap();
+				assert_eq!(
+					validate(&Call::receive_relayed_message {
+						message: message.clone(),
+						signature: bare_signature,
+					}),
+					Err(InvalidTransaction::BadProof.into())
+				);
+
+				let other_chain_signature = RELAYER
+					.sign(
+						&(
+							RELAYED_MESSAGE_SIGNING_CONTEXT,
+							H256::repeat_byte(1),
+							&message,
+						)
+							.encode(),
+					)
+					.unwrap();
+				assert_eq!(
+					validate(&Call::receive_relayed_message {
+						message,
+						signature: other_chain_signature,
+					}),
+					Err(InvalidTransaction::BadProof.into())
+				);
+			});
+		}
+
+		#[test]
+		fn validate_not_bonded() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				Relayers::<Runtime>::mutate(RELAYER, |info| {
+					info.as_mut().unwrap().account = None;
+				});
+
+				assert_eq!(
+					validate(&call_for(relayed_message(2), RELAYER)),
+					Err(InvalidTransaction::BadSigner.into())
+				);
+			});
+		}
+
+		#[test]
+		fn fee_paid_from_fee_pot() {
+			new_test_ext().execute_with(|| {
+				setup();
+				MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				let fee_pot = LiquidityPoolsGateway::fee_pot();
+				Balances::make_free_balance_be(&fee_pot, FEE * 2);
+				RelayerFeeStore::<Runtime>::put(RelayerFee {
+					amount: FEE,
+					payer: RelayerFeePayer::FeePot,
+				});
+
+				assert_ok!(receive(relayed_message(2)));
+
+				assert_eq!(Balances::free_balance(&fee_pot), FEE);
+				assert_eq!(Balances::free_balance(&RELAYER_ACCOUNT), RELAYER_BOND + FEE);
+				event_exists(Event::<Runtime>::RelayerFeePaid {
+					relayer: RELAYER,
+					payer: fee_pot,
+					amount: FEE,
+				});
+			});
+		}
+
+		#[test]
+		fn fee_paid_from_originator() {
+			new_test_ext().execute_with(|| {
+				setup();
+				MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				let originator = TEST_DOMAIN_ADDRESS.account();
+				Balances::make_free_balance_be(&originator, FEE * 2);
+				RelayerFeeStore::<Runtime>::put(RelayerFee {
+					amount: FEE,
+					payer: RelayerFeePayer::Originator,
+				});
+
+				assert_ok!(receive(relayed_message(2)));
+
+				assert_eq!(Balances::free_balance(&originator), FEE);
+				assert_eq!(Balances::free_balance(&RELAYER_ACCOUNT), RELAYER_BOND + FEE);
+			});
+		}
+
+		#[test]
+		fn unpaid_fee_does_not_fail_the_message() {
+			new_test_ext().execute_with(|| {
+				setup();
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				RelayerFeeStore::<Runtime>::put(RelayerFee {
+					amount: FEE,
+					payer: RelayerFeePayer::FeePot,
+				});
+
+				assert_ok!(receive(relayed_message(2)));
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(Balances::free_balance(&RELAYER_ACCOUNT), RELAYER_BOND);
+				assert_eq!(Relayers::<Runtime>::get(RELAYER).unwrap().reputation, 6);
+			});
+		}
+
+		#[test]
+		fn invalid_message_slashes_relayer() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let mut message = relayed_message(2);
+				message.domain_address = DomainAddress::Evm(TEST_EVM_CHAIN, H160::repeat_byte(2));
+
+				assert_ok!(receive(message));
+
+				let fee_pot = LiquidityPoolsGateway::fee_pot();
+				assert_eq!(Balances::free_balance(&fee_pot), RELAYER_BOND);
+				assert_eq!(Balances::reserved_balance(&RELAYER_ACCOUNT), 0);
+				assert_eq!(
+					Relayers::<Runtime>::get(RELAYER),
+					Some(RelayerInfo {
+						router_id: ROUTER_ID_1,
+						nonce: 3,
+						reputation: 5,
+						account: None,
+						bond: 0,
+					})
+				);
+				event_exists(Event::<Runtime>::RelayerSlashed {
+					relayer: RELAYER,
+					amount: RELAYER_BOND,
+					error: Error::<Runtime>::UnknownInstance.into(),
+				});
+
+				// The slashed relayer can not submit messages until bonded again
+				assert_noop!(
+					receive(relayed_message(3)),
+					Error::<Runtime>::RelayerNotBonded
+				);
+			});
+		}
+	}
+
+	mod simulate_inbound {
+		use super::*;
+
+		fn setup() {
+			Allowlist::<Runtime>::insert(TEST_DOMAIN, TEST_DOMAIN_ADDRESS, ());
+		}
+
+		#[test]
+		fn success_without_state_changes() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPools::mock_handle(|domain_address, message| {
+					assert_eq!(domain_address, TEST_DOMAIN_ADDRESS);
+					assert_eq!(message, Message::Simple);
+
+					DomainHookAddress::<Runtime>::insert(TEST_DOMAIN, get_test_hook_bytes());
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::simulate_inbound_message(
+					TEST_DOMAIN_ADDRESS,
+					Message::Simple.serialize()
+				));
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(DomainHookAddress::<Runtime>::get(TEST_DOMAIN), None);
+				assert!(frame_system::Pallet::<Runtime>::events().is_empty());
+			});
+		}
+
+		#[test]
+		fn dispatch_error() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let err = DispatchError::Unavailable;
+
+				MockLiquidityPools::mock_handle(move |_, _| Err(err));
+
+				assert_err!(
+					LiquidityPoolsGateway::simulate_inbound_message(
+						TEST_DOMAIN_ADDRESS,
+						Message::Simple.serialize()
+					),
+					err
+				);
+			});
+		}
+
+		#[test]
+		fn unknown_instance() {
+			new_test_ext().execute_with(|| {
+				assert_err!(
+					LiquidityPoolsGateway::simulate_inbound_message(
+						TEST_DOMAIN_ADDRESS,
+						Message::Simple.serialize()
+					),
+					Error::<Runtime>::UnknownInstance
+				);
+			});
+		}
+	}
+
+	mod outbound_batching {
+		use frame_support::traits::Hooks;
+
+		use super::*;
+
+		fn setup() {
+			assert_ok!(LiquidityPoolsGateway::set_routers(
+				RuntimeOrigin::root(),
+				BoundedVec::try_from(vec![ROUTER_ID_1]).unwrap(),
+			));
+
+			assert_ok!(LiquidityPoolsGateway::set_outbound_batching(
+				RuntimeOrigin::root(),
+				TEST_DOMAIN,
+				true,
+			));
+		}
+
+		#[test]
+		fn set_outbound_batching() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_outbound_batching(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					true,
+				));
+
+				assert!(OutboundBatching::<Runtime>::contains_key(TEST_DOMAIN));
+				event_exists(Event::<Runtime>::OutboundBatchingSet {
+					domain: TEST_DOMAIN,
+					enabled: true,
+				});
+
+				assert_ok!(LiquidityPoolsGateway::set_outbound_batching(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					false,
+				));
+
+				assert!(!OutboundBatching::<Runtime>::contains_key(TEST_DOMAIN));
+			});
+		}
+
+		#[test]
+		fn set_outbound_batching_wrong_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_outbound_batching(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						true,
+					),
+					BadOrigin
+				);
+			});
+		}
+
+		#[test]
+		fn set_outbound_batching_centrifuge_domain() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_outbound_batching(
+						RuntimeOrigin::root(),
+						Domain::Centrifuge,
+						true,
+					),
+					Error::<Runtime>::DomainNotSupported
+				);
+			});
+		}
+
+		#[test]
+		fn messages_queued_in_next_block() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|mock_msg| {
+					assert_eq!(
+						mock_msg,
+						GatewayMessage::Outbound {
+							message: Message::Pack(vec![Message::Simple, Message::Simple]),
+							router_id: ROUTER_ID_1,
+						}
+					);
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::handle(
+					get_test_account_id(),
+					TEST_DOMAIN,
+					Message::Simple
+				));
+				assert_ok!(LiquidityPoolsGateway::handle(
+					get_test_account_id(),
+					TEST_DOMAIN,
+					Message::Simple
+				));
+
+				assert_eq!(handler.times(), 0);
+
+				LiquidityPoolsGateway::on_initialize(2);
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(PendingOutboundBatch::<Runtime>::get(TEST_DOMAIN), None);
+			});
+		}
+
+		#[test]
+		fn full_batch_queued_immediately() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let handler = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				for _ in 0..MAX_PACKED_MESSAGES + 1 {
+					assert_ok!(LiquidityPoolsGateway::handle(
+						get_test_account_id(),
+						TEST_DOMAIN,
+						Message::Simple
+					));
+				}
+
+				assert_eq!(handler.times(), 1);
+				assert_eq!(
+					PendingOutboundBatch::<Runtime>::get(TEST_DOMAIN),
+					Some(Message::Simple)
+				);
+			});
+		}
+
+		#[test]
+		fn routers_not_found() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_outbound_batching(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					true,
+				));
+
+				assert_noop!(
+					LiquidityPoolsGateway::handle(
+						get_test_account_id(),
+						TEST_DOMAIN,
+						Message::Simple
+					),
+					Error::<Runtime>::NotEnoughRoutersForDomain
+				);
+			});
+		}
+
+		#[test]
+		fn queue_failure_keeps_batch() {
+			new_test_ext().execute_with(|| {
+				setup();
+
+				let err = DispatchError::Unavailable;
+
+				MockLiquidityPoolsGatewayQueue::mock_queue(move |_| Err(err));
+
+				assert_ok!(LiquidityPoolsGateway::handle(
+					get_test_account_id(),
+					TEST_DOMAIN,
+					Message::Simple
+				));
+
+				LiquidityPoolsGateway::on_initialize(2);
+
+				event_exists(Event::<Runtime>::OutboundBatchQueueFailed {
+					domain: TEST_DOMAIN,
+					error: err,
+				});
+				assert_eq!(
+					PendingOutboundBatch::<Runtime>::get(TEST_DOMAIN),
+					Some(Message::Simple)
+				);
+			});
+		}
+	}
+
+	mod domain_pause {
+		use super::*;
 
-						run_inbound_message_test_suite(suite);
-					}
-				}
+		const INBOUND: PauseState = PauseState {
+			inbound: true,
+			outbound: false,
+		};
+
+		fn inbound_message() -> GatewayMessage<Message, RouterId> {
+			GatewayMessage::Inbound {
+				domain_address: TEST_DOMAIN_ADDRESS,
+				message: Message::Simple,
+				router_id: ROUTER_ID_1,
 			}
+		}
 
-			#[test]
-			fn inbound_message_handler_error() {
-				new_test_ext().execute_with(|| {
-					let domain_address = DomainAddress::Evm(1, H160::repeat_byte(1));
+		fn outbound_message() -> GatewayMessage<Message, RouterId> {
+			GatewayMessage::Outbound {
+				message: Message::Simple,
+				router_id: ROUTER_ID_1,
+			}
+		}
 
-					Routers::<Runtime>::set(
-						BoundedVec::try_from(vec![ROUTER_ID_1.clone()]).unwrap(),
-					);
-					SessionIdStore::<Runtime>::set(1);
+		#[test]
+		fn set_domain_pause() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_domain_pause(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					INBOUND,
+				));
 
-					let message = Message::Simple;
-					let gateway_message = GatewayMessage::Inbound {
-						domain_address: domain_address.clone(),
-						message: message.clone(),
-						router_id: ROUTER_ID_1,
-					};
+				assert_eq!(PausedDomains::<Runtime>::get(TEST_DOMAIN), INBOUND);
+				event_exists(Event::<Runtime>::DomainPauseSet {
+					domain: TEST_DOMAIN,
+					state: INBOUND,
+				});
 
-					let err = DispatchError::Unavailable;
+				assert_ok!(LiquidityPoolsGateway::set_domain_pause(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					PauseState::default(),
+				));
 
-					MockLiquidityPools::mock_handle(move |mock_domain_address, mock_mesage| {
-						assert_eq!(mock_domain_address, domain_address);
-						assert_eq!(mock_mesage, message);
+				assert!(!PausedDomains::<Runtime>::contains_key(TEST_DOMAIN));
+			});
+		}
 
-						Err(err)
-					});
+		#[test]
+		fn set_domain_pause_wrong_origin() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::set_domain_pause(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						INBOUND,
+					),
+					BadOrigin
+				);
+				assert_noop!(
+					LiquidityPoolsGateway::set_emergency_pause(
+						RuntimeOrigin::signed(get_test_account_id()),
+						true,
+					),
+					BadOrigin
+				);
+			});
+		}
 
-					let (res, _) = LiquidityPoolsGateway::process(gateway_message);
-					assert_noop!(res, err);
+		#[test]
+		fn paused_direction_is_held() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_domain_pause(
+					RuntimeOrigin::root(),
+					TEST_DOMAIN,
+					INBOUND,
+				));
+
+				let handler = MockLiquidityPools::mock_handle(|_, _| Ok(()));
+				let sender = MockMessageSender::mock_send(|_, _, _| Ok(().into()));
+
+				let (res, _) = LiquidityPoolsGateway::process(inbound_message());
+				assert_ok!(res);
+
+				let (res, _) = LiquidityPoolsGateway::process(outbound_message());
+				assert_ok!(res);
+
+				assert_eq!(handler.times(), 0);
+				assert_eq!(sender.times(), 1);
+				assert_eq!(
+					PausedMessages::<Runtime>::get(TEST_DOMAIN, 0),
+					Some(inbound_message())
+				);
+				event_exists(Event::<Runtime>::MessageHeld {
+					domain: TEST_DOMAIN,
+					index: 0,
 				});
-			}
+			});
 		}
 
-		mod outbound {
-			use super::*;
+		#[test]
+		fn emergency_pause_holds_all() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_emergency_pause(
+					RuntimeOrigin::root(),
+					true,
+				));
 
-			#[test]
-			fn success() {
-				new_test_ext().execute_with(|| {
-					let message = Message::Simple;
+				let sender = MockMessageSender::mock_send(|_, _, _| Ok(().into()));
 
-					let gateway_message = GatewayMessage::Outbound {
-						message: message.clone(),
-						router_id: ROUTER_ID_1,
-					};
+				let (res, _) = LiquidityPoolsGateway::process(outbound_message());
+				assert_ok!(res);
 
-					let handler = MockMessageSender::mock_send(
-						move |mock_router_id, mock_sender, mock_message| {
-							assert_eq!(mock_router_id, ROUTER_ID_1);
-							assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
-							assert_eq!(mock_message, message);
+				assert_eq!(sender.times(), 0);
+				assert_eq!(
+					PausedMessages::<Runtime>::get(TEST_DOMAIN, 0),
+					Some(outbound_message())
+				);
+			});
+		}
 
-							Ok(())
-						},
-					);
+		#[test]
+		fn resume_paused_messages() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(LiquidityPoolsGateway::set_emergency_pause(
+					RuntimeOrigin::root(),
+					true,
+				));
 
-					let (res, weight) = LiquidityPoolsGateway::process(gateway_message);
-					assert_ok!(res);
-					assert!(weight.eq(&LP_DEFENSIVE_WEIGHT));
-					assert_eq!(handler.times(), 1);
+				LiquidityPoolsGateway::process(inbound_message());
+				LiquidityPoolsGateway::process(outbound_message());
+				LiquidityPoolsGateway::process(inbound_message());
+
+				assert_noop!(
+					LiquidityPoolsGateway::resume_paused_messages(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						2,
+					),
+					Error::<Runtime>::DomainPaused
+				);
+
+				assert_ok!(LiquidityPoolsGateway::set_emergency_pause(
+					RuntimeOrigin::root(),
+					false,
+				));
+
+				let queued = Arc::new(Mutex::new(Vec::new()));
+				let queued_clone = queued.clone();
+
+				MockLiquidityPoolsGatewayQueue::mock_queue(move |message| {
+					queued_clone.lock().unwrap().push(message);
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::resume_paused_messages(
+					RuntimeOrigin::signed(get_test_account_id()),
+					TEST_DOMAIN,
+					2,
+				));
+
+				assert_eq!(
+					*queued.lock().unwrap(),
+					vec![inbound_message(), outbound_message()]
+				);
+				assert_eq!(PausedMessageRange::<Runtime>::get(TEST_DOMAIN), (2, 3));
+				event_exists(Event::<Runtime>::PausedMessagesResumed {
+					domain: TEST_DOMAIN,
+					count: 2,
 				});
+
+				assert_ok!(LiquidityPoolsGateway::resume_paused_messages(
+					RuntimeOrigin::signed(get_test_account_id()),
+					TEST_DOMAIN,
+					2,
+				));
+
+				assert_eq!(queued.lock().unwrap().len(), 3);
+				assert!(!PausedMessageRange::<Runtime>::contains_key(TEST_DOMAIN));
+				assert_eq!(PausedMessages::<Runtime>::iter().count(), 0);
+			});
+		}
+
+		#[test]
+		fn resume_without_paused_messages() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					LiquidityPoolsGateway::resume_paused_messages(
+						RuntimeOrigin::signed(get_test_account_id()),
+						TEST_DOMAIN,
+						1,
+					),
+					Error::<Runtime>::NoPausedMessages
+				);
+			});
+		}
+	}
+
+	mod quarantine {
+		use super::*;
+
+		fn inbound_message() -> GatewayMessage<Message, RouterId> {
+			GatewayMessage::Inbound {
+				domain_address: TEST_DOMAIN_ADDRESS,
+				message: Message::Simple,
+				router_id: ROUTER_ID_1,
 			}
+		}
 
-			#[test]
-			fn message_sender_error() {
-				new_test_ext().execute_with(|| {
-					let message = Message::Simple;
+		fn outbound_message() -> GatewayMessage<Message, RouterId> {
+			GatewayMessage::Outbound {
+				message: Message::Simple,
+				router_id: ROUTER_ID_2,
+			}
+		}
 
-					let gateway_message = GatewayMessage::Outbound {
-						message: message.clone(),
-						router_id: ROUTER_ID_1,
-					};
+		#[test]
+		fn quarantine_message() {
+			new_test_ext().execute_with(|| {
+				let message_hash = Message::Simple.get_message_hash();
 
-					let router_err = DispatchError::Unavailable;
+				assert_noop!(
+					LiquidityPoolsGateway::quarantine_message(
+						RuntimeOrigin::signed(get_test_account_id()),
+						message_hash,
+					),
+					BadOrigin
+				);
 
-					MockMessageSender::mock_send(
-						move |mock_router_id, mock_sender, mock_message| {
-							assert_eq!(mock_router_id, ROUTER_ID_1);
-							assert_eq!(mock_sender, <Runtime as Config>::Sender::get());
-							assert_eq!(mock_message, message);
+				assert_ok!(LiquidityPoolsGateway::quarantine_message(
+					RuntimeOrigin::root(),
+					message_hash,
+				));
 
-							Err(router_err)
-						},
-					);
+				assert_eq!(
+					QuarantinedMessages::<Runtime>::get(message_hash),
+					Some(BoundedVec::default())
+				);
+				event_exists(Event::<Runtime>::MessageQuarantined { message_hash });
 
-					let (res, weight) = LiquidityPoolsGateway::process(gateway_message);
-					assert_noop!(res, router_err);
-					assert!(weight.eq(&LP_DEFENSIVE_WEIGHT));
+				assert_noop!(
+					LiquidityPoolsGateway::quarantine_message(RuntimeOrigin::root(), message_hash),
+					Error::<Runtime>::MessageAlreadyQuarantined
+				);
+			});
+		}
+
+		#[test]
+		fn quarantined_message_is_held() {
+			new_test_ext().execute_with(|| {
+				let message_hash = Message::Simple.get_message_hash();
+
+				assert_ok!(LiquidityPoolsGateway::quarantine_message(
+					RuntimeOrigin::root(),
+					message_hash,
+				));
+
+				let handler = MockLiquidityPools::mock_handle(|_, _| Ok(()));
+				let sender = MockMessageSender::mock_send(|_, _, _| Ok(().into()));
+
+				let (res, _) = LiquidityPoolsGateway::process(inbound_message());
+				assert_ok!(res);
+
+				let (res, _) = LiquidityPoolsGateway::process(outbound_message());
+				assert_ok!(res);
+
+				assert_eq!(handler.times(), 0);
+				assert_eq!(sender.times(), 0);
+				assert_eq!(
+					QuarantinedMessages::<Runtime>::get(message_hash).unwrap(),
+					vec![inbound_message(), outbound_message()]
+				);
+				event_exists(Event::<Runtime>::QuarantinedMessageHeld {
+					message_hash,
+					router_id: ROUTER_ID_2,
 				});
-			}
+			});
+		}
+
+		#[test]
+		fn release_quarantined_message() {
+			new_test_ext().execute_with(|| {
+				let message_hash = Message::Simple.get_message_hash();
+
+				assert_noop!(
+					LiquidityPoolsGateway::release_quarantined_message(
+						RuntimeOrigin::root(),
+						message_hash,
+					),
+					Error::<Runtime>::MessageNotQuarantined
+				);
+
+				assert_ok!(LiquidityPoolsGateway::quarantine_message(
+					RuntimeOrigin::root(),
+					message_hash,
+				));
+
+				LiquidityPoolsGateway::process(inbound_message());
+				LiquidityPoolsGateway::process(outbound_message());
+
+				let queued = Arc::new(Mutex::new(Vec::new()));
+				let queued_clone = queued.clone();
+
+				MockLiquidityPoolsGatewayQueue::mock_queue(move |message| {
+					queued_clone.lock().unwrap().push(message);
+					Ok(())
+				});
+
+				assert_ok!(LiquidityPoolsGateway::release_quarantined_message(
+					RuntimeOrigin::root(),
+					message_hash,
+				));
+
+				assert_eq!(
+					*queued.lock().unwrap(),
+					vec![inbound_message(), outbound_message()]
+				);
+				assert!(!QuarantinedMessages::<Runtime>::contains_key(message_hash));
+				event_exists(Event::<Runtime>::QuarantinedMessageReleased {
+					message_hash,
+					count: 2,
+				});
+			});
+		}
+
+		#[test]
+		fn discard_quarantined_message() {
+			new_test_ext().execute_with(|| {
+				let message_hash = Message::Simple.get_message_hash();
+
+				assert_noop!(
+					LiquidityPoolsGateway::discard_quarantined_message(
+						RuntimeOrigin::root(),
+						message_hash,
+					),
+					Error::<Runtime>::MessageNotQuarantined
+				);
+
+				assert_ok!(LiquidityPoolsGateway::quarantine_message(
+					RuntimeOrigin::root(),
+					message_hash,
+				));
+
+				LiquidityPoolsGateway::process(inbound_message());
+
+				let queue = MockLiquidityPoolsGatewayQueue::mock_queue(|_| Ok(()));
+
+				assert_ok!(LiquidityPoolsGateway::discard_quarantined_message(
+					RuntimeOrigin::root(),
+					message_hash,
+				));
+
+				assert_eq!(queue.times(), 0);
+				assert!(!QuarantinedMessages::<Runtime>::contains_key(message_hash));
+				event_exists(Event::<Runtime>::QuarantinedMessageDiscarded {
+					message_hash,
+					count: 1,
+				});
+			});
 		}
 	}
 
@@ -3587,17 +5757,40 @@ mod implementations {
 					];
 
 					for test in tests {
-						let res = LiquidityPoolsGateway::get_expected_proof_count(&test).unwrap();
+						let res =
+							LiquidityPoolsGateway::get_expected_proof_count(TEST_DOMAIN, &test)
+								.unwrap();
 
 						assert_eq!(res, (test.len() - 1) as u32);
 					}
 				});
 			}
 
+			#[test]
+			fn with_threshold() {
+				new_test_ext().execute_with(|| {
+					InboundThreshold::<Runtime>::insert(TEST_DOMAIN, 2);
+
+					let tests = vec![
+						(vec![ROUTER_ID_1], 0),
+						(vec![ROUTER_ID_1, ROUTER_ID_2], 1),
+						(vec![ROUTER_ID_1, ROUTER_ID_2, ROUTER_ID_3], 1),
+					];
+
+					for (test, expected) in tests {
+						let res =
+							LiquidityPoolsGateway::get_expected_proof_count(TEST_DOMAIN, &test)
+								.unwrap();
+
+						assert_eq!(res, expected);
+					}
+				});
+			}
+
 			#[test]
 			fn not_enough_routers_for_domain() {
 				new_test_ext().execute_with(|| {
-					let res = LiquidityPoolsGateway::get_expected_proof_count(&vec![]);
+					let res = LiquidityPoolsGateway::get_expected_proof_count(TEST_DOMAIN, &vec![]);
 
 					assert_eq!(
 						res.err().unwrap(),
@@ -25,6 +25,21 @@ pub trait WeightInfo {
 	fn execute_message_recovery() -> Weight;
 	fn initiate_message_recovery() -> Weight;
 	fn dispute_message_recovery() -> Weight;
+	fn execute_pending_message() -> Weight;
+	fn set_outbound_batching() -> Weight;
+	fn set_domain_pause() -> Weight;
+	fn set_emergency_pause() -> Weight;
+	fn resume_paused_messages(n: u32) -> Weight;
+	fn set_inbound_threshold() -> Weight;
+	fn register_relayer() -> Weight;
+	fn unregister_relayer() -> Weight;
+	fn set_relayer_fee() -> Weight;
+	fn set_domain_message_version() -> Weight;
+	fn quarantine_message() -> Weight;
+	fn release_quarantined_message(n: u32) -> Weight;
+	fn discard_quarantined_message() -> Weight;
+	fn export_domain_state(n: u32) -> Weight;
+	fn import_domain_state(n: u32) -> Weight;
 }
 
 // NOTE: We use temporary weights here. `execute_epoch` is by far our heaviest
@@ -170,4 +185,193 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
+
+	fn execute_pending_message() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has two reads and two writes for the nonce and the
+		//       pending message, plus the execution of the message itself.
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+			.saturating_add(cfg_primitives::LP_DEFENSIVE_WEIGHT)
+	}
+
+	fn set_outbound_batching() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one write for sure and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn set_domain_pause() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one write for sure and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn set_emergency_pause() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one write for sure and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn resume_paused_messages(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one reads the pause state and the range, and takes and
+		//       queues each of the `n` held messages.
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(
+				RocksDbWeight::get()
+					.reads_writes(2, 2)
+					.saturating_mul(n.into()),
+			)
+	}
+
+	fn set_inbound_threshold() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one write for sure and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn register_relayer() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one reads and writes the relayer and the reserved balance
+		//       of the caller
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn unregister_relayer() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one reads and writes the relayer and the reserved balance
+		//       of the caller
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	fn set_relayer_fee() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one write for sure and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn set_domain_message_version() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one has one write for sure and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn quarantine_message() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one reads and writes the quarantine and possible one read
+		//       for `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn release_quarantined_message(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one takes the quarantine and queues each of the `n` held
+		//       messages.
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+			.saturating_add(
+				RocksDbWeight::get()
+					.reads_writes(1, 2)
+					.saturating_mul(n.into()),
+			)
+	}
+
+	fn discard_quarantined_message() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one takes the quarantine and possible one read for
+		//       `AdminOrigin`
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	fn export_domain_state(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one reads and possibly removes every storage of the
+		//       domain, including up to `n` allowlisted instances,
+		//       sequenced and paused messages.
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(11))
+			.saturating_add(
+				RocksDbWeight::get()
+					.reads_writes(1, 1)
+					.saturating_mul(n.into()),
+			)
+			.saturating_add(Weight::from_parts(0, 17774).saturating_mul(n.into()))
+	}
+
+	fn import_domain_state(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `PoolSystem::set_max_reserve`
+		//       This one checks every storage of the domain is empty and
+		//       writes an entry for at most every 32 bytes of the `n` bytes
+		//       state.
+		Weight::from_parts(30_117_000, 5991)
+			.saturating_add(RocksDbWeight::get().reads(12))
+			.saturating_add(RocksDbWeight::get().writes(11))
+			.saturating_add(
+				RocksDbWeight::get()
+					.writes(1)
+					.saturating_mul((n / 32).into()),
+			)
+	}
 }
@@ -28,6 +28,12 @@ pub trait WeightInfo {
 	fn freeze_investor() -> Weight;
 	fn unfreeze_investor() -> Weight;
 	fn update_tranche_hook() -> Weight;
+	fn set_domain_transfer_ttl() -> Weight;
+	fn expire_transfer() -> Weight;
+	fn refund_transfer() -> Weight;
+	fn set_tranche_metadata_uri() -> Weight;
+	fn broadcast_message() -> Weight;
+	fn sync_investor_position() -> Weight;
 }
 
 // NOTE: We use temporary weights here. `execute_epoch` is by far our heaviest
@@ -116,4 +122,52 @@ impl WeightInfo for () {
 		// Writes: MessageNonceStore, MessageQueue
 		RocksDbWeight::get().reads_writes(3, 2)
 	}
+
+	fn set_domain_transfer_ttl() -> Weight {
+		// Writes: DomainTransferTtl
+		RocksDbWeight::get().writes(1)
+	}
+
+	fn expire_transfer() -> Weight {
+		// Reads: OutboundTransfers, Timestamp
+		// Writes: OutboundTransfers
+		RocksDbWeight::get().reads_writes(2, 1)
+	}
+
+	fn refund_transfer() -> Weight {
+		// Reads: OutboundTransfers, 2x Accounts
+		// Writes: OutboundTransfers, 2x Accounts
+		RocksDbWeight::get().reads_writes(3, 3)
+	}
+
+	fn set_tranche_metadata_uri() -> Weight {
+		// Reads: Permissions, TrancheDeployments, 2x AssetRegistry
+		// Writes: TrancheMetadataUris, MessageNonceStore and MessageQueue per
+		// domain
+		RocksDbWeight::get().reads_writes(4, 1).saturating_add(
+			RocksDbWeight::get()
+				.writes(2)
+				.saturating_mul(crate::MAX_TRANCHE_DOMAINS.into()),
+		)
+	}
+
+	fn broadcast_message() -> Weight {
+		// Reads: Permissions, 2x AssetRegistry, NextBroadcastId,
+		// TrancheDeployments per tranche
+		// Writes: NextBroadcastId and, per domain, BroadcastDeliveries,
+		// MessageNonceStore and MessageQueue
+		default_defensive_weight().saturating_add(
+			RocksDbWeight::get()
+				.reads_writes(1, 3)
+				.saturating_mul(crate::MAX_TRANCHE_DOMAINS.into()),
+		)
+	}
+
+	fn sync_investor_position() -> Weight {
+		// Reads: Pool, 2x AssetRegistry, LastPositionSnapshots, Timestamp,
+		// ForeignInvestmentInfo, ForeignRedemptionInfo, OrderBook, Investments,
+		// Accounts
+		// Writes: LastPositionSnapshots, MessageNonceStore and MessageQueue
+		RocksDbWeight::get().reads_writes(10, 3)
+	}
 }
@@ -41,36 +41,46 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 use core::convert::TryFrom;
 
-use cfg_traits::{liquidity_pools::OutboundMessageHandler, swaps::TokenSwaps, PreConditions};
+use cfg_traits::{
+	liquidity_pools::{
+		LpMessageBatch, LpMessageHash, MessageHash, OutboundMessageHandler, OutboundMessageTracker,
+	},
+	swaps::TokenSwaps,
+	PreConditions, Seconds,
+};
 use cfg_types::{
 	domain_address::{Domain, DomainAddress},
 	tokens::GeneralCurrencyIndex,
 };
 use cfg_utils::vec_to_fixed_array;
 use frame_support::{
+	pallet_prelude::{ConstU32, RuntimeDebug},
+	storage::with_storage_layer,
 	traits::{
 		fungibles::{Inspect, Mutate},
 		PalletInfo,
 	},
-	transactional,
+	transactional, BoundedVec,
 };
 use orml_traits::{
 	asset_registry::{self, Inspect as _},
 	GetByKey,
 };
 pub use pallet::*;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::{crypto::AccountId32, H160};
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, EnsureMul},
 	FixedPointNumber, SaturatedConversion,
 };
-use sp_std::{convert::TryInto, vec};
+use sp_std::{boxed::Box, convert::TryInto, vec, vec::Vec};
 use staging_xcm::{
 	v4::{Junction::*, NetworkId},
 	VersionedLocation,
 };
 
-use crate::message::UpdateRestrictionMessage;
+use crate::message::TOKEN_URI_SIZE;
 
 // NOTE: Should be replaced with generated weights in the future. For now, let's
 // be defensive.
@@ -89,7 +99,7 @@ mod gmpf {
 }
 
 mod message;
-pub use message::Message;
+pub use message::{Message, UpdateRestrictionMessage};
 
 pub mod hooks;
 mod inbound;
@@ -105,11 +115,74 @@ pub type GeneralCurrencyIndexType = u128;
 pub type GeneralCurrencyIndexOf<T> =
 	GeneralCurrencyIndex<GeneralCurrencyIndexType, <T as pallet::Config>::GeneralCurrencyPrefix>;
 
+pub type OutboundTransferOf<T> = OutboundTransfer<
+	<T as frame_system::Config>::AccountId,
+	<T as pallet::Config>::CurrencyId,
+	<T as pallet::Config>::Balance,
+>;
+
+/// Status of a tracked outbound transfer.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TransferStatus {
+	/// The transfer message was not sent yet.
+	Pending,
+	/// The transfer message was not sent within the time-to-live of the
+	/// destination domain and can no longer be sent.
+	Expired,
+	/// The funds of the expired transfer were restored to the sender.
+	Refunded,
+}
+
+/// An outbound transfer to a domain with a time-to-live, tracked until its
+/// message is sent.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct OutboundTransfer<AccountId, CurrencyId, Balance> {
+	/// The account whose funds were burned or escrowed.
+	pub sender: AccountId,
+	/// The destination domain.
+	pub domain: Domain,
+	/// The transferred currency.
+	pub currency_id: CurrencyId,
+	/// The transferred amount.
+	pub amount: Balance,
+	/// The moment from which the transfer can be expired.
+	pub expires_at: Seconds,
+	/// The status of the transfer.
+	pub status: TransferStatus,
+}
+
+/// Delivery status of a message broadcast to a domain.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum BroadcastStatus {
+	/// The message was queued in the gateway and is not sent yet.
+	Queued,
+	/// The message was sent through a router of the domain.
+	Sent,
+	/// The message could not be queued for the domain.
+	Failed,
+}
+
+/// Maximum number of domains a tranche token can be added to.
+pub const MAX_TRANCHE_DOMAINS: u32 = 16;
+
+/// The URI of the metadata of a tranche token, such as its icon.
+pub type TrancheMetadataUri = BoundedVec<u8, ConstU32<{ TOKEN_URI_SIZE as u32 }>>;
+
+/// The domains a tranche token was added to.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct TrancheDeployment {
+	/// The decimals the tranche token was added with, which can not change.
+	pub decimals: u8,
+	/// The domains the tranche token was added to.
+	pub domains: BoundedVec<Domain, ConstU32<MAX_TRANCHE_DOMAINS>>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use cfg_traits::{
-		investments::ForeignInvestment, liquidity_pools::InboundMessageHandler, CurrencyInspect,
-		Permissions, PoolInspect, Seconds, TimeAsSecs, TrancheTokenPrice,
+		investments::ForeignInvestment,
+		liquidity_pools::{InboundMessageHandler, PriceUpdateHandler},
+		CurrencyInspect, Permissions, PoolInspect, TimeAsSecs, TrancheTokenPrice,
 	};
 	use cfg_types::{
 		permissions::{PermissionScope, PoolRole, Role},
@@ -123,7 +196,10 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use parity_scale_codec::HasCompact;
 	use sp_core::U256;
-	use sp_runtime::{traits::Zero, DispatchError};
+	use sp_runtime::{
+		traits::{EnsureAdd, Zero},
+		DispatchError, Saturating,
+	};
 
 	use super::*;
 	use crate::defensive_weights::WeightInfo;
@@ -274,6 +350,15 @@ pub mod pallet {
 			Ratio = Self::BalanceRatio,
 		>;
 
+		/// The minimum time between two position snapshots sent to an
+		/// investor on a domain.
+		#[pallet::constant]
+		type PositionSnapshotInterval: Get<Seconds>;
+
+		/// The type for handling prices pushed by oracles of other domains
+		/// through [`Message::UpdatePrice`].
+		type PriceUpdateHandler: PriceUpdateHandler<DomainAddress>;
+
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 	}
 
@@ -287,8 +372,140 @@ pub mod pallet {
 			sender: DomainAddress,
 			message: Message,
 		},
+
+		/// The time-to-live of the outbound transfers to a domain was set.
+		DomainTransferTtlSet {
+			domain: Domain,
+			ttl: Option<Seconds>,
+		},
+
+		/// An outbound transfer is tracked until its message is sent.
+		TransferTracked {
+			hash: MessageHash,
+			transfer_id: u64,
+			expires_at: Seconds,
+		},
+
+		/// The message of a tracked outbound transfer was sent.
+		TransferSent { hash: MessageHash, transfer_id: u64 },
+
+		/// A tracked outbound transfer expired before its message was sent.
+		TransferExpired { hash: MessageHash, transfer_id: u64 },
+
+		/// The funds of an expired outbound transfer were restored.
+		TransferRefunded {
+			hash: MessageHash,
+			transfer_id: u64,
+			sender: T::AccountId,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+		},
+
+		/// The metadata URI of a tranche token was set.
+		TrancheMetadataUriSet {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			metadata_uri: Option<TrancheMetadataUri>,
+		},
+
+		/// A message was broadcast to all the domains a pool is deployed to.
+		MessageBroadcast {
+			broadcast_id: u64,
+			pool_id: T::PoolId,
+			hash: MessageHash,
+			domains: Vec<Domain>,
+		},
+
+		/// A broadcast message could not be queued for a domain.
+		BroadcastFailed {
+			broadcast_id: u64,
+			domain: Domain,
+			error: DispatchError,
+		},
+
+		/// A broadcast message was sent to a domain.
+		BroadcastSent { broadcast_id: u64, domain: Domain },
+
+		/// A broadcast message was sent to, or failed for, all its domains and
+		/// is no longer tracked.
+		BroadcastCompleted { broadcast_id: u64 },
+
+		/// A snapshot of the position of an investor was sent to its domain.
+		InvestorPositionSent {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			investor: DomainAddress,
+			computed_at: Seconds,
+		},
 	}
 
+	/// Time-to-live of the outbound transfers to a domain.
+	///
+	/// Transfers whose message was not sent within it can be expired and
+	/// their funds restored.
+	#[pallet::storage]
+	pub type DomainTransferTtl<T: Config> = StorageMap<_, Blake2_128Concat, Domain, Seconds>;
+
+	/// Identifier of the next tracked outbound transfer.
+	#[pallet::storage]
+	pub(crate) type NextTransferId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Outbound transfers to domains with a time-to-live, by the hash of their
+	/// message.
+	#[pallet::storage]
+	pub type OutboundTransfers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MessageHash,
+		Twox64Concat,
+		u64,
+		OutboundTransferOf<T>,
+	>;
+
+	/// Metadata URI of a tranche token, sent along with its name and symbol.
+	#[pallet::storage]
+	pub type TrancheMetadataUris<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::TrancheId,
+		TrancheMetadataUri,
+	>;
+
+	/// Domains a tranche token was added to, where its metadata updates are
+	/// propagated.
+	#[pallet::storage]
+	pub type TrancheDeployments<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::TrancheId,
+		TrancheDeployment,
+	>;
+
+	/// Identifier of the next broadcast message.
+	#[pallet::storage]
+	pub(crate) type NextBroadcastId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+	/// Delivery status of each domain of the messages broadcast to the domains
+	/// of a pool, by the hash of their message and their broadcast identifier.
+	#[pallet::storage]
+	pub type BroadcastDeliveries<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MessageHash,
+		Blake2_128Concat,
+		(u64, Domain),
+		BroadcastStatus,
+	>;
+
+	/// Time of the last position snapshot sent to an investor on a domain.
+	#[pallet::storage]
+	pub type LastPositionSnapshots<T: Config> =
+		StorageMap<_, Blake2_128Concat, DomainAddress, Seconds>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Failed to map the asset to the corresponding LiquidityPools' General
@@ -344,6 +561,27 @@ pub mod pallet {
 		/// This pallet does not expect to receive direclty a batch message,
 		/// instead it expects several calls to it with different messages.
 		UnsupportedBatchMessage,
+		/// The outbound transfer is not tracked.
+		TransferNotFound,
+		/// The outbound transfer is not pending or its time-to-live has not
+		/// passed yet.
+		TransferNotExpirable,
+		/// The outbound transfer has not expired.
+		TransferNotExpired,
+		/// The message of an expired outbound transfer can not be sent.
+		TransferAlreadyExpired,
+		/// The decimals of the tranche token differ from the ones it was added
+		/// with.
+		TrancheDecimalsMismatch,
+		/// The tranche token was added to the maximum number of domains.
+		TooManyTrancheDomains,
+		/// None of the tranche tokens of the pool was added to a domain.
+		PoolNotDeployed,
+		/// The message can not be broadcast for the pool.
+		MessageNotBroadcastable,
+		/// A position snapshot was sent to the investor within the
+		/// `PositionSnapshotInterval`.
+		PositionSnapshotThrottled,
 	}
 
 	#[pallet::call]
@@ -412,6 +650,9 @@ pub mod pallet {
 				.ok_or(Error::<T>::TrancheMetadataNotFound)?;
 			let token_name = vec_to_fixed_array(metadata.name);
 			let token_symbol = vec_to_fixed_array(metadata.symbol);
+			let decimals = metadata.decimals.saturated_into();
+
+			Self::record_tranche_deployment(pool_id, tranche_id, domain, decimals)?;
 
 			// Determine hook from EVM chain id and 20 byte hook stored in Gateway
 			let hook_bytes = T::OutboundMessageHandler::get(&domain)
@@ -428,10 +669,11 @@ pub mod pallet {
 				Message::AddTranche {
 					pool_id: pool_id.into(),
 					tranche_id: tranche_id.into(),
-					decimals: metadata.decimals.saturated_into(),
+					decimals,
 					token_name,
 					token_symbol,
 					hook: DomainAddress::Evm(evm_chain_id, hook_bytes.into()).bytes(),
+					metadata_uri: Self::tranche_metadata_uri(pool_id, tranche_id),
 				},
 			)?;
 
@@ -575,19 +817,27 @@ pub mod pallet {
 				Preservation::Expendable,
 			)?;
 
+			let message = Message::TransferTrancheTokens {
+				pool_id: pool_id.into(),
+				tranche_id: tranche_id.into(),
+				amount: amount.into(),
+				domain: domain_address.domain().into(),
+				receiver: domain_address.bytes(),
+			};
+
 			T::OutboundMessageHandler::handle(
 				who.clone(),
 				domain_address.domain(),
-				Message::TransferTrancheTokens {
-					pool_id: pool_id.into(),
-					tranche_id: tranche_id.into(),
-					amount: amount.into(),
-					domain: domain_address.domain().into(),
-					receiver: domain_address.bytes(),
-				},
+				message.clone(),
 			)?;
 
-			Ok(())
+			Self::track_transfer(
+				who,
+				domain_address.domain(),
+				invest_id.into(),
+				amount,
+				&message,
+			)
 		}
 
 		/// Transfer non-tranche tokens to a given address.
@@ -648,17 +898,15 @@ pub mod pallet {
 				Fortitude::Polite,
 			)?;
 
-			T::OutboundMessageHandler::handle(
-				who.clone(),
-				receiver.domain(),
-				Message::TransferAssets {
-					amount: amount.into(),
-					currency,
-					receiver: receiver.bytes(),
-				},
-			)?;
+			let message = Message::TransferAssets {
+				amount: amount.into(),
+				currency,
+				receiver: receiver.bytes(),
+			};
 
-			Ok(())
+			T::OutboundMessageHandler::handle(who.clone(), receiver.domain(), message.clone())?;
+
+			Self::track_transfer(who, receiver.domain(), currency_id, amount, &message)
 		}
 
 		/// Add a currency to the set of known currencies on the domain derived
@@ -763,7 +1011,8 @@ pub mod pallet {
 			)
 		}
 
-		/// Update the tranche token name and symbol on the specified domain
+		/// Update the tranche token name, symbol and metadata URI on the
+		/// specified domain
 		///
 		/// NOTE: Pulls the metadata from the `AssetRegistry` and thus requires
 		/// the pool admin to have updated the tranche tokens metadata there
@@ -778,22 +1027,9 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin.clone())?;
 
-			let investment_id = Self::derive_invest_id(pool_id, tranche_id)?;
-			let metadata = T::AssetRegistry::metadata(&investment_id.into())
-				.ok_or(Error::<T>::TrancheMetadataNotFound)?;
-			let token_name = vec_to_fixed_array(metadata.name);
-			let token_symbol = vec_to_fixed_array(metadata.symbol);
+			let message = Self::tranche_metadata_message(pool_id, tranche_id)?;
 
-			T::OutboundMessageHandler::handle(
-				who,
-				domain,
-				Message::UpdateTrancheMetadata {
-					pool_id: pool_id.into(),
-					tranche_id: tranche_id.into(),
-					token_name,
-					token_symbol,
-				},
-			)
+			T::OutboundMessageHandler::handle(who, domain, message)
 		}
 
 		/// Disallow a currency to be used as a pool currency and to invest in a
@@ -1033,9 +1269,485 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set the time-to-live of the outbound transfers to a domain.
+		///
+		/// Origin: Root.
+		#[pallet::call_index(18)]
+		#[pallet::weight(T::WeightInfo::set_domain_transfer_ttl())]
+		pub fn set_domain_transfer_ttl(
+			origin: OriginFor<T>,
+			domain: Domain,
+			ttl: Option<Seconds>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(matches!(domain, Domain::Evm(_)), Error::<T>::InvalidDomain);
+
+			DomainTransferTtl::<T>::set(domain, ttl);
+
+			Self::deposit_event(Event::<T>::DomainTransferTtlSet { domain, ttl });
+
+			Ok(())
+		}
+
+		/// Expire an outbound transfer whose message was not sent within the
+		/// time-to-live of its domain. The message can no longer be sent
+		/// afterwards.
+		///
+		/// Origin: Anyone.
+		#[pallet::call_index(19)]
+		#[pallet::weight(T::WeightInfo::expire_transfer())]
+		pub fn expire_transfer(
+			origin: OriginFor<T>,
+			hash: MessageHash,
+			transfer_id: u64,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			OutboundTransfers::<T>::try_mutate(hash, transfer_id, |transfer| {
+				let transfer = transfer.as_mut().ok_or(Error::<T>::TransferNotFound)?;
+
+				ensure!(
+					transfer.status == TransferStatus::Pending
+						&& T::Time::now() >= transfer.expires_at,
+					Error::<T>::TransferNotExpirable
+				);
+
+				transfer.status = TransferStatus::Expired;
+
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::<T>::TransferExpired { hash, transfer_id });
+
+			Ok(())
+		}
+
+		/// Restore the funds of an expired outbound transfer to its sender.
+		///
+		/// Origin: Root.
+		#[pallet::call_index(20)]
+		#[pallet::weight(T::WeightInfo::refund_transfer())]
+		pub fn refund_transfer(
+			origin: OriginFor<T>,
+			hash: MessageHash,
+			transfer_id: u64,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			let transfer = OutboundTransfers::<T>::try_mutate(hash, transfer_id, |transfer| {
+				let transfer = transfer.as_mut().ok_or(Error::<T>::TransferNotFound)?;
+
+				ensure!(
+					transfer.status == TransferStatus::Expired,
+					Error::<T>::TransferNotExpired
+				);
+
+				transfer.status = TransferStatus::Refunded;
+
+				Ok::<_, DispatchError>(transfer.clone())
+			})?;
+
+			if T::CurrencyId::is_tranche_token(transfer.currency_id) {
+				// Tranche tokens are escrowed in the domain account
+				T::Tokens::transfer(
+					transfer.currency_id,
+					&transfer.domain.into_account(),
+					&transfer.sender,
+					transfer.amount,
+					Preservation::Expendable,
+				)?;
+			} else {
+				// Other tokens are burned
+				T::Tokens::mint_into(transfer.currency_id, &transfer.sender, transfer.amount)?;
+			}
+
+			Self::deposit_event(Event::<T>::TransferRefunded {
+				hash,
+				transfer_id,
+				sender: transfer.sender,
+				currency_id: transfer.currency_id,
+				amount: transfer.amount,
+			});
+
+			Ok(())
+		}
+
+		/// Set the metadata URI of a tranche token and propagate it to all
+		/// the domains the tranche token was added to.
+		///
+		/// Origin: Pool admin
+		#[pallet::weight(T::WeightInfo::set_tranche_metadata_uri())]
+		#[pallet::call_index(21)]
+		pub fn set_tranche_metadata_uri(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			metadata_uri: Option<TrancheMetadataUri>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who.clone(),
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				Error::<T>::NotPoolAdmin
+			);
+
+			TrancheMetadataUris::<T>::set(pool_id, tranche_id, metadata_uri.clone());
+
+			let domains = TrancheDeployments::<T>::get(pool_id, tranche_id)
+				.map(|deployment| deployment.domains)
+				.unwrap_or_default();
+
+			if !domains.is_empty() {
+				let message = Self::tranche_metadata_message(pool_id, tranche_id)?;
+
+				for domain in domains {
+					T::OutboundMessageHandler::handle(who.clone(), domain, message.clone())?;
+				}
+			}
+
+			Self::deposit_event(Event::<T>::TrancheMetadataUriSet {
+				pool_id,
+				tranche_id,
+				metadata_uri,
+			});
+
+			Ok(())
+		}
+
+		/// Broadcast a message to all the domains the tranche tokens of a
+		/// pool were added to, tracking its delivery to each domain.
+		///
+		/// Only messages the pool admin could send to each domain separately
+		/// can be broadcast: `AddPool` and `UpdateTrancheMetadata` of the
+		/// given pool, the latter matching the `AssetRegistry`.
+		///
+		/// Origin: Pool admin
+		#[pallet::weight(T::WeightInfo::broadcast_message())]
+		#[pallet::call_index(22)]
+		pub fn broadcast_message(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			message: Box<Message>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who.clone(),
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				Error::<T>::NotPoolAdmin
+			);
+
+			Self::ensure_broadcastable(pool_id, &message)?;
+			Self::broadcast(who, pool_id, *message)?;
+
+			Ok(())
+		}
+
+		/// Send a snapshot of the full position of an investor in a tranche
+		/// to its domain, allowing the domain to restore its state if it
+		/// missed any message.
+		///
+		/// A snapshot can be sent to each investor on a domain once per
+		/// `PositionSnapshotInterval`.
+		///
+		/// Origin: Signed
+		#[pallet::weight(T::WeightInfo::sync_investor_position())]
+		#[pallet::call_index(23)]
+		pub fn sync_investor_position(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			investor: DomainAddress,
+			currency_id: T::CurrencyId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				investor.domain() != Domain::Centrifuge,
+				Error::<T>::InvalidDomain
+			);
+
+			let invest_id = Self::derive_invest_id(pool_id, tranche_id)?;
+			let currency = Self::try_get_general_index(currency_id)?;
+
+			let now = T::Time::now();
+			LastPositionSnapshots::<T>::try_mutate(&investor, |last| {
+				ensure!(
+					last.map_or(true, |last| now
+						>= last.saturating_add(T::PositionSnapshotInterval::get())),
+					Error::<T>::PositionSnapshotThrottled
+				);
+				*last = Some(now);
+
+				Ok::<_, DispatchError>(())
+			})?;
+
+			let account = investor.account();
+			let position = T::ForeignInvestment::foreign_position(&account, invest_id)?;
+
+			T::OutboundMessageHandler::handle(
+				who,
+				investor.domain(),
+				Message::InvestorPosition {
+					pool_id: pool_id.into(),
+					tranche_id: tranche_id.into(),
+					investor: account.clone().into(),
+					currency,
+					pending_deposit: position.pending_investment.into(),
+					pending_redeem: position.pending_redemption.into(),
+					claimable_redeem: position.claimable_redemption.into(),
+					held_tranche_tokens: T::Tokens::balance(invest_id.into(), &account).into(),
+					computed_at: now,
+				},
+			)?;
+
+			Self::deposit_event(Event::<T>::InvestorPositionSent {
+				pool_id,
+				tranche_id,
+				investor,
+				computed_at: now,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Sends a message to all the domains the tranche tokens of a pool
+		/// were added to, returning the identifier of the broadcast.
+		///
+		/// A domain failing to queue the message does not prevent sending it
+		/// to the others. The delivery to each domain is tracked in
+		/// `BroadcastDeliveries` until the message was sent to all of them.
+		pub fn broadcast(
+			sender: T::AccountId,
+			pool_id: T::PoolId,
+			message: Message,
+		) -> Result<u64, DispatchError> {
+			let domains = Self::pool_domains(pool_id);
+			ensure!(!domains.is_empty(), Error::<T>::PoolNotDeployed);
+
+			let hash = message.get_message_hash();
+			let broadcast_id = NextBroadcastId::<T>::mutate(|next_id| {
+				let broadcast_id = *next_id;
+				next_id.saturating_inc();
+				broadcast_id
+			});
+
+			Self::deposit_event(Event::<T>::MessageBroadcast {
+				broadcast_id,
+				pool_id,
+				hash,
+				domains: domains.clone(),
+			});
+
+			for domain in domains {
+				let status = match with_storage_layer(|| {
+					T::OutboundMessageHandler::handle(sender.clone(), domain, message.clone())
+				}) {
+					Ok(()) => BroadcastStatus::Queued,
+					Err(error) => {
+						Self::deposit_event(Event::<T>::BroadcastFailed {
+							broadcast_id,
+							domain,
+							error,
+						});
+						BroadcastStatus::Failed
+					}
+				};
+
+				BroadcastDeliveries::<T>::insert(hash, (broadcast_id, domain), status);
+			}
+
+			Self::try_complete_broadcast(hash, broadcast_id);
+
+			Ok(broadcast_id)
+		}
+
+		/// Returns the delivery status of a broadcast message to each domain,
+		/// empty if it is no longer tracked.
+		pub fn broadcast_status(
+			hash: MessageHash,
+			broadcast_id: u64,
+		) -> Vec<(Domain, BroadcastStatus)> {
+			BroadcastDeliveries::<T>::iter_prefix(hash)
+				.filter(|((id, _), _)| *id == broadcast_id)
+				.map(|((_, domain), status)| (domain, status))
+				.collect()
+		}
+
+		/// The domains any tranche token of a pool was added to.
+		fn pool_domains(pool_id: T::PoolId) -> Vec<Domain> {
+			let mut domains = Vec::new();
+			for (_, deployment) in TrancheDeployments::<T>::iter_prefix(pool_id) {
+				for domain in deployment.domains {
+					if !domains.contains(&domain) {
+						domains.push(domain);
+					}
+				}
+			}
+			domains
+		}
+
+		/// Ensures a message can be broadcast by the admin of a pool.
+		fn ensure_broadcastable(pool_id: T::PoolId, message: &Message) -> DispatchResult {
+			let broadcastable = match message {
+				Message::AddPool { pool_id: id } => *id == pool_id.into(),
+				Message::UpdateTrancheMetadata {
+					pool_id: id,
+					tranche_id,
+					..
+				} => {
+					*id == pool_id.into()
+						&& *message
+							== Self::tranche_metadata_message(pool_id, (*tranche_id).into())?
+				}
+				_ => false,
+			};
+
+			ensure!(broadcastable, Error::<T>::MessageNotBroadcastable);
+
+			Ok(())
+		}
+
+		/// Stops tracking a broadcast once no domain waits for its message.
+		fn try_complete_broadcast(hash: MessageHash, broadcast_id: u64) {
+			let statuses = Self::broadcast_status(hash, broadcast_id);
+
+			if statuses
+				.iter()
+				.all(|(_, status)| *status != BroadcastStatus::Queued)
+			{
+				for (domain, _) in statuses {
+					BroadcastDeliveries::<T>::remove(hash, (broadcast_id, domain));
+				}
+
+				Self::deposit_event(Event::<T>::BroadcastCompleted { broadcast_id });
+			}
+		}
+
+		/// Records the domain a tranche token is added to, ensuring its
+		/// decimals did not change since it was first added.
+		fn record_tranche_deployment(
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			domain: Domain,
+			decimals: u8,
+		) -> DispatchResult {
+			TrancheDeployments::<T>::try_mutate(pool_id, tranche_id, |deployment| {
+				let deployment = deployment.get_or_insert_with(|| TrancheDeployment {
+					decimals,
+					domains: BoundedVec::default(),
+				});
+
+				ensure!(
+					deployment.decimals == decimals,
+					Error::<T>::TrancheDecimalsMismatch
+				);
+
+				if !deployment.domains.contains(&domain) {
+					deployment
+						.domains
+						.try_push(domain)
+						.map_err(|_| Error::<T>::TooManyTrancheDomains)?;
+				}
+
+				Ok(())
+			})
+		}
+
+		/// Returns the metadata URI of a tranche token, zeroed if not set.
+		fn tranche_metadata_uri(
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+		) -> [u8; TOKEN_URI_SIZE] {
+			vec_to_fixed_array(
+				TrancheMetadataUris::<T>::get(pool_id, tranche_id)
+					.unwrap_or_default()
+					.into_inner(),
+			)
+		}
+
+		/// Builds the message updating the metadata of a tranche token from
+		/// the `AssetRegistry`, ensuring its decimals did not change since it
+		/// was added.
+		fn tranche_metadata_message(
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+		) -> Result<Message, DispatchError> {
+			let investment_id = Self::derive_invest_id(pool_id, tranche_id)?;
+			let metadata = T::AssetRegistry::metadata(&investment_id.into())
+				.ok_or(Error::<T>::TrancheMetadataNotFound)?;
+			let decimals = metadata.decimals.saturated_into();
+
+			if let Some(deployment) = TrancheDeployments::<T>::get(pool_id, tranche_id) {
+				ensure!(
+					deployment.decimals == decimals,
+					Error::<T>::TrancheDecimalsMismatch
+				);
+			}
+
+			Ok(Message::UpdateTrancheMetadata {
+				pool_id: pool_id.into(),
+				tranche_id: tranche_id.into(),
+				token_name: vec_to_fixed_array(metadata.name),
+				token_symbol: vec_to_fixed_array(metadata.symbol),
+				decimals,
+				metadata_uri: Self::tranche_metadata_uri(pool_id, tranche_id),
+			})
+		}
+
+		/// Tracks an outbound transfer if its domain has a time-to-live.
+		fn track_transfer(
+			sender: T::AccountId,
+			domain: Domain,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			message: &Message,
+		) -> DispatchResult {
+			let Some(ttl) = DomainTransferTtl::<T>::get(domain) else {
+				return Ok(());
+			};
+
+			let hash = message.get_message_hash();
+			let expires_at = T::Time::now().ensure_add(ttl)?;
+			let transfer_id = NextTransferId::<T>::mutate(|next_id| {
+				let transfer_id = *next_id;
+				next_id.saturating_inc();
+				transfer_id
+			});
+
+			OutboundTransfers::<T>::insert(
+				hash,
+				transfer_id,
+				OutboundTransfer {
+					sender,
+					domain,
+					currency_id,
+					amount,
+					expires_at,
+					status: TransferStatus::Pending,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::TransferTracked {
+				hash,
+				transfer_id,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
 		/// Returns the `u128` general index of a currency as the concatenation
 		/// of the configured `GeneralCurrencyPrefix` and its local currency
 		/// identifier.
@@ -1287,6 +1999,12 @@ pub mod pallet {
 					currency.into(),
 					sender,
 				),
+				Message::UpdatePrice {
+					feed,
+					price,
+					decimals,
+					computed_at,
+				} => T::PriceUpdateHandler::handle_price(sender, feed, price, decimals, computed_at),
 				Message::Batch(_) => Err(Error::<T>::UnsupportedBatchMessage.into()),
 				_ => Err(Error::<T>::InvalidIncomingMessage.into()),
 			}?;
@@ -1294,4 +2012,61 @@ pub mod pallet {
 			Ok(())
 		}
 	}
+
+	impl<T: Config> OutboundMessageTracker<Domain, Message> for Pallet<T> {
+		/// Removes the oldest pending transfer of each sent message and marks
+		/// the oldest broadcast queued for the domain as sent. Messages of
+		/// expired transfers can not be sent.
+		fn on_send(destination: Domain, msg: &Message) -> DispatchResult {
+			for submessage in msg.submessages() {
+				let hash = submessage.get_message_hash();
+
+				let mut pending = None;
+				let mut expired = false;
+				for (transfer_id, transfer) in OutboundTransfers::<T>::iter_prefix(hash) {
+					if transfer.domain != destination {
+						continue;
+					}
+
+					match transfer.status {
+						TransferStatus::Pending => {
+							pending =
+								Some(pending.map_or(transfer_id, |id: u64| id.min(transfer_id)))
+						}
+						TransferStatus::Expired | TransferStatus::Refunded => expired = true,
+					}
+				}
+
+				match pending {
+					Some(transfer_id) => {
+						OutboundTransfers::<T>::remove(hash, transfer_id);
+						Self::deposit_event(Event::<T>::TransferSent { hash, transfer_id });
+					}
+					None => ensure!(!expired, Error::<T>::TransferAlreadyExpired),
+				}
+
+				let queued = BroadcastDeliveries::<T>::iter_prefix(hash)
+					.filter(|((_, domain), status)| {
+						*domain == destination && *status == BroadcastStatus::Queued
+					})
+					.map(|((broadcast_id, _), _)| broadcast_id)
+					.min();
+
+				if let Some(broadcast_id) = queued {
+					BroadcastDeliveries::<T>::insert(
+						hash,
+						(broadcast_id, destination),
+						BroadcastStatus::Sent,
+					);
+					Self::deposit_event(Event::<T>::BroadcastSent {
+						broadcast_id,
+						domain: destination,
+					});
+					Self::try_complete_broadcast(hash, broadcast_id);
+				}
+			}
+
+			Ok(())
+		}
+	}
 }
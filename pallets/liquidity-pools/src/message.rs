@@ -8,7 +8,7 @@
 use cfg_traits::{
 	liquidity_pools::{
 		LpMessageBatch, LpMessageForwarded, LpMessageHash, LpMessageProof, LpMessageRecovery,
-		LpMessageSerializer, MessageHash,
+		LpMessageSequence, LpMessageSerializer, MessageHash, MessageVersion,
 	},
 	Seconds,
 };
@@ -42,6 +42,9 @@ pub const TOKEN_NAME_SIZE: usize = 128;
 // The fixed size for the array representing a tranche token symbol
 pub const TOKEN_SYMBOL_SIZE: usize = 32;
 
+/// The fixed size for the array representing a tranche token metadata URI
+pub const TOKEN_URI_SIZE: usize = 128;
+
 // Max amount of messages a batch can have
 const MAX_BATCH_MESSAGES: u32 = 16;
 
@@ -101,8 +104,8 @@ impl MaxEncodedLen for NonBatchMessage {
 		// This message uses a non-recursive message version to obtain the encoded
 		// len to avoid an infinite recursion of messages
 		//
-		// Note: A Batch can NOT contain Forwarded messages
-		Message::<(), ()>::max_encoded_len()
+		// Note: A Batch can NOT contain Forwarded or Sequenced messages
+		Message::<(), (), ()>::max_encoded_len()
 	}
 }
 
@@ -227,7 +230,40 @@ impl MaxEncodedLen for NonForwardMessage {
 		// len to avoid an infinite recursion of messages
 		//
 		// Note: A Batch CAN be inside of a Forwarded message
-		Message::<BatchMessages, ()>::max_encoded_len()
+		Message::<BatchMessages, (), ()>::max_encoded_len()
+	}
+}
+
+/// A message type that cannot be sequenced.
+#[derive(Encode, Decode, Serialize, Deserialize, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct NonSequencedMessage(Box<Message>);
+
+impl TryFrom<Message> for NonSequencedMessage {
+	type Error = DispatchError;
+
+	fn try_from(message: Message) -> Result<Self, DispatchError> {
+		match message {
+			Message::Sequenced { .. } => Err(DispatchError::Other(
+				"The inner sequenced message can not be a sequenced one",
+			)),
+			_ => Ok(Self(message.into())),
+		}
+	}
+}
+
+impl From<NonSequencedMessage> for Message {
+	fn from(value: NonSequencedMessage) -> Self {
+		*value.0
+	}
+}
+
+impl MaxEncodedLen for NonSequencedMessage {
+	fn max_encoded_len() -> usize {
+		// This message uses a non-recursive message version to obtain the encoded
+		// len to avoid an infinite recursion of messages
+		//
+		// Note: A Batch or a Forwarded message CAN be inside of a Sequenced message
+		Message::<BatchMessages, NonForwardMessage, ()>::max_encoded_len()
 	}
 }
 
@@ -245,7 +281,11 @@ impl MaxEncodedLen for NonForwardMessage {
 	MaxEncodedLen,
 	Default,
 )]
-pub enum Message<BatchContent = BatchMessages, ForwardContent = NonForwardMessage> {
+pub enum Message<
+	BatchContent = BatchMessages,
+	ForwardContent = NonForwardMessage,
+	SequenceContent = NonSequencedMessage,
+> {
 	#[default]
 	Invalid,
 	// --- Gateway ---
@@ -352,6 +392,9 @@ pub enum Message<BatchContent = BatchMessages, ForwardContent = NonForwardMessag
 		/// The RestrictionManager implementation to be used for this tranche
 		/// token on the domain it will be added and subsequently deployed in.
 		hook: Address,
+		/// The URI of the token metadata, such as its icon. Zeroed if not set.
+		#[serde(with = "serde_big_array::BigArray")]
+		metadata_uri: [u8; TOKEN_URI_SIZE],
 	},
 	/// Allow a currency to be used as a pool currency and to invest in a pool.
 	///
@@ -373,10 +416,11 @@ pub enum Message<BatchContent = BatchMessages, ForwardContent = NonForwardMessag
 		/// The timestamp at which the price was computed
 		computed_at: Seconds,
 	},
-	/// Updates the name and symbol of a tranche token.
+	/// Updates the name, symbol and metadata URI of a tranche token.
 	///
 	/// NOTE: We do not allow updating the decimals as this would require
-	/// migrating all associated balances.
+	/// migrating all associated balances. They are only sent to be verified
+	/// against the deployed token.
 	///
 	/// Directionality: Centrifuge -> EVM Domain.
 	UpdateTrancheMetadata {
@@ -385,6 +429,10 @@ pub enum Message<BatchContent = BatchMessages, ForwardContent = NonForwardMessag
 		#[serde(with = "serde_big_array::BigArray")]
 		token_name: [u8; TOKEN_NAME_SIZE],
 		token_symbol: [u8; TOKEN_SYMBOL_SIZE],
+		decimals: u8,
+		/// The URI of the token metadata, such as its icon. Zeroed if not set.
+		#[serde(with = "serde_big_array::BigArray")]
+		metadata_uri: [u8; TOKEN_URI_SIZE],
 	},
 	UpdateTrancheHook {
 		pool_id: u64,
@@ -575,12 +623,65 @@ pub enum Message<BatchContent = BatchMessages, ForwardContent = NonForwardMessag
 	/// Directionality: Centrifuge <-> EVM Domain.
 	Forwarded {
 		source_domain: SerializableDomain,
+		#[serde(with = "h160_bytes")]
 		forwarding_contract: H160,
 		message: ForwardContent,
 	},
+	/// A wrapped Message carrying the sequence number assigned to it by the
+	/// source domain. Sequenced messages are executed in order per domain.
+	///
+	/// Directionality: EVM Domain -> Centrifuge.
+	Sequenced {
+		nonce: u64,
+		message: SequenceContent,
+	},
+	/// A snapshot of the full position of an investor, allowing the domain
+	/// to restore its state if it diverged, e.g., after missing any of the
+	/// `Fulfilled*` messages.
+	///
+	/// Directionality: Centrifuge -> EVM Domain.
+	InvestorPosition {
+		/// The pool id
+		pool_id: u64,
+		/// The tranche id
+		tranche_id: TrancheId,
+		/// The investor's address
+		investor: Address,
+		/// The currency in which the investor invests and redeems
+		currency: u128,
+		/// The amount of `currency` invested and not yet collected
+		pending_deposit: u128,
+		/// The amount of tranche tokens pending to be redeemed
+		pending_redeem: u128,
+		/// The amount of `currency` already swapped from redemptions which
+		/// is pending to be sent to the investor
+		claimable_redeem: u128,
+		/// The amount of tranche tokens held by the investor on Centrifuge
+		held_tranche_tokens: u128,
+		/// The UNIX timestamp in seconds at which the snapshot was taken
+		computed_at: Seconds,
+	},
+	/// The price of an oracle feed of the source domain, e.g., a Chainlink or
+	/// DIA feed.
+	///
+	/// Directionality: EVM Domain -> Centrifuge.
+	UpdatePrice {
+		/// The identifier of the feed in the source domain, e.g., the address
+		/// of its contract
+		feed: [u8; 32],
+		/// The price as an integer with `decimals` decimals
+		price: u128,
+		/// The number of decimals of `price`
+		decimals: u8,
+		/// The UNIX timestamp in seconds at which the price was computed
+		computed_at: Seconds,
+	},
 }
 
 impl LpMessageSerializer for Message {
+	/// Version `1` wraps the legacy layout in the versioned envelope.
+	const LATEST_VERSION: MessageVersion = 1;
+
 	fn serialize(&self) -> Vec<u8> {
 		gmpf::to_vec(self).unwrap_or_default()
 	}
@@ -675,6 +776,36 @@ impl LpMessageForwarded for Message {
 	}
 }
 
+impl LpMessageSequence for Message {
+	fn split_sequence(self) -> (Option<u64>, Self) {
+		match self {
+			Self::Sequenced { nonce, message } => (Some(nonce), message.into()),
+			message => (None, message),
+		}
+	}
+
+	fn try_wrap_sequenced(nonce: u64, message: Self) -> Result<Self, DispatchError> {
+		Ok(Self::Sequenced {
+			nonce,
+			message: message.try_into()?,
+		})
+	}
+}
+
+/// Serializes an `H160` as its raw bytes instead of the hex string used by its
+/// default `serde` implementation, which GMPF does not support.
+mod h160_bytes {
+	use super::*;
+
+	pub fn serialize<S: Serializer>(address: &H160, serializer: S) -> Result<S::Ok, S::Error> {
+		address.0.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<H160, D::Error> {
+		<[u8; 20]>::deserialize(deserializer).map(H160)
+	}
+}
+
 /// A Liquidity Pool message for updating restrictions on foreign domains.
 #[derive(
 	Encode,
@@ -717,6 +848,7 @@ pub enum UpdateRestrictionMessage {
 #[cfg(test)]
 mod tests {
 	use cfg_primitives::{PoolId, TrancheId};
+	use cfg_traits::liquidity_pools::VERSIONED_MESSAGE_MARKER;
 	use cfg_types::fixed_point::Ratio;
 	use cfg_utils::vec_to_fixed_array;
 	use frame_support::assert_err;
@@ -732,7 +864,7 @@ mod tests {
 
 	#[test]
 	fn ensure_non_recursive_max_encoded_len_computation() {
-		Message::<BatchMessages, NonForwardMessage>::max_encoded_len();
+		Message::<BatchMessages, NonForwardMessage, NonSequencedMessage>::max_encoded_len();
 	}
 
 	#[test]
@@ -802,8 +934,9 @@ mod tests {
 				token_symbol: vec_to_fixed_array( b"SYMBOL"),
 				decimals: 15,
 				hook: default_address_32(),
+				metadata_uri: vec_to_fixed_array(b"ipfs://metadata"),
 			},
-			"0b0000000000000001811acd5b3f17c06841c7e41e9e04cb1b536f6d65204e616d65000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000053594d424f4c00000000000000000000000000000000000000000000000000000f4564564564564564564564564564564564564564564564564564564564564564",
+			"0b0000000000000001811acd5b3f17c06841c7e41e9e04cb1b536f6d65204e616d65000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000053594d424f4c00000000000000000000000000000000000000000000000000000f4564564564564564564564564564564564564564564564564564564564564564697066733a2f2f6d657461646174610000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
 		)
 	}
 
@@ -1051,8 +1184,10 @@ mod tests {
 				tranche_id: default_tranche_id(),
 				token_name: vec_to_fixed_array(b"Some Name"),
 				token_symbol: vec_to_fixed_array(b"SYMBOL"),
+				decimals: 15,
+				metadata_uri: vec_to_fixed_array(b"ipfs://metadata"),
 			},
-			"0f0000000000000001811acd5b3f17c06841c7e41e9e04cb1b536f6d65204e616d65000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000053594d424f4c0000000000000000000000000000000000000000000000000000",
+			"0f0000000000000001811acd5b3f17c06841c7e41e9e04cb1b536f6d65204e616d65000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000053594d424f4c00000000000000000000000000000000000000000000000000000f697066733a2f2f6d657461646174610000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
 		)
 	}
 
@@ -1123,6 +1258,136 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn sequenced() {
+		test_encode_decode_identity(
+			Message::Sequenced {
+				nonce: 7,
+				message: Message::AddPool { pool_id: 0 }.try_into().unwrap(),
+			},
+			concat!(
+				"1e",                 // Sequenced index
+				"0000000000000007",   // nonce
+				"0a0000000000000000", // AddPool content
+			),
+		)
+	}
+
+	#[test]
+	fn forwarded() {
+		test_encode_decode_identity(
+			Message::try_wrap_forward(
+				Domain::Evm(1),
+				default_address_20().into(),
+				Message::AddPool { pool_id: 0 },
+			)
+			.unwrap(),
+			concat!(
+				"1d",                                       // Forwarded index
+				"010000000000000001",                       // source_domain
+				"1231231231231231231231231231231231231231", // forwarding_contract
+				"0a0000000000000000",                       // AddPool content
+			),
+		)
+	}
+
+	#[test]
+	fn investor_position() {
+		test_encode_decode_identity(
+			Message::InvestorPosition {
+				pool_id: POOL_ID,
+				tranche_id: default_tranche_id(),
+				investor: vec_to_fixed_array(default_address_20()),
+				currency: TOKEN_ID,
+				pending_deposit: AMOUNT,
+				pending_redeem: AMOUNT / 2,
+				claimable_redeem: AMOUNT / 4,
+				held_tranche_tokens: AMOUNT,
+				computed_at: 1_700_000_000,
+			},
+			concat!(
+				"1f",                                                               /* InvestorPosition index */
+				"0000000000bce1a4",                                                 // pool_id
+				"811acd5b3f17c06841c7e41e9e04cb1b",                                 // tranche_id
+				"1231231231231231231231231231231231231231000000000000000000000000", // investor
+				"0000000000000000000000000eb5ec7b",                                 // currency
+				"000000000052b7d2dcc80cd2e4000000",                                 /* pending_deposit */
+				"0000000000295be96e64066972000000",                                 /* pending_redeem */
+				"000000000014adf4b7320334b9000000",                                 /* claimable_redeem */
+				"000000000052b7d2dcc80cd2e4000000",                                 /* held_tranche_tokens */
+				"000000006553f100",                                                 // computed_at
+			),
+		)
+	}
+
+	#[test]
+	fn update_price() {
+		test_encode_decode_identity(
+			Message::UpdatePrice {
+				feed: vec_to_fixed_array(default_address_20()),
+				price: 2_050_000_000,
+				decimals: 8,
+				computed_at: 1_700_000_000,
+			},
+			concat!(
+				"20",                                                               /* UpdatePrice index */
+				"1231231231231231231231231231231231231231000000000000000000000000", // feed
+				"0000000000000000000000007a308480",                                 // price
+				"08",                                                               // decimals
+				"000000006553f100",                                                 // computed_at
+			),
+		)
+	}
+
+	#[test]
+	fn sequenced_of_sequenced() {
+		let sequenced = Message::try_wrap_sequenced(1, Message::AddPool { pool_id: 0 }).unwrap();
+
+		assert_err!(
+			Message::try_wrap_sequenced(2, sequenced),
+			DispatchError::Other("The inner sequenced message can not be a sequenced one"),
+		);
+	}
+
+	#[test]
+	fn versioned_envelope() {
+		let msg = Message::AddPool { pool_id: POOL_ID };
+
+		assert_eq!(msg.serialize_versioned(0), msg.serialize());
+		assert_eq!(
+			hex::encode(msg.serialize_versioned(1)),
+			"ff010a0000000000bce1a4"
+		);
+		// Versions above the latest one fall back to the latest one
+		assert_eq!(msg.serialize_versioned(9), msg.serialize_versioned(1));
+
+		assert_eq!(
+			Message::deserialize_versioned(&msg.serialize_versioned(1)),
+			Ok((msg.clone(), 1))
+		);
+		assert_eq!(
+			Message::deserialize_versioned(&msg.serialize()),
+			Ok((msg, 0))
+		);
+	}
+
+	#[test]
+	fn unsupported_envelope_versions() {
+		let payload = Message::AddPool { pool_id: POOL_ID }.serialize();
+
+		for version in [0, 2] {
+			let input = [VERSIONED_MESSAGE_MARKER, version]
+				.into_iter()
+				.chain(payload.clone())
+				.collect::<Vec<_>>();
+
+			assert_err!(
+				Message::deserialize_versioned(&input),
+				DispatchError::Other("Unsupported LP message version"),
+			);
+		}
+	}
+
 	/// Verify the identity property of decode . encode on a Message value and
 	/// that it in fact encodes to and can be decoded from a given hex string.
 	fn test_encode_decode_identity(msg: Message, expected_hex: &str) {
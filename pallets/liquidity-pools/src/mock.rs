@@ -62,6 +62,7 @@ frame_support::construct_runtime!(
 		Gateway: cfg_mocks::pallet_mock_liquidity_pools_gateway,
 		TransferFilter: cfg_mocks::pre_conditions::pallet,
 		MarketRatio: cfg_mocks::token_swaps::pallet,
+		PriceUpdateHandler: cfg_mocks::price_update_handler::pallet,
 		Tokens: orml_tokens,
 		LiquidityPools: pallet_liquidity_pools,
 	}
@@ -114,6 +115,10 @@ impl cfg_mocks::pre_conditions::pallet::Config for Runtime {
 	type Result = DispatchResult;
 }
 
+impl cfg_mocks::price_update_handler::pallet::Config for Runtime {
+	type Sender = DomainAddress;
+}
+
 impl cfg_mocks::token_swaps::pallet::Config for Runtime {
 	type BalanceIn = Balance;
 	type BalanceOut = Balance;
@@ -145,6 +150,7 @@ impl orml_tokens::Config for Runtime {
 frame_support::parameter_types! {
 	pub CurrencyPrefix: [u8; 12] = [1; 12];
 	pub TreasuryAccount: AccountId = [2; 32].into();
+	pub const PositionSnapshotInterval: u64 = 3600;
 }
 
 impl pallet_liquidity_pools::Config for Runtime {
@@ -159,7 +165,9 @@ impl pallet_liquidity_pools::Config for Runtime {
 	type Permission = Permissions;
 	type PoolId = PoolId;
 	type PoolInspect = Pools;
+	type PositionSnapshotInterval = PositionSnapshotInterval;
 	type PreTransferFilter = TransferFilter;
+	type PriceUpdateHandler = PriceUpdateHandler;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Time;
 	type Tokens = Tokens;
@@ -2,16 +2,20 @@ use cfg_traits::Seconds;
 use cfg_types::{
 	domain_address::DomainAddress,
 	permissions::{PermissionScope, PoolRole, Role},
-	tokens::CurrencyId,
+	tokens::{AssetMetadata, CurrencyId},
 };
 use cfg_utils::vec_to_fixed_array;
 use frame_support::{
 	assert_noop, assert_ok,
 	traits::fungibles::{Inspect as _, Mutate as _},
+	BoundedVec,
 };
 use sp_runtime::{traits::Saturating, DispatchError, TokenError};
 
-use crate::{mock::*, Error, Message, UpdateRestrictionMessage};
+use crate::{
+	message::TOKEN_URI_SIZE, mock::*, Error, Message, TrancheDeployment, TrancheDeployments,
+	TrancheMetadataUris, UpdateRestrictionMessage,
+};
 
 mod inbound;
 
@@ -495,6 +499,7 @@ mod add_tranche {
 					token_symbol: vec_to_fixed_array(SYMBOL),
 					decimals: DECIMALS,
 					hook: DomainAddress::Evm(CHAIN_ID, DOMAIN_HOOK_ADDRESS).bytes(),
+					metadata_uri: [0; TOKEN_URI_SIZE],
 				}
 			);
 			Ok(())
@@ -512,6 +517,14 @@ mod add_tranche {
 				TRANCHE_ID,
 				CONTRACT_DOMAIN_ADDRESS.domain(),
 			));
+
+			assert_eq!(
+				TrancheDeployments::<Runtime>::get(POOL_ID, TRANCHE_ID),
+				Some(TrancheDeployment {
+					decimals: DECIMALS,
+					domains: BoundedVec::truncate_from(vec![CONTRACT_DOMAIN_ADDRESS.domain()]),
+				})
+			);
 		})
 	}
 
@@ -606,6 +619,37 @@ mod add_tranche {
 				);
 			})
 		}
+
+		#[test]
+		fn with_changed_decimals() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_ok!(LiquidityPools::add_tranche(
+					RuntimeOrigin::signed(ALICE),
+					POOL_ID,
+					TRANCHE_ID,
+					CONTRACT_DOMAIN_ADDRESS.domain(),
+				));
+
+				AssetRegistry::mock_metadata(|_| {
+					Some(AssetMetadata {
+						decimals: DECIMALS as u32 + 1,
+						..util::default_metadata()
+					})
+				});
+
+				assert_noop!(
+					LiquidityPools::add_tranche(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						CONTRACT_DOMAIN_ADDRESS.domain(),
+					),
+					Error::<Runtime>::TrancheDecimalsMismatch,
+				);
+			})
+		}
 	}
 }
 
@@ -626,6 +670,8 @@ mod update_tranche_token_metadata {
 					tranche_id: TRANCHE_ID,
 					token_name: vec_to_fixed_array(NAME),
 					token_symbol: vec_to_fixed_array(SYMBOL),
+					decimals: DECIMALS,
+					metadata_uri: [0; TOKEN_URI_SIZE],
 				}
 			);
 			Ok(())
@@ -705,6 +751,293 @@ mod update_tranche_token_metadata {
 	}
 }
 
+mod set_tranche_metadata_uri {
+	use super::*;
+
+	const METADATA_URI: &[u8] = b"ipfs://metadata";
+
+	fn metadata_uri() -> crate::TrancheMetadataUri {
+		BoundedVec::truncate_from(METADATA_URI.to_vec())
+	}
+
+	fn config_mocks() {
+		Permissions::mock_has(move |scope, who, role| {
+			assert_eq!(who, ALICE);
+			assert!(matches!(scope, PermissionScope::Pool(POOL_ID)));
+			assert!(matches!(role, Role::PoolRole(PoolRole::PoolAdmin)));
+			true
+		});
+		Pools::mock_pool_exists(|_| true);
+		Pools::mock_tranche_exists(|_, _| true);
+		AssetRegistry::mock_metadata(|_| Some(util::default_metadata()));
+	}
+
+	#[test]
+	fn success_without_deployments() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+
+			assert_ok!(LiquidityPools::set_tranche_metadata_uri(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				TRANCHE_ID,
+				Some(metadata_uri()),
+			));
+
+			assert_eq!(
+				TrancheMetadataUris::<Runtime>::get(POOL_ID, TRANCHE_ID),
+				Some(metadata_uri())
+			);
+		})
+	}
+
+	#[test]
+	fn propagates_to_deployed_domains() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+
+			TrancheDeployments::<Runtime>::insert(
+				POOL_ID,
+				TRANCHE_ID,
+				TrancheDeployment {
+					decimals: DECIMALS,
+					domains: BoundedVec::truncate_from(vec![CONTRACT_DOMAIN_ADDRESS.domain()]),
+				},
+			);
+
+			let handle = Gateway::mock_handle(|sender, destination, msg| {
+				assert_eq!(sender, ALICE);
+				assert_eq!(destination, CONTRACT_DOMAIN_ADDRESS.domain());
+				assert_eq!(
+					msg,
+					Message::UpdateTrancheMetadata {
+						pool_id: POOL_ID,
+						tranche_id: TRANCHE_ID,
+						token_name: vec_to_fixed_array(NAME),
+						token_symbol: vec_to_fixed_array(SYMBOL),
+						decimals: DECIMALS,
+						metadata_uri: vec_to_fixed_array(METADATA_URI),
+					}
+				);
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::set_tranche_metadata_uri(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				TRANCHE_ID,
+				Some(metadata_uri()),
+			));
+
+			assert_eq!(handle.times(), 1);
+		})
+	}
+
+	mod erroring_out {
+		use super::*;
+
+		#[test]
+		fn with_wrong_permissions() {
+			System::externalities().execute_with(|| {
+				Permissions::mock_has(move |_, _, _| false);
+
+				assert_noop!(
+					LiquidityPools::set_tranche_metadata_uri(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						Some(metadata_uri()),
+					),
+					Error::<Runtime>::NotPoolAdmin
+				);
+			})
+		}
+
+		#[test]
+		fn with_changed_decimals() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				TrancheDeployments::<Runtime>::insert(
+					POOL_ID,
+					TRANCHE_ID,
+					TrancheDeployment {
+						decimals: DECIMALS + 1,
+						domains: BoundedVec::truncate_from(vec![CONTRACT_DOMAIN_ADDRESS.domain()]),
+					},
+				);
+
+				assert_noop!(
+					LiquidityPools::set_tranche_metadata_uri(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						Some(metadata_uri()),
+					),
+					Error::<Runtime>::TrancheDecimalsMismatch
+				);
+			})
+		}
+	}
+}
+
+mod broadcast_message {
+	use cfg_traits::liquidity_pools::{LpMessageHash, OutboundMessageTracker};
+	use cfg_types::domain_address::Domain;
+
+	use super::*;
+	use crate::{BroadcastDeliveries, BroadcastStatus};
+
+	const OTHER_DOMAIN: Domain = Domain::Evm(CHAIN_ID + 1);
+
+	fn message() -> Message {
+		Message::AddPool { pool_id: POOL_ID }
+	}
+
+	fn config_mocks() {
+		Permissions::mock_has(move |scope, who, role| {
+			assert_eq!(who, ALICE);
+			assert!(matches!(scope, PermissionScope::Pool(POOL_ID)));
+			assert!(matches!(role, Role::PoolRole(PoolRole::PoolAdmin)));
+			true
+		});
+		AssetRegistry::mock_metadata(|_| Some(util::default_metadata()));
+
+		TrancheDeployments::<Runtime>::insert(
+			POOL_ID,
+			TRANCHE_ID,
+			TrancheDeployment {
+				decimals: DECIMALS,
+				domains: BoundedVec::truncate_from(vec![EVM_DOMAIN, OTHER_DOMAIN]),
+			},
+		);
+		TrancheDeployments::<Runtime>::insert(
+			POOL_ID,
+			[2; 16],
+			TrancheDeployment {
+				decimals: DECIMALS,
+				domains: BoundedVec::truncate_from(vec![EVM_DOMAIN]),
+			},
+		);
+	}
+
+	#[test]
+	fn success() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+
+			let handle = Gateway::mock_handle(|sender, _, msg| {
+				assert_eq!(sender, ALICE);
+				assert_eq!(msg, message());
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::broadcast_message(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				Box::new(message()),
+			));
+
+			assert_eq!(handle.times(), 2);
+
+			let status = LiquidityPools::broadcast_status(message().get_message_hash(), 0);
+			assert_eq!(status.len(), 2);
+			assert!(status.contains(&(EVM_DOMAIN, BroadcastStatus::Queued)));
+			assert!(status.contains(&(OTHER_DOMAIN, BroadcastStatus::Queued)));
+		})
+	}
+
+	#[test]
+	fn delivery_is_tracked_per_domain() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+			Gateway::mock_handle(|_, destination, _| {
+				if destination == OTHER_DOMAIN {
+					Err(DispatchError::Other("unreachable domain"))
+				} else {
+					Ok(())
+				}
+			});
+
+			assert_ok!(LiquidityPools::broadcast_message(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				Box::new(message()),
+			));
+
+			let hash = message().get_message_hash();
+			assert_eq!(
+				BroadcastDeliveries::<Runtime>::get(hash, (0, EVM_DOMAIN)),
+				Some(BroadcastStatus::Queued)
+			);
+			assert_eq!(
+				BroadcastDeliveries::<Runtime>::get(hash, (0, OTHER_DOMAIN)),
+				Some(BroadcastStatus::Failed)
+			);
+
+			assert_ok!(LiquidityPools::on_send(EVM_DOMAIN, &message()));
+
+			// All domains were handled, so the broadcast is no longer tracked
+			assert!(LiquidityPools::broadcast_status(hash, 0).is_empty());
+		})
+	}
+
+	mod erroring_out {
+		use super::*;
+
+		#[test]
+		fn with_wrong_permissions() {
+			System::externalities().execute_with(|| {
+				Permissions::mock_has(move |_, _, _| false);
+
+				assert_noop!(
+					LiquidityPools::broadcast_message(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						Box::new(message()),
+					),
+					Error::<Runtime>::NotPoolAdmin
+				);
+			})
+		}
+
+		#[test]
+		fn with_message_of_other_pool() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_noop!(
+					LiquidityPools::broadcast_message(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						Box::new(Message::AddPool {
+							pool_id: POOL_ID + 1
+						}),
+					),
+					Error::<Runtime>::MessageNotBroadcastable
+				);
+			})
+		}
+
+		#[test]
+		fn with_not_deployed_pool() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				let _ = TrancheDeployments::<Runtime>::clear_prefix(POOL_ID, u32::MAX, None);
+
+				assert_noop!(
+					LiquidityPools::broadcast_message(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						Box::new(message()),
+					),
+					Error::<Runtime>::PoolNotDeployed
+				);
+			})
+		}
+	}
+}
+
 mod update_token_price {
 	use super::*;
 
@@ -2080,3 +2413,362 @@ mod recover_assets {
 		}
 	}
 }
+
+mod transfer_ttl {
+	use cfg_traits::liquidity_pools::{LpMessageHash, OutboundMessageTracker};
+
+	use super::*;
+	use crate::{DomainTransferTtl, OutboundTransfers, TransferStatus};
+
+	const TTL: Seconds = 60;
+
+	fn message() -> Message {
+		Message::TransferAssets {
+			currency: util::currency_index(CURRENCY_ID),
+			receiver: CONTRACT_DOMAIN_ADDRESS.bytes(),
+			amount: AMOUNT,
+		}
+	}
+
+	fn transfer_with_ttl() {
+		Time::mock_now(|| NOW);
+		AssetRegistry::mock_metadata(|_| Some(util::locatable_transferable_metadata()));
+		TransferFilter::mock_check(|_| Ok(()));
+		Gateway::mock_handle(|_, _, _| Ok(()));
+
+		assert_ok!(LiquidityPools::set_domain_transfer_ttl(
+			RuntimeOrigin::root(),
+			EVM_DOMAIN,
+			Some(TTL)
+		));
+
+		Tokens::mint_into(CURRENCY_ID, &ALICE, AMOUNT).unwrap();
+
+		assert_ok!(LiquidityPools::transfer(
+			RuntimeOrigin::signed(ALICE),
+			CURRENCY_ID,
+			CONTRACT_DOMAIN_ADDRESS,
+			AMOUNT
+		));
+	}
+
+	#[test]
+	fn transfer_is_tracked() {
+		System::externalities().execute_with(|| {
+			transfer_with_ttl();
+
+			let transfer =
+				OutboundTransfers::<Runtime>::get(message().get_message_hash(), 0).unwrap();
+			assert_eq!(transfer.sender, ALICE);
+			assert_eq!(transfer.amount, AMOUNT);
+			assert_eq!(transfer.expires_at, NOW_SECS + TTL);
+			assert_eq!(transfer.status, TransferStatus::Pending);
+		})
+	}
+
+	#[test]
+	fn transfer_without_ttl_is_not_tracked() {
+		System::externalities().execute_with(|| {
+			transfer_with_ttl();
+			DomainTransferTtl::<Runtime>::remove(EVM_DOMAIN);
+
+			Tokens::mint_into(CURRENCY_ID, &ALICE, AMOUNT).unwrap();
+			assert_ok!(LiquidityPools::transfer(
+				RuntimeOrigin::signed(ALICE),
+				CURRENCY_ID,
+				CONTRACT_DOMAIN_ADDRESS,
+				AMOUNT
+			));
+
+			assert_eq!(
+				OutboundTransfers::<Runtime>::iter_prefix(message().get_message_hash()).count(),
+				1
+			);
+		})
+	}
+
+	#[test]
+	fn sent_transfer_is_no_longer_tracked() {
+		System::externalities().execute_with(|| {
+			transfer_with_ttl();
+
+			assert_ok!(LiquidityPools::on_send(EVM_DOMAIN, &message()));
+
+			assert!(OutboundTransfers::<Runtime>::get(message().get_message_hash(), 0).is_none());
+		})
+	}
+
+	#[test]
+	fn expire_and_refund() {
+		System::externalities().execute_with(|| {
+			transfer_with_ttl();
+			let hash = message().get_message_hash();
+
+			Time::mock_now(|| (NOW_SECS + TTL) * 1000);
+
+			assert_ok!(LiquidityPools::expire_transfer(
+				RuntimeOrigin::signed(ALICE),
+				hash,
+				0
+			));
+
+			assert_noop!(
+				LiquidityPools::on_send(EVM_DOMAIN, &message()),
+				Error::<Runtime>::TransferAlreadyExpired
+			);
+
+			assert_ok!(LiquidityPools::refund_transfer(
+				RuntimeOrigin::root(),
+				hash,
+				0
+			));
+
+			assert_eq!(Tokens::balance(CURRENCY_ID, &ALICE), AMOUNT);
+			assert_eq!(
+				OutboundTransfers::<Runtime>::get(hash, 0).unwrap().status,
+				TransferStatus::Refunded
+			);
+		})
+	}
+
+	mod erroring_out {
+		use super::*;
+
+		#[test]
+		fn expire_before_ttl() {
+			System::externalities().execute_with(|| {
+				transfer_with_ttl();
+
+				assert_noop!(
+					LiquidityPools::expire_transfer(
+						RuntimeOrigin::signed(ALICE),
+						message().get_message_hash(),
+						0
+					),
+					Error::<Runtime>::TransferNotExpirable
+				);
+			})
+		}
+
+		#[test]
+		fn refund_not_expired() {
+			System::externalities().execute_with(|| {
+				transfer_with_ttl();
+
+				assert_noop!(
+					LiquidityPools::refund_transfer(
+						RuntimeOrigin::root(),
+						message().get_message_hash(),
+						0
+					),
+					Error::<Runtime>::TransferNotExpired
+				);
+			})
+		}
+
+		#[test]
+		fn refund_with_wrong_origin() {
+			System::externalities().execute_with(|| {
+				transfer_with_ttl();
+
+				assert_noop!(
+					LiquidityPools::refund_transfer(
+						RuntimeOrigin::signed(ALICE),
+						message().get_message_hash(),
+						0
+					),
+					DispatchError::BadOrigin
+				);
+			})
+		}
+	}
+}
+
+mod sync_investor_position {
+	use cfg_traits::investments::ForeignInvestmentPosition;
+
+	use super::*;
+	use crate::LastPositionSnapshots;
+
+	fn message(computed_at: Seconds) -> Message {
+		Message::InvestorPosition {
+			pool_id: POOL_ID,
+			tranche_id: TRANCHE_ID,
+			investor: ALICE_EVM_DOMAIN_ADDRESS.account().into(),
+			currency: util::currency_index(CURRENCY_ID),
+			pending_deposit: AMOUNT,
+			pending_redeem: AMOUNT / 2,
+			claimable_redeem: AMOUNT / 4,
+			held_tranche_tokens: AMOUNT * 2,
+			computed_at,
+		}
+	}
+
+	fn config_mocks() {
+		Time::mock_now(|| NOW);
+		Pools::mock_pool_exists(|_| true);
+		Pools::mock_tranche_exists(|_, _| true);
+		AssetRegistry::mock_metadata(|_| Some(util::default_metadata()));
+		ForeignInvestment::mock_foreign_position(|who, investment_id| {
+			assert_eq!(*who, ALICE_EVM_DOMAIN_ADDRESS.account());
+			assert_eq!(investment_id, INVESTMENT_ID);
+			Ok(ForeignInvestmentPosition {
+				pending_investment: AMOUNT,
+				pending_redemption: AMOUNT / 2,
+				claimable_redemption: AMOUNT / 4,
+			})
+		});
+		Gateway::mock_handle(|sender, destination, msg| {
+			assert_eq!(sender, ALICE);
+			assert_eq!(destination, EVM_DOMAIN);
+			assert!(matches!(msg, Message::InvestorPosition { .. }));
+			Ok(())
+		});
+
+		assert_ok!(Tokens::mint_into(
+			TRANCHE_CURRENCY,
+			&ALICE_EVM_DOMAIN_ADDRESS.account(),
+			AMOUNT * 2
+		));
+	}
+
+	#[test]
+	fn success() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+			let handle = Gateway::mock_handle(|sender, destination, msg| {
+				assert_eq!(sender, ALICE);
+				assert_eq!(destination, EVM_DOMAIN);
+				assert_eq!(msg, message(NOW_SECS));
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::sync_investor_position(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				TRANCHE_ID,
+				ALICE_EVM_DOMAIN_ADDRESS,
+				CURRENCY_ID,
+			));
+
+			assert_eq!(handle.times(), 1);
+			assert_eq!(
+				LastPositionSnapshots::<Runtime>::get(ALICE_EVM_DOMAIN_ADDRESS),
+				Some(NOW_SECS)
+			);
+		})
+	}
+
+	#[test]
+	fn after_interval() {
+		System::externalities().execute_with(|| {
+			config_mocks();
+			LastPositionSnapshots::<Runtime>::insert(ALICE_EVM_DOMAIN_ADDRESS, NOW_SECS);
+
+			let later = NOW_SECS + PositionSnapshotInterval::get();
+			Time::mock_now(move || later * 1000);
+
+			assert_ok!(LiquidityPools::sync_investor_position(
+				RuntimeOrigin::signed(ALICE),
+				POOL_ID,
+				TRANCHE_ID,
+				ALICE_EVM_DOMAIN_ADDRESS,
+				CURRENCY_ID,
+			));
+
+			assert_eq!(
+				LastPositionSnapshots::<Runtime>::get(ALICE_EVM_DOMAIN_ADDRESS),
+				Some(later)
+			);
+		})
+	}
+
+	mod erroring_out {
+		use super::*;
+
+		#[test]
+		fn within_interval() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_ok!(LiquidityPools::sync_investor_position(
+					RuntimeOrigin::signed(ALICE),
+					POOL_ID,
+					TRANCHE_ID,
+					ALICE_EVM_DOMAIN_ADDRESS,
+					CURRENCY_ID,
+				));
+
+				let later = NOW_SECS + PositionSnapshotInterval::get() - 1;
+				Time::mock_now(move || later * 1000);
+
+				assert_noop!(
+					LiquidityPools::sync_investor_position(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						ALICE_EVM_DOMAIN_ADDRESS,
+						CURRENCY_ID,
+					),
+					Error::<Runtime>::PositionSnapshotThrottled
+				);
+			})
+		}
+
+		#[test]
+		fn with_centrifuge_domain() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+
+				assert_noop!(
+					LiquidityPools::sync_investor_position(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						ALICE_LOCAL_DOMAIN_ADDRESS,
+						CURRENCY_ID,
+					),
+					Error::<Runtime>::InvalidDomain
+				);
+			})
+		}
+
+		#[test]
+		fn with_wrong_tranche() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				Pools::mock_tranche_exists(|_, _| false);
+
+				assert_noop!(
+					LiquidityPools::sync_investor_position(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						ALICE_EVM_DOMAIN_ADDRESS,
+						CURRENCY_ID,
+					),
+					Error::<Runtime>::TrancheNotFound
+				);
+			})
+		}
+
+		#[test]
+		fn with_unknown_currency() {
+			System::externalities().execute_with(|| {
+				config_mocks();
+				AssetRegistry::mock_metadata(|_| None);
+
+				assert_noop!(
+					LiquidityPools::sync_investor_position(
+						RuntimeOrigin::signed(ALICE),
+						POOL_ID,
+						TRANCHE_ID,
+						ALICE_EVM_DOMAIN_ADDRESS,
+						CURRENCY_ID,
+					),
+					Error::<Runtime>::AssetNotFound
+				);
+			})
+		}
+	}
+}
@@ -797,3 +797,33 @@ mod handle_cancel_redeem_order {
 		}
 	}
 }
+
+mod handle_update_price {
+	use super::*;
+
+	const FEED: [u8; 32] = [7; 32];
+
+	#[test]
+	fn success() {
+		System::externalities().execute_with(|| {
+			PriceUpdateHandler::mock_handle_price(|sender, feed, price, decimals, computed_at| {
+				assert_eq!(sender, CONTRACT_DOMAIN_ADDRESS);
+				assert_eq!(feed, FEED);
+				assert_eq!(price, 2_050_000_000);
+				assert_eq!(decimals, 8);
+				assert_eq!(computed_at, 1_700_000_000);
+				Ok(())
+			});
+
+			assert_ok!(LiquidityPools::handle(
+				CONTRACT_DOMAIN_ADDRESS,
+				Message::UpdatePrice {
+					feed: FEED,
+					price: 2_050_000_000,
+					decimals: 8,
+					computed_at: 1_700_000_000,
+				},
+			));
+		});
+	}
+}
@@ -0,0 +1,483 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! # Liquidity Pools Test Vectors
+//!
+//! Canonical encodings of every Liquidity Pools message, used to detect any
+//! drift between the encoding of the chain and the one of the Solidity
+//! contracts.
+//!
+//! Each vector contains a message in its GMPF form, the one sent to other
+//! domains, with and without the versioned envelope, in its SCALE form, the
+//! one stored by the gateway, and wrapped into the Axelar `callContract` ABI
+//! call used by the Axelar router.
+//!
+//! The vectors are committed in `fixtures/lp-messages.json`, which is
+//! checked by the tests of this crate and consumed by the CI of the Solidity
+//! repository. After an intended change of the encoding, the fixtures are
+//! regenerated with:
+//!
+//! ```sh
+//! cargo run -p liquidity-pools-test-vectors
+//! ```
+
+use cfg_traits::liquidity_pools::{
+	LpMessageBatch, LpMessageForwarded, LpMessageSequence, LpMessageSerializer,
+};
+use cfg_types::domain_address::Domain;
+use cfg_utils::vec_to_fixed_array;
+use hex_literal::hex;
+use pallet_liquidity_pools::{Message, UpdateRestrictionMessage};
+use parity_scale_codec::Encode;
+use serde::{Deserialize, Serialize};
+use sp_core::H160;
+
+#[cfg(test)]
+mod tests;
+
+/// Path of the committed fixtures, relative to the root of this crate.
+pub const FIXTURES_PATH: &str = "fixtures/lp-messages.json";
+
+/// Destination chain used to wrap the messages into Axelar calls.
+pub const AXELAR_CHAIN: &str = "Ethereum";
+
+/// Destination contract used to wrap the messages into Axelar calls.
+pub const AXELAR_CONTRACT: [u8; 20] = [0x56; 20];
+
+const ADDRESS_20: [u8; 20] = hex!("1231231231231231231231231231231231231231");
+const ADDRESS_32: [u8; 32] =
+	hex!("4564564564564564564564564564564564564564564564564564564564564564");
+const TRANCHE_ID: [u8; 16] = hex!("811acd5b3f17c06841c7e41e9e04cb1b");
+const HASH: [u8; 32] = [0x78; 32];
+const POOL_ID: u64 = 12378532;
+const CURRENCY: u128 = 246803579;
+const AMOUNT: u128 = 100000000000000000000000000;
+
+/// A message with the name identifying it in the fixtures.
+pub struct TestVector {
+	pub name: &'static str,
+	pub message: Message,
+}
+
+/// The encodings of a message, as hex strings prefixed with `0x`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct EncodedVector {
+	pub name: String,
+	pub gmpf: String,
+	pub gmpf_versioned: String,
+	pub scale: String,
+	pub axelar_call_contract: String,
+}
+
+/// The content of the fixtures file.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Fixtures {
+	pub message_version: u8,
+	pub axelar_chain: String,
+	pub axelar_contract: String,
+	pub vectors: Vec<EncodedVector>,
+}
+
+impl TestVector {
+	pub fn encode(&self) -> EncodedVector {
+		let gmpf = LpMessageSerializer::serialize(&self.message);
+		let axelar_call_contract = pallet_axelar_router::wrap_into_axelar_msg(
+			gmpf.clone(),
+			AXELAR_CHAIN.as_bytes().to_vec(),
+			H160(AXELAR_CONTRACT),
+		)
+		.expect("Axelar call encoding never fails for valid UTF-8 chain names");
+
+		EncodedVector {
+			name: self.name.into(),
+			gmpf: to_hex(&gmpf),
+			gmpf_versioned: to_hex(&self.message.serialize_versioned(Message::LATEST_VERSION)),
+			scale: to_hex(&self.message.encode()),
+			axelar_call_contract: to_hex(&axelar_call_contract),
+		}
+	}
+}
+
+/// Returns the fixtures for the current encoding of the messages.
+pub fn fixtures() -> Fixtures {
+	Fixtures {
+		message_version: Message::LATEST_VERSION,
+		axelar_chain: AXELAR_CHAIN.into(),
+		axelar_contract: to_hex(&AXELAR_CONTRACT),
+		vectors: vectors().iter().map(TestVector::encode).collect(),
+	}
+}
+
+/// Returns the fixtures for the current encoding of the messages, in the
+/// format of the fixtures file.
+pub fn fixtures_json() -> String {
+	let mut json =
+		serde_json::to_string_pretty(&fixtures()).expect("Fixtures are always serializable");
+	json.push('\n');
+	json
+}
+
+/// Returns the test vectors, at least one for each message type.
+pub fn vectors() -> Vec<TestVector> {
+	let add_pool = Message::AddPool { pool_id: 0 };
+	let allow_asset = Message::AllowAsset {
+		pool_id: POOL_ID,
+		currency: CURRENCY,
+	};
+	let deposit_request = Message::DepositRequest {
+		pool_id: 1,
+		tranche_id: TRANCHE_ID,
+		investor: ADDRESS_32,
+		currency: CURRENCY,
+		amount: AMOUNT,
+	};
+	let update_restriction = |update| Message::UpdateRestriction {
+		pool_id: 2,
+		tranche_id: TRANCHE_ID,
+		update,
+	};
+
+	let mut batch = Message::empty();
+	batch
+		.pack_with(add_pool.clone())
+		.expect("Batch limit not reached");
+	batch
+		.pack_with(allow_asset.clone())
+		.expect("Batch limit not reached");
+
+	let mut recovered_amount = [0xff; 32];
+	recovered_amount[31] = 0xfe;
+
+	vec![
+		TestVector {
+			name: "Invalid",
+			message: Message::Invalid,
+		},
+		TestVector {
+			name: "MessageProof",
+			message: Message::MessageProof { hash: HASH },
+		},
+		TestVector {
+			name: "InitiateMessageRecovery",
+			message: Message::InitiateMessageRecovery {
+				hash: HASH,
+				router: ADDRESS_32,
+			},
+		},
+		TestVector {
+			name: "DisputeMessageRecovery",
+			message: Message::DisputeMessageRecovery {
+				hash: HASH,
+				router: ADDRESS_32,
+			},
+		},
+		TestVector {
+			name: "Batch",
+			message: batch,
+		},
+		TestVector {
+			name: "ScheduleUpgrade",
+			message: Message::ScheduleUpgrade {
+				contract: ADDRESS_20,
+			},
+		},
+		TestVector {
+			name: "CancelUpgrade",
+			message: Message::CancelUpgrade {
+				contract: ADDRESS_20,
+			},
+		},
+		TestVector {
+			name: "RecoverAssets",
+			message: Message::RecoverAssets {
+				contract: [2; 32],
+				asset: [1; 32],
+				recipient: [3; 32],
+				amount: recovered_amount,
+			},
+		},
+		TestVector {
+			name: "UpdateCentrifugeGasPrice",
+			message: Message::UpdateCentrifugeGasPrice {
+				price: 2_500_000_000,
+			},
+		},
+		TestVector {
+			name: "AddAsset",
+			message: Message::AddAsset {
+				currency: CURRENCY,
+				evm_address: ADDRESS_20,
+			},
+		},
+		TestVector {
+			name: "AddPool",
+			message: Message::AddPool { pool_id: POOL_ID },
+		},
+		TestVector {
+			name: "AddTranche",
+			message: Message::AddTranche {
+				pool_id: 1,
+				tranche_id: TRANCHE_ID,
+				token_name: vec_to_fixed_array(b"Some Name"),
+				token_symbol: vec_to_fixed_array(b"SYMBOL"),
+				decimals: 15,
+				hook: ADDRESS_32,
+				metadata_uri: vec_to_fixed_array(b"ipfs://metadata"),
+			},
+		},
+		TestVector {
+			name: "AllowAsset",
+			message: allow_asset,
+		},
+		TestVector {
+			name: "DisallowAsset",
+			message: Message::DisallowAsset {
+				pool_id: POOL_ID,
+				currency: CURRENCY,
+			},
+		},
+		TestVector {
+			name: "UpdateTranchePrice",
+			message: Message::UpdateTranchePrice {
+				pool_id: 1,
+				tranche_id: TRANCHE_ID,
+				currency: CURRENCY,
+				price: 1_000_000_000_000_000_000,
+				computed_at: 1698131924,
+			},
+		},
+		TestVector {
+			name: "UpdateTrancheMetadata",
+			message: Message::UpdateTrancheMetadata {
+				pool_id: 1,
+				tranche_id: TRANCHE_ID,
+				token_name: vec_to_fixed_array(b"Some Name"),
+				token_symbol: vec_to_fixed_array(b"SYMBOL"),
+				decimals: 15,
+				metadata_uri: vec_to_fixed_array(b"ipfs://metadata"),
+			},
+		},
+		TestVector {
+			name: "UpdateTrancheHook",
+			message: Message::UpdateTrancheHook {
+				pool_id: 1,
+				tranche_id: TRANCHE_ID,
+				hook: ADDRESS_32,
+			},
+		},
+		TestVector {
+			name: "TransferAssets",
+			message: Message::TransferAssets {
+				currency: CURRENCY,
+				receiver: ADDRESS_32,
+				amount: AMOUNT,
+			},
+		},
+		TestVector {
+			name: "TransferTrancheTokens",
+			message: Message::TransferTrancheTokens {
+				pool_id: 1,
+				tranche_id: TRANCHE_ID,
+				domain: Domain::Evm(1284).into(),
+				receiver: vec_to_fixed_array(ADDRESS_20),
+				amount: AMOUNT,
+			},
+		},
+		TestVector {
+			name: "UpdateRestriction.UpdateMember",
+			message: update_restriction(UpdateRestrictionMessage::UpdateMember {
+				member: ADDRESS_32,
+				valid_until: 1706260138,
+			}),
+		},
+		TestVector {
+			name: "UpdateRestriction.Freeze",
+			message: update_restriction(UpdateRestrictionMessage::Freeze {
+				address: ADDRESS_32,
+			}),
+		},
+		TestVector {
+			name: "UpdateRestriction.Unfreeze",
+			message: update_restriction(UpdateRestrictionMessage::Unfreeze {
+				address: ADDRESS_32,
+			}),
+		},
+		TestVector {
+			name: "DepositRequest",
+			message: deposit_request.clone(),
+		},
+		TestVector {
+			name: "RedeemRequest",
+			message: Message::RedeemRequest {
+				pool_id: 1,
+				tranche_id: TRANCHE_ID,
+				investor: ADDRESS_32,
+				currency: CURRENCY,
+				amount: AMOUNT,
+			},
+		},
+		TestVector {
+			name: "FulfilledDepositRequest",
+			message: Message::FulfilledDepositRequest {
+				pool_id: POOL_ID,
+				tranche_id: TRANCHE_ID,
+				investor: vec_to_fixed_array(ADDRESS_20),
+				currency: CURRENCY,
+				currency_payout: AMOUNT,
+				tranche_tokens_payout: AMOUNT / 2,
+			},
+		},
+		TestVector {
+			name: "FulfilledRedeemRequest",
+			message: Message::FulfilledRedeemRequest {
+				pool_id: POOL_ID,
+				tranche_id: TRANCHE_ID,
+				investor: vec_to_fixed_array(ADDRESS_20),
+				currency: CURRENCY,
+				currency_payout: AMOUNT,
+				tranche_tokens_payout: AMOUNT / 2,
+			},
+		},
+		TestVector {
+			name: "CancelDepositRequest",
+			message: Message::CancelDepositRequest {
+				pool_id: 1,
+				tranche_id: TRANCHE_ID,
+				investor: ADDRESS_32,
+				currency: CURRENCY,
+			},
+		},
+		TestVector {
+			name: "CancelRedeemRequest",
+			message: Message::CancelRedeemRequest {
+				pool_id: 1,
+				tranche_id: TRANCHE_ID,
+				investor: ADDRESS_32,
+				currency: CURRENCY,
+			},
+		},
+		TestVector {
+			name: "FulfilledCancelDepositRequest",
+			message: Message::FulfilledCancelDepositRequest {
+				pool_id: POOL_ID,
+				tranche_id: TRANCHE_ID,
+				investor: vec_to_fixed_array(ADDRESS_20),
+				currency: CURRENCY,
+				currency_payout: AMOUNT / 2,
+				fulfilled_invest_amount: AMOUNT / 4,
+			},
+		},
+		TestVector {
+			name: "FulfilledCancelRedeemRequest",
+			message: Message::FulfilledCancelRedeemRequest {
+				pool_id: POOL_ID,
+				tranche_id: TRANCHE_ID,
+				investor: vec_to_fixed_array(ADDRESS_20),
+				currency: CURRENCY,
+				tranche_tokens_payout: AMOUNT / 2,
+			},
+		},
+		TestVector {
+			name: "TriggerRedeemRequest",
+			message: Message::TriggerRedeemRequest {
+				pool_id: POOL_ID,
+				tranche_id: TRANCHE_ID,
+				investor: vec_to_fixed_array(ADDRESS_20),
+				currency: CURRENCY,
+				amount: AMOUNT,
+			},
+		},
+		TestVector {
+			name: "Forwarded",
+			message: Message::try_wrap_forward(Domain::Evm(1), H160(ADDRESS_20), deposit_request)
+				.expect("A deposit request can be forwarded"),
+		},
+		TestVector {
+			name: "Sequenced",
+			message: Message::try_wrap_sequenced(7, add_pool)
+				.expect("An add pool message can be sequenced"),
+		},
+		TestVector {
+			name: "InvestorPosition",
+			message: Message::InvestorPosition {
+				pool_id: POOL_ID,
+				tranche_id: TRANCHE_ID,
+				investor: vec_to_fixed_array(ADDRESS_20),
+				currency: CURRENCY,
+				pending_deposit: AMOUNT,
+				pending_redeem: AMOUNT / 2,
+				claimable_redeem: AMOUNT / 4,
+				held_tranche_tokens: AMOUNT,
+				computed_at: 1_700_000_000,
+			},
+		},
+		TestVector {
+			name: "UpdatePrice",
+			message: Message::UpdatePrice {
+				feed: vec_to_fixed_array(ADDRESS_20),
+				price: 2_050_000_000,
+				decimals: 8,
+				computed_at: 1_700_000_000,
+			},
+		},
+	]
+}
+
+/// Name of the message type, without its content.
+///
+/// NOTE: Exhaustive on purpose, so a new message type can not be added
+/// without being considered here and in [`vectors`].
+pub fn message_type(message: &Message) -> &'static str {
+	match message {
+		Message::Invalid => "Invalid",
+		Message::MessageProof { .. } => "MessageProof",
+		Message::InitiateMessageRecovery { .. } => "InitiateMessageRecovery",
+		Message::DisputeMessageRecovery { .. } => "DisputeMessageRecovery",
+		Message::Batch(_) => "Batch",
+		Message::ScheduleUpgrade { .. } => "ScheduleUpgrade",
+		Message::CancelUpgrade { .. } => "CancelUpgrade",
+		Message::RecoverAssets { .. } => "RecoverAssets",
+		Message::UpdateCentrifugeGasPrice { .. } => "UpdateCentrifugeGasPrice",
+		Message::AddAsset { .. } => "AddAsset",
+		Message::AddPool { .. } => "AddPool",
+		Message::AddTranche { .. } => "AddTranche",
+		Message::AllowAsset { .. } => "AllowAsset",
+		Message::DisallowAsset { .. } => "DisallowAsset",
+		Message::UpdateTranchePrice { .. } => "UpdateTranchePrice",
+		Message::UpdateTrancheMetadata { .. } => "UpdateTrancheMetadata",
+		Message::UpdateTrancheHook { .. } => "UpdateTrancheHook",
+		Message::TransferAssets { .. } => "TransferAssets",
+		Message::TransferTrancheTokens { .. } => "TransferTrancheTokens",
+		Message::UpdateRestriction { .. } => "UpdateRestriction",
+		Message::DepositRequest { .. } => "DepositRequest",
+		Message::RedeemRequest { .. } => "RedeemRequest",
+		Message::FulfilledDepositRequest { .. } => "FulfilledDepositRequest",
+		Message::FulfilledRedeemRequest { .. } => "FulfilledRedeemRequest",
+		Message::CancelDepositRequest { .. } => "CancelDepositRequest",
+		Message::CancelRedeemRequest { .. } => "CancelRedeemRequest",
+		Message::FulfilledCancelDepositRequest { .. } => "FulfilledCancelDepositRequest",
+		Message::FulfilledCancelRedeemRequest { .. } => "FulfilledCancelRedeemRequest",
+		Message::TriggerRedeemRequest { .. } => "TriggerRedeemRequest",
+		Message::Forwarded { .. } => "Forwarded",
+		Message::Sequenced { .. } => "Sequenced",
+		Message::InvestorPosition { .. } => "InvestorPosition",
+		Message::UpdatePrice { .. } => "UpdatePrice",
+	}
+}
+
+/// Number of message types, i.e. of arms in [`message_type`].
+pub const MESSAGE_TYPES: usize = 33;
+
+fn to_hex(bytes: &[u8]) -> String {
+	format!("0x{}", hex::encode(bytes))
+}
@@ -0,0 +1,31 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! Writes the test vectors to the given path, or to the committed fixtures
+//! if no path is given.
+
+use std::path::PathBuf;
+
+use liquidity_pools_test_vectors::{fixtures_json, FIXTURES_PATH};
+
+fn main() -> std::io::Result<()> {
+	let path = std::env::args()
+		.nth(1)
+		.map(PathBuf::from)
+		.unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(FIXTURES_PATH));
+
+	std::fs::write(&path, fixtures_json())?;
+	println!("Test vectors written to {}", path.display());
+
+	Ok(())
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeSet;
+
+use parity_scale_codec::Decode;
+
+use super::*;
+
+const COMMITTED_FIXTURES: &str = include_str!("../fixtures/lp-messages.json");
+
+fn from_hex(hex: &str) -> Vec<u8> {
+	hex::decode(hex.trim_start_matches("0x")).expect("Fixtures are valid hex")
+}
+
+#[test]
+fn committed_fixtures_are_up_to_date() {
+	let committed: Fixtures =
+		serde_json::from_str(COMMITTED_FIXTURES).expect("Fixtures are valid JSON");
+
+	assert_eq!(
+		committed,
+		fixtures(),
+		"The encoding of the messages changed. If intended, regenerate the fixtures with \
+		 `cargo run -p liquidity-pools-test-vectors` and update the Solidity repository",
+	);
+}
+
+#[test]
+fn every_message_type_has_a_vector() {
+	let covered = vectors()
+		.iter()
+		.map(|vector| message_type(&vector.message))
+		.collect::<BTreeSet<_>>();
+
+	assert_eq!(covered.len(), MESSAGE_TYPES);
+}
+
+#[test]
+fn vector_names_are_unique() {
+	let vectors = vectors();
+	let names = vectors
+		.iter()
+		.map(|vector| vector.name)
+		.collect::<BTreeSet<_>>();
+
+	assert_eq!(names.len(), vectors.len());
+}
+
+#[test]
+fn fixtures_decode_into_their_messages() {
+	let committed: Fixtures =
+		serde_json::from_str(COMMITTED_FIXTURES).expect("Fixtures are valid JSON");
+
+	for (vector, encoded) in vectors().into_iter().zip(committed.vectors) {
+		assert_eq!(vector.name, encoded.name);
+
+		assert_eq!(
+			<Message as LpMessageSerializer>::deserialize(&from_hex(&encoded.gmpf)),
+			Ok(vector.message.clone()),
+			"GMPF of {}",
+			vector.name,
+		);
+		assert_eq!(
+			Message::deserialize_versioned(&from_hex(&encoded.gmpf_versioned)),
+			Ok((vector.message.clone(), Message::LATEST_VERSION)),
+			"Versioned GMPF of {}",
+			vector.name,
+		);
+		assert_eq!(
+			Message::decode(&mut from_hex(&encoded.scale).as_slice())
+				.expect("SCALE decoding works"),
+			vector.message,
+			"SCALE of {}",
+			vector.name,
+		);
+	}
+}
@@ -9,6 +9,8 @@ const GROUP_A: u32 = 100;
 const GROUP_B: u32 = 101;
 const WEIGHT: u32 = 23;
 const CURRENCY_ID_A: u32 = 42;
+const REWARD_CURRENCY: u32 = 43;
+const FUNDS: u32 = 1000;
 
 fn init_test_mock() -> impl Sized {
 	#[cfg(test)]
@@ -22,6 +24,9 @@ fn init_test_mock() -> impl Sized {
 		MockRewards::mock_withdraw_stake(|_, _, _| Ok(()));
 		MockRewards::mock_claim_reward(|_, _| Ok(0));
 		MockRewards::mock_attach_currency(|_, _| Ok(()));
+		MockRewards::mock_lock_stake(|_, _, _, _| Ok(()));
+		MockRewards::mock_unlock_stake(|_, _| Ok(0));
+		MockRewards::mock_is_lock_expired(|_, _| true);
 		MockTime::mock_now(|| 0);
 	}
 }
@@ -113,6 +118,102 @@ benchmarks! {
 	set_currency_group {
 	}: _(RawOrigin::Root, CURRENCY_ID_A.into(), GROUP_A.into())
 
+	withdraw_unstaked {
+		let caller: T::AccountId = whitelisted_caller();
+
+		init_test_mock();
+
+		let request = UnstakeRequest {
+			amount: T::Balance::zero(),
+			matures_at: MomentOf::<T>::zero(),
+		};
+		let queue = sp_std::vec![request; T::MaxUnstakeRequests::get() as usize];
+		ExitQueue::<T>::insert(&caller, T::CurrencyId::from(CURRENCY_ID_A), BoundedVec::truncate_from(queue));
+
+	}: _(RawOrigin::Signed(caller), CURRENCY_ID_A.into())
+
+	set_group_emission_curve {
+		let curve = EmissionCurve {
+			decay: Perbill::from_percent(10),
+			floor: Perbill::from_percent(50),
+		};
+	}: _(RawOrigin::Root, GROUP_A.into(), Some(curve))
+
+	set_co_incentive {
+		let sponsor: T::AccountId = whitelisted_caller();
+
+		// Worst case: the co-incentive is added after all the others
+		for i in 1..T::MaxCoIncentives::get() {
+			Pallet::<T>::set_co_incentive(
+				RawOrigin::Root.into(),
+				CURRENCY_ID_A.into(),
+				(REWARD_CURRENCY + i).into(),
+				sponsor.clone(),
+				REWARD.into(),
+			).unwrap();
+		}
+
+	}: _(RawOrigin::Root, CURRENCY_ID_A.into(), REWARD_CURRENCY.into(), sponsor, REWARD.into())
+
+	fund_co_incentive {
+		let sponsor: T::AccountId = whitelisted_caller();
+
+		Pallet::<T>::set_co_incentive(
+			RawOrigin::Root.into(),
+			CURRENCY_ID_A.into(),
+			REWARD_CURRENCY.into(),
+			sponsor.clone(),
+			REWARD.into(),
+		).unwrap();
+		T::Currency::mint_into(REWARD_CURRENCY.into(), &sponsor, FUNDS.into()).unwrap();
+
+	}: _(RawOrigin::Signed(sponsor), CURRENCY_ID_A.into(), REWARD_CURRENCY.into(), FUNDS.into())
+
+	withdraw_co_incentive {
+		let sponsor: T::AccountId = whitelisted_caller();
+
+		Pallet::<T>::set_co_incentive(
+			RawOrigin::Root.into(),
+			CURRENCY_ID_A.into(),
+			REWARD_CURRENCY.into(),
+			sponsor.clone(),
+			REWARD.into(),
+		).unwrap();
+		T::Currency::mint_into(REWARD_CURRENCY.into(), &sponsor, FUNDS.into()).unwrap();
+		Pallet::<T>::fund_co_incentive(
+			RawOrigin::Signed(sponsor.clone()).into(),
+			CURRENCY_ID_A.into(),
+			REWARD_CURRENCY.into(),
+			FUNDS.into(),
+		).unwrap();
+
+	}: _(RawOrigin::Signed(sponsor), CURRENCY_ID_A.into(), REWARD_CURRENCY.into())
+
+	lock_stake {
+		let caller = whitelisted_caller();
+
+		init_test_mock();
+
+		Pallet::<T>::set_currency_group(RawOrigin::Root.into(), CURRENCY_ID_A.into(), GROUP_A.into()).unwrap();
+		Pallet::<T>::apply_epoch_changes(&mut Default::default()).unwrap();
+		Pallet::<T>::on_initialize(Zero::zero());
+
+	}: _(RawOrigin::Signed(caller), CURRENCY_ID_A.into(), T::Balance::zero(), 0)
+
+	unlock_stake {
+		let caller: T::AccountId = whitelisted_caller();
+
+		init_test_mock();
+
+		Pallet::<T>::set_currency_group(RawOrigin::Root.into(), CURRENCY_ID_A.into(), GROUP_A.into()).unwrap();
+		Pallet::<T>::apply_epoch_changes(&mut Default::default()).unwrap();
+		Pallet::<T>::on_initialize(Zero::zero());
+
+		// Worst case: the lock has not expired, so part of the reward is forfeited
+		Pallet::<T>::lock_stake(RawOrigin::Signed(caller.clone()).into(), CURRENCY_ID_A.into(), T::Balance::zero(), 0).unwrap();
+
+	}: _(RawOrigin::Signed(caller.clone()), CURRENCY_ID_A.into(), caller)
+
 }
 
 impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
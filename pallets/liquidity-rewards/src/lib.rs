@@ -26,6 +26,27 @@
 //! - Stake/Unstake a currency amount.
 //! - Claim the reward given to a staked currency.
 //! - Admin methods to configure epochs, currencies and reward groups.
+//!
+//! Unstaking happens in two phases. Unstaking an amount stops it from
+//! accruing rewards right away, but the amount is kept held in an exit queue
+//! until `UnstakeDelay` has passed, so it can still be accounted for by any
+//! pending slashing or invariant check. Once matured, the amount can be
+//! withdrawn with [`Pallet::withdraw_unstaked()`].
+//!
+//! The reward of a group can follow an emission curve, which decays the
+//! portion of its reward emitted each epoch down to a floor.
+//!
+//! Besides the native reward, the stakers of a currency can be rewarded in
+//! other currencies by co-incentives, i.e. a pool rewarding the stakers of its
+//! tranche tokens with its own tokens. Each co-incentive is configured by an
+//! admin and funded by its sponsor, and distributes a fixed amount each epoch
+//! among the current stakers of the currency until its funds are exhausted.
+//! Co-incentive rewards are claimed along with the native reward.
+//!
+//! Stakers can lock part of their stake for one of the `LockOptions` of the
+//! reward system to earn boosted rewards. A locked stake can not be unstaked
+//! until it is unlocked, and unlocking it before the lock expires forfeits
+//! part of its pending reward to the remaining stakers.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(test)]
@@ -39,16 +60,18 @@ pub mod weights;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+use cfg_traits::hooks::HookWeightStorage;
 pub use cfg_traits::rewards::{
-	AccountRewards, CurrencyGroupChange, DistributedRewards, GroupRewards,
+	AccountRewards, CurrencyGroupChange, DistributedRewards, GroupRewards, StakeLocks,
 };
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
-		tokens::{AssetId, Balance},
+		fungibles::{Mutate, MutateHold},
+		tokens::{AssetId, Balance, Precision, Preservation},
 		Time,
 	},
-	DefaultNoBound,
+	DefaultNoBound, PalletId,
 };
 pub use frame_support::{
 	storage::{bounded_btree_map::BoundedBTreeMap, transactional},
@@ -58,10 +81,13 @@ use frame_system::pallet_prelude::*;
 use num_traits::sign::Unsigned;
 pub use pallet::*;
 use sp_runtime::{
-	traits::{EnsureAdd, Zero},
-	FixedPointOperand,
+	traits::{
+		AccountIdConversion, EnsureAdd, EnsureAddAssign, EnsureSub, EnsureSubAssign, Saturating,
+		Zero,
+	},
+	ArithmeticError, FixedPointNumber, FixedPointOperand, FixedU128, PerThing, Perbill,
 };
-use sp_std::mem;
+use sp_std::{marker::PhantomData, mem, vec::Vec};
 pub use weights::WeightInfo;
 
 /// Type that contains the associated data of an epoch
@@ -105,8 +131,115 @@ pub struct EpochChanges<T: Config> {
 	currencies: BoundedBTreeMap<T::CurrencyId, T::GroupId, T::MaxChangesPerEpoch>,
 }
 
+/// Amount unstaked that is waiting in the exit queue.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct UnstakeRequest<Balance, Moment> {
+	/// Amount unstaked, still held in the account.
+	pub amount: Balance,
+
+	/// Moment from which the amount can be withdrawn.
+	pub matures_at: Moment,
+}
+
+/// Decaying emission of the reward of a group.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct EmissionCurve {
+	/// Portion of the emission by which it decays each epoch.
+	pub decay: Perbill,
+
+	/// Minimum portion of the group reward emitted once decayed.
+	pub floor: Perbill,
+}
+
+/// Emission curve of a group and the number of epochs emitted with it.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct GroupEmission {
+	pub curve: EmissionCurve,
+	pub epochs: u32,
+}
+
+impl GroupEmission {
+	/// Portion of the group reward emitted in the current epoch.
+	pub fn emitted(&self) -> Perbill {
+		self.curve
+			.decay
+			.left_from_one()
+			.saturating_pow(self.epochs as usize)
+			.max(self.curve.floor)
+	}
+}
+
+/// Reward in a non native currency given to the stakers of a currency.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct CoIncentive<AccountId, CurrencyId, Balance> {
+	/// Currency whose stakers are rewarded.
+	pub staked_currency: CurrencyId,
+
+	/// Currency of the reward.
+	pub reward_currency: CurrencyId,
+
+	/// Account funding the reward, which receives the remaining funds when
+	/// withdrawn.
+	pub sponsor: AccountId,
+
+	/// Amount distributed each epoch while there are remaining funds.
+	pub reward_per_epoch: Balance,
+
+	/// Funds not distributed yet.
+	pub remaining: Balance,
+
+	/// Reward accumulated per staked unit.
+	pub rpt: FixedU128,
+}
+
+/// Co-incentive reward of an account for a staked currency.
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, TypeInfo, MaxEncodedLen, RuntimeDebug)]
+pub struct CoIncentiveTally<Balance> {
+	/// Reward per staked unit of the co-incentive multiplied by the stake of
+	/// the account when it last changed or claimed.
+	pub reward_tally: Balance,
+
+	/// Reward accumulated before the last change of stake and not claimed
+	/// yet.
+	pub pending: Balance,
+}
+
 pub type MomentOf<T> = <<T as Config>::Timer as Time>::Moment;
 
+pub type UnstakeRequestOf<T> = UnstakeRequest<<T as Config>::Balance, MomentOf<T>>;
+
+pub type CoIncentiveOf<T> = CoIncentive<
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::CurrencyId,
+	<T as Config>::Balance,
+>;
+
+/// Group rewards emitting the portion of the reward given by the emission
+/// curve of each group.
+struct EmittedRewards<T>(PhantomData<T>);
+
+impl<T: Config> GroupRewards for EmittedRewards<T> {
+	type Balance = T::Balance;
+	type GroupId = T::GroupId;
+
+	fn is_ready(group_id: T::GroupId) -> bool {
+		T::Rewards::is_ready(group_id)
+	}
+
+	fn reward_group(group_id: T::GroupId, reward: T::Balance) -> Result<T::Balance, DispatchError> {
+		let emitted = EmissionCurves::<T>::get()
+			.get(&group_id)
+			.map(GroupEmission::emitted)
+			.unwrap_or(Perbill::one());
+
+		T::Rewards::reward_group(group_id, emitted.mul_floor(reward))
+	}
+
+	fn group_stake(group_id: T::GroupId) -> T::Balance {
+		T::Rewards::group_stake(group_id)
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -135,7 +268,17 @@ pub mod pallet {
 		type Rewards: GroupRewards<Balance = Self::Balance, GroupId = Self::GroupId>
 			+ AccountRewards<Self::AccountId, Balance = Self::Balance, CurrencyId = Self::CurrencyId>
 			+ CurrencyGroupChange<GroupId = Self::GroupId, CurrencyId = Self::CurrencyId>
-			+ DistributedRewards<Balance = Self::Balance, GroupId = Self::GroupId>;
+			+ DistributedRewards<Balance = Self::Balance, GroupId = Self::GroupId>
+			+ StakeLocks<Self::AccountId>;
+
+		/// Type used to hold the unstaked amounts until they mature and to
+		/// transfer the co-incentive rewards.
+		type Currency: MutateHold<
+				Self::AccountId,
+				AssetId = Self::CurrencyId,
+				Balance = Self::Balance,
+				Reason = (),
+			> + Mutate<Self::AccountId>;
 
 		type Timer: Time;
 
@@ -146,8 +289,9 @@ pub mod pallet {
 		type MaxGroups: Get<u32> + TypeInfo;
 
 		/// Max number of changes of the same type enqueued to apply in the next
-		/// epoch. Max calls to [`Pallet::set_group_weight()`] or to
-		/// [`Pallet::set_currency_group()`] with the same id.
+		/// epoch. Max calls to [`Pallet::set_group_weight()`], to
+		/// [`Pallet::set_currency_group()`] or to
+		/// [`Pallet::set_group_emission_curve()`] with the same id.
 		#[pallet::constant]
 		type MaxChangesPerEpoch: Get<u32> + TypeInfo + sp_std::fmt::Debug + Clone + PartialEq;
 
@@ -157,6 +301,24 @@ pub mod pallet {
 		#[pallet::constant]
 		type InitialEpochDuration: Get<MomentOf<Self>>;
 
+		/// Time an unstaked amount stays in the exit queue before it can be
+		/// withdrawn.
+		#[pallet::constant]
+		type UnstakeDelay: Get<MomentOf<Self>>;
+
+		/// Max number of unstake requests of an account for a currency
+		/// waiting in the exit queue.
+		#[pallet::constant]
+		type MaxUnstakeRequests: Get<u32>;
+
+		/// Max number of co-incentives among all currencies.
+		#[pallet::constant]
+		type MaxCoIncentives: Get<u32>;
+
+		/// Identifier of the account holding the co-incentive funds.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
 		/// Information of runtime weights
 		type WeightInfo: WeightInfo;
 	}
@@ -183,6 +345,55 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type NextEpochChanges<T: Config> = StorageValue<_, EpochChanges<T>, ValueQuery>;
 
+	/// Weight consumed by the hooks of this pallet and the block it was
+	/// consumed in.
+	#[pallet::storage]
+	pub type LastHookWeight<T: Config> = StorageValue<_, (BlockNumberFor<T>, Weight), OptionQuery>;
+
+	/// Unstake requests of an account for a currency waiting to be withdrawn,
+	/// in the order they were requested.
+	#[pallet::storage]
+	pub(super) type ExitQueue<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::CurrencyId,
+		BoundedVec<UnstakeRequestOf<T>, T::MaxUnstakeRequests>,
+		ValueQuery,
+	>;
+
+	/// Emission curves of the groups used to reward the current epoch.
+	#[pallet::storage]
+	pub(super) type EmissionCurves<T: Config> =
+		StorageValue<_, BoundedBTreeMap<T::GroupId, GroupEmission, T::MaxGroups>, ValueQuery>;
+
+	/// Emission curves set or removed when the current epoch finalizes.
+	/// Once they are applied, it's reset.
+	#[pallet::storage]
+	pub(super) type NextEmissionCurves<T: Config> = StorageValue<
+		_,
+		BoundedBTreeMap<T::GroupId, Option<EmissionCurve>, T::MaxChangesPerEpoch>,
+		ValueQuery,
+	>;
+
+	/// Co-incentives of all currencies.
+	#[pallet::storage]
+	pub(super) type CoIncentives<T: Config> =
+		StorageValue<_, BoundedVec<CoIncentiveOf<T>, T::MaxCoIncentives>, ValueQuery>;
+
+	/// Co-incentive rewards of an account, by staked and reward currency.
+	#[pallet::storage]
+	pub(super) type CoIncentiveTallies<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		(T::CurrencyId, T::CurrencyId),
+		CoIncentiveTally<T::Balance>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -191,13 +402,65 @@ pub mod pallet {
 			reward: T::Balance,
 			last_changes: EpochChanges<T>,
 		},
+		UnstakeRequested {
+			currency_id: T::CurrencyId,
+			account_id: T::AccountId,
+			amount: T::Balance,
+			matures_at: MomentOf<T>,
+		},
+		UnstakeWithdrawn {
+			currency_id: T::CurrencyId,
+			account_id: T::AccountId,
+			amount: T::Balance,
+		},
+		CoIncentiveSet {
+			staked_currency: T::CurrencyId,
+			reward_currency: T::CurrencyId,
+			sponsor: T::AccountId,
+			reward_per_epoch: T::Balance,
+		},
+		CoIncentiveFunded {
+			staked_currency: T::CurrencyId,
+			reward_currency: T::CurrencyId,
+			amount: T::Balance,
+		},
+		CoIncentiveWithdrawn {
+			staked_currency: T::CurrencyId,
+			reward_currency: T::CurrencyId,
+			amount: T::Balance,
+		},
+		CoIncentiveRewardClaimed {
+			staked_currency: T::CurrencyId,
+			reward_currency: T::CurrencyId,
+			account_id: T::AccountId,
+			amount: T::Balance,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
-		/// Limit of max calls with same id to [`Pallet::set_group_weight()`] or
-		/// [`Pallet::set_currency_group()`] reached.
+		/// Limit of max calls with same id to [`Pallet::set_group_weight()`],
+		/// [`Pallet::set_currency_group()`] or
+		/// [`Pallet::set_group_emission_curve()`] reached.
 		MaxChangesPerEpochReached,
+
+		/// Limit of unstake requests waiting in the exit queue reached.
+		MaxUnstakeRequestsReached,
+
+		/// There is no matured unstake request to withdraw.
+		NoMaturedUnstakeRequests,
+
+		/// Limit of co-incentives reached.
+		MaxCoIncentivesReached,
+
+		/// There is no co-incentive for the staked and reward currencies.
+		CoIncentiveNotFound,
+
+		/// The origin is not the sponsor of the co-incentive.
+		NotCoIncentiveSponsor,
+
+		/// Only the staker can unlock a stake before its lock expires.
+		LockNotExpired,
 	}
 
 	#[derive(Default)]
@@ -205,19 +468,35 @@ pub mod pallet {
 		groups: u32,
 		weights: u32,
 		currencies: u32,
+		co_incentives: u32,
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+		fn on_initialize(block: BlockNumberFor<T>) -> Weight {
 			let now = T::Timer::now();
 			if now < EndOfEpoch::<T>::get() {
 				// Not ready yet to change the epoch
 				return T::DbWeight::get().reads(1);
 			}
 
+			let weight = Self::finalize_epoch(now);
+			if !LastHookWeight::<T>::record(block, weight) {
+				return weight;
+			}
+
+			weight.saturating_add(T::DbWeight::get().writes(1))
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Finalizes the current epoch, which is over at `now`, and returns the
+		/// consumed weight.
+		fn finalize_epoch(now: MomentOf<T>) -> Weight {
 			let mut counter = ChangeCounter::default();
 			transactional::with_storage_layer(|| -> DispatchResult {
+				counter.co_incentives = Self::distribute_co_incentives()?;
+
 				let (epoch_data, last_changes) = Self::apply_epoch_changes(&mut counter)?;
 
 				let ends_on = now.ensure_add(epoch_data.duration)?;
@@ -235,21 +514,47 @@ pub mod pallet {
 			.ok();
 
 			T::WeightInfo::on_initialize(counter.groups, counter.weights, counter.currencies)
+				.saturating_add(
+					T::DbWeight::get()
+						.reads_writes(counter.co_incentives.saturating_add(3).into(), 3),
+				)
+		}
+
+		/// Weight consumed by the hooks of this pallet in the current block.
+		pub fn hook_weight() -> Weight {
+			LastHookWeight::<T>::consumed_in(frame_system::Pallet::<T>::block_number())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
 		pub fn apply_epoch_changes(
 			counter: &mut ChangeCounter,
 		) -> Result<(EpochData<T>, EpochChanges<T>), DispatchError> {
 			NextEpochChanges::<T>::try_mutate(|changes| {
 				ActiveEpochData::<T>::try_mutate(|epoch_data| {
-					counter.groups = T::Rewards::distribute_reward_with_weights(
+					counter.groups = EmittedRewards::<T>::distribute_reward_with_weights(
 						epoch_data.reward,
 						epoch_data.weights.iter().map(|(g, w)| (*g, *w)),
 					)
 					.map(|results| results.len() as u32)?;
 
+					EmissionCurves::<T>::mutate(|curves| {
+						for (_, emission) in curves.iter_mut() {
+							emission.epochs.saturating_inc();
+						}
+
+						for (group_id, curve) in NextEmissionCurves::<T>::take() {
+							match curve {
+								Some(curve) => {
+									curves
+										.try_insert(group_id, GroupEmission { curve, epochs: 0 })
+										.ok();
+								}
+								None => {
+									curves.remove(&group_id);
+								}
+							}
+						}
+					});
+
 					for (&group_id, &weight) in &changes.weights {
 						epoch_data.weights.try_insert(group_id, weight).ok();
 						counter.weights += 1;
@@ -267,6 +572,221 @@ pub mod pallet {
 				})
 			})
 		}
+
+		/// Unstake requests of an account for a currency, in queue order.
+		/// The index of a request is its position in the exit queue.
+		pub fn unstake_requests(
+			account_id: &T::AccountId,
+			currency_id: &T::CurrencyId,
+		) -> Vec<UnstakeRequestOf<T>> {
+			ExitQueue::<T>::get(account_id, currency_id).into_inner()
+		}
+
+		/// Amount of an account for a currency waiting in the exit queue.
+		/// This amount no longer accrues rewards but is still held, so it must
+		/// be accounted as stake by slashing or invariant checks.
+		pub fn queued_unstake(
+			account_id: &T::AccountId,
+			currency_id: &T::CurrencyId,
+		) -> Result<T::Balance, DispatchError> {
+			ExitQueue::<T>::get(account_id, currency_id)
+				.iter()
+				.try_fold(T::Balance::zero(), |total, request| {
+					total.ensure_add(request.amount)
+				})
+				.map_err(Into::into)
+		}
+
+		/// Co-incentive rewards of an account for a staked currency not
+		/// claimed yet, by reward currency.
+		pub fn pending_co_incentives(
+			account_id: &T::AccountId,
+			currency_id: &T::CurrencyId,
+		) -> Result<Vec<(T::CurrencyId, T::Balance)>, DispatchError> {
+			let stake = T::Rewards::account_stake(currency_id.clone(), account_id);
+
+			Self::co_incentives_of(currency_id)
+				.into_iter()
+				.map(|co_incentive| {
+					let reward = Self::co_incentive_reward(&co_incentive, account_id, stake)?;
+					Ok((co_incentive.reward_currency, reward))
+				})
+				.collect()
+		}
+
+		/// Weight of settling the co-incentives of a currency when the stake
+		/// of an account changes or its reward is claimed.
+		pub fn co_incentives_weight() -> Weight {
+			let co_incentives = u64::from(T::MaxCoIncentives::get());
+
+			T::DbWeight::get().reads_writes(
+				co_incentives.saturating_mul(3).saturating_add(1),
+				co_incentives.saturating_mul(3),
+			)
+		}
+
+		fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account_truncating()
+		}
+
+		fn co_incentives_of(currency_id: &T::CurrencyId) -> Vec<CoIncentiveOf<T>> {
+			CoIncentives::<T>::get()
+				.into_iter()
+				.filter(|co_incentive| co_incentive.staked_currency == *currency_id)
+				.collect()
+		}
+
+		fn co_incentive_reward(
+			co_incentive: &CoIncentiveOf<T>,
+			account_id: &T::AccountId,
+			stake: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let tally = CoIncentiveTallies::<T>::get(
+				account_id,
+				(
+					co_incentive.staked_currency.clone(),
+					co_incentive.reward_currency.clone(),
+				),
+			);
+
+			Ok(co_incentive
+				.rpt
+				.saturating_mul_int(stake)
+				.ensure_sub(tally.reward_tally)?
+				.ensure_add(tally.pending)?)
+		}
+
+		/// Distributes the reward of an epoch of each co-incentive among the
+		/// current stakers of its currency and returns the number of
+		/// co-incentives distributed.
+		fn distribute_co_incentives() -> Result<u32, DispatchError> {
+			CoIncentives::<T>::try_mutate(|co_incentives| {
+				let mut distributed = 0;
+				for co_incentive in co_incentives.iter_mut() {
+					let reward = co_incentive.reward_per_epoch.min(co_incentive.remaining);
+					if reward.is_zero() {
+						continue;
+					}
+
+					let stake = T::Rewards::currency_stake(co_incentive.staked_currency.clone());
+					if stake.is_zero() {
+						continue;
+					}
+
+					let rate = FixedU128::checked_from_rational(reward, stake)
+						.ok_or(ArithmeticError::Overflow)?;
+
+					co_incentive.rpt.ensure_add_assign(rate)?;
+					co_incentive.remaining.ensure_sub_assign(reward)?;
+					distributed += 1;
+				}
+
+				Ok(distributed)
+			})
+		}
+
+		/// Changes the stake of an account keeping the co-incentive rewards
+		/// accumulated with the previous stake.
+		fn change_stake(
+			currency_id: &T::CurrencyId,
+			account_id: &T::AccountId,
+			change: impl FnOnce() -> DispatchResult,
+		) -> DispatchResult {
+			let co_incentives = Self::co_incentives_of(currency_id);
+			if co_incentives.is_empty() {
+				return change();
+			}
+
+			let stake = T::Rewards::account_stake(currency_id.clone(), account_id);
+			let rewards = co_incentives
+				.iter()
+				.map(|co_incentive| Self::co_incentive_reward(co_incentive, account_id, stake))
+				.collect::<Result<Vec<_>, DispatchError>>()?;
+
+			change()?;
+
+			let stake = T::Rewards::account_stake(currency_id.clone(), account_id);
+			for (co_incentive, pending) in co_incentives.into_iter().zip(rewards) {
+				CoIncentiveTallies::<T>::insert(
+					account_id,
+					(co_incentive.staked_currency, co_incentive.reward_currency),
+					CoIncentiveTally {
+						reward_tally: co_incentive.rpt.saturating_mul_int(stake),
+						pending,
+					},
+				);
+			}
+
+			Ok(())
+		}
+
+		/// Transfers the co-incentive rewards of an account for a staked
+		/// currency.
+		fn claim_co_incentives(
+			currency_id: &T::CurrencyId,
+			account_id: &T::AccountId,
+		) -> DispatchResult {
+			let co_incentives = Self::co_incentives_of(currency_id);
+			if co_incentives.is_empty() {
+				return Ok(());
+			}
+
+			let stake = T::Rewards::account_stake(currency_id.clone(), account_id);
+			for co_incentive in co_incentives {
+				let reward = Self::co_incentive_reward(&co_incentive, account_id, stake)?;
+
+				CoIncentiveTallies::<T>::insert(
+					account_id,
+					(
+						co_incentive.staked_currency.clone(),
+						co_incentive.reward_currency.clone(),
+					),
+					CoIncentiveTally {
+						reward_tally: co_incentive.rpt.saturating_mul_int(stake),
+						pending: T::Balance::zero(),
+					},
+				);
+
+				if reward.is_zero() {
+					continue;
+				}
+
+				T::Currency::transfer(
+					co_incentive.reward_currency.clone(),
+					&Self::account_id(),
+					account_id,
+					reward,
+					Preservation::Expendable,
+				)?;
+
+				Self::deposit_event(Event::CoIncentiveRewardClaimed {
+					staked_currency: co_incentive.staked_currency,
+					reward_currency: co_incentive.reward_currency,
+					account_id: account_id.clone(),
+					amount: reward,
+				});
+			}
+
+			Ok(())
+		}
+
+		fn mutate_co_incentive<R>(
+			staked_currency: &T::CurrencyId,
+			reward_currency: &T::CurrencyId,
+			f: impl FnOnce(&mut CoIncentiveOf<T>) -> Result<R, DispatchError>,
+		) -> Result<R, DispatchError> {
+			CoIncentives::<T>::try_mutate(|co_incentives| {
+				let co_incentive = co_incentives
+					.iter_mut()
+					.find(|co_incentive| {
+						co_incentive.staked_currency == *staked_currency
+							&& co_incentive.reward_currency == *reward_currency
+					})
+					.ok_or(Error::<T>::CoIncentiveNotFound)?;
+
+				f(co_incentive)
+			})
+		}
 	}
 
 	#[pallet::call]
@@ -274,7 +794,7 @@ pub mod pallet {
 		/// Deposit a stake amount associated to a currency for the origin's
 		/// account. The account must have enough currency to make the deposit,
 		/// if not, an Err will be returned.
-		#[pallet::weight(T::WeightInfo::stake())]
+		#[pallet::weight(T::WeightInfo::stake().saturating_add(Pallet::<T>::co_incentives_weight()))]
 		#[transactional]
 		#[pallet::call_index(0)]
 		pub fn stake(
@@ -284,13 +804,19 @@ pub mod pallet {
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
 
-			T::Rewards::deposit_stake(currency_id, &account_id, amount)
+			Self::change_stake(&currency_id, &account_id, || {
+				T::Rewards::deposit_stake(currency_id.clone(), &account_id, amount)
+			})
 		}
 
 		/// Withdraw a stake amount associated to a currency for the origin's
 		/// account. The account must have enough currency staked to make the
 		/// withdraw, if not, an Err will be returned.
-		#[pallet::weight(T::WeightInfo::unstake())]
+		///
+		/// The amount stops accruing rewards but is kept held in the exit
+		/// queue until `UnstakeDelay` has passed. Then, it can be released
+		/// with [`Pallet::withdraw_unstaked()`].
+		#[pallet::weight(T::WeightInfo::unstake().saturating_add(Pallet::<T>::co_incentives_weight()))]
 		#[transactional]
 		#[pallet::call_index(1)]
 		pub fn unstake(
@@ -300,17 +826,40 @@ pub mod pallet {
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
 
-			T::Rewards::withdraw_stake(currency_id, &account_id, amount)
+			Self::change_stake(&currency_id, &account_id, || {
+				T::Rewards::withdraw_stake(currency_id.clone(), &account_id, amount)
+			})?;
+			T::Currency::hold(currency_id.clone(), &(), &account_id, amount)?;
+
+			let matures_at = T::Timer::now().ensure_add(T::UnstakeDelay::get())?;
+
+			ExitQueue::<T>::try_mutate(&account_id, &currency_id, |queue| {
+				queue
+					.try_push(UnstakeRequest { amount, matures_at })
+					.map_err(|_| Error::<T>::MaxUnstakeRequestsReached)
+			})?;
+
+			Self::deposit_event(Event::UnstakeRequested {
+				currency_id,
+				account_id,
+				amount,
+				matures_at,
+			});
+
+			Ok(())
 		}
 
-		/// Claims the reward the associated to a currency.
+		/// Claims the reward the associated to a currency, including the
+		/// rewards of its co-incentives.
 		/// The reward will be transferred to the origin's account.
-		#[pallet::weight(T::WeightInfo::claim_reward())]
+		#[pallet::weight(T::WeightInfo::claim_reward().saturating_add(Pallet::<T>::co_incentives_weight()))]
 		#[transactional]
 		#[pallet::call_index(2)]
 		pub fn claim_reward(origin: OriginFor<T>, currency_id: T::CurrencyId) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
 
+			Self::claim_co_incentives(&currency_id, &account_id)?;
+
 			T::Rewards::claim_reward(currency_id, &account_id).map(|_| ())
 		}
 
@@ -381,5 +930,250 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Releases the unstaked amounts of a currency for the origin's
+		/// account whose unstake requests have matured. If none has matured
+		/// yet, an Err will be returned.
+		#[pallet::weight(T::WeightInfo::withdraw_unstaked())]
+		#[transactional]
+		#[pallet::call_index(7)]
+		pub fn withdraw_unstaked(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+
+			let now = T::Timer::now();
+			let (matured, pending): (Vec<_>, Vec<_>) =
+				ExitQueue::<T>::get(&account_id, &currency_id)
+					.into_iter()
+					.partition(|request| request.matures_at <= now);
+
+			ensure!(!matured.is_empty(), Error::<T>::NoMaturedUnstakeRequests);
+
+			let amount = matured
+				.iter()
+				.try_fold(T::Balance::zero(), |total, request| {
+					total.ensure_add(request.amount)
+				})?;
+
+			if pending.is_empty() {
+				ExitQueue::<T>::remove(&account_id, &currency_id);
+			} else {
+				ExitQueue::<T>::insert(
+					&account_id,
+					&currency_id,
+					BoundedVec::truncate_from(pending),
+				);
+			}
+
+			T::Currency::release(
+				currency_id.clone(),
+				&(),
+				&account_id,
+				amount,
+				Precision::Exact,
+			)?;
+
+			Self::deposit_event(Event::UnstakeWithdrawn {
+				currency_id,
+				account_id,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Admin method to set the emission curve of a group used for the
+		/// next epochs, or to remove it if `None`. The emission of the group
+		/// decays from the first of those epochs. Current epoch is not
+		/// affected by this call.
+		#[pallet::weight(T::WeightInfo::set_group_emission_curve())]
+		#[pallet::call_index(8)]
+		pub fn set_group_emission_curve(
+			origin: OriginFor<T>,
+			group_id: T::GroupId,
+			curve: Option<EmissionCurve>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			NextEmissionCurves::<T>::try_mutate(|curves| {
+				curves
+					.try_insert(group_id, curve)
+					.map_err(|_| Error::<T>::MaxChangesPerEpochReached)
+			})?;
+
+			Ok(())
+		}
+
+		/// Admin method to create or update the co-incentive rewarding the
+		/// stakers of a currency in another currency. The reward per epoch
+		/// is used from the next distribution.
+		#[pallet::weight(T::WeightInfo::set_co_incentive())]
+		#[pallet::call_index(9)]
+		pub fn set_co_incentive(
+			origin: OriginFor<T>,
+			staked_currency: T::CurrencyId,
+			reward_currency: T::CurrencyId,
+			sponsor: T::AccountId,
+			reward_per_epoch: T::Balance,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			CoIncentives::<T>::try_mutate(|co_incentives| -> DispatchResult {
+				match co_incentives.iter_mut().find(|co_incentive| {
+					co_incentive.staked_currency == staked_currency
+						&& co_incentive.reward_currency == reward_currency
+				}) {
+					Some(co_incentive) => {
+						co_incentive.sponsor = sponsor.clone();
+						co_incentive.reward_per_epoch = reward_per_epoch;
+					}
+					None => co_incentives
+						.try_push(CoIncentive {
+							staked_currency: staked_currency.clone(),
+							reward_currency: reward_currency.clone(),
+							sponsor: sponsor.clone(),
+							reward_per_epoch,
+							remaining: T::Balance::zero(),
+							rpt: FixedU128::zero(),
+						})
+						.map_err(|_| Error::<T>::MaxCoIncentivesReached)?,
+				}
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CoIncentiveSet {
+				staked_currency,
+				reward_currency,
+				sponsor,
+				reward_per_epoch,
+			});
+
+			Ok(())
+		}
+
+		/// Adds funds to a co-incentive. The origin must be its sponsor.
+		#[pallet::weight(T::WeightInfo::fund_co_incentive())]
+		#[transactional]
+		#[pallet::call_index(10)]
+		pub fn fund_co_incentive(
+			origin: OriginFor<T>,
+			staked_currency: T::CurrencyId,
+			reward_currency: T::CurrencyId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::mutate_co_incentive(&staked_currency, &reward_currency, |co_incentive| {
+				ensure!(
+					co_incentive.sponsor == who,
+					Error::<T>::NotCoIncentiveSponsor
+				);
+				co_incentive.remaining.ensure_add_assign(amount)?;
+				Ok(())
+			})?;
+
+			T::Currency::transfer(
+				reward_currency.clone(),
+				&who,
+				&Self::account_id(),
+				amount,
+				Preservation::Expendable,
+			)?;
+
+			Self::deposit_event(Event::CoIncentiveFunded {
+				staked_currency,
+				reward_currency,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Returns the funds of a co-incentive not distributed yet to its
+		/// sponsor, which stops its distribution until funded again. The
+		/// origin must be its sponsor.
+		#[pallet::weight(T::WeightInfo::withdraw_co_incentive())]
+		#[transactional]
+		#[pallet::call_index(11)]
+		pub fn withdraw_co_incentive(
+			origin: OriginFor<T>,
+			staked_currency: T::CurrencyId,
+			reward_currency: T::CurrencyId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let amount =
+				Self::mutate_co_incentive(&staked_currency, &reward_currency, |co_incentive| {
+					ensure!(
+						co_incentive.sponsor == who,
+						Error::<T>::NotCoIncentiveSponsor
+					);
+					Ok(mem::take(&mut co_incentive.remaining))
+				})?;
+
+			T::Currency::transfer(
+				reward_currency.clone(),
+				&Self::account_id(),
+				&who,
+				amount,
+				Preservation::Expendable,
+			)?;
+
+			Self::deposit_event(Event::CoIncentiveWithdrawn {
+				staked_currency,
+				reward_currency,
+				amount,
+			});
+
+			Ok(())
+		}
+
+		/// Locks an amount already staked by the origin's account for the
+		/// duration of the lock option given by its index in `LockOptions`.
+		/// While locked, the amount can not be unstaked and earns rewards
+		/// boosted by the multiplier of the option.
+		#[pallet::weight(T::WeightInfo::lock_stake().saturating_add(Pallet::<T>::co_incentives_weight()))]
+		#[transactional]
+		#[pallet::call_index(12)]
+		pub fn lock_stake(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+			option: u32,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+
+			Self::change_stake(&currency_id, &account_id, || {
+				T::Rewards::lock_stake(currency_id.clone(), &account_id, amount, option)
+			})
+		}
+
+		/// Unlocks the stake locked by an account, removing its boost.
+		///
+		/// The staker can unlock it at any time, forfeiting part of the
+		/// reward pending if the lock has not expired yet. Once expired, any
+		/// origin can unlock it on behalf of the staker without penalty.
+		#[pallet::weight(T::WeightInfo::unlock_stake().saturating_add(Pallet::<T>::co_incentives_weight()))]
+		#[transactional]
+		#[pallet::call_index(13)]
+		pub fn unlock_stake(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			account_id: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				who == account_id || T::Rewards::is_lock_expired(currency_id.clone(), &account_id),
+				Error::<T>::LockNotExpired
+			);
+
+			Self::change_stake(&currency_id, &account_id, || {
+				T::Rewards::unlock_stake(currency_id.clone(), &account_id).map(|_| ())
+			})
+		}
 	}
 }
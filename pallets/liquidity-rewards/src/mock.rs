@@ -1,10 +1,11 @@
-use frame_support::{derive_impl, traits::ConstU64};
+use frame_support::{derive_impl, traits::ConstU64, PalletId};
 use frame_system::EnsureRoot;
 use sp_runtime::BuildStorage;
 
 use crate as pallet_liquidity_rewards;
 
 pub const INITIAL_EPOCH_DURATION: u64 = 23;
+pub const UNSTAKE_DELAY: u64 = 10;
 
 frame_support::construct_runtime!(
 	pub enum Runtime {
@@ -12,6 +13,7 @@ frame_support::construct_runtime!(
 		Liquidity: pallet_liquidity_rewards,
 		MockTime: cfg_mocks::pallet_mock_time,
 		MockRewards: cfg_mocks::pallet_mock_rewards,
+		Tokens: orml_tokens,
 	}
 );
 
@@ -21,6 +23,11 @@ frame_support::parameter_types! {
 
 	#[derive(scale_info::TypeInfo, Debug, PartialEq, Clone)]
 	pub const MaxChangesPerEpoch: u32 = 50;
+
+	pub const MaxUnstakeRequests: u32 = 3;
+
+	pub const MaxCoIncentives: u32 = 2;
+	pub const LiquidityRewardsPalletId: PalletId = PalletId(*b"lqrw/pot");
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
@@ -32,6 +39,24 @@ impl cfg_mocks::pallet_mock_time::Config for Runtime {
 	type Moment = u64;
 }
 
+orml_traits::parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: u32| -> u64 { 0 };
+}
+
+impl orml_tokens::Config for Runtime {
+	type Amount = i64;
+	type Balance = u64;
+	type CurrencyHooks = ();
+	type CurrencyId = u32;
+	type DustRemovalWhitelist = frame_support::traits::Nothing;
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
 impl cfg_mocks::pallet_mock_rewards::Config for Runtime {
 	type Balance = u64;
 	type CurrencyId = u32;
@@ -41,14 +66,19 @@ impl cfg_mocks::pallet_mock_rewards::Config for Runtime {
 impl pallet_liquidity_rewards::Config for Runtime {
 	type AdminOrigin = EnsureRoot<u64>;
 	type Balance = u64;
+	type Currency = Tokens;
 	type CurrencyId = u32;
 	type GroupId = u32;
 	type InitialEpochDuration = ConstU64<INITIAL_EPOCH_DURATION>;
 	type MaxChangesPerEpoch = MaxChangesPerEpoch;
+	type MaxCoIncentives = MaxCoIncentives;
 	type MaxGroups = MaxGroups;
+	type MaxUnstakeRequests = MaxUnstakeRequests;
+	type PalletId = LiquidityRewardsPalletId;
 	type Rewards = MockRewards;
 	type RuntimeEvent = RuntimeEvent;
 	type Timer = MockTime;
+	type UnstakeDelay = ConstU64<UNSTAKE_DELAY>;
 	type Weight = u64;
 	type WeightInfo = ();
 }
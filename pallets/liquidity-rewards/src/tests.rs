@@ -30,6 +30,20 @@ fn check_special_privileges() {
 			Liquidity::set_currency_group(RuntimeOrigin::signed(USER_A), CURRENCY_ID_A, GROUP_A),
 			BadOrigin
 		);
+		assert_noop!(
+			Liquidity::set_group_emission_curve(RuntimeOrigin::signed(USER_A), GROUP_A, None),
+			BadOrigin
+		);
+		assert_noop!(
+			Liquidity::set_co_incentive(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A,
+				CURRENCY_ID_A,
+				USER_A,
+				10
+			),
+			BadOrigin
+		);
 	});
 }
 
@@ -61,6 +75,22 @@ fn distributed_reward_change() {
 	});
 }
 
+#[test]
+fn hook_weight_recorded_on_epoch_change() {
+	new_test_ext().execute_with(|| {
+		// Blocks not changing the epoch are not recorded
+		MockTime::mock_now(|| INITIAL_EPOCH_DURATION - 1);
+		Liquidity::on_initialize(0);
+		assert!(LastHookWeight::<Runtime>::get().is_none());
+
+		MockTime::mock_now(|| INITIAL_EPOCH_DURATION);
+		let weight = Liquidity::on_initialize(0);
+
+		assert!(!Liquidity::hook_weight().is_zero());
+		assert!(weight.all_gte(Liquidity::hook_weight()));
+	});
+}
+
 #[test]
 fn epoch_change() {
 	const EPOCH_DURATION: u64 = 42;
@@ -311,3 +341,538 @@ fn discard_groups_exceed_max_grups() {
 		}
 	});
 }
+
+#[test]
+fn emission_curve_changes() {
+	const REWARD: u64 = 100;
+	const CURVE: EmissionCurve = EmissionCurve {
+		decay: Perbill::from_percent(50),
+		floor: Perbill::from_percent(20),
+	};
+
+	new_test_ext().execute_with(|| {
+		// EPOCH 0
+		assert_ok!(Liquidity::set_distributed_reward(
+			RuntimeOrigin::root(),
+			REWARD
+		));
+		assert_ok!(Liquidity::set_group_weight(
+			RuntimeOrigin::root(),
+			GROUP_A,
+			1
+		));
+		assert_ok!(Liquidity::set_group_emission_curve(
+			RuntimeOrigin::root(),
+			GROUP_A,
+			Some(CURVE)
+		));
+		assert_eq!(
+			NextEmissionCurves::<Runtime>::get().get(&GROUP_A),
+			Some(&Some(CURVE))
+		);
+		assert_eq!(EmissionCurves::<Runtime>::get().get(&GROUP_A), None);
+
+		MockTime::mock_now(|| INITIAL_EPOCH_DURATION);
+		Liquidity::on_initialize(0);
+
+		// EPOCH 1
+		assert!(NextEmissionCurves::<Runtime>::get().is_empty());
+		assert_eq!(
+			EmissionCurves::<Runtime>::get().get(&GROUP_A),
+			Some(&GroupEmission {
+				curve: CURVE,
+				epochs: 0
+			})
+		);
+
+		MockRewards::mock_is_ready(|_| true);
+
+		let mut epoch = 1;
+		let mut finalize_epoch = |emitted: u64| {
+			MockRewards::mock_reward_group(move |group_id, reward| {
+				assert_eq!(group_id, GROUP_A);
+				assert_eq!(reward, emitted);
+				Ok(reward)
+			});
+
+			epoch += 1;
+			MockTime::mock_now(move || INITIAL_EPOCH_DURATION * epoch);
+			Liquidity::on_initialize(0);
+		};
+
+		// The emission decays each epoch until reaching the floor
+		for emitted in [REWARD, REWARD / 2, REWARD / 4, REWARD / 5, REWARD / 5] {
+			finalize_epoch(emitted);
+		}
+
+		assert_ok!(Liquidity::set_group_emission_curve(
+			RuntimeOrigin::root(),
+			GROUP_A,
+			None
+		));
+
+		// The removal is applied once the current epoch is rewarded
+		finalize_epoch(REWARD / 5);
+		assert_eq!(EmissionCurves::<Runtime>::get().get(&GROUP_A), None);
+
+		finalize_epoch(REWARD);
+	});
+}
+
+mod co_incentives {
+	use std::{cell::Cell, rc::Rc};
+
+	use frame_support::traits::fungibles::{Inspect, Mutate};
+
+	use super::*;
+
+	const SPONSOR: u64 = 10;
+	const USER_B: u64 = 3;
+
+	const REWARD_CURRENCY: u32 = 5;
+	const REWARD_PER_EPOCH: u64 = 40;
+	const FUNDS: u64 = 1000;
+
+	fn balance(account_id: &u64) -> u64 {
+		Tokens::balance(REWARD_CURRENCY, account_id)
+	}
+
+	fn pot() -> u64 {
+		LiquidityRewardsPalletId::get().into_account_truncating()
+	}
+
+	fn co_incentive() -> CoIncentiveOf<Runtime> {
+		CoIncentives::<Runtime>::get()[0].clone()
+	}
+
+	fn finalize_epoch(epoch: u64) {
+		MockTime::mock_now(move || INITIAL_EPOCH_DURATION * epoch);
+		Liquidity::on_initialize(0);
+	}
+
+	fn create_co_incentive() {
+		assert_ok!(Tokens::mint_into(REWARD_CURRENCY, &SPONSOR, FUNDS));
+		assert_ok!(Liquidity::set_co_incentive(
+			RuntimeOrigin::root(),
+			CURRENCY_ID_A,
+			REWARD_CURRENCY,
+			SPONSOR,
+			REWARD_PER_EPOCH
+		));
+		assert_ok!(Liquidity::fund_co_incentive(
+			RuntimeOrigin::signed(SPONSOR),
+			CURRENCY_ID_A,
+			REWARD_CURRENCY,
+			FUNDS
+		));
+	}
+
+	/// Mocks the stake of `USER_A`, which changes when staking, and the
+	/// fixed stake of `USER_B`.
+	fn config_mocks(stake_a: u64, stake_b: u64) {
+		let stake = Rc::new(Cell::new(stake_a));
+
+		MockRewards::mock_account_stake({
+			let stake = stake.clone();
+			move |currency_id, account_id| {
+				assert_eq!(currency_id, CURRENCY_ID_A);
+				match *account_id {
+					USER_A => stake.get(),
+					USER_B => stake_b,
+					_ => 0,
+				}
+			}
+		});
+		MockRewards::mock_currency_stake({
+			let stake = stake.clone();
+			move |_| stake.get() + stake_b
+		});
+		MockRewards::mock_deposit_stake(move |_, _, amount| {
+			stake.set(stake.get() + amount);
+			Ok(())
+		});
+		MockRewards::mock_claim_reward(|_, _| Ok(0));
+	}
+
+	#[test]
+	fn fund_and_withdraw() {
+		new_test_ext().execute_with(|| {
+			create_co_incentive();
+
+			assert_eq!(balance(&SPONSOR), 0);
+			assert_eq!(balance(&pot()), FUNDS);
+			assert_eq!(co_incentive().remaining, FUNDS);
+
+			assert_ok!(Liquidity::withdraw_co_incentive(
+				RuntimeOrigin::signed(SPONSOR),
+				CURRENCY_ID_A,
+				REWARD_CURRENCY
+			));
+
+			assert_eq!(balance(&SPONSOR), FUNDS);
+			assert_eq!(balance(&pot()), 0);
+			assert_eq!(co_incentive().remaining, 0);
+		});
+	}
+
+	#[test]
+	fn only_sponsor() {
+		new_test_ext().execute_with(|| {
+			create_co_incentive();
+
+			assert_noop!(
+				Liquidity::fund_co_incentive(
+					RuntimeOrigin::signed(USER_A),
+					CURRENCY_ID_A,
+					REWARD_CURRENCY,
+					FUNDS
+				),
+				Error::<Runtime>::NotCoIncentiveSponsor
+			);
+			assert_noop!(
+				Liquidity::withdraw_co_incentive(
+					RuntimeOrigin::signed(USER_A),
+					CURRENCY_ID_A,
+					REWARD_CURRENCY
+				),
+				Error::<Runtime>::NotCoIncentiveSponsor
+			);
+			assert_noop!(
+				Liquidity::withdraw_co_incentive(
+					RuntimeOrigin::signed(SPONSOR),
+					CURRENCY_ID_A,
+					CURRENCY_ID_A
+				),
+				Error::<Runtime>::CoIncentiveNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn max_co_incentives() {
+		new_test_ext().execute_with(|| {
+			for reward_currency in 0..MaxCoIncentives::get() {
+				assert_ok!(Liquidity::set_co_incentive(
+					RuntimeOrigin::root(),
+					CURRENCY_ID_A,
+					reward_currency,
+					SPONSOR,
+					REWARD_PER_EPOCH
+				));
+			}
+
+			assert_noop!(
+				Liquidity::set_co_incentive(
+					RuntimeOrigin::root(),
+					CURRENCY_ID_A,
+					MaxCoIncentives::get(),
+					SPONSOR,
+					REWARD_PER_EPOCH
+				),
+				Error::<Runtime>::MaxCoIncentivesReached
+			);
+
+			// An existing co-incentive can still be updated
+			assert_ok!(Liquidity::set_co_incentive(
+				RuntimeOrigin::root(),
+				CURRENCY_ID_A,
+				0,
+				USER_A,
+				REWARD_PER_EPOCH * 2
+			));
+			assert_eq!(co_incentive().sponsor, USER_A);
+			assert_eq!(co_incentive().reward_per_epoch, REWARD_PER_EPOCH * 2);
+		});
+	}
+
+	#[test]
+	fn distribute_among_stakers() {
+		new_test_ext().execute_with(|| {
+			create_co_incentive();
+			config_mocks(100, 300);
+
+			finalize_epoch(0);
+
+			assert_eq!(co_incentive().remaining, FUNDS - REWARD_PER_EPOCH);
+			assert_eq!(
+				Liquidity::pending_co_incentives(&USER_A, &CURRENCY_ID_A),
+				Ok(vec![(REWARD_CURRENCY, REWARD_PER_EPOCH / 4)])
+			);
+			assert_eq!(
+				Liquidity::pending_co_incentives(&USER_B, &CURRENCY_ID_A),
+				Ok(vec![(REWARD_CURRENCY, REWARD_PER_EPOCH * 3 / 4)])
+			);
+
+			assert_ok!(Liquidity::claim_reward(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A
+			));
+
+			assert_eq!(balance(&USER_A), REWARD_PER_EPOCH / 4);
+			assert_eq!(
+				Liquidity::pending_co_incentives(&USER_A, &CURRENCY_ID_A),
+				Ok(vec![(REWARD_CURRENCY, 0)])
+			);
+		});
+	}
+
+	#[test]
+	fn stake_change_keeps_reward() {
+		new_test_ext().execute_with(|| {
+			create_co_incentive();
+			config_mocks(100, 0);
+
+			finalize_epoch(0);
+
+			// USER_A has all the stake when rewarded
+			assert_ok!(Liquidity::stake(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A,
+				300
+			));
+
+			finalize_epoch(1);
+
+			assert_eq!(
+				Liquidity::pending_co_incentives(&USER_A, &CURRENCY_ID_A),
+				Ok(vec![(REWARD_CURRENCY, REWARD_PER_EPOCH * 2)])
+			);
+		});
+	}
+
+	#[test]
+	fn stake_after_distribution() {
+		new_test_ext().execute_with(|| {
+			create_co_incentive();
+			config_mocks(0, 100);
+
+			finalize_epoch(0);
+
+			// USER_A stakes after the distribution
+			assert_ok!(Liquidity::stake(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A,
+				100
+			));
+
+			assert_eq!(
+				Liquidity::pending_co_incentives(&USER_A, &CURRENCY_ID_A),
+				Ok(vec![(REWARD_CURRENCY, 0)])
+			);
+
+			finalize_epoch(1);
+
+			assert_eq!(
+				Liquidity::pending_co_incentives(&USER_A, &CURRENCY_ID_A),
+				Ok(vec![(REWARD_CURRENCY, REWARD_PER_EPOCH / 2)])
+			);
+		});
+	}
+
+	#[test]
+	fn distribution_stops_without_funds() {
+		new_test_ext().execute_with(|| {
+			create_co_incentive();
+			config_mocks(100, 0);
+
+			assert_ok!(Liquidity::set_co_incentive(
+				RuntimeOrigin::root(),
+				CURRENCY_ID_A,
+				REWARD_CURRENCY,
+				SPONSOR,
+				FUNDS * 2 / 3
+			));
+
+			finalize_epoch(0);
+			finalize_epoch(1);
+			finalize_epoch(2);
+
+			assert_eq!(co_incentive().remaining, 0);
+			assert_eq!(
+				Liquidity::pending_co_incentives(&USER_A, &CURRENCY_ID_A),
+				Ok(vec![(REWARD_CURRENCY, FUNDS)])
+			);
+
+			assert_ok!(Liquidity::claim_reward(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A
+			));
+
+			assert_eq!(balance(&USER_A), FUNDS);
+			assert_eq!(balance(&pot()), 0);
+		});
+	}
+}
+
+mod exit_queue {
+	use frame_support::traits::fungibles::{InspectHold, Mutate};
+
+	use super::*;
+
+	const STAKE: u64 = 100;
+
+	fn config_mocks() {
+		MockRewards::mock_withdraw_stake(|currency_id, account_id, _| {
+			assert_eq!(currency_id, CURRENCY_ID_A);
+			assert_eq!(*account_id, USER_A);
+			Ok(())
+		});
+		MockTime::mock_now(|| 0);
+
+		// Emulates the stake already held
+		assert_ok!(Tokens::mint_into(CURRENCY_ID_A, &USER_A, STAKE));
+	}
+
+	fn held() -> u64 {
+		Tokens::balance_on_hold(CURRENCY_ID_A, &(), &USER_A)
+	}
+
+	#[test]
+	fn unstake_is_queued() {
+		new_test_ext().execute_with(|| {
+			config_mocks();
+
+			assert_ok!(Liquidity::unstake(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A,
+				STAKE
+			));
+
+			assert_eq!(held(), STAKE);
+			assert_eq!(
+				Liquidity::unstake_requests(&USER_A, &CURRENCY_ID_A),
+				vec![UnstakeRequest {
+					amount: STAKE,
+					matures_at: UNSTAKE_DELAY
+				}]
+			);
+			assert_eq!(
+				Liquidity::queued_unstake(&USER_A, &CURRENCY_ID_A),
+				Ok(STAKE)
+			);
+		});
+	}
+
+	#[test]
+	fn withdraw_before_maturity() {
+		new_test_ext().execute_with(|| {
+			config_mocks();
+
+			assert_ok!(Liquidity::unstake(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A,
+				STAKE
+			));
+
+			MockTime::mock_now(|| UNSTAKE_DELAY - 1);
+
+			assert_noop!(
+				Liquidity::withdraw_unstaked(RuntimeOrigin::signed(USER_A), CURRENCY_ID_A),
+				Error::<Runtime>::NoMaturedUnstakeRequests
+			);
+		});
+	}
+
+	#[test]
+	fn withdraw_only_matured() {
+		new_test_ext().execute_with(|| {
+			config_mocks();
+
+			assert_ok!(Liquidity::unstake(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A,
+				STAKE / 4
+			));
+
+			MockTime::mock_now(|| UNSTAKE_DELAY / 2);
+
+			assert_ok!(Liquidity::unstake(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A,
+				STAKE / 2
+			));
+
+			MockTime::mock_now(|| UNSTAKE_DELAY);
+
+			assert_ok!(Liquidity::withdraw_unstaked(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A
+			));
+
+			assert_eq!(held(), STAKE / 2);
+			assert_eq!(
+				orml_tokens::Accounts::<Runtime>::get(USER_A, CURRENCY_ID_A).free,
+				STAKE / 2
+			);
+			assert_eq!(
+				Liquidity::unstake_requests(&USER_A, &CURRENCY_ID_A),
+				vec![UnstakeRequest {
+					amount: STAKE / 2,
+					matures_at: UNSTAKE_DELAY / 2 + UNSTAKE_DELAY
+				}]
+			);
+
+			MockTime::mock_now(|| UNSTAKE_DELAY / 2 + UNSTAKE_DELAY);
+
+			assert_ok!(Liquidity::withdraw_unstaked(
+				RuntimeOrigin::signed(USER_A),
+				CURRENCY_ID_A
+			));
+
+			assert_eq!(held(), 0);
+			assert!(!ExitQueue::<Runtime>::contains_key(USER_A, CURRENCY_ID_A));
+		});
+	}
+
+	#[test]
+	fn max_unstake_requests() {
+		new_test_ext().execute_with(|| {
+			config_mocks();
+
+			for _ in 0..MaxUnstakeRequests::get() {
+				assert_ok!(Liquidity::unstake(
+					RuntimeOrigin::signed(USER_A),
+					CURRENCY_ID_A,
+					1
+				));
+			}
+
+			assert_noop!(
+				Liquidity::unstake(RuntimeOrigin::signed(USER_A), CURRENCY_ID_A, 1),
+				Error::<Runtime>::MaxUnstakeRequestsReached
+			);
+		});
+	}
+}
+
+#[test]
+fn unlock_stake_of_another_account() {
+	const USER_B: u64 = 3;
+
+	new_test_ext().execute_with(|| {
+		MockRewards::mock_unlock_stake(|currency_id, account_id| {
+			assert_eq!(currency_id, CURRENCY_ID_A);
+			assert_eq!(*account_id, USER_A);
+			Ok(0)
+		});
+
+		// Before the lock expires, only the staker can unlock it
+		MockRewards::mock_is_lock_expired(|_, _| false);
+		assert_noop!(
+			Liquidity::unlock_stake(RuntimeOrigin::signed(USER_B), CURRENCY_ID_A, USER_A),
+			Error::<Runtime>::LockNotExpired
+		);
+		assert_ok!(Liquidity::unlock_stake(
+			RuntimeOrigin::signed(USER_A),
+			CURRENCY_ID_A,
+			USER_A
+		));
+
+		MockRewards::mock_is_lock_expired(|_, _| true);
+		assert_ok!(Liquidity::unlock_stake(
+			RuntimeOrigin::signed(USER_B),
+			CURRENCY_ID_A,
+			USER_A
+		));
+	});
+}
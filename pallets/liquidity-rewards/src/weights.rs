@@ -52,6 +52,13 @@ pub trait WeightInfo {
 	fn set_epoch_duration() -> Weight;
 	fn set_group_weight() -> Weight;
 	fn set_currency_group() -> Weight;
+	fn withdraw_unstaked() -> Weight;
+	fn set_group_emission_curve() -> Weight;
+	fn set_co_incentive() -> Weight;
+	fn fund_co_incentive() -> Weight;
+	fn withdraw_co_incentive() -> Weight;
+	fn lock_stake() -> Weight;
+	fn unlock_stake() -> Weight;
 }
 
 /// Weights for pallet_liquidity_rewards using the Substrate node and
@@ -112,8 +119,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `8503`
 		// Minimum execution time: 17_000 nanoseconds.
 		Weight::from_parts(17_000_000, 8503)
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 
 	/// Storage: LiquidityRewardsBase Currency (r:1 w:0)
@@ -186,6 +193,140 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added:
+	/// 503, mode: MaxEncodedLen) Storage: LiquidityRewards ExitQueue (r:1 w:1)
+	/// Proof: LiquidityRewards ExitQueue (max_values: None, max_size:
+	/// Some(1163), added: 3638, mode: MaxEncodedLen) Storage: OrmlTokens
+	/// Accounts (r:1 w:1) Proof: OrmlTokens Accounts (max_values: None,
+	/// max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	fn withdraw_unstaked() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1330`
+		//  Estimated: `4628`
+		// Minimum execution time: 33_000 nanoseconds.
+		Weight::from_parts(35_000_000, 4628)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+
+	/// Storage: LiquidityRewards NextEmissionCurves (r:1 w:1)
+	/// Proof: LiquidityRewards NextEmissionCurves (max_values: Some(1), max_size:
+	/// Some(651), added: 1146, mode: MaxEncodedLen)
+	fn set_group_emission_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `2136`
+		// Minimum execution time: 6_000 nanoseconds.
+		Weight::from_parts(6_000_000, 2136)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: LiquidityRewards CoIncentives (r:1 w:1)
+	/// Proof: LiquidityRewards CoIncentives (max_values: Some(1), max_size:
+	/// Some(1141), added: 1636, mode: MaxEncodedLen)
+	fn set_co_incentive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `2626`
+		// Minimum execution time: 7_000 nanoseconds.
+		Weight::from_parts(7_000_000, 2626)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+
+	/// Storage: LiquidityRewards CoIncentives (r:1 w:1)
+	/// Proof: LiquidityRewards CoIncentives (max_values: Some(1), max_size:
+	/// Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: OrmlTokens Accounts (r:2 w:2)
+	/// Proof: OrmlTokens Accounts (max_values: None, max_size: Some(129), added:
+	/// 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added:
+	/// 2603, mode: MaxEncodedLen)
+	fn fund_co_incentive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6196`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(39_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: LiquidityRewards CoIncentives (r:1 w:1)
+	/// Proof: LiquidityRewards CoIncentives (max_values: Some(1), max_size:
+	/// Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: OrmlTokens Accounts (r:2 w:2)
+	/// Proof: OrmlTokens Accounts (max_values: None, max_size: Some(129), added:
+	/// 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added:
+	/// 2603, mode: MaxEncodedLen)
+	fn withdraw_co_incentive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `617`
+		//  Estimated: `6196`
+		// Minimum execution time: 37_000 nanoseconds.
+		Weight::from_parts(38_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: LiquidityRewardsBase Currency (r:1 w:1)
+	/// Proof: LiquidityRewardsBase Currency (max_values: None, max_size: Some(863), added:
+	/// 3338, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase Group (r:1 w:1)
+	/// Proof: LiquidityRewardsBase Group (max_values: None, max_size: Some(72), added:
+	/// 2547, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase StakeAccount (r:1 w:1)
+	/// Proof: LiquidityRewardsBase StakeAccount (max_values: None, max_size: Some(143), added:
+	/// 2618, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase StakeLock (r:1 w:1)
+	/// Proof: LiquidityRewardsBase StakeLock (max_values: None, max_size: Some(125), added:
+	/// 2600, mode: MaxEncodedLen)
+	fn lock_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `480`
+		//  Estimated: `4328`
+		// Minimum execution time: 19_000 nanoseconds.
+		Weight::from_parts(20_000_000, 4328)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+
+	/// Storage: LiquidityRewardsBase Currency (r:1 w:1)
+	/// Proof: LiquidityRewardsBase Currency (max_values: None, max_size: Some(863), added:
+	/// 3338, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase Group (r:1 w:1)
+	/// Proof: LiquidityRewardsBase Group (max_values: None, max_size: Some(72), added:
+	/// 2547, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase StakeAccount (r:1 w:1)
+	/// Proof: LiquidityRewardsBase StakeAccount (max_values: None, max_size: Some(143), added:
+	/// 2618, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase StakeLock (r:1 w:1)
+	/// Proof: LiquidityRewardsBase StakeLock (max_values: None, max_size: Some(125), added:
+	/// 2600, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase ForfeitedReward (r:1 w:1)
+	/// Proof: LiquidityRewardsBase ForfeitedReward (max_values: None, max_size: Some(36), added:
+	/// 2511, mode: MaxEncodedLen)
+	/// Storage: OrmlTokens Accounts (r:2 w:2)
+	/// Proof: OrmlTokens Accounts (max_values: None, max_size: Some(129), added:
+	/// 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added:
+	/// 2603, mode: MaxEncodedLen)
+	fn unlock_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `6196`
+		// Minimum execution time: 47_000 nanoseconds.
+		Weight::from_parts(48_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
 }
 
 impl WeightInfo for () {
@@ -243,8 +384,8 @@ impl WeightInfo for () {
 		//  Estimated: `8503`
 		// Minimum execution time: 17_000 nanoseconds.
 		Weight::from_parts(17_000_000, 8503)
-			.saturating_add(RocksDbWeight::get().reads(3))
-			.saturating_add(RocksDbWeight::get().writes(3))
+			.saturating_add(RocksDbWeight::get().reads(6))
+			.saturating_add(RocksDbWeight::get().writes(5))
 	}
 
 	/// Storage: LiquidityRewardsBase Currency (r:1 w:0)
@@ -317,4 +458,138 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1))
 			.saturating_add(RocksDbWeight::get().writes(1))
 	}
+
+	/// Storage: Timestamp Now (r:1 w:0)
+	/// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added:
+	/// 503, mode: MaxEncodedLen) Storage: LiquidityRewards ExitQueue (r:1 w:1)
+	/// Proof: LiquidityRewards ExitQueue (max_values: None, max_size:
+	/// Some(1163), added: 3638, mode: MaxEncodedLen) Storage: OrmlTokens
+	/// Accounts (r:1 w:1) Proof: OrmlTokens Accounts (max_values: None,
+	/// max_size: Some(129), added: 2604, mode: MaxEncodedLen)
+	fn withdraw_unstaked() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1330`
+		//  Estimated: `4628`
+		// Minimum execution time: 33_000 nanoseconds.
+		Weight::from_parts(35_000_000, 4628)
+			.saturating_add(RocksDbWeight::get().reads(3))
+			.saturating_add(RocksDbWeight::get().writes(2))
+	}
+
+	/// Storage: LiquidityRewards NextEmissionCurves (r:1 w:1)
+	/// Proof: LiquidityRewards NextEmissionCurves (max_values: Some(1), max_size:
+	/// Some(651), added: 1146, mode: MaxEncodedLen)
+	fn set_group_emission_curve() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `2136`
+		// Minimum execution time: 6_000 nanoseconds.
+		Weight::from_parts(6_000_000, 2136)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	/// Storage: LiquidityRewards CoIncentives (r:1 w:1)
+	/// Proof: LiquidityRewards CoIncentives (max_values: Some(1), max_size:
+	/// Some(1141), added: 1636, mode: MaxEncodedLen)
+	fn set_co_incentive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `109`
+		//  Estimated: `2626`
+		// Minimum execution time: 7_000 nanoseconds.
+		Weight::from_parts(7_000_000, 2626)
+			.saturating_add(RocksDbWeight::get().reads(1))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
+
+	/// Storage: LiquidityRewards CoIncentives (r:1 w:1)
+	/// Proof: LiquidityRewards CoIncentives (max_values: Some(1), max_size:
+	/// Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: OrmlTokens Accounts (r:2 w:2)
+	/// Proof: OrmlTokens Accounts (max_values: None, max_size: Some(129), added:
+	/// 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added:
+	/// 2603, mode: MaxEncodedLen)
+	fn fund_co_incentive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6196`
+		// Minimum execution time: 38_000 nanoseconds.
+		Weight::from_parts(39_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	/// Storage: LiquidityRewards CoIncentives (r:1 w:1)
+	/// Proof: LiquidityRewards CoIncentives (max_values: Some(1), max_size:
+	/// Some(1141), added: 1636, mode: MaxEncodedLen)
+	/// Storage: OrmlTokens Accounts (r:2 w:2)
+	/// Proof: OrmlTokens Accounts (max_values: None, max_size: Some(129), added:
+	/// 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added:
+	/// 2603, mode: MaxEncodedLen)
+	fn withdraw_co_incentive() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `617`
+		//  Estimated: `6196`
+		// Minimum execution time: 37_000 nanoseconds.
+		Weight::from_parts(38_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	/// Storage: LiquidityRewardsBase Currency (r:1 w:1)
+	/// Proof: LiquidityRewardsBase Currency (max_values: None, max_size: Some(863), added:
+	/// 3338, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase Group (r:1 w:1)
+	/// Proof: LiquidityRewardsBase Group (max_values: None, max_size: Some(72), added:
+	/// 2547, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase StakeAccount (r:1 w:1)
+	/// Proof: LiquidityRewardsBase StakeAccount (max_values: None, max_size: Some(143), added:
+	/// 2618, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase StakeLock (r:1 w:1)
+	/// Proof: LiquidityRewardsBase StakeLock (max_values: None, max_size: Some(125), added:
+	/// 2600, mode: MaxEncodedLen)
+	fn lock_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `480`
+		//  Estimated: `4328`
+		// Minimum execution time: 19_000 nanoseconds.
+		Weight::from_parts(20_000_000, 4328)
+			.saturating_add(RocksDbWeight::get().reads(4))
+			.saturating_add(RocksDbWeight::get().writes(4))
+	}
+
+	/// Storage: LiquidityRewardsBase Currency (r:1 w:1)
+	/// Proof: LiquidityRewardsBase Currency (max_values: None, max_size: Some(863), added:
+	/// 3338, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase Group (r:1 w:1)
+	/// Proof: LiquidityRewardsBase Group (max_values: None, max_size: Some(72), added:
+	/// 2547, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase StakeAccount (r:1 w:1)
+	/// Proof: LiquidityRewardsBase StakeAccount (max_values: None, max_size: Some(143), added:
+	/// 2618, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase StakeLock (r:1 w:1)
+	/// Proof: LiquidityRewardsBase StakeLock (max_values: None, max_size: Some(125), added:
+	/// 2600, mode: MaxEncodedLen)
+	/// Storage: LiquidityRewardsBase ForfeitedReward (r:1 w:1)
+	/// Proof: LiquidityRewardsBase ForfeitedReward (max_values: None, max_size: Some(36), added:
+	/// 2511, mode: MaxEncodedLen)
+	/// Storage: OrmlTokens Accounts (r:2 w:2)
+	/// Proof: OrmlTokens Accounts (max_values: None, max_size: Some(129), added:
+	/// 2604, mode: MaxEncodedLen)
+	/// Storage: System Account (r:1 w:1)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added:
+	/// 2603, mode: MaxEncodedLen)
+	fn unlock_stake() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1012`
+		//  Estimated: `6196`
+		// Minimum execution time: 47_000 nanoseconds.
+		Weight::from_parts(48_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(8))
+			.saturating_add(RocksDbWeight::get().writes(8))
+	}
 }
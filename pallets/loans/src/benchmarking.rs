@@ -11,7 +11,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_primitives::CFG;
+use cfg_primitives::{CFG, SECONDS_PER_DAY};
 use cfg_traits::{
 	benchmarking::FundedPoolBenchmarkHelper,
 	changes::ChangeGuard,
@@ -42,7 +42,8 @@ use crate::{
 	types::{
 		cashflow::{InterestPayments, Maturity, PayDownSchedule, RepaymentSchedule},
 		valuation::{DiscountedCashFlow, ValuationMethod},
-		BorrowRestrictions, LoanRestrictions, RepayRestrictions,
+		BorrowRestrictions, LoanRestrictions, OriginationFee, OriginationFeeDestination,
+		RateQuoteSettings, RepayRestrictions, RepaymentAllocation,
 	},
 };
 
@@ -66,6 +67,7 @@ fn config_mocks() {
 	MockPermissions::mock_has(|_, _, _| true);
 	MockPools::mock_pool_exists(|_| true);
 	MockPools::mock_account_for(|_| 0);
+	MockPools::mock_currency_for(|_| Some(0));
 	MockPools::mock_withdraw(|_, _, _| Ok(()));
 	MockPools::mock_deposit(|_, _, _| Ok(()));
 	MockPrices::mock_register_id(|_, _| Ok(()));
@@ -158,6 +160,43 @@ where
 		}
 	}
 
+	fn set_rate_quote_settings(pool_id: T::PoolId) {
+		let pool_admin = account("pool_admin", 0, 0);
+
+		Pallet::<T>::set_rate_quote_settings(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			Some(Self::rate_quote_settings()),
+		)
+		.unwrap();
+	}
+
+	fn set_origination_fee(pool_id: T::PoolId) {
+		let pool_admin = account("pool_admin", 0, 0);
+
+		Pallet::<T>::set_origination_fee(
+			RawOrigin::Signed(pool_admin).into(),
+			pool_id,
+			Some(Self::origination_fee()),
+		)
+		.unwrap();
+	}
+
+	fn origination_fee() -> OriginationFee<T::AccountId, T::Rate> {
+		OriginationFee {
+			rate: T::Rate::saturating_from_rational(1, 2),
+			destination: OriginationFeeDestination::Originator(account("originator", 0, 0)),
+		}
+	}
+
+	fn rate_quote_settings() -> RateQuoteSettings<T::Rate> {
+		RateQuoteSettings {
+			benchmark_rate: T::Rate::saturating_from_rational(1, 5000),
+			spread: T::Rate::saturating_from_rational(1, 10000),
+			window: 24 * 3600, // 1 day
+		}
+	}
+
 	fn create_loan(pool_id: T::PoolId, item_id: T::ItemId) -> T::LoanId {
 		let borrower = account("borrower", 0, 0);
 
@@ -345,6 +384,7 @@ benchmarks! {
 		let borrower = account("borrower", 0, 0);
 		let pool_id = Helper::<T>::initialize_active_state(n);
 		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
+		Helper::<T>::set_origination_fee(pool_id);
 
 	}: _(RawOrigin::Signed(borrower), pool_id, loan_id, PrincipalInput::Internal(10.into()))
 
@@ -482,6 +522,81 @@ benchmarks! {
 		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
 
 	}: _(RawOrigin::Signed(borrower), pool_id, loan_id, PrincipalInput::Internal(10.into()))
+
+	set_rate_quote_settings {
+		let pool_admin = account("pool_admin", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+		let settings = Helper::<T>::rate_quote_settings();
+
+	}: _(RawOrigin::Signed(pool_admin), pool_id, Some(settings))
+
+	request_rate_quote {
+		let borrower = account("borrower", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+		Helper::<T>::set_rate_quote_settings(pool_id);
+
+	}: _(RawOrigin::Signed(borrower), pool_id)
+
+	set_settlement_currency {
+		let pool_admin = account("pool_admin", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+		let currency_id = T::Pool::currency_for(pool_id).unwrap();
+
+	}: _(RawOrigin::Signed(pool_admin), pool_id, currency_id, true)
+
+	set_custodian {
+		let pool_admin = account("pool_admin", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+		let custodian = account("custodian", 0, 0);
+
+	}: _(RawOrigin::Signed(pool_admin), pool_id, custodian, true)
+
+	set_attestation_period {
+		let pool_admin = account("pool_admin", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+
+	}: _(RawOrigin::Signed(pool_admin), pool_id, Some(SECONDS_PER_DAY))
+
+	set_origination_fee {
+		let pool_admin = account("pool_admin", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+
+	}: _(RawOrigin::Signed(pool_admin), pool_id, Some(Helper::<T>::origination_fee()))
+
+	set_repayment_allocation {
+		let pool_admin = account("pool_admin", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+
+	}: _(RawOrigin::Signed(pool_admin), pool_id, RepaymentAllocation::InterestFirst)
+
+	attest_collateral {
+		let n in 1..Helper::<T>::max_active_loans() - 1;
+
+		let custodian = account::<T::AccountId>("custodian", 0, 0);
+		let pool_id = Helper::<T>::initialize_active_state(n);
+		let loan_id = Helper::<T>::create_loan(pool_id, u16::MAX.into());
+		Helper::<T>::borrow_loan(pool_id, loan_id);
+		Pallet::<T>::set_custodian(
+			RawOrigin::Signed(account("pool_admin", 0, 0)).into(),
+			pool_id,
+			custodian.clone(),
+			true,
+		)
+		.unwrap();
+
+	}: _(RawOrigin::Signed(custodian), pool_id, loan_id, T::Hash::default())
+
+	create_with_quote {
+		let borrower = account::<T::AccountId>("borrower", 0, 0);
+		let pool_id = Helper::<T>::prepare_benchmark();
+		Helper::<T>::set_rate_quote_settings(pool_id);
+		Pallet::<T>::request_rate_quote(RawOrigin::Signed(borrower.clone()).into(), pool_id).unwrap();
+
+		let (collection_id, item_id) = (COLLECION_ID.into(), 1.into());
+		T::NonFungible::mint_into(&collection_id, &item_id, &borrower).unwrap();
+		let loan_info = Helper::<T>::base_loan(item_id);
+
+	}: _(RawOrigin::Signed(borrower), pool_id, loan_info)
 }
 
 impl_benchmark_test_suite!(
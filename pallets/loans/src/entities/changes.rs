@@ -33,6 +33,30 @@ pub enum LoanMutation<Rate> {
 	Internal(InternalMutation<Rate>),
 }
 
+impl<Rate> LoanMutation<Rate> {
+	/// Whether the mutation changes the terms agreed with the borrower, which
+	/// is recorded as a restructuring of the loan
+	pub fn is_structural(&self) -> bool {
+		!matches!(self, LoanMutation::Internal(_))
+	}
+}
+
+/// Code given by the loan admin to explain why a loan was restructured, as
+/// defined by the pool for its reporting.
+pub type RestructuringReason = u16;
+
+/// Structural mutation applied to an active loan
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct RestructuringRecord<Rate> {
+	pub mutation: LoanMutation<Rate>,
+
+	/// Reason given when proposing the mutation, if any
+	pub reason: Option<RestructuringReason>,
+
+	/// Moment the mutation was applied
+	pub applied_at: Seconds,
+}
+
 /// Change description
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 #[scale_info(skip_type_params(T))]
@@ -40,4 +64,5 @@ pub enum Change<T: Config> {
 	Loan(T::LoanId, LoanMutation<T::Rate>),
 	Policy(BoundedVec<WriteOffRule<T::Rate>, T::MaxWriteOffPolicySize>),
 	TransferDebt(T::LoanId, T::LoanId, RepaidInput<T>, PrincipalInput<T>),
+	Restructuring(T::LoanId, LoanMutation<T::Rate>, RestructuringReason),
 }
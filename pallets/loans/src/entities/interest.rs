@@ -54,6 +54,12 @@ impl<T: Config> ActiveInterestRate<T> {
 		self.penalty
 	}
 
+	/// Fixed rate currently equivalent to the interest rate. A floating rate is
+	/// resolved using the current value of its reference.
+	pub fn fixed_rate(&self) -> Result<InterestRate<T::Rate>, DispatchError> {
+		T::InterestAccrual::fixed_rate(&self.interest_rate)
+	}
+
 	pub fn current_debt(&self) -> Result<T::Balance, DispatchError> {
 		let now = T::Time::now();
 		T::InterestAccrual::calculate_debt(&self.interest_rate, self.normalized_acc, now)
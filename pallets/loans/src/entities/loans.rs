@@ -25,12 +25,12 @@ use crate::{
 			Pricing,
 		},
 	},
-	pallet::{AssetOf, Config, Error},
+	pallet::{AssetOf, CollateralAttestations, Config, Error, RepaymentAllocations},
 	types::{
 		cashflow::{CashflowPayment, RepaymentSchedule},
 		policy::{WriteOffStatus, WriteOffTrigger},
 		BorrowLoanError, BorrowRestrictions, CloseLoanError, CreateLoanError, LoanRestrictions,
-		MutationError, RepaidAmount, RepayLoanError, RepayRestrictions,
+		MutationError, RepaidAmount, RepayLoanError, RepayRestrictions, RepaymentAllocation,
 	},
 	PriceOf,
 };
@@ -76,6 +76,29 @@ impl<T: Config> LoanInfo<T> {
 			Error::<T>::from(CreateLoanError::InvalidRepaymentSchedule)
 		);
 
+		if let BorrowRestrictions::Revolving { available_until } = self.restrictions.borrows {
+			let is_revolving = match &self.pricing {
+				Pricing::Internal(pricing) => pricing.is_revolving(),
+				Pricing::External(_) => false,
+			};
+
+			ensure!(
+				is_revolving
+					&& available_until > now
+					&& self
+						.schedule
+						.maturity
+						.date()
+						.map_or(true, |maturity| available_until <= maturity),
+				Error::<T>::from(CreateLoanError::InvalidBorrowRestriction)
+			);
+
+			ensure!(
+				self.restrictions.repayments == RepayRestrictions::None,
+				Error::<T>::from(CreateLoanError::InvalidRepayRestriction)
+			);
+		}
+
 		Ok(())
 	}
 }
@@ -250,15 +273,70 @@ impl<T: Config> ActiveLoan<T> {
 	}
 
 	pub fn expected_cashflows(&self) -> Result<Vec<CashflowPayment<T::Balance>>, DispatchError> {
-		self.schedule.generate_cashflows(
+		let mut cashflows = self.schedule.generate_cashflows(
 			self.repayments_on_schedule_until,
 			self.principal()?,
 			match &self.pricing {
 				ActivePricing::Internal(_) => self.principal()?,
 				ActivePricing::External(inner) => inner.outstanding_notional_principal()?,
 			},
-			self.pricing.interest().rate(),
-		)
+			&self.pricing.interest().fixed_rate()?,
+		)?;
+
+		// The commitment fee of the undrawn amount is expected to be paid at the
+		// end of the availability period of a revolving loan
+		if let Some(available_until) = self.available_until() {
+			let fee = self.commitment_fee_until(available_until)?;
+			if !fee.is_zero() {
+				cashflows.push(CashflowPayment {
+					when: available_until,
+					principal: Zero::zero(),
+					interest: fee,
+				});
+				cashflows.sort_by_key(|payment| payment.when);
+			}
+		}
+
+		Ok(cashflows)
+	}
+
+	/// End of the availability period of a revolving loan, which is its
+	/// maturity date if no availability period is restricted.
+	fn available_until(&self) -> Option<Seconds> {
+		match self.restrictions.borrows {
+			BorrowRestrictions::Revolving { available_until } => Some(available_until),
+			_ => self.maturity_date(),
+		}
+	}
+
+	/// Commitment fee accrued over the undrawn amount since the principal was
+	/// last changed until `until`, capped to the availability period.
+	fn commitment_fee_until(&self, until: Seconds) -> Result<T::Balance, DispatchError> {
+		match &self.pricing {
+			ActivePricing::Internal(inner) if inner.is_revolving() => {
+				let until = self
+					.available_until()
+					.map_or(until, |available_until| until.min(available_until));
+				let period = until.saturating_sub(self.repayments_on_schedule_until);
+
+				inner.commitment_fee(self.principal()?, period)
+			}
+			_ => Ok(Zero::zero()),
+		}
+	}
+
+	/// Adds the commitment fee accrued until now to the debt of the loan as
+	/// interest. Must be called before any change of the principal.
+	fn accrue_commitment_fee(&mut self) -> DispatchResult {
+		let fee = self.commitment_fee_until(T::Time::now())?;
+
+		if let ActivePricing::Internal(inner) = &mut self.pricing {
+			if !fee.is_zero() {
+				inner.adjust(Adjustment::Increase(fee))?;
+			}
+		}
+
+		Ok(())
 	}
 
 	pub fn write_off_status(&self) -> WriteOffStatus<T::Rate> {
@@ -277,6 +355,7 @@ impl<T: Config> ActiveLoan<T> {
 		&self,
 		trigger: &WriteOffTrigger,
 		pool_id: T::PoolId,
+		loan_id: T::LoanId,
 	) -> Result<bool, DispatchError> {
 		let now = T::Time::now();
 		match trigger {
@@ -290,6 +369,12 @@ impl<T: Config> ActiveLoan<T> {
 				}
 				ActivePricing::Internal(_) => Ok(false),
 			},
+			WriteOffTrigger::AttestationOutdated(secs) => {
+				let last_attested = CollateralAttestations::<T>::get(pool_id, loan_id)
+					.map_or(self.origination_date, |attestation| attestation.attested_at);
+
+				Ok(now >= last_attested.ensure_add(*secs)?)
+			}
 		}
 	}
 
@@ -305,6 +390,19 @@ impl<T: Config> ActiveLoan<T> {
 		self.write_down(value)
 	}
 
+	/// Expected loss of this loan over the next `horizon` seconds, using the
+	/// written down outstanding debt as exposure. It is computed for risk
+	/// reporting only and does not affect the valuation of the loan.
+	pub fn expected_loss(&self, horizon: Seconds) -> Result<T::Balance, DispatchError> {
+		match &self.pricing {
+			ActivePricing::Internal(inner) => {
+				let exposure = self.write_down(inner.interest.current_debt()?)?;
+				inner.expected_loss(exposure, horizon)
+			}
+			ActivePricing::External(_) => Ok(T::Balance::zero()),
+		}
+	}
+
 	/// An optimized version of `ActiveLoan::present_value()` when some input
 	/// data can be used from cached collections. Instead of fetch the current
 	/// debt and prices from the pallets,
@@ -332,7 +430,7 @@ impl<T: Config> ActiveLoan<T> {
 		let max_borrow_amount = match &self.pricing {
 			ActivePricing::Internal(inner) => {
 				amount.internal()?;
-				inner.max_borrow_amount(self.total_borrowed)?
+				inner.max_borrow_amount(self.total_borrowed, self.principal()?)?
 			}
 			ActivePricing::External(inner) => {
 				let external_amount = amount.external()?;
@@ -357,6 +455,9 @@ impl<T: Config> ActiveLoan<T> {
 						ActivePricing::External(inner) => inner.has_registered_price(pool_id),
 					}
 				}
+				BorrowRestrictions::Revolving { available_until } => {
+					self.write_off_status().is_none() && T::Time::now() < available_until
+				}
 			},
 			Error::<T>::from(BorrowLoanError::Restriction)
 		);
@@ -372,6 +473,7 @@ impl<T: Config> ActiveLoan<T> {
 
 	pub fn borrow(&mut self, amount: &PrincipalInput<T>, pool_id: T::PoolId) -> DispatchResult {
 		self.ensure_can_borrow(amount, pool_id)?;
+		self.accrue_commitment_fee()?;
 
 		self.total_borrowed.ensure_add_assign(amount.balance()?)?;
 
@@ -433,6 +535,19 @@ impl<T: Config> ActiveLoan<T> {
 			Error::<T>::from(RepayLoanError::Restriction)
 		);
 
+		ensure!(
+			match RepaymentAllocations::<T>::get(pool_id) {
+				RepaymentAllocation::Unordered => true,
+				RepaymentAllocation::InterestFirst => {
+					amount.principal.balance()?.is_zero() || amount.interest == outstanding_interest
+				}
+				RepaymentAllocation::PrincipalFirst => {
+					amount.interest.is_zero() || amount.principal.balance()? == max_repay_principal
+				}
+			},
+			Error::<T>::from(RepayLoanError::AllocationOrder)
+		);
+
 		Ok(amount)
 	}
 
@@ -441,6 +556,7 @@ impl<T: Config> ActiveLoan<T> {
 		amount: RepaidInput<T>,
 		pool_id: T::PoolId,
 	) -> Result<RepaidInput<T>, DispatchError> {
+		self.accrue_commitment_fee()?;
 		let amount = self.prepare_repayment(amount, pool_id)?;
 
 		self.total_repaid
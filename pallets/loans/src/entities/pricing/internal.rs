@@ -1,3 +1,4 @@
+use cfg_primitives::SECONDS_PER_YEAR;
 use cfg_traits::{
 	interest::{InterestRate, RateCollection},
 	Seconds, TimeAsSecs,
@@ -12,8 +13,8 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::Saturating;
 use sp_runtime::{
-	traits::{EnsureFixedPointNumber, EnsureSub},
-	DispatchError,
+	traits::{EnsureFixedPointNumber, EnsureMul, EnsureSub, Zero},
+	DispatchError, FixedPointNumber,
 };
 
 use crate::{
@@ -33,6 +34,15 @@ pub enum MaxBorrowAmount<Rate> {
 
 	/// Max borrow amount computation using the outstanding debt
 	UpToOutstandingDebt { advance_rate: Rate },
+
+	/// Revolving credit line, where the committed limit is computed from the
+	/// collateral value and the principal repaid can be borrowed again.
+	/// An optional commitment fee per year is charged over the undrawn amount
+	/// of the limit.
+	Revolving {
+		advance_rate: Rate,
+		commitment_fee: Option<Rate>,
+	},
 }
 
 /// Internal pricing method
@@ -58,6 +68,10 @@ impl<T: Config> InternalPricing<T> {
 
 		Ok(())
 	}
+
+	pub fn is_revolving(&self) -> bool {
+		matches!(self.max_borrow_amount, MaxBorrowAmount::Revolving { .. })
+	}
 }
 
 /// Internal pricing method with extra attributes for active loans
@@ -98,13 +112,13 @@ impl<T: Config> InternalActivePricing<T> {
 					maturity_date.ok_or(Error::<T>::MaturityDateNeededForValuationMethod)?;
 
 				let now = T::Time::now();
-				Ok(dcf.compute_present_value(
+				dcf.compute_present_value(
 					debt,
 					now,
-					self.interest.rate(),
+					&self.interest.fixed_rate()?,
 					maturity_date,
 					origination_date,
-				)?)
+				)
 			}
 			ValuationMethod::OutstandingDebt | ValuationMethod::Cash => Ok(debt),
 		}
@@ -132,6 +146,22 @@ impl<T: Config> InternalActivePricing<T> {
 		self.compute_present_value(debt, origination_date, maturity_date)
 	}
 
+	/// Expected loss of the given exposure over `horizon` seconds.
+	/// Only loans valued with a discounted cash flow carry default
+	/// assumptions, any other valuation method has no expected loss.
+	pub fn expected_loss(
+		&self,
+		exposure: T::Balance,
+		horizon: Seconds,
+	) -> Result<T::Balance, DispatchError> {
+		match &self.info.valuation_method {
+			ValuationMethod::DiscountedCashFlow(dcf) => {
+				Ok(dcf.compute_expected_loss(exposure, horizon)?)
+			}
+			ValuationMethod::OutstandingDebt | ValuationMethod::Cash => Ok(Zero::zero()),
+		}
+	}
+
 	pub fn outstanding_interest(
 		&self,
 		outstanding_principal: T::Balance,
@@ -143,6 +173,7 @@ impl<T: Config> InternalActivePricing<T> {
 	pub fn max_borrow_amount(
 		&self,
 		total_borrowed: T::Balance,
+		principal: T::Balance,
 	) -> Result<T::Balance, DispatchError> {
 		Ok(match self.info.max_borrow_amount {
 			MaxBorrowAmount::UpToTotalBorrowed { advance_rate } => advance_rate
@@ -151,9 +182,40 @@ impl<T: Config> InternalActivePricing<T> {
 			MaxBorrowAmount::UpToOutstandingDebt { advance_rate } => advance_rate
 				.ensure_mul_int(self.info.collateral_value)?
 				.saturating_sub(self.interest.current_debt()?),
+			MaxBorrowAmount::Revolving { advance_rate, .. } => advance_rate
+				.ensure_mul_int(self.info.collateral_value)?
+				.saturating_sub(principal),
 		})
 	}
 
+	pub fn is_revolving(&self) -> bool {
+		self.info.is_revolving()
+	}
+
+	/// Commitment fee of a revolving credit line over `period` seconds, given
+	/// the outstanding `principal`. Zero if there is no commitment fee.
+	pub fn commitment_fee(
+		&self,
+		principal: T::Balance,
+		period: Seconds,
+	) -> Result<T::Balance, DispatchError> {
+		match self.info.max_borrow_amount {
+			MaxBorrowAmount::Revolving {
+				advance_rate,
+				commitment_fee: Some(fee),
+			} => {
+				let undrawn = advance_rate
+					.ensure_mul_int(self.info.collateral_value)?
+					.saturating_sub(principal);
+
+				Ok(T::Rate::saturating_from_rational(period, SECONDS_PER_YEAR)
+					.ensure_mul(fee)?
+					.ensure_mul_int(undrawn)?)
+			}
+			_ => Ok(Zero::zero()),
+		}
+	}
+
 	pub fn adjust(&mut self, adjustment: Adjustment<T::Balance>) -> DispatchResult {
 		self.interest.adjust_debt(adjustment)
 	}
@@ -20,6 +20,7 @@
 //! | Extrinsics                          | Role      |
 //! |-------------------------------------|-----------|
 //! | [`Pallet::create()`]                | Borrower  |
+//! | [`Pallet::create_with_quote()`]     | Borrower  |
 //! | [`Pallet::borrow()`]                | Borrower  |
 //! | [`Pallet::repay()`]                 | Borrower  |
 //! | [`Pallet::write_off()`]             |           |
@@ -37,6 +38,8 @@
 //! | [`Pallet::propose_write_off_policy()`]   | PoolAdmin |
 //! | [`Pallet::apply_write_off_policy()`]     |           |
 //! | [`Pallet::update_portfolio_valuation()`] |           |
+//! | [`Pallet::set_rate_quote_settings()`]    | PoolAdmin |
+//! | [`Pallet::request_rate_quote()`]         | Borrower  |
 //!
 //! The whole pallet is optimized for the more expensive extrinsic that is
 //! [`Pallet::update_portfolio_valuation()`] that should go through all active
@@ -72,9 +75,12 @@ pub use weights::WeightInfo;
 pub mod pallet {
 	use cfg_traits::{
 		self,
+		activity::{ActivityKind, ActivityRecorder},
 		changes::ChangeGuard,
 		data::{DataCollection, DataRegistry},
-		interest::InterestAccrual,
+		interest::{InterestAccrual, InterestRate},
+		keeper::{KeeperAction, KeeperRewards},
+		swaps::{OrderRatio, TokenSwaps},
 		IntoSeconds, Permissions, PoolInspect, PoolNAV, PoolReserve, PoolWriteOffPolicyMutate,
 		Seconds, TimeAsSecs,
 	};
@@ -84,7 +90,7 @@ pub mod pallet {
 		portfolio::{self, InitialPortfolioValuation, PortfolioValuationUpdateType},
 	};
 	use entities::{
-		changes::{Change, LoanMutation},
+		changes::{Change, LoanMutation, RestructuringReason, RestructuringRecord},
 		input::{PriceCollectionInput, PrincipalInput, RepaidInput},
 		loans::{self, ActiveLoan, ActiveLoanInfo, LoanInfo},
 	};
@@ -101,16 +107,20 @@ pub mod pallet {
 	use scale_info::TypeInfo;
 	use sp_arithmetic::{FixedPointNumber, PerThing};
 	use sp_runtime::{
-		traits::{BadOrigin, EnsureAdd, EnsureAddAssign, EnsureInto, One, Zero},
+		traits::{
+			BadOrigin, EnsureAdd, EnsureAddAssign, EnsureFixedPointNumber, EnsureInto, EnsureSub,
+			Hash as _, One, Zero,
+		},
 		ArithmeticError, FixedPointOperand, TransactionOutcome,
 	};
 	use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
 	use types::{
 		self,
 		cashflow::CashflowPayment,
-		policy::{self, WriteOffRule, WriteOffStatus},
-		BorrowLoanError, CloseLoanError, CreateLoanError, MutationError, RepayLoanError,
-		WrittenOffError,
+		policy::{self, WriteOffPolicyInfo, WriteOffRule, WriteOffStatus},
+		AttestationStatus, BorrowLoanError, CloseLoanError, CollateralAttestation, CreateLoanError,
+		MutationError, OriginationFee, OriginationFeeDestination, RateQuote, RateQuoteSettings,
+		RepayLoanError, RepaymentAllocation, WrittenOffError,
 	};
 
 	use super::*;
@@ -214,6 +224,26 @@ pub mod pallet {
 			Change = Self::RuntimeChange,
 		>;
 
+		/// Identification of a swap order
+		type OrderId: Parameter + Copy + MaxEncodedLen;
+
+		/// Used to convert repayments done in a settlement currency into the
+		/// pool currency
+		type TokenSwaps: TokenSwaps<
+			Self::AccountId,
+			CurrencyId = Self::CurrencyId,
+			BalanceIn = Self::Balance,
+			BalanceOut = Self::Balance,
+			OrderId = Self::OrderId,
+		>;
+
+		/// Rewards paid to the accounts writing off loans according to the
+		/// write off policy.
+		type KeeperRewards: KeeperRewards<AccountId = Self::AccountId, Action = KeeperAction>;
+
+		/// Records the pools and loans touched in a block.
+		type ActivityRecorder: ActivityRecorder;
+
 		/// Max number of active loans per pool.
 		#[pallet::constant]
 		type MaxActiveLoansPerPool: Get<u32>;
@@ -222,6 +252,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxWriteOffPolicySize: Get<u32> + Parameter;
 
+		/// Max number of restructurings kept in the history of a loan.
+		#[pallet::constant]
+		type MaxRestructurings: Get<u32>;
+
 		/// Information of runtime weights
 		type WeightInfo: WeightInfo;
 	}
@@ -280,6 +314,11 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Stores the number of write off policies applied to each pool
+	#[pallet::storage]
+	pub(crate) type WriteOffPolicyVersion<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, u32, ValueQuery>;
+
 	/// Stores the portfolio valuation associated to each pool
 	#[pallet::storage]
 	#[pallet::getter(fn portfolio_valuation)]
@@ -292,6 +331,109 @@ pub mod pallet {
 		InitialPortfolioValuation<T::Time>,
 	>;
 
+	/// Stores the settings used to quote rates to the borrowers of each pool
+	#[pallet::storage]
+	pub(crate) type RateQuoteSettingsOf<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, RateQuoteSettings<T::Rate>, OptionQuery>;
+
+	/// Stores the rate quote locked by a borrower in a pool.
+	/// Each borrower can hold only one quote per pool at a time.
+	#[pallet::storage]
+	pub(crate) type RateQuotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		RateQuote<T::Rate>,
+		OptionQuery,
+	>;
+
+	/// Stores the currencies, other than the pool currency, accepted to repay
+	/// the loans of a pool.
+	#[pallet::storage]
+	pub(crate) type SettlementCurrencies<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::CurrencyId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Stores the custodians allowed to attest the off-chain collateral of
+	/// the loans of a pool.
+	#[pallet::storage]
+	pub(crate) type Custodians<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		(),
+		OptionQuery,
+	>;
+
+	/// Stores the period in which the collateral of the active loans of a
+	/// pool should be attested by a custodian.
+	#[pallet::storage]
+	pub(crate) type AttestationPeriods<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, Seconds, OptionQuery>;
+
+	/// Stores the last custodian attestation of the collateral of a loan.
+	#[pallet::storage]
+	pub(crate) type CollateralAttestations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::LoanId,
+		CollateralAttestation<T::AccountId, T::Hash>,
+		OptionQuery,
+	>;
+
+	/// Stores the latest structural mutations applied to each loan. The oldest
+	/// records are dropped once `MaxRestructurings` is reached.
+	#[pallet::storage]
+	pub(crate) type RestructuringHistory<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::LoanId,
+		BoundedVec<RestructuringRecord<T::Rate>, T::MaxRestructurings>,
+		ValueQuery,
+	>;
+
+	/// Stores the origination fee deducted from the borrows of a pool.
+	#[pallet::storage]
+	pub(crate) type OriginationFees<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		OriginationFee<T::AccountId, T::Rate>,
+		OptionQuery,
+	>;
+
+	/// Stores the total origination fees deducted from the borrows of a loan.
+	#[pallet::storage]
+	pub(crate) type OriginationFeesCharged<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::LoanId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// Stores the order in which the repayments of a pool settle interest and
+	/// principal.
+	#[pallet::storage]
+	pub(crate) type RepaymentAllocations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, RepaymentAllocation, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -362,6 +504,68 @@ pub mod pallet {
 			loan_id: T::LoanId,
 			amount: RepaidInput<T>,
 		},
+		/// The rate quote settings for a pool were updated
+		RateQuoteSettingsUpdated {
+			pool_id: T::PoolId,
+			settings: Option<RateQuoteSettings<T::Rate>>,
+		},
+		/// A rate was quoted and locked for a borrower
+		RateQuoted {
+			pool_id: T::PoolId,
+			borrower: T::AccountId,
+			quote: RateQuote<T::Rate>,
+		},
+		/// A settlement currency was allowed or disallowed for a pool
+		SettlementCurrencyUpdated {
+			pool_id: T::PoolId,
+			currency_id: T::CurrencyId,
+			allowed: bool,
+		},
+		/// A custodian was allowed or disallowed for a pool
+		CustodianUpdated {
+			pool_id: T::PoolId,
+			custodian: T::AccountId,
+			allowed: bool,
+		},
+		/// The attestation period of a pool was updated
+		AttestationPeriodUpdated {
+			pool_id: T::PoolId,
+			period: Option<Seconds>,
+		},
+		/// A custodian attested the collateral of a loan
+		CollateralAttested {
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			custodian: T::AccountId,
+			document_hash: T::Hash,
+		},
+		/// An amount was repaid for a loan using a settlement currency.
+		/// `settlement_amount` of `currency_id` was converted into
+		/// `converted_amount` of pool currency.
+		RepaidWithSettlement {
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			amount: RepaidInput<T>,
+			currency_id: T::CurrencyId,
+			settlement_amount: T::Balance,
+			converted_amount: T::Balance,
+		},
+		/// The origination fee of a pool was updated
+		OriginationFeeUpdated {
+			pool_id: T::PoolId,
+			fee: Option<OriginationFee<T::AccountId, T::Rate>>,
+		},
+		/// An origination fee was deducted from a borrowed amount
+		OriginationFeeCharged {
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			amount: T::Balance,
+		},
+		/// The repayment allocation order of a pool was updated
+		RepaymentAllocationUpdated {
+			pool_id: T::PoolId,
+			allocation: RepaymentAllocation,
+		},
 	}
 
 	#[pallet::error]
@@ -409,6 +613,24 @@ pub mod pallet {
 		/// method needs one. Making valuation and maturity settings
 		/// incompatible.
 		MaturityDateNeededForValuationMethod,
+		/// Emits when the pool has no rate quote settings configured
+		RateQuoteSettingsNotFound,
+		/// Emits when the borrower has no rate quote for the pool
+		RateQuoteNotFound,
+		/// Emits when the rate quote window has already passed
+		RateQuoteExpired,
+		/// Emits when the currency is not allowed to repay loans of the pool
+		SettlementCurrencyNotAllowed,
+		/// Emits when the order used to convert a settlement does not sell
+		/// pool currency for the settlement currency at market ratio
+		SettlementOrderMismatch,
+		/// Emits when the settlement amount exceeds the maximum given by the
+		/// borrower
+		SettlementSlippageExceeded,
+		/// Emits when the account is not a custodian of the pool
+		NotCustodian,
+		/// Emits when the origination fee rate is above 100%
+		InvalidOriginationFee,
 	}
 
 	impl<T> From<CreateLoanError> for Error<T> {
@@ -461,25 +683,8 @@ pub mod pallet {
 			info: LoanInfo<T>,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
-			Self::ensure_role(pool_id, &who, PoolRole::Borrower)?;
-			Self::ensure_collateral_owner(&who, info.collateral())?;
-			Self::ensure_pool_exists(pool_id)?;
-
-			info.validate(T::Time::now())?;
-
-			let collateral = info.collateral();
-			T::NonFungible::transfer(&collateral.0, &collateral.1, &T::Pool::account_for(pool_id))?;
-
-			let loan_id = Self::generate_loan_id(pool_id)?;
-			CreatedLoan::<T>::insert(pool_id, loan_id, loans::CreatedLoan::new(info.clone(), who));
-
-			Self::deposit_event(Event::<T>::Created {
-				pool_id,
-				loan_id,
-				loan_info: info,
-			});
 
-			Ok(())
+			Self::create_action(who, pool_id, info)
 		}
 
 		/// Transfers borrow amount to the borrower.
@@ -487,8 +692,10 @@ pub mod pallet {
 		/// The origin must be the borrower of the loan.
 		/// The borrow action should fulfill the borrow restrictions configured
 		/// at [`types::LoanRestrictions`]. The `amount` will be transferred
-		/// from pool reserve to borrower. The portfolio valuation of the pool
-		/// is updated to reflect the new present value of the loan.
+		/// from pool reserve to borrower, minus the origination fee of the
+		/// pool if any. The whole `amount` is added to the debt of the loan.
+		/// The portfolio valuation of the pool is updated to reflect the new
+		/// present value of the loan.
 		#[pallet::weight(T::WeightInfo::borrow(T::MaxActiveLoansPerPool::get()))]
 		#[pallet::call_index(1)]
 		pub fn borrow(
@@ -496,12 +703,15 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
 			amount: PrincipalInput<T>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let _count = Self::borrow_action(&who, pool_id, loan_id, &amount, false)?;
+			let count = Self::borrow_action(&who, pool_id, loan_id, &amount, false)?;
+
+			let balance = amount.balance()?;
+			let fee = Self::charge_origination_fee(pool_id, loan_id, balance)?;
 
-			T::Pool::withdraw(pool_id, who, amount.balance()?)?;
+			T::Pool::withdraw(pool_id, who, balance.ensure_sub(fee)?)?;
 
 			Self::deposit_event(Event::<T>::Borrowed {
 				pool_id,
@@ -509,7 +719,7 @@ pub mod pallet {
 				amount,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::borrow(count)).into())
 		}
 
 		/// Transfers amount borrowed to the pool reserve.
@@ -529,10 +739,10 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
 			amount: RepaidInput<T>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let (amount, _count) = Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
+			let (amount, count) = Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
 
 			T::Pool::deposit(pool_id, who, amount.repaid_amount()?.total()?)?;
 
@@ -542,7 +752,7 @@ pub mod pallet {
 				amount,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::repay(count)).into())
 		}
 
 		/// Writes off an overdue loan.
@@ -559,32 +769,39 @@ pub mod pallet {
 		///
 		/// No special permisions are required to this call.
 		/// The portfolio valuation of the pool is updated to reflect the new
-		/// present value of the loan.
+		/// present value of the loan. The caller is rewarded through
+		/// `KeeperRewards` if the write off status of the loan changed.
 		#[pallet::weight(T::WeightInfo::write_off(T::MaxActiveLoansPerPool::get()))]
 		#[pallet::call_index(3)]
 		pub fn write_off(
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
-		) -> DispatchResult {
-			ensure_signed(origin)?;
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
 
-			let (status, _count) = Self::update_active_loan(pool_id, loan_id, |loan| {
-				let rule = Self::find_write_off_rule(pool_id, loan)?
+			let ((status, changed), count) = Self::update_active_loan(pool_id, loan_id, |loan| {
+				let rule = Self::find_write_off_rule(pool_id, loan_id, loan)?
 					.ok_or(Error::<T>::NoValidWriteOffRule)?;
-				let status = rule.status.compose_max(&loan.write_off_status());
+				let previous = loan.write_off_status();
+				let status = rule.status.compose_max(&previous);
 
 				loan.write_off(&status)?;
-				Ok(status)
+				Ok((status.clone(), status != previous))
 			})?;
 
+			// Re-applying an already applied rule is not rewarded
+			if changed {
+				T::KeeperRewards::reward(&who, KeeperAction::WriteOffLoan);
+			}
+
 			Self::deposit_event(Event::<T>::WrittenOff {
 				pool_id,
 				loan_id,
 				status,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::write_off(count)).into())
 		}
 
 		/// Writes off a loan from admin origin.
@@ -605,7 +822,7 @@ pub mod pallet {
 			loan_id: T::LoanId,
 			percentage: T::Rate,
 			penalty: T::Rate,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			Self::ensure_role(pool_id, &who, PoolRole::LoanAdmin)?;
 
@@ -614,8 +831,8 @@ pub mod pallet {
 				penalty,
 			};
 
-			let (_, _count) = Self::update_active_loan(pool_id, loan_id, |loan| {
-				let rule = Self::find_write_off_rule(pool_id, loan)?;
+			let (_, count) = Self::update_active_loan(pool_id, loan_id, |loan| {
+				let rule = Self::find_write_off_rule(pool_id, loan_id, loan)?;
 				Self::ensure_admin_write_off(&status, rule)?;
 
 				loan.write_off(&status)?;
@@ -628,7 +845,7 @@ pub mod pallet {
 				status,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::admin_write_off(count)).into())
 		}
 
 		/// Propose a change.
@@ -641,11 +858,11 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
 			mutation: LoanMutation<T::Rate>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			Self::ensure_role(pool_id, &who, PoolRole::LoanAdmin)?;
 
-			let (mut loan, _count) = Self::get_active_loan(pool_id, loan_id)?;
+			let (mut loan, count) = Self::get_active_loan(pool_id, loan_id)?;
 			transactional::with_transaction(|| {
 				let result = loan.mutate_with(mutation.clone());
 
@@ -656,7 +873,7 @@ pub mod pallet {
 
 			T::ChangeGuard::note(pool_id, Change::Loan(loan_id, mutation).into())?;
 
-			Ok(())
+			Ok(Some(T::WeightInfo::propose_loan_mutation(count)).into())
 		}
 
 		/// Apply a proposed change identified by a change id.
@@ -668,25 +885,32 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
 			change_id: T::Hash,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			ensure_signed(origin)?;
 
-			let Change::Loan(loan_id, mutation) = Self::get_released_change(pool_id, change_id)?
-			else {
-				Err(Error::<T>::UnrelatedChangeId)?
+			let (loan_id, mutation, reason) = match Self::get_released_change(pool_id, change_id)? {
+				Change::Loan(loan_id, mutation) => (loan_id, mutation, None),
+				Change::Restructuring(loan_id, mutation, reason) => {
+					(loan_id, mutation, Some(reason))
+				}
+				_ => Err(Error::<T>::UnrelatedChangeId)?,
 			};
 
-			let (_, _count) = Self::update_active_loan(pool_id, loan_id, |loan| {
+			let (_, count) = Self::update_active_loan(pool_id, loan_id, |loan| {
 				loan.mutate_with(mutation.clone())
 			})?;
 
+			if mutation.is_structural() {
+				Self::record_restructuring(pool_id, loan_id, mutation.clone(), reason);
+			}
+
 			Self::deposit_event(Event::<T>::Mutated {
 				pool_id,
 				loan_id,
 				mutation,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::apply_loan_mutation(count)).into())
 		}
 
 		/// Closes a given loan
@@ -700,10 +924,10 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let ((closed_loan, borrower), _count) = match CreatedLoan::<T>::take(pool_id, loan_id) {
+			let ((closed_loan, borrower), count) = match CreatedLoan::<T>::take(pool_id, loan_id) {
 				Some(created_loan) => (created_loan.close()?, Zero::zero()),
 				None => {
 					let (active_loan, count) = Self::take_active_loan(pool_id, loan_id)?;
@@ -717,6 +941,8 @@ pub mod pallet {
 			T::NonFungible::transfer(&collateral.0, &collateral.1, &who)?;
 
 			ClosedLoan::<T>::insert(pool_id, loan_id, closed_loan);
+			T::ActivityRecorder::record(ActivityKind::Loan, &(pool_id, loan_id));
+			CollateralAttestations::<T>::remove(pool_id, loan_id);
 
 			Self::deposit_event(Event::<T>::Closed {
 				pool_id,
@@ -724,7 +950,7 @@ pub mod pallet {
 				collateral,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::close(count)).into())
 		}
 
 		/// Updates the write off policy with write off rules.
@@ -802,10 +1028,10 @@ pub mod pallet {
 			to_loan_id: T::LoanId,
 			repaid_amount: RepaidInput<T>,
 			borrow_amount: PrincipalInput<T>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			transactional::with_transaction(|| {
+			let (_, count) = transactional::with_transaction(|| {
 				let result = Self::transfer_debt_action(
 					&who,
 					pool_id,
@@ -826,7 +1052,7 @@ pub mod pallet {
 				Change::TransferDebt(from_loan_id, to_loan_id, repaid_amount, borrow_amount).into(),
 			)?;
 
-			Ok(())
+			Ok(Some(T::WeightInfo::propose_transfer_debt(count)).into())
 		}
 
 		/// Transfer debt from one loan to another loan,
@@ -840,7 +1066,7 @@ pub mod pallet {
 			origin: OriginFor<T>,
 			pool_id: T::PoolId,
 			change_id: T::Hash,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
 			let Change::TransferDebt(from_loan_id, to_loan_id, repaid_amount, borrow_amount) =
@@ -849,7 +1075,7 @@ pub mod pallet {
 				Err(Error::<T>::UnrelatedChangeId)?
 			};
 
-			let (repaid_amount, _count) = Self::transfer_debt_action(
+			let (repaid_amount, count) = Self::transfer_debt_action(
 				&who,
 				pool_id,
 				from_loan_id,
@@ -867,7 +1093,7 @@ pub mod pallet {
 				borrow_amount,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::apply_transfer_debt(count)).into())
 		}
 
 		/// Increase debt for a loan. Similar to [`Pallet::borrow()`] but
@@ -884,10 +1110,10 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
 			amount: PrincipalInput<T>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let _count = Self::borrow_action(&who, pool_id, loan_id, &amount, false)?;
+			let count = Self::borrow_action(&who, pool_id, loan_id, &amount, false)?;
 
 			Self::deposit_event(Event::<T>::DebtIncreased {
 				pool_id,
@@ -895,7 +1121,7 @@ pub mod pallet {
 				amount,
 			});
 
-			Ok(())
+			Ok(Some(T::WeightInfo::increase_debt(count)).into())
 		}
 
 		/// Decrease debt for a loan. Similar to [`Pallet::repay()`] but
@@ -912,10 +1138,10 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			loan_id: T::LoanId,
 			amount: RepaidInput<T>,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 
-			let (amount, _count) = Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
+			let (amount, count) = Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
 
 			Self::deposit_event(Event::<T>::DebtDecreased {
 				pool_id,
@@ -923,12 +1149,404 @@ pub mod pallet {
 				amount,
 			});
 
+			Ok(Some(T::WeightInfo::increase_debt(count)).into())
+		}
+
+		/// Updates the settings used to quote rates to the borrowers of a pool.
+		/// Passing `None` disables new quotes. Already locked quotes remain
+		/// valid until they expire.
+		///
+		/// The origin must be the admin of the pool.
+		#[pallet::weight(T::WeightInfo::set_rate_quote_settings())]
+		#[pallet::call_index(15)]
+		pub fn set_rate_quote_settings(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			settings: Option<RateQuoteSettings<T::Rate>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::PoolAdmin)?;
+			Self::ensure_pool_exists(pool_id)?;
+
+			if let Some(settings) = &settings {
+				settings.quoted_rate()?;
+			}
+
+			RateQuoteSettingsOf::<T>::set(pool_id, settings.clone());
+
+			Self::deposit_event(Event::<T>::RateQuoteSettingsUpdated { pool_id, settings });
+
+			Ok(())
+		}
+
+		/// Requests a rate quote computed as the pool benchmark rate plus the
+		/// pool spread. The quote is locked for the window configured in the
+		/// pool settings, replacing any previous quote of the borrower.
+		///
+		/// The origin must be a borrower of the pool.
+		#[pallet::weight(T::WeightInfo::request_rate_quote())]
+		#[pallet::call_index(16)]
+		pub fn request_rate_quote(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::Borrower)?;
+
+			let settings = RateQuoteSettingsOf::<T>::get(pool_id)
+				.ok_or(Error::<T>::RateQuoteSettingsNotFound)?;
+
+			let quote = RateQuote {
+				rate_per_year: settings.quoted_rate()?,
+				expires_at: T::Time::now().ensure_add(settings.window)?,
+			};
+
+			RateQuotes::<T>::insert(pool_id, &who, quote.clone());
+
+			Self::deposit_event(Event::<T>::RateQuoted {
+				pool_id,
+				borrower: who,
+				quote,
+			});
+
+			Ok(())
+		}
+
+		/// Creates a new loan as [`Pallet::create()`] but using the rate
+		/// locked by a previous [`Pallet::request_rate_quote()`] call.
+		///
+		/// The rate per year of `info` is replaced by the quoted rate, the
+		/// compounding schedule is kept. The quote is consumed by this call.
+		#[pallet::weight(T::WeightInfo::create_with_quote())]
+		#[pallet::call_index(17)]
+		pub fn create_with_quote(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			mut info: LoanInfo<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let quote =
+				RateQuotes::<T>::take(pool_id, &who).ok_or(Error::<T>::RateQuoteNotFound)?;
+
+			ensure!(
+				T::Time::now() <= quote.expires_at,
+				Error::<T>::RateQuoteExpired
+			);
+
+			info.interest_rate = InterestRate::Fixed {
+				rate_per_year: quote.rate_per_year,
+				compounding: info.interest_rate.compounding().clone(),
+			};
+
+			Self::create_action(who, pool_id, info)
+		}
+
+		/// Allows or disallows a currency, other than the pool currency, to be
+		/// used to repay the loans of a pool.
+		///
+		/// The origin must be the admin of the pool.
+		#[pallet::weight(T::WeightInfo::set_settlement_currency())]
+		#[pallet::call_index(18)]
+		pub fn set_settlement_currency(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			currency_id: T::CurrencyId,
+			allowed: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::PoolAdmin)?;
+			Self::ensure_pool_exists(pool_id)?;
+
+			match allowed {
+				true => SettlementCurrencies::<T>::insert(pool_id, currency_id, ()),
+				false => SettlementCurrencies::<T>::remove(pool_id, currency_id),
+			}
+
+			Self::deposit_event(Event::<T>::SettlementCurrencyUpdated {
+				pool_id,
+				currency_id,
+				allowed,
+			});
+
+			Ok(())
+		}
+
+		/// Repays a loan as [`Pallet::repay()`] but paying in a settlement
+		/// currency allowed by the pool.
+		///
+		/// The borrower fills `order_id`, which must sell pool currency for
+		/// `currency_id` at market ratio, to obtain the pool currency
+		/// repaid. The call fails if the settlement currency amount required
+		/// exceeds `max_settlement_amount`.
+		#[pallet::weight(T::WeightInfo::repay_with_settlement(T::MaxActiveLoansPerPool::get()))]
+		#[pallet::call_index(19)]
+		pub fn repay_with_settlement(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			amount: RepaidInput<T>,
+			currency_id: T::CurrencyId,
+			order_id: T::OrderId,
+			max_settlement_amount: T::Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				SettlementCurrencies::<T>::contains_key(pool_id, currency_id),
+				Error::<T>::SettlementCurrencyNotAllowed
+			);
+
+			let pool_currency = T::Pool::currency_for(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let order = T::TokenSwaps::get_order_details(order_id)
+				.ok_or(Error::<T>::SettlementOrderMismatch)?;
+
+			ensure!(
+				order.swap.currency_out == pool_currency
+					&& order.swap.currency_in == currency_id
+					&& !matches!(order.ratio, OrderRatio::Custom(_)),
+				Error::<T>::SettlementOrderMismatch
+			);
+
+			let (amount, count) = Self::repay_action(&who, pool_id, loan_id, &amount, false)?;
+			let converted_amount = amount.repaid_amount()?.total()?;
+
+			let settlement_amount =
+				T::TokenSwaps::convert_by_market(currency_id, pool_currency, converted_amount)?;
+
+			ensure!(
+				settlement_amount <= max_settlement_amount,
+				Error::<T>::SettlementSlippageExceeded
+			);
+
+			T::TokenSwaps::fill_order(who.clone(), order_id, converted_amount)?;
+			T::Pool::deposit(pool_id, who, converted_amount)?;
+
+			Self::deposit_event(Event::<T>::RepaidWithSettlement {
+				pool_id,
+				loan_id,
+				amount,
+				currency_id,
+				settlement_amount,
+				converted_amount,
+			});
+
+			Ok(Some(T::WeightInfo::repay_with_settlement(count)).into())
+		}
+
+		/// Allows or disallows an account to attest the off-chain collateral
+		/// of the loans of a pool.
+		///
+		/// The origin must be the admin of the pool.
+		#[pallet::weight(T::WeightInfo::set_custodian())]
+		#[pallet::call_index(20)]
+		pub fn set_custodian(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			custodian: T::AccountId,
+			allowed: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::PoolAdmin)?;
+			Self::ensure_pool_exists(pool_id)?;
+
+			match allowed {
+				true => Custodians::<T>::insert(pool_id, &custodian, ()),
+				false => Custodians::<T>::remove(pool_id, &custodian),
+			}
+
+			Self::deposit_event(Event::<T>::CustodianUpdated {
+				pool_id,
+				custodian,
+				allowed,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the period in which the collateral of the active loans of a
+		/// pool should be attested. Passing `None` stops requiring
+		/// attestations.
+		///
+		/// The origin must be the admin of the pool.
+		#[pallet::weight(T::WeightInfo::set_attestation_period())]
+		#[pallet::call_index(21)]
+		pub fn set_attestation_period(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			period: Option<Seconds>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::PoolAdmin)?;
+			Self::ensure_pool_exists(pool_id)?;
+
+			AttestationPeriods::<T>::set(pool_id, period);
+
+			Self::deposit_event(Event::<T>::AttestationPeriodUpdated { pool_id, period });
+
+			Ok(())
+		}
+
+		/// Attests that the off-chain collateral of an active loan is held by
+		/// the custodian, replacing any previous attestation of the loan.
+		///
+		/// The origin must be a custodian of the pool.
+		#[pallet::weight(T::WeightInfo::attest_collateral(T::MaxActiveLoansPerPool::get()))]
+		#[pallet::call_index(22)]
+		pub fn attest_collateral(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			document_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Custodians::<T>::contains_key(pool_id, &who),
+				Error::<T>::NotCustodian
+			);
+
+			let (_, count) = Self::get_active_loan(pool_id, loan_id)?;
+
+			CollateralAttestations::<T>::insert(
+				pool_id,
+				loan_id,
+				CollateralAttestation {
+					custodian: who.clone(),
+					document_hash,
+					attested_at: T::Time::now(),
+				},
+			);
+
+			Self::deposit_event(Event::<T>::CollateralAttested {
+				pool_id,
+				loan_id,
+				custodian: who,
+				document_hash,
+			});
+
+			Ok(Some(T::WeightInfo::attest_collateral(count)).into())
+		}
+
+		/// Propose a structural change of a loan, giving the reason of the
+		/// restructuring. It's applied as any other mutation through
+		/// [`Pallet::apply_loan_mutation()`], and recorded in the
+		/// restructuring history of the loan with the given reason.
+		#[pallet::weight(T::WeightInfo::propose_loan_mutation(T::MaxActiveLoansPerPool::get()))]
+		#[pallet::call_index(23)]
+		pub fn propose_loan_restructuring(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			mutation: LoanMutation<T::Rate>,
+			reason: RestructuringReason,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::LoanAdmin)?;
+
+			ensure!(
+				mutation.is_structural(),
+				Error::<T>::from(MutationError::NotStructural)
+			);
+
+			let (mut loan, count) = Self::get_active_loan(pool_id, loan_id)?;
+			transactional::with_transaction(|| {
+				let result = loan.mutate_with(mutation.clone());
+
+				// We do not want to apply the mutation,
+				// only check if there is no error in applying it
+				TransactionOutcome::Rollback(result)
+			})?;
+
+			T::ChangeGuard::note(
+				pool_id,
+				Change::Restructuring(loan_id, mutation, reason).into(),
+			)?;
+
+			Ok(Some(T::WeightInfo::propose_loan_mutation(count)).into())
+		}
+
+		/// Sets the origination fee deducted from every amount borrowed from
+		/// the pool. Passing `None` stops charging origination fees.
+		///
+		/// The origin must be the admin of the pool.
+		#[pallet::weight(T::WeightInfo::set_origination_fee())]
+		#[pallet::call_index(24)]
+		pub fn set_origination_fee(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			fee: Option<OriginationFee<T::AccountId, T::Rate>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::PoolAdmin)?;
+			Self::ensure_pool_exists(pool_id)?;
+
+			if let Some(fee) = &fee {
+				ensure!(
+					fee.rate <= T::Rate::one(),
+					Error::<T>::InvalidOriginationFee
+				);
+			}
+
+			OriginationFees::<T>::set(pool_id, fee.clone());
+
+			Self::deposit_event(Event::<T>::OriginationFeeUpdated { pool_id, fee });
+
+			Ok(())
+		}
+
+		/// Sets whether the repayments of the pool must settle the outstanding
+		/// interest before the principal, or the other way around.
+		///
+		/// The origin must be the admin of the pool.
+		#[pallet::weight(T::WeightInfo::set_repayment_allocation())]
+		#[pallet::call_index(25)]
+		pub fn set_repayment_allocation(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			allocation: RepaymentAllocation,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_role(pool_id, &who, PoolRole::PoolAdmin)?;
+			Self::ensure_pool_exists(pool_id)?;
+
+			RepaymentAllocations::<T>::insert(pool_id, allocation);
+
+			Self::deposit_event(Event::<T>::RepaymentAllocationUpdated {
+				pool_id,
+				allocation,
+			});
+
 			Ok(())
 		}
 	}
 
 	// Loan actions
 	impl<T: Config> Pallet<T> {
+		fn create_action(
+			who: T::AccountId,
+			pool_id: T::PoolId,
+			info: LoanInfo<T>,
+		) -> DispatchResult {
+			Self::ensure_role(pool_id, &who, PoolRole::Borrower)?;
+			Self::ensure_collateral_owner(&who, info.collateral())?;
+			Self::ensure_pool_exists(pool_id)?;
+
+			info.validate(T::Time::now())?;
+
+			let collateral = info.collateral();
+			T::NonFungible::transfer(&collateral.0, &collateral.1, &T::Pool::account_for(pool_id))?;
+
+			let loan_id = Self::generate_loan_id(pool_id)?;
+			CreatedLoan::<T>::insert(pool_id, loan_id, loans::CreatedLoan::new(info.clone(), who));
+			T::ActivityRecorder::record(ActivityKind::Loan, &(pool_id, loan_id));
+
+			Self::deposit_event(Event::<T>::Created {
+				pool_id,
+				loan_id,
+				loan_info: info,
+			});
+
+			Ok(())
+		}
+
 		fn borrow_action(
 			who: &T::AccountId,
 			pool_id: T::PoolId,
@@ -1004,6 +1622,39 @@ pub mod pallet {
 			Ok((repaid_amount, count))
 		}
 
+		/// Deducts the origination fee of the pool from a borrowed `amount`
+		/// and credits it to the configured destination. Returns the fee.
+		fn charge_origination_fee(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			amount: T::Balance,
+		) -> Result<T::Balance, DispatchError> {
+			let Some(fee) = OriginationFees::<T>::get(pool_id) else {
+				return Ok(Zero::zero());
+			};
+
+			let fee_amount = fee.rate.ensure_mul_int(amount)?;
+			if fee_amount.is_zero() {
+				return Ok(fee_amount);
+			}
+
+			if let OriginationFeeDestination::Originator(originator) = fee.destination {
+				T::Pool::withdraw(pool_id, originator, fee_amount)?;
+			}
+
+			OriginationFeesCharged::<T>::try_mutate(pool_id, loan_id, |charged| {
+				charged.ensure_add_assign(fee_amount)
+			})?;
+
+			Self::deposit_event(Event::<T>::OriginationFeeCharged {
+				pool_id,
+				loan_id,
+				amount: fee_amount,
+			});
+
+			Ok(fee_amount)
+		}
+
 		/// Set the maturity date of the loan to this instant.
 		#[cfg(feature = "runtime-benchmarks")]
 		pub fn expire_action(pool_id: T::PoolId, loan_id: T::LoanId) -> DispatchResult {
@@ -1070,14 +1721,33 @@ pub mod pallet {
 
 		fn find_write_off_rule(
 			pool_id: T::PoolId,
+			loan_id: T::LoanId,
 			loan: &ActiveLoan<T>,
 		) -> Result<Option<WriteOffRule<T::Rate>>, DispatchError> {
 			let rules = WriteOffPolicy::<T>::get(pool_id).into_iter();
 			policy::find_rule(rules, |trigger| {
-				loan.check_write_off_trigger(trigger, pool_id)
+				loan.check_write_off_trigger(trigger, pool_id, loan_id)
 			})
 		}
 
+		fn record_restructuring(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			mutation: LoanMutation<T::Rate>,
+			reason: Option<RestructuringReason>,
+		) {
+			RestructuringHistory::<T>::mutate(pool_id, loan_id, |history| {
+				let record = RestructuringRecord {
+					mutation,
+					reason,
+					applied_at: T::Time::now(),
+				};
+
+				// Only fails if the history can not hold any record
+				let _ = history.force_insert_keep_right(history.len(), record);
+			});
+		}
+
 		fn get_released_change(
 			pool_id: T::PoolId,
 			change_id: T::Hash,
@@ -1123,6 +1793,7 @@ pub mod pallet {
 			let portfolio = portfolio::PortfolioValuation::from_values(T::Time::now(), values)?;
 			let valuation = portfolio.value();
 			PortfolioValuation::<T>::insert(pool_id, portfolio);
+			T::ActivityRecorder::record(ActivityKind::Pool, &pool_id);
 
 			Self::deposit_event(Event::<T>::PortfolioValuationUpdated {
 				pool_id,
@@ -1138,6 +1809,8 @@ pub mod pallet {
 			loan_id: T::LoanId,
 			loan: ActiveLoan<T>,
 		) -> Result<u32, DispatchError> {
+			T::ActivityRecorder::record(ActivityKind::Loan, &(pool_id, loan_id));
+
 			PortfolioValuation::<T>::try_mutate(pool_id, |portfolio| {
 				portfolio.insert_elem(loan_id, loan.present_value(pool_id)?)?;
 
@@ -1165,6 +1838,8 @@ pub mod pallet {
 		where
 			F: FnOnce(&mut ActiveLoan<T>) -> Result<R, DispatchError>,
 		{
+			T::ActivityRecorder::record(ActivityKind::Loan, &(pool_id, loan_id));
+
 			PortfolioValuation::<T>::try_mutate(pool_id, |portfolio| {
 				ActiveLoans::<T>::try_mutate(pool_id, |active_loans| {
 					let (_, loan) = active_loans
@@ -1192,6 +1867,9 @@ pub mod pallet {
 			policy: BoundedVec<WriteOffRule<T::Rate>, T::MaxWriteOffPolicySize>,
 		) -> DispatchResult {
 			WriteOffPolicy::<T>::insert(pool_id, policy.clone());
+			WriteOffPolicyVersion::<T>::mutate(pool_id, |version| {
+				*version = version.saturating_add(1)
+			});
 
 			Self::deposit_event(Event::<T>::WriteOffPolicyUpdated { pool_id, policy });
 
@@ -1263,6 +1941,77 @@ pub mod pallet {
 				.map(|(_, loan)| loan.expected_cashflows())
 				.ok_or(Error::<T>::LoanNotActiveOrNotFound)?
 		}
+
+		pub fn expected_loss(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+			horizon: Seconds,
+		) -> Result<T::Balance, DispatchError> {
+			ActiveLoans::<T>::get(pool_id)
+				.into_iter()
+				.find(|(id, _)| *id == loan_id)
+				.map(|(_, loan)| loan.expected_loss(horizon))
+				.ok_or(Error::<T>::LoanNotActiveOrNotFound)?
+		}
+
+		pub fn portfolio_expected_loss(
+			pool_id: T::PoolId,
+			horizon: Seconds,
+		) -> Result<T::Balance, DispatchError> {
+			ActiveLoans::<T>::get(pool_id)
+				.into_iter()
+				.try_fold(T::Balance::zero(), |total, (_, loan)| {
+					Ok(total.ensure_add(loan.expected_loss(horizon)?)?)
+				})
+		}
+
+		pub fn write_off_policy(pool_id: T::PoolId) -> WriteOffPolicyInfo<T::Rate, T::Hash> {
+			let rules = WriteOffPolicy::<T>::get(pool_id);
+
+			WriteOffPolicyInfo {
+				version: WriteOffPolicyVersion::<T>::get(pool_id),
+				hash: T::Hashing::hash_of(&rules),
+				rules: rules.into_inner(),
+			}
+		}
+
+		/// Structural mutations applied to a loan, from the oldest to the most
+		/// recent one kept.
+		pub fn restructuring_history(
+			pool_id: T::PoolId,
+			loan_id: T::LoanId,
+		) -> Vec<RestructuringRecord<T::Rate>> {
+			RestructuringHistory::<T>::get(pool_id, loan_id).into_inner()
+		}
+
+		/// Attestation status of the active loans of a pool. Empty if the pool
+		/// does not require attestations.
+		pub fn attestation_health(pool_id: T::PoolId) -> Vec<(T::LoanId, AttestationStatus)> {
+			let Some(period) = AttestationPeriods::<T>::get(pool_id) else {
+				return Vec::new();
+			};
+
+			let now = T::Time::now();
+
+			ActiveLoans::<T>::get(pool_id)
+				.into_iter()
+				.map(|(loan_id, _)| {
+					let status = match CollateralAttestations::<T>::get(pool_id, loan_id) {
+						None => AttestationStatus::Missing,
+						Some(CollateralAttestation { attested_at, .. })
+							if now > attested_at.saturating_add(period) =>
+						{
+							AttestationStatus::Stale { attested_at }
+						}
+						Some(CollateralAttestation { attested_at, .. }) => {
+							AttestationStatus::Valid { attested_at }
+						}
+					};
+
+					(loan_id, status)
+				})
+				.collect()
+		}
 	}
 
 	// TODO: This implementation can be cleaned once #908 be solved
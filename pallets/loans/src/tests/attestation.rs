@@ -0,0 +1,261 @@
+use sp_core::H256;
+
+use super::*;
+use crate::{pallet::CollateralAttestations, types::AttestationStatus};
+
+const CUSTODIAN: AccountId = 20;
+const DOCUMENT_HASH: H256 = H256::repeat_byte(0x01);
+
+fn config_mocks() {
+	MockPermissions::mock_has(|scope, who, role| {
+		matches!(scope, PermissionScope::Pool(id) if id == POOL_A)
+			&& matches!(role, Role::PoolRole(PoolRole::PoolAdmin))
+			&& who == POOL_ADMIN
+	});
+	MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A);
+}
+
+fn set_up_custodian() {
+	assert_ok!(Loans::set_custodian(
+		RuntimeOrigin::signed(POOL_ADMIN),
+		POOL_A,
+		CUSTODIAN,
+		true
+	));
+}
+
+fn set_up_period(period: Duration) {
+	assert_ok!(Loans::set_attestation_period(
+		RuntimeOrigin::signed(POOL_ADMIN),
+		POOL_A,
+		Some(period.as_secs())
+	));
+}
+
+fn attest(loan_id: LoanId) {
+	assert_ok!(Loans::attest_collateral(
+		RuntimeOrigin::signed(CUSTODIAN),
+		POOL_A,
+		loan_id,
+		DOCUMENT_HASH
+	));
+}
+
+fn set_up_attestation_policy(period: Duration) {
+	MockPermissions::mock_has(|_, _, _| true);
+	MockPools::mock_pool_exists(|_| true);
+	MockChangeGuard::mock_released(move |_, _| {
+		Ok(Change::Policy(
+			vec![WriteOffRule::new(
+				[WriteOffTrigger::AttestationOutdated(period.as_secs())],
+				Rate::from_float(POLICY_PERCENTAGE),
+				Rate::from_float(POLICY_PENALTY),
+			)]
+			.try_into()
+			.unwrap(),
+		))
+	});
+
+	assert_ok!(Loans::apply_write_off_policy(
+		RuntimeOrigin::signed(ANY),
+		POOL_A,
+		CHANGE_ID
+	));
+}
+
+#[test]
+fn set_custodian_with_wrong_permissions() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+
+		assert_noop!(
+			Loans::set_custodian(RuntimeOrigin::signed(ANY), POOL_A, CUSTODIAN, true),
+			BadOrigin
+		);
+		assert_noop!(
+			Loans::set_attestation_period(RuntimeOrigin::signed(ANY), POOL_A, Some(1)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn attest_without_custodian() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		assert_noop!(
+			Loans::attest_collateral(
+				RuntimeOrigin::signed(CUSTODIAN),
+				POOL_A,
+				loan_id,
+				DOCUMENT_HASH
+			),
+			Error::<Runtime>::NotCustodian
+		);
+
+		config_mocks();
+		set_up_custodian();
+		assert_ok!(Loans::set_custodian(
+			RuntimeOrigin::signed(POOL_ADMIN),
+			POOL_A,
+			CUSTODIAN,
+			false
+		));
+
+		assert_noop!(
+			Loans::attest_collateral(
+				RuntimeOrigin::signed(CUSTODIAN),
+				POOL_A,
+				loan_id,
+				DOCUMENT_HASH
+			),
+			Error::<Runtime>::NotCustodian
+		);
+	});
+}
+
+#[test]
+fn attest_not_active_loan() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+
+		config_mocks();
+		set_up_custodian();
+
+		assert_noop!(
+			Loans::attest_collateral(
+				RuntimeOrigin::signed(CUSTODIAN),
+				POOL_A,
+				loan_id,
+				DOCUMENT_HASH
+			),
+			Error::<Runtime>::LoanNotActiveOrNotFound
+		);
+	});
+}
+
+#[test]
+fn attest_with_success() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		config_mocks();
+		set_up_custodian();
+		attest(loan_id);
+
+		let attestation = CollateralAttestations::<Runtime>::get(POOL_A, loan_id).unwrap();
+		assert_eq!(attestation.custodian, CUSTODIAN);
+		assert_eq!(attestation.document_hash, DOCUMENT_HASH);
+		assert_eq!(attestation.attested_at, now().as_secs());
+
+		System::assert_last_event(RuntimeEvent::Loans(Event::CollateralAttested {
+			pool_id: POOL_A,
+			loan_id,
+			custodian: CUSTODIAN,
+			document_hash: DOCUMENT_HASH,
+		}));
+	});
+}
+
+#[test]
+fn health_without_period() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		assert_eq!(Loans::attestation_health(POOL_A), vec![]);
+	});
+}
+
+#[test]
+fn health_status() {
+	new_test_ext().execute_with(|| {
+		let attested_loan = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(attested_loan, PrincipalInput::Internal(COLLATERAL_VALUE));
+		let missing_loan = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(missing_loan, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		config_mocks();
+		set_up_custodian();
+		set_up_period(DAY);
+		attest(attested_loan);
+
+		let attested_at = now().as_secs();
+
+		assert_eq!(
+			Loans::attestation_health(POOL_A),
+			vec![
+				(attested_loan, AttestationStatus::Valid { attested_at }),
+				(missing_loan, AttestationStatus::Missing),
+			]
+		);
+
+		advance_time(DAY + BLOCK_TIME);
+
+		assert_eq!(
+			Loans::attestation_health(POOL_A),
+			vec![
+				(attested_loan, AttestationStatus::Stale { attested_at }),
+				(missing_loan, AttestationStatus::Missing),
+			]
+		);
+	});
+}
+
+#[test]
+fn write_off_with_outdated_attestation() {
+	new_test_ext().execute_with(|| {
+		set_up_attestation_policy(DAY);
+
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		config_mocks();
+		set_up_custodian();
+
+		advance_time(DAY / 2);
+		attest(loan_id);
+
+		// Outdated since the origination date, but attested later
+		advance_time(DAY / 2 + BLOCK_TIME);
+		assert_noop!(
+			Loans::write_off(RuntimeOrigin::signed(ANY), POOL_A, loan_id),
+			Error::<Runtime>::NoValidWriteOffRule
+		);
+
+		advance_time(DAY / 2);
+		assert_ok!(Loans::write_off(
+			RuntimeOrigin::signed(ANY),
+			POOL_A,
+			loan_id
+		));
+
+		assert_eq!(
+			WriteOffStatus {
+				percentage: Rate::from_float(POLICY_PERCENTAGE),
+				penalty: Rate::from_float(POLICY_PENALTY),
+			},
+			util::get_loan(loan_id).write_off_status()
+		);
+	});
+}
+
+#[test]
+fn write_off_without_attestation() {
+	new_test_ext().execute_with(|| {
+		set_up_attestation_policy(DAY);
+
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		advance_time(DAY);
+		assert_ok!(Loans::write_off(
+			RuntimeOrigin::signed(ANY),
+			POOL_A,
+			loan_id
+		));
+	});
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::weights::WeightInfo;
 
 /// Used where the error comes from other pallet impl. unknown from the tests
 fn config_mocks(withdraw_amount: Balance) {
@@ -60,6 +61,25 @@ fn from_other_borrower() {
 	});
 }
 
+#[test]
+fn with_actual_weight() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+
+		config_mocks(COLLATERAL_VALUE);
+		let post_info = Loans::borrow(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			PrincipalInput::Internal(COLLATERAL_VALUE),
+		)
+		.unwrap();
+
+		// Only the loan being borrowed is active in the pool
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::borrow(1)));
+	});
+}
+
 #[test]
 fn with_restriction_no_written_off() {
 	new_test_ext().execute_with(|| {
@@ -0,0 +1,130 @@
+use super::*;
+
+fn assert_close(value: Balance, expected: Balance) {
+	assert!(
+		value.abs_diff(expected) <= 1,
+		"expected {expected}, got {value}"
+	);
+}
+
+#[test]
+fn with_wrong_loan_id() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Loans::expected_loss(POOL_A, 0, YEAR.as_secs()),
+			Error::<Runtime>::LoanNotActiveOrNotFound
+		);
+	});
+}
+
+#[test]
+fn without_active_loans() {
+	new_test_ext().execute_with(|| {
+		util::create_loan(util::dcf_internal_loan());
+
+		assert_eq!(
+			Loans::portfolio_expected_loss(POOL_A, YEAR.as_secs()),
+			Ok(0)
+		);
+	});
+}
+
+#[test]
+fn with_dcf_internal_loan() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::dcf_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		let pd_lgd = DEFAULT_PROBABILITY_OF_DEFAULT * DEFAULT_LOSS_GIVEN_DEFAULT;
+
+		assert_close(
+			Loans::expected_loss(POOL_A, loan_id, YEAR.as_secs()).unwrap(),
+			(COLLATERAL_VALUE as f64 * pd_lgd) as Balance,
+		);
+		assert_close(
+			Loans::expected_loss(POOL_A, loan_id, (YEAR / 2).as_secs()).unwrap(),
+			(COLLATERAL_VALUE as f64 * pd_lgd / 2.0) as Balance,
+		);
+		assert_eq!(Loans::expected_loss(POOL_A, loan_id, 0), Ok(0));
+	});
+}
+
+#[test]
+fn with_horizon_longer_than_total_loss() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::dcf_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		// PD * LGD = 5% per year, so after 20 years the whole exposure is lost
+		assert_eq!(
+			Loans::expected_loss(POOL_A, loan_id, (YEAR * 30).as_secs()),
+			Ok(COLLATERAL_VALUE)
+		);
+	});
+}
+
+#[test]
+fn with_accrued_interest() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::dcf_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		advance_time(YEAR / 2);
+
+		let pd_lgd = DEFAULT_PROBABILITY_OF_DEFAULT * DEFAULT_LOSS_GIVEN_DEFAULT;
+		let debt = util::current_loan_debt(loan_id);
+
+		assert_close(
+			Loans::expected_loss(POOL_A, loan_id, YEAR.as_secs()).unwrap(),
+			(debt as f64 * pd_lgd) as Balance,
+		);
+	});
+}
+
+#[test]
+fn without_default_assumptions() {
+	new_test_ext().execute_with(|| {
+		let loan_1 = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_1, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		let loan_2 = util::create_loan(LoanInfo {
+			collateral: ASSET_BA,
+			..util::base_external_loan()
+		});
+		let amount = ExternalAmount::new(QUANTITY, PRICE_VALUE);
+		util::borrow_loan(loan_2, PrincipalInput::External(amount));
+
+		assert_eq!(Loans::expected_loss(POOL_A, loan_1, YEAR.as_secs()), Ok(0));
+		assert_eq!(Loans::expected_loss(POOL_A, loan_2, YEAR.as_secs()), Ok(0));
+	});
+}
+
+#[test]
+fn aggregated_by_pool() {
+	new_test_ext().execute_with(|| {
+		let loan_1 = util::create_loan(util::dcf_internal_loan());
+		util::borrow_loan(loan_1, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		let loan_2 = util::create_loan(LoanInfo {
+			collateral: ASSET_BA,
+			..util::dcf_internal_loan()
+		});
+		util::borrow_loan(loan_2, PrincipalInput::Internal(COLLATERAL_VALUE / 2));
+
+		let loan_3 = util::create_loan(LoanInfo {
+			collateral: ASSET_BB,
+			..util::base_internal_loan()
+		});
+		util::borrow_loan(loan_3, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		let horizon = YEAR.as_secs();
+		let expected = Loans::expected_loss(POOL_A, loan_1, horizon).unwrap()
+			+ Loans::expected_loss(POOL_A, loan_2, horizon).unwrap();
+
+		assert_eq!(
+			Loans::portfolio_expected_loss(POOL_A, horizon),
+			Ok(expected)
+		);
+		assert_eq!(Loans::portfolio_expected_loss(POOL_B, horizon), Ok(0));
+	});
+}
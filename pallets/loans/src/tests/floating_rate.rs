@@ -0,0 +1,96 @@
+use cfg_traits::interest::ReferenceRateId;
+
+use super::*;
+
+const SOFR: ReferenceRateId = *b"SOFR\0\0\0\0\0\0\0\0\0\0\0\0";
+
+fn config_mocks(reference_rate: Option<Rate>) {
+	MockReferenceRates::mock_get(move |_, reference| {
+		assert_eq!(*reference, SOFR);
+		Ok(reference_rate)
+	});
+}
+
+/// Floating rate equivalent to `util::default_interest_rate()` with a reference
+/// rate of 30%.
+fn floating_interest_rate() -> InterestRate<Rate> {
+	InterestRate::Floating {
+		reference: SOFR,
+		spread: Rate::from_rational(2, 10),
+		compounding: CompoundingSchedule::Secondly,
+	}
+}
+
+#[test]
+fn accrues_as_equivalent_fixed_rate() {
+	new_test_ext().execute_with(|| {
+		config_mocks(Some(Rate::from_rational(3, 10)));
+
+		let fixed_loan_id = util::create_loan(util::dcf_internal_loan());
+		let floating_loan_id = util::create_loan(LoanInfo {
+			interest_rate: floating_interest_rate(),
+			collateral: ASSET_BA,
+			..util::dcf_internal_loan()
+		});
+
+		util::borrow_loan(fixed_loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+		util::borrow_loan(floating_loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		advance_time(YEAR / 2);
+
+		assert!(util::current_loan_debt(floating_loan_id) > COLLATERAL_VALUE);
+		assert_eq!(
+			util::current_loan_debt(floating_loan_id),
+			util::current_loan_debt(fixed_loan_id)
+		);
+		assert_eq!(
+			util::current_loan_pv(floating_loan_id),
+			util::current_loan_pv(fixed_loan_id)
+		);
+	});
+}
+
+#[test]
+fn with_floating_discount_rate() {
+	new_test_ext().execute_with(|| {
+		config_mocks(Some(Rate::from_rational(3, 10)));
+		MockPermissions::mock_has(|_, _, _| true);
+		MockPools::mock_pool_exists(|_| true);
+
+		let loan = LoanInfo {
+			pricing: Pricing::Internal(InternalPricing {
+				valuation_method: ValuationMethod::DiscountedCashFlow(DiscountedCashFlow {
+					probability_of_default: Rate::from_float(DEFAULT_PROBABILITY_OF_DEFAULT),
+					loss_given_default: Rate::from_float(DEFAULT_LOSS_GIVEN_DEFAULT),
+					discount_rate: floating_interest_rate(),
+				}),
+				..util::base_internal_pricing()
+			}),
+			..util::base_internal_loan()
+		};
+
+		assert_noop!(
+			Loans::create(RuntimeOrigin::signed(BORROWER), POOL_A, loan),
+			Error::<Runtime>::from(CreateLoanError::InvalidValuationMethod)
+		);
+	});
+}
+
+#[test]
+fn without_reference_rate_value() {
+	new_test_ext().execute_with(|| {
+		config_mocks(None);
+		MockPermissions::mock_has(|_, _, _| true);
+		MockPools::mock_pool_exists(|_| true);
+
+		let loan = LoanInfo {
+			interest_rate: floating_interest_rate(),
+			..util::base_internal_loan()
+		};
+
+		assert_noop!(
+			Loans::create(RuntimeOrigin::signed(BORROWER), POOL_A, loan),
+			pallet_interest_accrual::Error::<Runtime>::ReferenceRateNotFound
+		);
+	});
+}
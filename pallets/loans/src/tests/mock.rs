@@ -14,15 +14,16 @@
 use std::time::Duration;
 
 use cfg_mocks::{
-	pallet_mock_change_guard, pallet_mock_data, pallet_mock_permissions, pallet_mock_pools,
+	pallet_mock_change_guard, pallet_mock_data, pallet_mock_keeper_rewards,
+	pallet_mock_permissions, pallet_mock_pools, pallet_mock_token_swaps,
 };
-use cfg_traits::Millis;
+use cfg_traits::{interest::ReferenceRateId, keeper::KeeperAction, Millis};
 use cfg_types::permissions::PermissionScope;
 use frame_support::{
 	derive_impl,
 	traits::{
 		tokens::nonfungibles::{Create, Mutate},
-		AsEnsureOriginWithArg, Hooks, UnixTime,
+		AsEnsureOriginWithArg, UnixTime,
 	},
 };
 use frame_system::{EnsureRoot, EnsureSigned};
@@ -92,6 +93,7 @@ pub type TrancheId = u64;
 pub type LoanId = u64;
 pub type PriceId = u64;
 pub type ChangeId = H256;
+pub type OrderId = u64;
 
 frame_support::construct_runtime!(
 	pub enum Runtime {
@@ -99,19 +101,25 @@ frame_support::construct_runtime!(
 		MockTimer: cfg_mocks::time::pallet,
 		Balances: pallet_balances,
 		Uniques: pallet_uniques,
+		MockReferenceRates: cfg_mocks::value_provider::pallet,
 		InterestAccrual: pallet_interest_accrual,
 		MockPools: pallet_mock_pools,
 		MockPermissions: pallet_mock_permissions,
 		MockPrices: pallet_mock_data,
 		MockChangeGuard: pallet_mock_change_guard,
+		MockKeeperRewards: pallet_mock_keeper_rewards,
+		MockTokenSwaps: pallet_mock_token_swaps,
 		Loans: pallet_loans,
 	}
 );
 
 frame_support::parameter_types! {
 	pub const MaxActiveLoansPerPool: u32 = 5;
+	pub const MaxRateSnapshots: u32 = 3;
+	pub const SnapshotPeriod: u64 = 24 * 3600;
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxWriteOffPolicySize: u32 = 4;
+	pub const MaxRestructurings: u32 = 2;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
@@ -154,11 +162,20 @@ impl pallet_uniques::Config for Runtime {
 	type WeightInfo = ();
 }
 
+impl cfg_mocks::value_provider::pallet::Config for Runtime {
+	type Key = ReferenceRateId;
+	type Source = ();
+	type Value = Rate;
+}
+
 impl pallet_interest_accrual::Config for Runtime {
 	type Balance = Balance;
 	type MaxRateCount = MaxActiveLoansPerPool;
+	type MaxRateSnapshots = MaxRateSnapshots;
 	type Rate = Rate;
+	type ReferenceRates = MockReferenceRates;
 	type RuntimeEvent = RuntimeEvent;
+	type SnapshotPeriod = SnapshotPeriod;
 	type Time = MockTimer;
 	type Weights = ();
 }
@@ -189,18 +206,35 @@ impl pallet_mock_change_guard::Config for Runtime {
 	type PoolId = PoolId;
 }
 
+impl pallet_mock_keeper_rewards::Config for Runtime {
+	type Action = KeeperAction;
+	type Balance = Balance;
+}
+
+impl pallet_mock_token_swaps::Config for Runtime {
+	type BalanceIn = Balance;
+	type BalanceOut = Balance;
+	type CurrencyId = CurrencyId;
+	type OrderId = OrderId;
+	type Ratio = Quantity;
+}
+
 impl pallet_loans::Config for Runtime {
+	type ActivityRecorder = ();
 	type Balance = Balance;
 	type ChangeGuard = MockChangeGuard;
 	type CollectionId = CollectionId;
 	type CurrencyId = CurrencyId;
 	type InterestAccrual = InterestAccrual;
 	type ItemId = ItemId;
+	type KeeperRewards = MockKeeperRewards;
 	type LoanId = LoanId;
 	type MaxActiveLoansPerPool = MaxActiveLoansPerPool;
+	type MaxRestructurings = MaxRestructurings;
 	type MaxWriteOffPolicySize = MaxWriteOffPolicySize;
 	type Moment = Millis;
 	type NonFungible = Uniques;
+	type OrderId = OrderId;
 	type PerThing = Perbill;
 	type Permissions = MockPermissions;
 	type Pool = MockPools;
@@ -212,6 +246,7 @@ impl pallet_loans::Config for Runtime {
 	type RuntimeChange = Change<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = MockTimer;
+	type TokenSwaps = MockTokenSwaps;
 	type WeightInfo = ();
 }
 
@@ -219,6 +254,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	let mut ext = System::externalities();
 	ext.execute_with(|| {
 		MockTimer::mock_now(|| BLOCK_TIME.as_millis() as u64);
+		MockKeeperRewards::mock_reward(|_, _| 0);
 
 		Uniques::create_collection(&COLLECTION_A, &BORROWER, &ASSET_COLLECTION_OWNER).unwrap();
 		Uniques::mint_into(&COLLECTION_A, &ASSET_AA.1, &BORROWER).unwrap();
@@ -239,5 +275,4 @@ pub fn now() -> Duration {
 pub fn advance_time(elapsed: Duration) {
 	let before = now();
 	MockTimer::mock_now(move || (before + elapsed).as_millis() as u64);
-	InterestAccrual::on_initialize(0);
 }
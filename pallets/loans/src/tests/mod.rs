@@ -2,7 +2,10 @@ use std::time::Duration;
 
 use cfg_mocks::pallet_mock_data::util::MockDataCollection;
 use cfg_primitives::{SECONDS_PER_DAY, SECONDS_PER_YEAR};
-use cfg_traits::interest::{CompoundingSchedule, InterestRate};
+use cfg_traits::{
+	interest::{CompoundingSchedule, InterestRate},
+	keeper::KeeperAction,
+};
 use cfg_types::permissions::{PermissionScope, PoolRole, Role};
 use frame_support::{assert_noop, assert_ok, storage::bounded_vec::BoundedVec};
 use sp_runtime::{
@@ -12,7 +15,9 @@ use sp_runtime::{
 
 use super::{
 	entities::{
-		changes::{Change, InternalMutation, LoanMutation},
+		changes::{
+			Change, InternalMutation, LoanMutation, RestructuringReason, RestructuringRecord,
+		},
 		input::{PrincipalInput, RepaidInput},
 		loans::{ActiveLoan, ActiveLoanInfo, LoanInfo},
 		pricing::{
@@ -24,26 +29,35 @@ use super::{
 			ActivePricing, Pricing,
 		},
 	},
-	pallet::{ActiveLoans, CreatedLoan, Error, Event, LastLoanId, PortfolioValuation},
+	pallet::{ActiveLoans, CreatedLoan, Error, Event, LastLoanId, PortfolioValuation, RateQuotes},
 	types::{
 		cashflow::{InterestPayments, Maturity, PayDownSchedule, RepaymentSchedule},
 		policy::{WriteOffRule, WriteOffStatus, WriteOffTrigger},
 		valuation::{DiscountedCashFlow, ValuationMethod},
 		BorrowLoanError, BorrowRestrictions, CloseLoanError, CreateLoanError, LoanRestrictions,
-		MutationError, RepayLoanError, RepayRestrictions, WrittenOffError,
+		MutationError, RateQuote, RateQuoteSettings, RepayLoanError, RepayRestrictions,
+		WrittenOffError,
 	},
 };
 
 pub mod mock;
 use mock::*;
 
+mod attestation;
 mod borrow_loan;
 mod close_loan;
 mod create_loan;
+mod expected_loss;
+mod floating_rate;
 mod mutate_loan;
+mod origination_fee;
 mod policy;
 mod portfolio_valuation;
+mod rate_quote;
 mod repay_loan;
+mod repay_settlement;
+mod repayment_allocation;
+mod revolving;
 mod transfer_debt;
 mod util;
 mod write_off_loan;
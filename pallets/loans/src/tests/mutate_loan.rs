@@ -298,3 +298,167 @@ fn with_successful_mutation_application() {
 		}
 	});
 }
+
+mod restructuring {
+	use super::*;
+
+	const REASON: RestructuringReason = 3;
+
+	fn extension() -> LoanMutation<Rate> {
+		LoanMutation::MaturityExtension(SECONDS_PER_DAY)
+	}
+
+	fn config_restructuring_mocks(loan_id: LoanId, mutation: &LoanMutation<Rate>) {
+		config_mocks(loan_id, mutation);
+
+		MockChangeGuard::mock_note({
+			let mutation = mutation.clone();
+			move |_, change| {
+				assert_eq!(
+					change,
+					Change::Restructuring(loan_id, mutation.clone(), REASON)
+				);
+				Ok(CHANGE_ID)
+			}
+		});
+
+		MockChangeGuard::mock_released({
+			let mutation = mutation.clone();
+			move |_, _| Ok(Change::Restructuring(loan_id, mutation.clone(), REASON))
+		});
+	}
+
+	fn restructure(loan_id: LoanId, mutation: LoanMutation<Rate>) {
+		config_restructuring_mocks(loan_id, &mutation);
+
+		assert_ok!(Loans::propose_loan_restructuring(
+			RuntimeOrigin::signed(LOAN_ADMIN),
+			POOL_A,
+			loan_id,
+			mutation,
+			REASON,
+		));
+		assert_ok!(Loans::apply_loan_mutation(
+			RuntimeOrigin::signed(ANY),
+			POOL_A,
+			CHANGE_ID,
+		));
+	}
+
+	#[test]
+	fn with_non_structural_mutation() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_internal_loan());
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			let mutation = LoanMutation::Internal(InternalMutation::ProbabilityOfDefault(
+				Rate::from_float(0.5),
+			));
+
+			config_restructuring_mocks(loan_id, &mutation);
+			assert_noop!(
+				Loans::propose_loan_restructuring(
+					RuntimeOrigin::signed(LOAN_ADMIN),
+					POOL_A,
+					loan_id,
+					mutation,
+					REASON,
+				),
+				Error::<Runtime>::MutationError(MutationError::NotStructural)
+			);
+		});
+	}
+
+	#[test]
+	fn with_wrong_permissions() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_internal_loan());
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			config_restructuring_mocks(loan_id, &extension());
+			assert_noop!(
+				Loans::propose_loan_restructuring(
+					RuntimeOrigin::signed(BORROWER),
+					POOL_A,
+					loan_id,
+					extension(),
+					REASON,
+				),
+				BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn records_the_reason() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_internal_loan());
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			restructure(loan_id, extension());
+
+			assert_eq!(
+				Loans::restructuring_history(POOL_A, loan_id),
+				vec![RestructuringRecord {
+					mutation: extension(),
+					reason: Some(REASON),
+					applied_at: now().as_secs(),
+				}]
+			);
+		});
+	}
+
+	#[test]
+	fn records_structural_mutations_without_reason() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_internal_loan());
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			let internal = LoanMutation::Internal(InternalMutation::ValuationMethod(
+				ValuationMethod::OutstandingDebt,
+			));
+			for mutation in [internal, extension()] {
+				config_mocks(loan_id, &mutation);
+				assert_ok!(Loans::apply_loan_mutation(
+					RuntimeOrigin::signed(ANY),
+					POOL_A,
+					CHANGE_ID,
+				));
+			}
+
+			assert_eq!(
+				Loans::restructuring_history(POOL_A, loan_id),
+				vec![RestructuringRecord {
+					mutation: extension(),
+					reason: None,
+					applied_at: now().as_secs(),
+				}]
+			);
+		});
+	}
+
+	#[test]
+	fn keeps_the_latest_records() {
+		new_test_ext().execute_with(|| {
+			let loan_id = util::create_loan(util::base_internal_loan());
+			util::borrow_loan(loan_id, PrincipalInput::Internal(0));
+
+			// One more than `MaxRestructurings`
+			let extensions: Vec<_> = (1..=3)
+				.map(|days| LoanMutation::MaturityExtension(days * SECONDS_PER_DAY))
+				.collect();
+
+			for mutation in extensions.clone() {
+				restructure(loan_id, mutation);
+			}
+
+			assert_eq!(
+				Loans::restructuring_history(POOL_A, loan_id)
+					.into_iter()
+					.map(|record| record.mutation)
+					.collect::<Vec<_>>(),
+				extensions[1..]
+			);
+		});
+	}
+}
@@ -0,0 +1,156 @@
+use super::*;
+use crate::{
+	pallet::OriginationFeesCharged,
+	types::{OriginationFee, OriginationFeeDestination},
+};
+
+const ORIGINATOR: AccountId = 20;
+
+fn config_mocks() {
+	MockPermissions::mock_has(|scope, who, role| {
+		matches!(scope, PermissionScope::Pool(id) if id == POOL_A)
+			&& matches!(role, Role::PoolRole(PoolRole::PoolAdmin))
+			&& who == POOL_ADMIN
+	});
+	MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A);
+}
+
+fn set_up_fee(rate: Rate, destination: OriginationFeeDestination<AccountId>) {
+	config_mocks();
+	assert_ok!(Loans::set_origination_fee(
+		RuntimeOrigin::signed(POOL_ADMIN),
+		POOL_A,
+		Some(OriginationFee { rate, destination })
+	));
+}
+
+fn config_borrow_mocks(expected_withdrawals: Vec<(AccountId, Balance)>) {
+	let withdrawals = std::cell::RefCell::new(expected_withdrawals.into_iter());
+	MockPools::mock_withdraw(move |pool_id, to, amount| {
+		assert_eq!(pool_id, POOL_A);
+		assert_eq!(
+			withdrawals.borrow_mut().next(),
+			Some((to, amount)),
+			"unexpected withdrawal"
+		);
+		Ok(())
+	});
+	MockPrices::mock_get(|_, _| Ok((PRICE_VALUE, BLOCK_TIME_MS)));
+	MockPrices::mock_register_id(|_, _| Ok(()));
+}
+
+#[test]
+fn set_origination_fee_with_wrong_permissions() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+
+		assert_noop!(
+			Loans::set_origination_fee(
+				RuntimeOrigin::signed(ANY),
+				POOL_A,
+				Some(OriginationFee {
+					rate: Rate::from_float(0.01),
+					destination: OriginationFeeDestination::Reserve,
+				})
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_origination_fee_above_one() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+
+		assert_noop!(
+			Loans::set_origination_fee(
+				RuntimeOrigin::signed(POOL_ADMIN),
+				POOL_A,
+				Some(OriginationFee {
+					rate: Rate::from_float(1.01),
+					destination: OriginationFeeDestination::Reserve,
+				})
+			),
+			Error::<Runtime>::InvalidOriginationFee
+		);
+	});
+}
+
+#[test]
+fn borrow_pays_fee_to_originator() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		set_up_fee(
+			Rate::from_float(0.01),
+			OriginationFeeDestination::Originator(ORIGINATOR),
+		);
+
+		config_borrow_mocks(vec![(ORIGINATOR, 10), (BORROWER, 990)]);
+		assert_ok!(Loans::borrow(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			PrincipalInput::Internal(1000)
+		));
+
+		// The whole amount is borrowed
+		assert_eq!(util::current_loan_debt(loan_id), 1000);
+		assert_eq!(OriginationFeesCharged::<Runtime>::get(POOL_A, loan_id), 10);
+
+		config_borrow_mocks(vec![(ORIGINATOR, 5), (BORROWER, 495)]);
+		assert_ok!(Loans::borrow(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			PrincipalInput::Internal(500)
+		));
+
+		assert_eq!(OriginationFeesCharged::<Runtime>::get(POOL_A, loan_id), 15);
+	});
+}
+
+#[test]
+fn borrow_keeps_fee_in_reserve() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		set_up_fee(Rate::from_float(0.02), OriginationFeeDestination::Reserve);
+
+		config_borrow_mocks(vec![(BORROWER, 980)]);
+		assert_ok!(Loans::borrow(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			PrincipalInput::Internal(1000)
+		));
+
+		assert_eq!(util::current_loan_debt(loan_id), 1000);
+		assert_eq!(OriginationFeesCharged::<Runtime>::get(POOL_A, loan_id), 20);
+	});
+}
+
+#[test]
+fn borrow_without_fee() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(util::base_internal_loan());
+		set_up_fee(
+			Rate::from_float(0.01),
+			OriginationFeeDestination::Originator(ORIGINATOR),
+		);
+		assert_ok!(Loans::set_origination_fee(
+			RuntimeOrigin::signed(POOL_ADMIN),
+			POOL_A,
+			None
+		));
+
+		config_borrow_mocks(vec![(BORROWER, 1000)]);
+		assert_ok!(Loans::borrow(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			loan_id,
+			PrincipalInput::Internal(1000)
+		));
+
+		assert_eq!(OriginationFeesCharged::<Runtime>::get(POOL_A, loan_id), 0);
+	});
+}
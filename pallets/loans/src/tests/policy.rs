@@ -234,3 +234,33 @@ fn with_success() {
 		);
 	});
 }
+
+#[test]
+fn policy_info_after_apply() {
+	new_test_ext().execute_with(|| {
+		let policy: BoundedVec<_, _> = vec![WriteOffRule::new(
+			[WriteOffTrigger::PrincipalOverdue(1)],
+			Rate::from_float(POLICY_PERCENTAGE),
+			Rate::from_float(POLICY_PENALTY),
+		)]
+		.try_into()
+		.unwrap();
+
+		config_mocks(POOL_A, &policy);
+
+		let initial = Loans::write_off_policy(POOL_A);
+		assert_eq!(initial.version, 0);
+		assert!(initial.rules.is_empty());
+
+		assert_ok!(Loans::apply_write_off_policy(
+			RuntimeOrigin::signed(ANY),
+			POOL_A,
+			CHANGE_ID
+		));
+
+		let info = Loans::write_off_policy(POOL_A);
+		assert_eq!(info.version, 1);
+		assert_eq!(info.rules, policy.into_inner());
+		assert_ne!(info.hash, initial.hash);
+	});
+}
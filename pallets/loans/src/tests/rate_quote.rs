@@ -0,0 +1,192 @@
+use super::*;
+
+const QUOTE_WINDOW: Duration = DAY;
+
+fn config_mocks() {
+	MockPermissions::mock_has(|scope, who, role| {
+		matches!(scope, PermissionScope::Pool(id) if id == POOL_A)
+			&& match role {
+				Role::PoolRole(PoolRole::Borrower) => who == BORROWER,
+				Role::PoolRole(PoolRole::PoolAdmin) => who == POOL_ADMIN,
+				_ => false,
+			}
+	});
+	MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A);
+	MockPools::mock_account_for(|_| POOL_A_ACCOUNT);
+}
+
+fn settings() -> RateQuoteSettings<Rate> {
+	RateQuoteSettings {
+		benchmark_rate: Rate::from_float(0.04),
+		spread: Rate::from_float(0.02),
+		window: QUOTE_WINDOW.as_secs(),
+	}
+}
+
+fn quoted_rate() -> Rate {
+	Rate::from_float(0.06)
+}
+
+fn set_settings() {
+	assert_ok!(Loans::set_rate_quote_settings(
+		RuntimeOrigin::signed(POOL_ADMIN),
+		POOL_A,
+		Some(settings())
+	));
+}
+
+#[test]
+fn set_settings_with_wrong_permissions() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+
+		assert_noop!(
+			Loans::set_rate_quote_settings(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				Some(settings())
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn request_without_settings() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+
+		assert_noop!(
+			Loans::request_rate_quote(RuntimeOrigin::signed(BORROWER), POOL_A),
+			Error::<Runtime>::RateQuoteSettingsNotFound
+		);
+	});
+}
+
+#[test]
+fn request_with_wrong_permissions() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+		set_settings();
+
+		assert_noop!(
+			Loans::request_rate_quote(RuntimeOrigin::signed(NO_BORROWER), POOL_A),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn request_locks_benchmark_plus_spread() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+		set_settings();
+
+		assert_ok!(Loans::request_rate_quote(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A
+		));
+
+		let quote = RateQuote {
+			rate_per_year: quoted_rate(),
+			expires_at: (BLOCK_TIME + QUOTE_WINDOW).as_secs(),
+		};
+
+		assert_eq!(
+			RateQuotes::<Runtime>::get(POOL_A, BORROWER),
+			Some(quote.clone())
+		);
+
+		System::assert_last_event(RuntimeEvent::Loans(Event::RateQuoted {
+			pool_id: POOL_A,
+			borrower: BORROWER,
+			quote,
+		}));
+	});
+}
+
+#[test]
+fn create_with_quote_uses_locked_rate() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+		set_settings();
+
+		assert_ok!(Loans::request_rate_quote(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A
+		));
+
+		// The benchmark moves after the quote was locked
+		assert_ok!(Loans::set_rate_quote_settings(
+			RuntimeOrigin::signed(POOL_ADMIN),
+			POOL_A,
+			Some(RateQuoteSettings {
+				benchmark_rate: Rate::from_float(0.08),
+				..settings()
+			})
+		));
+
+		advance_time(QUOTE_WINDOW);
+
+		assert_ok!(Loans::create_with_quote(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A,
+			util::base_internal_loan()
+		));
+
+		System::assert_last_event(RuntimeEvent::Loans(Event::Created {
+			pool_id: POOL_A,
+			loan_id: LastLoanId::<Runtime>::get(POOL_A),
+			loan_info: LoanInfo {
+				interest_rate: InterestRate::Fixed {
+					rate_per_year: quoted_rate(),
+					compounding: CompoundingSchedule::Secondly,
+				},
+				..util::base_internal_loan()
+			},
+		}));
+
+		// The quote is consumed
+		assert_eq!(RateQuotes::<Runtime>::get(POOL_A, BORROWER), None);
+	});
+}
+
+#[test]
+fn create_without_quote() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+
+		assert_noop!(
+			Loans::create_with_quote(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				util::base_internal_loan()
+			),
+			Error::<Runtime>::RateQuoteNotFound
+		);
+	});
+}
+
+#[test]
+fn create_with_expired_quote() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+		set_settings();
+
+		assert_ok!(Loans::request_rate_quote(
+			RuntimeOrigin::signed(BORROWER),
+			POOL_A
+		));
+
+		advance_time(QUOTE_WINDOW + BLOCK_TIME);
+
+		assert_noop!(
+			Loans::create_with_quote(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				util::base_internal_loan()
+			),
+			Error::<Runtime>::RateQuoteExpired
+		);
+	});
+}
@@ -0,0 +1,203 @@
+use cfg_traits::swaps::{OrderInfo, OrderRatio, Swap};
+use frame_support::dispatch::DispatchResult;
+
+use super::*;
+
+const POOL_CURRENCY: CurrencyId = 1;
+const SETTLEMENT_CURRENCY: CurrencyId = 2;
+const ORDER_ID: OrderId = 42;
+
+/// Market ratio between settlement and pool currency
+const MARKET_RATIO: Balance = 2;
+
+fn config_mocks() {
+	MockPermissions::mock_has(|scope, who, role| {
+		matches!(scope, PermissionScope::Pool(id) if id == POOL_A)
+			&& matches!(role, Role::PoolRole(PoolRole::PoolAdmin))
+			&& who == POOL_ADMIN
+	});
+	MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A);
+	MockPools::mock_currency_for(|_| Some(POOL_CURRENCY));
+	MockTokenSwaps::mock_get_order_details(|order_id| {
+		assert_eq!(order_id, ORDER_ID);
+		Some(order(OrderRatio::Market))
+	});
+	MockTokenSwaps::mock_convert_by_market(|currency_in, currency_out, amount_out| {
+		assert_eq!(currency_in, SETTLEMENT_CURRENCY);
+		assert_eq!(currency_out, POOL_CURRENCY);
+		Ok(amount_out * MARKET_RATIO)
+	});
+	MockTokenSwaps::mock_fill_order(|who, order_id, amount| {
+		assert_eq!(who, BORROWER);
+		assert_eq!(order_id, ORDER_ID);
+		assert_eq!(amount, COLLATERAL_VALUE);
+		Ok(())
+	});
+	MockPools::mock_deposit(|pool_id, from, amount| {
+		assert_eq!(pool_id, POOL_A);
+		assert_eq!(from, BORROWER);
+		assert_eq!(amount, COLLATERAL_VALUE);
+		Ok(())
+	});
+}
+
+fn order(ratio: OrderRatio<Quantity>) -> OrderInfo<Balance, CurrencyId, Quantity> {
+	OrderInfo {
+		swap: Swap {
+			currency_in: SETTLEMENT_CURRENCY,
+			currency_out: POOL_CURRENCY,
+			amount_out: COLLATERAL_VALUE,
+		},
+		ratio,
+	}
+}
+
+fn allow_settlement_currency() {
+	assert_ok!(Loans::set_settlement_currency(
+		RuntimeOrigin::signed(POOL_ADMIN),
+		POOL_A,
+		SETTLEMENT_CURRENCY,
+		true
+	));
+}
+
+fn repaid_amount() -> RepaidInput<Runtime> {
+	RepaidInput {
+		principal: PrincipalInput::Internal(COLLATERAL_VALUE),
+		interest: 0,
+		unscheduled: 0,
+	}
+}
+
+fn create_and_borrow_loan() -> LoanId {
+	let loan_id = util::create_loan(util::base_internal_loan());
+	util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+	loan_id
+}
+
+fn repay_with_settlement(loan_id: LoanId, max_settlement_amount: Balance) -> DispatchResult {
+	Loans::repay_with_settlement(
+		RuntimeOrigin::signed(BORROWER),
+		POOL_A,
+		loan_id,
+		repaid_amount(),
+		SETTLEMENT_CURRENCY,
+		ORDER_ID,
+		max_settlement_amount,
+	)
+}
+
+#[test]
+fn set_settlement_currency_with_wrong_permissions() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+
+		assert_noop!(
+			Loans::set_settlement_currency(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				SETTLEMENT_CURRENCY,
+				true
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn with_not_allowed_currency() {
+	new_test_ext().execute_with(|| {
+		let loan_id = create_and_borrow_loan();
+		config_mocks();
+
+		assert_noop!(
+			repay_with_settlement(loan_id, u128::MAX),
+			Error::<Runtime>::SettlementCurrencyNotAllowed
+		);
+
+		allow_settlement_currency();
+		assert_ok!(Loans::set_settlement_currency(
+			RuntimeOrigin::signed(POOL_ADMIN),
+			POOL_A,
+			SETTLEMENT_CURRENCY,
+			false
+		));
+
+		assert_noop!(
+			repay_with_settlement(loan_id, u128::MAX),
+			Error::<Runtime>::SettlementCurrencyNotAllowed
+		);
+	});
+}
+
+#[test]
+fn with_mismatched_order() {
+	new_test_ext().execute_with(|| {
+		let loan_id = create_and_borrow_loan();
+		config_mocks();
+		allow_settlement_currency();
+
+		MockTokenSwaps::mock_get_order_details(|_| {
+			Some(order(OrderRatio::Custom(Quantity::one())))
+		});
+		assert_noop!(
+			repay_with_settlement(loan_id, u128::MAX),
+			Error::<Runtime>::SettlementOrderMismatch
+		);
+
+		MockTokenSwaps::mock_get_order_details(|_| {
+			let mut order = order(OrderRatio::Market);
+			order.swap.currency_in = POOL_CURRENCY;
+			Some(order)
+		});
+		assert_noop!(
+			repay_with_settlement(loan_id, u128::MAX),
+			Error::<Runtime>::SettlementOrderMismatch
+		);
+
+		MockTokenSwaps::mock_get_order_details(|_| None);
+		assert_noop!(
+			repay_with_settlement(loan_id, u128::MAX),
+			Error::<Runtime>::SettlementOrderMismatch
+		);
+	});
+}
+
+#[test]
+fn with_slippage_exceeded() {
+	new_test_ext().execute_with(|| {
+		let loan_id = create_and_borrow_loan();
+		config_mocks();
+		allow_settlement_currency();
+
+		assert_noop!(
+			repay_with_settlement(loan_id, COLLATERAL_VALUE * MARKET_RATIO - 1),
+			Error::<Runtime>::SettlementSlippageExceeded
+		);
+	});
+}
+
+#[test]
+fn with_success() {
+	new_test_ext().execute_with(|| {
+		let loan_id = create_and_borrow_loan();
+		config_mocks();
+		allow_settlement_currency();
+
+		assert_ok!(repay_with_settlement(
+			loan_id,
+			COLLATERAL_VALUE * MARKET_RATIO
+		));
+
+		assert_eq!(0, util::current_loan_debt(loan_id));
+
+		System::assert_last_event(RuntimeEvent::Loans(Event::RepaidWithSettlement {
+			pool_id: POOL_A,
+			loan_id,
+			amount: repaid_amount(),
+			currency_id: SETTLEMENT_CURRENCY,
+			settlement_amount: COLLATERAL_VALUE * MARKET_RATIO,
+			converted_amount: COLLATERAL_VALUE,
+		}));
+	});
+}
@@ -0,0 +1,164 @@
+use sp_runtime::DispatchResult;
+
+use super::*;
+use crate::{pallet::RepaymentAllocations, types::RepaymentAllocation};
+
+fn config_mocks() {
+	MockPermissions::mock_has(|scope, who, role| {
+		matches!(scope, PermissionScope::Pool(id) if id == POOL_A)
+			&& matches!(role, Role::PoolRole(PoolRole::PoolAdmin))
+			&& who == POOL_ADMIN
+	});
+	MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A);
+}
+
+fn set_up_allocation(allocation: RepaymentAllocation) {
+	config_mocks();
+	assert_ok!(Loans::set_repayment_allocation(
+		RuntimeOrigin::signed(POOL_ADMIN),
+		POOL_A,
+		allocation
+	));
+}
+
+fn borrowed_loan() -> LoanId {
+	let loan_id = util::create_loan(util::base_internal_loan());
+	util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+	loan_id
+}
+
+fn outstanding_interest(loan_id: LoanId) -> Balance {
+	util::current_loan_debt(loan_id) - util::get_loan(loan_id).principal().unwrap()
+}
+
+fn repay(loan_id: LoanId, principal: Balance, interest: Balance) -> DispatchResult {
+	MockPools::mock_deposit(|_, _, _| Ok(()));
+	MockPrices::mock_get(|_, _| Ok((PRICE_VALUE, BLOCK_TIME_MS)));
+
+	Loans::repay(
+		RuntimeOrigin::signed(BORROWER),
+		POOL_A,
+		loan_id,
+		RepaidInput {
+			principal: PrincipalInput::Internal(principal),
+			interest,
+			unscheduled: 0,
+		},
+	)
+}
+
+#[test]
+fn set_repayment_allocation_with_wrong_permissions() {
+	new_test_ext().execute_with(|| {
+		config_mocks();
+
+		assert_noop!(
+			Loans::set_repayment_allocation(
+				RuntimeOrigin::signed(ANY),
+				POOL_A,
+				RepaymentAllocation::InterestFirst
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_repayment_allocation() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(
+			RepaymentAllocations::<Runtime>::get(POOL_A),
+			RepaymentAllocation::Unordered
+		);
+
+		set_up_allocation(RepaymentAllocation::PrincipalFirst);
+
+		assert_eq!(
+			RepaymentAllocations::<Runtime>::get(POOL_A),
+			RepaymentAllocation::PrincipalFirst
+		);
+		System::assert_last_event(RuntimeEvent::Loans(Event::RepaymentAllocationUpdated {
+			pool_id: POOL_A,
+			allocation: RepaymentAllocation::PrincipalFirst,
+		}));
+	});
+}
+
+#[test]
+fn unordered_repays_principal_before_interest() {
+	new_test_ext().execute_with(|| {
+		let loan_id = borrowed_loan();
+		advance_time(YEAR / 2);
+
+		assert_ok!(repay(loan_id, COLLATERAL_VALUE / 2, 0));
+	});
+}
+
+#[test]
+fn interest_first() {
+	new_test_ext().execute_with(|| {
+		let loan_id = borrowed_loan();
+		advance_time(YEAR / 2);
+		set_up_allocation(RepaymentAllocation::InterestFirst);
+
+		let interest = outstanding_interest(loan_id);
+		assert_ne!(interest, 0);
+
+		assert_noop!(
+			repay(loan_id, COLLATERAL_VALUE / 2, interest / 2),
+			Error::<Runtime>::from(RepayLoanError::AllocationOrder)
+		);
+
+		assert_ok!(repay(loan_id, 0, interest / 2));
+		assert_ok!(repay(loan_id, COLLATERAL_VALUE / 2, u128::MAX));
+		assert_eq!(outstanding_interest(loan_id), 0);
+	});
+}
+
+#[test]
+fn principal_first() {
+	new_test_ext().execute_with(|| {
+		let loan_id = borrowed_loan();
+		advance_time(YEAR / 2);
+		set_up_allocation(RepaymentAllocation::PrincipalFirst);
+
+		let interest = outstanding_interest(loan_id);
+
+		assert_noop!(
+			repay(loan_id, 0, interest),
+			Error::<Runtime>::from(RepayLoanError::AllocationOrder)
+		);
+		assert_noop!(
+			repay(loan_id, COLLATERAL_VALUE / 2, interest),
+			Error::<Runtime>::from(RepayLoanError::AllocationOrder)
+		);
+
+		assert_ok!(repay(loan_id, COLLATERAL_VALUE / 2, 0));
+		assert_ok!(repay(loan_id, COLLATERAL_VALUE / 2, interest / 2));
+		assert_ok!(repay(loan_id, 0, u128::MAX));
+		assert_eq!(util::current_loan_debt(loan_id), 0);
+	});
+}
+
+#[test]
+fn interest_first_with_written_off_loan() {
+	new_test_ext().execute_with(|| {
+		let loan_id = borrowed_loan();
+		advance_time(YEAR + DAY);
+		util::write_off_loan(loan_id);
+		set_up_allocation(RepaymentAllocation::InterestFirst);
+
+		let interest_before_penalty = outstanding_interest(loan_id);
+		advance_time(YEAR / 2);
+		let interest = outstanding_interest(loan_id);
+
+		// The penalty interest must be settled before the principal as well
+		assert_noop!(
+			repay(loan_id, COLLATERAL_VALUE, interest_before_penalty),
+			Error::<Runtime>::from(RepayLoanError::AllocationOrder)
+		);
+
+		assert_ok!(repay(loan_id, COLLATERAL_VALUE, interest));
+		assert_eq!(util::current_loan_debt(loan_id), 0);
+	});
+}
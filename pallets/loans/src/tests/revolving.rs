@@ -0,0 +1,205 @@
+use super::*;
+
+const LIMIT: Balance = COLLATERAL_VALUE;
+
+fn revolving_loan(commitment_fee: Option<Rate>) -> LoanInfo<Runtime> {
+	LoanInfo {
+		pricing: Pricing::Internal(InternalPricing {
+			max_borrow_amount: IntMaxBorrowAmount::Revolving {
+				advance_rate: Rate::one(),
+				commitment_fee,
+			},
+			..util::base_internal_pricing()
+		}),
+		restrictions: LoanRestrictions {
+			borrows: BorrowRestrictions::Revolving {
+				available_until: (now() + YEAR / 2).as_secs(),
+			},
+			repayments: RepayRestrictions::None,
+		},
+		..util::base_internal_loan()
+	}
+}
+
+fn config_create_mocks() {
+	MockPermissions::mock_has(|_, _, _| true);
+	MockPools::mock_pool_exists(|_| true);
+	MockPools::mock_account_for(|_| POOL_A_ACCOUNT);
+}
+
+fn config_borrow_mocks() {
+	MockPools::mock_withdraw(|_, _, _| Ok(()));
+	MockPrices::mock_get(|_, _| Ok((PRICE_VALUE, BLOCK_TIME_MS)));
+	MockPrices::mock_register_id(|_, _| Ok(()));
+}
+
+#[test]
+fn create_without_revolving_pricing() {
+	new_test_ext().execute_with(|| {
+		config_create_mocks();
+
+		let loan = LoanInfo {
+			pricing: Pricing::Internal(util::base_internal_pricing()),
+			..revolving_loan(None)
+		};
+
+		assert_noop!(
+			Loans::create(RuntimeOrigin::signed(BORROWER), POOL_A, loan),
+			Error::<Runtime>::from(CreateLoanError::InvalidBorrowRestriction)
+		);
+	});
+}
+
+#[test]
+fn create_with_availability_after_maturity() {
+	new_test_ext().execute_with(|| {
+		config_create_mocks();
+
+		let loan = LoanInfo {
+			restrictions: LoanRestrictions {
+				borrows: BorrowRestrictions::Revolving {
+					available_until: (now() + YEAR * 2).as_secs(),
+				},
+				repayments: RepayRestrictions::None,
+			},
+			..revolving_loan(None)
+		};
+
+		assert_noop!(
+			Loans::create(RuntimeOrigin::signed(BORROWER), POOL_A, loan),
+			Error::<Runtime>::from(CreateLoanError::InvalidBorrowRestriction)
+		);
+	});
+}
+
+#[test]
+fn create_with_full_repayments() {
+	new_test_ext().execute_with(|| {
+		config_create_mocks();
+
+		let loan = LoanInfo {
+			restrictions: LoanRestrictions {
+				borrows: BorrowRestrictions::Revolving {
+					available_until: (now() + YEAR / 2).as_secs(),
+				},
+				repayments: RepayRestrictions::Full,
+			},
+			..revolving_loan(None)
+		};
+
+		assert_noop!(
+			Loans::create(RuntimeOrigin::signed(BORROWER), POOL_A, loan),
+			Error::<Runtime>::from(CreateLoanError::InvalidRepayRestriction)
+		);
+	});
+}
+
+#[test]
+fn borrow_again_after_repay() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(revolving_loan(None));
+
+		util::borrow_loan(loan_id, PrincipalInput::Internal(LIMIT));
+
+		config_borrow_mocks();
+		assert_noop!(
+			Loans::borrow(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				loan_id,
+				PrincipalInput::Internal(1)
+			),
+			Error::<Runtime>::from(BorrowLoanError::MaxAmountExceeded)
+		);
+
+		util::repay_loan(loan_id, PrincipalInput::Internal(LIMIT / 4));
+		util::borrow_loan(loan_id, PrincipalInput::Internal(LIMIT / 4));
+
+		config_borrow_mocks();
+		assert_noop!(
+			Loans::borrow(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				loan_id,
+				PrincipalInput::Internal(1)
+			),
+			Error::<Runtime>::from(BorrowLoanError::MaxAmountExceeded)
+		);
+
+		let loan = util::get_loan(loan_id);
+		assert_eq!(loan.principal().unwrap(), LIMIT);
+	});
+}
+
+#[test]
+fn borrow_after_availability_period() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(revolving_loan(None));
+
+		util::borrow_loan(loan_id, PrincipalInput::Internal(LIMIT / 2));
+
+		advance_time(YEAR / 2);
+
+		config_borrow_mocks();
+		assert_noop!(
+			Loans::borrow(
+				RuntimeOrigin::signed(BORROWER),
+				POOL_A,
+				loan_id,
+				PrincipalInput::Internal(1)
+			),
+			Error::<Runtime>::from(BorrowLoanError::Restriction)
+		);
+
+		util::repay_loan(loan_id, PrincipalInput::Internal(LIMIT / 2));
+		assert_eq!(util::get_loan(loan_id).principal().unwrap(), 0);
+	});
+}
+
+#[test]
+fn commitment_fee_accrued_as_interest() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(revolving_loan(Some(Rate::from_rational(1, 10))));
+
+		advance_time(YEAR / 4);
+		util::borrow_loan(loan_id, PrincipalInput::Internal(LIMIT / 2));
+
+		// A quarter of a year over the whole limit undrawn
+		let fee = LIMIT / 40;
+		assert_eq!(util::current_loan_debt(loan_id), LIMIT / 2 + fee);
+	});
+}
+
+#[test]
+fn commitment_fee_in_cashflows() {
+	new_test_ext().execute_with(|| {
+		let loan_id = util::create_loan(revolving_loan(Some(Rate::from_rational(1, 10))));
+
+		util::borrow_loan(loan_id, PrincipalInput::Internal(LIMIT / 2));
+
+		let loan = util::get_loan(loan_id);
+
+		let principal = LIMIT / 2;
+		let acc_interest_rate_per_year = checked_pow(
+			util::default_interest_rate().per_sec().unwrap(),
+			SECONDS_PER_YEAR as usize,
+		)
+		.unwrap();
+		let interest = acc_interest_rate_per_year.saturating_mul_int(principal) - principal;
+
+		// Half a year over the undrawn half of the limit
+		let fee = (LIMIT - principal) / 20;
+
+		assert_eq!(
+			loan.expected_cashflows()
+				.unwrap()
+				.into_iter()
+				.map(|payment| (payment.when, payment.principal, payment.interest))
+				.collect::<Vec<_>>(),
+			vec![
+				((now() + YEAR / 2).as_secs(), 0, fee),
+				(loan.maturity_date().unwrap(), principal, interest)
+			]
+		);
+	});
+}
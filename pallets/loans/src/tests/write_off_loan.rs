@@ -157,6 +157,39 @@ fn with_success() {
 	});
 }
 
+#[test]
+fn with_keeper_reward_only_on_status_change() {
+	new_test_ext().execute_with(|| {
+		util::set_up_policy(POLICY_PERCENTAGE, POLICY_PENALTY);
+
+		let loan_id = util::create_loan(util::base_internal_loan());
+		util::borrow_loan(loan_id, PrincipalInput::Internal(COLLATERAL_VALUE));
+
+		advance_time(YEAR + DAY);
+
+		MockKeeperRewards::mock_reward(|who, action| {
+			assert_eq!(*who, ANY);
+			assert_eq!(action, KeeperAction::WriteOffLoan);
+			1
+		});
+
+		assert_ok!(Loans::write_off(
+			RuntimeOrigin::signed(ANY),
+			POOL_A,
+			loan_id
+		));
+
+		MockKeeperRewards::mock_reward(|_, _| panic!("no status change"));
+
+		// Same rule applied again, the status does not change.
+		assert_ok!(Loans::write_off(
+			RuntimeOrigin::signed(ANY),
+			POOL_A,
+			loan_id
+		));
+	});
+}
+
 #[test]
 fn with_admin_success() {
 	new_test_ext().execute_with(|| {
@@ -16,10 +16,7 @@ use frame_support::pallet_prelude::RuntimeDebug;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{
-		ensure_pow, EnsureAdd, EnsureAddAssign, EnsureFixedPointNumber, EnsureInto, EnsureSub,
-		EnsureSubAssign,
-	},
+	traits::{EnsureAdd, EnsureAddAssign, EnsureFixedPointNumber, EnsureSub, EnsureSubAssign},
 	DispatchError, FixedPointNumber, FixedPointOperand, FixedU128,
 };
 use sp_std::{vec, vec::Vec};
@@ -139,8 +136,8 @@ impl RepaymentSchedule {
 			.map(|(_, weight)| weight)
 			.try_fold(0, |a, b| a.ensure_add(*b))?;
 
-		let lifetime = maturity.ensure_sub(origination_date)?.ensure_into()?;
-		let interest_rate_per_lifetime = ensure_pow(interest_rate.per_sec()?, lifetime)?;
+		let interest_rate_per_lifetime =
+			interest_rate.accrual_factor(origination_date, maturity)?;
 		let interest_at_maturity = interest_rate_per_lifetime
 			.ensure_mul_int(principal)?
 			.ensure_sub(principal_base)?;
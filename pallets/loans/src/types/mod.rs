@@ -13,6 +13,7 @@
 
 //! Contains base types without Config references
 
+use cfg_traits::Seconds;
 use frame_support::{pallet_prelude::RuntimeDebug, PalletError};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
@@ -55,6 +56,9 @@ pub enum RepayLoanError {
 	Restriction,
 	/// Emits when the principal amount is more than the borrowed amount
 	MaxPrincipalAmountExceeded,
+	/// Emits when the repayment does not follow the allocation order of the
+	/// pool
+	AllocationOrder,
 }
 
 /// Error related to loan borrowing
@@ -82,6 +86,8 @@ pub enum MutationError {
 	InternalPricingExpected,
 	/// Maturity extensions exceed max extension allowed.
 	MaturityExtendedTooMuch,
+	/// Emits when a restructuring does not change the terms of the loan.
+	NotStructural,
 }
 
 /// Specify how offer a loan can be borrowed
@@ -96,6 +102,10 @@ pub enum BorrowRestrictions {
 	/// The externally priced loan can only be borrowed
 	/// once an oracle price exists.
 	OraclePriceRequired,
+
+	/// The revolving loan can be borrowed repeatedly up to its limit until
+	/// the end of its availability period, if it has not been written off.
+	Revolving { available_until: Seconds },
 }
 
 /// Specify how offer a loan can be repaid
@@ -142,3 +152,97 @@ impl<Balance: EnsureAdd + Copy> RepaidAmount<Balance> {
 		self.unscheduled.ensure_add_assign(other.unscheduled)
 	}
 }
+
+/// Pool configuration used to quote rates to borrowers
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct RateQuoteSettings<Rate> {
+	/// Current benchmark rate per year
+	pub benchmark_rate: Rate,
+
+	/// Spread added by the pool over the benchmark rate
+	pub spread: Rate,
+
+	/// Seconds a quote remains locked since it was requested
+	pub window: Seconds,
+}
+
+impl<Rate: EnsureAdd + Copy> RateQuoteSettings<Rate> {
+	pub fn quoted_rate(&self) -> Result<Rate, ArithmeticError> {
+		self.benchmark_rate.ensure_add(self.spread)
+	}
+}
+
+/// Rate locked for a borrower until the quote expires
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct RateQuote<Rate> {
+	/// Locked rate per year
+	pub rate_per_year: Rate,
+
+	/// Last instant the quote can be used to create a loan
+	pub expires_at: Seconds,
+}
+
+/// Attestation of a custodian holding the off-chain collateral of a loan
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct CollateralAttestation<AccountId, Hash> {
+	/// Custodian that attested
+	pub custodian: AccountId,
+
+	/// Hash of the document supporting the attestation
+	pub document_hash: Hash,
+
+	/// Instant of the attestation
+	pub attested_at: Seconds,
+}
+
+/// Where the origination fees of a pool are credited
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub enum OriginationFeeDestination<AccountId> {
+	/// The fee is transferred from the reserve to the originator account
+	Originator(AccountId),
+
+	/// The fee is kept in the pool reserve
+	Reserve,
+}
+
+/// Fee deducted from every amount borrowed from a pool
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct OriginationFee<AccountId, Rate> {
+	/// Part of the borrowed amount charged as fee
+	pub rate: Rate,
+
+	/// Where the fee is credited
+	pub destination: OriginationFeeDestination<AccountId>,
+}
+
+/// Order in which the repayments of a pool settle the outstanding interest
+/// and principal of a loan
+#[derive(
+	Encode, Decode, Clone, Copy, Default, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen,
+)]
+pub enum RepaymentAllocation {
+	/// Interest and principal are repaid in any proportion
+	#[default]
+	Unordered,
+
+	/// The outstanding interest must be repaid before any principal.
+	/// The penalty interest of a written-off loan accrues as interest, so it
+	/// is settled along with it.
+	InterestFirst,
+
+	/// The outstanding principal must be repaid before any interest
+	PrincipalFirst,
+}
+
+/// Attestation status of a loan in a pool requiring periodic attestations
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub enum AttestationStatus {
+	/// The collateral was never attested
+	Missing,
+
+	/// The last attestation is older than the attestation period of the pool
+	Stale { attested_at: Seconds },
+
+	/// The last attestation is within the attestation period of the pool
+	Valid { attested_at: Seconds },
+}
@@ -19,7 +19,7 @@ use sp_runtime::{
 	traits::{Get, Zero},
 	DispatchError,
 };
-use sp_std::collections::btree_set::BTreeSet;
+use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 use strum::EnumCount;
 
 /// Indicator of when the write off should be applied
@@ -42,6 +42,10 @@ pub enum WriteOffTrigger {
 
 	/// Seconds since the oracle valuation was last updated
 	PriceOutdated(Seconds),
+
+	/// Seconds since the collateral was last attested by a custodian, or
+	/// since the loan was originated if it was never attested
+	AttestationOutdated(Seconds),
 }
 
 /// Wrapper type to identify equality berween kinds of triggers,
@@ -58,6 +62,9 @@ impl PartialEq for UniqueWriteOffTrigger {
 			WriteOffTrigger::PriceOutdated(_) => {
 				matches!(other.0, WriteOffTrigger::PriceOutdated(_))
 			}
+			WriteOffTrigger::AttestationOutdated(_) => {
+				matches!(other.0, WriteOffTrigger::AttestationOutdated(_))
+			}
 		}
 	}
 }
@@ -77,6 +84,19 @@ impl Get<u32> for TriggerSize {
 	}
 }
 
+/// Write off policy currently applied to a pool
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub struct WriteOffPolicyInfo<Rate, Hash> {
+	/// Number of times a policy was applied to the pool
+	pub version: u32,
+
+	/// Hash of the encoded rules
+	pub hash: Hash,
+
+	/// Rules of the policy
+	pub rules: Vec<WriteOffRule<Rate>>,
+}
+
 /// The data structure for storing a specific write off policy
 #[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 pub struct WriteOffRule<Rate> {
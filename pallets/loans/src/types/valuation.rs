@@ -19,10 +19,9 @@ use frame_support::{
 };
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_arithmetic::traits::checked_pow;
 use sp_runtime::{
-	traits::{EnsureDiv, EnsureFixedPointNumber, EnsureInto, EnsureMul, EnsureSub, One},
-	ArithmeticError, FixedPointNumber, FixedPointOperand,
+	traits::{EnsureDiv, EnsureFixedPointNumber, EnsureMul, EnsureSub, One},
+	ArithmeticError, DispatchError, FixedPointNumber, FixedPointOperand,
 };
 
 /// Discounted cash flow values
@@ -59,7 +58,7 @@ impl<Rate: FixedPointNumber> DiscountedCashFlow<Rate> {
 		interest_rate: &InterestRate<Rate>,
 		maturity_date: Seconds,
 		origination_date: Seconds,
-	) -> Result<Balance, ArithmeticError> {
+	) -> Result<Balance, DispatchError> {
 		// If the loan is overdue, there are no future cash flows to discount,
 		// hence we use the outstanding debt as the value.
 		if when > maturity_date {
@@ -78,12 +77,11 @@ impl<Rate: FixedPointNumber> DiscountedCashFlow<Rate> {
 		let tel_inv = Rate::one().ensure_sub(tel)?;
 
 		// Calculate the risk-adjusted expected cash flows
-		let exp = maturity_date.ensure_sub(when)?.ensure_into()?;
-
 		// TODO: Simplify this once #1231 is merged allowing to extract only the
 		// acc_rate from InterestAccrual
-		let interest_rate_per_sec = interest_rate.per_sec()?;
-		let acc_rate = checked_pow(interest_rate_per_sec, exp).ok_or(ArithmeticError::Overflow)?;
+		// NOTE: A floating `interest_rate` must be resolved into its current fixed
+		// rate by the caller.
+		let acc_rate = interest_rate.accrual_factor(when, maturity_date)?;
 		let ecf = acc_rate.ensure_mul_int(debt)?;
 		let ra_ecf = tel_inv.ensure_mul_int(ecf)?;
 
@@ -91,11 +89,26 @@ impl<Rate: FixedPointNumber> DiscountedCashFlow<Rate> {
 
 		// TODO: use InterestAccrual for this once #1231 is merged
 		// This would immply that discount_rate should be register/unregister.
-		let discount_rate_per_sec = self.discount_rate.per_sec()?;
-		let rate = checked_pow(discount_rate_per_sec, exp).ok_or(ArithmeticError::Overflow)?;
+		let rate = self.discount_rate.accrual_factor(when, maturity_date)?;
 		let d = Rate::one().ensure_div(rate)?;
 
-		d.ensure_mul_int(ra_ecf)
+		Ok(d.ensure_mul_int(ra_ecf)?)
+	}
+
+	/// Expected loss of an exposure over the given horizon, computed from the
+	/// annualized probability of default and the loss given default.
+	/// Unlike `compute_present_value()`, no discounting is applied and the
+	/// result never exceeds the exposure.
+	pub fn compute_expected_loss<Balance: tokens::Balance + FixedPointOperand>(
+		&self,
+		exposure: Balance,
+		horizon: Seconds,
+	) -> Result<Balance, ArithmeticError> {
+		Rate::saturating_from_rational(horizon, SECONDS_PER_YEAR)
+			.ensure_mul(self.probability_of_default)?
+			.ensure_mul(self.loss_given_default)?
+			.min(One::one())
+			.ensure_mul_int(exposure)
 	}
 }
 
@@ -118,7 +131,11 @@ where
 {
 	pub fn is_valid(&self) -> bool {
 		match self {
-			ValuationMethod::DiscountedCashFlow(dcf) => dcf.discount_rate.per_year() <= One::one(),
+			// A floating discount rate is not supported
+			ValuationMethod::DiscountedCashFlow(dcf) => dcf
+				.discount_rate
+				.per_year()
+				.map_or(false, |rate| rate <= One::one()),
 			ValuationMethod::OutstandingDebt | ValuationMethod::Cash => true,
 		}
 	}
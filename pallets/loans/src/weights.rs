@@ -28,6 +28,16 @@ pub trait WeightInfo {
 	fn propose_transfer_debt(n: u32) -> Weight;
 	fn apply_transfer_debt(n: u32) -> Weight;
 	fn increase_debt(n: u32) -> Weight;
+	fn set_rate_quote_settings() -> Weight;
+	fn request_rate_quote() -> Weight;
+	fn create_with_quote() -> Weight;
+	fn set_settlement_currency() -> Weight;
+	fn repay_with_settlement(n: u32) -> Weight;
+	fn set_custodian() -> Weight;
+	fn set_attestation_period() -> Weight;
+	fn attest_collateral(n: u32) -> Weight;
+	fn set_origination_fee() -> Weight;
+	fn set_repayment_allocation() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -86,4 +96,44 @@ impl WeightInfo for () {
 	fn increase_debt(_: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn set_rate_quote_settings() -> Weight {
+		Weight::zero()
+	}
+
+	fn request_rate_quote() -> Weight {
+		Weight::zero()
+	}
+
+	fn create_with_quote() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_settlement_currency() -> Weight {
+		Weight::zero()
+	}
+
+	fn repay_with_settlement(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn set_custodian() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_attestation_period() -> Weight {
+		Weight::zero()
+	}
+
+	fn attest_collateral(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn set_origination_fee() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_repayment_allocation() -> Weight {
+		Weight::zero()
+	}
 }
@@ -0,0 +1,247 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+//! # Oracle Adapter Pallet
+//!
+//! Ingests prices pushed by oracles of other domains, e.g., Chainlink or DIA
+//! feeds on EVM chains, which are received through the liquidity pools
+//! gateway.
+//!
+//! The admin registers each feed of a source domain address under an oracle
+//! key. The prices of registered feeds are normalized from the decimals used
+//! by the feed into the fixed point representation of the oracle values and
+//! fed on behalf of the source domain address. Any oracle collection, e.g.,
+//! the price collection used by loans, can then configure that domain
+//! address as a feeder of its keys.
+//!
+//! Prices older than [`Config::MaxPriceAge`] or not newer than the last price
+//! of the same feed are rejected.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use cfg_traits::{liquidity_pools::PriceUpdateHandler, Seconds, TimeAsSecs, ValueFeeder};
+	use cfg_types::domain_address::DomainAddress;
+	use frame_support::{pallet_prelude::*, traits::OriginTrait};
+	use frame_system::pallet_prelude::*;
+	use sp_core::crypto::AccountId32;
+	use sp_runtime::FixedPointNumber;
+
+	use super::*;
+
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(0);
+
+	pub type FeederOf<T> =
+		<<T as frame_system::Config>::RuntimeOrigin as OriginTrait>::PalletsOrigin;
+
+	/// Identifier of a feed in its source domain
+	pub type FeedId = [u8; 32];
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config<AccountId = AccountId32> {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin allowed to register and remove feeds
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Identify an oracle value
+		type OracleKey: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// Represent an oracle value
+		type OracleValue: Parameter + Member + Copy + MaxEncodedLen + FixedPointNumber;
+
+		/// Storage where the normalized prices are fed
+		type OracleFeeder: ValueFeeder<FeederOf<Self>, Self::OracleKey, Value = Self::OracleValue>;
+
+		/// A way to obtain the current time
+		type Time: TimeAsSecs;
+
+		/// Max age of a price when it is received
+		#[pallet::constant]
+		type MaxPriceAge: Get<Seconds>;
+
+		/// The weight information for this pallet extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// Information of a registered feed
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+	pub struct FeedInfo<OracleKey> {
+		/// Oracle key under which the prices of the feed are fed
+		pub key: OracleKey,
+
+		/// Time at which the last received price was computed
+		pub last_computed_at: Option<Seconds>,
+	}
+
+	/// Store the registered feeds indexed by their source domain address
+	#[pallet::storage]
+	pub(crate) type Feeds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		DomainAddress,
+		Blake2_128Concat,
+		FeedId,
+		FeedInfo<T::OracleKey>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		FeedRegistered {
+			source: DomainAddress,
+			feed: FeedId,
+			key: T::OracleKey,
+		},
+		FeedRemoved {
+			source: DomainAddress,
+			feed: FeedId,
+		},
+		PriceFed {
+			source: DomainAddress,
+			feed: FeedId,
+			key: T::OracleKey,
+			value: T::OracleValue,
+			computed_at: Seconds,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The feed is not registered for the source domain address
+		FeedNotFound,
+
+		/// The price is older than the max age or than the last price of the
+		/// feed
+		PriceOutdated,
+
+		/// The decimals of the price can not be represented
+		InvalidDecimals,
+
+		/// The price can not be represented as an oracle value
+		PriceOverflow,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a feed of a source domain address, whose prices will be
+		/// fed under `key`. Registering an existing feed overwrites its key.
+		#[pallet::weight(T::WeightInfo::register_feed())]
+		#[pallet::call_index(0)]
+		pub fn register_feed(
+			origin: OriginFor<T>,
+			source: DomainAddress,
+			feed: FeedId,
+			key: T::OracleKey,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Feeds::<T>::insert(
+				&source,
+				feed,
+				FeedInfo {
+					key,
+					last_computed_at: None,
+				},
+			);
+
+			Self::deposit_event(Event::<T>::FeedRegistered { source, feed, key });
+
+			Ok(())
+		}
+
+		/// Remove a registered feed. Its prices will be rejected.
+		#[pallet::weight(T::WeightInfo::remove_feed())]
+		#[pallet::call_index(1)]
+		pub fn remove_feed(
+			origin: OriginFor<T>,
+			source: DomainAddress,
+			feed: FeedId,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Feeds::<T>::take(&source, feed).ok_or(Error::<T>::FeedNotFound)?;
+
+			Self::deposit_event(Event::<T>::FeedRemoved { source, feed });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> PriceUpdateHandler<DomainAddress> for Pallet<T> {
+		fn handle_price(
+			source: DomainAddress,
+			feed: FeedId,
+			price: u128,
+			decimals: u8,
+			computed_at: Seconds,
+		) -> DispatchResult {
+			Feeds::<T>::try_mutate(&source, feed, |maybe_info| {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::FeedNotFound)?;
+
+				ensure!(
+					info.last_computed_at
+						.map_or(true, |last| computed_at > last),
+					Error::<T>::PriceOutdated
+				);
+				ensure!(
+					T::Time::now().saturating_sub(computed_at) <= T::MaxPriceAge::get(),
+					Error::<T>::PriceOutdated
+				);
+
+				let value = Self::normalize(price, decimals)?;
+				let feeder = T::RuntimeOrigin::signed(source.account()).into_caller();
+
+				T::OracleFeeder::feed(feeder, info.key, value)?;
+
+				info.last_computed_at = Some(computed_at);
+
+				Self::deposit_event(Event::<T>::PriceFed {
+					source: source.clone(),
+					feed,
+					key: info.key,
+					value,
+					computed_at,
+				});
+
+				Ok(())
+			})
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Converts an integer price with `decimals` decimals into an oracle
+		/// value.
+		pub fn normalize(price: u128, decimals: u8) -> Result<T::OracleValue, DispatchError> {
+			let unit = 10u128
+				.checked_pow(decimals.into())
+				.ok_or(Error::<T>::InvalidDecimals)?;
+
+			T::OracleValue::checked_from_rational(price, unit)
+				.ok_or(Error::<T>::PriceOverflow.into())
+		}
+	}
+}
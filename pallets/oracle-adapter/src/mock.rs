@@ -0,0 +1,63 @@
+use cfg_types::domain_address::DomainAddress;
+use frame_support::{derive_impl, parameter_types};
+use sp_core::{crypto::AccountId32, H160};
+use sp_io::TestExternalities;
+use sp_runtime::{traits::IdentityLookup, FixedU128};
+
+use crate::pallet as pallet_oracle_adapter;
+
+pub type OracleKey = u32;
+pub type OracleValue = FixedU128;
+
+pub const SOURCE: DomainAddress = DomainAddress::Evm(1, H160::repeat_byte(2));
+pub const FEED: [u8; 32] = [3; 32];
+pub const KEY: OracleKey = 42;
+
+pub const NOW: u64 = 1_700_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		MockTime: cfg_mocks::pallet_mock_time,
+		MockFeeder: cfg_mocks::value_feeder::pallet,
+		OracleAdapter: pallet_oracle_adapter,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type AccountId = AccountId32;
+	type Block = frame_system::mocking::MockBlock<Runtime>;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+impl cfg_mocks::pallet_mock_time::Config for Runtime {
+	type Moment = u64;
+}
+
+impl cfg_mocks::value_feeder::pallet::Config for Runtime {
+	type Key = OracleKey;
+	type Source = OriginCaller;
+	type Value = OracleValue;
+}
+
+parameter_types! {
+	pub const MaxPriceAge: u64 = 3600;
+}
+
+impl pallet_oracle_adapter::Config for Runtime {
+	type AdminOrigin = frame_system::EnsureRoot<AccountId32>;
+	type MaxPriceAge = MaxPriceAge;
+	type OracleFeeder = MockFeeder;
+	type OracleKey = OracleKey;
+	type OracleValue = OracleValue;
+	type RuntimeEvent = RuntimeEvent;
+	type Time = MockTime;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut ext = System::externalities();
+	ext.execute_with(|| MockTime::mock_now(|| NOW * 1000));
+	ext
+}
@@ -0,0 +1,208 @@
+use cfg_traits::liquidity_pools::PriceUpdateHandler;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, OriginTrait},
+};
+use sp_runtime::{DispatchError, FixedPointNumber};
+
+use super::*;
+use crate::mock::*;
+
+const PRICE: u128 = 2_050_000_000;
+const DECIMALS: u8 = 8;
+
+fn register_feed() {
+	assert_ok!(OracleAdapter::register_feed(
+		RuntimeOrigin::root(),
+		SOURCE,
+		FEED,
+		KEY
+	));
+}
+
+mod register_feed {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			register_feed();
+
+			System::assert_last_event(
+				Event::<Runtime>::FeedRegistered {
+					source: SOURCE,
+					feed: FEED,
+					key: KEY,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn with_wrong_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				OracleAdapter::register_feed(
+					RuntimeOrigin::signed(SOURCE.account()),
+					SOURCE,
+					FEED,
+					KEY
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
+mod remove_feed {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			register_feed();
+
+			assert_ok!(OracleAdapter::remove_feed(
+				RuntimeOrigin::root(),
+				SOURCE,
+				FEED
+			));
+
+			assert_noop!(
+				OracleAdapter::handle_price(SOURCE, FEED, PRICE, DECIMALS, NOW),
+				Error::<Runtime>::FeedNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn without_feed() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				OracleAdapter::remove_feed(RuntimeOrigin::root(), SOURCE, FEED),
+				Error::<Runtime>::FeedNotFound
+			);
+		});
+	}
+}
+
+mod handle_price {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			register_feed();
+
+			MockFeeder::mock_feed(|feeder, key, value| {
+				assert_eq!(
+					feeder,
+					RuntimeOrigin::signed(SOURCE.account()).into_caller()
+				);
+				assert_eq!(key, KEY);
+				assert_eq!(value, OracleValue::saturating_from_rational(205, 10));
+				Ok(())
+			});
+
+			assert_ok!(OracleAdapter::handle_price(
+				SOURCE,
+				FEED,
+				PRICE,
+				DECIMALS,
+				NOW - 10
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::PriceFed {
+					source: SOURCE,
+					feed: FEED,
+					key: KEY,
+					value: OracleValue::saturating_from_rational(205, 10),
+					computed_at: NOW - 10,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn normalizes_decimals() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(
+				OracleAdapter::normalize(2_050_000_000, 8),
+				OracleValue::saturating_from_rational(205, 10)
+			);
+			assert_ok!(
+				OracleAdapter::normalize(20_500_000_000_000_000_000, 18),
+				OracleValue::saturating_from_rational(205, 10)
+			);
+			assert_ok!(
+				OracleAdapter::normalize(205, 0),
+				OracleValue::saturating_from_integer(205)
+			);
+		});
+	}
+
+	mod erroring_out {
+		use super::*;
+
+		#[test]
+		fn without_feed() {
+			new_test_ext().execute_with(|| {
+				assert_noop!(
+					OracleAdapter::handle_price(SOURCE, FEED, PRICE, DECIMALS, NOW),
+					Error::<Runtime>::FeedNotFound
+				);
+			});
+		}
+
+		#[test]
+		fn with_too_old_price() {
+			new_test_ext().execute_with(|| {
+				register_feed();
+
+				assert_noop!(
+					OracleAdapter::handle_price(
+						SOURCE,
+						FEED,
+						PRICE,
+						DECIMALS,
+						NOW - MaxPriceAge::get() - 1
+					),
+					Error::<Runtime>::PriceOutdated
+				);
+			});
+		}
+
+		#[test]
+		fn with_price_older_than_last_one() {
+			new_test_ext().execute_with(|| {
+				register_feed();
+
+				MockFeeder::mock_feed(|_, _, _| Ok(()));
+
+				assert_ok!(OracleAdapter::handle_price(
+					SOURCE, FEED, PRICE, DECIMALS, NOW
+				));
+
+				assert_noop!(
+					OracleAdapter::handle_price(SOURCE, FEED, PRICE, DECIMALS, NOW),
+					Error::<Runtime>::PriceOutdated
+				);
+			});
+		}
+
+		#[test]
+		fn with_invalid_decimals() {
+			new_test_ext().execute_with(|| {
+				register_feed();
+
+				assert_noop!(
+					OracleAdapter::handle_price(SOURCE, FEED, PRICE, 39, NOW),
+					Error::<Runtime>::InvalidDecimals
+				);
+			});
+		}
+	}
+}
@@ -2,11 +2,14 @@ use cfg_traits::{
 	benchmarking::PoolBenchmarkHelper, changes::ChangeGuard, data::DataRegistry, ValueProvider,
 };
 use frame_benchmarking::{v2::*, whitelisted_caller};
+use frame_support::traits::{Currency, EnsureOrigin, Get};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Saturating;
+use sp_std::{vec, vec::Vec};
 
 use crate::{
-	pallet::{Call, Collection, Config, Pallet},
-	types::{Change, CollectionInfo},
+	pallet::{Call, Collection, Config, FeederDisputes, KeySourceList, Pallet},
+	types::{Change, CollectionInfo, KeyBreaker, KeySources, OracleSource},
 };
 
 #[cfg(test)]
@@ -45,6 +48,47 @@ mod util {
 		)
 		.unwrap()
 	}
+
+	pub fn key_sources<T: Config>(n: u32) -> Result<KeySources<T>, BenchmarkError>
+	where
+		T::FeederId: From<u32>,
+	{
+		let feeders = crate::util::feeders_from::<_, T::MaxFeedersPerKey>(
+			(0..T::MaxFeedersPerKey::get()).map(Into::into),
+		)?;
+
+		(0..n)
+			.map(|_| OracleSource::Feeders {
+				feeders: feeders.clone(),
+				min_feeders: 1,
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.map_err(|_| BenchmarkError::Stop("Too many sources"))
+	}
+
+	pub fn bondable_key_feeder<T>() -> T::AccountId
+	where
+		T: Config,
+		T::CollectionId: Default,
+		T::OracleKey: Default,
+	{
+		KeySourceList::<T>::insert(
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+			KeySources::<T>::truncate_from(vec![OracleSource::BondedFeeders { min_feeders: 1 }]),
+		);
+
+		let feeder: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(
+			&feeder,
+			T::Currency::minimum_balance()
+				.saturating_add(T::FeederBond::get())
+				.saturating_mul(2u32.into()),
+		);
+
+		feeder
+	}
 }
 
 #[benchmarks(
@@ -154,5 +198,187 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn propose_update_key_sources(n: Linear<1, 3>) -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let admin: T::AccountId = whitelisted_caller();
+
+		T::ChangeGuard::bench_create_pool(T::CollectionId::default(), &admin);
+
+		#[extrinsic_call]
+		propose_update_key_sources(
+			RawOrigin::Signed(admin),
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+			util::key_sources::<T>(n)?,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn apply_update_key_sources(n: Linear<1, 3>) -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let admin: T::AccountId = whitelisted_caller();
+
+		T::ChangeGuard::bench_create_pool(T::CollectionId::default(), &admin);
+
+		let change_id = T::ChangeGuard::note(
+			T::CollectionId::default(),
+			Change::<T>::KeySources(T::OracleKey::default(), util::key_sources::<T>(n)?).into(),
+		)?;
+
+		#[extrinsic_call]
+		apply_update_key_sources(
+			RawOrigin::Signed(admin),
+			T::CollectionId::default(),
+			change_id,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_admin_value() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let admin: T::AccountId = whitelisted_caller();
+
+		T::ChangeGuard::bench_create_pool(T::CollectionId::default(), &admin);
+
+		#[extrinsic_call]
+		set_admin_value(
+			RawOrigin::Signed(admin),
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+			T::OracleValue::default(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_update_key_breaker() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let admin: T::AccountId = whitelisted_caller();
+
+		T::ChangeGuard::bench_create_pool(T::CollectionId::default(), &admin);
+
+		#[extrinsic_call]
+		propose_update_key_breaker(
+			RawOrigin::Signed(admin),
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+			KeyBreaker::default(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn apply_update_key_breaker() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let admin: T::AccountId = whitelisted_caller();
+
+		T::ChangeGuard::bench_create_pool(T::CollectionId::default(), &admin);
+
+		let change_id = T::ChangeGuard::note(
+			T::CollectionId::default(),
+			Change::<T>::KeyBreaker(T::OracleKey::default(), KeyBreaker::default()).into(),
+		)?;
+
+		#[extrinsic_call]
+		apply_update_key_breaker(
+			RawOrigin::Signed(admin),
+			T::CollectionId::default(),
+			change_id,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn bond_feeder() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let feeder = util::bondable_key_feeder::<T>();
+
+		#[extrinsic_call]
+		bond_feeder(
+			RawOrigin::Signed(feeder),
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn unbond_feeder() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let feeder = util::bondable_key_feeder::<T>();
+
+		Pallet::<T>::bond_feeder(
+			RawOrigin::Signed(feeder.clone()).into(),
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+		)?;
+
+		#[extrinsic_call]
+		unbond_feeder(
+			RawOrigin::Signed(feeder),
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resolve_feeder_dispute() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let feeder = util::bondable_key_feeder::<T>();
+
+		Pallet::<T>::bond_feeder(
+			RawOrigin::Signed(feeder.clone()).into(),
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+		)?;
+
+		FeederDisputes::<T>::insert(
+			(T::CollectionId::default(), T::OracleKey::default()),
+			T::FeederId::from(feeder.clone()),
+			(T::OracleValue::default(), T::OracleValue::default()),
+		);
+
+		let origin =
+			T::DisputeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		resolve_feeder_dispute(
+			origin as T::RuntimeOrigin,
+			T::CollectionId::default(),
+			T::OracleKey::default(),
+			feeder,
+			true,
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -21,6 +21,27 @@
 //! configuration of the collection. The resulting collection is optimized to
 //! iterate through all values in just one read.
 //!
+//! The admin can also configure an ordered list of sources for a key, as a
+//! primary set of feeders, fallback sets of feeders and a last-resort value
+//! set by the admin. The value of the key is taken from the first source with
+//! a non-outdated value.
+//!
+//! When the collection is updated, every feeder whose value deviates from the
+//! aggregated value of a key more than a configured threshold is reported
+//! through an event, so off-chain monitoring can detect faulty feeders.
+//!
+//! Each key can also be protected by circuit breaker rules: a max age of its
+//! value and a max deviation between two consecutive collection updates. A
+//! value violating the rules flags the key and, if configured, is rejected.
+//! Consumers can query the flag through [`DataRegistry::is_flagged`], and
+//! monitoring can count the stale keys through [`Pallet::stale_keys_count`].
+//!
+//! Keys whose sources include [`OracleSource::BondedFeeders`] can be fed by
+//! anyone bonding [`Config::FeederBond`], without being allowed by the admin.
+//! A bonded feeder whose value deviates from the aggregated value more than
+//! [`Config::MaxFeederDeviation`] gets a dispute recorded, which is resolved
+//! by [`Config::DisputeOrigin`] either slashing its bond or dismissing it.
+//!
 //! # Assumptions
 //!
 //! This pallet is not fed with external values, you need to configure a
@@ -46,20 +67,36 @@ pub use weights::WeightInfo;
 pub mod pallet {
 	use cfg_traits::{changes::ChangeGuard, data::DataRegistry, PreConditions, ValueProvider};
 	use frame_support::{
-		pallet_prelude::*, storage::bounded_btree_map::BoundedBTreeMap, traits::Time,
+		pallet_prelude::*,
+		storage::{bounded_btree_map::BoundedBTreeMap, bounded_btree_set::BoundedBTreeSet},
+		traits::{Currency, Imbalance, OnUnbalanced, ReservableCurrency, Time},
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::traits::{EnsureAddAssign, EnsureSub, EnsureSubAssign, Zero};
+	use sp_runtime::{
+		traits::{AtLeast32BitUnsigned, EnsureAddAssign, EnsureSub, EnsureSubAssign, Zero},
+		Perquintill,
+	};
 	use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 	use crate::{
 		traits::AggregationProvider,
-		types::{self, CachedCollection, Change, OracleValuePair},
+		types::{
+			self, BreakerTrip, CachedCollection, Change, KeyBreaker, KeySources, OracleSource,
+			OracleValuePair,
+		},
+		util,
 		weights::WeightInfo,
 	};
 
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
@@ -71,8 +108,8 @@ pub mod pallet {
 		/// Represent a runtime change
 		type RuntimeChange: From<Change<Self>> + TryInto<Change<Self>>;
 
-		/// Identify a feeder
-		type FeederId: Parameter + Member + Ord + MaxEncodedLen;
+		/// Identify a feeder. A bonded feeder is identified by its account.
+		type FeederId: Parameter + Member + Ord + MaxEncodedLen + From<Self::AccountId>;
 
 		/// Identify an oracle value
 		type CollectionId: Parameter + Member + Copy + MaxEncodedLen;
@@ -81,7 +118,7 @@ pub mod pallet {
 		type OracleKey: Parameter + Member + Copy + MaxEncodedLen + Ord;
 
 		/// Represent an oracle value
-		type OracleValue: Parameter + Member + Copy + MaxEncodedLen + Ord;
+		type OracleValue: Parameter + Member + Copy + MaxEncodedLen + AtLeast32BitUnsigned;
 
 		/// Represent the time moment when the value was fed
 		type Timestamp: Parameter + Member + Copy + MaxEncodedLen + Ord + EnsureSub;
@@ -119,6 +156,28 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxFeedersPerKey: Get<u32> + Parameter;
 
+		/// Max number of sources configured for a key
+		#[pallet::constant]
+		type MaxSourcesPerKey: Get<u32> + Parameter;
+
+		/// Max deviation of a feeder value from the aggregated value before
+		/// the feeder is reported
+		#[pallet::constant]
+		type MaxFeederDeviation: Get<Perquintill>;
+
+		/// Currency used to bond feeders
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// Amount reserved from a feeder bonding to feed a key
+		#[pallet::constant]
+		type FeederBond: Get<BalanceOf<Self>>;
+
+		/// Origin allowed to resolve the disputes of bonded feeders
+		type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Handler for the bonds slashed from bonded feeders
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
 		/// The weight information for this pallet extrinsics.
 		type WeightInfo: WeightInfo;
 	}
@@ -151,6 +210,89 @@ pub mod pallet {
 	pub(crate) type CollectionKeyCount<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::CollectionId, u32, ValueQuery>;
 
+	/// Store the ordered sources of the keys that do not use the collection
+	/// feeders
+	#[pallet::storage]
+	pub(crate) type KeySourceList<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::OracleKey,
+		KeySources<T>,
+		ValueQuery,
+	>;
+
+	/// Store the values set by the collection admin, used as last-resort
+	/// source
+	#[pallet::storage]
+	pub(crate) type AdminValues<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::OracleKey,
+		OracleValuePair<T>,
+	>;
+
+	/// Store the circuit breaker rules of the keys of a collection
+	#[pallet::storage]
+	pub(crate) type KeyBreakers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::OracleKey,
+		KeyBreaker<T>,
+		ValueQuery,
+	>;
+
+	/// Store the keys whose last value violated their circuit breaker rules
+	#[pallet::storage]
+	pub(crate) type TrippedKeys<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::OracleKey,
+		BreakerTrip,
+	>;
+
+	/// Store the feeders that bonded to feed a key of a collection
+	#[pallet::storage]
+	pub(crate) type BondedFeeders<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::CollectionId,
+		Blake2_128Concat,
+		T::OracleKey,
+		BoundedBTreeSet<T::FeederId, T::MaxFeedersPerKey>,
+		ValueQuery,
+	>;
+
+	/// Store the amount bonded by each account to feed a key of a collection
+	#[pallet::storage]
+	pub(crate) type FeederBonds<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::CollectionId, T::OracleKey),
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+	>;
+
+	/// Store the unresolved disputes of bonded feeders, as the deviated value
+	/// and the aggregated value it deviated from
+	#[pallet::storage]
+	pub(crate) type FeederDisputes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		(T::CollectionId, T::OracleKey),
+		Blake2_128Concat,
+		T::FeederId,
+		(T::OracleValue, T::OracleValue),
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -170,6 +312,58 @@ pub mod pallet {
 			collection_id: T::CollectionId,
 			keys_updated: u32,
 		},
+		UpdatedKeySources {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			sources: KeySources<T>,
+		},
+		AdminValueSet {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			value: T::OracleValue,
+		},
+		FeederDeviated {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			feeder: T::FeederId,
+			value: T::OracleValue,
+			aggregated_value: T::OracleValue,
+			deviation: Perquintill,
+		},
+		UpdatedKeyBreaker {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			breaker: KeyBreaker<T>,
+		},
+		KeyBreakerTripped {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			value: T::OracleValue,
+			trip: BreakerTrip,
+			rejected: bool,
+		},
+		FeederBonded {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			account: T::AccountId,
+			bond: BalanceOf<T>,
+		},
+		FeederUnbonded {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			account: T::AccountId,
+		},
+		FeederSlashed {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			account: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		FeederDisputeDismissed {
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			account: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -194,6 +388,24 @@ pub mod pallet {
 
 		/// The amount of feeders for a key is not enough
 		NotEnoughFeeders,
+
+		/// The key does not accept bonded feeders
+		KeyNotBondable,
+
+		/// The account is already a bonded feeder of the key
+		FeederAlreadyBonded,
+
+		/// The account is not a bonded feeder of the key
+		FeederNotBonded,
+
+		/// The max number of bonded feeders of the key was reached
+		MaxBondedFeeders,
+
+		/// The bonded feeder has an unresolved dispute
+		FeederDisputed,
+
+		/// The bonded feeder has no dispute to resolve
+		NoFeederDispute,
 	}
 
 	#[pallet::call]
@@ -234,9 +446,11 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_signed(origin)?;
 
-			let Change::CollectionInfo(info) = T::ChangeGuard::released(collection_id, change_id)?
-				.try_into()
-				.map_err(|_| Error::<T>::NoOracleCollectionChangeId)?;
+			let Ok(Change::CollectionInfo(info)) =
+				T::ChangeGuard::released(collection_id, change_id)?.try_into()
+			else {
+				return Err(Error::<T>::NoOracleCollectionChangeId.into());
+			};
 
 			CollectionInfo::<T>::insert(collection_id, info.clone());
 			Collection::<T>::remove(collection_id);
@@ -254,7 +468,7 @@ pub mod pallet {
 		///
 		/// This call is permissionless.
 		#[pallet::weight(T::WeightInfo::update_collection(
-			T::MaxFeedersPerKey::get(),
+			T::MaxFeedersPerKey::get().saturating_mul(T::MaxSourcesPerKey::get()),
 			T::MaxCollectionSize::get(),
 		))]
 		#[pallet::call_index(2)]
@@ -264,28 +478,36 @@ pub mod pallet {
 		) -> DispatchResult {
 			ensure_signed(origin)?;
 
-			let mut older_value_timestamp = T::Time::now();
+			let previous = Collection::<T>::get(collection_id).content;
 
 			let values = Keys::<T>::iter_key_prefix(collection_id)
 				.filter_map(|key| {
-					let value = <Self as DataRegistry<T::OracleKey, T::CollectionId>>::get(
-						&key,
-						&collection_id,
-					);
-
-					match value {
-						Ok((value, timestamp)) => {
-							if timestamp < older_value_timestamp {
-								older_value_timestamp = timestamp;
-							}
-							Some(Ok((key, (value, timestamp))))
+					let value = match Self::value_with_feeders(&key, &collection_id) {
+						Ok(((value, timestamp), fed_values)) => {
+							Self::report_deviations(collection_id, key, value, fed_values);
+							Self::apply_breaker(
+								collection_id,
+								key,
+								(value, timestamp),
+								previous.get(&key).copied(),
+							)
 						}
-						Err(err) if err == Error::<T>::KeyNotInCollection.into() => None,
-						Err(err) => Some(Err(err)),
-					}
+						Err(err) if err == Error::<T>::KeyNotInCollection.into() => Ok(None),
+						Err(err) => Err(err),
+					};
+
+					value
+						.map(|value| value.map(|value| (key, value)))
+						.transpose()
 				})
 				.collect::<Result<BTreeMap<_, _>, _>>()?;
 
+			let older_value_timestamp = values
+				.values()
+				.map(|(_, timestamp)| *timestamp)
+				.min()
+				.unwrap_or_else(T::Time::now);
+
 			let collection =
 				BoundedBTreeMap::try_from(values).map_err(|()| Error::<T>::MaxCollectionSize)?;
 
@@ -307,6 +529,261 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Propose an update of the ordered sources of a key of a collection.
+		/// An empty list makes the key use the collection feeders again.
+		/// The sources will only be modified once
+		/// [`Pallet::apply_update_key_sources`] is called.
+		#[pallet::weight(T::WeightInfo::propose_update_key_sources(T::MaxSourcesPerKey::get()))]
+		#[pallet::call_index(3)]
+		pub fn propose_update_key_sources(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			sources: KeySources<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsAdmin::check((who, collection_id)),
+				Error::<T>::IsNotAdmin
+			);
+
+			T::ChangeGuard::note(collection_id, Change::KeySources(key, sources).into())?;
+
+			Ok(())
+		}
+
+		/// Apply an change previously proposed by
+		/// [`Pallet::propose_update_key_sources`] if the conditions to get it
+		/// ready are fullfilled.
+		///
+		/// This call is permissionless.
+		#[pallet::weight(T::WeightInfo::apply_update_key_sources(T::MaxSourcesPerKey::get()))]
+		#[pallet::call_index(4)]
+		pub fn apply_update_key_sources(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let Ok(Change::KeySources(key, sources)) =
+				T::ChangeGuard::released(collection_id, change_id)?.try_into()
+			else {
+				return Err(Error::<T>::NoOracleCollectionChangeId.into());
+			};
+
+			KeySourceList::<T>::set(collection_id, key, sources.clone());
+
+			Self::deposit_event(Event::<T>::UpdatedKeySources {
+				collection_id,
+				key,
+				sources,
+			});
+
+			Ok(())
+		}
+
+		/// Set the value of a key used by the keys configured with an
+		/// [`OracleSource::AdminValue`] source.
+		#[pallet::weight(T::WeightInfo::set_admin_value())]
+		#[pallet::call_index(5)]
+		pub fn set_admin_value(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			value: T::OracleValue,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsAdmin::check((who, collection_id)),
+				Error::<T>::IsNotAdmin
+			);
+
+			AdminValues::<T>::insert(collection_id, key, (value, T::Time::now()));
+
+			Self::deposit_event(Event::<T>::AdminValueSet {
+				collection_id,
+				key,
+				value,
+			});
+
+			Ok(())
+		}
+
+		/// Propose an update of the circuit breaker rules of a key of a
+		/// collection. The rules will only be modified once
+		/// [`Pallet::apply_update_key_breaker`] is called.
+		#[pallet::weight(T::WeightInfo::propose_update_key_breaker())]
+		#[pallet::call_index(6)]
+		pub fn propose_update_key_breaker(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			breaker: KeyBreaker<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsAdmin::check((who, collection_id)),
+				Error::<T>::IsNotAdmin
+			);
+
+			T::ChangeGuard::note(collection_id, Change::KeyBreaker(key, breaker).into())?;
+
+			Ok(())
+		}
+
+		/// Apply an change previously proposed by
+		/// [`Pallet::propose_update_key_breaker`] if the conditions to get it
+		/// ready are fullfilled. The flag of the key is cleared until the
+		/// next collection update.
+		///
+		/// This call is permissionless.
+		#[pallet::weight(T::WeightInfo::apply_update_key_breaker())]
+		#[pallet::call_index(7)]
+		pub fn apply_update_key_breaker(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let Ok(Change::KeyBreaker(key, breaker)) =
+				T::ChangeGuard::released(collection_id, change_id)?.try_into()
+			else {
+				return Err(Error::<T>::NoOracleCollectionChangeId.into());
+			};
+
+			KeyBreakers::<T>::insert(collection_id, key, breaker.clone());
+			TrippedKeys::<T>::remove(collection_id, key);
+
+			Self::deposit_event(Event::<T>::UpdatedKeyBreaker {
+				collection_id,
+				key,
+				breaker,
+			});
+
+			Ok(())
+		}
+
+		/// Bond [`Config::FeederBond`] to become a feeder of a key whose
+		/// sources include [`OracleSource::BondedFeeders`].
+		#[pallet::weight(T::WeightInfo::bond_feeder())]
+		#[pallet::call_index(8)]
+		pub fn bond_feeder(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				KeySourceList::<T>::get(collection_id, key)
+					.iter()
+					.any(|source| matches!(source, OracleSource::BondedFeeders { .. })),
+				Error::<T>::KeyNotBondable
+			);
+			ensure!(
+				!FeederBonds::<T>::contains_key((collection_id, key), &who),
+				Error::<T>::FeederAlreadyBonded
+			);
+
+			BondedFeeders::<T>::try_mutate(collection_id, key, |feeders| {
+				feeders
+					.try_insert(who.clone().into())
+					.map_err(|_| Error::<T>::MaxBondedFeeders)
+			})?;
+
+			let bond = T::FeederBond::get();
+			T::Currency::reserve(&who, bond)?;
+			FeederBonds::<T>::insert((collection_id, key), &who, bond);
+
+			Self::deposit_event(Event::<T>::FeederBonded {
+				collection_id,
+				key,
+				account: who,
+				bond,
+			});
+
+			Ok(())
+		}
+
+		/// Withdraw the bond of a bonded feeder of a key, which stops being
+		/// a feeder of the key. Not allowed while the feeder has an
+		/// unresolved dispute.
+		#[pallet::weight(T::WeightInfo::unbond_feeder())]
+		#[pallet::call_index(9)]
+		pub fn unbond_feeder(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				!FeederDisputes::<T>::contains_key(
+					(collection_id, key),
+					T::FeederId::from(who.clone())
+				),
+				Error::<T>::FeederDisputed
+			);
+
+			let bond = Self::remove_bonded_feeder(collection_id, key, &who)?;
+			T::Currency::unreserve(&who, bond);
+
+			Self::deposit_event(Event::<T>::FeederUnbonded {
+				collection_id,
+				key,
+				account: who,
+			});
+
+			Ok(())
+		}
+
+		/// Resolve the dispute of a bonded feeder whose value deviated from
+		/// the aggregated value, either slashing its bond and removing it from
+		/// the feeders of the key, or dismissing the dispute.
+		#[pallet::weight(T::WeightInfo::resolve_feeder_dispute())]
+		#[pallet::call_index(10)]
+		pub fn resolve_feeder_dispute(
+			origin: OriginFor<T>,
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			account: T::AccountId,
+			slash: bool,
+		) -> DispatchResult {
+			T::DisputeOrigin::ensure_origin(origin)?;
+
+			FeederDisputes::<T>::take((collection_id, key), T::FeederId::from(account.clone()))
+				.ok_or(Error::<T>::NoFeederDispute)?;
+
+			if !slash {
+				Self::deposit_event(Event::<T>::FeederDisputeDismissed {
+					collection_id,
+					key,
+					account,
+				});
+
+				return Ok(());
+			}
+
+			let bond = Self::remove_bonded_feeder(collection_id, key, &account)?;
+			let (imbalance, _) = T::Currency::slash_reserved(&account, bond);
+			let amount = imbalance.peek();
+			T::Slash::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::<T>::FeederSlashed {
+				collection_id,
+				key,
+				account,
+				amount,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> DataRegistry<T::OracleKey, T::CollectionId> for Pallet<T> {
@@ -317,35 +794,7 @@ pub mod pallet {
 			key: &T::OracleKey,
 			collection_id: &T::CollectionId,
 		) -> Result<Self::Data, DispatchError> {
-			let info = CollectionInfo::<T>::get(collection_id);
-
-			let fed_values = info
-				.feeders
-				.into_iter()
-				.filter_map(|feeder| {
-					T::OracleProvider::get(&(feeder, *collection_id), key).transpose()
-				})
-				.collect::<Result<Vec<_>, _>>()?;
-
-			if fed_values.len() < (info.min_feeders as usize) {
-				Err(Error::<T>::NotEnoughFeeders)?
-			}
-
-			let updated_fed_values = fed_values
-				.into_iter()
-				.filter(|(_, timestamp)| {
-					Self::ensure_valid_timestamp(collection_id, *timestamp).is_ok()
-				})
-				.collect::<Vec<_>>();
-
-			if updated_fed_values.len() < (info.min_feeders as usize) {
-				Err(Error::<T>::OracleValueOutdated)?
-			}
-
-			let (value, timestamp) = T::AggregationProvider::aggregate(updated_fed_values)
-				.ok_or(Error::<T>::KeyNotInCollection)?;
-
-			Ok((value, timestamp))
+			Self::value_with_feeders(key, collection_id).map(|(value, _)| value)
 		}
 
 		fn collection(collection_id: &T::CollectionId) -> Result<Self::Collection, DispatchError> {
@@ -413,9 +862,251 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		fn is_flagged(key: &T::OracleKey, collection_id: &T::CollectionId) -> bool {
+			if TrippedKeys::<T>::contains_key(collection_id, key) {
+				return true;
+			}
+
+			let max_age = KeyBreakers::<T>::get(collection_id, key).max_age;
+			let cached = Collection::<T>::get(collection_id)
+				.content
+				.get(key)
+				.copied();
+
+			match (max_age, cached) {
+				(Some(max_age), Some((_, timestamp))) => T::Time::now()
+					.ensure_sub(timestamp)
+					.map_or(false, |age| age > max_age),
+				_ => false,
+			}
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Number of keys registered in any collection whose cached value is
+		/// missing, outdated or flagged.
+		pub fn stale_keys_count() -> u32 {
+			Keys::<T>::iter_keys()
+				.filter(|(collection_id, key)| {
+					let cached = Collection::<T>::get(collection_id)
+						.content
+						.get(key)
+						.copied();
+
+					let outdated = cached.map_or(true, |(_, timestamp)| {
+						Self::ensure_valid_timestamp(collection_id, timestamp).is_err()
+					});
+
+					outdated || Self::is_flagged(key, collection_id)
+				})
+				.count() as u32
+		}
+
+		/// The value of a key along with the feeder values it was aggregated
+		/// from, if any.
+		fn value_with_feeders(
+			key: &T::OracleKey,
+			collection_id: &T::CollectionId,
+		) -> Result<(OracleValuePair<T>, Vec<(T::FeederId, T::OracleValue)>), DispatchError> {
+			let sources = KeySourceList::<T>::get(collection_id, key);
+
+			if sources.is_empty() {
+				let info = CollectionInfo::<T>::get(collection_id);
+				return Self::aggregate_feeders(key, collection_id, info.feeders, info.min_feeders);
+			}
+
+			let mut first_error = None;
+			for source in sources {
+				let value = match source {
+					OracleSource::Feeders {
+						feeders,
+						min_feeders,
+					} => Self::aggregate_feeders(key, collection_id, feeders, min_feeders),
+					OracleSource::AdminValue => {
+						Self::admin_value(key, collection_id).map(|value| (value, Vec::new()))
+					}
+					OracleSource::BondedFeeders { min_feeders } => Self::aggregate_feeders(
+						key,
+						collection_id,
+						BondedFeeders::<T>::get(collection_id, key),
+						min_feeders,
+					),
+				};
+
+				match value {
+					Ok(value) => return Ok(value),
+					Err(err) => {
+						first_error.get_or_insert(err);
+					}
+				}
+			}
+
+			Err(first_error.unwrap_or_else(|| Error::<T>::KeyNotInCollection.into()))
+		}
+
+		/// Aggregates the non-outdated values fed by a set of feeders.
+		fn aggregate_feeders(
+			key: &T::OracleKey,
+			collection_id: &T::CollectionId,
+			feeders: impl IntoIterator<Item = T::FeederId>,
+			min_feeders: u32,
+		) -> Result<(OracleValuePair<T>, Vec<(T::FeederId, T::OracleValue)>), DispatchError> {
+			let fed_values = feeders
+				.into_iter()
+				.filter_map(|feeder| {
+					T::OracleProvider::get(&(feeder.clone(), *collection_id), key)
+						.map(|value| value.map(|value| (feeder, value)))
+						.transpose()
+				})
+				.collect::<Result<Vec<_>, _>>()?;
+
+			if fed_values.len() < (min_feeders as usize) {
+				Err(Error::<T>::NotEnoughFeeders)?
+			}
+
+			let updated_fed_values = fed_values
+				.into_iter()
+				.filter(|(_, (_, timestamp))| {
+					Self::ensure_valid_timestamp(collection_id, *timestamp).is_ok()
+				})
+				.collect::<Vec<_>>();
+
+			if updated_fed_values.len() < (min_feeders as usize) {
+				Err(Error::<T>::OracleValueOutdated)?
+			}
+
+			let (value, timestamp) =
+				T::AggregationProvider::aggregate(updated_fed_values.iter().map(|(_, pair)| *pair))
+					.ok_or(Error::<T>::KeyNotInCollection)?;
+
+			let feeder_values = updated_fed_values
+				.into_iter()
+				.map(|(feeder, (value, _))| (feeder, value))
+				.collect();
+
+			Ok(((value, timestamp), feeder_values))
+		}
+
+		/// Reports the feeders whose value deviates from the aggregated value
+		/// more than [`Config::MaxFeederDeviation`], recording a dispute for
+		/// the bonded ones.
+		fn report_deviations(
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			aggregated_value: T::OracleValue,
+			feeder_values: Vec<(T::FeederId, T::OracleValue)>,
+		) {
+			for (feeder, value) in feeder_values {
+				let deviation = util::deviation(value, aggregated_value);
+
+				if deviation > T::MaxFeederDeviation::get() {
+					if BondedFeeders::<T>::get(collection_id, key).contains(&feeder) {
+						FeederDisputes::<T>::insert(
+							(collection_id, key),
+							&feeder,
+							(value, aggregated_value),
+						);
+					}
+
+					Self::deposit_event(Event::<T>::FeederDeviated {
+						collection_id,
+						key,
+						feeder,
+						value,
+						aggregated_value,
+						deviation,
+					});
+				}
+			}
+		}
+
+		/// Removes a bonded feeder of a key, returning its bond.
+		fn remove_bonded_feeder(
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			account: &T::AccountId,
+		) -> Result<BalanceOf<T>, DispatchError> {
+			let bond = FeederBonds::<T>::take((collection_id, key), account)
+				.ok_or(Error::<T>::FeederNotBonded)?;
+
+			BondedFeeders::<T>::mutate(collection_id, key, |feeders| {
+				feeders.remove(&T::FeederId::from(account.clone()))
+			});
+
+			Ok(bond)
+		}
+
+		/// Checks a new value of a key against the circuit breaker rules of
+		/// the key, flagging the key if any rule is violated. Returns the
+		/// value that must be used in the collection, if any.
+		fn apply_breaker(
+			collection_id: T::CollectionId,
+			key: T::OracleKey,
+			(value, timestamp): OracleValuePair<T>,
+			previous: Option<OracleValuePair<T>>,
+		) -> Result<Option<OracleValuePair<T>>, DispatchError> {
+			let breaker = KeyBreakers::<T>::get(collection_id, key);
+
+			let Some(trip) = Self::breaker_trip(&breaker, (value, timestamp), previous)? else {
+				TrippedKeys::<T>::remove(collection_id, key);
+				return Ok(Some((value, timestamp)));
+			};
+
+			TrippedKeys::<T>::insert(collection_id, key, trip);
+
+			Self::deposit_event(Event::<T>::KeyBreakerTripped {
+				collection_id,
+				key,
+				value,
+				trip,
+				rejected: breaker.reject,
+			});
+
+			Ok(match (breaker.reject, trip) {
+				(false, _) => Some((value, timestamp)),
+				(true, BreakerTrip::Stale) => None,
+				(true, BreakerTrip::Deviated(_)) => previous,
+			})
+		}
+
+		/// The first circuit breaker rule violated by a value, if any.
+		fn breaker_trip(
+			breaker: &KeyBreaker<T>,
+			(value, timestamp): OracleValuePair<T>,
+			previous: Option<OracleValuePair<T>>,
+		) -> Result<Option<BreakerTrip>, DispatchError> {
+			if let Some(max_age) = breaker.max_age {
+				if T::Time::now().ensure_sub(timestamp)? > max_age {
+					return Ok(Some(BreakerTrip::Stale));
+				}
+			}
+
+			if let (Some(max_deviation), Some((previous_value, _))) =
+				(breaker.max_deviation, previous)
+			{
+				let deviation = util::deviation(value, previous_value);
+				if deviation > max_deviation {
+					return Ok(Some(BreakerTrip::Deviated(deviation)));
+				}
+			}
+
+			Ok(None)
+		}
+
+		/// The non-outdated value of a key set by the collection admin.
+		fn admin_value(
+			key: &T::OracleKey,
+			collection_id: &T::CollectionId,
+		) -> Result<OracleValuePair<T>, DispatchError> {
+			let (value, timestamp) =
+				AdminValues::<T>::get(collection_id, key).ok_or(Error::<T>::KeyNotInCollection)?;
+
+			Self::ensure_valid_timestamp(collection_id, timestamp)?;
+
+			Ok((value, timestamp))
+		}
+
 		fn ensure_valid_timestamp(
 			collection_id: &T::CollectionId,
 			timestamp: T::Timestamp,
@@ -477,9 +1168,9 @@ pub mod types {
 		pallet_prelude::{Decode, DispatchError, Encode, MaxEncodedLen, TypeInfo},
 		storage::{bounded_btree_map::BoundedBTreeMap, bounded_btree_set::BoundedBTreeSet},
 		traits::Time,
-		RuntimeDebugNoBound,
+		BoundedVec, RuntimeDebugNoBound,
 	};
-	use sp_runtime::RuntimeDebug;
+	use sp_runtime::{Perquintill, RuntimeDebug};
 	use sp_std::vec::Vec;
 
 	use crate::pallet::{Config, Error};
@@ -514,6 +1205,75 @@ pub mod types {
 		}
 	}
 
+	/// A source of the values of a key
+	#[derive(
+		Encode, Decode, PartialEq, Eq, Clone, TypeInfo, RuntimeDebugNoBound, MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub enum OracleSource<T: Config> {
+		/// Aggregation of the values fed by a set of feeders.
+		Feeders {
+			/// List of feeders of the key
+			feeders: BoundedBTreeSet<T::FeederId, T::MaxFeedersPerKey>,
+
+			/// Minimun number of feeders to succesfully aggregate a value.
+			min_feeders: u32,
+		},
+
+		/// Value set by the collection admin.
+		AdminValue,
+
+		/// Aggregation of the values fed by the feeders that bonded to feed
+		/// the key.
+		BondedFeeders {
+			/// Minimun number of feeders to succesfully aggregate a value.
+			min_feeders: u32,
+		},
+	}
+
+	/// Ordered list of sources of a key
+	pub type KeySources<T> = BoundedVec<OracleSource<T>, <T as Config>::MaxSourcesPerKey>;
+
+	/// Circuit breaker rules of a key, checked on every collection update
+	#[derive(
+		Encode, Decode, PartialEq, Eq, Clone, TypeInfo, RuntimeDebugNoBound, MaxEncodedLen,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct KeyBreaker<T: Config> {
+		/// Maximum duration since the value was fed to consider it non-stale.
+		pub max_age: Option<T::Timestamp>,
+
+		/// Maximum deviation of a value from the value of the key in the
+		/// previous collection update.
+		pub max_deviation: Option<Perquintill>,
+
+		/// If `true`, a value violating the rules is not used: a stale value
+		/// is removed from the collection and a deviated value is replaced by
+		/// the previous one. Otherwise, the value is used and only flagged.
+		pub reject: bool,
+	}
+
+	impl<T: Config> Default for KeyBreaker<T> {
+		fn default() -> Self {
+			Self {
+				max_age: None,
+				max_deviation: None,
+				reject: false,
+			}
+		}
+	}
+
+	/// Circuit breaker rule violated by the value of a key
+	#[derive(Encode, Decode, PartialEq, Eq, Clone, Copy, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+	pub enum BreakerTrip {
+		/// The value is older than the max age of the key
+		Stale,
+
+		/// The value deviates from the previous one more than the max
+		/// deviation of the key
+		Deviated(Perquintill),
+	}
+
 	/// A collection cached in memory
 	#[derive(Encode, Decode, Clone, TypeInfo, RuntimeDebug, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
@@ -568,6 +1328,8 @@ pub mod types {
 	#[scale_info(skip_type_params(T))]
 	pub enum Change<T: Config> {
 		CollectionInfo(CollectionInfo<T>),
+		KeySources(T::OracleKey, KeySources<T>),
+		KeyBreaker(T::OracleKey, KeyBreaker<T>),
 	}
 }
 
@@ -584,7 +1346,9 @@ pub mod traits {
 /// Provide types to use in runtime to configure this pallet
 pub mod util {
 	use frame_support::{storage::bounded_btree_set::BoundedBTreeSet, traits::Get};
-	use sp_runtime::DispatchError;
+	use sp_runtime::{
+		traits::AtLeast32BitUnsigned, DispatchError, Perquintill, SaturatedConversion,
+	};
 	use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
 
 	use super::traits::AggregationProvider;
@@ -624,6 +1388,25 @@ pub mod util {
 		Some(item)
 	}
 
+	/// Computes the deviation of a value relative to a reference value.
+	/// Any value deviates completely from a zero reference unless it is also
+	/// zero.
+	pub fn deviation<T: AtLeast32BitUnsigned + Copy>(value: T, reference: T) -> Perquintill {
+		let difference = value.max(reference) - value.min(reference);
+
+		if reference.is_zero() {
+			return match difference.is_zero() {
+				true => Perquintill::zero(),
+				false => Perquintill::one(),
+			};
+		}
+
+		Perquintill::from_rational(
+			difference.saturated_into::<u128>(),
+			reference.saturated_into::<u128>(),
+		)
+	}
+
 	pub fn feeders_from<T: Ord, Size: Get<u32>>(
 		feeders: impl IntoIterator<Item = T>,
 	) -> Result<BoundedBTreeSet<T, Size>, DispatchError> {
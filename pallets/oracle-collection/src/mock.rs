@@ -1,14 +1,16 @@
 use frame_support::{
 	derive_impl,
 	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
-	traits::ConstU32,
+	traits::{ConstU128, ConstU32},
 };
+use frame_system::EnsureRoot;
 use sp_io::TestExternalities;
-use sp_runtime::testing::H256;
+use sp_runtime::{testing::H256, Perquintill};
 
 use crate::pallet as pallet_oracle_collection;
 
 pub type AccountId = u64;
+pub type Balance = u128;
 pub type OracleKey = u32;
 pub type OracleValue = u128;
 pub type Timestamp = u64;
@@ -16,15 +18,22 @@ pub type CollectionId = u32;
 pub type ChangeId = H256;
 
 pub const NOW: Timestamp = 1000;
+pub const FEEDER_BOND: Balance = 100;
 
 frame_support::parameter_types! {
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxFeedersPerKey: u32 = 5;
+
+	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+	pub const MaxSourcesPerKey: u32 = 3;
+
+	pub const MaxFeederDeviation: Perquintill = Perquintill::from_percent(1);
 }
 
 frame_support::construct_runtime!(
 	pub enum Runtime {
 		System: frame_system,
+		Balances: pallet_balances,
 		MockProvider: cfg_mocks::value_provider::pallet,
 		MockIsAdmin: cfg_mocks::pre_conditions::pallet,
 		MockChangeGuard: cfg_mocks::change_guard::pallet,
@@ -35,9 +44,19 @@ frame_support::construct_runtime!(
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
+	type AccountData = pallet_balances::AccountData<Balance>;
 	type Block = frame_system::mocking::MockBlock<Runtime>;
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
+impl pallet_balances::Config for Runtime {
+	type AccountStore = System;
+	type Balance = Balance;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU128<1>;
+	type RuntimeHoldReason = ();
+}
+
 impl cfg_mocks::value_provider::pallet::Config for Runtime {
 	type Key = OracleKey;
 	type Source = (AccountId, CollectionId);
@@ -63,15 +82,21 @@ impl pallet_oracle_collection::Config for Runtime {
 	type AggregationProvider = crate::util::MedianAggregation;
 	type ChangeGuard = MockChangeGuard;
 	type CollectionId = CollectionId;
+	type Currency = Balances;
+	type DisputeOrigin = EnsureRoot<AccountId>;
+	type FeederBond = ConstU128<FEEDER_BOND>;
 	type FeederId = AccountId;
 	type IsAdmin = MockIsAdmin;
 	type MaxCollectionSize = ConstU32<100>;
+	type MaxFeederDeviation = MaxFeederDeviation;
 	type MaxFeedersPerKey = MaxFeedersPerKey;
+	type MaxSourcesPerKey = MaxSourcesPerKey;
 	type OracleKey = OracleKey;
 	type OracleProvider = MockProvider;
 	type OracleValue = OracleValue;
 	type RuntimeChange = crate::types::Change<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
+	type Slash = ();
 	type Time = MockTime;
 	type Timestamp = Timestamp;
 	type WeightInfo = ();
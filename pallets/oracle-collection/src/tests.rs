@@ -1,11 +1,11 @@
 use cfg_traits::data::DataRegistry;
 use frame_support::{assert_err, assert_ok};
-use sp_runtime::{testing::H256, traits::Get, DispatchError};
+use sp_runtime::{testing::H256, traits::Get, DispatchError, Perquintill};
 
 use crate::{
 	mock::*,
 	pallet::{Config, Error, Event, Keys},
-	types::{Change, CollectionInfo},
+	types::{BreakerTrip, Change, CollectionInfo, KeyBreaker, KeySources, OracleSource},
 };
 
 const ADMIN: AccountId = 1;
@@ -113,6 +113,89 @@ mod util {
 		MockChangeGuard::mock_released(|_, _| panic!("no released() mock"));
 		MockIsAdmin::mock_check(|_| panic!("no check() mock"));
 	}
+
+	pub fn feeders_source(
+		feeders: impl IntoIterator<Item = AccountId>,
+		min_feeders: u32,
+	) -> OracleSource<Runtime> {
+		OracleSource::Feeders {
+			feeders: crate::util::feeders_from(feeders).unwrap(),
+			min_feeders,
+		}
+	}
+
+	pub fn update_key_sources(key: OracleKey, sources: Vec<OracleSource<Runtime>>) {
+		let sources = KeySources::<Runtime>::try_from(sources).unwrap();
+
+		MockChangeGuard::mock_note(|_, _| Ok(CHANGE_ID));
+		MockChangeGuard::mock_released({
+			let sources = sources.clone();
+			move |_, _| Ok(Change::KeySources(key, sources.clone()))
+		});
+		MockIsAdmin::mock_check(|_| true);
+
+		OracleCollection::propose_update_key_sources(
+			RuntimeOrigin::signed(ADMIN),
+			COLLECTION_ID,
+			key,
+			sources,
+		)
+		.unwrap();
+
+		OracleCollection::apply_update_key_sources(
+			RuntimeOrigin::signed(ADMIN),
+			COLLECTION_ID,
+			CHANGE_ID,
+		)
+		.unwrap();
+
+		MockChangeGuard::mock_note(|_, _| panic!("no note() mock"));
+		MockChangeGuard::mock_released(|_, _| panic!("no released() mock"));
+		MockIsAdmin::mock_check(|_| panic!("no check() mock"));
+	}
+
+	pub fn update_key_breaker(key: OracleKey, breaker: KeyBreaker<Runtime>) {
+		MockChangeGuard::mock_note(|_, _| Ok(CHANGE_ID));
+		MockChangeGuard::mock_released({
+			let breaker = breaker.clone();
+			move |_, _| Ok(Change::KeyBreaker(key, breaker.clone()))
+		});
+		MockIsAdmin::mock_check(|_| true);
+
+		OracleCollection::propose_update_key_breaker(
+			RuntimeOrigin::signed(ADMIN),
+			COLLECTION_ID,
+			key,
+			breaker,
+		)
+		.unwrap();
+
+		OracleCollection::apply_update_key_breaker(
+			RuntimeOrigin::signed(ADMIN),
+			COLLECTION_ID,
+			CHANGE_ID,
+		)
+		.unwrap();
+
+		MockChangeGuard::mock_note(|_, _| panic!("no note() mock"));
+		MockChangeGuard::mock_released(|_, _| panic!("no released() mock"));
+		MockIsAdmin::mock_check(|_| panic!("no check() mock"));
+	}
+
+	pub fn feed_and_update(value: OracleValue, timestamp: Timestamp) {
+		MockProvider::mock_get(move |_, _| Ok(Some((value, timestamp))));
+
+		OracleCollection::update_collection(RuntimeOrigin::signed(ANY), COLLECTION_ID).unwrap();
+	}
+
+	pub fn set_admin_value(key: OracleKey, value: OracleValue) {
+		MockIsAdmin::mock_check(|_| true);
+
+		OracleCollection::set_admin_value(RuntimeOrigin::signed(ADMIN), COLLECTION_ID, key, value)
+			.unwrap();
+
+		MockIsAdmin::mock_check(|_| panic!("no check() mock"));
+	}
 }
 
 #[test]
@@ -276,6 +359,23 @@ fn getting_value_but_outdated() {
 	});
 }
 
+#[test]
+fn counting_stale_keys() {
+	new_test_ext().execute_with(|| {
+		util::update_collection_info(Some(ENOUGH_MAX_AGE), 0, vec![FEEDER_1]);
+
+		assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+		assert_ok!(OracleCollection::register_id(&KEY_B, &COLLECTION_ID));
+		assert_eq!(OracleCollection::stale_keys_count(), 2);
+
+		util::feed_and_update(100, NOW);
+		assert_eq!(OracleCollection::stale_keys_count(), 0);
+
+		MockTime::mock_now(|| NOW + ENOUGH_MAX_AGE + 1);
+		assert_eq!(OracleCollection::stale_keys_count(), 2);
+	});
+}
+
 #[test]
 fn update_collection() {
 	new_test_ext().execute_with(|| {
@@ -507,3 +607,722 @@ fn update_collection_exceed_size() {
 		);
 	});
 }
+
+#[test]
+fn update_collection_with_deviated_feeder() {
+	new_test_ext().execute_with(|| {
+		util::update_collection_info(None, 0, vec![FEEDER_1, FEEDER_2, FEEDER_3]);
+
+		assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+		MockProvider::mock_get(|(account, _), _| match *account {
+			FEEDER_1 => Ok(Some((100, NOW))),
+			FEEDER_2 => Ok(Some((101, NOW))),
+			FEEDER_3 => Ok(Some((150, NOW))),
+			_ => unreachable!(),
+		});
+
+		assert_ok!(OracleCollection::update_collection(
+			RuntimeOrigin::signed(ANY),
+			COLLECTION_ID
+		));
+
+		assert_eq!(
+			OracleCollection::collection(&COLLECTION_ID)
+				.unwrap()
+				.as_vec(),
+			vec![(KEY_A, (101, NOW))]
+		);
+
+		// Only the feeder deviating more than the 1% threshold is reported
+		let deviated_feeders = System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::OracleCollection(Event::FeederDeviated { feeder, .. }) => {
+					Some(feeder)
+				}
+				_ => None,
+			})
+			.collect::<Vec<_>>();
+
+		assert_eq!(deviated_feeders, vec![FEEDER_3]);
+
+		System::assert_has_event(
+			Event::<Runtime>::FeederDeviated {
+				collection_id: COLLECTION_ID,
+				key: KEY_A,
+				feeder: FEEDER_3,
+				value: 150,
+				aggregated_value: 101,
+				deviation: Perquintill::from_rational(49u128, 101u128),
+			}
+			.into(),
+		);
+	});
+}
+
+mod key_sources {
+	use super::*;
+
+	#[test]
+	fn updating_key_sources() {
+		new_test_ext().execute_with(|| {
+			let sources = KeySources::<Runtime>::try_from(vec![
+				util::feeders_source([FEEDER_1], 1),
+				OracleSource::AdminValue,
+			])
+			.unwrap();
+
+			MockIsAdmin::mock_check(|(admin, collection_id)| {
+				assert_eq!(admin, ADMIN);
+				assert_eq!(collection_id, COLLECTION_ID);
+				true
+			});
+			MockChangeGuard::mock_note({
+				let sources = sources.clone();
+				move |_, change| {
+					assert_eq!(change, Change::KeySources(KEY_A, sources.clone()));
+					Ok(CHANGE_ID)
+				}
+			});
+			MockChangeGuard::mock_released({
+				let sources = sources.clone();
+				move |_, _| Ok(Change::KeySources(KEY_A, sources.clone()))
+			});
+
+			assert_ok!(OracleCollection::propose_update_key_sources(
+				RuntimeOrigin::signed(ADMIN),
+				COLLECTION_ID,
+				KEY_A,
+				sources.clone(),
+			));
+
+			assert_ok!(OracleCollection::apply_update_key_sources(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID,
+				CHANGE_ID,
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::UpdatedKeySources {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+					sources,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn updating_key_sources_wrong_admin() {
+		new_test_ext().execute_with(|| {
+			MockIsAdmin::mock_check(|_| false);
+
+			assert_err!(
+				OracleCollection::propose_update_key_sources(
+					RuntimeOrigin::signed(ADMIN),
+					COLLECTION_ID,
+					KEY_A,
+					KeySources::<Runtime>::default(),
+				),
+				Error::<Runtime>::IsNotAdmin
+			);
+		});
+	}
+
+	#[test]
+	fn applying_wrong_change() {
+		new_test_ext().execute_with(|| {
+			MockChangeGuard::mock_released(|_, _| {
+				Ok(Change::CollectionInfo(CollectionInfo::default()))
+			});
+
+			assert_err!(
+				OracleCollection::apply_update_key_sources(
+					RuntimeOrigin::signed(ANY),
+					COLLECTION_ID,
+					CHANGE_ID,
+				),
+				Error::<Runtime>::NoOracleCollectionChangeId
+			);
+		});
+	}
+
+	#[test]
+	fn setting_admin_value_wrong_admin() {
+		new_test_ext().execute_with(|| {
+			MockIsAdmin::mock_check(|_| false);
+
+			assert_err!(
+				OracleCollection::set_admin_value(
+					RuntimeOrigin::signed(ANY),
+					COLLECTION_ID,
+					KEY_A,
+					500
+				),
+				Error::<Runtime>::IsNotAdmin
+			);
+		});
+	}
+
+	#[test]
+	fn getting_value_from_primary_source() {
+		new_test_ext().execute_with(|| {
+			util::update_key_sources(
+				KEY_A,
+				vec![
+					util::feeders_source([FEEDER_1, FEEDER_2], 2),
+					OracleSource::AdminValue,
+				],
+			);
+			util::set_admin_value(KEY_A, 500);
+
+			mock::prepare_provider();
+
+			assert_ok!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				(101, NOW - 50)
+			);
+		});
+	}
+
+	#[test]
+	fn getting_value_from_fallback_feeders() {
+		new_test_ext().execute_with(|| {
+			util::update_key_sources(
+				KEY_B,
+				vec![
+					util::feeders_source([FEEDER_2, FEEDER_3], 1),
+					util::feeders_source([FEEDER_1], 1),
+				],
+			);
+
+			mock::prepare_provider();
+
+			assert_ok!(OracleCollection::get(&KEY_B, &COLLECTION_ID), (1000, NOW));
+		});
+	}
+
+	#[test]
+	fn getting_admin_value_when_feeders_outdated() {
+		new_test_ext().execute_with(|| {
+			util::update_collection_info(Some(NOT_ENOUGH_MAX_AGE), 0, vec![]);
+			util::update_key_sources(
+				KEY_A,
+				vec![
+					util::feeders_source([FEEDER_1, FEEDER_2, FEEDER_3], 1),
+					OracleSource::AdminValue,
+				],
+			);
+			util::set_admin_value(KEY_A, 500);
+
+			mock::prepare_provider();
+
+			assert_ok!(OracleCollection::get(&KEY_A, &COLLECTION_ID), (500, NOW));
+		});
+	}
+
+	#[test]
+	fn getting_value_with_all_sources_failing() {
+		new_test_ext().execute_with(|| {
+			util::update_collection_info(Some(NOT_ENOUGH_MAX_AGE), 0, vec![]);
+			util::update_key_sources(
+				KEY_A,
+				vec![
+					util::feeders_source([FEEDER_1, FEEDER_2, FEEDER_3], 1),
+					OracleSource::AdminValue,
+				],
+			);
+
+			mock::prepare_provider();
+
+			// The error of the primary source is reported
+			assert_err!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				Error::<Runtime>::OracleValueOutdated
+			);
+		});
+	}
+
+	#[test]
+	fn update_collection_with_key_sources() {
+		new_test_ext().execute_with(|| {
+			util::update_collection_info(None, 0, vec![FEEDER_1, FEEDER_2, FEEDER_3]);
+			util::update_key_sources(
+				KEY_B,
+				vec![
+					util::feeders_source([FEEDER_2], 1),
+					OracleSource::AdminValue,
+				],
+			);
+			util::set_admin_value(KEY_B, 500);
+
+			assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+			assert_ok!(OracleCollection::register_id(&KEY_B, &COLLECTION_ID));
+
+			mock::prepare_provider();
+
+			assert_ok!(OracleCollection::update_collection(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID
+			));
+
+			let collection = OracleCollection::collection(&COLLECTION_ID).unwrap();
+			assert_eq!(
+				collection.as_vec(),
+				vec![(KEY_A, (101, NOW - 50)), (KEY_B, (500, NOW))]
+			);
+		});
+	}
+}
+
+mod key_breakers {
+	use super::*;
+
+	fn deviation_breaker(reject: bool) -> KeyBreaker<Runtime> {
+		KeyBreaker {
+			max_age: None,
+			max_deviation: Some(Perquintill::from_percent(10)),
+			reject,
+		}
+	}
+
+	fn stale_breaker(reject: bool) -> KeyBreaker<Runtime> {
+		KeyBreaker {
+			max_age: Some(NOT_ENOUGH_MAX_AGE),
+			max_deviation: None,
+			reject,
+		}
+	}
+
+	#[test]
+	fn updating_key_breaker() {
+		new_test_ext().execute_with(|| {
+			let breaker = deviation_breaker(true);
+
+			MockIsAdmin::mock_check(|(admin, collection_id)| {
+				assert_eq!(admin, ADMIN);
+				assert_eq!(collection_id, COLLECTION_ID);
+				true
+			});
+			MockChangeGuard::mock_note({
+				let breaker = breaker.clone();
+				move |_, change| {
+					assert_eq!(change, Change::KeyBreaker(KEY_A, breaker.clone()));
+					Ok(CHANGE_ID)
+				}
+			});
+			MockChangeGuard::mock_released({
+				let breaker = breaker.clone();
+				move |_, _| Ok(Change::KeyBreaker(KEY_A, breaker.clone()))
+			});
+
+			assert_ok!(OracleCollection::propose_update_key_breaker(
+				RuntimeOrigin::signed(ADMIN),
+				COLLECTION_ID,
+				KEY_A,
+				breaker.clone(),
+			));
+
+			assert_ok!(OracleCollection::apply_update_key_breaker(
+				RuntimeOrigin::signed(ANY),
+				COLLECTION_ID,
+				CHANGE_ID,
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::UpdatedKeyBreaker {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+					breaker,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn updating_key_breaker_wrong_admin() {
+		new_test_ext().execute_with(|| {
+			MockIsAdmin::mock_check(|_| false);
+
+			assert_err!(
+				OracleCollection::propose_update_key_breaker(
+					RuntimeOrigin::signed(ADMIN),
+					COLLECTION_ID,
+					KEY_A,
+					KeyBreaker::default(),
+				),
+				Error::<Runtime>::IsNotAdmin
+			);
+		});
+	}
+
+	#[test]
+	fn flagging_deviated_value() {
+		new_test_ext().execute_with(|| {
+			util::update_collection_info(None, 1, vec![FEEDER_1]);
+			util::update_key_breaker(KEY_A, deviation_breaker(false));
+
+			assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+			util::feed_and_update(100, NOW);
+			assert!(!OracleCollection::is_flagged(&KEY_A, &COLLECTION_ID));
+
+			util::feed_and_update(150, NOW);
+			assert!(OracleCollection::is_flagged(&KEY_A, &COLLECTION_ID));
+
+			// The value is used anyway
+			assert_eq!(
+				OracleCollection::collection(&COLLECTION_ID)
+					.unwrap()
+					.as_vec(),
+				vec![(KEY_A, (150, NOW))]
+			);
+
+			System::assert_has_event(
+				Event::<Runtime>::KeyBreakerTripped {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+					value: 150,
+					trip: BreakerTrip::Deviated(Perquintill::from_percent(50)),
+					rejected: false,
+				}
+				.into(),
+			);
+
+			// A value within the deviation clears the flag
+			util::feed_and_update(155, NOW);
+			assert!(!OracleCollection::is_flagged(&KEY_A, &COLLECTION_ID));
+		});
+	}
+
+	#[test]
+	fn rejecting_deviated_value() {
+		new_test_ext().execute_with(|| {
+			util::update_collection_info(None, 1, vec![FEEDER_1]);
+			util::update_key_breaker(KEY_A, deviation_breaker(true));
+
+			assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+			util::feed_and_update(100, NOW - 10);
+			util::feed_and_update(150, NOW);
+
+			assert!(OracleCollection::is_flagged(&KEY_A, &COLLECTION_ID));
+
+			// The previous value is kept
+			assert_eq!(
+				OracleCollection::collection(&COLLECTION_ID)
+					.unwrap()
+					.as_vec(),
+				vec![(KEY_A, (100, NOW - 10))]
+			);
+		});
+	}
+
+	#[test]
+	fn rejecting_stale_value() {
+		new_test_ext().execute_with(|| {
+			util::update_collection_info(None, 0, vec![FEEDER_1]);
+			util::update_key_breaker(KEY_A, stale_breaker(true));
+
+			assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+			util::feed_and_update(100, NOW - ENOUGH_MAX_AGE);
+
+			assert!(OracleCollection::is_flagged(&KEY_A, &COLLECTION_ID));
+			assert!(OracleCollection::collection(&COLLECTION_ID)
+				.unwrap()
+				.as_vec()
+				.is_empty());
+
+			System::assert_has_event(
+				Event::<Runtime>::KeyBreakerTripped {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+					value: 100,
+					trip: BreakerTrip::Stale,
+					rejected: true,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn flagging_value_stale_after_update() {
+		new_test_ext().execute_with(|| {
+			util::update_collection_info(None, 0, vec![FEEDER_1]);
+			util::update_key_breaker(KEY_A, stale_breaker(false));
+
+			assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+			util::feed_and_update(100, NOW);
+			assert!(!OracleCollection::is_flagged(&KEY_A, &COLLECTION_ID));
+
+			MockTime::mock_now(|| NOW + ENOUGH_MAX_AGE);
+			assert!(OracleCollection::is_flagged(&KEY_A, &COLLECTION_ID));
+		});
+	}
+}
+
+mod bonded_feeders {
+	use frame_support::{
+		assert_noop,
+		traits::{Currency, ReservableCurrency},
+	};
+	use sp_runtime::DispatchError::BadOrigin;
+
+	use super::*;
+
+	const INITIAL_BALANCE: Balance = 1000;
+
+	fn setup_bondable_key() {
+		util::update_key_sources(KEY_A, vec![OracleSource::BondedFeeders { min_feeders: 1 }]);
+
+		for feeder in [FEEDER_1, FEEDER_2, FEEDER_3] {
+			Balances::make_free_balance_be(&feeder, INITIAL_BALANCE);
+		}
+	}
+
+	fn bond(feeder: AccountId) {
+		assert_ok!(OracleCollection::bond_feeder(
+			RuntimeOrigin::signed(feeder),
+			COLLECTION_ID,
+			KEY_A
+		));
+	}
+
+	fn update_with_deviated_feeder() {
+		assert_ok!(OracleCollection::register_id(&KEY_A, &COLLECTION_ID));
+
+		MockProvider::mock_get(|(account, _), _| match *account {
+			FEEDER_1 => Ok(Some((100, NOW))),
+			FEEDER_2 => Ok(Some((101, NOW))),
+			FEEDER_3 => Ok(Some((150, NOW))),
+			_ => unreachable!(),
+		});
+
+		assert_ok!(OracleCollection::update_collection(
+			RuntimeOrigin::signed(ANY),
+			COLLECTION_ID
+		));
+	}
+
+	#[test]
+	fn bonding_feeder() {
+		new_test_ext().execute_with(|| {
+			setup_bondable_key();
+
+			bond(FEEDER_1);
+
+			assert_eq!(Balances::reserved_balance(FEEDER_1), FEEDER_BOND);
+			System::assert_last_event(
+				Event::<Runtime>::FeederBonded {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+					account: FEEDER_1,
+					bond: FEEDER_BOND,
+				}
+				.into(),
+			);
+
+			assert_noop!(
+				OracleCollection::bond_feeder(
+					RuntimeOrigin::signed(FEEDER_1),
+					COLLECTION_ID,
+					KEY_A
+				),
+				Error::<Runtime>::FeederAlreadyBonded
+			);
+		});
+	}
+
+	#[test]
+	fn bonding_feeder_not_bondable_key() {
+		new_test_ext().execute_with(|| {
+			Balances::make_free_balance_be(&FEEDER_1, INITIAL_BALANCE);
+
+			assert_noop!(
+				OracleCollection::bond_feeder(
+					RuntimeOrigin::signed(FEEDER_1),
+					COLLECTION_ID,
+					KEY_A
+				),
+				Error::<Runtime>::KeyNotBondable
+			);
+		});
+	}
+
+	#[test]
+	fn unbonding_feeder() {
+		new_test_ext().execute_with(|| {
+			setup_bondable_key();
+
+			bond(FEEDER_1);
+
+			assert_ok!(OracleCollection::unbond_feeder(
+				RuntimeOrigin::signed(FEEDER_1),
+				COLLECTION_ID,
+				KEY_A
+			));
+
+			assert_eq!(Balances::reserved_balance(FEEDER_1), 0);
+			assert_eq!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				Err(Error::<Runtime>::NotEnoughFeeders.into())
+			);
+
+			assert_noop!(
+				OracleCollection::unbond_feeder(
+					RuntimeOrigin::signed(FEEDER_1),
+					COLLECTION_ID,
+					KEY_A
+				),
+				Error::<Runtime>::FeederNotBonded
+			);
+		});
+	}
+
+	#[test]
+	fn getting_value_from_bonded_feeders() {
+		new_test_ext().execute_with(|| {
+			setup_bondable_key();
+
+			bond(FEEDER_1);
+			bond(FEEDER_2);
+			bond(FEEDER_3);
+
+			mock::prepare_provider();
+
+			assert_ok!(
+				OracleCollection::get(&KEY_A, &COLLECTION_ID),
+				(101, NOW - 50)
+			);
+		});
+	}
+
+	#[test]
+	fn slashing_deviated_feeder() {
+		new_test_ext().execute_with(|| {
+			setup_bondable_key();
+
+			bond(FEEDER_1);
+			bond(FEEDER_2);
+			bond(FEEDER_3);
+
+			update_with_deviated_feeder();
+
+			assert_noop!(
+				OracleCollection::unbond_feeder(
+					RuntimeOrigin::signed(FEEDER_3),
+					COLLECTION_ID,
+					KEY_A
+				),
+				Error::<Runtime>::FeederDisputed
+			);
+
+			assert_ok!(OracleCollection::resolve_feeder_dispute(
+				RuntimeOrigin::root(),
+				COLLECTION_ID,
+				KEY_A,
+				FEEDER_3,
+				true
+			));
+
+			assert_eq!(Balances::reserved_balance(FEEDER_3), 0);
+			assert_eq!(
+				Balances::free_balance(FEEDER_3),
+				INITIAL_BALANCE - FEEDER_BOND
+			);
+			System::assert_last_event(
+				Event::<Runtime>::FeederSlashed {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+					account: FEEDER_3,
+					amount: FEEDER_BOND,
+				}
+				.into(),
+			);
+
+			// The slashed feeder is no longer aggregated
+			assert_ok!(OracleCollection::get(&KEY_A, &COLLECTION_ID), (101, NOW));
+
+			assert_noop!(
+				OracleCollection::resolve_feeder_dispute(
+					RuntimeOrigin::root(),
+					COLLECTION_ID,
+					KEY_A,
+					FEEDER_3,
+					true
+				),
+				Error::<Runtime>::NoFeederDispute
+			);
+		});
+	}
+
+	#[test]
+	fn dismissing_dispute() {
+		new_test_ext().execute_with(|| {
+			setup_bondable_key();
+
+			bond(FEEDER_1);
+			bond(FEEDER_2);
+			bond(FEEDER_3);
+
+			update_with_deviated_feeder();
+
+			assert_noop!(
+				OracleCollection::resolve_feeder_dispute(
+					RuntimeOrigin::root(),
+					COLLECTION_ID,
+					KEY_A,
+					FEEDER_1,
+					true
+				),
+				Error::<Runtime>::NoFeederDispute
+			);
+
+			assert_ok!(OracleCollection::resolve_feeder_dispute(
+				RuntimeOrigin::root(),
+				COLLECTION_ID,
+				KEY_A,
+				FEEDER_3,
+				false
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::FeederDisputeDismissed {
+					collection_id: COLLECTION_ID,
+					key: KEY_A,
+					account: FEEDER_3,
+				}
+				.into(),
+			);
+
+			assert_ok!(OracleCollection::unbond_feeder(
+				RuntimeOrigin::signed(FEEDER_3),
+				COLLECTION_ID,
+				KEY_A
+			));
+			assert_eq!(Balances::free_balance(FEEDER_3), INITIAL_BALANCE);
+		});
+	}
+
+	#[test]
+	fn resolving_dispute_wrong_origin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				OracleCollection::resolve_feeder_dispute(
+					RuntimeOrigin::signed(ADMIN),
+					COLLECTION_ID,
+					KEY_A,
+					FEEDER_3,
+					true
+				),
+				BadOrigin
+			);
+		});
+	}
+}
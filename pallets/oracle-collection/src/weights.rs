@@ -17,6 +17,14 @@ pub trait WeightInfo {
 	fn propose_update_collection_info(feeders: u32) -> Weight;
 	fn apply_update_collection_info(feeders: u32) -> Weight;
 	fn update_collection(feeders: u32, keys: u32) -> Weight;
+	fn propose_update_key_sources(sources: u32) -> Weight;
+	fn apply_update_key_sources(sources: u32) -> Weight;
+	fn set_admin_value() -> Weight;
+	fn propose_update_key_breaker() -> Weight;
+	fn apply_update_key_breaker() -> Weight;
+	fn bond_feeder() -> Weight;
+	fn unbond_feeder() -> Weight;
+	fn resolve_feeder_dispute() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -31,4 +39,36 @@ impl WeightInfo for () {
 	fn update_collection(_: u32, _: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn propose_update_key_sources(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn apply_update_key_sources(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn set_admin_value() -> Weight {
+		Weight::zero()
+	}
+
+	fn propose_update_key_breaker() -> Weight {
+		Weight::zero()
+	}
+
+	fn apply_update_key_breaker() -> Weight {
+		Weight::zero()
+	}
+
+	fn bond_feeder() -> Weight {
+		Weight::zero()
+	}
+
+	fn unbond_feeder() -> Weight {
+		Weight::zero()
+	}
+
+	fn resolve_feeder_dispute() -> Weight {
+		Weight::zero()
+	}
 }
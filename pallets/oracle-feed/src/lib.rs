@@ -43,7 +43,7 @@ pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use cfg_traits::{fees::PayFee, ValueProvider};
+	use cfg_traits::{fees::PayFee, ValueFeeder, ValueProvider};
 	use frame_support::{
 		pallet_prelude::*,
 		traits::{OriginTrait, Time},
@@ -165,6 +165,20 @@ pub mod pallet {
 			FedValues::<T>::insert(source.caller(), key, value)
 		}
 	}
+
+	/// Feeds values on behalf of feeders already verified by the caller,
+	/// without charging any fee.
+	impl<T: Config> ValueFeeder<Feeder<T>, T::OracleKey> for Pallet<T> {
+		type Value = T::OracleValue;
+
+		fn feed(feeder: Feeder<T>, key: T::OracleKey, value: T::OracleValue) -> DispatchResult {
+			FedValues::<T>::insert(&feeder, key, (value, T::Time::now()));
+
+			Self::deposit_event(Event::<T>::Fed { feeder, key, value });
+
+			Ok(())
+		}
+	}
 }
 
 pub mod util {
@@ -1,4 +1,4 @@
-use cfg_traits::ValueProvider;
+use cfg_traits::{ValueFeeder, ValueProvider};
 use frame_support::{assert_ok, traits::OriginTrait};
 
 use crate::{mock::*, Event};
@@ -59,6 +59,35 @@ fn feed_root() {
 	});
 }
 
+#[test]
+fn feed_from_adapter() {
+	new_test_ext().execute_with(|| {
+		MockTime::mock_now(|| TIMESTAMP1);
+		MockPayFee::mock_pay(|_| unreachable!("Feeding through an adapter does not require fees"));
+
+		let feeder = RuntimeOrigin::signed(FEEDER).into_caller();
+
+		assert_ok!(<OracleFeed as ValueFeeder<_, _>>::feed(
+			feeder.clone(),
+			KEY,
+			VALUE1
+		));
+		assert_ok!(
+			OracleFeed::get(&RuntimeOrigin::signed(FEEDER), &KEY),
+			Some((VALUE1, TIMESTAMP1))
+		);
+
+		System::assert_last_event(
+			Event::<Runtime>::Fed {
+				feeder,
+				key: KEY,
+				value: VALUE1,
+			}
+			.into(),
+		);
+	});
+}
+
 #[test]
 fn get_unfeeded() {
 	new_test_ext().execute_with(|| {
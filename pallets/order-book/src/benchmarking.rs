@@ -111,17 +111,59 @@ where
 		min_fulfillment + T::BalanceOut::from(5u32) * zeros
 	}
 
+	/// Market orders with a max slippage are the most expensive ones,
+	/// because they need to read the market ratio when placed and filled.
+	pub fn worst_ratio() -> OrderRatio<T::Ratio> {
+		OrderRatio::MarketWithSlippage {
+			max_slippage: T::Ratio::saturating_from_rational(1, 10),
+		}
+	}
+
 	pub fn place_order(account_out: &T::AccountId) -> T::OrderIdNonce {
+		Self::feed_market();
+
 		<Pallet<T> as TokenSwaps<T::AccountId>>::place_order(
 			account_out.clone(),
 			CURRENCY_IN.into(),
 			CURRENCY_OUT.into(),
 			Self::amount_out(),
-			OrderRatio::Market,
+			Self::worst_ratio(),
 		)
 		.unwrap()
 	}
 
+	/// Places an order crossing the one placed by `place_order()` exactly at
+	/// the market ratio.
+	pub fn place_inverse_order(account_in: &T::AccountId) -> T::OrderIdNonce {
+		let amount_out: BalanceOf<T> = Pallet::<T>::convert_with_ratio(
+			CURRENCY_OUT.into(),
+			CURRENCY_IN.into(),
+			T::Ratio::saturating_from_integer(RATIO),
+			Self::amount_out(),
+		)
+		.unwrap()
+		.into();
+
+		Pallet::<T>::place_order(
+			RawOrigin::Signed(account_in.clone()).into(),
+			CURRENCY_OUT.into(),
+			CURRENCY_IN.into(),
+			amount_out.into(),
+			OrderRatio::Custom(T::Ratio::saturating_from_rational(1, RATIO)),
+		)
+		.unwrap();
+
+		OrderIdNonceStore::<T>::get()
+	}
+
+	pub fn worst_fees() -> FeeConfig<T::AccountId> {
+		FeeConfig {
+			maker: Permill::from_percent(1),
+			taker: Permill::from_percent(1),
+			destination: Some(account::<T::AccountId>("fee_destination", 0, 0)),
+		}
+	}
+
 	pub fn feed_market() {
 		Pallet::<T>::set_market_feeder(RawOrigin::Root.into(), FEEDER.into()).unwrap();
 		T::RatioProvider::set(
@@ -148,6 +190,7 @@ mod benchmarks {
 		init_mocks();
 
 		let (account_out, _) = Helper::<T>::setup();
+		Helper::<T>::feed_market();
 
 		#[extrinsic_call]
 		place_order(
@@ -155,7 +198,7 @@ mod benchmarks {
 			CURRENCY_IN.into(),
 			CURRENCY_OUT.into(),
 			Helper::<T>::amount_out(),
-			OrderRatio::Market, // Market is the expensive one
+			Helper::<T>::worst_ratio(),
 		);
 
 		Ok(())
@@ -175,7 +218,7 @@ mod benchmarks {
 			RawOrigin::Signed(account_out),
 			order_id,
 			amount,
-			OrderRatio::Market,
+			Helper::<T>::worst_ratio(),
 		);
 
 		Ok(())
@@ -206,6 +249,10 @@ mod benchmarks {
 
 		Helper::<T>::feed_market();
 
+		// Charging fees to a custom destination is the worst case.
+		Pallet::<T>::set_fees(RawOrigin::Root.into(), Helper::<T>::worst_fees())?;
+		FeeExemptOrders::<T>::remove(order_id);
+
 		#[extrinsic_call]
 		fill_order(RawOrigin::Signed(account_in), order_id, amount);
 
@@ -223,5 +270,121 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn place_order_with_expiry() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let (account_out, _) = Helper::<T>::setup();
+		let expires_at = frame_system::Pallet::<T>::block_number();
+		Helper::<T>::feed_market();
+
+		#[extrinsic_call]
+		place_order_with_expiry(
+			RawOrigin::Signed(account_out.clone()),
+			CURRENCY_IN.into(),
+			CURRENCY_OUT.into(),
+			Helper::<T>::amount_out(),
+			Helper::<T>::worst_ratio(),
+			expires_at,
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_expired_order() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let (account_out, account_in) = Helper::<T>::setup();
+		let expires_at = frame_system::Pallet::<T>::block_number();
+
+		Pallet::<T>::place_order_with_expiry(
+			RawOrigin::Signed(account_out).into(),
+			CURRENCY_IN.into(),
+			CURRENCY_OUT.into(),
+			Helper::<T>::amount_out(),
+			OrderRatio::Market,
+			expires_at,
+		)?;
+		let order_id = OrderIdNonceStore::<T>::get();
+
+		frame_system::Pallet::<T>::set_block_number(expires_at + 1u32.into());
+
+		#[extrinsic_call]
+		cancel_expired_order(RawOrigin::Signed(account_in), order_id);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn place_order_with_constraints() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let (account_out, _) = Helper::<T>::setup();
+		Helper::<T>::feed_market();
+
+		#[extrinsic_call]
+		place_order_with_constraints(
+			RawOrigin::Signed(account_out.clone()),
+			CURRENCY_IN.into(),
+			CURRENCY_OUT.into(),
+			Helper::<T>::amount_out(),
+			Helper::<T>::worst_ratio(),
+			FillConstraints {
+				min_fulfillment_amount_out: Helper::<T>::amount_out(),
+				fill_or_kill: true,
+			},
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_fees() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		#[extrinsic_call]
+		set_fees(RawOrigin::Root, Helper::<T>::worst_fees());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn match_orders() -> Result<(), BenchmarkError> {
+		#[cfg(test)]
+		init_mocks();
+
+		let (account_out, account_in) = Helper::<T>::setup();
+		let order_a = Helper::<T>::place_order(&account_out);
+		let order_b = Helper::<T>::place_inverse_order(&account_in);
+
+		Pallet::<T>::set_fees(RawOrigin::Root.into(), Helper::<T>::worst_fees())?;
+		FeeExemptOrders::<T>::remove(order_a);
+
+		let keeper = account::<T::AccountId>("keeper", 0, 0);
+
+		#[extrinsic_call]
+		match_orders(RawOrigin::Signed(keeper), order_a, order_b);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_pair_order_limit() -> Result<(), BenchmarkError> {
+		#[extrinsic_call]
+		set_pair_order_limit(
+			RawOrigin::Root,
+			CURRENCY_OUT.into(),
+			CURRENCY_IN.into(),
+			Some(u32::MAX),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Runtime);
 }
@@ -31,6 +31,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 
 pub use pallet::*;
@@ -40,6 +41,8 @@ pub use weights::WeightInfo;
 pub mod pallet {
 	use cfg_primitives::conversion::convert_balance_decimals;
 	use cfg_traits::{
+		hooks::HookWeightStorage,
+		keeper::{KeeperAction, KeeperRewards},
 		swaps::{OrderInfo, OrderRatio, Swap, SwapInfo, TokenSwaps},
 		StatusNotificationHook, ValueProvider,
 	};
@@ -59,16 +62,20 @@ pub mod pallet {
 	use sp_runtime::{
 		traits::{
 			AtLeast32BitUnsigned, EnsureAdd, EnsureAddAssign, EnsureDiv, EnsureFixedPointNumber,
-			EnsureMul, EnsureSub, EnsureSubAssign, MaybeSerializeDeserialize, One, Zero,
+			EnsureMul, EnsureSub, EnsureSubAssign, MaybeSerializeDeserialize, One, Saturating,
+			Zero,
 		},
-		FixedPointNumber, FixedPointOperand, TokenError,
+		FixedPointNumber, FixedPointOperand, Permill, TokenError,
+	};
+	use sp_std::{
+		cmp::{min, Ordering},
+		vec::Vec,
 	};
-	use sp_std::cmp::{min, Ordering};
 
 	use super::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
 
 	pub type BalanceOf<T> =
 		<<T as Config>::Currency as AssetInspect<<T as frame_system::Config>::AccountId>>::Balance;
@@ -177,6 +184,25 @@ pub mod pallet {
 		/// The admin origin of this pallet
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Rewards paid to the account matching two crossing orders.
+		type KeeperRewards: KeeperRewards<AccountId = Self::AccountId, Action = KeeperAction>;
+
+		/// Account receiving the fees charged on fills, unless another
+		/// destination is configured in `Fees`.
+		#[pallet::constant]
+		type TreasuryAccount: Get<Self::AccountId>;
+
+		/// Maximum number of closed orders kept in the history of each
+		/// account. Once reached, the oldest entry is dropped.
+		#[pallet::constant]
+		type MaxOrderHistory: Get<u32>;
+
+		/// Maximum number of open orders an account can place through the
+		/// extrinsics of this pallet. Orders placed by other pallets through
+		/// `TokenSwaps` are counted but not limited.
+		#[pallet::constant]
+		type MaxOrdersPerUser: Get<u32>;
+
 		/// Type for pallet weights
 		type Weights: WeightInfo;
 	}
@@ -211,8 +237,111 @@ pub mod pallet {
 
 		/// Price given for this order,
 		pub ratio: OrderRatio<T::Ratio>,
+
+		/// Last block in which this order can be filled. After that, the order
+		/// can be cancelled by anyone, returning the held funds to the placing
+		/// account.
+		pub expires_at: Option<BlockNumberFor<T>>,
+	}
+
+	impl<T: Config> Order<T> {
+		/// Returns `true` if the order can no longer be filled at `now`.
+		pub fn is_expired(&self, now: BlockNumberFor<T>) -> bool {
+			self.expires_at.map_or(false, |expires_at| now > expires_at)
+		}
+	}
+
+	/// Restrictions on how an order can be filled, overriding the defaults.
+	#[derive(Clone, RuntimeDebug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	pub struct FillConstraints<Balance> {
+		/// Minimum amount of `currency_out` to fill at once, unless the
+		/// remaining amount of the order is lower.
+		pub min_fulfillment_amount_out: Balance,
+
+		/// If `true`, the order can only be filled completely in one fill.
+		pub fill_or_kill: bool,
+	}
+
+	/// Protocol fees charged when an order is filled.
+	#[derive(
+		Clone, RuntimeDebug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo, DefaultNoBound,
+	)]
+	pub struct FeeConfig<AccountId> {
+		/// Fee charged to the placing account on the received `currency_in`.
+		pub maker: Permill,
+
+		/// Fee charged to the fulfilling account on the received
+		/// `currency_out`.
+		pub taker: Permill,
+
+		/// Account receiving the fees. If `None`, the `TreasuryAccount` is
+		/// used.
+		pub destination: Option<AccountId>,
+	}
+
+	/// Amount available in the order book at a given ratio.
+	#[derive(Clone, RuntimeDebug, Encode, Decode, Eq, PartialEq, TypeInfo)]
+	pub struct PriceLevel<Ratio, Balance> {
+		/// Ratio of `currency_in` per `currency_out` the orders are filled at.
+		pub ratio: Ratio,
+
+		/// Total amount of `currency_out` available at this ratio.
+		pub amount_out: Balance,
+	}
+
+	/// Reason an order is no longer open.
+	#[derive(Clone, Copy, RuntimeDebug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	pub enum OrderClosure {
+		/// The whole amount of the order was swapped.
+		Filled,
+
+		/// The order was cancelled before being completely filled.
+		Cancelled,
+
+		/// The order expired before being completely filled.
+		Expired,
+	}
+
+	/// Summary of an order which is no longer open.
+	#[derive(Clone, RuntimeDebug, Encode, Decode, Eq, PartialEq, MaxEncodedLen, TypeInfo)]
+	pub struct ClosedOrder<OrderId, CurrencyId, BalanceIn, BalanceOut, Ratio, BlockNumber> {
+		/// Id of the order
+		pub order_id: OrderId,
+
+		/// Currency id received by the order
+		pub currency_in: CurrencyId,
+
+		/// Currency id given by the order
+		pub currency_out: CurrencyId,
+
+		/// Amount of `currency_out` offered by the order
+		pub amount_out_initial: BalanceOut,
+
+		/// Amount of `currency_out` swapped before the order was closed
+		pub amount_out_filled: BalanceOut,
+
+		/// Amount of `currency_in` received before the order was closed
+		pub amount_in_received: BalanceIn,
+
+		/// Price given for the order when it was closed
+		pub ratio: OrderRatio<Ratio>,
+
+		/// Reason the order was closed
+		pub closure: OrderClosure,
+
+		/// Block in which the order was closed
+		pub closed_at: BlockNumber,
 	}
 
+	pub type ClosedOrderOf<T> = ClosedOrder<
+		<T as Config>::OrderIdNonce,
+		<T as Config>::CurrencyId,
+		<T as Config>::BalanceIn,
+		<T as Config>::BalanceOut,
+		<T as Config>::Ratio,
+		BlockNumberFor<T>,
+	>;
+
 	/// Map of Orders to look up orders by their order id.
 	#[pallet::storage]
 	pub type Orders<T: Config> = StorageMap<
@@ -237,6 +366,35 @@ pub mod pallet {
 		ResultQuery<Error<T>::OrderNotFound>,
 	>;
 
+	/// Number of open orders of an account in `UserOrders`.
+	#[pallet::storage]
+	pub type UserOrderCount<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// Index of the open orders of a currency pair, keyed by
+	/// `(currency_out, currency_in)` and the order id.
+	#[pallet::storage]
+	pub type PairOrders<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		(T::CurrencyId, T::CurrencyId),
+		Twox64Concat,
+		T::OrderIdNonce,
+		(),
+		OptionQuery,
+	>;
+
+	/// Number of open orders of a currency pair in `PairOrders`.
+	#[pallet::storage]
+	pub type PairOrderCount<T: Config> =
+		StorageMap<_, Twox64Concat, (T::CurrencyId, T::CurrencyId), u32, ValueQuery>;
+
+	/// Maximum number of open orders of a currency pair, keyed by
+	/// `(currency_out, currency_in)`. Pairs without an entry are not limited.
+	/// Only applies to orders placed through the extrinsics of this pallet.
+	#[pallet::storage]
+	pub type PairOrderLimit<T: Config> =
+		StorageMap<_, Twox64Concat, (T::CurrencyId, T::CurrencyId), u32, OptionQuery>;
+
 	/// Stores OrderIdNonce for orders placed
 	/// Given that OrderIdNonce is to ensure that all orders have a unique ID,
 	/// we can use just one OrderIdNonce, which means that we only have one val
@@ -245,11 +403,65 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type OrderIdNonceStore<T: Config> = StorageValue<_, T::OrderIdNonce, ValueQuery>;
 
+	/// Index of orders with an expiry, keyed by the block after which they
+	/// expire. Used to clean up expired orders in `on_idle`.
+	#[pallet::storage]
+	pub type ExpiringOrders<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		Twox64Concat,
+		T::OrderIdNonce,
+		(),
+		OptionQuery,
+	>;
+
+	/// Next block of `ExpiringOrders` to be inspected by `on_idle`.
+	#[pallet::storage]
+	pub type ExpiryCursor<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// Weight consumed by the hooks of this pallet and the block it was
+	/// consumed in.
+	#[pallet::storage]
+	pub type LastHookWeight<T: Config> = StorageValue<_, (BlockNumberFor<T>, Weight), OptionQuery>;
+
 	/// Stores the market feeder id used to set with market conversion ratios
 	#[pallet::storage]
 	pub type MarketFeederId<T: Config> =
 		StorageValue<_, T::FeederId, ResultQuery<Error<T>::MarketFeederNotFound>>;
 
+	/// Fill constraints of orders placed with `place_order_with_constraints`.
+	#[pallet::storage]
+	pub type OrderFillConstraints<T: Config> =
+		StorageMap<_, Twox64Concat, T::OrderIdNonce, FillConstraints<T::BalanceOut>, OptionQuery>;
+
+	/// Current protocol fees of the order book.
+	#[pallet::storage]
+	pub type Fees<T: Config> = StorageValue<_, FeeConfig<T::AccountId>, ValueQuery>;
+
+	/// Orders which are not charged with fees when filled. These are the
+	/// orders placed by other pallets through `TokenSwaps`, i.e. the swaps of
+	/// foreign investments.
+	#[pallet::storage]
+	pub type FeeExemptOrders<T: Config> =
+		StorageMap<_, Twox64Concat, T::OrderIdNonce, (), OptionQuery>;
+
+	/// Lowest ratio accepted when filling an order placed with
+	/// `OrderRatio::MarketWithSlippage`.
+	#[pallet::storage]
+	pub type MarketRatioFloor<T: Config> =
+		StorageMap<_, Twox64Concat, T::OrderIdNonce, T::Ratio, OptionQuery>;
+
+	/// Most recent closed orders of each account, oldest first.
+	#[pallet::storage]
+	pub type OrderHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<ClosedOrderOf<T>, T::MaxOrderHistory>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -290,8 +502,44 @@ pub mod pallet {
 			currency_out: T::CurrencyId,
 			ratio: T::Ratio,
 		},
+		/// Event emitted when an order is placed with custom fill constraints.
+		OrderFillConstraintsSet {
+			order_id: T::OrderIdNonce,
+			constraints: FillConstraints<T::BalanceOut>,
+		},
+		/// Event emitted when the protocol fees are updated.
+		FeesUpdated { fees: FeeConfig<T::AccountId> },
+		/// Event emitted when fees are charged for filling an order.
+		FeesCharged {
+			order_id: T::OrderIdNonce,
+			destination: T::AccountId,
+			maker_fee: T::BalanceIn,
+			taker_fee: T::BalanceOut,
+		},
+		/// Event emitted when two crossing orders are matched.
+		OrdersMatched {
+			order_a: T::OrderIdNonce,
+			order_b: T::OrderIdNonce,
+			keeper: T::AccountId,
+			ratio: T::Ratio,
+			amount_out_a: T::BalanceOut,
+			amount_out_b: T::BalanceOut,
+		},
 		/// Event emitted when a valid trading pair is removed.
 		FeederChanged { feeder_id: T::FeederId },
+		/// Event emitted when an expired order is removed and its funds
+		/// returned to the placing account.
+		OrderExpired {
+			account: T::AccountId,
+			order_id: T::OrderIdNonce,
+		},
+		/// Event emitted when the open order limit of a currency pair is
+		/// updated. A `None` limit removes it.
+		PairOrderLimitSet {
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+			limit: Option<u32>,
+		},
 	}
 
 	#[pallet::error]
@@ -318,6 +566,38 @@ pub mod pallet {
 		MarketFeederNotFound,
 		/// Expected a market ratio for the given pair of currencies.
 		MarketRatioNotFound,
+		/// The expiry of an order can not be in the past.
+		InvalidExpiry,
+		/// The order has expired and can no longer be filled.
+		OrderExpired,
+		/// The order has not expired yet.
+		OrderNotExpired,
+		/// The max slippage of a market order can not be greater than one.
+		InvalidMaxSlippage,
+		/// The market ratio dropped below the max slippage of the order.
+		MaxSlippageExceeded,
+		/// A fill-or-kill order can only be filled completely.
+		FillOrKillNotFullyFilled,
+		/// The orders are not of inverse pairs or their ratios do not cross.
+		OrdersNotCrossing,
+		/// The account reached `MaxOrdersPerUser` open orders.
+		TooManyUserOrders,
+		/// The currency pair reached its limit of open orders.
+		TooManyPairOrders,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let record_weight = T::DbWeight::get().writes(1);
+			let weight =
+				Self::remove_expired_orders(now, remaining_weight.saturating_sub(record_weight));
+			if !LastHookWeight::<T>::record(now, weight) {
+				return weight;
+			}
+
+			weight.saturating_add(record_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -334,6 +614,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
 
+			Self::ensure_order_limits(&account_id, currency_out, currency_in)?;
+
 			Self::inner_place_order(
 				account_id,
 				currency_in,
@@ -341,6 +623,7 @@ pub mod pallet {
 				amount_out,
 				ratio,
 				Self::min_fulfillment_amount(currency_out)?,
+				None,
 			)?;
 
 			Ok(())
@@ -367,7 +650,7 @@ pub mod pallet {
 				order.clone(),
 				amount_out,
 				ratio,
-				Self::min_fulfillment_amount(order.currency_out)?,
+				Self::order_min_fulfillment_amount(&order)?,
 			)
 		}
 
@@ -415,6 +698,153 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Create an order with the default min fulfillment amount which can
+		/// only be filled until the `expires_at` block (inclusive).
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::Weights::place_order_with_expiry())]
+		pub fn place_order_with_expiry(
+			origin: OriginFor<T>,
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_out: T::BalanceOut,
+			ratio: OrderRatio<T::Ratio>,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+
+			Self::ensure_order_limits(&account_id, currency_out, currency_in)?;
+
+			Self::inner_place_order(
+				account_id,
+				currency_in,
+				currency_out,
+				amount_out,
+				ratio,
+				Self::min_fulfillment_amount(currency_out)?,
+				Some(expires_at),
+			)?;
+
+			Ok(())
+		}
+
+		/// Cancel an expired order, returning the held funds to the placing
+		/// account. Can be called by anyone.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::Weights::cancel_expired_order())]
+		pub fn cancel_expired_order(
+			origin: OriginFor<T>,
+			order_id: T::OrderIdNonce,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let order = Orders::<T>::get(order_id)?;
+
+			ensure!(
+				order.is_expired(frame_system::Pallet::<T>::block_number()),
+				Error::<T>::OrderNotExpired
+			);
+
+			Self::expire_order(order)
+		}
+
+		/// Create an order with its own fill constraints. The
+		/// `min_fulfillment_amount_out` can not be lower than the default
+		/// one. Fill-or-kill orders can only be filled completely at once.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::Weights::place_order_with_constraints())]
+		pub fn place_order_with_constraints(
+			origin: OriginFor<T>,
+			currency_in: T::CurrencyId,
+			currency_out: T::CurrencyId,
+			amount_out: T::BalanceOut,
+			ratio: OrderRatio<T::Ratio>,
+			constraints: FillConstraints<T::BalanceOut>,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+
+			ensure!(
+				constraints.min_fulfillment_amount_out
+					>= Self::min_fulfillment_amount(currency_out)?,
+				Error::<T>::BelowMinFulfillmentAmount
+			);
+
+			Self::ensure_order_limits(&account_id, currency_out, currency_in)?;
+
+			let order_id = Self::inner_place_order(
+				account_id,
+				currency_in,
+				currency_out,
+				amount_out,
+				ratio,
+				constraints.min_fulfillment_amount_out,
+				None,
+			)?;
+
+			OrderFillConstraints::<T>::insert(order_id, constraints.clone());
+
+			Self::deposit_event(Event::OrderFillConstraintsSet {
+				order_id,
+				constraints,
+			});
+
+			Ok(())
+		}
+
+		/// Set the protocol fees charged when filling orders.
+		/// The origin must be the admin origin.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::Weights::set_fees())]
+		pub fn set_fees(origin: OriginFor<T>, fees: FeeConfig<T::AccountId>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Fees::<T>::put(fees.clone());
+
+			Self::deposit_event(Event::<T>::FeesUpdated { fees });
+
+			Ok(())
+		}
+
+		/// Match two crossing orders of the inverse pair, settling them
+		/// against each other at the mid ratio. The caller is rewarded through
+		/// `KeeperRewards` unless it placed one of the orders.
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::Weights::match_orders())]
+		pub fn match_orders(
+			origin: OriginFor<T>,
+			order_a: T::OrderIdNonce,
+			order_b: T::OrderIdNonce,
+		) -> DispatchResult {
+			let keeper = ensure_signed(origin)?;
+			let order_a = <Orders<T>>::get(order_a)?;
+			let order_b = <Orders<T>>::get(order_b)?;
+
+			Self::match_crossing_orders(order_a, order_b, keeper)
+		}
+
+		/// Set the maximum number of open orders of a currency pair. A `None`
+		/// limit removes it. Orders already open above a new limit are kept.
+		/// The origin must be the admin origin.
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::Weights::set_pair_order_limit())]
+		pub fn set_pair_order_limit(
+			origin: OriginFor<T>,
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+			limit: Option<u32>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			PairOrderLimit::<T>::set((currency_out, currency_in), limit);
+
+			Self::deposit_event(Event::<T>::PairOrderLimitSet {
+				currency_out,
+				currency_in,
+				limit,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -425,7 +855,15 @@ pub mod pallet {
 			amount_out: T::BalanceOut,
 			ratio: OrderRatio<T::Ratio>,
 			min_fulfillment_amount_out: T::BalanceOut,
+			expires_at: Option<BlockNumberFor<T>>,
 		) -> Result<T::OrderIdNonce, DispatchError> {
+			if let Some(expires_at) = expires_at {
+				ensure!(
+					expires_at >= frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
+
 			let order_id = OrderIdNonceStore::<T>::try_mutate(|n| {
 				n.ensure_add_assign(One::one())?;
 				Ok::<_, DispatchError>(*n)
@@ -440,6 +878,8 @@ pub mod pallet {
 
 			T::Currency::hold(currency_out, &(), &account, amount_out.into())?;
 
+			Self::update_market_ratio_floor(order_id, currency_out, currency_in, &ratio)?;
+
 			let new_order = Order {
 				order_id,
 				placing_account: account.clone(),
@@ -449,10 +889,16 @@ pub mod pallet {
 				ratio,
 				amount_out_initial: amount_out,
 				amount_in: Zero::zero(),
+				expires_at,
 			};
 
 			Orders::<T>::insert(order_id, new_order.clone());
-			UserOrders::<T>::insert(&account, order_id, ());
+			Self::add_to_user_index(&account, order_id)?;
+			Self::add_to_pair_index(currency_out, currency_in, order_id)?;
+
+			if let Some(expires_at) = expires_at {
+				ExpiringOrders::<T>::insert(expires_at, order_id, ());
+			}
 
 			Self::deposit_event(Event::OrderCreated {
 				creator_account: account,
@@ -504,6 +950,13 @@ pub mod pallet {
 				Ordering::Equal => (),
 			}
 
+			Self::update_market_ratio_floor(
+				order.order_id,
+				order.currency_out,
+				order.currency_in,
+				&ratio,
+			)?;
+
 			order.amount_out = amount_out;
 			order.ratio = ratio;
 
@@ -525,58 +978,298 @@ pub mod pallet {
 			let order = <Orders<T>>::get(order_id)?;
 
 			Orders::<T>::remove(order.order_id);
-			UserOrders::<T>::remove(&order.placing_account, order.order_id);
+			Self::remove_from_user_index(&order.placing_account, order.order_id);
+			MarketRatioFloor::<T>::remove(order.order_id);
+			OrderFillConstraints::<T>::remove(order.order_id);
+			FeeExemptOrders::<T>::remove(order.order_id);
+			Self::remove_from_pair_index(order.currency_out, order.currency_in, order.order_id);
+
+			if let Some(expires_at) = order.expires_at {
+				ExpiringOrders::<T>::remove(expires_at, order.order_id);
+			}
 
 			Ok(())
 		}
 
-		fn fulfill_order_with_amount(
-			order: Order<T>,
-			amount_out: T::BalanceOut,
-			fulfilling_account: T::AccountId,
-		) -> DispatchResult {
-			let min_fulfillment_amount_out = min(
-				order.amount_out,
-				Self::min_fulfillment_amount(order.currency_out)?,
-			);
+		/// Adds an order which is no longer open to the history of its placing
+		/// account, dropping the oldest entry if the history is full.
+		fn record_closed_order(order: &Order<T>, closure: OrderClosure) {
+			let closed_order = ClosedOrder {
+				order_id: order.order_id,
+				currency_in: order.currency_in,
+				currency_out: order.currency_out,
+				amount_out_initial: order.amount_out_initial,
+				amount_out_filled: order.amount_out_initial.saturating_sub(order.amount_out),
+				amount_in_received: order.amount_in,
+				ratio: order.ratio,
+				closure,
+				closed_at: frame_system::Pallet::<T>::block_number(),
+			};
+
+			OrderHistory::<T>::mutate(&order.placing_account, |history| {
+				if history.is_full() && !history.is_empty() {
+					history.remove(0);
+				}
 
+				// NOTE: Only fails if `MaxOrderHistory` is zero, in which case no
+				// history is kept.
+				let _ = history.try_push(closed_order);
+			});
+		}
+
+		/// Closed orders of the given account, oldest first.
+		pub fn order_history(account: &T::AccountId) -> Vec<ClosedOrderOf<T>> {
+			OrderHistory::<T>::get(account).into_inner()
+		}
+
+		/// Ensures a new order of the account for the given currency pair
+		/// stays within `MaxOrdersPerUser` and the limit of the pair.
+		fn ensure_order_limits(
+			account: &T::AccountId,
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+		) -> DispatchResult {
 			ensure!(
-				amount_out >= min_fulfillment_amount_out,
-				Error::<T>::BelowMinFulfillmentAmount,
+				UserOrderCount::<T>::get(account) < T::MaxOrdersPerUser::get(),
+				Error::<T>::TooManyUserOrders
 			);
 
-			let ratio = match order.ratio {
-				OrderRatio::Market => Self::market_ratio(order.currency_out, order.currency_in)?,
-				OrderRatio::Custom(ratio) => ratio,
-			};
+			let pair = (currency_out, currency_in);
+			if let Some(limit) = PairOrderLimit::<T>::get(pair) {
+				ensure!(
+					PairOrderCount::<T>::get(pair) < limit,
+					Error::<T>::TooManyPairOrders
+				);
+			}
 
-			let amount_in =
-				Self::convert_with_ratio(order.currency_out, order.currency_in, ratio, amount_out)?;
+			Ok(())
+		}
 
-			let remaining_amount_out = order
-				.amount_out
-				.checked_sub(&amount_out)
-				.ok_or(Error::<T>::FulfillAmountTooLarge)?;
+		fn add_to_user_index(account: &T::AccountId, order_id: T::OrderIdNonce) -> DispatchResult {
+			UserOrderCount::<T>::try_mutate(account, |count| count.ensure_add_assign(1))?;
+			UserOrders::<T>::insert(account, order_id, ());
 
-			let partial_fulfillment = !remaining_amount_out.is_zero();
-			if partial_fulfillment {
-				let mut updated_order = order.clone();
-				updated_order.amount_out = remaining_amount_out;
-				updated_order.amount_in = order.amount_in.ensure_add(amount_in)?;
+			Ok(())
+		}
 
-				Orders::<T>::insert(updated_order.order_id, updated_order.clone());
-			} else {
-				Self::remove_order(order.order_id)?;
+		fn remove_from_user_index(account: &T::AccountId, order_id: T::OrderIdNonce) {
+			if UserOrders::<T>::take(account, order_id).is_ok() {
+				UserOrderCount::<T>::mutate_exists(account, |count| {
+					*count = count
+						.map(|count| count.saturating_sub(1))
+						.filter(|count| *count > 0);
+				});
 			}
+		}
+
+		fn add_to_pair_index(
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+			order_id: T::OrderIdNonce,
+		) -> DispatchResult {
+			let pair = (currency_out, currency_in);
+			PairOrderCount::<T>::try_mutate(pair, |count| count.ensure_add_assign(1))?;
+			PairOrders::<T>::insert(pair, order_id, ());
+
+			Ok(())
+		}
+
+		fn remove_from_pair_index(
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+			order_id: T::OrderIdNonce,
+		) {
+			let pair = (currency_out, currency_in);
+			if PairOrders::<T>::take(pair, order_id).is_some() {
+				PairOrderCount::<T>::mutate_exists(pair, |count| {
+					*count = count
+						.map(|count| count.saturating_sub(1))
+						.filter(|count| *count > 0);
+				});
+			}
+		}
+
+		/// Ids of the open orders of the given currency pair.
+		pub fn pair_orders(
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+		) -> impl Iterator<Item = T::OrderIdNonce> {
+			PairOrders::<T>::iter_key_prefix((currency_out, currency_in))
+		}
+
+		/// Orders of the given currency pair that can be filled right now,
+		/// sorted by the ratio they are filled at, lowest first.
+		fn fillable_orders(
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+		) -> Vec<(T::Ratio, Order<T>)> {
+			let now = frame_system::Pallet::<T>::block_number();
+
+			let mut orders = Self::pair_orders(currency_out, currency_in)
+				.filter_map(|order_id| Orders::<T>::get(order_id).ok())
+				.filter(|order| !order.is_expired(now))
+				.filter_map(|order| Some((Self::fill_ratio(&order).ok()?, order)))
+				.collect::<Vec<_>>();
 
+			orders.sort_by(|(a, _), (b, _)| a.cmp(b));
+			orders
+		}
+
+		/// Amounts of `currency_out` available to buy with `currency_in`,
+		/// aggregated by ratio and sorted from the best ratio for the buyer.
+		/// At most `levels` ratios are returned.
+		pub fn depth(
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+			levels: u32,
+		) -> Vec<PriceLevel<T::Ratio, T::BalanceOut>> {
+			let mut depth: Vec<PriceLevel<T::Ratio, T::BalanceOut>> = Vec::new();
+
+			for (ratio, order) in Self::fillable_orders(currency_out, currency_in) {
+				match depth.last_mut() {
+					Some(level) if level.ratio == ratio => {
+						level.amount_out = level.amount_out.saturating_add(order.amount_out);
+					}
+					_ if depth.len() >= levels as usize => break,
+					_ => depth.push(PriceLevel {
+						ratio,
+						amount_out: order.amount_out,
+					}),
+				}
+			}
+
+			depth
+		}
+
+		/// Best ratio at which `currency_out` can be bought with
+		/// `currency_in`, with the amount available at it.
+		pub fn best_ask(
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+		) -> Option<PriceLevel<T::Ratio, T::BalanceOut>> {
+			Self::depth(currency_out, currency_in, 1).pop()
+		}
+
+		/// Amount of `currency_in` needed to buy `amount_out` of
+		/// `currency_out` by filling the best orders first. Returns `None` if
+		/// there is not enough liquidity. Fill constraints and fees are not
+		/// taken into account.
+		pub fn estimate_fill(
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+			amount_out: T::BalanceOut,
+		) -> Option<T::BalanceIn> {
+			let mut remaining = amount_out;
+			let mut amount_in = T::BalanceIn::zero();
+
+			for (ratio, order) in Self::fillable_orders(currency_out, currency_in) {
+				if remaining.is_zero() {
+					break;
+				}
+
+				let fill = min(remaining, order.amount_out);
+				let fill_in =
+					Self::convert_with_ratio(currency_out, currency_in, ratio, fill).ok()?;
+
+				amount_in = amount_in.ensure_add(fill_in).ok()?;
+				remaining = remaining.saturating_sub(fill);
+			}
+
+			remaining.is_zero().then_some(amount_in)
+		}
+
+		/// Release the held funds of an expired order and remove it.
+		fn expire_order(order: Order<T>) -> DispatchResult {
 			T::Currency::release(
 				order.currency_out,
 				&(),
 				&order.placing_account,
-				amount_out.into(),
+				order.amount_out.into(),
 				Precision::Exact,
 			)?;
 
+			Self::remove_order(order.order_id)?;
+			Self::record_closed_order(&order, OrderClosure::Expired);
+
+			Self::deposit_event(Event::OrderExpired {
+				account: order.placing_account,
+				order_id: order.order_id,
+			});
+
+			Ok(())
+		}
+
+		/// Weight consumed by the hooks of this pallet in the current block.
+		pub fn hook_weight() -> Weight {
+			LastHookWeight::<T>::consumed_in(frame_system::Pallet::<T>::block_number())
+		}
+
+		/// Removes orders which expired before `now`, as long as the
+		/// `remaining_weight` allows it. Returns the consumed weight.
+		pub(crate) fn remove_expired_orders(
+			now: BlockNumberFor<T>,
+			remaining_weight: Weight,
+		) -> Weight {
+			let db = T::DbWeight::get();
+			let mut weight = db.reads_writes(1, 1);
+			if remaining_weight.any_lt(weight) {
+				return Weight::zero();
+			}
+
+			let mut cursor = ExpiryCursor::<T>::get().unwrap_or(now);
+
+			while cursor < now {
+				let next = ExpiringOrders::<T>::iter_key_prefix(cursor).next();
+
+				let step = match next {
+					Some(_) => T::Weights::cancel_expired_order().saturating_add(db.reads(1)),
+					None => db.reads(1),
+				};
+
+				if remaining_weight.any_lt(weight.saturating_add(step)) {
+					break;
+				}
+				weight.saturating_accrue(step);
+
+				match next {
+					Some(order_id) => {
+						let expired = Orders::<T>::get(order_id).and_then(Self::expire_order);
+
+						if expired.is_err() {
+							// Drop the entry from the index so the cursor can make progress.
+							// If the order still exists, it can be cancelled by its owner.
+							ExpiringOrders::<T>::remove(cursor, order_id);
+						}
+					}
+					None => cursor.saturating_inc(),
+				}
+			}
+
+			ExpiryCursor::<T>::put(cursor);
+
+			weight
+		}
+
+		fn fulfill_order_with_amount(
+			order: Order<T>,
+			amount_out: T::BalanceOut,
+			fulfilling_account: T::AccountId,
+		) -> DispatchResult {
+			ensure!(
+				!order.is_expired(frame_system::Pallet::<T>::block_number()),
+				Error::<T>::OrderExpired
+			);
+
+			Self::ensure_fill_amount(&order, amount_out)?;
+
+			let ratio = Self::fill_ratio(&order)?;
+
+			let amount_in =
+				Self::convert_with_ratio(order.currency_out, order.currency_in, ratio, amount_out)?;
+
+			let remaining_amount_out = Self::consume_order(&order, amount_out, amount_in)?;
+			let partial_fulfillment = !remaining_amount_out.is_zero();
+
 			if T::Currency::balance(order.currency_out, &order.placing_account) < amount_out.into()
 			{
 				Err(DispatchError::Token(TokenError::FundsUnavailable))?
@@ -601,6 +1294,8 @@ pub mod pallet {
 				Preservation::Expendable,
 			)?;
 
+			Self::charge_fees(&order, &fulfilling_account, amount_in, amount_out)?;
+
 			T::FulfilledOrderHook::notify_status_change(
 				order.order_id,
 				SwapInfo {
@@ -629,6 +1324,292 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Ensures the order accepts being filled with `amount_out`, taking
+		/// its fill constraints into account.
+		fn ensure_fill_amount(order: &Order<T>, amount_out: T::BalanceOut) -> DispatchResult {
+			let min_fulfillment_amount_out = match OrderFillConstraints::<T>::get(order.order_id) {
+				Some(FillConstraints {
+					fill_or_kill: true, ..
+				}) => {
+					ensure!(
+						amount_out >= order.amount_out,
+						Error::<T>::FillOrKillNotFullyFilled
+					);
+					order.amount_out
+				}
+				Some(constraints) => min(order.amount_out, constraints.min_fulfillment_amount_out),
+				None => min(
+					order.amount_out,
+					Self::min_fulfillment_amount(order.currency_out)?,
+				),
+			};
+
+			ensure!(
+				amount_out >= min_fulfillment_amount_out,
+				Error::<T>::BelowMinFulfillmentAmount,
+			);
+
+			Ok(())
+		}
+
+		/// Ratio the order is filled at right now.
+		fn fill_ratio(order: &Order<T>) -> Result<T::Ratio, DispatchError> {
+			Ok(match order.ratio {
+				OrderRatio::Market => Self::market_ratio(order.currency_out, order.currency_in)?,
+				OrderRatio::MarketWithSlippage { .. } => {
+					let ratio = Self::market_ratio(order.currency_out, order.currency_in)?;
+
+					if let Some(floor) = MarketRatioFloor::<T>::get(order.order_id) {
+						ensure!(ratio >= floor, Error::<T>::MaxSlippageExceeded);
+					}
+
+					ratio
+				}
+				OrderRatio::Custom(ratio) => ratio,
+			})
+		}
+
+		/// Updates the order after `amount_out` of it has been swapped for
+		/// `amount_in`, removing it once completely filled, and releases the
+		/// swapped amount from hold. Returns the remaining `amount_out`.
+		fn consume_order(
+			order: &Order<T>,
+			amount_out: T::BalanceOut,
+			amount_in: T::BalanceIn,
+		) -> Result<T::BalanceOut, DispatchError> {
+			let remaining_amount_out = order
+				.amount_out
+				.checked_sub(&amount_out)
+				.ok_or(Error::<T>::FulfillAmountTooLarge)?;
+
+			let mut updated_order = order.clone();
+			updated_order.amount_out = remaining_amount_out;
+			updated_order.amount_in = order.amount_in.ensure_add(amount_in)?;
+
+			if remaining_amount_out.is_zero() {
+				Self::remove_order(order.order_id)?;
+				Self::record_closed_order(&updated_order, OrderClosure::Filled);
+			} else {
+				Orders::<T>::insert(updated_order.order_id, updated_order);
+			}
+
+			T::Currency::release(
+				order.currency_out,
+				&(),
+				&order.placing_account,
+				amount_out.into(),
+				Precision::Exact,
+			)?;
+
+			Ok(remaining_amount_out)
+		}
+
+		/// Settles two crossing orders of the inverse pair against each other
+		/// at the mid ratio of both. The largest amount accepted by both
+		/// orders is swapped.
+		fn match_crossing_orders(
+			order_a: Order<T>,
+			order_b: Order<T>,
+			keeper: T::AccountId,
+		) -> DispatchResult {
+			ensure!(
+				order_a.order_id != order_b.order_id
+					&& order_a.currency_out == order_b.currency_in
+					&& order_a.currency_in == order_b.currency_out,
+				Error::<T>::OrdersNotCrossing
+			);
+
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				!order_a.is_expired(now) && !order_b.is_expired(now),
+				Error::<T>::OrderExpired
+			);
+
+			// Both ratios are expressed as `currency_in` per `currency_out` of
+			// `order_a`.
+			let ratio_a = Self::fill_ratio(&order_a)?;
+			let ratio_b = T::Ratio::one().ensure_div(Self::fill_ratio(&order_b)?)?;
+			ensure!(ratio_a <= ratio_b, Error::<T>::OrdersNotCrossing);
+
+			let ratio = ratio_a
+				.ensure_add(ratio_b)?
+				.ensure_div(T::Ratio::saturating_from_integer(2))?;
+
+			let max_amount_out_a: BalanceOf<T> = Self::convert_with_ratio(
+				order_b.currency_out,
+				order_b.currency_in,
+				T::Ratio::one().ensure_div(ratio)?,
+				order_b.amount_out,
+			)?
+			.into();
+			let amount_out_a = min(order_a.amount_out, max_amount_out_a.into());
+
+			let amount_in_a: BalanceOf<T> = Self::convert_with_ratio(
+				order_a.currency_out,
+				order_a.currency_in,
+				ratio,
+				amount_out_a,
+			)?
+			.into();
+			let amount_out_b = min(order_b.amount_out, amount_in_a.into());
+
+			// Each order receives the amount the other one gives.
+			let received_a: BalanceOf<T> = amount_out_b.into();
+			let received_b: BalanceOf<T> = amount_out_a.into();
+			let amount_in_a = T::BalanceIn::from(received_a);
+			let amount_in_b = T::BalanceIn::from(received_b);
+
+			Self::ensure_fill_amount(&order_a, amount_out_a)?;
+			Self::ensure_fill_amount(&order_b, amount_out_b)?;
+
+			let remaining_a = Self::consume_order(&order_a, amount_out_a, amount_in_a)?;
+			let remaining_b = Self::consume_order(&order_b, amount_out_b, amount_in_b)?;
+
+			T::Currency::transfer(
+				order_a.currency_out,
+				&order_a.placing_account,
+				&order_b.placing_account,
+				amount_out_a.into(),
+				Preservation::Expendable,
+			)?;
+			T::Currency::transfer(
+				order_b.currency_out,
+				&order_b.placing_account,
+				&order_a.placing_account,
+				amount_out_b.into(),
+				Preservation::Expendable,
+			)?;
+
+			// Both orders were resting in the book, so they are only charged as
+			// makers.
+			Self::charge_fees(
+				&order_a,
+				&order_b.placing_account,
+				amount_in_a,
+				Zero::zero(),
+			)?;
+			Self::charge_fees(
+				&order_b,
+				&order_a.placing_account,
+				amount_in_b,
+				Zero::zero(),
+			)?;
+
+			// Crossing own orders is not rewarded, otherwise rewards could be
+			// farmed without providing liquidity to anybody.
+			if keeper != order_a.placing_account && keeper != order_b.placing_account {
+				T::KeeperRewards::reward(&keeper, KeeperAction::MatchOrders);
+			}
+
+			for (order, counterparty, remaining, amount_in, amount_out, ratio) in [
+				(
+					&order_a,
+					&order_b.placing_account,
+					remaining_a,
+					amount_in_a,
+					amount_out_a,
+					ratio,
+				),
+				(
+					&order_b,
+					&order_a.placing_account,
+					remaining_b,
+					amount_in_b,
+					amount_out_b,
+					T::Ratio::one().ensure_div(ratio)?,
+				),
+			] {
+				T::FulfilledOrderHook::notify_status_change(
+					order.order_id,
+					SwapInfo {
+						remaining: Swap {
+							amount_out: remaining,
+							currency_in: order.currency_in,
+							currency_out: order.currency_out,
+						},
+						swapped_in: amount_in,
+						swapped_out: amount_out,
+						ratio,
+					},
+				)?;
+
+				Self::deposit_event(Event::OrderFulfillment {
+					order_id: order.order_id,
+					placing_account: order.placing_account.clone(),
+					fulfilling_account: counterparty.clone(),
+					partial_fulfillment: !remaining.is_zero(),
+					currency_in: order.currency_in,
+					currency_out: order.currency_out,
+					fulfillment_amount: amount_out,
+					ratio,
+				});
+			}
+
+			Self::deposit_event(Event::OrdersMatched {
+				order_a: order_a.order_id,
+				order_b: order_b.order_id,
+				keeper,
+				ratio,
+				amount_out_a,
+				amount_out_b,
+			});
+
+			Ok(())
+		}
+
+		/// Charges the maker fee on the `amount_in` received by the placing
+		/// account and the taker fee on the `amount_out` received by the
+		/// fulfilling account. Fee exempt orders are not charged.
+		fn charge_fees(
+			order: &Order<T>,
+			fulfilling_account: &T::AccountId,
+			amount_in: T::BalanceIn,
+			amount_out: T::BalanceOut,
+		) -> DispatchResult {
+			if FeeExemptOrders::<T>::contains_key(order.order_id) {
+				return Ok(());
+			}
+
+			let fees = Fees::<T>::get();
+			let maker_fee = fees.maker.mul_floor(amount_in);
+			let taker_fee = fees.taker.mul_floor(amount_out);
+
+			if maker_fee.is_zero() && taker_fee.is_zero() {
+				return Ok(());
+			}
+
+			let destination = fees.destination.unwrap_or_else(T::TreasuryAccount::get);
+
+			if !maker_fee.is_zero() {
+				T::Currency::transfer(
+					order.currency_in,
+					&order.placing_account,
+					&destination,
+					maker_fee.into(),
+					Preservation::Expendable,
+				)?;
+			}
+
+			if !taker_fee.is_zero() {
+				T::Currency::transfer(
+					order.currency_out,
+					fulfilling_account,
+					&destination,
+					taker_fee.into(),
+					Preservation::Expendable,
+				)?;
+			}
+
+			Self::deposit_event(Event::FeesCharged {
+				order_id: order.order_id,
+				destination,
+				maker_fee,
+				taker_fee,
+			});
+
+			Ok(())
+		}
+
 		pub fn market_ratio(
 			currency_from: T::CurrencyId,
 			currency_to: T::CurrencyId,
@@ -639,6 +1620,35 @@ pub mod pallet {
 				.ok_or(Error::<T>::MarketRatioNotFound.into())
 		}
 
+		/// Stores the lowest ratio an order with a max slippage can be filled
+		/// at, computed from the current market ratio. Any previous floor is
+		/// removed if the order uses another kind of ratio.
+		fn update_market_ratio_floor(
+			order_id: T::OrderIdNonce,
+			currency_out: T::CurrencyId,
+			currency_in: T::CurrencyId,
+			ratio: &OrderRatio<T::Ratio>,
+		) -> DispatchResult {
+			match ratio {
+				OrderRatio::MarketWithSlippage { max_slippage } => {
+					ensure!(
+						*max_slippage <= T::Ratio::one(),
+						Error::<T>::InvalidMaxSlippage
+					);
+
+					let market = Self::market_ratio(currency_out, currency_in)?;
+					let floor = market.ensure_mul(T::Ratio::one().saturating_sub(*max_slippage))?;
+
+					MarketRatioFloor::<T>::insert(order_id, floor);
+				}
+				OrderRatio::Market | OrderRatio::Custom(_) => {
+					MarketRatioFloor::<T>::remove(order_id);
+				}
+			}
+
+			Ok(())
+		}
+
 		/// `ratio` is the value you multiply `amount_from` to obtain
 		/// `amount_to`
 		pub fn convert_with_ratio(
@@ -659,6 +1669,15 @@ pub mod pallet {
 			Ok(convert_balance_decimals(from_decimals, to_decimals, amount_in.into())?.into())
 		}
 
+		/// Min fulfillment amount of an order, taking its fill constraints
+		/// into account.
+		fn order_min_fulfillment_amount(order: &Order<T>) -> Result<T::BalanceOut, DispatchError> {
+			match OrderFillConstraints::<T>::get(order.order_id) {
+				Some(constraints) => Ok(constraints.min_fulfillment_amount_out),
+				None => Self::min_fulfillment_amount(order.currency_out),
+			}
+		}
+
 		pub fn min_fulfillment_amount(
 			currency: T::CurrencyId,
 		) -> Result<T::BalanceOut, DispatchError> {
@@ -689,14 +1708,19 @@ pub mod pallet {
 			amount_out: T::BalanceOut,
 			ratio: OrderRatio<T::Ratio>,
 		) -> Result<Self::OrderId, DispatchError> {
-			Self::inner_place_order(
+			let order_id = Self::inner_place_order(
 				account,
 				currency_in,
 				currency_out,
 				amount_out,
 				ratio,
 				T::BalanceOut::zero(),
-			)
+				None,
+			)?;
+
+			FeeExemptOrders::<T>::insert(order_id, ());
+
+			Ok(order_id)
 		}
 
 		fn cancel_order(order: Self::OrderId) -> DispatchResult {
@@ -712,6 +1736,8 @@ pub mod pallet {
 			)?;
 
 			Self::remove_order(order.order_id)?;
+			Self::record_closed_order(&order, OrderClosure::Cancelled);
+
 			Self::deposit_event(Event::OrderCancelled {
 				account: account_id,
 				order_id: order.order_id,
@@ -793,4 +1819,54 @@ pub mod pallet {
 			T::RatioProvider::set(&feeder, &pair, value);
 		}
 	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl<T: Config> cfg_traits::benchmarking::SwapsBenchmarkHelper for Pallet<T>
+	where
+		T::FeederId: From<u32>,
+		T::AssetRegistry: asset_registry::Mutate,
+		<T::AssetRegistry as asset_registry::Inspect>::CustomMetadata: Default,
+	{
+		type AccountId = T::AccountId;
+		type Balance = BalanceOf<T>;
+		type CurrencyId = T::CurrencyId;
+
+		fn bench_setup_pair(currency_a: T::CurrencyId, currency_b: T::CurrencyId) {
+			// Both currencies share the decimals of the one already registered,
+			// so a ratio of one is also the market ratio between them.
+			let decimals = T::AssetRegistry::metadata(&currency_a)
+				.or_else(|| T::AssetRegistry::metadata(&currency_b))
+				.map(|metadata| metadata.decimals)
+				.unwrap_or(6);
+
+			for currency in [currency_a, currency_b] {
+				if T::AssetRegistry::metadata(&currency).is_none() {
+					<T::AssetRegistry as asset_registry::Mutate>::register_asset(
+						Some(currency),
+						cfg_traits::AssetMetadataOf::<T::AssetRegistry> {
+							decimals,
+							name: Default::default(),
+							symbol: Default::default(),
+							existential_deposit: Zero::zero(),
+							location: None,
+							additional: Default::default(),
+						},
+					)
+					.unwrap();
+				}
+			}
+
+			if MarketFeederId::<T>::get().is_err() {
+				MarketFeederId::<T>::put(T::FeederId::from(0));
+			}
+
+			let feeder = MarketFeederId::<T>::get().unwrap();
+			T::RatioProvider::set(&feeder, &(currency_a, currency_b), One::one());
+			T::RatioProvider::set(&feeder, &(currency_b, currency_a), One::one());
+		}
+
+		fn bench_mint(currency: T::CurrencyId, account: &T::AccountId, amount: BalanceOf<T>) {
+			T::Currency::mint_into(currency, account, amount).unwrap();
+		}
+	}
 }
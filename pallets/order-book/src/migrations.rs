@@ -0,0 +1,271 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+pub mod v2 {
+	use cfg_traits::swaps::OrderRatio;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Get, OnRuntimeUpgrade},
+	};
+	use sp_runtime::traits::Saturating;
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::{SaturatedConversion, TryRuntimeError};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use crate::{Config, Order, Orders};
+
+	const LOG_PREFIX: &str = "OrderBookV2";
+
+	#[derive(Decode)]
+	struct OrderV1<T: Config> {
+		order_id: T::OrderIdNonce,
+		placing_account: T::AccountId,
+		currency_in: T::CurrencyId,
+		currency_out: T::CurrencyId,
+		amount_in: T::BalanceIn,
+		amount_out: T::BalanceOut,
+		amount_out_initial: T::BalanceOut,
+		ratio: OrderRatio<T::Ratio>,
+	}
+
+	/// Adds the `expires_at` field to all stored orders. Existing orders never
+	/// expire.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+
+			Orders::<T>::translate_values::<OrderV1<T>, _>(|old| {
+				count.saturating_inc();
+
+				Some(Order {
+					order_id: old.order_id,
+					placing_account: old.placing_account,
+					currency_in: old.currency_in,
+					currency_out: old.currency_out,
+					amount_in: old.amount_in,
+					amount_out: old.amount_out,
+					amount_out_initial: old.amount_out_initial,
+					ratio: old.ratio,
+					expires_at: None,
+				})
+			});
+
+			log::info!("{LOG_PREFIX}: Migrated {count} orders!");
+
+			T::DbWeight::get().reads_writes(count, count)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let count: u64 = Orders::<T>::iter_keys().count().saturated_into();
+
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let pre_count: u64 = Decode::decode(&mut pre_state.as_slice())
+				.expect("pre_upgrade provides a valid state; qed");
+			let post_count: u64 = Orders::<T>::iter_values().count().saturated_into();
+
+			assert_eq!(
+				pre_count, post_count,
+				"{LOG_PREFIX}: Mismatching number of orders after migration!"
+			);
+
+			log::info!("{LOG_PREFIX}: Post checks done!");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v3 {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Get, OnRuntimeUpgrade},
+	};
+	use sp_runtime::traits::{EnsureAddAssign, Saturating};
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::{SaturatedConversion, TryRuntimeError};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use crate::{Config, Orders, PairOrderCount, PairOrders};
+
+	const LOG_PREFIX: &str = "OrderBookV3";
+
+	/// Builds the `PairOrders` index and its counters from the stored orders.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut count: u64 = 0;
+
+			for order in Orders::<T>::iter_values() {
+				count.saturating_inc();
+
+				let pair = (order.currency_out, order.currency_in);
+				PairOrders::<T>::insert(pair, order.order_id, ());
+				if let Err(e) = PairOrderCount::<T>::try_mutate(pair, |n| n.ensure_add_assign(1)) {
+					log::error!(
+						"{LOG_PREFIX}: Failed to count order {:?}: {e:?}",
+						order.order_id
+					);
+				}
+			}
+
+			log::info!("{LOG_PREFIX}: Indexed {count} orders!");
+
+			T::DbWeight::get().reads_writes(count.saturating_mul(2), count.saturating_mul(2))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let count: u64 = Orders::<T>::iter_keys().count().saturated_into();
+
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let pre_count: u64 = Decode::decode(&mut pre_state.as_slice())
+				.expect("pre_upgrade provides a valid state; qed");
+			let indexed: u64 = PairOrders::<T>::iter_keys().count().saturated_into();
+			let counted: u64 = PairOrderCount::<T>::iter_values().map(u64::from).sum();
+
+			assert_eq!(
+				pre_count, indexed,
+				"{LOG_PREFIX}: Mismatching number of indexed orders after migration!"
+			);
+			assert_eq!(
+				pre_count, counted,
+				"{LOG_PREFIX}: Mismatching number of counted orders after migration!"
+			);
+
+			log::info!("{LOG_PREFIX}: Post checks done!");
+
+			Ok(())
+		}
+	}
+}
+
+pub mod v4 {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Get, OnRuntimeUpgrade},
+	};
+	use sp_runtime::traits::{EnsureAddAssign, Saturating};
+	#[cfg(feature = "try-runtime")]
+	use sp_runtime::{SaturatedConversion, TryRuntimeError};
+	#[cfg(feature = "try-runtime")]
+	use sp_std::vec::Vec;
+
+	use crate::{Config, Orders, PairOrderCount, PairOrders, UserOrderCount, UserOrders};
+
+	const LOG_PREFIX: &str = "OrderBookV4";
+
+	/// Prunes the `UserOrders` and `PairOrders` entries of orders which no
+	/// longer exist, and rebuilds `UserOrderCount` and `PairOrderCount` from
+	/// the remaining entries.
+	pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for Migration<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+			let mut pruned: u64 = 0;
+
+			let user_entries = UserOrders::<T>::iter_keys().collect::<sp_std::vec::Vec<_>>();
+			for (account, order_id) in user_entries {
+				reads.saturating_accrue(2);
+
+				if Orders::<T>::contains_key(order_id) {
+					if let Err(e) =
+						UserOrderCount::<T>::try_mutate(&account, |n| n.ensure_add_assign(1))
+					{
+						log::error!("{LOG_PREFIX}: Failed to count order {order_id:?}: {e:?}");
+					}
+				} else {
+					UserOrders::<T>::remove(&account, order_id);
+					pruned.saturating_inc();
+				}
+
+				writes.saturating_inc();
+			}
+
+			let _ = PairOrderCount::<T>::clear(u32::MAX, None);
+			let pair_entries = PairOrders::<T>::iter_keys().collect::<sp_std::vec::Vec<_>>();
+			for (pair, order_id) in pair_entries {
+				reads.saturating_accrue(2);
+
+				if Orders::<T>::contains_key(order_id) {
+					if let Err(e) =
+						PairOrderCount::<T>::try_mutate(pair, |n| n.ensure_add_assign(1))
+					{
+						log::error!("{LOG_PREFIX}: Failed to count order {order_id:?}: {e:?}");
+					}
+				} else {
+					PairOrders::<T>::remove(pair, order_id);
+					pruned.saturating_inc();
+				}
+
+				writes.saturating_inc();
+			}
+
+			log::info!("{LOG_PREFIX}: Pruned {pruned} index entries!");
+
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let count: u64 = Orders::<T>::iter_keys().count().saturated_into();
+
+			Ok(count.encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(pre_state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let pre_count: u64 = Decode::decode(&mut pre_state.as_slice())
+				.expect("pre_upgrade provides a valid state; qed");
+			let user_indexed: u64 = UserOrders::<T>::iter_keys().count().saturated_into();
+			let user_counted: u64 = UserOrderCount::<T>::iter_values().map(u64::from).sum();
+			let pair_indexed: u64 = PairOrders::<T>::iter_keys().count().saturated_into();
+			let pair_counted: u64 = PairOrderCount::<T>::iter_values().map(u64::from).sum();
+
+			assert_eq!(
+				pre_count, user_indexed,
+				"{LOG_PREFIX}: Mismatching number of user indexed orders after migration!"
+			);
+			assert_eq!(
+				pre_count, user_counted,
+				"{LOG_PREFIX}: Mismatching number of user counted orders after migration!"
+			);
+			assert_eq!(
+				pre_count, pair_indexed,
+				"{LOG_PREFIX}: Mismatching number of pair indexed orders after migration!"
+			);
+			assert_eq!(
+				pre_count, pair_counted,
+				"{LOG_PREFIX}: Mismatching number of pair counted orders after migration!"
+			);
+
+			log::info!("{LOG_PREFIX}: Post checks done!");
+
+			Ok(())
+		}
+	}
+}
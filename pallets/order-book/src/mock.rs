@@ -10,7 +10,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::{swaps::SwapInfo, AssetMetadataOf};
+use cfg_traits::{keeper::KeeperAction, swaps::SwapInfo, AssetMetadataOf};
 use cfg_types::tokens::CurrencyId;
 use frame_support::{derive_impl, parameter_types};
 use frame_system::EnsureRoot;
@@ -24,6 +24,9 @@ pub const FROM: u64 = 0x1;
 pub const TO: u64 = 0x2;
 pub const OTHER: u64 = 0x3;
 pub const FEEDER: u64 = 0x42;
+pub const TREASURY: u64 = 0x7;
+pub const MAX_ORDER_HISTORY: u32 = 3;
+pub const MAX_ORDERS_PER_USER: u32 = 5;
 pub const INITIAL_A: Balance = token_a(1000);
 pub const INITIAL_B: Balance = token_b(1000);
 
@@ -58,6 +61,7 @@ frame_support::construct_runtime!(
 		  OrderBook: order_book,
 		  MockRatioProvider: cfg_mocks::value_provider::pallet,
 		  MockFulfilledOrderHook: cfg_mocks::status_notification::pallet,
+		  MockKeeperRewards: cfg_mocks::keeper_rewards::pallet,
 	  }
 );
 
@@ -86,6 +90,11 @@ impl cfg_mocks::status_notification::pallet::Config for Runtime {
 	type Status = SwapInfo<Balance, Balance, CurrencyId, Ratio>;
 }
 
+impl cfg_mocks::keeper_rewards::pallet::Config for Runtime {
+	type Action = KeeperAction;
+	type Balance = Balance;
+}
+
 #[derive_impl(pallet_balances::config_preludes::TestDefaultConfig as pallet_balances::DefaultConfig)]
 impl pallet_balances::Config for Runtime {
 	type AccountStore = System;
@@ -116,6 +125,7 @@ impl orml_tokens::Config for Runtime {
 
 parameter_types! {
 	pub MinFulfillmentAmountNative: Balance = native(2);
+	pub const TreasuryAccount: AccountId = TREASURY;
 }
 
 impl order_book::Config for Runtime {
@@ -127,12 +137,16 @@ impl order_book::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type FeederId = AccountId;
 	type FulfilledOrderHook = MockFulfilledOrderHook;
+	type KeeperRewards = MockKeeperRewards;
+	type MaxOrderHistory = ConstU32<MAX_ORDER_HISTORY>;
+	type MaxOrdersPerUser = ConstU32<MAX_ORDERS_PER_USER>;
 	type MinFulfillmentAmountNative = MinFulfillmentAmountNative;
 	type NativeDecimals = ConstU32<NATIVE_DECIMALS>;
 	type OrderIdNonce = OrderId;
 	type Ratio = Ratio;
 	type RatioProvider = MockRatioProvider;
 	type RuntimeEvent = RuntimeEvent;
+	type TreasuryAccount = TreasuryAccount;
 	type Weights = ();
 }
 
@@ -177,5 +191,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	.assimilate_storage(&mut t)
 	.unwrap();
 
-	sp_io::TestExternalities::new(t)
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| MockKeeperRewards::mock_reward(|_, _| 0));
+	ext
 }
@@ -10,12 +10,15 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::swaps::{OrderInfo, OrderRatio, Swap, SwapInfo, TokenSwaps};
+use cfg_traits::{
+	keeper::KeeperAction,
+	swaps::{OrderInfo, OrderRatio, Swap, SwapInfo, TokenSwaps},
+};
 use frame_support::{
 	assert_err, assert_ok,
 	traits::fungibles::{Inspect, InspectHold},
 };
-use sp_runtime::{DispatchError, FixedPointNumber};
+use sp_runtime::{DispatchError, DispatchResult, FixedPointNumber};
 
 use super::*;
 use crate::mock::*;
@@ -52,6 +55,10 @@ mod util {
 	pub fn assert_exists_order(order_id: OrderId) {
 		assert_ok!(Orders::<Runtime>::get(order_id));
 		assert_ok!(UserOrders::<Runtime>::get(FROM, order_id));
+		assert!(PairOrders::<Runtime>::contains_key(
+			(CURRENCY_A, CURRENCY_B),
+			order_id
+		));
 	}
 
 	pub fn assert_no_exists_order(order_id: OrderId) {
@@ -64,6 +71,11 @@ mod util {
 			UserOrders::<Runtime>::get(FROM, order_id),
 			Error::<Runtime>::OrderNotFound
 		);
+
+		assert!(!PairOrders::<Runtime>::contains_key(
+			(CURRENCY_A, CURRENCY_B),
+			order_id
+		));
 	}
 
 	pub fn expect_notification(
@@ -113,6 +125,7 @@ fn create_order_works() {
 				amount_out_initial: token_a(10),
 				ratio: OrderRatio::Custom(DEFAULT_RATIO),
 				amount_in: token_b(0),
+				expires_at: None,
 			}
 		);
 
@@ -168,7 +181,8 @@ fn update_order_works() {
 				amount_out: token_a(15),
 				amount_out_initial: token_a(15),
 				ratio: OrderRatio::Custom((1, 2).into()),
-				amount_in: token_b(0)
+				amount_in: token_b(0),
+				expires_at: None,
 			}
 		);
 
@@ -196,6 +210,7 @@ fn update_order_works() {
 				amount_out_initial: token_a(5),
 				ratio: OrderRatio::Custom((1, 2).into()),
 				amount_in: token_b(0),
+				expires_at: None,
 			}
 		);
 
@@ -326,6 +341,7 @@ fn fill_order_partial_in_two_times() {
 				amount_out_initial: token_a(10),
 				ratio: OrderRatio::Custom(DEFAULT_RATIO),
 				amount_in: first_amount_in,
+				expires_at: None,
 			}
 		);
 
@@ -556,6 +572,7 @@ mod market {
 					amount_out_initial: token_a(10),
 					ratio: OrderRatio::Market,
 					amount_in: first_amount_in,
+					expires_at: None,
 				}
 			);
 
@@ -601,4 +618,1060 @@ mod market {
 			);
 		});
 	}
+
+	mod slippage {
+		use super::*;
+
+		const MAX_SLIPPAGE: Ratio = Ratio::from_rational(1, 10);
+
+		fn place_order(ratio: OrderRatio<Ratio>) -> OrderId {
+			assert_ok!(OrderBook::place_order(
+				RuntimeOrigin::signed(FROM),
+				CURRENCY_B,
+				CURRENCY_A,
+				token_a(10),
+				ratio,
+			));
+
+			OrderIdNonceStore::<Runtime>::get()
+		}
+
+		fn feed_market(ratio: Ratio) {
+			MockRatioProvider::mock_get(move |feeder, pair| {
+				assert_eq!(*feeder, FEEDER);
+				assert_eq!(*pair, (CURRENCY_A, CURRENCY_B));
+				Ok(Some(ratio))
+			});
+		}
+
+		#[test]
+		fn place_order_stores_floor() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(OrderBook::set_market_feeder(RuntimeOrigin::root(), FEEDER));
+				feed_market(DEFAULT_RATIO);
+
+				let order_id = place_order(OrderRatio::MarketWithSlippage {
+					max_slippage: MAX_SLIPPAGE,
+				});
+
+				assert_eq!(
+					MarketRatioFloor::<Runtime>::get(order_id),
+					Some(Ratio::from_rational(18, 10))
+				);
+			});
+		}
+
+		#[test]
+		fn place_order_with_invalid_slippage() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(OrderBook::set_market_feeder(RuntimeOrigin::root(), FEEDER));
+				feed_market(DEFAULT_RATIO);
+
+				assert_err!(
+					OrderBook::place_order(
+						RuntimeOrigin::signed(FROM),
+						CURRENCY_B,
+						CURRENCY_A,
+						token_a(10),
+						OrderRatio::MarketWithSlippage {
+							max_slippage: Ratio::from_rational(11, 10),
+						},
+					),
+					Error::<Runtime>::InvalidMaxSlippage
+				);
+			});
+		}
+
+		#[test]
+		fn place_order_without_market_ratio() {
+			new_test_ext().execute_with(|| {
+				assert_err!(
+					OrderBook::place_order(
+						RuntimeOrigin::signed(FROM),
+						CURRENCY_B,
+						CURRENCY_A,
+						token_a(10),
+						OrderRatio::MarketWithSlippage {
+							max_slippage: MAX_SLIPPAGE,
+						},
+					),
+					Error::<Runtime>::MarketFeederNotFound
+				);
+			});
+		}
+
+		#[test]
+		fn fill_order_within_slippage() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(OrderBook::set_market_feeder(RuntimeOrigin::root(), FEEDER));
+				feed_market(DEFAULT_RATIO);
+
+				let order_id = place_order(OrderRatio::MarketWithSlippage {
+					max_slippage: MAX_SLIPPAGE,
+				});
+
+				let ratio = Ratio::from_rational(19, 10);
+				feed_market(ratio);
+
+				MockFulfilledOrderHook::mock_notify_status_change(move |id, swap_info| {
+					assert_eq!(id, order_id);
+					assert_eq!(swap_info.ratio, ratio);
+					Ok(())
+				});
+
+				assert_ok!(OrderBook::fill_order(
+					RuntimeOrigin::signed(TO),
+					order_id,
+					token_a(10),
+				));
+
+				util::assert_no_exists_order(order_id);
+				assert_eq!(MarketRatioFloor::<Runtime>::get(order_id), None);
+			});
+		}
+
+		#[test]
+		fn fill_order_exceeding_slippage() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(OrderBook::set_market_feeder(RuntimeOrigin::root(), FEEDER));
+				feed_market(DEFAULT_RATIO);
+
+				let order_id = place_order(OrderRatio::MarketWithSlippage {
+					max_slippage: MAX_SLIPPAGE,
+				});
+
+				feed_market(Ratio::from_rational(17, 10));
+
+				assert_err!(
+					OrderBook::fill_order(RuntimeOrigin::signed(TO), order_id, token_a(10)),
+					Error::<Runtime>::MaxSlippageExceeded,
+				);
+			});
+		}
+
+		#[test]
+		fn update_order_resets_floor() {
+			new_test_ext().execute_with(|| {
+				assert_ok!(OrderBook::set_market_feeder(RuntimeOrigin::root(), FEEDER));
+				feed_market(DEFAULT_RATIO);
+
+				let order_id = place_order(OrderRatio::MarketWithSlippage {
+					max_slippage: MAX_SLIPPAGE,
+				});
+
+				feed_market(Ratio::from_rational(3, 1));
+				assert_ok!(OrderBook::update_order(
+					RuntimeOrigin::signed(FROM),
+					order_id,
+					token_a(10),
+					OrderRatio::MarketWithSlippage {
+						max_slippage: MAX_SLIPPAGE,
+					},
+				));
+				assert_eq!(
+					MarketRatioFloor::<Runtime>::get(order_id),
+					Some(Ratio::from_rational(27, 10))
+				);
+
+				assert_ok!(OrderBook::update_order(
+					RuntimeOrigin::signed(FROM),
+					order_id,
+					token_a(10),
+					OrderRatio::Custom(DEFAULT_RATIO),
+				));
+				assert_eq!(MarketRatioFloor::<Runtime>::get(order_id), None);
+			});
+		}
+	}
+}
+
+mod expiry {
+	use frame_support::{traits::Hooks, weights::Weight};
+
+	use super::*;
+
+	const EXPIRES_AT: u64 = 5;
+
+	fn create_expiring_order(amount_out: Balance) -> OrderId {
+		assert_ok!(OrderBook::place_order_with_expiry(
+			RuntimeOrigin::signed(FROM),
+			CURRENCY_B,
+			CURRENCY_A,
+			amount_out,
+			OrderRatio::Custom(DEFAULT_RATIO),
+			EXPIRES_AT,
+		));
+
+		OrderIdNonceStore::<Runtime>::get()
+	}
+
+	#[test]
+	fn create_order_with_expiry() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			let order_id = create_expiring_order(token_a(10));
+
+			assert_eq!(
+				Orders::<Runtime>::get(order_id).unwrap().expires_at,
+				Some(EXPIRES_AT)
+			);
+			assert!(ExpiringOrders::<Runtime>::contains_key(
+				EXPIRES_AT, order_id
+			));
+		})
+	}
+
+	#[test]
+	fn create_order_with_past_expiry() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(EXPIRES_AT + 1);
+
+			assert_err!(
+				OrderBook::place_order_with_expiry(
+					RuntimeOrigin::signed(FROM),
+					CURRENCY_B,
+					CURRENCY_A,
+					token_a(10),
+					OrderRatio::Custom(DEFAULT_RATIO),
+					EXPIRES_AT,
+				),
+				Error::<Runtime>::InvalidExpiry
+			);
+		})
+	}
+
+	#[test]
+	fn fill_order_until_expiry() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			let order_id = create_expiring_order(token_a(10));
+			let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(5));
+			util::expect_notification(order_id, token_a(5), amount_in, token_a(5));
+
+			System::set_block_number(EXPIRES_AT);
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(5),
+			));
+
+			System::set_block_number(EXPIRES_AT + 1);
+			assert_err!(
+				OrderBook::fill_order(RuntimeOrigin::signed(TO), order_id, token_a(5)),
+				Error::<Runtime>::OrderExpired
+			);
+		})
+	}
+
+	#[test]
+	fn cancel_expired_order_by_anyone() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			let order_id = create_expiring_order(token_a(10));
+
+			assert_err!(
+				OrderBook::cancel_expired_order(RuntimeOrigin::signed(OTHER), order_id),
+				Error::<Runtime>::OrderNotExpired
+			);
+
+			System::set_block_number(EXPIRES_AT + 1);
+			assert_ok!(OrderBook::cancel_expired_order(
+				RuntimeOrigin::signed(OTHER),
+				order_id
+			));
+
+			util::assert_no_exists_order(order_id);
+			assert!(!ExpiringOrders::<Runtime>::contains_key(
+				EXPIRES_AT, order_id
+			));
+			assert_eq!(Tokens::balance_on_hold(CURRENCY_A, &(), &FROM), 0);
+			assert_eq!(Tokens::balance(CURRENCY_A, &FROM), INITIAL_A);
+
+			System::assert_last_event(
+				Event::<Runtime>::OrderExpired {
+					account: FROM,
+					order_id,
+				}
+				.into(),
+			);
+		})
+	}
+
+	#[test]
+	fn expired_orders_are_removed_on_idle() {
+		new_test_ext().execute_with(|| {
+			System::set_block_number(1);
+
+			let order_id = create_expiring_order(token_a(10));
+			let other_order_id = util::create_default_order(token_a(10));
+
+			OrderBook::on_idle(1, Weight::MAX);
+			assert_eq!(ExpiryCursor::<Runtime>::get(), Some(1));
+
+			OrderBook::on_idle(EXPIRES_AT, Weight::MAX);
+			util::assert_exists_order(order_id);
+
+			OrderBook::on_idle(EXPIRES_AT + 1, Weight::MAX);
+			util::assert_no_exists_order(order_id);
+			util::assert_exists_order(other_order_id);
+			assert_eq!(ExpiryCursor::<Runtime>::get(), Some(EXPIRES_AT + 1));
+			assert_eq!(Tokens::balance_on_hold(CURRENCY_A, &(), &FROM), token_a(10));
+		})
+	}
+}
+
+mod pair_index {
+	use cfg_types::tokens::CurrencyId;
+
+	use super::*;
+
+	const PAIR: (CurrencyId, CurrencyId) = (CURRENCY_A, CURRENCY_B);
+
+	#[test]
+	fn orders_are_counted_per_pair() {
+		new_test_ext().execute_with(|| {
+			let order_id_1 = util::create_default_order(token_a(10));
+			let order_id_2 = util::create_default_order(token_a(10));
+
+			assert_eq!(PairOrderCount::<Runtime>::get(PAIR), 2);
+			assert_eq!(PairOrderCount::<Runtime>::get((CURRENCY_B, CURRENCY_A)), 0);
+			assert_eq!(
+				OrderBook::pair_orders(CURRENCY_A, CURRENCY_B).collect::<Vec<_>>(),
+				vec![order_id_1, order_id_2]
+			);
+
+			assert_ok!(OrderBook::cancel_order(
+				RuntimeOrigin::signed(FROM),
+				order_id_1
+			));
+			assert_eq!(PairOrderCount::<Runtime>::get(PAIR), 1);
+
+			assert_ok!(OrderBook::cancel_order(
+				RuntimeOrigin::signed(FROM),
+				order_id_2
+			));
+			assert!(!PairOrderCount::<Runtime>::contains_key(PAIR));
+			assert_eq!(OrderBook::pair_orders(CURRENCY_A, CURRENCY_B).count(), 0);
+		})
+	}
+
+	#[test]
+	fn partially_filled_orders_stay_indexed() {
+		new_test_ext().execute_with(|| {
+			let order_id = util::create_default_order(token_a(10));
+
+			let first_amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(9));
+			util::expect_notification(order_id, token_a(1), first_amount_in, token_a(9));
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(9),
+			));
+			util::assert_exists_order(order_id);
+			assert_eq!(PairOrderCount::<Runtime>::get(PAIR), 1);
+
+			let second_amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(1));
+			util::expect_notification(order_id, token_a(0), second_amount_in, token_a(1));
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(1),
+			));
+			util::assert_no_exists_order(order_id);
+			assert_eq!(PairOrderCount::<Runtime>::get(PAIR), 0);
+		})
+	}
+}
+
+mod order_limits {
+	use super::*;
+
+	fn place_order() -> DispatchResult {
+		OrderBook::place_order(
+			RuntimeOrigin::signed(FROM),
+			CURRENCY_B,
+			CURRENCY_A,
+			token_a(10),
+			OrderRatio::Custom(DEFAULT_RATIO),
+		)
+	}
+
+	fn place_inverse_order() -> DispatchResult {
+		OrderBook::place_order(
+			RuntimeOrigin::signed(TO),
+			CURRENCY_A,
+			CURRENCY_B,
+			token_b(10),
+			OrderRatio::Custom(DEFAULT_RATIO),
+		)
+	}
+
+	#[test]
+	fn user_order_limit() {
+		new_test_ext().execute_with(|| {
+			for _ in 0..MAX_ORDERS_PER_USER {
+				assert_ok!(place_order());
+			}
+			assert_eq!(UserOrderCount::<Runtime>::get(FROM), MAX_ORDERS_PER_USER);
+
+			assert_err!(place_order(), Error::<Runtime>::TooManyUserOrders);
+			assert_err!(
+				OrderBook::place_order_with_expiry(
+					RuntimeOrigin::signed(FROM),
+					CURRENCY_B,
+					CURRENCY_A,
+					token_a(10),
+					OrderRatio::Custom(DEFAULT_RATIO),
+					10,
+				),
+				Error::<Runtime>::TooManyUserOrders
+			);
+
+			// Other accounts are not affected
+			assert_ok!(place_inverse_order());
+
+			assert_ok!(OrderBook::cancel_order(RuntimeOrigin::signed(FROM), 1));
+			assert_eq!(
+				UserOrderCount::<Runtime>::get(FROM),
+				MAX_ORDERS_PER_USER - 1
+			);
+			assert_ok!(place_order());
+		})
+	}
+
+	#[test]
+	fn swaps_are_not_limited() {
+		new_test_ext().execute_with(|| {
+			for _ in 0..MAX_ORDERS_PER_USER {
+				assert_ok!(place_order());
+			}
+
+			assert_ok!(<OrderBook as TokenSwaps<AccountId>>::place_order(
+				FROM,
+				CURRENCY_B,
+				CURRENCY_A,
+				token_a(10),
+				OrderRatio::Custom(DEFAULT_RATIO),
+			));
+			assert_eq!(
+				UserOrderCount::<Runtime>::get(FROM),
+				MAX_ORDERS_PER_USER + 1
+			);
+		})
+	}
+
+	#[test]
+	fn pair_order_limit() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(OrderBook::set_pair_order_limit(
+				RuntimeOrigin::root(),
+				CURRENCY_A,
+				CURRENCY_B,
+				Some(2),
+			));
+			System::assert_last_event(
+				Event::<Runtime>::PairOrderLimitSet {
+					currency_out: CURRENCY_A,
+					currency_in: CURRENCY_B,
+					limit: Some(2),
+				}
+				.into(),
+			);
+
+			assert_ok!(place_order());
+			assert_ok!(place_order());
+			assert_err!(place_order(), Error::<Runtime>::TooManyPairOrders);
+
+			// The inverse pair is not affected
+			assert_ok!(place_inverse_order());
+
+			assert_ok!(OrderBook::set_pair_order_limit(
+				RuntimeOrigin::root(),
+				CURRENCY_A,
+				CURRENCY_B,
+				None,
+			));
+			assert_ok!(place_order());
+		})
+	}
+
+	#[test]
+	fn set_pair_order_limit_with_wrong_origin() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				OrderBook::set_pair_order_limit(
+					RuntimeOrigin::signed(FROM),
+					CURRENCY_A,
+					CURRENCY_B,
+					Some(2),
+				),
+				DispatchError::BadOrigin
+			);
+		})
+	}
+}
+
+mod fill_constraints {
+	use super::*;
+
+	fn create_order(amount_out: Balance, constraints: FillConstraints<Balance>) -> OrderId {
+		assert_ok!(OrderBook::place_order_with_constraints(
+			RuntimeOrigin::signed(FROM),
+			CURRENCY_B,
+			CURRENCY_A,
+			amount_out,
+			OrderRatio::Custom(DEFAULT_RATIO),
+			constraints,
+		));
+
+		OrderIdNonceStore::<Runtime>::get()
+	}
+
+	#[test]
+	fn create_order_with_constraints() {
+		new_test_ext().execute_with(|| {
+			let constraints = FillConstraints {
+				min_fulfillment_amount_out: token_a(5),
+				fill_or_kill: false,
+			};
+			let order_id = create_order(token_a(10), constraints.clone());
+
+			util::assert_exists_order(order_id);
+			assert_eq!(
+				OrderFillConstraints::<Runtime>::get(order_id),
+				Some(constraints.clone())
+			);
+
+			System::assert_last_event(
+				Event::<Runtime>::OrderFillConstraintsSet {
+					order_id,
+					constraints,
+				}
+				.into(),
+			);
+
+			assert_ok!(OrderBook::cancel_order(
+				RuntimeOrigin::signed(FROM),
+				order_id
+			));
+			assert_eq!(OrderFillConstraints::<Runtime>::get(order_id), None);
+		})
+	}
+
+	#[test]
+	fn create_order_below_default_min_fulfillment_amount() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				OrderBook::place_order_with_constraints(
+					RuntimeOrigin::signed(FROM),
+					CURRENCY_B,
+					CURRENCY_A,
+					token_a(10),
+					OrderRatio::Custom(DEFAULT_RATIO),
+					FillConstraints {
+						min_fulfillment_amount_out: token_a(1),
+						fill_or_kill: false,
+					},
+				),
+				Error::<Runtime>::BelowMinFulfillmentAmount
+			);
+		})
+	}
+
+	#[test]
+	fn fill_order_below_custom_min_fulfillment_amount() {
+		new_test_ext().execute_with(|| {
+			let order_id = create_order(
+				token_a(10),
+				FillConstraints {
+					min_fulfillment_amount_out: token_a(5),
+					fill_or_kill: false,
+				},
+			);
+
+			assert_err!(
+				OrderBook::fill_order(RuntimeOrigin::signed(TO), order_id, token_a(4)),
+				Error::<Runtime>::BelowMinFulfillmentAmount
+			);
+
+			let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(5));
+			util::expect_notification(order_id, token_a(5), amount_in, token_a(5));
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(5),
+			));
+
+			util::assert_exists_order(order_id);
+		})
+	}
+
+	#[test]
+	fn fill_or_kill_order() {
+		new_test_ext().execute_with(|| {
+			let order_id = create_order(
+				token_a(10),
+				FillConstraints {
+					min_fulfillment_amount_out: token_a(2),
+					fill_or_kill: true,
+				},
+			);
+
+			assert_err!(
+				OrderBook::fill_order(RuntimeOrigin::signed(TO), order_id, token_a(9)),
+				Error::<Runtime>::FillOrKillNotFullyFilled
+			);
+
+			let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(10));
+			util::expect_notification(order_id, token_a(0), amount_in, token_a(10));
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(10),
+			));
+
+			util::assert_no_exists_order(order_id);
+			assert_eq!(OrderFillConstraints::<Runtime>::get(order_id), None);
+		})
+	}
+}
+
+mod fees {
+	use sp_runtime::Permill;
+
+	use super::*;
+
+	const OTHER_DESTINATION: AccountId = 0x8;
+
+	fn fees(destination: Option<AccountId>) -> FeeConfig<AccountId> {
+		FeeConfig {
+			maker: Permill::from_percent(1),
+			taker: Permill::from_percent(2),
+			destination,
+		}
+	}
+
+	fn fill_default_order() -> OrderId {
+		let order_id = util::create_default_order(token_a(10));
+
+		let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(10));
+		util::expect_notification(order_id, token_a(0), amount_in, token_a(10));
+
+		assert_ok!(OrderBook::fill_order(
+			RuntimeOrigin::signed(TO),
+			order_id,
+			token_a(10),
+		));
+
+		order_id
+	}
+
+	#[test]
+	fn set_fees() {
+		new_test_ext().execute_with(|| {
+			assert_err!(
+				OrderBook::set_fees(RuntimeOrigin::signed(FROM), fees(None)),
+				DispatchError::BadOrigin
+			);
+
+			assert_ok!(OrderBook::set_fees(RuntimeOrigin::root(), fees(None)));
+			assert_eq!(Fees::<Runtime>::get(), fees(None));
+
+			System::assert_last_event(Event::<Runtime>::FeesUpdated { fees: fees(None) }.into());
+		})
+	}
+
+	#[test]
+	fn fill_order_charges_fees_to_treasury() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(OrderBook::set_fees(RuntimeOrigin::root(), fees(None)));
+
+			let order_id = fill_default_order();
+
+			let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(10));
+			let maker_fee = amount_in / 100;
+			let taker_fee = token_a(10) * 2 / 100;
+
+			System::assert_has_event(
+				Event::<Runtime>::FeesCharged {
+					order_id,
+					destination: TREASURY,
+					maker_fee,
+					taker_fee,
+				}
+				.into(),
+			);
+
+			assert_eq!(Tokens::balance(CURRENCY_B, &FROM), amount_in - maker_fee);
+			assert_eq!(Tokens::balance(CURRENCY_A, &TO), token_a(10) - taker_fee);
+			assert_eq!(Tokens::balance(CURRENCY_B, &TREASURY), maker_fee);
+			assert_eq!(Tokens::balance(CURRENCY_A, &TREASURY), taker_fee);
+		})
+	}
+
+	#[test]
+	fn fill_order_charges_fees_to_destination() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(OrderBook::set_fees(
+				RuntimeOrigin::root(),
+				fees(Some(OTHER_DESTINATION))
+			));
+
+			fill_default_order();
+
+			let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(10));
+			assert_eq!(
+				Tokens::balance(CURRENCY_B, &OTHER_DESTINATION),
+				amount_in / 100
+			);
+			assert_eq!(
+				Tokens::balance(CURRENCY_A, &OTHER_DESTINATION),
+				token_a(10) * 2 / 100
+			);
+			assert_eq!(Tokens::balance(CURRENCY_B, &TREASURY), 0);
+		})
+	}
+
+	#[test]
+	fn swap_orders_are_fee_exempt() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(OrderBook::set_fees(RuntimeOrigin::root(), fees(None)));
+
+			let order_id = <OrderBook as TokenSwaps<AccountId>>::place_order(
+				FROM,
+				CURRENCY_B,
+				CURRENCY_A,
+				token_a(10),
+				OrderRatio::Custom(DEFAULT_RATIO),
+			)
+			.unwrap();
+			assert!(FeeExemptOrders::<Runtime>::contains_key(order_id));
+
+			let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(10));
+			util::expect_notification(order_id, token_a(0), amount_in, token_a(10));
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(10),
+			));
+
+			assert_eq!(Tokens::balance(CURRENCY_B, &FROM), amount_in);
+			assert_eq!(Tokens::balance(CURRENCY_A, &TREASURY), 0);
+			assert!(!FeeExemptOrders::<Runtime>::contains_key(order_id));
+		})
+	}
+}
+
+mod matching {
+	use super::*;
+
+	const RATIO_A: Ratio = Ratio::from_rational(3, 1);
+
+	fn create_orders(amount_out_b: Balance, ratio_b: Ratio) -> (OrderId, OrderId) {
+		assert_ok!(OrderBook::place_order(
+			RuntimeOrigin::signed(FROM),
+			CURRENCY_B,
+			CURRENCY_A,
+			token_a(10),
+			OrderRatio::Custom(RATIO_A),
+		));
+		let order_a = OrderIdNonceStore::<Runtime>::get();
+
+		assert_ok!(OrderBook::place_order(
+			RuntimeOrigin::signed(TO),
+			CURRENCY_A,
+			CURRENCY_B,
+			amount_out_b,
+			OrderRatio::Custom(ratio_b),
+		));
+
+		MockFulfilledOrderHook::mock_notify_status_change(|_, _| Ok(()));
+		MockKeeperRewards::mock_reward(|keeper, action| {
+			assert_eq!(*keeper, OTHER);
+			assert_eq!(action, KeeperAction::MatchOrders);
+			native(1)
+		});
+
+		(order_a, OrderIdNonceStore::<Runtime>::get())
+	}
+
+	#[test]
+	fn match_orders_at_mid_ratio() {
+		new_test_ext().execute_with(|| {
+			// Order b pays up to 5 B per A, the mid ratio is 4 B per A.
+			let (order_a, order_b) = create_orders(token_b(40), Ratio::from_rational(1, 5));
+
+			assert_ok!(OrderBook::match_orders(
+				RuntimeOrigin::signed(OTHER),
+				order_a,
+				order_b
+			));
+
+			System::assert_last_event(
+				Event::<Runtime>::OrdersMatched {
+					order_a,
+					order_b,
+					keeper: OTHER,
+					ratio: Ratio::from_rational(4, 1),
+					amount_out_a: token_a(10),
+					amount_out_b: token_b(40),
+				}
+				.into(),
+			);
+
+			util::assert_no_exists_order(order_a);
+			util::assert_no_exists_order(order_b);
+
+			assert_eq!(Tokens::balance(CURRENCY_B, &FROM), token_b(40));
+			assert_eq!(Tokens::balance(CURRENCY_A, &TO), token_a(10));
+		})
+	}
+
+	#[test]
+	fn match_own_orders_is_not_rewarded() {
+		new_test_ext().execute_with(|| {
+			let (order_a, order_b) = create_orders(token_b(40), Ratio::from_rational(1, 5));
+
+			MockKeeperRewards::mock_reward(|_, _| panic!("no rewards expected"));
+
+			assert_ok!(OrderBook::match_orders(
+				RuntimeOrigin::signed(FROM),
+				order_a,
+				order_b
+			));
+		})
+	}
+
+	#[test]
+	fn match_orders_partially() {
+		new_test_ext().execute_with(|| {
+			let (order_a, order_b) = create_orders(token_b(20), Ratio::from_rational(1, 5));
+
+			assert_ok!(OrderBook::match_orders(
+				RuntimeOrigin::signed(OTHER),
+				order_a,
+				order_b
+			));
+
+			util::assert_no_exists_order(order_b);
+			util::assert_exists_order(order_a);
+
+			let order = Orders::<Runtime>::get(order_a).unwrap();
+			assert_eq!(order.amount_out, token_a(5));
+			assert_eq!(order.amount_in, token_b(20));
+			assert_eq!(Tokens::balance_on_hold(CURRENCY_A, &(), &FROM), token_a(5));
+		})
+	}
+
+	#[test]
+	fn match_orders_not_crossing() {
+		new_test_ext().execute_with(|| {
+			// Order b pays up to 2 B per A, below the 3 B per A of order a.
+			let (order_a, order_b) = create_orders(token_b(40), Ratio::from_rational(1, 2));
+
+			assert_err!(
+				OrderBook::match_orders(RuntimeOrigin::signed(OTHER), order_a, order_b),
+				Error::<Runtime>::OrdersNotCrossing
+			);
+		})
+	}
+
+	#[test]
+	fn match_orders_of_same_pair() {
+		new_test_ext().execute_with(|| {
+			let order_a = util::create_default_order(token_a(10));
+			let order_b = util::create_default_order(token_a(10));
+
+			assert_err!(
+				OrderBook::match_orders(RuntimeOrigin::signed(OTHER), order_a, order_b),
+				Error::<Runtime>::OrdersNotCrossing
+			);
+		})
+	}
+}
+
+mod quotes {
+	use super::*;
+
+	fn create_order(amount_out: Balance, ratio: Ratio) {
+		assert_ok!(OrderBook::place_order(
+			RuntimeOrigin::signed(FROM),
+			CURRENCY_B,
+			CURRENCY_A,
+			amount_out,
+			OrderRatio::Custom(ratio),
+		));
+	}
+
+	fn create_orders() {
+		create_order(token_a(20), Ratio::from_rational(3, 1));
+		create_order(token_a(10), DEFAULT_RATIO);
+		create_order(token_a(5), DEFAULT_RATIO);
+	}
+
+	#[test]
+	fn depth_is_aggregated_by_ratio() {
+		new_test_ext().execute_with(|| {
+			create_orders();
+
+			assert_eq!(
+				OrderBook::depth(CURRENCY_A, CURRENCY_B, 5),
+				vec![
+					PriceLevel {
+						ratio: DEFAULT_RATIO,
+						amount_out: token_a(15),
+					},
+					PriceLevel {
+						ratio: Ratio::from_rational(3, 1),
+						amount_out: token_a(20),
+					},
+				]
+			);
+			assert_eq!(OrderBook::depth(CURRENCY_A, CURRENCY_B, 1).len(), 1);
+			assert_eq!(OrderBook::depth(CURRENCY_B, CURRENCY_A, 5), vec![]);
+		})
+	}
+
+	#[test]
+	fn best_ask() {
+		new_test_ext().execute_with(|| {
+			assert_eq!(OrderBook::best_ask(CURRENCY_A, CURRENCY_B), None);
+
+			create_orders();
+
+			assert_eq!(
+				OrderBook::best_ask(CURRENCY_A, CURRENCY_B),
+				Some(PriceLevel {
+					ratio: DEFAULT_RATIO,
+					amount_out: token_a(15),
+				})
+			);
+		})
+	}
+
+	#[test]
+	fn estimate_fill() {
+		new_test_ext().execute_with(|| {
+			create_orders();
+
+			assert_eq!(
+				OrderBook::estimate_fill(CURRENCY_A, CURRENCY_B, token_a(20)),
+				Some(token_b(45))
+			);
+			assert_eq!(
+				OrderBook::estimate_fill(CURRENCY_A, CURRENCY_B, token_a(36)),
+				None
+			);
+		})
+	}
+}
+
+mod order_history {
+	use super::*;
+
+	fn closed_order(
+		order_id: OrderId,
+		amount_out_filled: Balance,
+		amount_in_received: Balance,
+		closure: OrderClosure,
+	) -> ClosedOrderOf<Runtime> {
+		ClosedOrder {
+			order_id,
+			currency_in: CURRENCY_B,
+			currency_out: CURRENCY_A,
+			amount_out_initial: token_a(10),
+			amount_out_filled,
+			amount_in_received,
+			ratio: OrderRatio::Custom(DEFAULT_RATIO),
+			closure,
+			closed_at: frame_system::Pallet::<Runtime>::block_number(),
+		}
+	}
+
+	#[test]
+	fn filled() {
+		new_test_ext().execute_with(|| {
+			let order_id = util::create_default_order(token_a(10));
+
+			let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(10));
+			util::expect_notification(order_id, token_a(0), amount_in, token_a(10));
+
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(10),
+			));
+
+			assert_eq!(
+				OrderBook::order_history(&FROM),
+				vec![closed_order(
+					order_id,
+					token_a(10),
+					amount_in,
+					OrderClosure::Filled
+				)]
+			);
+		})
+	}
+
+	#[test]
+	fn cancelled_after_partial_fill() {
+		new_test_ext().execute_with(|| {
+			let order_id = util::create_default_order(token_a(10));
+
+			let amount_in = token_b(DEFAULT_RATIO.saturating_mul_int(9));
+			util::expect_notification(order_id, token_a(1), amount_in, token_a(9));
+
+			assert_ok!(OrderBook::fill_order(
+				RuntimeOrigin::signed(TO),
+				order_id,
+				token_a(9),
+			));
+
+			assert_eq!(OrderBook::order_history(&FROM), vec![]);
+
+			assert_ok!(OrderBook::cancel_order(
+				RuntimeOrigin::signed(FROM),
+				order_id
+			));
+
+			assert_eq!(
+				OrderBook::order_history(&FROM),
+				vec![closed_order(
+					order_id,
+					token_a(9),
+					amount_in,
+					OrderClosure::Cancelled
+				)]
+			);
+		})
+	}
+
+	#[test]
+	fn bounded_retention() {
+		new_test_ext().execute_with(|| {
+			let order_ids = (0..MAX_ORDER_HISTORY + 1)
+				.map(|_| {
+					let order_id = util::create_default_order(token_a(10));
+					assert_ok!(OrderBook::cancel_order(
+						RuntimeOrigin::signed(FROM),
+						order_id
+					));
+					order_id
+				})
+				.collect::<Vec<_>>();
+
+			// The oldest closed order is dropped
+			assert_eq!(
+				OrderBook::order_history(&FROM),
+				order_ids[1..]
+					.iter()
+					.map(|order_id| closed_order(*order_id, 0, 0, OrderClosure::Cancelled))
+					.collect::<Vec<_>>()
+			);
+		})
+	}
 }
@@ -18,6 +18,12 @@ pub trait WeightInfo {
 	fn cancel_order() -> Weight;
 	fn fill_order() -> Weight;
 	fn set_market_feeder() -> Weight;
+	fn place_order_with_expiry() -> Weight;
+	fn cancel_expired_order() -> Weight;
+	fn place_order_with_constraints() -> Weight;
+	fn set_fees() -> Weight;
+	fn match_orders() -> Weight;
+	fn set_pair_order_limit() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -40,4 +46,28 @@ impl WeightInfo for () {
 	fn set_market_feeder() -> Weight {
 		Weight::zero()
 	}
+
+	fn place_order_with_expiry() -> Weight {
+		Weight::zero()
+	}
+
+	fn cancel_expired_order() -> Weight {
+		Weight::zero()
+	}
+
+	fn place_order_with_constraints() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_fees() -> Weight {
+		Weight::zero()
+	}
+
+	fn match_orders() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_pair_order_limit() -> Weight {
+		Weight::zero()
+	}
 }
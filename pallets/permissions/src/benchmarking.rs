@@ -131,6 +131,87 @@ benchmarks! {
 	verify {
 		assert!(!<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id, acc, role));
 	}
+
+	delegate {
+		// setup pool admin
+		let acc = admin::<T>(0);
+		let role = T::Role::editor();
+		let pool_id: T::Scope = Default::default();
+		let res = PermissionsPallet::<T>::add(RawOrigin::Root.into(), role.clone(), acc.clone(), pool_id.clone(), role.clone());
+		assert_ok!(res);
+
+		let acc2 = admin::<T>(1);
+		let until = T::Time::now() + T::MaxDelegationPeriod::get();
+	}:_(RawOrigin::Signed(acc.clone()), pool_id.clone(), role.clone(), acc2.clone(), until)
+	verify {
+		assert!(<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id, acc2, role));
+	}
+
+	revoke_delegation {
+		// setup pool admin and its delegate
+		let acc = admin::<T>(0);
+		let role = T::Role::editor();
+		let pool_id: T::Scope = Default::default();
+		let res = PermissionsPallet::<T>::add(RawOrigin::Root.into(), role.clone(), acc.clone(), pool_id.clone(), role.clone());
+		assert_ok!(res);
+
+		let acc2 = admin::<T>(1);
+		let until = T::Time::now() + T::MaxDelegationPeriod::get();
+		let res = PermissionsPallet::<T>::delegate(RawOrigin::Signed(acc.clone()).into(), pool_id.clone(), role.clone(), acc2.clone(), until);
+		assert_ok!(res);
+	}:_(RawOrigin::Signed(acc.clone()), pool_id.clone(), role.clone(), acc2.clone())
+	verify {
+		assert!(!<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id, acc2, role));
+	}
+
+	admin_purge_delegations {
+		// setup pool admin and its delegate
+		let acc = admin::<T>(0);
+		let role = T::Role::editor();
+		let pool_id: T::Scope = Default::default();
+		let res = PermissionsPallet::<T>::add(RawOrigin::Root.into(), role.clone(), acc.clone(), pool_id.clone(), role.clone());
+		assert_ok!(res);
+
+		let acc2 = admin::<T>(1);
+		let until = T::Time::now() + T::MaxDelegationPeriod::get();
+		let res = PermissionsPallet::<T>::delegate(RawOrigin::Signed(acc.clone()).into(), pool_id.clone(), role.clone(), acc2.clone(), until);
+		assert_ok!(res);
+	}:_(RawOrigin::Root, acc2.clone(), pool_id.clone())
+	verify {
+		assert!(!<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id, acc2, role));
+	}
+
+	batch_add {
+		let n in 1..T::MaxBatchSize::get();
+
+		let role = T::Role::user();
+		let pool_id: T::Scope = Default::default();
+		let permissions: BoundedVec<_, T::MaxBatchSize> = (0..n)
+			.map(|i| (pool_id.clone(), admin::<T>(i), role.clone()))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+	}:_(RawOrigin::Root, permissions)
+	verify {
+		assert!(<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id, admin::<T>(n - 1), role));
+	}
+
+	batch_remove {
+		let n in 1..T::MaxBatchSize::get();
+
+		let role = T::Role::user();
+		let pool_id: T::Scope = Default::default();
+		let permissions: BoundedVec<_, T::MaxBatchSize> = (0..n)
+			.map(|i| (pool_id.clone(), admin::<T>(i), role.clone()))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let res = PermissionsPallet::<T>::batch_add(RawOrigin::Root.into(), permissions.clone());
+		assert_ok!(res);
+	}:_(RawOrigin::Root, permissions)
+	verify {
+		assert!(!<PermissionsPallet::<T> as TPermissions<T::AccountId>>::has(pool_id, admin::<T>(n - 1), role));
+	}
 }
 
 impl_benchmark_test_suite!(
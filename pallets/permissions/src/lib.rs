@@ -24,20 +24,80 @@ mod tests;
 mod benchmarking;
 pub mod weights;
 
-/// Who informs about the caller's role
-enum Who {
-	/// Admins can add/remove permissions,
-	/// and purge other users permissions.
-	Admin,
-	/// Editors can add/remove permissions
-	Editor,
-}
-
-use cfg_traits::{Permissions, Properties};
+use cfg_traits::{hooks::HookWeightStorage, Permissions, Properties, Seconds, TimeAsSecs};
 use frame_support::{dispatch::DispatchResult, pallet_prelude::*, traits::Contains};
 use frame_system::pallet_prelude::*;
+use sp_runtime::traits::Saturating;
+use sp_std::vec::Vec;
 pub use weights::WeightInfo;
 
+/// Max length of the raw storage key kept as `on_idle` sweep cursor.
+pub const MAX_CURSOR_LEN: u32 = 256;
+
+/// A role temporarily delegated by one of its holders to another account.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct Delegation<AccountId, Role> {
+	/// Holder of the role that delegated it
+	pub delegator: AccountId,
+
+	/// Delegated role
+	pub role: Role,
+
+	/// Last moment, in seconds, in which the delegation is valid
+	pub until: Seconds,
+}
+
+/// Who changed a permission.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub enum ChangeOrigin<AccountId, Role> {
+	/// The admin origin, which can add/remove permissions and purge other
+	/// users permissions. Contains the account if the origin was signed.
+	Admin { who: Option<AccountId> },
+	/// An editor, allowed to add/remove the permission by one of its roles
+	Editor { who: AccountId, with_role: Role },
+	/// Another pallet through the `Permissions` trait
+	Internal,
+}
+
+/// Kind of a permission change.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub enum ChangeKind {
+	Added,
+	Removed,
+}
+
+/// A permission added or removed in a scope, kept for audit trails.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen)]
+pub struct PermissionChange<AccountId, Role, BlockNumber, Hash> {
+	/// Whether the role was added or removed
+	pub kind: ChangeKind,
+
+	/// Account the role was added to or removed from
+	pub account: AccountId,
+
+	/// Changed role
+	pub role: Role,
+
+	/// Who changed the role
+	pub origin: ChangeOrigin<AccountId, Role>,
+
+	/// Hash of the reason given for the change, e.g., of an off-chain document
+	pub reason: Option<Hash>,
+
+	/// Block in which the change happened
+	pub block: BlockNumber,
+}
+
+pub type ChangeOriginOf<T> =
+	ChangeOrigin<<T as frame_system::Config>::AccountId, <T as pallet::Config>::Role>;
+
+pub type PermissionChangeOf<T> = PermissionChange<
+	<T as frame_system::Config>::AccountId,
+	<T as pallet::Config>::Role,
+	BlockNumberFor<T>,
+	<T as frame_system::Config>::Hash,
+>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -53,7 +113,7 @@ pub mod pallet {
 
 		type Scope: Member + Parameter + MaxEncodedLen;
 
-		type Role: Member + Parameter;
+		type Role: Member + Parameter + MaxEncodedLen;
 
 		type Storage: Member
 			+ Parameter
@@ -63,11 +123,34 @@ pub mod pallet {
 
 		type Editors: Contains<(Self::AccountId, Option<Self::Role>, Self::Scope, Self::Role)>;
 
+		/// Roles that can be delegated by their holders.
+		type DelegableRoles: Contains<Self::Role>;
+
 		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Time provider used to expire delegations.
+		type Time: TimeAsSecs;
+
 		#[pallet::constant]
 		type MaxRolesPerScope: Get<u32>;
 
+		/// Max number of permissions added or removed in a single batch.
+		#[pallet::constant]
+		type MaxBatchSize: Get<u32>;
+
+		/// Max number of roles delegated to an account in a scope.
+		#[pallet::constant]
+		type MaxDelegations: Get<u32>;
+
+		/// Max number of seconds a delegation can last.
+		#[pallet::constant]
+		type MaxDelegationPeriod: Get<Seconds>;
+
+		/// Max number of permission changes kept per scope. The oldest ones
+		/// are dropped when new changes happen.
+		#[pallet::constant]
+		type MaxChangesPerScope: Get<u32>;
+
 		type WeightInfo: WeightInfo;
 	}
 
@@ -86,6 +169,39 @@ pub mod pallet {
 	#[pallet::getter(fn permission_count)]
 	pub type PermissionCount<T: Config> = StorageMap<_, Blake2_128Concat, T::Scope, u32>;
 
+	/// Roles delegated to an account in a scope. They are kept apart from
+	/// the roles in `Permission`, so they can be revoked all at once.
+	#[pallet::storage]
+	pub type Delegations<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::Scope,
+		BoundedVec<Delegation<T::AccountId, T::Role>, T::MaxDelegations>,
+		ValueQuery,
+	>;
+
+	/// Raw key of the last `Permission` entry inspected by `on_idle`.
+	#[pallet::storage]
+	pub type PurgeCursor<T: Config> =
+		StorageValue<_, BoundedVec<u8, ConstU32<MAX_CURSOR_LEN>>, OptionQuery>;
+
+	/// Weight consumed by the hooks of this pallet and the block it was
+	/// consumed in.
+	#[pallet::storage]
+	pub type LastHookWeight<T: Config> = StorageValue<_, (BlockNumberFor<T>, Weight), OptionQuery>;
+
+	/// Last permissions added or removed in a scope, oldest first.
+	#[pallet::storage]
+	pub type PermissionChanges<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::Scope,
+		BoundedVec<PermissionChangeOf<T>, T::MaxChangesPerScope>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -93,16 +209,42 @@ pub mod pallet {
 			to: T::AccountId,
 			scope: T::Scope,
 			role: T::Role,
+			origin: ChangeOriginOf<T>,
+			reason: Option<T::Hash>,
 		},
 		Removed {
 			from: T::AccountId,
 			scope: T::Scope,
 			role: T::Role,
+			origin: ChangeOriginOf<T>,
+			reason: Option<T::Hash>,
 		},
 		Purged {
 			from: T::AccountId,
 			scope: T::Scope,
 		},
+		ExpiredPurged {
+			from: T::AccountId,
+			scope: T::Scope,
+			count: u32,
+		},
+		Delegated {
+			from: T::AccountId,
+			to: T::AccountId,
+			scope: T::Scope,
+			role: T::Role,
+			until: Seconds,
+		},
+		DelegationRevoked {
+			from: T::AccountId,
+			to: T::AccountId,
+			scope: T::Scope,
+			role: T::Role,
+		},
+		DelegationsPurged {
+			to: T::AccountId,
+			scope: T::Scope,
+		},
 	}
 
 	// Errors inform users that something went wrong.
@@ -114,6 +256,24 @@ pub mod pallet {
 		NoEditor,
 		WrongParameters,
 		TooManyRoles,
+		NoExpiredRoles,
+		InvalidDelegationPeriod,
+		TooManyDelegations,
+		NoDelegation,
+		RoleNotDelegable,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let record_weight = T::DbWeight::get().writes(1);
+			let weight = Self::sweep_expired(remaining_weight.saturating_sub(record_weight));
+			if !LastHookWeight::<T>::record(now, weight) {
+				return weight;
+			}
+
+			weight.saturating_add(record_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -127,14 +287,7 @@ pub mod pallet {
 			scope: T::Scope,
 			role: T::Role,
 		) -> DispatchResultWithPostInfo {
-			let who = Self::ensure_admin_or_editor(origin, with_role, scope.clone(), role.clone())?;
-
-			Pallet::<T>::do_add(scope, to, role)?;
-
-			match who {
-				Who::Editor => Ok(Some(T::WeightInfo::add_as_editor()).into()),
-				Who::Admin => Ok(Some(T::WeightInfo::add_as_admin()).into()),
-			}
+			Self::add_with_origin(origin, with_role, to, scope, role, None)
 		}
 
 		#[pallet::weight(T::WeightInfo::remove_as_editor().max(T::WeightInfo::remove_as_admin()))]
@@ -146,14 +299,7 @@ pub mod pallet {
 			scope: T::Scope,
 			role: T::Role,
 		) -> DispatchResultWithPostInfo {
-			let who = Self::ensure_admin_or_editor(origin, with_role, scope.clone(), role.clone())?;
-
-			Pallet::<T>::do_remove(scope, from, role)?;
-
-			match who {
-				Who::Editor => Ok(Some(T::WeightInfo::remove_as_editor()).into()),
-				Who::Admin => Ok(Some(T::WeightInfo::remove_as_admin()).into()),
-			}
+			Self::remove_with_origin(origin, with_role, from, scope, role, None)
 		}
 
 		#[pallet::weight(T::WeightInfo::purge())]
@@ -193,6 +339,218 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Removes the expired roles of an account in a scope. Can be called
+		/// by anyone.
+		#[pallet::weight(T::WeightInfo::purge_expired())]
+		#[pallet::call_index(4)]
+		pub fn purge_expired(
+			origin: OriginFor<T>,
+			scope: T::Scope,
+			from: T::AccountId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_purge_expired(scope, from)
+		}
+
+		/// Delegates a role held by the caller to another account until the
+		/// given moment, in seconds. The delegation lapses if the caller loses
+		/// the role. Delegated roles can not be delegated further and do not
+		/// allow to edit permissions. Only roles in `DelegableRoles` can be
+		/// delegated.
+		#[pallet::weight(T::WeightInfo::delegate())]
+		#[pallet::call_index(5)]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			scope: T::Scope,
+			role: T::Role,
+			to: T::AccountId,
+			until: Seconds,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			ensure!(
+				T::DelegableRoles::contains(&role),
+				Error::<T>::RoleNotDelegable
+			);
+			ensure!(Self::holds(&from, &scope, &role), Error::<T>::RoleNotGiven);
+
+			let now = T::Time::now();
+			ensure!(
+				until >= now && until <= now.saturating_add(T::MaxDelegationPeriod::get()),
+				Error::<T>::InvalidDelegationPeriod
+			);
+
+			Delegations::<T>::try_mutate(&to, &scope, |delegations| -> DispatchResult {
+				delegations.retain(|d| d.until >= now);
+
+				match delegations
+					.iter_mut()
+					.find(|d| d.delegator == from && d.role == role)
+				{
+					Some(delegation) => delegation.until = until,
+					None => delegations
+						.try_push(Delegation {
+							delegator: from.clone(),
+							role: role.clone(),
+							until,
+						})
+						.map_err(|_| Error::<T>::TooManyDelegations)?,
+				}
+
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::<T>::Delegated {
+				from,
+				to,
+				scope,
+				role,
+				until,
+			});
+
+			Ok(())
+		}
+
+		/// Revokes a role the caller delegated to another account.
+		#[pallet::weight(T::WeightInfo::revoke_delegation())]
+		#[pallet::call_index(6)]
+		pub fn revoke_delegation(
+			origin: OriginFor<T>,
+			scope: T::Scope,
+			role: T::Role,
+			to: T::AccountId,
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+
+			Delegations::<T>::try_mutate_exists(&to, &scope, |maybe_delegations| {
+				let delegations = maybe_delegations.as_mut().ok_or(Error::<T>::NoDelegation)?;
+				let index = delegations
+					.iter()
+					.position(|d| d.delegator == from && d.role == role)
+					.ok_or(Error::<T>::NoDelegation)?;
+
+				delegations.remove(index);
+				if delegations.is_empty() {
+					*maybe_delegations = None;
+				}
+
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::<T>::DelegationRevoked {
+				from,
+				to,
+				scope,
+				role,
+			});
+
+			Ok(())
+		}
+
+		/// Revokes all roles delegated to an account in a scope.
+		#[pallet::weight(T::WeightInfo::admin_purge_delegations())]
+		#[pallet::call_index(7)]
+		pub fn admin_purge_delegations(
+			origin: OriginFor<T>,
+			to: T::AccountId,
+			scope: T::Scope,
+		) -> DispatchResult {
+			Self::ensure_admin(origin)?;
+
+			ensure!(
+				Delegations::<T>::contains_key(&to, &scope),
+				Error::<T>::NoDelegation
+			);
+
+			Delegations::<T>::remove(&to, &scope);
+
+			Self::deposit_event(Event::<T>::DelegationsPurged { to, scope });
+
+			Ok(())
+		}
+
+		/// Adds a list of roles at once. Either all of them are added or none.
+		///
+		/// The origin must be an admin.
+		#[pallet::weight(T::WeightInfo::batch_add(permissions.len() as u32))]
+		#[pallet::call_index(8)]
+		pub fn batch_add(
+			origin: OriginFor<T>,
+			permissions: BoundedVec<(T::Scope, T::AccountId, T::Role), T::MaxBatchSize>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin.clone())?;
+			let who = ensure_signed(origin).ok();
+
+			for (scope, to, role) in permissions {
+				Self::do_add(
+					scope,
+					to,
+					role,
+					ChangeOrigin::Admin { who: who.clone() },
+					None,
+				)?;
+			}
+
+			Ok(())
+		}
+
+		/// Removes a list of roles at once. Either all of them are removed or
+		/// none.
+		///
+		/// The origin must be an admin.
+		#[pallet::weight(T::WeightInfo::batch_remove(permissions.len() as u32))]
+		#[pallet::call_index(9)]
+		pub fn batch_remove(
+			origin: OriginFor<T>,
+			permissions: BoundedVec<(T::Scope, T::AccountId, T::Role), T::MaxBatchSize>,
+		) -> DispatchResult {
+			Self::ensure_admin(origin.clone())?;
+			let who = ensure_signed(origin).ok();
+
+			for (scope, from, role) in permissions {
+				Self::do_remove(
+					scope,
+					from,
+					role,
+					ChangeOrigin::Admin { who: who.clone() },
+					None,
+				)?;
+			}
+
+			Ok(())
+		}
+
+		/// Adds a role as `add` does, recording the hash of the reason for it
+		/// in the audit trail of the scope.
+		#[pallet::weight(T::WeightInfo::add_as_admin().max(T::WeightInfo::add_as_editor()))]
+		#[pallet::call_index(10)]
+		pub fn add_with_reason(
+			origin: OriginFor<T>,
+			with_role: T::Role,
+			to: T::AccountId,
+			scope: T::Scope,
+			role: T::Role,
+			reason: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::add_with_origin(origin, with_role, to, scope, role, Some(reason))
+		}
+
+		/// Removes a role as `remove` does, recording the hash of the reason
+		/// for it in the audit trail of the scope.
+		#[pallet::weight(T::WeightInfo::remove_as_editor().max(T::WeightInfo::remove_as_admin()))]
+		#[pallet::call_index(11)]
+		pub fn remove_with_reason(
+			origin: OriginFor<T>,
+			with_role: T::Role,
+			from: T::AccountId,
+			scope: T::Scope,
+			role: T::Role,
+			reason: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			Self::remove_with_origin(origin, with_role, from, scope, role, Some(reason))
+		}
 	}
 }
 
@@ -202,30 +560,102 @@ impl<T: Config> Pallet<T> {
 		with_role: T::Role,
 		scope: T::Scope,
 		role: T::Role,
-	) -> Result<Who, DispatchError> {
+	) -> Result<ChangeOriginOf<T>, DispatchError> {
 		// check if origin is admin
 		match Self::ensure_admin(origin.clone()) {
-			Ok(()) => Ok(Who::Admin),
+			Ok(()) => Ok(ChangeOrigin::Admin {
+				who: ensure_signed(origin).ok(),
+			}),
 			_ => {
 				// check if origin is editor
 				let editor = ensure_signed(origin)?;
 				let is_editor = Permission::<T>::get(editor.clone(), scope.clone())
 					.map(|roles| {
 						roles.exists(with_role.clone())
-							&& T::Editors::contains(&(editor, Some(with_role), scope, role))
+							&& T::Editors::contains(&(
+								editor.clone(),
+								Some(with_role.clone()),
+								scope,
+								role,
+							))
 					})
 					.unwrap_or(false);
 				ensure!(is_editor, Error::<T>::NoEditor);
-				Ok(Who::Editor)
+				Ok(ChangeOrigin::Editor {
+					who: editor,
+					with_role,
+				})
 			}
 		}
 	}
 
+	fn add_with_origin(
+		origin: OriginFor<T>,
+		with_role: T::Role,
+		to: T::AccountId,
+		scope: T::Scope,
+		role: T::Role,
+		reason: Option<T::Hash>,
+	) -> DispatchResultWithPostInfo {
+		let origin = Self::ensure_admin_or_editor(origin, with_role, scope.clone(), role.clone())?;
+		let weight = match origin {
+			ChangeOrigin::Editor { .. } => T::WeightInfo::add_as_editor(),
+			_ => T::WeightInfo::add_as_admin(),
+		};
+
+		Self::do_add(scope, to, role, origin, reason)?;
+
+		Ok(Some(weight).into())
+	}
+
+	fn remove_with_origin(
+		origin: OriginFor<T>,
+		with_role: T::Role,
+		from: T::AccountId,
+		scope: T::Scope,
+		role: T::Role,
+		reason: Option<T::Hash>,
+	) -> DispatchResultWithPostInfo {
+		let origin = Self::ensure_admin_or_editor(origin, with_role, scope.clone(), role.clone())?;
+		let weight = match origin {
+			ChangeOrigin::Editor { .. } => T::WeightInfo::remove_as_editor(),
+			_ => T::WeightInfo::remove_as_admin(),
+		};
+
+		Self::do_remove(scope, from, role, origin, reason)?;
+
+		Ok(Some(weight).into())
+	}
+
+	/// Whether the account holds the role itself, not through a delegation.
+	fn holds(who: &T::AccountId, scope: &T::Scope, role: &T::Role) -> bool {
+		Permission::<T>::get(who, scope).map_or(false, |roles| roles.exists(role.clone()))
+	}
+
+	/// Whether the account was delegated the role by a current holder of it.
+	fn is_delegated(who: &T::AccountId, scope: &T::Scope, role: &T::Role) -> bool {
+		if !T::DelegableRoles::contains(role) {
+			return false;
+		}
+
+		let now = T::Time::now();
+
+		Delegations::<T>::get(who, scope)
+			.iter()
+			.any(|d| d.role == *role && d.until >= now && Self::holds(&d.delegator, scope, role))
+	}
+
 	fn ensure_admin(origin: OriginFor<T>) -> DispatchResult {
 		T::AdminOrigin::ensure_origin(origin).map_or(Err(Error::<T>::NoEditor.into()), |_| Ok(()))
 	}
 
-	fn do_add(scope: T::Scope, to: T::AccountId, role: T::Role) -> DispatchResult {
+	fn do_add(
+		scope: T::Scope,
+		to: T::AccountId,
+		role: T::Role,
+		origin: ChangeOriginOf<T>,
+		reason: Option<T::Hash>,
+	) -> DispatchResult {
 		PermissionCount::<T>::try_mutate(scope.clone(), |perm_count| {
 			let num_permissions = perm_count.map_or(1, |count| count + 1);
 			if num_permissions > T::MaxRolesPerScope::get() {
@@ -251,11 +681,32 @@ impl<T: Config> Pallet<T> {
 			)
 		})?;
 
-		Self::deposit_event(Event::<T>::Added { to, scope, role });
+		Self::record_change(
+			scope.clone(),
+			ChangeKind::Added,
+			to.clone(),
+			role.clone(),
+			origin.clone(),
+			reason,
+		);
+
+		Self::deposit_event(Event::<T>::Added {
+			to,
+			scope,
+			role,
+			origin,
+			reason,
+		});
 		Ok(())
 	}
 
-	fn do_remove(scope: T::Scope, from: T::AccountId, role: T::Role) -> DispatchResult {
+	fn do_remove(
+		scope: T::Scope,
+		from: T::AccountId,
+		role: T::Role,
+		origin: ChangeOriginOf<T>,
+		reason: Option<T::Hash>,
+	) -> DispatchResult {
 		PermissionCount::<T>::try_mutate(scope.clone(), |perm_count| {
 			let num_permissions = perm_count.map_or(0, |count| count - 1);
 			if num_permissions == 0 {
@@ -286,9 +737,140 @@ impl<T: Config> Pallet<T> {
 			)
 		})?;
 
-		Self::deposit_event(Event::<T>::Removed { from, scope, role });
+		Self::record_change(
+			scope.clone(),
+			ChangeKind::Removed,
+			from.clone(),
+			role.clone(),
+			origin.clone(),
+			reason,
+		);
+
+		Self::deposit_event(Event::<T>::Removed {
+			from,
+			scope,
+			role,
+			origin,
+			reason,
+		});
+		Ok(())
+	}
+
+	/// Appends a change to the audit trail of the scope, dropping the oldest
+	/// one if it is full.
+	fn record_change(
+		scope: T::Scope,
+		kind: ChangeKind,
+		account: T::AccountId,
+		role: T::Role,
+		origin: ChangeOriginOf<T>,
+		reason: Option<T::Hash>,
+	) {
+		let change = PermissionChange {
+			kind,
+			account,
+			role,
+			origin,
+			reason,
+			block: frame_system::Pallet::<T>::block_number(),
+		};
+
+		PermissionChanges::<T>::mutate(scope, |changes| {
+			// Only fails if `MaxChangesPerScope` is zero, in which case nothing is kept
+			let _ = changes.force_insert_keep_right(changes.len(), change);
+		});
+	}
+
+	fn do_purge_expired(scope: T::Scope, from: T::AccountId) -> DispatchResult {
+		let count = Permission::<T>::try_mutate_exists(
+			from.clone(),
+			scope.clone(),
+			|maybe_roles| -> Result<u32, DispatchError> {
+				let roles = maybe_roles.as_mut().ok_or(Error::<T>::NoRoles)?;
+				let count = roles.purge_expired();
+				ensure!(count > 0, Error::<T>::NoExpiredRoles);
+
+				if roles.empty() {
+					*maybe_roles = None;
+				}
+
+				Ok(count)
+			},
+		)?;
+
+		PermissionCount::<T>::mutate_exists(scope.clone(), |perm_count| {
+			*perm_count = perm_count
+				.map(|num_permissions| num_permissions.saturating_sub(count))
+				.filter(|num_permissions| *num_permissions > 0);
+		});
+
+		Self::deposit_event(Event::<T>::ExpiredPurged { from, scope, count });
 		Ok(())
 	}
+
+	/// Weight consumed by the hooks of this pallet in the current block.
+	pub fn hook_weight() -> Weight {
+		LastHookWeight::<T>::consumed_in(frame_system::Pallet::<T>::block_number())
+	}
+
+	/// Purges expired roles as long as the `remaining_weight` allows it,
+	/// continuing where the previous sweep stopped. Returns the consumed
+	/// weight.
+	pub(crate) fn sweep_expired(remaining_weight: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		let mut weight = db.reads_writes(1, 1);
+		if remaining_weight.any_lt(weight) {
+			return Weight::zero();
+		}
+
+		let mut keys = match PurgeCursor::<T>::get() {
+			Some(cursor) => Permission::<T>::iter_keys_from(cursor.into_inner()),
+			None => Permission::<T>::iter_keys(),
+		};
+		let step = T::WeightInfo::purge_expired().saturating_add(db.reads(1));
+
+		loop {
+			if remaining_weight.any_lt(weight.saturating_add(step)) {
+				// If the key does not fit, the next sweep starts over.
+				PurgeCursor::<T>::set(BoundedVec::try_from(keys.last_raw_key().to_vec()).ok());
+				break;
+			}
+			weight.saturating_accrue(step);
+
+			match keys.next() {
+				Some((from, scope)) => {
+					// Entries without expired roles are expected and just skipped.
+					let _ = Self::do_purge_expired(scope, from);
+				}
+				None => {
+					PurgeCursor::<T>::kill();
+					break;
+				}
+			}
+		}
+
+		weight
+	}
+
+	/// Lists the valid roles an account holds across all scopes.
+	pub fn account_roles(who: T::AccountId) -> Vec<(T::Scope, T::Role)> {
+		Permission::<T>::iter_prefix(who)
+			.flat_map(|(scope, roles)| {
+				roles
+					.properties()
+					.into_iter()
+					.map(move |role| (scope.clone(), role))
+			})
+			.collect()
+	}
+
+	/// Lists the last `count` permission changes of a scope, oldest first.
+	pub fn permission_changes(scope: T::Scope, count: u32) -> Vec<PermissionChangeOf<T>> {
+		let changes = PermissionChanges::<T>::get(scope).into_inner();
+		let skip = changes.len().saturating_sub(count as usize);
+
+		changes.into_iter().skip(skip).collect()
+	}
 }
 
 impl<T: Config> Permissions<T::AccountId> for Pallet<T> {
@@ -298,14 +880,14 @@ impl<T: Config> Permissions<T::AccountId> for Pallet<T> {
 	type Scope = T::Scope;
 
 	fn has(scope: T::Scope, who: T::AccountId, role: T::Role) -> bool {
-		Permission::<T>::get(who, scope).map_or(false, |roles| roles.exists(role))
+		Self::holds(&who, &scope, &role) || Self::is_delegated(&who, &scope, &role)
 	}
 
 	fn add(scope: T::Scope, who: T::AccountId, role: T::Role) -> Result<(), DispatchError> {
-		Pallet::<T>::do_add(scope, who, role)
+		Pallet::<T>::do_add(scope, who, role, ChangeOrigin::Internal, None)
 	}
 
 	fn remove(scope: T::Scope, who: T::AccountId, role: T::Role) -> Result<(), DispatchError> {
-		Pallet::<T>::do_remove(scope, who, role)
+		Pallet::<T>::do_remove(scope, who, role, ChangeOrigin::Internal, None)
 	}
 }
@@ -13,7 +13,8 @@
 pub use dummy::pallet as pallet_dummy;
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{Contains, EitherOfDiverse, SortedMembers},
+	traits::{Contains, EitherOfDiverse, SortedMembers, UnixTime},
+	weights::RuntimeDbWeight,
 };
 use frame_system::{EnsureRoot, EnsureSignedBy};
 use pallet_permissions::Properties;
@@ -25,20 +26,20 @@ use sp_runtime::{traits::AccountIdConversion, BuildStorage};
 ///! Mock environment setup for testing the pallet-permissions
 use crate::{self as pallet_permissions};
 
-#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq)]
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq, MaxEncodedLen)]
 pub enum OrganisationRole {
 	SeniorExeutive,
 	HeadOfSaubermaching,
 	Admin,
 }
 
-#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq)]
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq, MaxEncodedLen)]
 pub enum XcmRole {
 	Sender,
 	Receiver,
 }
 
-#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq)]
+#[derive(Encode, Decode, TypeInfo, Debug, Clone, Eq, PartialEq, MaxEncodedLen)]
 pub enum Role {
 	Organisation(OrganisationRole),
 	Xcm(XcmRole),
@@ -148,6 +149,46 @@ impl Properties for Storage {
 		};
 		Ok(())
 	}
+
+	/// Xcm roles are temporary in this mock, they expire once
+	/// `XcmRolesExpired` is set.
+	fn purge_expired(&mut self) -> u32 {
+		if !XcmRolesExpired::get() {
+			return 0;
+		}
+
+		let expired = self.xcm.bits().count_ones();
+		self.xcm = XcmStorage::empty();
+		expired
+	}
+
+	fn properties(&self) -> Vec<Self::Property> {
+		[
+			(
+				self.org.contains(OrgStorage::SENIOR_EXEC),
+				Role::Organisation(OrganisationRole::SeniorExeutive),
+			),
+			(
+				self.org.contains(OrgStorage::HEAD_OF_SAUBERMACHING),
+				Role::Organisation(OrganisationRole::HeadOfSaubermaching),
+			),
+			(
+				self.org.contains(OrgStorage::ADMIN),
+				Role::Organisation(OrganisationRole::Admin),
+			),
+			(
+				self.xcm.contains(XcmStorage::SENDER),
+				Role::Xcm(XcmRole::Sender),
+			),
+			(
+				self.xcm.contains(XcmStorage::RECEIVER),
+				Role::Xcm(XcmRole::Receiver),
+			),
+		]
+		.into_iter()
+		.filter_map(|(given, role)| given.then_some(role))
+		.collect()
+	}
 }
 
 mod dummy {
@@ -238,24 +279,46 @@ frame_support::construct_runtime!(
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
 impl frame_system::Config for Runtime {
 	type Block = frame_system::mocking::MockBlock<Runtime>;
+	type DbWeight = DbWeight;
 }
 
 parameter_types! {
+	pub const DbWeight: RuntimeDbWeight = RuntimeDbWeight { read: 1, write: 1 };
 	pub const One: u64 = 1;
 	pub const MaxRoles: u32 = 10;
 	pub const MaxTranches: u32 = 5;
+	pub const MaxBatchSize: u32 = 3;
+	pub const MaxDelegations: u32 = 2;
+	pub const MaxDelegationPeriod: u64 = 100;
+	pub const MaxChangesPerScope: u32 = 3;
+	pub static XcmRolesExpired: bool = false;
+	pub static Now: u64 = 0;
+}
+
+/// Time source whose seconds are set through `Now`.
+pub struct Time;
+impl UnixTime for Time {
+	fn now() -> std::time::Duration {
+		std::time::Duration::from_secs(Now::get())
+	}
 }
 
 type AdminOrigin = EitherOfDiverse<EnsureRoot<u64>, EnsureSignedBy<One, u64>>;
 
 impl pallet_permissions::Config for Runtime {
 	type AdminOrigin = AdminOrigin;
+	type DelegableRoles = DelegableRoles;
 	type Editors = Editors;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxChangesPerScope = MaxChangesPerScope;
+	type MaxDelegationPeriod = MaxDelegationPeriod;
+	type MaxDelegations = MaxDelegations;
 	type MaxRolesPerScope = MaxRoles;
 	type Role = Role;
 	type RuntimeEvent = RuntimeEvent;
 	type Scope = Scope;
 	type Storage = Storage;
+	type Time = Time;
 	type WeightInfo = ();
 }
 
@@ -300,6 +363,14 @@ impl Contains<(AccountId, Option<Role>, Scope, Role)> for Editors {
 	}
 }
 
+/// Organisation roles can be delegated, xcm roles can not.
+pub struct DelegableRoles;
+impl Contains<Role> for DelegableRoles {
+	fn contains(role: &Role) -> bool {
+		matches!(role, Role::Organisation(_))
+	}
+}
+
 impl SortedMembers<u64> for One {
 	fn sorted_members() -> Vec<u64> {
 		vec![1]
@@ -10,12 +10,16 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight, BoundedVec};
 use pallet_permissions::{Permissions, Properties};
+use parity_scale_codec::Encode;
+use sp_runtime::testing::H256;
 
 ///! Tests for the permissions pallet
 use crate as pallet_permissions;
-use crate::{mock::*, Error as PermissionsError};
+use crate::{
+	mock::*, ChangeKind, ChangeOrigin, Delegation, Error as PermissionsError, PermissionChange,
+};
 
 #[test]
 fn add_ext_works() {
@@ -441,3 +445,629 @@ fn permission_counting() {
 			assert!(pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA,).is_none(),);
 		})
 }
+
+fn add_xcm_roles(to: AccountId) {
+	for role in [XcmRole::Sender, XcmRole::Receiver] {
+		assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+			RuntimeOrigin::signed(1),
+			Role::Organisation(OrganisationRole::HeadOfSaubermaching),
+			to,
+			Scope::PalletA,
+			Role::Xcm(role)
+		));
+	}
+}
+
+#[test]
+fn purge_expired_works() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			add_xcm_roles(2);
+			add_xcm_roles(3);
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::HeadOfSaubermaching),
+				3,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::SeniorExeutive)
+			));
+			XcmRolesExpired::set(true);
+
+			// Anyone can purge
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::purge_expired(
+				RuntimeOrigin::signed(4),
+				Scope::PalletA,
+				2
+			));
+			assert!(pallet_permissions::Permission::<Runtime>::get(2, Scope::PalletA).is_none());
+			assert_eq!(
+				pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA),
+				Some(3)
+			);
+
+			// Valid roles are kept
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::purge_expired(
+				RuntimeOrigin::signed(4),
+				Scope::PalletA,
+				3
+			));
+			assert_eq!(
+				pallet_permissions::Pallet::<Runtime>::account_roles(3),
+				vec![(
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::SeniorExeutive)
+				)]
+			);
+			assert_eq!(
+				pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA),
+				Some(1)
+			);
+		})
+}
+
+#[test]
+fn purge_expired_fails() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::purge_expired(
+					RuntimeOrigin::signed(4),
+					Scope::PalletA,
+					2
+				),
+				PermissionsError::<Runtime>::NoRoles
+			);
+
+			add_xcm_roles(2);
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::purge_expired(
+					RuntimeOrigin::signed(4),
+					Scope::PalletA,
+					2
+				),
+				PermissionsError::<Runtime>::NoExpiredRoles
+			);
+		})
+}
+
+#[test]
+fn account_roles_lists_all_scopes() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::HeadOfSaubermaching),
+				2,
+				Scope::PalletB,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+			add_xcm_roles(2);
+
+			let mut roles = pallet_permissions::Pallet::<Runtime>::account_roles(2);
+			roles.sort_by_key(|(scope, _)| scope.encode());
+
+			assert_eq!(
+				roles,
+				vec![
+					(Scope::PalletA, Role::Xcm(XcmRole::Sender)),
+					(Scope::PalletA, Role::Xcm(XcmRole::Receiver)),
+					(Scope::PalletB, Role::Organisation(OrganisationRole::Admin)),
+				]
+			);
+			assert!(pallet_permissions::Pallet::<Runtime>::account_roles(3).is_empty());
+		})
+}
+
+#[test]
+fn on_idle_sweeps_expired_roles_within_budget() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			add_xcm_roles(2);
+			add_xcm_roles(3);
+			XcmRolesExpired::set(true);
+
+			let remaining = |account| {
+				pallet_permissions::Permission::<Runtime>::get(account, Scope::PalletA).is_some()
+			};
+
+			// The budget covers the cursor handling, a single entry and recording
+			// the consumed weight
+			let budget = Weight::from_parts(4, 0);
+
+			assert_eq!(
+				pallet_permissions::Pallet::<Runtime>::on_idle(0, budget),
+				budget
+			);
+			assert_eq!(
+				pallet_permissions::LastHookWeight::<Runtime>::get(),
+				Some((0, Weight::from_parts(3, 0)))
+			);
+			assert!(remaining(2) ^ remaining(3));
+			assert!(pallet_permissions::PurgeCursor::<Runtime>::get().is_some());
+
+			pallet_permissions::Pallet::<Runtime>::on_idle(0, budget);
+			assert!(!remaining(2) && !remaining(3));
+
+			// Iteration is exhausted, the next sweep starts over
+			pallet_permissions::Pallet::<Runtime>::on_idle(0, budget);
+			assert!(pallet_permissions::PurgeCursor::<Runtime>::get().is_none());
+
+			assert_eq!(
+				pallet_permissions::Pallet::<Runtime>::on_idle(0, Weight::from_parts(1, 0)),
+				Weight::zero()
+			);
+		})
+}
+
+fn add_admin_role(to: AccountId, role: OrganisationRole) {
+	assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+		RuntimeOrigin::signed(1),
+		Role::Organisation(OrganisationRole::HeadOfSaubermaching),
+		to,
+		Scope::PalletA,
+		Role::Organisation(role)
+	));
+}
+
+fn has(who: AccountId, scope: Scope, role: OrganisationRole) -> bool {
+	<pallet_permissions::Pallet<Runtime> as Permissions<AccountId>>::has(
+		scope,
+		who,
+		Role::Organisation(role),
+	)
+}
+
+#[test]
+fn delegate_works() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			add_admin_role(2, OrganisationRole::Admin);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				10
+			));
+
+			assert!(has(3, Scope::PalletA, OrganisationRole::Admin));
+			assert!(!has(3, Scope::PalletA, OrganisationRole::SeniorExeutive));
+			assert!(!has(3, Scope::PalletB, OrganisationRole::Admin));
+
+			// Delegated roles are not stored as regular roles
+			assert!(pallet_permissions::Permission::<Runtime>::get(3, Scope::PalletA).is_none());
+
+			Now::set(10);
+			assert!(has(3, Scope::PalletA, OrganisationRole::Admin));
+
+			Now::set(11);
+			assert!(!has(3, Scope::PalletA, OrganisationRole::Admin));
+		})
+}
+
+#[test]
+fn delegate_fails() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(2),
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::Admin),
+					3,
+					10
+				),
+				PermissionsError::<Runtime>::RoleNotGiven
+			);
+
+			add_admin_role(2, OrganisationRole::Admin);
+			add_admin_role(2, OrganisationRole::SeniorExeutive);
+			add_admin_role(2, OrganisationRole::HeadOfSaubermaching);
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(2),
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::Admin),
+					3,
+					MaxDelegationPeriod::get() + 1
+				),
+				PermissionsError::<Runtime>::InvalidDelegationPeriod
+			);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				10
+			));
+
+			// Delegated roles can not be delegated further
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(3),
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::Admin),
+					4,
+					10
+				),
+				PermissionsError::<Runtime>::RoleNotGiven
+			);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::SeniorExeutive),
+				3,
+				10
+			));
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(2),
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::HeadOfSaubermaching),
+					3,
+					10
+				),
+				PermissionsError::<Runtime>::TooManyDelegations
+			);
+
+			// Delegating again only extends the delegation
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				20
+			));
+		})
+}
+
+#[test]
+fn delegate_only_delegable_roles() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::HeadOfSaubermaching),
+				2,
+				Scope::PalletA,
+				Role::Xcm(XcmRole::Sender)
+			));
+			add_admin_role(2, OrganisationRole::SeniorExeutive);
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(2),
+					Scope::PalletA,
+					Role::Xcm(XcmRole::Sender),
+					3,
+					10
+				),
+				PermissionsError::<Runtime>::RoleNotDelegable
+			);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::SeniorExeutive),
+				3,
+				10
+			));
+
+			assert!(has(3, Scope::PalletA, OrganisationRole::SeniorExeutive));
+
+			// A stored delegation of a non delegable role grants nothing
+			pallet_permissions::Delegations::<Runtime>::mutate(3, Scope::PalletA, |delegations| {
+				delegations
+					.try_push(Delegation {
+						delegator: 2,
+						role: Role::Xcm(XcmRole::Sender),
+						until: 10,
+					})
+					.unwrap()
+			});
+
+			assert!(!<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::has(Scope::PalletA, 3, Role::Xcm(XcmRole::Sender)));
+		})
+}
+
+#[test]
+fn delegation_lapses_with_delegator_role() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			add_admin_role(2, OrganisationRole::Admin);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				10
+			));
+
+			assert_ok!(<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::remove(
+				Scope::PalletA,
+				2,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+
+			assert!(!has(3, Scope::PalletA, OrganisationRole::Admin));
+		})
+}
+
+#[test]
+fn revoke_delegation_works() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			add_admin_role(2, OrganisationRole::Admin);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+				RuntimeOrigin::signed(2),
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				10
+			));
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::revoke_delegation(
+					RuntimeOrigin::signed(4),
+					Scope::PalletA,
+					Role::Organisation(OrganisationRole::Admin),
+					3
+				),
+				PermissionsError::<Runtime>::NoDelegation
+			);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::revoke_delegation(
+				RuntimeOrigin::signed(2),
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin),
+				3
+			));
+
+			assert!(!has(3, Scope::PalletA, OrganisationRole::Admin));
+			assert!(!pallet_permissions::Delegations::<Runtime>::contains_key(
+				3,
+				Scope::PalletA
+			));
+		})
+}
+
+#[test]
+fn admin_purge_delegations_works() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			add_admin_role(2, OrganisationRole::Admin);
+			add_admin_role(4, OrganisationRole::SeniorExeutive);
+
+			for (from, role) in [
+				(2, OrganisationRole::Admin),
+				(4, OrganisationRole::SeniorExeutive),
+			] {
+				assert_ok!(pallet_permissions::Pallet::<Runtime>::delegate(
+					RuntimeOrigin::signed(from),
+					Scope::PalletA,
+					Role::Organisation(role),
+					3,
+					10
+				));
+			}
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::admin_purge_delegations(
+					RuntimeOrigin::signed(2),
+					3,
+					Scope::PalletA
+				),
+				PermissionsError::<Runtime>::NoEditor
+			);
+
+			assert_ok!(
+				pallet_permissions::Pallet::<Runtime>::admin_purge_delegations(
+					RuntimeOrigin::root(),
+					3,
+					Scope::PalletA
+				)
+			);
+
+			assert!(!has(3, Scope::PalletA, OrganisationRole::Admin));
+			assert!(!has(3, Scope::PalletA, OrganisationRole::SeniorExeutive));
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::admin_purge_delegations(
+					RuntimeOrigin::root(),
+					3,
+					Scope::PalletA
+				),
+				PermissionsError::<Runtime>::NoDelegation
+			);
+		})
+}
+
+#[test]
+fn batch_add_and_remove_work() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			let permissions: BoundedVec<_, MaxBatchSize> = vec![
+				(Scope::PalletA, 2, Role::Xcm(XcmRole::Sender)),
+				(Scope::PalletA, 3, Role::Xcm(XcmRole::Sender)),
+				(Scope::PalletB, 2, Role::Xcm(XcmRole::Receiver)),
+			]
+			.try_into()
+			.unwrap();
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::batch_add(
+				RuntimeOrigin::root(),
+				permissions.clone()
+			));
+
+			for (scope, who, role) in permissions.clone() {
+				assert!(<pallet_permissions::Pallet<Runtime> as Permissions<
+					AccountId,
+				>>::has(scope, who, role));
+			}
+			assert_eq!(
+				pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA),
+				Some(2)
+			);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::batch_remove(
+				RuntimeOrigin::root(),
+				permissions.clone()
+			));
+
+			for (scope, who, role) in permissions {
+				assert!(!<pallet_permissions::Pallet<Runtime> as Permissions<
+					AccountId,
+				>>::has(scope, who, role));
+			}
+			assert!(pallet_permissions::PermissionCount::<Runtime>::get(Scope::PalletA).is_none());
+		})
+}
+
+#[test]
+fn batch_add_fails() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			let permissions: BoundedVec<_, MaxBatchSize> = vec![
+				(Scope::PalletA, 2, Role::Xcm(XcmRole::Sender)),
+				(Scope::PalletA, 2, Role::Xcm(XcmRole::Sender)),
+			]
+			.try_into()
+			.unwrap();
+
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::batch_add(
+					RuntimeOrigin::signed(2),
+					permissions.clone()
+				),
+				PermissionsError::<Runtime>::NoEditor
+			);
+
+			// Nothing is added if any of the roles can not be added
+			assert_noop!(
+				pallet_permissions::Pallet::<Runtime>::batch_add(
+					RuntimeOrigin::root(),
+					permissions
+				),
+				PermissionsError::<Runtime>::RoleAlreadyGiven
+			);
+		})
+}
+
+#[test]
+fn permission_changes_are_recorded() {
+	TestExternalitiesBuilder::default()
+		.build(|| {})
+		.execute_with(|| {
+			System::set_block_number(1);
+			let reason = H256::repeat_byte(1);
+
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add(
+				RuntimeOrigin::root(),
+				Role::Organisation(OrganisationRole::Admin),
+				2,
+				Scope::PalletA,
+				Role::Organisation(OrganisationRole::Admin)
+			));
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::add_with_reason(
+				RuntimeOrigin::signed(2),
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				Scope::PalletA,
+				Role::Xcm(XcmRole::Sender),
+				reason
+			));
+
+			let editor = ChangeOrigin::Editor {
+				who: 2,
+				with_role: Role::Organisation(OrganisationRole::Admin),
+			};
+			System::assert_last_event(
+				pallet_permissions::Event::<Runtime>::Added {
+					to: 3,
+					scope: Scope::PalletA,
+					role: Role::Xcm(XcmRole::Sender),
+					origin: editor.clone(),
+					reason: Some(reason),
+				}
+				.into(),
+			);
+
+			System::set_block_number(2);
+			assert_ok!(pallet_permissions::Pallet::<Runtime>::remove(
+				RuntimeOrigin::signed(1),
+				Role::Organisation(OrganisationRole::Admin),
+				3,
+				Scope::PalletA,
+				Role::Xcm(XcmRole::Sender)
+			));
+			assert_ok!(<pallet_permissions::Pallet<Runtime> as Permissions<
+				AccountId,
+			>>::add(Scope::PalletA, 4, Role::Xcm(XcmRole::Receiver)));
+
+			// The oldest change was dropped
+			assert_eq!(
+				pallet_permissions::Pallet::<Runtime>::permission_changes(Scope::PalletA, 10),
+				vec![
+					PermissionChange {
+						kind: ChangeKind::Added,
+						account: 3,
+						role: Role::Xcm(XcmRole::Sender),
+						origin: editor,
+						reason: Some(reason),
+						block: 1,
+					},
+					PermissionChange {
+						kind: ChangeKind::Removed,
+						account: 3,
+						role: Role::Xcm(XcmRole::Sender),
+						origin: ChangeOrigin::Admin { who: Some(1) },
+						reason: None,
+						block: 2,
+					},
+					PermissionChange {
+						kind: ChangeKind::Added,
+						account: 4,
+						role: Role::Xcm(XcmRole::Receiver),
+						origin: ChangeOrigin::Internal,
+						reason: None,
+						block: 2,
+					},
+				]
+			);
+			assert_eq!(
+				pallet_permissions::Pallet::<Runtime>::permission_changes(Scope::PalletA, 1)
+					.into_iter()
+					.map(|change| change.account)
+					.collect::<Vec<_>>(),
+				vec![4]
+			);
+			assert!(
+				pallet_permissions::Pallet::<Runtime>::permission_changes(Scope::PalletB, 10)
+					.is_empty()
+			);
+		})
+}
@@ -20,6 +20,12 @@ pub trait WeightInfo {
 	fn remove_as_editor() -> Weight;
 	fn purge() -> Weight;
 	fn admin_purge() -> Weight;
+	fn purge_expired() -> Weight;
+	fn delegate() -> Weight;
+	fn revoke_delegation() -> Weight;
+	fn admin_purge_delegations() -> Weight;
+	fn batch_add(n: u32) -> Weight;
+	fn batch_remove(n: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -46,4 +52,28 @@ impl WeightInfo for () {
 	fn admin_purge() -> Weight {
 		Weight::zero()
 	}
+
+	fn purge_expired() -> Weight {
+		Weight::zero()
+	}
+
+	fn delegate() -> Weight {
+		Weight::zero()
+	}
+
+	fn revoke_delegation() -> Weight {
+		Weight::zero()
+	}
+
+	fn admin_purge_delegations() -> Weight {
+		Weight::zero()
+	}
+
+	fn batch_add(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn batch_remove(_: u32) -> Weight {
+		Weight::zero()
+	}
 }
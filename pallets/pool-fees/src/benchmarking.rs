@@ -21,7 +21,10 @@ use frame_benchmarking::v2::*;
 use frame_support::{assert_ok, dispatch::RawOrigin};
 
 use super::*;
-use crate::{types::Change, Pallet as PoolFees};
+use crate::{
+	types::{Change, FeeWaiver},
+	Pallet as PoolFees,
+};
 
 pub(crate) const CHARGE_AMOUNT: u128 = 1_000_000_000_000_000_000;
 pub(crate) const ACCOUNT_INDEX: u32 = 1_234;
@@ -147,6 +150,18 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_fee_waiver() -> Result<(), BenchmarkError> {
+		benchmark_setup::<T>(1);
+
+		let signer = RawOrigin::Signed(whitelisted_caller());
+
+		#[extrinsic_call]
+		set_fee_waiver(signer, 1.into(), Some(FeeWaiver { start: 0, end: 1 }));
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		PoolFees,
 		crate::mock::ExtBuilder::default().build(),
@@ -67,7 +67,7 @@ pub mod pallet {
 	use strum::IntoEnumIterator;
 
 	use super::*;
-	use crate::types::Change;
+	use crate::types::{Change, FeeWaiver};
 
 	pub type PoolFeeInfoOf<T> = PoolFeeInfo<
 		<T as frame_system::Config>::AccountId,
@@ -229,6 +229,22 @@ pub mod pallet {
 	pub(crate) type AssetsUnderManagement<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::PoolId, T::Balance, ValueQuery>;
 
+	/// Time window during which a fee does not accrue, declared by the pool
+	/// admin.
+	///
+	/// Lifetime of a storage entry: Until the fee is removed or the waiver is
+	/// cleared.
+	#[pallet::storage]
+	pub type FeeWaivers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FeeId, FeeWaiver, OptionQuery>;
+
+	/// Total amount a fee did not accrue because of its waiver.
+	///
+	/// Lifetime of a storage entry: Until the fee is removed.
+	#[pallet::storage]
+	pub type WaivedAmounts<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::FeeId, T::Balance, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -280,6 +296,18 @@ pub mod pallet {
 			pending: T::Balance,
 			disbursement: T::Balance,
 		},
+		/// The waiver of a pool fee was set or cleared.
+		WaiverSet {
+			pool_id: T::PoolId,
+			fee_id: T::FeeId,
+			waiver: Option<FeeWaiver>,
+		},
+		/// A pool fee did not accrue because of its waiver.
+		Waived {
+			pool_id: T::PoolId,
+			fee_id: T::FeeId,
+			amount: T::Balance,
+		},
 		/// The portfolio valuation for a pool was updated.
 		PortfolioValuationUpdated {
 			pool_id: T::PoolId,
@@ -312,6 +340,8 @@ pub mod pallet {
 		NothingCharged,
 		/// Attempted to uncharge with zero amount
 		NothingUncharged,
+		/// The end of a waiver window must be after its start.
+		InvalidWaiver,
 	}
 
 	#[pallet::call]
@@ -508,6 +538,42 @@ pub mod pallet {
 
 			Ok(Some(T::WeightInfo::update_portfolio_valuation(count)).into())
 		}
+
+		/// Set or clear the window during which a fee does not accrue.
+		///
+		/// Origin must be by pool admin.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_fee_waiver())]
+		pub fn set_fee_waiver(
+			origin: OriginFor<T>,
+			fee_id: T::FeeId,
+			waiver: Option<FeeWaiver>,
+		) -> DispatchResult {
+			let who = ensure_signed_or_root(origin)?;
+
+			let (pool_id, _) =
+				FeeIdsToPoolBucket::<T>::get(fee_id).ok_or(Error::<T>::FeeNotFound)?;
+			if let Some(signer) = who {
+				ensure!(
+					T::IsPoolAdmin::check((signer, pool_id)),
+					Error::<T>::NotPoolAdmin
+				);
+			}
+
+			if let Some(waiver) = &waiver {
+				ensure!(waiver.end > waiver.start, Error::<T>::InvalidWaiver);
+			}
+
+			FeeWaivers::<T>::set(fee_id, waiver.clone());
+
+			Self::deposit_event(Event::<T>::WaiverSet {
+				pool_id,
+				fee_id,
+				waiver,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -619,19 +685,42 @@ pub mod pallet {
 			assets_under_management: T::Balance,
 			epoch_duration: Seconds,
 		) -> Result<T::Balance, DispatchError> {
+			let now = T::Time::now();
+
 			ActiveFees::<T>::mutate(pool_id, bucket, |fees| {
 				for fee in fees.iter_mut() {
 					let limit = fee.amounts.limit();
+					let prorated_amount = |period: Seconds| {
+						<PoolFeeAmount<
+							<T as Config>::Balance,
+							<T as Config>::Rate,
+						> as FeeAmountProration<T::Balance, T::Rate, Seconds>>::saturated_prorated_amount(
+							limit,
+							assets_under_management,
+							period,
+						)
+					};
 
 					// Determine payable amount since last update based on epoch duration
-					let epoch_amount = <PoolFeeAmount<
-						<T as Config>::Balance,
-						<T as Config>::Rate,
-					> as FeeAmountProration<T::Balance, T::Rate, Seconds>>::saturated_prorated_amount(
-						limit,
-						assets_under_management,
-						epoch_duration,
-					);
+					let mut epoch_amount = prorated_amount(epoch_duration);
+
+					// Suppress the accrual during the waiver window
+					let waived_period = FeeWaivers::<T>::get(fee.id)
+						.map(|waiver| waiver.overlap(now, epoch_duration))
+						.unwrap_or_default();
+					if !waived_period.is_zero() {
+						let waived = prorated_amount(waived_period).min(epoch_amount);
+						epoch_amount.ensure_sub_assign(waived)?;
+
+						WaivedAmounts::<T>::mutate(fee.id, |amount| {
+							amount.saturating_accrue(waived)
+						});
+						Self::deposit_event(Event::<T>::Waived {
+							pool_id,
+							fee_id: fee.id,
+							amount: waived,
+						});
+					}
 
 					let fee_amount = match fee.amounts.payable {
 						PayableFeeAmount::UpTo(payable) => {
@@ -719,6 +808,9 @@ pub mod pallet {
 				Ok::<(), DispatchError>(())
 			})?;
 
+			FeeWaivers::<T>::remove(fee_id);
+			WaivedAmounts::<T>::remove(fee_id);
+
 			Ok(())
 		}
 
@@ -801,6 +893,19 @@ pub mod pallet {
 			Ok(fee_count.saturated_into())
 		}
 
+		/// Returns the total amount waived for each fee of a pool with a
+		/// waiver record.
+		pub fn get_waived_amounts(pool_id: T::PoolId) -> Vec<(T::FeeId, T::Balance)> {
+			PoolFeeBucket::iter()
+				.flat_map(|bucket| FeeIds::<T>::get(pool_id, bucket))
+				.filter_map(|fee_id| {
+					WaivedAmounts::<T>::try_get(fee_id)
+						.ok()
+						.map(|amount| (fee_id, amount))
+				})
+				.collect()
+		}
+
 		// Returns all fees of a pool divided by the buckets
 		pub fn get_pool_fees(
 			pool_id: T::PoolId,
@@ -4,11 +4,14 @@ use rand::Rng;
 use sp_arithmetic::FixedPointNumber;
 
 use super::*;
-use crate::mock::{
-	add_fees, assert_pending_fee, config_change_mocks, default_chargeable_fees, default_fees,
-	default_fixed_fee, new_fee, ExtBuilder, OrmlTokens, PoolFees, Runtime, RuntimeOrigin, System,
-	ADMIN, ANY, BUCKET, CHANGE_ID, DESTINATION, EDITOR, ERR_CHANGE_GUARD_RELEASE, NOT_ADMIN,
-	NOT_DESTINATION, NOT_EDITOR, POOL,
+use crate::{
+	mock::{
+		add_fees, assert_pending_fee, config_change_mocks, default_chargeable_fees, default_fees,
+		default_fixed_fee, new_fee, ExtBuilder, OrmlTokens, PoolFees, Runtime, RuntimeOrigin,
+		System, ADMIN, ANY, BUCKET, CHANGE_ID, DESTINATION, EDITOR, ERR_CHANGE_GUARD_RELEASE,
+		NOT_ADMIN, NOT_DESTINATION, NOT_EDITOR, POOL,
+	},
+	types::FeeWaiver,
 };
 
 mod extrinsics {
@@ -235,6 +238,32 @@ mod extrinsics {
 				}
 			})
 		}
+
+		#[test]
+		fn set_fee_waiver_works() {
+			ExtBuilder::default().build().execute_with(|| {
+				add_fees(vec![default_fixed_fee()]);
+				let waiver = FeeWaiver { start: 0, end: 100 };
+
+				assert_ok!(PoolFees::set_fee_waiver(
+					RuntimeOrigin::signed(ADMIN),
+					1,
+					Some(waiver.clone())
+				));
+				assert_eq!(FeeWaivers::<Runtime>::get(1), Some(waiver.clone()));
+				System::assert_last_event(
+					Event::<Runtime>::WaiverSet {
+						pool_id: POOL,
+						fee_id: 1,
+						waiver: Some(waiver),
+					}
+					.into(),
+				);
+
+				assert_ok!(PoolFees::set_fee_waiver(RuntimeOrigin::root(), 1, None));
+				assert_eq!(FeeWaivers::<Runtime>::get(1), None);
+			})
+		}
 	}
 
 	mod should_fail {
@@ -286,6 +315,54 @@ mod extrinsics {
 			})
 		}
 
+		#[test]
+		fn set_fee_waiver_wrong_origin() {
+			ExtBuilder::default().build().execute_with(|| {
+				add_fees(vec![default_fixed_fee()]);
+				MockIsAdmin::mock_check(|_| false);
+
+				for account in NOT_ADMIN {
+					assert_noop!(
+						PoolFees::set_fee_waiver(
+							RuntimeOrigin::signed(account),
+							1,
+							Some(FeeWaiver { start: 0, end: 100 })
+						),
+						Error::<Runtime>::NotPoolAdmin
+					);
+				}
+			})
+		}
+
+		#[test]
+		fn set_fee_waiver_invalid_window() {
+			ExtBuilder::default().build().execute_with(|| {
+				add_fees(vec![default_fixed_fee()]);
+
+				assert_noop!(
+					PoolFees::set_fee_waiver(
+						RuntimeOrigin::signed(ADMIN),
+						1,
+						Some(FeeWaiver {
+							start: 100,
+							end: 100
+						})
+					),
+					Error::<Runtime>::InvalidWaiver
+				);
+			})
+		}
+
+		#[test]
+		fn set_fee_waiver_missing_fee() {
+			ExtBuilder::default().build().execute_with(|| {
+				assert_noop!(
+					PoolFees::set_fee_waiver(RuntimeOrigin::signed(ADMIN), 1, None),
+					Error::<Runtime>::FeeNotFound
+				);
+			})
+		}
+
 		#[test]
 		fn apply_new_fee_changeguard_unreleased() {
 			ExtBuilder::default().build().execute_with(|| {
@@ -712,6 +789,53 @@ mod disbursements {
 				}
 			}
 
+			#[test]
+			fn waived_half_of_epoch_sfa() {
+				ExtBuilder::default().set_aum(NAV).build().execute_with(|| {
+					MockTime::mock_now(|| SECONDS_PER_YEAR * SECONDS);
+
+					let fee_id = 1;
+					let res_pre_fees: Balance = (2 * SECONDS_PER_YEAR).into();
+					let res_post_fees = &mut res_pre_fees.clone();
+					let waived_amount: Balance = (SECONDS_PER_YEAR / 2).into();
+
+					add_fees(vec![new_fee(PoolFeeType::Fixed {
+						limit: PoolFeeAmount::AmountPerSecond(1),
+					})]);
+					assert_ok!(PoolFees::set_fee_waiver(
+						RuntimeOrigin::signed(ADMIN),
+						fee_id,
+						Some(FeeWaiver {
+							start: 0,
+							end: SECONDS_PER_YEAR / 2,
+						})
+					));
+
+					assert_ok!(PoolFees::on_closing_mutate_reserve(
+						POOL,
+						NAV,
+						res_post_fees
+					));
+					System::assert_has_event(
+						Event::Waived {
+							pool_id: POOL,
+							fee_id,
+							amount: waived_amount,
+						}
+						.into(),
+					);
+
+					let fee_amount = Balance::from(SECONDS_PER_YEAR) - waived_amount;
+					assert_eq!(*res_post_fees, res_pre_fees - fee_amount);
+					assert_eq!(get_disbursements(), vec![fee_amount]);
+					assert_eq!(WaivedAmounts::<Runtime>::get(fee_id), waived_amount);
+					assert_eq!(
+						PoolFees::get_waived_amounts(POOL),
+						vec![(fee_id, waived_amount)]
+					);
+				});
+			}
+
 			#[test]
 			fn no_disbursement_without_prep_sfa() {
 				ExtBuilder::default().set_aum(NAV).build().execute_with(|| {
@@ -10,7 +10,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::fee::PoolFeeBucket;
+use cfg_traits::{fee::PoolFeeBucket, Seconds};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
@@ -23,3 +23,22 @@ use crate::{Config, PoolFeeInfoOf};
 pub enum Change<T: Config> {
 	AppendFee(T::FeeId, PoolFeeBucket, PoolFeeInfoOf<T>),
 }
+
+/// Time window during which a fee does not accrue.
+#[derive(Debug, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Clone)]
+pub struct FeeWaiver {
+	/// Start of the window, inclusive.
+	pub start: Seconds,
+
+	/// End of the window, exclusive.
+	pub end: Seconds,
+}
+
+impl FeeWaiver {
+	/// Seconds of the period ending at `now` with the given duration which
+	/// overlap the waiver window.
+	pub fn overlap(&self, now: Seconds, duration: Seconds) -> Seconds {
+		let from = now.saturating_sub(duration).max(self.start);
+		now.min(self.end).saturating_sub(from)
+	}
+}
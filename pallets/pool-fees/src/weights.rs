@@ -39,6 +39,7 @@ pub trait WeightInfo {
 	fn charge_fee(n: u32) -> Weight;
 	fn uncharge_fee(n: u32) -> Weight;
 	fn update_portfolio_valuation(n: u32) -> Weight;
+	fn set_fee_waiver() -> Weight;
 }
 
 /// Weights for pallet_pool_fees using the Substrate node and recommended
@@ -91,6 +92,13 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+
+	fn set_fee_waiver() -> Weight {
+		Weight::from_parts(24_315_000, 0)
+			.saturating_add(Weight::from_parts(0, 3693))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
 
 // For backwards compatibility and tests
@@ -141,4 +149,11 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(5))
 			.saturating_add(RocksDbWeight::get().writes(2))
 	}
+
+	fn set_fee_waiver() -> Weight {
+		Weight::from_parts(24_315_000, 0)
+			.saturating_add(Weight::from_parts(0, 3693))
+			.saturating_add(RocksDbWeight::get().reads(2))
+			.saturating_add(RocksDbWeight::get().writes(1))
+	}
 }
@@ -15,7 +15,7 @@
 use cfg_primitives::PoolEpochId;
 use cfg_traits::{benchmarking::PoolFeesBenchmarkHelper, fee::PoolFeeBucket};
 use cfg_types::{pools::TrancheMetadata, tokens::CurrencyId};
-use frame_benchmarking::benchmarks;
+use frame_benchmarking::{account, benchmarks};
 use frame_support::traits::fungibles::Inspect;
 use frame_system::RawOrigin;
 use orml_traits::Change;
@@ -29,6 +29,7 @@ use pallet_pool_system::{
 	pool_types::PoolChanges,
 	tranches::{TrancheIndex, TrancheInput, TrancheType, TrancheUpdate},
 };
+use sp_core::H256;
 use sp_runtime::{
 	traits::{One, Zero},
 	Perquintill,
@@ -223,6 +224,64 @@ benchmarks! {
 		let metadata: BoundedVec<u8, <T as Config>::MaxSizeMetadata> = metadata.try_into().unwrap();
 		assert_eq!(get_pool_metadata::<T>().metadata, metadata);
 	}
+
+	publish_notice {
+		let caller: <T as frame_system::Config>::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(2, 0, caller.clone())?;
+		Pools::<T>::insert(POOL, PoolRegistrationStatus::Registered);
+	}: publish_notice(RawOrigin::Signed(caller), POOL, H256::repeat_byte(1), true)
+	verify {
+		assert_eq!(Pallet::<T>::required_notice(POOL), Some(1));
+	}
+
+	acknowledge_notice {
+		let admin: <T as frame_system::Config>::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(2, 0, admin.clone())?;
+		Pools::<T>::insert(POOL, PoolRegistrationStatus::Registered);
+		Pallet::<T>::publish_notice(RawOrigin::Signed(admin).into(), POOL, H256::repeat_byte(1), true)?;
+		let investor = create_investor::<T>(0, TRANCHE, Some(1))?;
+	}: acknowledge_notice(RawOrigin::Signed(investor.clone()), POOL, 1)
+	verify {
+		assert!(Pallet::<T>::pending_acknowledgement(POOL, &investor).is_none());
+	}
+
+	set_investor_allowlist_root {
+		let caller: <T as frame_system::Config>::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(2, 0, caller.clone())?;
+		Pools::<T>::insert(POOL, PoolRegistrationStatus::Registered);
+		let tranche_id = get_tranche_id::<T>(TRANCHE);
+	}: set_investor_allowlist_root(RawOrigin::Signed(caller), POOL, tranche_id, Some(H256::repeat_byte(1)))
+	verify {
+		assert_eq!(Pallet::<T>::investor_allowlist_root(POOL, tranche_id), Some(H256::repeat_byte(1)));
+	}
+
+	register_investor {
+		let n in 0..T::MaxAllowlistProofLength::get();
+
+		let admin: <T as frame_system::Config>::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(2, 0, admin.clone())?;
+		let tranche_id = get_tranche_id::<T>(TRANCHE);
+
+		let investor: T::AccountId = account("investor", 0, 0);
+		let proof: BoundedVec<H256, T::MaxAllowlistProofLength> = (0..n)
+			.map(|i| H256::repeat_byte(i as u8))
+			.collect::<Vec<_>>()
+			.try_into()
+			.unwrap();
+		let root = Pallet::<T>::allowlist_root(Pallet::<T>::allowlist_leaf(&investor, u64::MAX), &proof);
+		InvestorAllowlistRoots::<T>::insert(POOL, tranche_id, root);
+	}: register_investor(RawOrigin::Signed(investor.clone()), POOL, tranche_id, u64::MAX, proof)
+	verify {
+		assert!(<T as Config>::Permission::has(
+			PermissionScope::Pool(POOL),
+			investor,
+			Role::PoolRole(PoolRole::TrancheInvestor(tranche_id, u64::MAX))
+		));
+	}
 }
 
 fn get_pool_metadata<T: Config<PoolId = u64>>() -> PoolMetadataOf<T> {
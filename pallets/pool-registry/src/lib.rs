@@ -15,12 +15,24 @@
 //!
 //! The Pool Registry pallet is used for creating, updating, and setting the
 //! metadata of pools.
+//!
+//! Pool admins can also publish notices for the investors of a pool, i.e. a
+//! NAV restatement. Only the hash of the notice is stored on-chain. Investors
+//! acknowledge notices on-chain, and notices can require an acknowledgement
+//! before the investors are allowed to perform sensitive actions in the pool.
+//!
+//! Instead of adding every tranche investor permission, pool admins can
+//! register the merkle root of an investor allowlist per tranche. Investors in
+//! the allowlist register their own permission by proving their membership.
+//! Leaves are the hash of the SCALE encoded `(account, valid_until)` pair and
+//! nodes the hash of their sorted children, both hashed with `BlakeTwo256`.
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::too_many_arguments)]
 
 use cfg_traits::{
 	fee::{PoolFeeBucket, PoolFeesInspect},
-	AssetMetadataOf, Permissions, PoolMutate, PoolWriteOffPolicyMutate, UpdateState,
+	AssetMetadataOf, Permissions, PoolMutate, PoolNotices, PoolWriteOffPolicyMutate, Seconds,
+	UpdateState,
 };
 use cfg_types::{
 	permissions::{PermissionScope, PoolRole, Role},
@@ -33,8 +45,9 @@ use orml_traits::asset_registry::{Inspect, Mutate};
 pub use pallet::*;
 use parity_scale_codec::{HasCompact, MaxEncodedLen};
 use scale_info::TypeInfo;
+use sp_core::H256;
 use sp_runtime::{
-	traits::{AtLeast32BitUnsigned, BadOrigin},
+	traits::{AtLeast32BitUnsigned, BadOrigin, BlakeTwo256, Hash},
 	FixedPointNumber, FixedPointOperand,
 };
 use sp_std::vec::Vec;
@@ -51,6 +64,25 @@ pub mod weights;
 
 type PoolMetadataOf<T> = PoolMetadata<<T as Config>::MaxSizeMetadata>;
 
+/// Identifier of a notice, sequential per pool.
+pub type NoticeId = u32;
+
+/// A notice published for the investors of a pool.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PoolNotice<BlockNumber> {
+	/// Hash of the notice content, which is stored off-chain.
+	pub hash: H256,
+
+	/// Block when the notice was published.
+	pub published_at: BlockNumber,
+
+	/// Whether investors need to acknowledge the notice before performing
+	/// sensitive actions in the pool.
+	pub requires_ack: bool,
+}
+
+type PoolNoticeOf<T> = PoolNotice<BlockNumberFor<T>>;
+
 type PoolChangesOf<T> = <<T as Config>::ModifyPool as cfg_traits::PoolMutate<
 	<T as frame_system::Config>::AccountId,
 	<T as Config>::PoolId,
@@ -157,6 +189,11 @@ pub mod pallet {
 		/// The source of truth for the pool fees counters;
 		type PoolFeesInspect: PoolFeesInspect<PoolId = Self::PoolId>;
 
+		/// Max number of hashes in an investor allowlist proof, i.e. the max
+		/// depth of the allowlist merkle tree.
+		#[pallet::constant]
+		type MaxAllowlistProofLength: Get<u32>;
+
 		/// Weight Information
 		type WeightInfo: WeightInfo;
 	}
@@ -174,6 +211,41 @@ pub mod pallet {
 	pub(crate) type Pools<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::PoolId, PoolRegistrationStatus>;
 
+	/// The last notice id used by each pool.
+	#[pallet::storage]
+	pub(crate) type NoticeNonce<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, NoticeId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn notice)]
+	pub(crate) type Notices<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PoolId, Twox64Concat, NoticeId, PoolNoticeOf<T>>;
+
+	/// The last notice of each pool requiring an acknowledgement.
+	#[pallet::storage]
+	#[pallet::getter(fn required_notice)]
+	pub(crate) type RequiredNotice<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, NoticeId>;
+
+	/// The block when an investor acknowledged a notice.
+	#[pallet::storage]
+	#[pallet::getter(fn acknowledgement)]
+	pub(crate) type Acknowledgements<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::PoolId>,
+			NMapKey<Twox64Concat, NoticeId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		BlockNumberFor<T>,
+	>;
+
+	/// The merkle root of the investor allowlist of a tranche.
+	#[pallet::storage]
+	#[pallet::getter(fn investor_allowlist_root)]
+	pub(crate) type InvestorAllowlistRoots<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::PoolId, Blake2_128Concat, T::TrancheId, H256>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -190,6 +262,33 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			metadata: BoundedVec<u8, T::MaxSizeMetadata>,
 		},
+		/// A notice was published for the investors of a pool.
+		NoticePublished {
+			pool_id: T::PoolId,
+			notice_id: NoticeId,
+			hash: H256,
+			requires_ack: bool,
+		},
+		/// An investor acknowledged a notice.
+		NoticeAcknowledged {
+			pool_id: T::PoolId,
+			notice_id: NoticeId,
+			who: T::AccountId,
+		},
+		/// The investor allowlist root of a tranche was set or removed.
+		InvestorAllowlistRootSet {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			root: Option<H256>,
+		},
+		/// An investor in the allowlist of a tranche registered as tranche
+		/// investor.
+		InvestorRegistered {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			who: T::AccountId,
+			valid_until: Seconds,
+		},
 	}
 
 	#[pallet::error]
@@ -206,6 +305,16 @@ pub mod pallet {
 		TrancheTokenNameTooLong,
 		/// The given tranche symbol name exceeds the length limit
 		TrancheSymbolNameTooLong,
+		/// The pool was not registered
+		PoolNotRegistered,
+		/// No notice found for the given pool and notice id
+		NoticeNotFound,
+		/// The notice was already acknowledged by the caller
+		NoticeAlreadyAcknowledged,
+		/// The tranche has no investor allowlist
+		NoInvestorAllowlist,
+		/// The proof does not match the investor allowlist root of the tranche
+		InvalidAllowlistProof,
 	}
 
 	#[pallet::call]
@@ -397,9 +506,195 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Publishes the hash of a notice for the investors of the pool.
+		///
+		/// If `requires_ack` is set, investors must acknowledge the notice
+		/// before performing sensitive actions in the pool, i.e. increasing
+		/// their investment after a fee change.
+		///
+		/// The caller must have the `PoolAdmin` role in order to
+		/// invoke this extrinsic.
+		#[pallet::weight(T::WeightInfo::publish_notice())]
+		#[pallet::call_index(4)]
+		pub fn publish_notice(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			hash: H256,
+			requires_ack: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who,
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				BadOrigin,
+			);
+			ensure!(
+				Pools::<T>::contains_key(pool_id),
+				Error::<T>::PoolNotRegistered
+			);
+
+			let notice_id = NoticeNonce::<T>::mutate(pool_id, |nonce| {
+				*nonce = nonce.saturating_add(1);
+				*nonce
+			});
+
+			Notices::<T>::insert(
+				pool_id,
+				notice_id,
+				PoolNotice {
+					hash,
+					published_at: frame_system::Pallet::<T>::block_number(),
+					requires_ack,
+				},
+			);
+
+			if requires_ack {
+				RequiredNotice::<T>::insert(pool_id, notice_id);
+			}
+
+			Self::deposit_event(Event::NoticePublished {
+				pool_id,
+				notice_id,
+				hash,
+				requires_ack,
+			});
+
+			Ok(())
+		}
+
+		/// Acknowledges a notice published for the pool.
+		#[pallet::weight(T::WeightInfo::acknowledge_notice())]
+		#[pallet::call_index(5)]
+		pub fn acknowledge_notice(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			notice_id: NoticeId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				Notices::<T>::contains_key(pool_id, notice_id),
+				Error::<T>::NoticeNotFound
+			);
+			ensure!(
+				!Acknowledgements::<T>::contains_key((pool_id, notice_id, &who)),
+				Error::<T>::NoticeAlreadyAcknowledged
+			);
+
+			Acknowledgements::<T>::insert(
+				(pool_id, notice_id, &who),
+				frame_system::Pallet::<T>::block_number(),
+			);
+
+			Self::deposit_event(Event::NoticeAcknowledged {
+				pool_id,
+				notice_id,
+				who,
+			});
+
+			Ok(())
+		}
+
+		/// Sets the merkle root of the investor allowlist of a tranche.
+		/// Passing `None` removes the allowlist. Permissions already
+		/// registered from a previous allowlist are kept.
+		///
+		/// The caller must have the `PoolAdmin` role in order to
+		/// invoke this extrinsic.
+		#[pallet::weight(T::WeightInfo::set_investor_allowlist_root())]
+		#[pallet::call_index(6)]
+		pub fn set_investor_allowlist_root(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			root: Option<H256>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				T::Permission::has(
+					PermissionScope::Pool(pool_id),
+					who,
+					Role::PoolRole(PoolRole::PoolAdmin)
+				),
+				BadOrigin,
+			);
+			ensure!(
+				Pools::<T>::contains_key(pool_id),
+				Error::<T>::PoolNotRegistered
+			);
+
+			InvestorAllowlistRoots::<T>::set(pool_id, tranche_id, root);
+
+			Self::deposit_event(Event::InvestorAllowlistRootSet {
+				pool_id,
+				tranche_id,
+				root,
+			});
+
+			Ok(())
+		}
+
+		/// Registers the caller as investor of a tranche until `valid_until`,
+		/// proving with `proof` that the pair is in the investor allowlist of
+		/// the tranche.
+		#[pallet::weight(T::WeightInfo::register_investor(proof.len() as u32))]
+		#[pallet::call_index(7)]
+		pub fn register_investor(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			valid_until: Seconds,
+			proof: BoundedVec<H256, T::MaxAllowlistProofLength>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let root = InvestorAllowlistRoots::<T>::get(pool_id, tranche_id)
+				.ok_or(Error::<T>::NoInvestorAllowlist)?;
+
+			ensure!(
+				Self::allowlist_root(Self::allowlist_leaf(&who, valid_until), &proof) == root,
+				Error::<T>::InvalidAllowlistProof
+			);
+
+			T::Permission::add(
+				PermissionScope::Pool(pool_id),
+				who.clone(),
+				Role::PoolRole(PoolRole::TrancheInvestor(tranche_id, valid_until)),
+			)?;
+
+			Self::deposit_event(Event::InvestorRegistered {
+				pool_id,
+				tranche_id,
+				who,
+				valid_until,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// The leaf of an investor in an allowlist.
+		pub fn allowlist_leaf(who: &T::AccountId, valid_until: Seconds) -> H256 {
+			BlakeTwo256::hash_of(&(who, valid_until))
+		}
+
+		/// The root of the allowlist containing `leaf`, given the sibling
+		/// hashes from the leaf up to the root.
+		pub fn allowlist_root(leaf: H256, proof: &[H256]) -> H256 {
+			proof.iter().fold(leaf, |node, sibling| {
+				if node <= *sibling {
+					BlakeTwo256::hash_of(&(node, sibling))
+				} else {
+					BlakeTwo256::hash_of(&(sibling, node))
+				}
+			})
+		}
+
 		pub(crate) fn do_set_metadata(pool_id: T::PoolId, metadata: Vec<u8>) -> DispatchResult {
 			let checked_metadata: BoundedVec<u8, T::MaxSizeMetadata> =
 				metadata.try_into().map_err(|_| Error::<T>::BadMetadata)?;
@@ -420,6 +715,15 @@ pub mod pallet {
 		}
 	}
 
+	impl<T: Config> PoolNotices<T::AccountId, T::PoolId> for Pallet<T> {
+		type NoticeId = NoticeId;
+
+		fn pending_acknowledgement(pool_id: T::PoolId, who: &T::AccountId) -> Option<NoticeId> {
+			RequiredNotice::<T>::get(pool_id)
+				.filter(|notice_id| !Acknowledgements::<T>::contains_key((pool_id, notice_id, who)))
+		}
+	}
+
 	impl<T: Config> cfg_traits::PoolMetadata<T::Balance, VersionedLocation> for Pallet<T> {
 		type AssetMetadata = AssetMetadataOf<T::AssetRegistry>;
 		type CustomMetadata = CustomMetadata;
@@ -39,13 +39,18 @@ use frame_support::{
 use frame_system::EnsureSigned;
 use orml_traits::{asset_registry::AssetMetadata, parameter_type_with_key};
 use pallet_pool_system::{
-	pool_types::{PoolChanges, PoolDetails, ScheduledUpdateDetails},
+	pool_types::{
+		changes::{Change as PoolChange, PoolChangeProposal},
+		PoolChanges, PoolDetails, ScheduledUpdateDetails,
+	},
 	tranches::TrancheInput,
 };
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{ConstU128, Zero},
-	BuildStorage,
+	BuildStorage, RuntimeDebug,
 };
 
 use crate::{self as pallet_pool_registry, Config};
@@ -94,6 +99,11 @@ parameter_types! {
 
 	pub const MinUpdateDelay: u64 = 0; // for testing purposes
 	pub const ChallengeTime: BlockNumber = 0;
+	pub const ChallengeTimeLowerBound: BlockNumber = 0;
+	pub const ChallengeTimeUpperBound: BlockNumber = 100;
+	#[derive(scale_info::TypeInfo, Eq, PartialEq, Debug, Clone, Copy )]
+	pub const MaxSolvers: u32 = 3;
+	pub const MaxPendingApprovals: u32 = 3;
 	// Defaults for pool parameters
 	pub const DefaultMinEpochTime: u64 = 1;
 	pub const DefaultMaxNAVAge: u64 = 24 * 60 * 60;
@@ -130,13 +140,38 @@ impl EnsureOriginWithArg<RuntimeOrigin, PoolId> for All {
 	}
 }
 
+/// Pool system changes, released without requirements
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RuntimeChange(PoolChange<Test>);
+
+impl From<PoolChange<Test>> for RuntimeChange {
+	fn from(change: PoolChange<Test>) -> Self {
+		RuntimeChange(change)
+	}
+}
+
+impl From<RuntimeChange> for PoolChange<Test> {
+	fn from(change: RuntimeChange) -> Self {
+		change.0
+	}
+}
+
+impl From<RuntimeChange> for PoolChangeProposal {
+	fn from(_: RuntimeChange) -> Self {
+		PoolChangeProposal::new([])
+	}
+}
+
 impl pallet_pool_system::Config for Test {
+	type ActivityRecorder = ();
 	type AdminOrigin = All;
 	type AssetRegistry = RegistryMock;
 	type AssetsUnderManagementNAV = FakeNav;
 	type Balance = Balance;
 	type BalanceRatio = Quantity;
 	type ChallengeTime = ChallengeTime;
+	type ChallengeTimeLowerBound = ChallengeTimeLowerBound;
+	type ChallengeTimeUpperBound = ChallengeTimeUpperBound;
 	type Currency = Balances;
 	type CurrencyId = CurrencyId;
 	type DefaultMaxNAVAge = DefaultMaxNAVAge;
@@ -144,6 +179,8 @@ impl pallet_pool_system::Config for Test {
 	type EpochId = PoolEpochId;
 	type Investments = Investments;
 	type MaxNAVAgeUpperBound = MaxNAVAgeUpperBound;
+	type MaxPendingApprovals = MaxPendingApprovals;
+	type MaxSolvers = MaxSolvers;
 	type MaxTranches = MaxTranches;
 	type MinEpochTimeLowerBound = MinEpochTimeLowerBound;
 	type MinEpochTimeUpperBound = MinEpochTimeUpperBound;
@@ -159,7 +196,7 @@ impl pallet_pool_system::Config for Test {
 	type PoolFeesNAV = PoolFees;
 	type PoolId = PoolId;
 	type Rate = Rate;
-	type RuntimeChange = pallet_pool_system::pool_types::changes::PoolChangeProposal;
+	type RuntimeChange = RuntimeChange;
 	type RuntimeEvent = RuntimeEvent;
 	type StringLimit = StringLimit;
 	type Time = Timestamp;
@@ -214,6 +251,7 @@ parameter_types! {
 	// Pool metadata limit
 	#[derive(scale_info::TypeInfo, Eq, PartialEq, Debug, Clone, Copy )]
 	pub const MaxSizeMetadata: u32 = 100;
+	pub const MaxAllowlistProofLength: u32 = 4;
 }
 
 pub struct ModifyPoolMock<T> {
@@ -307,6 +345,7 @@ impl Config for Test {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type InterestRate = Rate;
+	type MaxAllowlistProofLength = MaxAllowlistProofLength;
 	type MaxSizeMetadata = MaxSizeMetadata;
 	type MaxTranches = MaxTranches;
 	type ModifyPool = ModifyPoolMock<Self>;
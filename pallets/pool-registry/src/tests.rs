@@ -10,7 +10,7 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::{AssetMetadataOf, PoolMetadata};
+use cfg_traits::{AssetMetadataOf, PoolMetadata, PoolNotices};
 use cfg_types::pools::TrancheMetadata;
 use frame_support::{assert_noop, assert_ok, BoundedVec};
 use orml_traits::Change;
@@ -20,7 +20,12 @@ use pallet_pool_system::{
 };
 use staging_xcm::VersionedLocation;
 
-use crate::{mock::*, pallet, pallet::Error, Event, PoolMetadataOf};
+use crate::{
+	mock::*,
+	pallet,
+	pallet::{Acknowledgements, Error, Notices, Pools, RequiredNotice},
+	Event, PoolMetadataOf, PoolNotice,
+};
 
 fn find_metadata_event(pool_id: u64, metadata: BoundedVec<u8, MaxSizeMetadata>) -> Option<usize> {
 	System::events().iter().position(|e| match &e.event {
@@ -369,3 +374,282 @@ fn trait_pool_metadata_update_tranche_token_metadata() {
 			);
 		})
 }
+
+mod notices {
+	use cfg_types::pools::PoolRegistrationStatus;
+	use sp_core::H256;
+
+	use super::*;
+
+	const POOL_ID: u64 = 0;
+	const POOL_ADMIN: u64 = 0;
+	const INVESTOR: u64 = 1;
+	const HASH: H256 = H256::repeat_byte(1);
+
+	fn publish(requires_ack: bool) {
+		assert_ok!(PoolRegistry::publish_notice(
+			RuntimeOrigin::signed(POOL_ADMIN),
+			POOL_ID,
+			HASH,
+			requires_ack,
+		));
+	}
+
+	#[test]
+	fn publish_notice() {
+		TestExternalitiesBuilder::default()
+			.build()
+			.execute_with(|| {
+				Pools::<Test>::insert(POOL_ID, PoolRegistrationStatus::Registered);
+
+				publish(false);
+				publish(true);
+
+				assert_eq!(
+					Notices::<Test>::get(POOL_ID, 1),
+					Some(PoolNotice {
+						hash: HASH,
+						published_at: 1,
+						requires_ack: false,
+					})
+				);
+				assert_eq!(RequiredNotice::<Test>::get(POOL_ID), Some(2));
+				System::assert_last_event(RuntimeEvent::PoolRegistry(Event::NoticePublished {
+					pool_id: POOL_ID,
+					notice_id: 2,
+					hash: HASH,
+					requires_ack: true,
+				}));
+
+				// A notice without acknowledgement does not replace the required one
+				publish(false);
+				assert_eq!(RequiredNotice::<Test>::get(POOL_ID), Some(2));
+			})
+	}
+
+	#[test]
+	fn publish_notice_for_unregistered_pool() {
+		TestExternalitiesBuilder::default()
+			.build()
+			.execute_with(|| {
+				assert_noop!(
+					PoolRegistry::publish_notice(
+						RuntimeOrigin::signed(POOL_ADMIN),
+						POOL_ID,
+						HASH,
+						true,
+					),
+					Error::<Test>::PoolNotRegistered
+				);
+			})
+	}
+
+	#[test]
+	fn acknowledge_notice() {
+		TestExternalitiesBuilder::default()
+			.build()
+			.execute_with(|| {
+				Pools::<Test>::insert(POOL_ID, PoolRegistrationStatus::Registered);
+				publish(true);
+
+				assert_eq!(
+					PoolRegistry::pending_acknowledgement(POOL_ID, &INVESTOR),
+					Some(1)
+				);
+
+				assert_ok!(PoolRegistry::acknowledge_notice(
+					RuntimeOrigin::signed(INVESTOR),
+					POOL_ID,
+					1,
+				));
+
+				assert_eq!(
+					Acknowledgements::<Test>::get((POOL_ID, 1, INVESTOR)),
+					Some(1)
+				);
+				assert_eq!(
+					PoolRegistry::pending_acknowledgement(POOL_ID, &INVESTOR),
+					None
+				);
+
+				assert_noop!(
+					PoolRegistry::acknowledge_notice(RuntimeOrigin::signed(INVESTOR), POOL_ID, 1),
+					Error::<Test>::NoticeAlreadyAcknowledged
+				);
+
+				// A new required notice must be acknowledged again
+				publish(true);
+				assert_eq!(
+					PoolRegistry::pending_acknowledgement(POOL_ID, &INVESTOR),
+					Some(2)
+				);
+			})
+	}
+
+	#[test]
+	fn acknowledge_unknown_notice() {
+		TestExternalitiesBuilder::default()
+			.build()
+			.execute_with(|| {
+				assert_noop!(
+					PoolRegistry::acknowledge_notice(RuntimeOrigin::signed(INVESTOR), POOL_ID, 1),
+					Error::<Test>::NoticeNotFound
+				);
+			})
+	}
+}
+
+mod investor_allowlist {
+	use cfg_primitives::TrancheId;
+	use cfg_types::pools::PoolRegistrationStatus;
+	use sp_core::H256;
+
+	use super::*;
+	use crate::pallet::InvestorAllowlistRoots;
+
+	const POOL_ID: u64 = 0;
+	const POOL_ADMIN: u64 = 0;
+	const INVESTOR: u64 = 1;
+	const TRANCHE_ID: TrancheId = [0; 16];
+	const VALID_UNTIL: u64 = 1_000;
+
+	/// Builds an allowlist with the investor and 3 other accounts, returning
+	/// the root and the proof of the investor.
+	fn allowlist() -> (H256, Vec<H256>) {
+		let leaves: Vec<H256> = (INVESTOR..INVESTOR + 4)
+			.map(|who| PoolRegistry::allowlist_leaf(&who, VALID_UNTIL))
+			.collect();
+
+		let right = PoolRegistry::allowlist_root(leaves[2], &[leaves[3]]);
+		let proof = vec![leaves[1], right];
+
+		(PoolRegistry::allowlist_root(leaves[0], &proof), proof)
+	}
+
+	fn set_root(root: H256) {
+		assert_ok!(PoolRegistry::set_investor_allowlist_root(
+			RuntimeOrigin::signed(POOL_ADMIN),
+			POOL_ID,
+			TRANCHE_ID,
+			Some(root),
+		));
+	}
+
+	#[test]
+	fn set_investor_allowlist_root() {
+		TestExternalitiesBuilder::default()
+			.build()
+			.execute_with(|| {
+				assert_noop!(
+					PoolRegistry::set_investor_allowlist_root(
+						RuntimeOrigin::signed(POOL_ADMIN),
+						POOL_ID,
+						TRANCHE_ID,
+						Some(H256::repeat_byte(1)),
+					),
+					Error::<Test>::PoolNotRegistered
+				);
+
+				Pools::<Test>::insert(POOL_ID, PoolRegistrationStatus::Registered);
+				set_root(H256::repeat_byte(1));
+
+				assert_eq!(
+					InvestorAllowlistRoots::<Test>::get(POOL_ID, TRANCHE_ID),
+					Some(H256::repeat_byte(1))
+				);
+
+				assert_ok!(PoolRegistry::set_investor_allowlist_root(
+					RuntimeOrigin::signed(POOL_ADMIN),
+					POOL_ID,
+					TRANCHE_ID,
+					None,
+				));
+
+				assert!(!InvestorAllowlistRoots::<Test>::contains_key(
+					POOL_ID, TRANCHE_ID
+				));
+				System::assert_last_event(RuntimeEvent::PoolRegistry(
+					Event::InvestorAllowlistRootSet {
+						pool_id: POOL_ID,
+						tranche_id: TRANCHE_ID,
+						root: None,
+					},
+				));
+			})
+	}
+
+	#[test]
+	fn register_investor() {
+		TestExternalitiesBuilder::default()
+			.build()
+			.execute_with(|| {
+				Pools::<Test>::insert(POOL_ID, PoolRegistrationStatus::Registered);
+
+				let (root, proof) = allowlist();
+				set_root(root);
+
+				assert_ok!(PoolRegistry::register_investor(
+					RuntimeOrigin::signed(INVESTOR),
+					POOL_ID,
+					TRANCHE_ID,
+					VALID_UNTIL,
+					proof.try_into().unwrap(),
+				));
+
+				System::assert_last_event(RuntimeEvent::PoolRegistry(Event::InvestorRegistered {
+					pool_id: POOL_ID,
+					tranche_id: TRANCHE_ID,
+					who: INVESTOR,
+					valid_until: VALID_UNTIL,
+				}));
+			})
+	}
+
+	#[test]
+	fn register_investor_with_invalid_proof() {
+		TestExternalitiesBuilder::default()
+			.build()
+			.execute_with(|| {
+				Pools::<Test>::insert(POOL_ID, PoolRegistrationStatus::Registered);
+
+				let (root, proof) = allowlist();
+
+				assert_noop!(
+					PoolRegistry::register_investor(
+						RuntimeOrigin::signed(INVESTOR),
+						POOL_ID,
+						TRANCHE_ID,
+						VALID_UNTIL,
+						proof.clone().try_into().unwrap(),
+					),
+					Error::<Test>::NoInvestorAllowlist
+				);
+
+				set_root(root);
+
+				// The proof is only valid for the allowlisted validity
+				assert_noop!(
+					PoolRegistry::register_investor(
+						RuntimeOrigin::signed(INVESTOR),
+						POOL_ID,
+						TRANCHE_ID,
+						VALID_UNTIL + 1,
+						proof.clone().try_into().unwrap(),
+					),
+					Error::<Test>::InvalidAllowlistProof
+				);
+
+				// The proof is only valid for the allowlisted account
+				assert_noop!(
+					PoolRegistry::register_investor(
+						RuntimeOrigin::signed(INVESTOR + 4),
+						POOL_ID,
+						TRANCHE_ID,
+						VALID_UNTIL,
+						proof.try_into().unwrap(),
+					),
+					Error::<Test>::InvalidAllowlistProof
+				);
+			})
+	}
+}
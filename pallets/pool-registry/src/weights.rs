@@ -19,6 +19,10 @@ pub trait WeightInfo {
 	fn update_and_execute(n: u32, m: u32) -> Weight;
 	fn execute_update(n: u32, m: u32) -> Weight;
 	fn set_metadata(n: u32, m: u32) -> Weight;
+	fn publish_notice() -> Weight;
+	fn acknowledge_notice() -> Weight;
+	fn set_investor_allowlist_root() -> Weight;
+	fn register_investor(n: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -41,4 +45,20 @@ impl WeightInfo for () {
 	fn set_metadata(_n: u32, _m: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn publish_notice() -> Weight {
+		Weight::zero()
+	}
+
+	fn acknowledge_notice() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_investor_allowlist_root() -> Weight {
+		Weight::zero()
+	}
+
+	fn register_investor(_n: u32) -> Weight {
+		Weight::zero()
+	}
 }
@@ -15,6 +15,7 @@
 use cfg_primitives::PoolEpochId;
 use cfg_traits::{
 	benchmarking::PoolFeesBenchmarkHelper,
+	changes::ChangeGuard,
 	fee::{PoolFeeBucket, PoolFeesInspect},
 	UpdateState,
 };
@@ -26,6 +27,7 @@ use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
 use frame_support::traits::Currency;
 use frame_system::RawOrigin;
 use parity_scale_codec::EncodeLike;
+use sp_runtime::traits::TrailingZeroInput;
 use sp_std::vec;
 
 use super::*;
@@ -197,6 +199,90 @@ benchmarks! {
 		assert_eq!(get_pool::<T>().epoch.current, 2);
 		assert!(Pallet::<T>::epoch_targets(POOL).is_none());
 	}
+
+	propose_epoch_settings {
+		let n in 1..T::MaxSolvers::get();
+
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+		let settings = build_epoch_settings::<T>(n);
+	}: propose_epoch_settings(RawOrigin::Signed(admin), POOL, settings.clone())
+	verify {
+		assert_eq!(ScheduledPoolEpochSettings::<T>::get(POOL).unwrap().settings, settings);
+	}
+
+	apply_epoch_settings {
+		let n in 1..T::MaxSolvers::get();
+
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+		let settings = build_epoch_settings::<T>(n);
+		Pallet::<T>::propose_epoch_settings(RawOrigin::Signed(admin.clone()).into(), POOL, settings.clone())?;
+	}: apply_epoch_settings(RawOrigin::Signed(admin), POOL)
+	verify {
+		assert_eq!(PoolEpochSettings::<T>::get(POOL), Some(settings));
+	}
+
+	set_pool_mode {
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+	}: set_pool_mode(RawOrigin::Signed(admin), POOL, PoolMode::Continuous)
+	verify {
+		assert_eq!(PoolModes::<T>::get(POOL), PoolMode::Continuous);
+	}
+
+	set_change_approver {
+		let n in 0..T::MaxPendingApprovals::get();
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+		note_pending_approvals::<T>(n);
+		let approver: T::AccountId = account("approver", 0, 0);
+	}: set_change_approver(RawOrigin::Signed(admin), POOL, Some(approver.clone()))
+	verify {
+		assert_eq!(ChangeApprover::<T>::get(POOL), Some(approver));
+		assert_eq!(ChangeApprovals::<T>::iter_prefix(POOL).count(), 0);
+	}
+
+	approve_change {
+		let n in 0..T::MaxPendingApprovals::get().saturating_sub(1);
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+		let approver: T::AccountId = account("approver", 0, 0);
+		Pallet::<T>::set_change_approver(RawOrigin::Signed(admin.clone()).into(), POOL, Some(approver))?;
+		note_pending_approvals::<T>(n);
+
+		let change = T::RuntimeChange::decode(&mut TrailingZeroInput::zeroes())
+			.expect("A zeroed change is decodable");
+		let change_id = Pallet::<T>::note(POOL, change)?;
+	}: approve_change(RawOrigin::Signed(admin), POOL, change_id)
+	verify {
+		assert!(ChangeApprovals::<T>::get(POOL, change_id).admin);
+	}
+
+	propose_tranche_fees {
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+		let tranche_id = get_tranche_id::<T>(TRANCHE);
+		let fees = bench_tranche_fees();
+	}: propose_tranche_fees(RawOrigin::Signed(admin), POOL, tranche_id, fees)
+
+	apply_tranche_fees {
+		let admin: T::AccountId = create_admin::<T>(0);
+		prepare_asset_registry::<T>();
+		create_pool::<T>(1, 0, admin.clone())?;
+		let tranche_id = get_tranche_id::<T>(TRANCHE);
+		let fees = bench_tranche_fees();
+		let change_id = Pallet::<T>::note(POOL, PoolChange::TrancheFees(tranche_id, fees).into())?;
+	}: apply_tranche_fees(RawOrigin::Signed(admin), POOL, change_id)
+	verify {
+		assert_eq!(PoolTrancheFees::<T>::get(POOL, tranche_id), fees);
+	}
 }
 
 pub fn prepare_asset_registry<T: Config>()
@@ -234,6 +320,27 @@ pub fn unrestrict_epoch_close<T: Config<PoolId = u64>>() {
 	});
 }
 
+/// Stores approvals for `n` changes of the benchmarked pool
+fn note_pending_approvals<T: Config<PoolId = u64>>(n: u32) {
+	for i in 0..n {
+		ChangeApprovals::<T>::insert(
+			POOL,
+			T::Hashing::hash_of(&i),
+			ChangeApproval {
+				admin: true,
+				approver: false,
+			},
+		);
+	}
+}
+
+fn bench_tranche_fees() -> TrancheFees {
+	TrancheFees {
+		entry: Perquintill::from_percent(1),
+		exit: Perquintill::from_percent(1),
+	}
+}
+
 pub fn get_pool<T: Config<PoolId = u64>>() -> PoolDetailsOf<T> {
 	Pallet::<T>::pool(POOL).unwrap()
 }
@@ -355,6 +462,21 @@ pub fn assert_update_tranches_match<T: Config>(
 	}
 }
 
+pub fn build_epoch_settings<T: Config>(num_solvers: u32) -> EpochSettingsOf<T> {
+	let solvers = (0..num_solvers)
+		.map(|i| account::<T::AccountId>("solver", i, 0))
+		.collect::<sp_std::collections::btree_set::BTreeSet<_>>();
+
+	EpochSettings {
+		challenge_time: T::ChallengeTimeUpperBound::get(),
+		solvers: Some(
+			solvers
+				.try_into()
+				.expect("num_solvers is bounded by MaxSolvers. qed."),
+		),
+	}
+}
+
 pub fn build_bench_input_tranches<T: Config>(
 	num_tranches: u32,
 ) -> Vec<TrancheInput<T::Rate, T::StringLimit>> {
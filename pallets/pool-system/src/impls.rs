@@ -11,6 +11,7 @@
 // GNU General Public License for more details.
 
 use cfg_traits::{
+	activity::{ActivityKind, ActivityRecorder},
 	changes::ChangeGuard,
 	fee::{PoolFeeBucket, PoolFeesMutate},
 	investments::{InvestmentAccountant, TrancheCurrency},
@@ -165,6 +166,7 @@ impl<T: Config> PoolMutate<T::AccountId, T::PoolId> for Pallet<T> {
 		};
 
 		Pool::<T>::insert(pool_id, pool_details.clone());
+		T::ActivityRecorder::record(ActivityKind::Pool, &pool_id);
 
 		// For SubQuery, pool creation event should be dispatched before related events
 		let ids: Vec<T::TrancheCurrency> = tranches
@@ -234,9 +236,7 @@ impl<T: Config> PoolMutate<T::AccountId, T::PoolId> for Pallet<T> {
 		{
 			// If there's an existing update, we remove it
 			// If not, this transaction is a no-op
-			if ScheduledUpdate::<T>::contains_key(pool_id) {
-				ScheduledUpdate::<T>::remove(pool_id);
-			}
+			Self::remove_scheduled_update(pool_id);
 
 			return Ok(UpdateState::NoExecution);
 		}
@@ -258,6 +258,8 @@ impl<T: Config> PoolMutate<T::AccountId, T::PoolId> for Pallet<T> {
 
 		let pool = Pool::<T>::try_get(pool_id).map_err(|_| Error::<T>::NoSuchPool)?;
 
+		Self::ensure_tranches_supported_by_mode(pool_id, &changes)?;
+
 		if let Change::NewValue(tranches) = &changes.tranches {
 			Self::is_valid_tranche_change(Some(&pool.tranches), tranches)?;
 		}
@@ -269,15 +271,28 @@ impl<T: Config> PoolMutate<T::AccountId, T::PoolId> for Pallet<T> {
 			submitted_at: now,
 		};
 
+		let approved = Self::update_requirements_fulfilled(pool_id, &pool, &changes);
+
 		let num_tranches = pool.tranches.num_tranches().try_into().unwrap();
-		if T::MinUpdateDelay::get() == 0 && T::UpdateGuard::released(&pool, &update, now) {
+		if T::MinUpdateDelay::get() == 0
+			&& T::UpdateGuard::released(&pool, &update, now)
+			&& approved
+		{
 			Self::do_update_pool(&pool_id, &changes)?;
 
 			Ok(UpdateState::Executed(num_tranches))
 		} else {
 			// If an update was already stored, this will override it
+			Self::remove_scheduled_update(pool_id);
 			ScheduledUpdate::<T>::insert(pool_id, update);
 
+			if !approved {
+				Self::deposit_event(Event::UpdateRequiresApproval {
+					pool_id,
+					change_id: Self::update_change_id(&changes),
+				});
+			}
+
 			Ok(UpdateState::Stored(num_tranches))
 		}
 	}
@@ -300,10 +315,15 @@ impl<T: Config> PoolMutate<T::AccountId, T::PoolId> for Pallet<T> {
 		);
 
 		ensure!(
-			T::UpdateGuard::released(&pool, &update, now),
+			T::UpdateGuard::released(&pool, &update, now)
+				&& Self::update_requirements_fulfilled(pool_id, &pool, &update.changes),
 			Error::<T>::UpdatePrerequesitesNotFulfilled
 		);
 
+		// The pool could have been switched to continuous mode after the update
+		// was scheduled
+		Self::ensure_tranches_supported_by_mode(pool_id, &update.changes)?;
+
 		Self::do_update_pool(&pool_id, &update.changes)?;
 
 		let num_tranches = pool.tranches.num_tranches().try_into().unwrap();
@@ -404,6 +424,7 @@ impl<T: Config> ChangeGuard for Pallet<T> {
 			change,
 		};
 		NotedChange::<T>::insert(pool_id, change_id, noted_change.clone());
+		ChangeApprovals::<T>::remove(pool_id, change_id);
 
 		Self::deposit_event(Event::ProposedChange {
 			pool_id,
@@ -436,12 +457,17 @@ impl<T: Config> ChangeGuard for Pallet<T> {
 					T::Time::now().saturating_sub(submitted_time) >= secs as u64
 				}
 				Requirement::BlockedByLockedRedemptions => true, // TODO: #1407
+				Requirement::DualApproval => {
+					!ChangeApprover::<T>::contains_key(pool_id)
+						|| ChangeApprovals::<T>::get(pool_id, change_id).is_complete()
+				}
 			}
 		}
 
 		let change = allowed
 			.then(|| {
 				NotedChange::<T>::remove(pool_id, change_id);
+				ChangeApprovals::<T>::remove(pool_id, change_id);
 				change
 			})
 			.ok_or(Error::<T>::ChangeNotReady)?;
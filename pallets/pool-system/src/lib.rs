@@ -22,6 +22,7 @@ use frame_support::{
 	dispatch::DispatchResult,
 	ensure,
 	pallet_prelude::RuntimeDebug,
+	storage::with_transaction,
 	traits::{
 		fungibles::{Inspect, Mutate},
 		ReservableCurrency,
@@ -36,26 +37,30 @@ use orml_traits::{
 pub use pallet::*;
 use parity_scale_codec::{Decode, Encode, HasCompact, MaxEncodedLen};
 use pool_types::{
-	changes::{NotedPoolChange, PoolChangeProposal},
-	PoolChanges, PoolDepositInfo, PoolDetails, PoolEssence, PoolLocator, ScheduledUpdateDetails,
+	changes::{
+		Change as PoolChange, ChangeApproval, NotedPoolChange, PoolChangeProposal, Requirement,
+	},
+	EpochPreview, EpochSettings, PoolChanges, PoolDepositInfo, PoolDetails, PoolEssence,
+	PoolLocator, PoolMode, ScheduledEpochSettings, ScheduledUpdateDetails, TrancheFees,
 };
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
 pub use solution::*;
-use sp_arithmetic::traits::BaseArithmetic;
+use sp_arithmetic::{traits::BaseArithmetic, PerThing, Rounding};
 use sp_runtime::{
 	traits::{
 		AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, CheckedSub, EnsureAdd,
-		EnsureAddAssign, EnsureFixedPointNumber, EnsureSub, EnsureSubAssign, Get, One, Saturating,
-		Zero,
+		EnsureAddAssign, EnsureFixedPointNumber, EnsureSub, EnsureSubAssign, Get, Hash, One,
+		Saturating, Zero,
 	},
 	DispatchError, FixedPointNumber, FixedPointOperand, Perquintill, TokenError,
+	TransactionOutcome,
 };
 use sp_std::{cmp::Ordering, vec::Vec};
 use tranches::{
-	EpochExecutionTranche, EpochExecutionTranches, Tranche, TrancheSolution, TrancheType,
-	TrancheUpdate, Tranches,
+	EpochExecutionTranche, EpochExecutionTranches, Tranche, TrancheLoc, TrancheSolution,
+	TrancheType, TrancheUpdate, Tranches,
 };
 pub use weights::*;
 
@@ -148,6 +153,18 @@ type ScheduledUpdateDetailsOf<T> = ScheduledUpdateDetails<
 pub type PoolChangesOf<T> =
 	PoolChanges<<T as Config>::Rate, <T as Config>::StringLimit, <T as Config>::MaxTranches>;
 
+pub type EpochSettingsOf<T> = EpochSettings<
+	BlockNumberFor<T>,
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::MaxSolvers,
+>;
+
+type ScheduledEpochSettingsOf<T> = ScheduledEpochSettings<
+	BlockNumberFor<T>,
+	<T as frame_system::Config>::AccountId,
+	<T as Config>::MaxSolvers,
+>;
+
 pub type PoolEssenceOf<T> = PoolEssence<
 	<T as Config>::CurrencyId,
 	<T as Config>::Balance,
@@ -172,6 +189,8 @@ impl Default for Release {
 #[frame_support::pallet]
 pub mod pallet {
 	use cfg_traits::{
+		activity::{ActivityKind, ActivityRecorder},
+		changes::ChangeGuard,
 		fee::{PoolFeeBucket, PoolFeesInspect, PoolFeesMutate},
 		investments::{OrderManager, TrancheCurrency as TrancheCurrencyT},
 		EpochTransitionHook, PoolUpdateGuard,
@@ -270,7 +289,13 @@ pub mod pallet {
 
 		type CurrencyId: Parameter + Copy + MaxEncodedLen;
 
-		type RuntimeChange: Parameter + Member + MaxEncodedLen + TypeInfo + Into<PoolChangeProposal>;
+		type RuntimeChange: Parameter
+			+ Member
+			+ MaxEncodedLen
+			+ TypeInfo
+			+ Into<PoolChangeProposal>
+			+ From<PoolChange<Self>>
+			+ TryInto<PoolChange<Self>>;
 
 		type PoolCurrency: Contains<Self::CurrencyId>;
 
@@ -344,6 +369,21 @@ pub mod pallet {
 		#[pallet::constant]
 		type ChallengeTime: Get<BlockNumberFor<Self>>;
 
+		/// Bounds for the challenge time configured per pool
+		#[pallet::constant]
+		type ChallengeTimeLowerBound: Get<BlockNumberFor<Self>>;
+
+		#[pallet::constant]
+		type ChallengeTimeUpperBound: Get<BlockNumberFor<Self>>;
+
+		/// Max number of solvers that can be whitelisted for a pool
+		#[pallet::constant]
+		type MaxSolvers: Get<u32> + Member + scale_info::TypeInfo;
+
+		/// Max number of changes of a pool holding approvals at the same time
+		#[pallet::constant]
+		type MaxPendingApprovals: Get<u32>;
+
 		/// Pool parameter defaults
 		#[pallet::constant]
 		type DefaultMinEpochTime: Get<Seconds>;
@@ -379,6 +419,9 @@ pub mod pallet {
 		/// The origin permitted to create pools
 		type PoolCreateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Records the pools touched in a block
+		type ActivityRecorder: ActivityRecorder;
+
 		/// Weight Information
 		type WeightInfo: WeightInfo;
 	}
@@ -412,6 +455,20 @@ pub mod pallet {
 	#[pallet::getter(fn pool_deposits)]
 	pub type PoolDeposit<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, PoolDepositOf<T>>;
 
+	/// Epoch settings of pools which do not use the runtime defaults
+	#[pallet::storage]
+	pub type PoolEpochSettings<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, EpochSettingsOf<T>>;
+
+	#[pallet::storage]
+	pub type ScheduledPoolEpochSettings<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, ScheduledEpochSettingsOf<T>>;
+
+	/// Execution mode of each pool. Pools default to epoch based execution.
+	#[pallet::storage]
+	pub type PoolModes<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::PoolId, PoolMode, ValueQuery>;
+
 	#[pallet::storage]
 	pub type NotedChange<T: Config> = StorageDoubleMap<
 		_,
@@ -422,6 +479,35 @@ pub mod pallet {
 		NotedPoolChange<T::RuntimeChange>,
 	>;
 
+	/// Account which has to approve, together with the pool admin, the changes
+	/// of a pool that require dual approval.
+	#[pallet::storage]
+	pub type ChangeApprover<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, T::AccountId>;
+
+	/// Approvals given to the noted changes of a pool.
+	#[pallet::storage]
+	pub type ChangeApprovals<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::Hash,
+		ChangeApproval,
+		ValueQuery,
+	>;
+
+	/// Entry and exit fees of the tranches charging them.
+	#[pallet::storage]
+	pub type PoolTrancheFees<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::TrancheId,
+		TrancheFees,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -470,6 +556,40 @@ pub mod pallet {
 			change_id: T::Hash,
 			change: T::RuntimeChange,
 		},
+		/// The change approver of a pool was set or removed.
+		ChangeApproverSet {
+			pool_id: T::PoolId,
+			approver: Option<T::AccountId>,
+		},
+		/// A scheduled pool update needs to be approved by both the pool
+		/// admin and the change approver before being executed.
+		UpdateRequiresApproval {
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		},
+		/// A change was approved by the pool admin or the change approver.
+		ChangeApproved {
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+			approval: ChangeApproval,
+		},
+		/// New epoch settings were proposed for a pool.
+		EpochSettingsProposed {
+			pool_id: T::PoolId,
+			settings: EpochSettingsOf<T>,
+		},
+		/// The epoch settings of a pool were updated.
+		EpochSettingsUpdated {
+			pool_id: T::PoolId,
+			settings: EpochSettingsOf<T>,
+		},
+		/// The execution mode of a pool was changed.
+		PoolModeSet { pool_id: T::PoolId, mode: PoolMode },
+		/// The outstanding orders of a continuous pool were executed.
+		ContinuousOrdersExecuted {
+			pool_id: T::PoolId,
+			epoch_id: T::EpochId,
+		},
 		/// The PoolFeesNAV exceeds the sum of the AUM and the total reserve of
 		/// the pool
 		NegativeBalanceSheet {
@@ -478,6 +598,20 @@ pub mod pallet {
 			nav_fees: T::Balance,
 			reserve: T::Balance,
 		},
+		/// The entry and exit fees of a tranche were updated.
+		TrancheFeesUpdated {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			fees: TrancheFees,
+		},
+		/// Entry and exit fees were charged to the investors of a tranche
+		/// whose orders were fulfilled.
+		TrancheFeesCharged {
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			entry_fee: T::Balance,
+			exit_fee: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -556,6 +690,28 @@ pub mod pallet {
 		ChangeNotFound,
 		/// The external change was found for is not ready yet to be released.
 		ChangeNotReady,
+		/// The change approver of a pool can not be a pool admin
+		ApproverIsPoolAdmin,
+		/// The account is not whitelisted to submit solutions for the pool
+		NotWhitelistedSolver,
+		/// No epoch settings for the pool are scheduled
+		NoScheduledEpochSettings,
+		/// A solver whitelist must contain at least one account
+		EmptySolverWhitelist,
+		/// Continuous execution is only supported for single tranche pools
+		ContinuousModeRequiresSingleTranche,
+		/// The operation is not available for pools in continuous mode
+		PoolInContinuousMode,
+		/// The operation is only available for pools in continuous mode
+		PoolNotInContinuousMode,
+		/// Executing the orders would leave the pool in an unhealthy state
+		UnhealthyContinuousExecution,
+		/// Entry and exit fees must be lower than 100%
+		InvalidTrancheFees,
+		/// The change id does not correspond to a tranche fees change
+		NoTrancheFeesChangeId,
+		/// Approving the change would exceed the pending approvals of the pool
+		TooManyPendingApprovals,
 	}
 
 	#[pallet::call]
@@ -617,6 +773,7 @@ pub mod pallet {
 		#[pallet::call_index(1)]
 		pub fn close_epoch(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResultWithPostInfo {
 			T::AdminOrigin::ensure_origin(origin, &pool_id)?;
+			T::ActivityRecorder::record(ActivityKind::Pool, &pool_id);
 
 			Pool::<T>::try_mutate(pool_id, |pool| {
 				let pool = pool.as_mut().ok_or(Error::<T>::NoSuchPool)?;
@@ -624,6 +781,10 @@ pub mod pallet {
 					!EpochExecution::<T>::contains_key(pool_id),
 					Error::<T>::InSubmissionPeriod
 				);
+				ensure!(
+					PoolModes::<T>::get(pool_id) == PoolMode::Epoch,
+					Error::<T>::PoolInContinuousMode
+				);
 
 				let now = T::Time::now();
 				ensure!(
@@ -631,42 +792,7 @@ pub mod pallet {
 					Error::<T>::MinEpochTimeHasNotPassed
 				);
 
-				// Get positive NAV from AUM
-				let (nav_aum, aum_last_updated) =
-					T::AssetsUnderManagementNAV::nav(pool_id).ok_or(Error::<T>::NoNAV)?;
-				ensure!(
-					now.saturating_sub(aum_last_updated) <= pool.parameters.max_nav_age,
-					Error::<T>::NAVTooOld
-				);
-
-				// Calculate fees to get negative NAV
-				T::OnEpochTransition::on_closing_mutate_reserve(
-					pool_id,
-					nav_aum,
-					&mut pool.reserve.total,
-				)?;
-				let (nav_fees, fees_last_updated) =
-					T::PoolFeesNAV::nav(pool_id).ok_or(Error::<T>::NoNAV)?;
-				ensure!(
-					now.saturating_sub(fees_last_updated) <= pool.parameters.max_nav_age,
-					Error::<T>::NAVTooOld
-				);
-				let nav = Nav::new(nav_aum, nav_fees);
-				let nav_total = nav
-					.total(pool.reserve.total)
-					// NOTE: From an accounting perspective, erroring out would be correct. However,
-					// since investments of this epoch are included in the reserve only in the next
-					// epoch, every new pool with a configured fee is likely to be blocked if we
-					// threw an error here. Thus, we dispatch an event as a defensive workaround.
-					.map_err(|_| {
-						Self::deposit_event(Event::NegativeBalanceSheet {
-							pool_id,
-							nav_aum,
-							nav_fees,
-							reserve: pool.reserve.total,
-						});
-					})
-					.unwrap_or(T::Balance::default());
+				let (nav, nav_total) = Self::calculate_nav(pool_id, pool, now)?;
 				let submission_period_epoch = pool.epoch.current;
 
 				pool.start_next_epoch(now)?;
@@ -697,12 +823,21 @@ pub mod pallet {
 					pool.tranches.combine_with_mut_residual_top(
 						&epoch_tranche_prices,
 						|tranche, price| {
-							let zero_fulfillment = FulfillmentWithPrice {
-								of_amount: Perquintill::zero(),
-								price: *price,
-							};
-							T::Investments::invest_fulfillment(tranche.currency, zero_fulfillment)?;
-							T::Investments::redeem_fulfillment(tranche.currency, zero_fulfillment)
+							let fees = Self::tranche_fees(&tranche.currency);
+							T::Investments::invest_fulfillment(
+								tranche.currency,
+								FulfillmentWithPrice {
+									of_amount: Perquintill::zero(),
+									price: fees.invest_price(*price)?,
+								},
+							)?;
+							T::Investments::redeem_fulfillment(
+								tranche.currency,
+								FulfillmentWithPrice {
+									of_amount: Perquintill::zero(),
+									price: fees.redeem_price(*price)?,
+								},
+							)
 						},
 					)?;
 
@@ -723,34 +858,13 @@ pub mod pallet {
 					.into());
 				}
 
-				let epoch_tranches: Vec<EpochExecutionTrancheOf<T>> =
-					pool.tranches.combine_with_residual_top(
-						epoch_tranche_prices
-							.iter()
-							.zip(orders.invest_redeem_residual_top()),
-						|tranche, (price, (invest, redeem))| {
-							let epoch_tranche = EpochExecutionTranche {
-								currency: tranche.currency,
-								supply: tranche.balance()?,
-								price: *price,
-								invest,
-								redeem,
-								seniority: tranche.seniority,
-								min_risk_buffer: tranche.min_risk_buffer(),
-								_phantom: Default::default(),
-							};
-
-							Ok(epoch_tranche)
-						},
-					)?;
-
-				let mut epoch = EpochExecutionInfo {
+				let mut epoch = Self::epoch_execution_info(
+					pool,
 					nav,
-					epoch: submission_period_epoch,
-					tranches: EpochExecutionTranches::new(epoch_tranches),
-					best_submission: None,
-					challenge_period_end: None,
-				};
+					submission_period_epoch,
+					&epoch_tranche_prices,
+					&orders,
+				)?;
 
 				let full_execution_solution = pool.tranches.combine_residual_top(|_| {
 					Ok(TrancheSolution {
@@ -823,7 +937,13 @@ pub mod pallet {
 			pool_id: T::PoolId,
 			solution: Vec<TrancheSolution>,
 		) -> DispatchResultWithPostInfo {
-			ensure_signed(origin)?;
+			let who = ensure_signed(origin)?;
+			T::ActivityRecorder::record(ActivityKind::Pool, &pool_id);
+
+			let settings = Self::epoch_settings(pool_id);
+			if let Some(solvers) = &settings.solvers {
+				ensure!(solvers.contains(&who), Error::<T>::NotWhitelistedSolver);
+			}
 
 			EpochExecution::<T>::try_mutate(pool_id, |epoch| {
 				let epoch = epoch.as_mut().ok_or(Error::<T>::NotInSubmissionPeriod)?;
@@ -842,7 +962,7 @@ pub mod pallet {
 				// Challenge period starts when the first new solution has been submitted
 				if epoch.challenge_period_end.is_none() {
 					epoch.challenge_period_end =
-						Some(Self::current_block().saturating_add(T::ChallengeTime::get()));
+						Some(Self::current_block().saturating_add(settings.challenge_time));
 				}
 
 				Self::deposit_event(Event::SolutionSubmitted {
@@ -880,6 +1000,7 @@ pub mod pallet {
 			pool_id: T::PoolId,
 		) -> DispatchResultWithPostInfo {
 			T::AdminOrigin::ensure_origin(origin, &pool_id)?;
+			T::ActivityRecorder::record(ActivityKind::Pool, &pool_id);
 
 			EpochExecution::<T>::try_mutate(pool_id, |epoch_info| {
 				let epoch = epoch_info
@@ -944,6 +1065,327 @@ pub mod pallet {
 				.into())
 			})
 		}
+
+		/// Propose new epoch settings for a pool
+		///
+		/// The settings define the challenge time of the pool and
+		/// optionally restrict the accounts that can submit
+		/// solutions. They can be applied with
+		/// `apply_epoch_settings` once `MinUpdateDelay` has passed.
+		/// A previously proposed change is overwritten.
+		#[pallet::weight(T::WeightInfo::propose_epoch_settings(T::MaxSolvers::get()))]
+		#[pallet::call_index(4)]
+		pub fn propose_epoch_settings(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			settings: EpochSettingsOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin, &pool_id)?;
+
+			ensure!(Pool::<T>::contains_key(pool_id), Error::<T>::NoSuchPool);
+			ensure!(
+				settings.challenge_time >= T::ChallengeTimeLowerBound::get()
+					&& settings.challenge_time <= T::ChallengeTimeUpperBound::get(),
+				Error::<T>::PoolParameterBoundViolated
+			);
+			ensure!(
+				settings
+					.solvers
+					.as_ref()
+					.map_or(true, |solvers| !solvers.is_empty()),
+				Error::<T>::EmptySolverWhitelist
+			);
+
+			ScheduledPoolEpochSettings::<T>::insert(
+				pool_id,
+				ScheduledEpochSettings {
+					settings: settings.clone(),
+					submitted_at: T::Time::now(),
+				},
+			);
+
+			Self::deposit_event(Event::EpochSettingsProposed { pool_id, settings });
+
+			Ok(())
+		}
+
+		/// Apply the epoch settings proposed for a pool
+		///
+		/// Callable by any signed account once `MinUpdateDelay`
+		/// has passed since the settings were proposed. While the
+		/// pool is in a submission period only the pool admin can
+		/// apply them, e.g. to replace a solver whitelist whose
+		/// solvers do not submit solutions.
+		#[pallet::weight(T::WeightInfo::apply_epoch_settings(T::MaxSolvers::get()))]
+		#[pallet::call_index(5)]
+		pub fn apply_epoch_settings(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			if EpochExecution::<T>::contains_key(pool_id) {
+				T::AdminOrigin::ensure_origin(origin, &pool_id)
+					.map_err(|_| Error::<T>::InSubmissionPeriod)?;
+			} else {
+				ensure_signed(origin)?;
+			}
+
+			let scheduled = ScheduledPoolEpochSettings::<T>::get(pool_id)
+				.ok_or(Error::<T>::NoScheduledEpochSettings)?;
+
+			ensure!(
+				T::Time::now()
+					>= scheduled
+						.submitted_at
+						.ensure_add(T::MinUpdateDelay::get())?,
+				Error::<T>::ScheduledTimeHasNotPassed
+			);
+
+			ScheduledPoolEpochSettings::<T>::remove(pool_id);
+			PoolEpochSettings::<T>::insert(pool_id, scheduled.settings.clone());
+
+			Self::deposit_event(Event::EpochSettingsUpdated {
+				pool_id,
+				settings: scheduled.settings,
+			});
+
+			Ok(())
+		}
+
+		/// Set the execution mode of a pool
+		///
+		/// In `Continuous` mode the epoch and solution machinery is
+		/// bypassed: orders are executed immediately against the
+		/// reserve via `execute_continuous_orders`. Only single
+		/// tranche pools can be switched to this mode and the pool
+		/// must not be in a submission period.
+		#[pallet::weight(T::WeightInfo::set_pool_mode())]
+		#[pallet::call_index(6)]
+		pub fn set_pool_mode(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			mode: PoolMode,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin, &pool_id)?;
+
+			let pool = Pool::<T>::get(pool_id).ok_or(Error::<T>::NoSuchPool)?;
+			ensure!(
+				!EpochExecution::<T>::contains_key(pool_id),
+				Error::<T>::InSubmissionPeriod
+			);
+
+			if mode == PoolMode::Continuous {
+				ensure!(
+					pool.tranches.num_tranches() == 1,
+					Error::<T>::ContinuousModeRequiresSingleTranche
+				);
+			}
+
+			PoolModes::<T>::insert(pool_id, mode);
+
+			Self::deposit_event(Event::PoolModeSet { pool_id, mode });
+
+			Ok(())
+		}
+
+		/// Execute the outstanding orders of a continuous pool
+		///
+		/// Callable by any signed account. The tranche price is
+		/// derived from the current NAV, all invest orders are
+		/// fulfilled and redeem orders are fulfilled as far as the
+		/// reserve allows. The execution fails if it would violate
+		/// the maximum reserve or leave the tranche wiped out.
+		#[pallet::weight(T::WeightInfo::close_epoch_execute(
+			T::MaxTranches::get(),
+			T::PoolFees::get_max_fees_per_bucket()
+		))]
+		#[transactional]
+		#[pallet::call_index(7)]
+		pub fn execute_continuous_orders(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			T::ActivityRecorder::record(ActivityKind::Pool, &pool_id);
+
+			ensure!(
+				PoolModes::<T>::get(pool_id) == PoolMode::Continuous,
+				Error::<T>::PoolNotInContinuousMode
+			);
+
+			Pool::<T>::try_mutate(pool_id, |pool| {
+				let pool = pool.as_mut().ok_or(Error::<T>::NoSuchPool)?;
+				Self::do_execute_continuous(pool_id, pool)
+			})
+		}
+
+		/// Set or remove the change approver of a pool
+		///
+		/// Changes requiring dual approval are only released once
+		/// approved by both the pool admin and the change approver.
+		/// Without a change approver, the pool admin alone governs
+		/// them. An approver can be set by the pool admin, but once
+		/// set, it can only be replaced or removed by itself or by
+		/// root. Replacing the approver discards previous approvals.
+		#[pallet::weight(T::WeightInfo::set_change_approver(T::MaxPendingApprovals::get()))]
+		#[pallet::call_index(8)]
+		pub fn set_change_approver(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			approver: Option<T::AccountId>,
+		) -> DispatchResult {
+			match ChangeApprover::<T>::get(pool_id) {
+				Some(current) => {
+					if ensure_root(origin.clone()).is_err() {
+						ensure!(ensure_signed(origin)? == current, BadOrigin);
+					}
+				}
+				None => {
+					T::AdminOrigin::ensure_origin(origin, &pool_id)?;
+				}
+			}
+
+			ensure!(Pool::<T>::contains_key(pool_id), Error::<T>::NoSuchPool);
+
+			if let Some(approver) = &approver {
+				ensure!(
+					!T::Permission::has(
+						PermissionScope::Pool(pool_id),
+						approver.clone(),
+						Role::PoolRole(PoolRole::PoolAdmin)
+					),
+					Error::<T>::ApproverIsPoolAdmin
+				);
+			}
+
+			ChangeApprover::<T>::set(pool_id, approver.clone());
+			// Approvals are bounded by `MaxPendingApprovals`, so all of them are
+			// removed at once
+			let _ =
+				ChangeApprovals::<T>::clear_prefix(pool_id, T::MaxPendingApprovals::get(), None);
+
+			Self::deposit_event(Event::ChangeApproverSet { pool_id, approver });
+
+			Ok(())
+		}
+
+		/// Approve a noted change or a scheduled pool update
+		///
+		/// Callable by the change approver of the pool, or by the
+		/// pool admin otherwise. The approval is needed to release
+		/// changes requiring dual approval. A scheduled pool update
+		/// is identified by [`Pallet::update_change_id`]. At most
+		/// `MaxPendingApprovals` changes of a pool hold approvals.
+		#[pallet::weight(T::WeightInfo::approve_change(T::MaxPendingApprovals::get()))]
+		#[pallet::call_index(9)]
+		pub fn approve_change(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			let is_approver = match (
+				ensure_signed(origin.clone()),
+				ChangeApprover::<T>::get(pool_id),
+			) {
+				(Ok(who), Some(approver)) => who == approver,
+				_ => false,
+			};
+
+			if !is_approver {
+				T::AdminOrigin::ensure_origin(origin, &pool_id)?;
+			}
+
+			ensure!(
+				NotedChange::<T>::contains_key(pool_id, change_id)
+					|| ScheduledUpdate::<T>::get(pool_id)
+						.map(|update| Self::update_change_id(&update.changes))
+						== Some(change_id),
+				Error::<T>::ChangeNotFound
+			);
+
+			if !ChangeApprovals::<T>::contains_key(pool_id, change_id) {
+				let max = T::MaxPendingApprovals::get() as usize;
+				ensure!(
+					ChangeApprovals::<T>::iter_key_prefix(pool_id)
+						.take(max)
+						.count() < max,
+					Error::<T>::TooManyPendingApprovals
+				);
+			}
+
+			let approval = ChangeApprovals::<T>::mutate(pool_id, change_id, |approval| {
+				if is_approver {
+					approval.approver = true;
+				} else {
+					approval.admin = true;
+				}
+				approval.clone()
+			});
+
+			Self::deposit_event(Event::ChangeApproved {
+				pool_id,
+				change_id,
+				approval,
+			});
+
+			Ok(())
+		}
+
+		/// Propose new entry and exit fees for a tranche
+		///
+		/// The fees are charged to the investors of the tranche when
+		/// their orders are fulfilled and remain in the pool reserve.
+		/// They are only updated once the change is released through
+		/// `apply_tranche_fees`.
+		#[pallet::weight(T::WeightInfo::propose_tranche_fees())]
+		#[pallet::call_index(10)]
+		pub fn propose_tranche_fees(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			tranche_id: T::TrancheId,
+			fees: TrancheFees,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin, &pool_id)?;
+
+			let pool = Pool::<T>::get(pool_id).ok_or(Error::<T>::NoSuchPool)?;
+			ensure!(
+				pool.tranches
+					.tranche_index(&TrancheLoc::Id(tranche_id))
+					.is_some(),
+				Error::<T>::InvalidTrancheId
+			);
+			ensure!(fees.is_valid(), Error::<T>::InvalidTrancheFees);
+
+			Self::note(pool_id, PoolChange::TrancheFees(tranche_id, fees).into())?;
+
+			Ok(())
+		}
+
+		/// Apply the tranche fees previously proposed by
+		/// `propose_tranche_fees` once the change is ready to be released.
+		///
+		/// This call is permissionless.
+		#[pallet::weight(T::WeightInfo::apply_tranche_fees())]
+		#[pallet::call_index(11)]
+		pub fn apply_tranche_fees(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			change_id: T::Hash,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let Ok(PoolChange::TrancheFees(tranche_id, fees)) =
+				Self::released(pool_id, change_id)?.try_into()
+			else {
+				return Err(Error::<T>::NoTrancheFeesChangeId.into());
+			};
+
+			PoolTrancheFees::<T>::set(pool_id, tranche_id, fees);
+
+			Self::deposit_event(Event::TrancheFeesUpdated {
+				pool_id,
+				tranche_id,
+				fees,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
@@ -951,6 +1393,332 @@ pub mod pallet {
 			<frame_system::Pallet<T>>::block_number()
 		}
 
+		/// Entry and exit fees of the tranche of a tranche currency.
+		pub fn tranche_fees(currency: &T::TrancheCurrency) -> TrancheFees {
+			PoolTrancheFees::<T>::get(currency.of_pool(), currency.of_tranche())
+		}
+
+		/// Epoch settings of the pool, falling back to the runtime
+		/// `ChallengeTime` and no solver restrictions if none were set.
+		pub fn epoch_settings(pool_id: T::PoolId) -> EpochSettingsOf<T> {
+			PoolEpochSettings::<T>::get(pool_id).unwrap_or(EpochSettings {
+				challenge_time: T::ChallengeTime::get(),
+				solvers: None,
+			})
+		}
+
+		/// Ensures a tranche change keeps a pool in continuous mode with a
+		/// single tranche, the only structure continuous execution supports.
+		pub(crate) fn ensure_tranches_supported_by_mode(
+			pool_id: T::PoolId,
+			changes: &PoolChangesOf<T>,
+		) -> DispatchResult {
+			if let Change::NewValue(tranches) = &changes.tranches {
+				ensure!(
+					PoolModes::<T>::get(pool_id) == PoolMode::Epoch || tranches.len() == 1,
+					Error::<T>::ContinuousModeRequiresSingleTranche
+				);
+			}
+
+			Ok(())
+		}
+
+		/// Identifier used to approve a pool update.
+		pub fn update_change_id(changes: &PoolChangesOf<T>) -> T::Hash {
+			T::Hashing::hash(&changes.encode())
+		}
+
+		/// Requirements of a pool update. Changing the interest or the
+		/// seniority of a tranche requires dual approval.
+		pub(crate) fn update_requirements(
+			pool: &PoolDetailsOf<T>,
+			changes: &PoolChangesOf<T>,
+		) -> PoolChangeProposal {
+			let mut requirements = Vec::new();
+
+			if let Change::NewValue(tranches) = &changes.tranches {
+				let current = pool.tranches.residual_top_slice();
+				let changes_economics = tranches.len() != current.len()
+					|| tranches.iter().zip(current).enumerate().any(
+						|(index, (update, tranche))| {
+							update.tranche_type != tranche.tranche_type
+								|| update.seniority.or_else(|| index.try_into().ok())
+									!= Some(tranche.seniority)
+						},
+					);
+
+				if changes_economics {
+					requirements.push(Requirement::DualApproval);
+				}
+			}
+
+			PoolChangeProposal::new(requirements)
+		}
+
+		/// Whether the requirements of a pool update are fulfilled.
+		pub(crate) fn update_requirements_fulfilled(
+			pool_id: T::PoolId,
+			pool: &PoolDetailsOf<T>,
+			changes: &PoolChangesOf<T>,
+		) -> bool {
+			Self::update_requirements(pool, changes)
+				.requirements()
+				.all(|requirement| match requirement {
+					Requirement::DualApproval => {
+						!ChangeApprover::<T>::contains_key(pool_id)
+							|| ChangeApprovals::<T>::get(pool_id, Self::update_change_id(changes))
+								.is_complete()
+					}
+					_ => true,
+				})
+		}
+
+		/// Removes the scheduled update of a pool along with its approvals.
+		pub(crate) fn remove_scheduled_update(pool_id: T::PoolId) {
+			if let Some(update) = ScheduledUpdate::<T>::take(pool_id) {
+				ChangeApprovals::<T>::remove(pool_id, Self::update_change_id(&update.changes));
+			}
+		}
+
+		/// Number of pools in epoch mode whose minimum epoch time has passed
+		/// without their epoch being closed.
+		pub fn overdue_epochs_count() -> u32 {
+			let now = T::Time::now();
+
+			Pool::<T>::iter()
+				.filter(|(pool_id, pool)| {
+					PoolModes::<T>::get(pool_id) == PoolMode::Epoch
+						&& !EpochExecution::<T>::contains_key(pool_id)
+						&& now.saturating_sub(pool.epoch.last_closed)
+							>= pool.parameters.min_epoch_time
+				})
+				.count() as u32
+		}
+
+		/// Computes the NAV of the pool and its total value including the
+		/// reserve, after applying the epoch transition hook to the reserve.
+		fn calculate_nav(
+			pool_id: T::PoolId,
+			pool: &mut PoolDetailsOf<T>,
+			now: Seconds,
+		) -> Result<(Nav<T::Balance>, T::Balance), DispatchError> {
+			// Get positive NAV from AUM
+			let (nav_aum, aum_last_updated) =
+				T::AssetsUnderManagementNAV::nav(pool_id).ok_or(Error::<T>::NoNAV)?;
+			ensure!(
+				now.saturating_sub(aum_last_updated) <= pool.parameters.max_nav_age,
+				Error::<T>::NAVTooOld
+			);
+
+			// Calculate fees to get negative NAV
+			T::OnEpochTransition::on_closing_mutate_reserve(
+				pool_id,
+				nav_aum,
+				&mut pool.reserve.total,
+			)?;
+			let (nav_fees, fees_last_updated) =
+				T::PoolFeesNAV::nav(pool_id).ok_or(Error::<T>::NoNAV)?;
+			ensure!(
+				now.saturating_sub(fees_last_updated) <= pool.parameters.max_nav_age,
+				Error::<T>::NAVTooOld
+			);
+			let nav = Nav::new(nav_aum, nav_fees);
+			let nav_total = nav
+				.total(pool.reserve.total)
+				// NOTE: From an accounting perspective, erroring out would be correct. However,
+				// since investments of this epoch are included in the reserve only in the next
+				// epoch, every new pool with a configured fee is likely to be blocked if we
+				// threw an error here. Thus, we dispatch an event as a defensive workaround.
+				.map_err(|_| {
+					Self::deposit_event(Event::NegativeBalanceSheet {
+						pool_id,
+						nav_aum,
+						nav_fees,
+						reserve: pool.reserve.total,
+					});
+				})
+				.unwrap_or(T::Balance::default());
+
+			Ok((nav, nav_total))
+		}
+
+		fn epoch_execution_info(
+			pool: &PoolDetailsOf<T>,
+			nav: Nav<T::Balance>,
+			epoch_id: T::EpochId,
+			prices: &[T::BalanceRatio],
+			orders: &SummarizedOrders<T::Balance>,
+		) -> Result<EpochExecutionInfoOf<T>, DispatchError> {
+			let epoch_tranches: Vec<EpochExecutionTrancheOf<T>> =
+				pool.tranches.combine_with_residual_top(
+					prices.iter().zip(orders.invest_redeem_residual_top()),
+					|tranche, (price, (invest, redeem))| {
+						let epoch_tranche = EpochExecutionTranche {
+							currency: tranche.currency,
+							supply: tranche.balance()?,
+							price: *price,
+							invest,
+							redeem,
+							seniority: tranche.seniority,
+							min_risk_buffer: tranche.min_risk_buffer(),
+							_phantom: Default::default(),
+						};
+
+						Ok(epoch_tranche)
+					},
+				)?;
+
+			Ok(EpochExecutionInfo {
+				nav,
+				epoch: epoch_id,
+				tranches: EpochExecutionTranches::new(epoch_tranches),
+				best_submission: None,
+				challenge_period_end: None,
+			})
+		}
+
+		/// Executes the outstanding orders of a continuous pool at the
+		/// current NAV-derived price.
+		///
+		/// Invest orders are always fulfilled in full. Redeem orders are
+		/// fulfilled proportionally to the liquidity available after the
+		/// investments were added to the reserve.
+		fn do_execute_continuous(
+			pool_id: T::PoolId,
+			pool: &mut PoolDetailsOf<T>,
+		) -> DispatchResult {
+			let now = T::Time::now();
+			let (nav, nav_total) = Self::calculate_nav(pool_id, pool, now)?;
+			let epoch_id = pool.epoch.current;
+
+			pool.start_next_epoch(now)?;
+
+			let prices = pool
+				.tranches
+				.calculate_prices::<T::BalanceRatio, T::Tokens, _>(nav_total, now)?;
+			ensure!(
+				!prices.iter().any(|price| *price == Zero::zero()),
+				Error::<T>::WipedOut
+			);
+
+			let orders = Self::summarize_orders(&pool.tranches, &prices)?;
+			let epoch = Self::epoch_execution_info(pool, nav, epoch_id, &prices, &orders)?;
+
+			let liquidity = pool.reserve.total.ensure_add(orders.acc_invest_orders)?;
+			let redeem_fulfillment = if orders.acc_redeem_orders <= liquidity {
+				Perquintill::one()
+			} else {
+				Perquintill::from_rational_with_rounding(
+					liquidity,
+					orders.acc_redeem_orders,
+					Rounding::Down,
+				)
+				.map_err(|_| Error::<T>::InsufficientCurrency)?
+			};
+
+			let solution = pool.tranches.combine_residual_top(|_| {
+				Ok(TrancheSolution {
+					invest_fulfillment: Perquintill::one(),
+					redeem_fulfillment,
+				})
+			})?;
+
+			ensure!(
+				Self::inspect_solution(pool, &epoch, &solution)? == PoolState::Healthy,
+				Error::<T>::UnhealthyContinuousExecution
+			);
+
+			Self::do_execute_epoch(pool_id, pool, &epoch, &solution)?;
+
+			Self::deposit_event(Event::ContinuousOrdersExecuted { pool_id, epoch_id });
+
+			Ok(())
+		}
+
+		/// Computes the outcome of closing the current epoch of a pool with
+		/// its current orders and NAV, without changing any state. The minimum
+		/// epoch time is not checked.
+		pub fn preview_epoch(
+			pool_id: T::PoolId,
+		) -> Result<EpochPreview<T::Balance, T::BalanceRatio>, DispatchError> {
+			with_transaction(|| TransactionOutcome::Rollback(Self::do_preview_epoch(pool_id)))
+		}
+
+		/// Follows the steps of `close_epoch()`. The caller must discard the
+		/// state changes.
+		fn do_preview_epoch(
+			pool_id: T::PoolId,
+		) -> Result<EpochPreview<T::Balance, T::BalanceRatio>, DispatchError> {
+			let mut pool = Pool::<T>::get(pool_id).ok_or(Error::<T>::NoSuchPool)?;
+			ensure!(
+				!EpochExecution::<T>::contains_key(pool_id),
+				Error::<T>::InSubmissionPeriod
+			);
+			ensure!(
+				PoolModes::<T>::get(pool_id) == PoolMode::Epoch,
+				Error::<T>::PoolInContinuousMode
+			);
+
+			let now = T::Time::now();
+			let (nav, nav_total) = Self::calculate_nav(pool_id, &mut pool, now)?;
+			let submission_period_epoch = pool.epoch.current;
+
+			pool.start_next_epoch(now)?;
+
+			let prices = pool
+				.tranches
+				.calculate_prices::<T::BalanceRatio, T::Tokens, _>(nav_total, now)?;
+			ensure!(
+				!prices.iter().any(|price| *price == Zero::zero()),
+				Error::<T>::WipedOut
+			);
+
+			let orders = Self::summarize_orders(&pool.tranches, &prices)?;
+			let epoch =
+				Self::epoch_execution_info(&pool, nav, submission_period_epoch, &prices, &orders)?;
+
+			let solution = |fulfillment: Perquintill| {
+				pool.tranches.combine_residual_top(|_| {
+					Ok(TrancheSolution {
+						invest_fulfillment: fulfillment,
+						redeem_fulfillment: fulfillment,
+					})
+				})
+			};
+			let full_execution_solution = solution(Perquintill::one())?;
+
+			// Without orders, the epoch is always executed
+			let executed = orders.all_are_zero()
+				|| Self::inspect_solution(&pool, &epoch, &full_execution_solution)
+					.map(|state| state == PoolState::Healthy)
+					.unwrap_or(false);
+
+			let fulfillments = match executed {
+				true => full_execution_solution,
+				false => solution(Perquintill::zero())?,
+			};
+			pool.reserve
+				.deposit_from_epoch(&epoch.tranches, &fulfillments)?;
+
+			let (invest_prices, redeem_prices): (Vec<_>, Vec<_>) = pool
+				.tranches
+				.combine_with_residual_top(&prices, |tranche, price| {
+					let fees = Self::tranche_fees(&tranche.currency);
+					Ok((fees.invest_price(*price)?, fees.redeem_price(*price)?))
+				})?
+				.into_iter()
+				.unzip();
+
+			Ok(EpochPreview {
+				tranche_prices: prices,
+				fulfillments,
+				reserve: pool.reserve.total,
+				executed,
+				invest_prices,
+				redeem_prices,
+			})
+		}
+
 		fn summarize_orders(
 			tranches: &TranchesOf<T>,
 			prices: &[T::BalanceRatio],
@@ -966,9 +1734,12 @@ pub mod pallet {
 				invest_orders.push(invest_order.amount);
 
 				// Redeem order is denominated in the `TrancheCurrency`. Hence, we need to
-				// convert them into `PoolCurrency` denomination
+				// convert them into `PoolCurrency` denomination, at the price redeemers
+				// obtain once the exit fee is deducted
 				let redeem_order = T::Investments::process_redeem_orders(tranche.currency)?;
-				let redeem_amount_in_pool_currency = price.ensure_mul_int(redeem_order.amount)?;
+				let redeem_price = Self::tranche_fees(&tranche.currency).redeem_price(*price)?;
+				let redeem_amount_in_pool_currency =
+					redeem_price.ensure_mul_int(redeem_order.amount)?;
 				acc_redeem_orders.ensure_add_assign(redeem_amount_in_pool_currency)?;
 				redeem_orders.push(redeem_amount_in_pool_currency);
 
@@ -1143,6 +1914,8 @@ pub mod pallet {
 					}
 				}
 
+				T::ActivityRecorder::record(ActivityKind::Pool, pool_id);
+
 				Self::deposit_event(Event::Updated {
 					id: *pool_id,
 					old: old_pool,
@@ -1150,7 +1923,7 @@ pub mod pallet {
 						.essence_from_registry::<T::AssetRegistry, T::Balance, T::StringLimit>()?,
 				});
 
-				ScheduledUpdate::<T>::remove(pool_id);
+				Self::remove_scheduled_update(*pool_id);
 
 				Ok(())
 			})
@@ -1219,11 +1992,13 @@ pub mod pallet {
 			pool.reserve.deposit_from_epoch(&epoch.tranches, solution)?;
 
 			for (tranche, solution) in epoch.tranches.residual_top_slice().iter().zip(solution) {
+				let fees = Self::tranche_fees(&tranche.currency);
+
 				T::Investments::invest_fulfillment(
 					tranche.currency,
 					FulfillmentWithPrice {
 						of_amount: solution.invest_fulfillment,
-						price: tranche.price,
+						price: fees.invest_price(tranche.price)?,
 					},
 				)?;
 
@@ -1231,9 +2006,21 @@ pub mod pallet {
 					tranche.currency,
 					FulfillmentWithPrice {
 						of_amount: solution.redeem_fulfillment,
-						price: tranche.price,
+						price: fees.redeem_price(tranche.price)?,
 					},
 				)?;
+
+				if !fees.is_zero() {
+					let invested = solution.invest_fulfillment.mul_floor(tranche.invest);
+					let redeemed = solution.redeem_fulfillment.mul_floor(tranche.redeem);
+
+					Self::deposit_event(Event::TrancheFeesCharged {
+						pool_id,
+						tranche_id: tranche.currency.of_tranche(),
+						entry_fee: fees.entry.mul_floor(invested),
+						exit_fee: fees.exit_fee::<T::BalanceRatio>(redeemed)?,
+					});
+				}
 			}
 
 			pool.execute_previous_epoch()?;
@@ -34,15 +34,18 @@ use frame_system::{EnsureSigned, EnsureSignedBy};
 use orml_traits::{asset_registry::AssetMetadata, parameter_type_with_key};
 use pallet_pool_fees::PoolFeeInfoOf;
 use pallet_restricted_tokens::TransferDetails;
-use parity_scale_codec::Encode;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use sp_arithmetic::FixedPointNumber;
-use sp_core::{ConstU128, H256};
-use sp_runtime::{traits::Zero, BuildStorage};
+use sp_core::{ConstU128, ConstU32, ConstU64, H256};
+use sp_runtime::{traits::Zero, BuildStorage, DispatchError, RuntimeDebug};
 use sp_std::marker::PhantomData;
 
 use crate::{
 	self as pallet_pool_system,
-	pool_types::{changes::PoolChangeProposal, PoolDetails, ScheduledUpdateDetails},
+	pool_types::{
+		changes::{Change as PoolChange, PoolChangeProposal},
+		PoolDetails, ScheduledUpdateDetails,
+	},
 	Config, DispatchResult,
 };
 
@@ -161,12 +164,18 @@ parameter_types! {
 
 impl pallet_permissions::Config for Runtime {
 	type AdminOrigin = EnsureSignedBy<One, u64>;
+	type DelegableRoles = frame_support::traits::Everything;
 	type Editors = frame_support::traits::Everything;
+	type MaxBatchSize = ConstU32<10>;
+	type MaxChangesPerScope = ConstU32<10>;
+	type MaxDelegationPeriod = ConstU64<100>;
+	type MaxDelegations = ConstU32<10>;
 	type MaxRolesPerScope = MaxRoles;
 	type Role = Role<TrancheId>;
 	type RuntimeEvent = RuntimeEvent;
 	type Scope = PermissionScope<u64, CurrencyId>;
 	type Storage = PermissionRoles<TimeProvider<Timestamp>, MinDelay, TrancheId, MaxTranches>;
+	type Time = Timestamp;
 	type WeightInfo = ();
 }
 
@@ -246,6 +255,7 @@ impl pallet_restricted_tokens::Config for Runtime {
 	type PreReservableCurrency = cfg_traits::Always;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TransferHooks = ();
 	type WeightInfo = ();
 }
 
@@ -364,6 +374,11 @@ parameter_types! {
 
 	pub const MinUpdateDelay: u64 = 0; // no delay
 	pub const ChallengeTime: BlockNumber = 0;
+	pub const ChallengeTimeLowerBound: BlockNumber = 0;
+	pub const ChallengeTimeUpperBound: BlockNumber = 100;
+	#[derive(scale_info::TypeInfo, Eq, PartialEq, Debug, Clone, Copy )]
+	pub const MaxSolvers: u32 = 3;
+	pub const MaxPendingApprovals: u32 = 3;
 
 	// Defaults for pool parameters
 	pub const DefaultMinEpochTime: u64 = 1;
@@ -395,13 +410,58 @@ impl EnsureOriginWithArg<RuntimeOrigin, PoolId> for All {
 	}
 }
 
+/// Changes noted in the pool system, either directly as proposals with
+/// requirements or as pool changes without requirements.
+#[derive(
+	Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, scale_info::TypeInfo, MaxEncodedLen,
+)]
+pub enum RuntimeChange {
+	Proposal(PoolChangeProposal),
+	Pool(PoolChange<Runtime>),
+}
+
+impl From<PoolChangeProposal> for RuntimeChange {
+	fn from(proposal: PoolChangeProposal) -> Self {
+		RuntimeChange::Proposal(proposal)
+	}
+}
+
+impl From<PoolChange<Runtime>> for RuntimeChange {
+	fn from(change: PoolChange<Runtime>) -> Self {
+		RuntimeChange::Pool(change)
+	}
+}
+
+impl From<RuntimeChange> for PoolChangeProposal {
+	fn from(change: RuntimeChange) -> Self {
+		match change {
+			RuntimeChange::Proposal(proposal) => proposal,
+			RuntimeChange::Pool(_) => PoolChangeProposal::new([]),
+		}
+	}
+}
+
+impl TryFrom<RuntimeChange> for PoolChange<Runtime> {
+	type Error = DispatchError;
+
+	fn try_from(change: RuntimeChange) -> Result<Self, DispatchError> {
+		match change {
+			RuntimeChange::Pool(change) => Ok(change),
+			_ => Err(DispatchError::Other("Expected a pool change")),
+		}
+	}
+}
+
 impl Config for Runtime {
+	type ActivityRecorder = ();
 	type AdminOrigin = All;
 	type AssetRegistry = RegistryMock;
 	type AssetsUnderManagementNAV = FakeNav;
 	type Balance = Balance;
 	type BalanceRatio = Quantity;
 	type ChallengeTime = ChallengeTime;
+	type ChallengeTimeLowerBound = ChallengeTimeLowerBound;
+	type ChallengeTimeUpperBound = ChallengeTimeUpperBound;
 	type Currency = Balances;
 	type CurrencyId = CurrencyId;
 	type DefaultMaxNAVAge = DefaultMaxNAVAge;
@@ -409,6 +469,8 @@ impl Config for Runtime {
 	type EpochId = PoolEpochId;
 	type Investments = Investments;
 	type MaxNAVAgeUpperBound = MaxNAVAgeUpperBound;
+	type MaxPendingApprovals = MaxPendingApprovals;
+	type MaxSolvers = MaxSolvers;
 	type MaxTranches = MaxTranches;
 	type MinEpochTimeLowerBound = MinEpochTimeLowerBound;
 	type MinEpochTimeUpperBound = MinEpochTimeUpperBound;
@@ -424,7 +486,7 @@ impl Config for Runtime {
 	type PoolFeesNAV = PoolFees;
 	type PoolId = PoolId;
 	type Rate = Rate;
-	type RuntimeChange = PoolChangeProposal;
+	type RuntimeChange = RuntimeChange;
 	type RuntimeEvent = RuntimeEvent;
 	type StringLimit = StringLimit;
 	type Time = Timestamp;
@@ -17,7 +17,7 @@ use frame_support::{
 	dispatch::DispatchResult,
 	pallet_prelude::{DispatchError, RuntimeDebug},
 	traits::Get,
-	BoundedVec,
+	BoundedBTreeSet, BoundedVec,
 };
 use orml_traits::Change;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -25,7 +25,7 @@ use scale_info::TypeInfo;
 use sp_arithmetic::traits::{BaseArithmetic, Unsigned};
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, One, Zero},
-	FixedPointNumber, FixedPointOperand, TypeId,
+	ArithmeticError, FixedPointNumber, FixedPointOperand, Perquintill, TypeId,
 };
 use sp_std::{cmp::PartialEq, vec::Vec};
 
@@ -98,6 +98,147 @@ where
 	pub submitted_at: Seconds,
 }
 
+/// Settings for the execution of epochs that went into a submission period.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct EpochSettings<BlockNumber, AccountId, MaxSolvers>
+where
+	AccountId: Ord,
+	MaxSolvers: Get<u32>,
+{
+	/// Number of blocks after the first valid solution until the epoch can be
+	/// executed.
+	pub challenge_time: BlockNumber,
+	/// Accounts allowed to submit solutions. If `None`, anybody can.
+	pub solvers: Option<BoundedBTreeSet<AccountId, MaxSolvers>>,
+}
+
+/// How the orders of a pool are executed.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub enum PoolMode {
+	/// Orders are locked in when an epoch is closed and executed according
+	/// to a solution once the pool constraints allow it.
+	#[default]
+	Epoch,
+	/// Orders are executed immediately against the reserve at the current
+	/// NAV-derived price. Only available for single tranche pools.
+	Continuous,
+}
+
+/// Epoch settings proposed for a pool, waiting for `MinUpdateDelay` to pass
+/// before they can be applied.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct ScheduledEpochSettings<BlockNumber, AccountId, MaxSolvers>
+where
+	AccountId: Ord,
+	MaxSolvers: Get<u32>,
+{
+	pub settings: EpochSettings<BlockNumber, AccountId, MaxSolvers>,
+	pub submitted_at: Seconds,
+}
+
+/// Projected outcome of closing the current epoch of a pool
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct EpochPreview<Balance, BalanceRatio> {
+	/// Token prices the tranches would be closed at, residual tranche first
+	pub tranche_prices: Vec<BalanceRatio>,
+
+	/// Fulfillment of the orders of each tranche, residual tranche first. No
+	/// order is fulfilled if the epoch would not be executed when closed.
+	pub fulfillments: Vec<TrancheSolution>,
+
+	/// Total reserve after closing the epoch
+	pub reserve: Balance,
+
+	/// Whether the epoch would be executed when closed. Otherwise, solutions
+	/// have to be submitted in the submission period.
+	pub executed: bool,
+
+	/// Prices investors would obtain tranche tokens at, after the entry fees,
+	/// residual tranche first
+	pub invest_prices: Vec<BalanceRatio>,
+
+	/// Prices redeemers would obtain pool currency at, after the exit fees,
+	/// residual tranche first
+	pub redeem_prices: Vec<BalanceRatio>,
+}
+
+/// Fees charged to the investors of a tranche when their orders are fulfilled.
+/// The charged amounts remain in the pool reserve, accruing to the tranche.
+#[derive(
+	Encode, Decode, Clone, Copy, Default, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+pub struct TrancheFees {
+	/// Fraction of the invested amount charged when an invest order is
+	/// fulfilled
+	pub entry: Perquintill,
+
+	/// Fraction of the redeemed amount charged when a redeem order is
+	/// fulfilled
+	pub exit: Perquintill,
+}
+
+impl TrancheFees {
+	pub fn is_valid(&self) -> bool {
+		self.entry < Perquintill::one() && self.exit < Perquintill::one()
+	}
+
+	pub fn is_zero(&self) -> bool {
+		self.entry.is_zero() && self.exit.is_zero()
+	}
+
+	/// Price at which investors obtain tranche tokens, so that the entry fee
+	/// does not mint any token.
+	pub fn invest_price<BalanceRatio: FixedPointNumber>(
+		&self,
+		price: BalanceRatio,
+	) -> Result<BalanceRatio, DispatchError> {
+		let net = Self::ratio::<BalanceRatio>(Perquintill::one().saturating_sub(self.entry))?;
+
+		price
+			.checked_div(&net)
+			.ok_or(ArithmeticError::DivisionByZero.into())
+	}
+
+	/// Price at which redeemers obtain pool currency, once the exit fee is
+	/// deducted.
+	pub fn redeem_price<BalanceRatio: FixedPointNumber>(
+		&self,
+		price: BalanceRatio,
+	) -> Result<BalanceRatio, DispatchError> {
+		let net = Self::ratio::<BalanceRatio>(Perquintill::one().saturating_sub(self.exit))?;
+
+		price
+			.checked_mul(&net)
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+
+	/// Exit fee charged to obtain the given redeemed amount, which is already
+	/// net of the fee.
+	pub fn exit_fee<BalanceRatio: FixedPointNumber>(
+		&self,
+		redeemed: BalanceRatio::Inner,
+	) -> Result<BalanceRatio::Inner, DispatchError>
+	where
+		BalanceRatio::Inner: FixedPointOperand,
+	{
+		BalanceRatio::checked_from_rational(
+			self.exit.deconstruct(),
+			Perquintill::one().saturating_sub(self.exit).deconstruct(),
+		)
+		.and_then(|ratio| ratio.checked_mul_int(redeemed))
+		.ok_or(ArithmeticError::Overflow.into())
+	}
+
+	fn ratio<BalanceRatio: FixedPointNumber>(
+		part: Perquintill,
+	) -> Result<BalanceRatio, DispatchError> {
+		BalanceRatio::checked_from_rational(part.deconstruct(), Perquintill::one().deconstruct())
+			.ok_or(ArithmeticError::Overflow.into())
+	}
+}
+
 /// A representation of a pool identifier that can be converted to an account
 /// address
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -326,6 +467,7 @@ pub mod changes {
 	use strum::EnumCount;
 
 	use super::*;
+	use crate::Config;
 
 	/// Requirements to perform the change
 	#[derive(
@@ -352,6 +494,10 @@ pub mod changes {
 
 		/// Evaluates if the change must be blocked if redemptions are locked.
 		BlockedByLockedRedemptions,
+
+		/// The change must be approved by both the pool admin and the change
+		/// approver of the pool. Satisfied if the pool has no change approver.
+		DualApproval,
 	}
 
 	/// Wrapper type to identify equality between variants,
@@ -371,6 +517,9 @@ pub mod changes {
 				Requirement::BlockedByLockedRedemptions => {
 					matches!(other.0, Requirement::BlockedByLockedRedemptions)
 				}
+				Requirement::DualApproval => {
+					matches!(other.0, Requirement::DualApproval)
+				}
 			}
 		}
 	}
@@ -413,6 +562,30 @@ pub mod changes {
 		}
 	}
 
+	/// Approvals given to a change requiring dual approval
+	#[derive(
+		Encode, Decode, Clone, Default, PartialEq, Eq, TypeInfo, RuntimeDebug, MaxEncodedLen,
+	)]
+	pub struct ChangeApproval {
+		/// Approved by the pool admin
+		pub admin: bool,
+		/// Approved by the change approver of the pool
+		pub approver: bool,
+	}
+
+	impl ChangeApproval {
+		pub fn is_complete(&self) -> bool {
+			self.admin && self.approver
+		}
+	}
+
+	/// Changes of a pool done through a change guard.
+	#[derive(Debug, Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Clone)]
+	#[scale_info(skip_type_params(T))]
+	pub enum Change<T: Config> {
+		TrancheFees(T::TrancheId, TrancheFees),
+	}
+
 	/// A PoolChangeProposal with extra information about when it was noted.
 	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	pub struct NotedPoolChange<ChangeProposal: Into<PoolChangeProposal>> {
@@ -982,6 +982,21 @@ fn submission_period() {
 	});
 }
 
+#[test]
+fn overdue_epochs() {
+	new_test_ext().execute_with(|| {
+		util::default_pool::create();
+		assert_eq!(PoolSystem::overdue_epochs_count(), 0);
+
+		util::advance_secs(1);
+		assert_eq!(PoolSystem::overdue_epochs_count(), 1);
+
+		// In submission period
+		util::default_pool::close_epoch();
+		assert_eq!(PoolSystem::overdue_epochs_count(), 0);
+	});
+}
+
 #[test]
 fn execute_info_removed_after_epoch_execute() {
 	new_test_ext().execute_with(|| {
@@ -2479,6 +2494,7 @@ fn create_tranche_token_metadata() {
 					pool_currency: false,
 					transferability: CrossChainTransferability::LiquidityPools,
 					local_representation: None,
+					xcm_restricted: true,
 				},
 			}
 		);
@@ -2553,7 +2569,7 @@ mod changes {
 
 	use super::*;
 	use crate::{
-		pool_types::changes::{PoolChangeProposal, Requirement},
+		pool_types::changes::{ChangeApproval, PoolChangeProposal, Requirement},
 		Event,
 	};
 
@@ -2563,18 +2579,18 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([]);
-			let change_id_1 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_1 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(1)]);
-			let change_id_2 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_2 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id_3 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_3 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			// Same change but different moment so overwrites
 			util::advance_secs(1);
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id_4 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_4 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_eq!(change_id_4, change_id_3);
 
@@ -2589,10 +2605,10 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id_1 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_1 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id_2 = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id_2 = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_eq!(change_id_1, change_id_2)
 		});
@@ -2604,14 +2620,14 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(2)]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.clone()).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.clone().into()).unwrap();
 
 			assert_eq!(
 				System::events().last().unwrap().event,
 				RuntimeEvent::PoolSystem(Event::ProposedChange {
 					pool_id: DEFAULT_POOL_ID,
 					change_id,
-					change,
+					change: change.into(),
 				})
 			);
 		});
@@ -2629,7 +2645,7 @@ mod changes {
 			);
 
 			let change = PoolChangeProposal::new([]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.clone()).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.clone().into()).unwrap();
 
 			// ChangeId not found in the pool
 			assert_noop!(
@@ -2652,7 +2668,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_ok!(PoolSystem::released(DEFAULT_POOL_ID, change_id));
 		});
@@ -2664,7 +2680,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			// Starts submitted period
 			util::default_pool::close_epoch();
@@ -2687,7 +2703,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::DelayTime(23)]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			util::advance_secs(22);
 
@@ -2708,7 +2724,7 @@ mod changes {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::NextEpoch]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_noop!(
 				PoolSystem::released(DEFAULT_POOL_ID, change_id),
@@ -2730,7 +2746,7 @@ mod changes {
 	fn requirement_next_epoch_no_pool() {
 		new_test_ext().execute_with(|| {
 			let change = PoolChangeProposal::new([]);
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			assert_err!(
 				PoolSystem::released(DEFAULT_POOL_ID, change_id),
@@ -2739,13 +2755,343 @@ mod changes {
 		});
 	}
 
+	mod dual_approval {
+		use cfg_traits::UpdateState;
+		use sp_runtime::DispatchError;
+
+		use super::*;
+		use crate::{tranches::TrancheUpdate, ChangeApprovals, ChangeApprover};
+
+		const APPROVER: AccountId = 42;
+
+		fn set_approver() {
+			assert_ok!(PoolSystem::set_change_approver(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID,
+				Some(APPROVER)
+			));
+		}
+
+		fn note_change() -> <Runtime as frame_system::Config>::Hash {
+			let change = PoolChangeProposal::new([Requirement::DualApproval]);
+			PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap()
+		}
+
+		fn senior_interest_update(
+			interest_rate_per_sec: Rate,
+		) -> PoolChanges<Rate, StringLimit, MaxTranches> {
+			let metadata = TrancheMetadata {
+				token_name: BoundedVec::default(),
+				token_symbol: BoundedVec::default(),
+			};
+
+			PoolChanges {
+				tranches: Change::NewValue(
+					vec![
+						TrancheUpdate {
+							tranche_type: TrancheType::Residual,
+							seniority: None,
+						},
+						TrancheUpdate {
+							tranche_type: TrancheType::NonResidual {
+								interest_rate_per_sec,
+								min_risk_buffer: Perquintill::from_percent(25),
+							},
+							seniority: None,
+						},
+					]
+					.try_into()
+					.unwrap(),
+				),
+				tranche_metadata: Change::NewValue(
+					vec![metadata.clone(), metadata].try_into().unwrap(),
+				),
+				min_epoch_time: Change::NoChange,
+				max_nav_age: Change::NoChange,
+			}
+		}
+
+		#[test]
+		fn without_approver() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+
+				let change_id = note_change();
+
+				assert_ok!(PoolSystem::released(DEFAULT_POOL_ID, change_id));
+			});
+		}
+
+		#[test]
+		fn requires_both_approvals() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+				set_approver();
+
+				let change_id = note_change();
+
+				assert_noop!(
+					PoolSystem::released(DEFAULT_POOL_ID, change_id),
+					Error::<Runtime>::ChangeNotReady
+				);
+
+				assert_ok!(PoolSystem::approve_change(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					change_id
+				));
+
+				assert_noop!(
+					PoolSystem::released(DEFAULT_POOL_ID, change_id),
+					Error::<Runtime>::ChangeNotReady
+				);
+
+				assert_ok!(PoolSystem::approve_change(
+					RuntimeOrigin::signed(APPROVER),
+					DEFAULT_POOL_ID,
+					change_id
+				));
+
+				assert_eq!(
+					System::events().last().unwrap().event,
+					RuntimeEvent::PoolSystem(Event::ChangeApproved {
+						pool_id: DEFAULT_POOL_ID,
+						change_id,
+						approval: ChangeApproval {
+							admin: true,
+							approver: true,
+						},
+					})
+				);
+
+				assert_ok!(PoolSystem::released(DEFAULT_POOL_ID, change_id));
+				assert!(!ChangeApprovals::<Runtime>::contains_key(
+					DEFAULT_POOL_ID,
+					change_id
+				));
+			});
+		}
+
+		#[test]
+		fn noting_again_discards_approvals() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+				set_approver();
+
+				let change_id = note_change();
+
+				for who in [DEFAULT_POOL_OWNER, APPROVER] {
+					assert_ok!(PoolSystem::approve_change(
+						RuntimeOrigin::signed(who),
+						DEFAULT_POOL_ID,
+						change_id
+					));
+				}
+
+				assert_eq!(note_change(), change_id);
+
+				assert_noop!(
+					PoolSystem::released(DEFAULT_POOL_ID, change_id),
+					Error::<Runtime>::ChangeNotReady
+				);
+			});
+		}
+
+		#[test]
+		fn approve_unknown_change() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+				set_approver();
+
+				assert_noop!(
+					PoolSystem::approve_change(
+						RuntimeOrigin::signed(APPROVER),
+						DEFAULT_POOL_ID,
+						Default::default()
+					),
+					Error::<Runtime>::ChangeNotFound
+				);
+			});
+		}
+
+		#[test]
+		fn approver_can_not_be_pool_admin() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+
+				assert_noop!(
+					PoolSystem::set_change_approver(
+						RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+						DEFAULT_POOL_ID,
+						Some(DEFAULT_POOL_OWNER)
+					),
+					Error::<Runtime>::ApproverIsPoolAdmin
+				);
+			});
+		}
+
+		#[test]
+		fn only_approver_or_root_replace_approver() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+				set_approver();
+
+				assert_noop!(
+					PoolSystem::set_change_approver(
+						RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+						DEFAULT_POOL_ID,
+						None
+					),
+					DispatchError::BadOrigin
+				);
+
+				assert_ok!(PoolSystem::set_change_approver(
+					RuntimeOrigin::signed(APPROVER),
+					DEFAULT_POOL_ID,
+					Some(APPROVER + 1)
+				));
+
+				assert_ok!(PoolSystem::set_change_approver(
+					RuntimeOrigin::root(),
+					DEFAULT_POOL_ID,
+					None
+				));
+
+				assert_eq!(ChangeApprover::<Runtime>::get(DEFAULT_POOL_ID), None);
+			});
+		}
+
+		#[test]
+		fn pending_approvals_are_bounded() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+				set_approver();
+
+				let note_delayed_change = |delay| {
+					let change = PoolChangeProposal::new([
+						Requirement::DualApproval,
+						Requirement::DelayTime(delay),
+					]);
+					PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap()
+				};
+
+				for delay in 0..MaxPendingApprovals::get() {
+					assert_ok!(PoolSystem::approve_change(
+						RuntimeOrigin::signed(APPROVER),
+						DEFAULT_POOL_ID,
+						note_delayed_change(delay)
+					));
+				}
+
+				let change_id = note_delayed_change(MaxPendingApprovals::get());
+				assert_noop!(
+					PoolSystem::approve_change(
+						RuntimeOrigin::signed(APPROVER),
+						DEFAULT_POOL_ID,
+						change_id
+					),
+					Error::<Runtime>::TooManyPendingApprovals
+				);
+
+				// Replacing the approver discards all pending approvals
+				assert_ok!(PoolSystem::set_change_approver(
+					RuntimeOrigin::signed(APPROVER),
+					DEFAULT_POOL_ID,
+					Some(APPROVER + 1)
+				));
+				assert_eq!(
+					ChangeApprovals::<Runtime>::iter_prefix(DEFAULT_POOL_ID).count(),
+					0
+				);
+
+				assert_ok!(PoolSystem::approve_change(
+					RuntimeOrigin::signed(APPROVER + 1),
+					DEFAULT_POOL_ID,
+					change_id
+				));
+			});
+		}
+
+		#[test]
+		fn pool_update_changing_interest_requires_both_approvals() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+				set_approver();
+
+				let changes = senior_interest_update(Rate::one());
+				let change_id = PoolSystem::update_change_id(&changes);
+
+				assert_eq!(
+					PoolSystem::update(DEFAULT_POOL_ID, changes),
+					Ok(UpdateState::Stored(2))
+				);
+
+				assert_eq!(
+					System::events().last().unwrap().event,
+					RuntimeEvent::PoolSystem(Event::UpdateRequiresApproval {
+						pool_id: DEFAULT_POOL_ID,
+						change_id,
+					})
+				);
+
+				assert_ok!(PoolSystem::approve_change(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					change_id
+				));
+
+				assert_noop!(
+					PoolSystem::execute_update(DEFAULT_POOL_ID),
+					Error::<Runtime>::UpdatePrerequesitesNotFulfilled
+				);
+
+				assert_ok!(PoolSystem::approve_change(
+					RuntimeOrigin::signed(APPROVER),
+					DEFAULT_POOL_ID,
+					change_id
+				));
+
+				assert_ok!(PoolSystem::execute_update(DEFAULT_POOL_ID));
+				assert!(!ChangeApprovals::<Runtime>::contains_key(
+					DEFAULT_POOL_ID,
+					change_id
+				));
+			});
+		}
+
+		#[test]
+		fn pool_update_without_economic_changes() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+				set_approver();
+
+				assert_eq!(
+					PoolSystem::update(DEFAULT_POOL_ID, senior_interest_update(Rate::default())),
+					Ok(UpdateState::Executed(2))
+				);
+			});
+		}
+
+		#[test]
+		fn pool_update_without_approver() {
+			new_test_ext().execute_with(|| {
+				util::default_pool::create();
+
+				assert_eq!(
+					PoolSystem::update(DEFAULT_POOL_ID, senior_interest_update(Rate::one())),
+					Ok(UpdateState::Executed(2))
+				);
+			});
+		}
+	}
+
 	#[test]
 	fn requirement_blocked_by_locked_redemptions() {
 		new_test_ext().execute_with(|| {
 			util::default_pool::create();
 
 			let change = PoolChangeProposal::new([Requirement::BlockedByLockedRedemptions]);
-			let _change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let _change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			/* TODO: 1407
 			assert_noop!(
@@ -2771,7 +3117,7 @@ mod changes {
 				Requirement::DelayTime(3),
 			]);
 
-			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change).unwrap();
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
 
 			util::advance_secs(4);
 
@@ -3472,12 +3818,963 @@ mod pool_fees {
 	}
 }
 
-#[test]
-#[cfg(feature = "runtime-benchmarks")]
-fn benchmark_pool() {
-	use cfg_traits::benchmarking::PoolBenchmarkHelper;
+mod epoch_settings {
+	use sp_std::collections::btree_set::BTreeSet;
 
-	new_test_ext().execute_with(|| {
-		PoolSystem::bench_create_pool(0, &0);
-	});
+	use super::*;
+	use crate::{
+		pool_types::EpochSettings, EpochSettingsOf, Event, PoolEpochSettings,
+		ScheduledPoolEpochSettings,
+	};
+
+	const SOLVER: AccountId = 2;
+	const OTHER_SOLVER: AccountId = 3;
+
+	fn settings(challenge_time: u64, solvers: Option<Vec<AccountId>>) -> EpochSettingsOf<Runtime> {
+		EpochSettings {
+			challenge_time,
+			solvers: solvers.map(|solvers| {
+				BTreeSet::from_iter(solvers)
+					.try_into()
+					.expect("less solvers than MaxSolvers")
+			}),
+		}
+	}
+
+	fn zero_solution() -> Vec<TrancheSolution> {
+		vec![
+			TrancheSolution {
+				invest_fulfillment: Perquintill::zero(),
+				redeem_fulfillment: Perquintill::zero(),
+			};
+			2
+		]
+	}
+
+	fn create_pool() {
+		let senior_interest_rate = Rate::saturating_from_rational(10, 100)
+			/ Rate::saturating_from_integer(SECONDS_PER_YEAR)
+			+ One::one();
+
+		assert_ok!(PoolSystem::create(
+			DEFAULT_POOL_OWNER,
+			DEFAULT_POOL_OWNER,
+			DEFAULT_POOL_ID,
+			vec![
+				TrancheInput {
+					tranche_type: TrancheType::Residual,
+					seniority: None,
+					metadata: TrancheMetadata {
+						token_name: BoundedVec::default(),
+						token_symbol: BoundedVec::default(),
+					}
+				},
+				TrancheInput {
+					tranche_type: TrancheType::NonResidual {
+						interest_rate_per_sec: senior_interest_rate,
+						min_risk_buffer: Perquintill::from_percent(10),
+					},
+					seniority: None,
+					metadata: TrancheMetadata {
+						token_name: BoundedVec::default(),
+						token_symbol: BoundedVec::default(),
+					}
+				},
+			],
+			AUSD_CURRENCY_ID,
+			10_000 * CURRENCY,
+			vec![],
+		));
+
+		// Force min_epoch_time to 0 without using update
+		// as this breaks the runtime-defined pool
+		// parameter bounds and update will not allow this.
+		crate::Pool::<Runtime>::try_mutate(DEFAULT_POOL_ID, |maybe_pool| -> Result<(), ()> {
+			maybe_pool.as_mut().unwrap().parameters.min_epoch_time = 0;
+			maybe_pool.as_mut().unwrap().parameters.max_nav_age = u64::MAX;
+			Ok(())
+		})
+		.unwrap();
+	}
+
+	fn enter_submission_period() {
+		invest_close_and_collect(
+			DEFAULT_POOL_ID,
+			vec![
+				(0, JuniorTrancheId::get(), 500 * CURRENCY),
+				(1, SeniorTrancheId::get(), 500 * CURRENCY),
+			],
+		);
+
+		// Redeeming everything from the junior tranche violates the risk buffer
+		assert_ok!(Investments::update_redeem_order(
+			RuntimeOrigin::signed(0),
+			(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+			500 * CURRENCY
+		));
+		assert_ok!(PoolSystem::close_epoch(
+			RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+			DEFAULT_POOL_ID
+		));
+		assert!(EpochExecution::<Runtime>::contains_key(DEFAULT_POOL_ID));
+	}
+
+	fn set_settings(settings: EpochSettingsOf<Runtime>) {
+		assert_ok!(PoolSystem::propose_epoch_settings(
+			RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+			DEFAULT_POOL_ID,
+			settings
+		));
+		assert_ok!(PoolSystem::apply_epoch_settings(
+			RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+			DEFAULT_POOL_ID
+		));
+	}
+
+	#[test]
+	fn default_settings() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			assert_eq!(
+				PoolSystem::epoch_settings(DEFAULT_POOL_ID),
+				settings(ChallengeTime::get(), None)
+			);
+		});
+	}
+
+	#[test]
+	fn propose_and_apply() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			let new_settings = settings(5, Some(vec![SOLVER]));
+
+			assert_ok!(PoolSystem::propose_epoch_settings(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID,
+				new_settings.clone()
+			));
+			System::assert_last_event(
+				Event::<Runtime>::EpochSettingsProposed {
+					pool_id: DEFAULT_POOL_ID,
+					settings: new_settings.clone(),
+				}
+				.into(),
+			);
+			assert_eq!(PoolEpochSettings::<Runtime>::get(DEFAULT_POOL_ID), None);
+
+			assert_ok!(PoolSystem::apply_epoch_settings(
+				RuntimeOrigin::signed(SOLVER),
+				DEFAULT_POOL_ID
+			));
+			System::assert_last_event(
+				Event::<Runtime>::EpochSettingsUpdated {
+					pool_id: DEFAULT_POOL_ID,
+					settings: new_settings.clone(),
+				}
+				.into(),
+			);
+
+			assert_eq!(
+				ScheduledPoolEpochSettings::<Runtime>::get(DEFAULT_POOL_ID),
+				None
+			);
+			assert_eq!(PoolSystem::epoch_settings(DEFAULT_POOL_ID), new_settings);
+		});
+	}
+
+	#[test]
+	fn propose_out_of_bounds() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			assert_noop!(
+				PoolSystem::propose_epoch_settings(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					settings(ChallengeTimeUpperBound::get() + 1, None)
+				),
+				Error::<Runtime>::PoolParameterBoundViolated
+			);
+		});
+	}
+
+	#[test]
+	fn propose_empty_whitelist() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			assert_noop!(
+				PoolSystem::propose_epoch_settings(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					settings(5, Some(vec![]))
+				),
+				Error::<Runtime>::EmptySolverWhitelist
+			);
+		});
+	}
+
+	#[test]
+	fn propose_for_missing_pool() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				PoolSystem::propose_epoch_settings(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					settings(5, None)
+				),
+				Error::<Runtime>::NoSuchPool
+			);
+		});
+	}
+
+	#[test]
+	fn apply_without_proposal() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			assert_noop!(
+				PoolSystem::apply_epoch_settings(RuntimeOrigin::signed(SOLVER), DEFAULT_POOL_ID),
+				Error::<Runtime>::NoScheduledEpochSettings
+			);
+		});
+	}
+
+	#[test]
+	fn apply_in_submission_period() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			enter_submission_period();
+
+			assert_ok!(PoolSystem::propose_epoch_settings(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID,
+				settings(5, None)
+			));
+
+			assert_noop!(
+				PoolSystem::apply_epoch_settings(RuntimeOrigin::signed(SOLVER), DEFAULT_POOL_ID),
+				Error::<Runtime>::InSubmissionPeriod
+			);
+		});
+	}
+
+	#[test]
+	fn admin_applies_in_submission_period() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			set_settings(settings(0, Some(vec![SOLVER])));
+			enter_submission_period();
+
+			assert_ok!(PoolSystem::propose_epoch_settings(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID,
+				settings(0, None)
+			));
+			assert_ok!(PoolSystem::apply_epoch_settings(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID
+			));
+
+			assert_ok!(PoolSystem::submit_solution(
+				RuntimeOrigin::signed(OTHER_SOLVER),
+				DEFAULT_POOL_ID,
+				zero_solution()
+			));
+		});
+	}
+
+	#[test]
+	fn only_whitelisted_solvers_can_submit() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			set_settings(settings(0, Some(vec![SOLVER])));
+			enter_submission_period();
+
+			assert_noop!(
+				PoolSystem::submit_solution(
+					RuntimeOrigin::signed(OTHER_SOLVER),
+					DEFAULT_POOL_ID,
+					zero_solution()
+				),
+				Error::<Runtime>::NotWhitelistedSolver
+			);
+
+			assert_ok!(PoolSystem::submit_solution(
+				RuntimeOrigin::signed(SOLVER),
+				DEFAULT_POOL_ID,
+				zero_solution()
+			));
+		});
+	}
+
+	#[test]
+	fn pool_challenge_time_is_respected() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			set_settings(settings(2, None));
+			enter_submission_period();
+
+			let pool_owner_origin = RuntimeOrigin::signed(DEFAULT_POOL_OWNER);
+
+			assert_ok!(PoolSystem::submit_solution(
+				pool_owner_origin.clone(),
+				DEFAULT_POOL_ID,
+				zero_solution()
+			));
+
+			next_block();
+			assert_noop!(
+				PoolSystem::execute_epoch(pool_owner_origin.clone(), DEFAULT_POOL_ID),
+				Error::<Runtime>::ChallengeTimeHasNotPassed
+			);
+
+			next_block();
+			assert_ok!(PoolSystem::execute_epoch(
+				pool_owner_origin,
+				DEFAULT_POOL_ID
+			));
+		});
+	}
+}
+
+#[test]
+#[cfg(feature = "runtime-benchmarks")]
+fn benchmark_pool() {
+	use cfg_traits::benchmarking::PoolBenchmarkHelper;
+
+	new_test_ext().execute_with(|| {
+		PoolSystem::bench_create_pool(0, &0);
+	});
+}
+
+mod continuous_mode {
+	use super::*;
+	use crate::{pool_types::PoolMode, tranches::TrancheUpdate, Event, PoolModes};
+
+	fn residual_tranche() -> TrancheInput<Rate, StringLimit> {
+		TrancheInput {
+			tranche_type: TrancheType::Residual,
+			seniority: None,
+			metadata: TrancheMetadata {
+				token_name: BoundedVec::default(),
+				token_symbol: BoundedVec::default(),
+			},
+		}
+	}
+
+	fn create_pool(tranches: Vec<TrancheInput<Rate, StringLimit>>) {
+		assert_ok!(PoolSystem::create(
+			DEFAULT_POOL_OWNER,
+			DEFAULT_POOL_OWNER,
+			DEFAULT_POOL_ID,
+			tranches,
+			AUSD_CURRENCY_ID,
+			10_000 * CURRENCY,
+			vec![],
+		));
+
+		crate::Pool::<Runtime>::try_mutate(DEFAULT_POOL_ID, |maybe_pool| -> Result<(), ()> {
+			maybe_pool.as_mut().unwrap().parameters.min_epoch_time = 0;
+			maybe_pool.as_mut().unwrap().parameters.max_nav_age = u64::MAX;
+			Ok(())
+		})
+		.unwrap();
+	}
+
+	fn create_continuous_pool() {
+		create_pool(vec![residual_tranche()]);
+		assert_ok!(PoolSystem::set_pool_mode(
+			RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+			DEFAULT_POOL_ID,
+			PoolMode::Continuous
+		));
+	}
+
+	fn execute() {
+		assert_ok!(PoolSystem::execute_continuous_orders(
+			RuntimeOrigin::signed(0),
+			DEFAULT_POOL_ID
+		));
+	}
+
+	fn invest_and_execute(amount: Balance) {
+		assert_ok!(Investments::update_invest_order(
+			RuntimeOrigin::signed(0),
+			(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+			amount
+		));
+		execute();
+		assert_ok!(Investments::collect_investments(
+			RuntimeOrigin::signed(0),
+			(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+		));
+	}
+
+	#[test]
+	fn set_pool_mode() {
+		new_test_ext().execute_with(|| {
+			create_pool(vec![residual_tranche()]);
+			assert_eq!(PoolModes::<Runtime>::get(DEFAULT_POOL_ID), PoolMode::Epoch);
+
+			assert_ok!(PoolSystem::set_pool_mode(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID,
+				PoolMode::Continuous
+			));
+			System::assert_last_event(
+				Event::<Runtime>::PoolModeSet {
+					pool_id: DEFAULT_POOL_ID,
+					mode: PoolMode::Continuous,
+				}
+				.into(),
+			);
+			assert_eq!(
+				PoolModes::<Runtime>::get(DEFAULT_POOL_ID),
+				PoolMode::Continuous
+			);
+		});
+	}
+
+	#[test]
+	fn set_pool_mode_with_multiple_tranches() {
+		new_test_ext().execute_with(|| {
+			create_pool(vec![
+				residual_tranche(),
+				TrancheInput {
+					tranche_type: TrancheType::NonResidual {
+						interest_rate_per_sec: Rate::saturating_from_rational(10, 100)
+							/ Rate::saturating_from_integer(SECONDS_PER_YEAR)
+							+ One::one(),
+						min_risk_buffer: Perquintill::from_percent(10),
+					},
+					seniority: None,
+					metadata: TrancheMetadata {
+						token_name: BoundedVec::default(),
+						token_symbol: BoundedVec::default(),
+					},
+				},
+			]);
+
+			assert_noop!(
+				PoolSystem::set_pool_mode(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					PoolMode::Continuous
+				),
+				Error::<Runtime>::ContinuousModeRequiresSingleTranche
+			);
+		});
+	}
+
+	#[test]
+	fn tranche_updates_keep_a_single_tranche() {
+		new_test_ext().execute_with(|| {
+			create_continuous_pool();
+
+			let changes = |tranche_types: Vec<TrancheType<Rate>>| PoolChanges {
+				tranche_metadata: Change::NewValue(
+					tranche_types
+						.iter()
+						.map(|_| TrancheMetadata {
+							token_name: BoundedVec::default(),
+							token_symbol: BoundedVec::default(),
+						})
+						.collect::<Vec<_>>()
+						.try_into()
+						.unwrap(),
+				),
+				tranches: Change::NewValue(
+					tranche_types
+						.into_iter()
+						.map(|tranche_type| TrancheUpdate {
+							tranche_type,
+							seniority: None,
+						})
+						.collect::<Vec<_>>()
+						.try_into()
+						.unwrap(),
+				),
+				min_epoch_time: Change::NoChange,
+				max_nav_age: Change::NoChange,
+			};
+
+			assert_err!(
+				PoolSystem::update(
+					DEFAULT_POOL_ID,
+					changes(vec![
+						TrancheType::Residual,
+						TrancheType::NonResidual {
+							interest_rate_per_sec: One::one(),
+							min_risk_buffer: Perquintill::from_percent(10),
+						},
+					])
+				),
+				Error::<Runtime>::ContinuousModeRequiresSingleTranche
+			);
+
+			assert_ok!(PoolSystem::update(
+				DEFAULT_POOL_ID,
+				changes(vec![TrancheType::Residual])
+			));
+		});
+	}
+
+	#[test]
+	fn epoch_calls_are_rejected() {
+		new_test_ext().execute_with(|| {
+			create_continuous_pool();
+
+			assert_noop!(
+				PoolSystem::close_epoch(RuntimeOrigin::signed(DEFAULT_POOL_OWNER), DEFAULT_POOL_ID),
+				Error::<Runtime>::PoolInContinuousMode
+			);
+		});
+	}
+
+	#[test]
+	fn execute_in_epoch_mode() {
+		new_test_ext().execute_with(|| {
+			create_pool(vec![residual_tranche()]);
+
+			assert_noop!(
+				PoolSystem::execute_continuous_orders(RuntimeOrigin::signed(0), DEFAULT_POOL_ID),
+				Error::<Runtime>::PoolNotInContinuousMode
+			);
+		});
+	}
+
+	#[test]
+	fn invest_executes_immediately() {
+		new_test_ext().execute_with(|| {
+			create_continuous_pool();
+
+			invest_and_execute(500 * CURRENCY);
+			System::assert_last_event(
+				Event::<Runtime>::ContinuousOrdersExecuted {
+					pool_id: DEFAULT_POOL_ID,
+					epoch_id: 1,
+				}
+				.into(),
+			);
+
+			let pool = PoolSystem::pool(DEFAULT_POOL_ID).unwrap();
+			assert_eq!(pool.reserve.total, 500 * CURRENCY);
+			assert_eq!(pool.reserve.available, 500 * CURRENCY);
+			assert_eq!(pool.epoch.last_executed, 1);
+			assert!(!EpochExecution::<Runtime>::contains_key(DEFAULT_POOL_ID));
+		});
+	}
+
+	#[test]
+	fn redeem_is_limited_by_liquidity() {
+		new_test_ext().execute_with(|| {
+			create_continuous_pool();
+			invest_and_execute(500 * CURRENCY);
+
+			assert_ok!(test_borrow(3, DEFAULT_POOL_ID, 400 * CURRENCY));
+
+			assert_ok!(Investments::update_redeem_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				500 * CURRENCY
+			));
+			execute();
+
+			// Only the 100 left in the reserve could be paid out
+			let pool = PoolSystem::pool(DEFAULT_POOL_ID).unwrap();
+			assert_eq!(pool.reserve.total, 0);
+		});
+	}
+
+	#[test]
+	fn execution_exceeding_max_reserve() {
+		new_test_ext().execute_with(|| {
+			create_continuous_pool();
+			crate::Pool::<Runtime>::mutate(DEFAULT_POOL_ID, |pool| {
+				pool.as_mut().unwrap().reserve.max = 100 * CURRENCY;
+			});
+
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				500 * CURRENCY
+			));
+
+			assert_noop!(
+				PoolSystem::execute_continuous_orders(RuntimeOrigin::signed(0), DEFAULT_POOL_ID),
+				Error::<Runtime>::UnhealthyContinuousExecution
+			);
+		});
+	}
+}
+
+mod preview_epoch {
+	use super::*;
+	use crate::pool_types::EpochPreview;
+
+	fn create_pool() {
+		assert_ok!(PoolSystem::create(
+			DEFAULT_POOL_OWNER,
+			DEFAULT_POOL_OWNER,
+			DEFAULT_POOL_ID,
+			util::default_pool::one_tranche_input(),
+			AUSD_CURRENCY_ID,
+			10_000 * CURRENCY,
+			vec![],
+		));
+
+		crate::Pool::<Runtime>::try_mutate(DEFAULT_POOL_ID, |maybe_pool| -> Result<(), ()> {
+			maybe_pool.as_mut().unwrap().parameters.min_epoch_time = 0;
+			maybe_pool.as_mut().unwrap().parameters.max_nav_age = u64::MAX;
+			Ok(())
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn matches_closing() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				500 * CURRENCY
+			));
+
+			let pool = PoolSystem::pool(DEFAULT_POOL_ID).unwrap();
+			let preview = PoolSystem::preview_epoch(DEFAULT_POOL_ID).unwrap();
+
+			assert_eq!(
+				preview,
+				EpochPreview {
+					tranche_prices: vec![One::one()],
+					fulfillments: vec![TrancheSolution {
+						invest_fulfillment: Perquintill::one(),
+						redeem_fulfillment: Perquintill::one(),
+					}],
+					reserve: 500 * CURRENCY,
+					executed: true,
+					invest_prices: vec![One::one()],
+					redeem_prices: vec![One::one()],
+				}
+			);
+
+			// Nothing changed
+			assert_eq!(PoolSystem::pool(DEFAULT_POOL_ID).unwrap(), pool);
+
+			assert_ok!(PoolSystem::close_epoch(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID
+			));
+			assert_eq!(
+				PoolSystem::pool(DEFAULT_POOL_ID).unwrap().reserve.total,
+				preview.reserve
+			);
+		});
+	}
+
+	#[test]
+	fn without_execution() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			crate::Pool::<Runtime>::mutate(DEFAULT_POOL_ID, |pool| {
+				pool.as_mut().unwrap().reserve.max = 100 * CURRENCY;
+			});
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				500 * CURRENCY
+			));
+
+			let preview = PoolSystem::preview_epoch(DEFAULT_POOL_ID).unwrap();
+
+			assert!(!preview.executed);
+			assert_eq!(preview.reserve, 0);
+			assert_eq!(
+				preview.fulfillments,
+				vec![TrancheSolution {
+					invest_fulfillment: Perquintill::zero(),
+					redeem_fulfillment: Perquintill::zero(),
+				}]
+			);
+		});
+	}
+
+	#[test]
+	fn in_submission_period() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			crate::Pool::<Runtime>::mutate(DEFAULT_POOL_ID, |pool| {
+				pool.as_mut().unwrap().reserve.max = 100 * CURRENCY;
+			});
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(0),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				500 * CURRENCY
+			));
+			assert_ok!(PoolSystem::close_epoch(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID
+			));
+
+			assert_err!(
+				PoolSystem::preview_epoch(DEFAULT_POOL_ID),
+				Error::<Runtime>::InSubmissionPeriod
+			);
+		});
+	}
+}
+
+mod tranche_fees {
+	use cfg_traits::changes::ChangeGuard;
+	use frame_support::traits::fungibles::Inspect;
+
+	use super::*;
+	use crate::{
+		pool_types::{
+			changes::{Change as PoolChange, PoolChangeProposal},
+			TrancheFees,
+		},
+		Event, PoolTrancheFees,
+	};
+
+	const INVESTOR: AccountId = 0;
+
+	fn create_pool() {
+		assert_ok!(PoolSystem::create(
+			DEFAULT_POOL_OWNER,
+			DEFAULT_POOL_OWNER,
+			DEFAULT_POOL_ID,
+			util::default_pool::one_tranche_input(),
+			AUSD_CURRENCY_ID,
+			10_000 * CURRENCY,
+			vec![],
+		));
+
+		crate::Pool::<Runtime>::try_mutate(DEFAULT_POOL_ID, |maybe_pool| -> Result<(), ()> {
+			maybe_pool.as_mut().unwrap().parameters.min_epoch_time = 0;
+			maybe_pool.as_mut().unwrap().parameters.max_nav_age = u64::MAX;
+			Ok(())
+		})
+		.unwrap();
+	}
+
+	fn set_fees(entry: Perquintill, exit: Perquintill) -> TrancheFees {
+		let fees = TrancheFees { entry, exit };
+		let change = PoolChange::TrancheFees(JuniorTrancheId::get(), fees);
+		let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
+
+		assert_ok!(PoolSystem::apply_tranche_fees(
+			RuntimeOrigin::signed(INVESTOR),
+			DEFAULT_POOL_ID,
+			change_id
+		));
+
+		fees
+	}
+
+	fn close_epoch() {
+		assert_ok!(PoolSystem::close_epoch(
+			RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+			DEFAULT_POOL_ID
+		));
+	}
+
+	#[test]
+	fn propose_and_apply() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			let fees = TrancheFees {
+				entry: Perquintill::from_percent(1),
+				exit: Perquintill::from_percent(2),
+			};
+
+			assert_ok!(PoolSystem::propose_tranche_fees(
+				RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+				DEFAULT_POOL_ID,
+				JuniorTrancheId::get(),
+				fees
+			));
+
+			let change = RuntimeChange::from(PoolChange::TrancheFees(JuniorTrancheId::get(), fees));
+			let change_id = match System::events().last().unwrap().event {
+				RuntimeEvent::PoolSystem(Event::ProposedChange {
+					change_id,
+					change: proposed,
+					..
+				}) if proposed == change => change_id,
+				_ => panic!("change not proposed"),
+			};
+
+			// Not applied until released
+			assert_eq!(
+				PoolTrancheFees::<Runtime>::get(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				TrancheFees::default()
+			);
+
+			assert_ok!(PoolSystem::apply_tranche_fees(
+				RuntimeOrigin::signed(INVESTOR),
+				DEFAULT_POOL_ID,
+				change_id
+			));
+
+			assert_eq!(
+				PoolTrancheFees::<Runtime>::get(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				fees
+			);
+			System::assert_last_event(
+				Event::<Runtime>::TrancheFeesUpdated {
+					pool_id: DEFAULT_POOL_ID,
+					tranche_id: JuniorTrancheId::get(),
+					fees,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn propose_invalid() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			assert_noop!(
+				PoolSystem::propose_tranche_fees(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					JuniorTrancheId::get(),
+					TrancheFees {
+						entry: Perquintill::one(),
+						exit: Perquintill::zero(),
+					}
+				),
+				Error::<Runtime>::InvalidTrancheFees
+			);
+
+			assert_noop!(
+				PoolSystem::propose_tranche_fees(
+					RuntimeOrigin::signed(DEFAULT_POOL_OWNER),
+					DEFAULT_POOL_ID,
+					SeniorTrancheId::get(),
+					TrancheFees::default()
+				),
+				Error::<Runtime>::InvalidTrancheId
+			);
+		});
+	}
+
+	#[test]
+	fn apply_other_change() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			let change = PoolChangeProposal::new([]);
+			let change_id = PoolSystem::note(DEFAULT_POOL_ID, change.into()).unwrap();
+
+			assert_noop!(
+				PoolSystem::apply_tranche_fees(
+					RuntimeOrigin::signed(INVESTOR),
+					DEFAULT_POOL_ID,
+					change_id
+				),
+				Error::<Runtime>::NoTrancheFeesChangeId
+			);
+		});
+	}
+
+	#[test]
+	fn entry_fee_charged() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+			set_fees(Perquintill::from_percent(10), Perquintill::zero());
+
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(INVESTOR),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+				500 * CURRENCY
+			));
+
+			let preview = PoolSystem::preview_epoch(DEFAULT_POOL_ID).unwrap();
+			assert_eq!(
+				preview.invest_prices,
+				vec![Quantity::one() / Quantity::saturating_from_rational(9, 10)]
+			);
+			assert_eq!(preview.redeem_prices, vec![Quantity::one()]);
+
+			close_epoch();
+
+			System::assert_has_event(
+				Event::<Runtime>::TrancheFeesCharged {
+					pool_id: DEFAULT_POOL_ID,
+					tranche_id: JuniorTrancheId::get(),
+					entry_fee: 50 * CURRENCY,
+					exit_fee: 0,
+				}
+				.into(),
+			);
+
+			// The whole invested amount is in the reserve
+			let pool = PoolSystem::pool(DEFAULT_POOL_ID).unwrap();
+			assert_eq!(pool.reserve.total, 500 * CURRENCY);
+
+			// But the investor only obtains tokens for the amount after the fee
+			assert_ok!(Investments::collect_investments(
+				RuntimeOrigin::signed(INVESTOR),
+				(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+			));
+			assert_eq!(
+				OrmlTokens::balance(
+					CurrencyId::Tranche(DEFAULT_POOL_ID, JuniorTrancheId::get()),
+					&INVESTOR
+				),
+				450 * CURRENCY
+			);
+		});
+	}
+
+	#[test]
+	fn exit_fee_charged() {
+		new_test_ext().execute_with(|| {
+			create_pool();
+
+			let investment_id = (DEFAULT_POOL_ID, JuniorTrancheId::get());
+			assert_ok!(Investments::update_invest_order(
+				RuntimeOrigin::signed(INVESTOR),
+				investment_id,
+				500 * CURRENCY
+			));
+			close_epoch();
+			assert_ok!(Investments::collect_investments(
+				RuntimeOrigin::signed(INVESTOR),
+				investment_id,
+			));
+
+			set_fees(Perquintill::zero(), Perquintill::from_percent(10));
+
+			assert_ok!(Investments::update_redeem_order(
+				RuntimeOrigin::signed(INVESTOR),
+				investment_id,
+				500 * CURRENCY
+			));
+			close_epoch();
+
+			// The fee remains in the reserve
+			let pool = PoolSystem::pool(DEFAULT_POOL_ID).unwrap();
+			assert_eq!(pool.reserve.total, 50 * CURRENCY);
+
+			let balance = OrmlTokens::balance(AUSD_CURRENCY_ID, &INVESTOR);
+			assert_ok!(Investments::collect_redemptions(
+				RuntimeOrigin::signed(INVESTOR),
+				investment_id,
+			));
+			assert_eq!(
+				OrmlTokens::balance(AUSD_CURRENCY_ID, &INVESTOR) - balance,
+				450 * CURRENCY
+			);
+		});
+	}
 }
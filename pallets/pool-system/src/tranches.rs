@@ -214,6 +214,7 @@ where
 				pool_currency: false,
 				transferability: CrossChainTransferability::LiquidityPools,
 				local_representation: None,
+				xcm_restricted: true,
 			},
 		}
 	}
@@ -20,6 +20,13 @@ pub trait WeightInfo {
 	fn close_epoch_execute(n: u32, m: u32) -> Weight;
 	fn submit_solution(n: u32, m: u32) -> Weight;
 	fn execute_epoch(n: u32, m: u32) -> Weight;
+	fn propose_epoch_settings(n: u32) -> Weight;
+	fn apply_epoch_settings(n: u32) -> Weight;
+	fn set_pool_mode() -> Weight;
+	fn set_change_approver(n: u32) -> Weight;
+	fn approve_change(n: u32) -> Weight;
+	fn propose_tranche_fees() -> Weight;
+	fn apply_tranche_fees() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -46,4 +53,32 @@ impl WeightInfo for () {
 	fn execute_epoch(_: u32, _: u32) -> Weight {
 		Weight::zero()
 	}
+
+	fn propose_epoch_settings(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn apply_epoch_settings(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn set_pool_mode() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_change_approver(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn approve_change(_: u32) -> Weight {
+		Weight::zero()
+	}
+
+	fn propose_tranche_fees() -> Weight {
+		Weight::zero()
+	}
+
+	fn apply_tranche_fees() -> Weight {
+		Weight::zero()
+	}
 }
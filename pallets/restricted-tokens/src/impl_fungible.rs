@@ -205,6 +205,7 @@ impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
 			)),
 			Error::<T>::PreConditionsNotMet
 		);
+		Pallet::<T>::check_transfer_hooks(source, dest, T::NativeToken::get(), amount)?;
 
 		<T::NativeFungible as Mutate<T::AccountId>>::transfer(source, dest, amount, preservation)
 	}
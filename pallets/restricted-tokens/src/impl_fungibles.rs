@@ -341,6 +341,7 @@ impl<T: Config> Mutate<T::AccountId> for Pallet<T> {
 				)),
 				Error::<T>::PreConditionsNotMet
 			);
+			Pallet::<T>::check_transfer_hooks(source, dest, asset, amount)?;
 
 			<T::Fungibles as Mutate<T::AccountId>>::transfer(
 				asset,
@@ -63,7 +63,7 @@ impl<AccountId, CurrencyId, Balance> TransferDetails<AccountId, CurrencyId, Bala
 ///! Mimics ORML-tokens Call-Api.
 #[frame_support::pallet]
 pub mod pallet {
-	use cfg_traits::PreConditions;
+	use cfg_traits::{CurrencyTransferHook, PreConditions};
 	use frame_support::{
 		pallet_prelude::TypeInfo,
 		sp_runtime::{
@@ -124,6 +124,11 @@ pub mod pallet {
 			Result = bool,
 		>;
 
+		/// Transfer filters selected by currency, checked on top of the pre
+		/// conditions of the transfers via extrinsics and the fungible(s)
+		/// traits
+		type TransferHooks: CurrencyTransferHook<Self::AccountId, Self::CurrencyId, Self::Balance>;
+
 		/// Checks the pre conditions for trait fungibles::Inspect calls
 		type PreFungiblesInspect: PreConditions<
 			FungiblesInspectEffects<Self::CurrencyId, Self::AccountId, Self::Balance>,
@@ -270,6 +275,7 @@ pub mod pallet {
 				)),
 				Error::<T>::PreConditionsNotMet
 			);
+			Self::check_transfer_hooks(&from, &to, currency_id, amount)?;
 
 			let token = if T::NativeToken::get() == currency_id {
 				<T::NativeFungible as fungible::Mutate<T::AccountId>>::transfer(
@@ -349,6 +355,7 @@ pub mod pallet {
 				)),
 				Error::<T>::PreConditionsNotMet
 			);
+			Self::check_transfer_hooks(&from, &to, currency_id, reducible_balance)?;
 
 			let token = if T::NativeToken::get() == currency_id {
 				<T::NativeFungible as fungible::Mutate<T::AccountId>>::transfer(
@@ -407,6 +414,7 @@ pub mod pallet {
 				)),
 				Error::<T>::PreConditionsNotMet
 			);
+			Self::check_transfer_hooks(&from, &to, currency_id, amount)?;
 
 			let token = if T::NativeToken::get() == currency_id {
 				<T::NativeFungible as fungible::Mutate<T::AccountId>>::transfer(
@@ -586,4 +594,20 @@ pub mod pallet {
 			}
 		}
 	}
+
+	impl<T: Config> Pallet<T> {
+		/// Checks a transfer against the hooks of its currency, if any.
+		pub(crate) fn check_transfer_hooks(
+			from: &T::AccountId,
+			to: &T::AccountId,
+			currency_id: T::CurrencyId,
+			amount: T::Balance,
+		) -> DispatchResult {
+			if T::TransferHooks::applies_to(&currency_id) {
+				T::TransferHooks::check(from, to, &currency_id, amount)
+			} else {
+				Ok(())
+			}
+		}
+	}
 }
@@ -10,15 +10,15 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_traits::PreConditions;
-use frame_support::{derive_impl, parameter_types};
+use cfg_traits::{CurrencyTransferHook, PreConditions};
+use frame_support::{derive_impl, ensure, parameter_types};
 use orml_traits::parameter_type_with_key;
 use pallet_restricted_tokens::TransferDetails;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sp_runtime::{traits::ConstU32, BuildStorage};
+use sp_runtime::{traits::ConstU32, BuildStorage, DispatchError, DispatchResult};
 use sp_std::collections::btree_map::BTreeMap;
 
 pub use crate as pallet_restricted_tokens;
@@ -435,9 +435,31 @@ impl pallet_restricted_tokens::Config for Runtime {
 	type PreReservableCurrency = cfg_traits::Always;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TransferHooks = JurisdictionHook;
 	type WeightInfo = ();
 }
 
+pub const BLOCKED_JURISDICTION_ACCOUNT: AccountId = 666;
+pub const JURISDICTION_ERROR: DispatchError = DispatchError::Other("Blocked jurisdiction");
+
+// AUSD can not be sent to accounts of a blocked jurisdiction
+pub struct JurisdictionHook;
+impl CurrencyTransferHook<AccountId, CurrencyId, Balance> for JurisdictionHook {
+	fn applies_to(currency: &CurrencyId) -> bool {
+		*currency == CurrencyId::AUSD
+	}
+
+	fn check(
+		_send: &AccountId,
+		recv: &AccountId,
+		_currency: &CurrencyId,
+		_amount: Balance,
+	) -> DispatchResult {
+		ensure!(*recv != BLOCKED_JURISDICTION_ACCOUNT, JURISDICTION_ERROR);
+		Ok(())
+	}
+}
+
 // Restricted coins are only allowed to be send to users with an id over 100
 pub struct RestrictedTokens;
 impl PreConditions<TransferDetails<AccountId, CurrencyId, Balance>> for RestrictedTokens {
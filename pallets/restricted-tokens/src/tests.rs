@@ -92,6 +92,41 @@ fn transfer_fails() {
 		})
 }
 
+#[test]
+fn transfer_fails_by_currency_hook() {
+	TestExternalitiesBuilder::default()
+		.build(Some(|| {}))
+		.execute_with(|| {
+			assert_noop!(
+				pallet_restricted_tokens::Pallet::<Runtime>::transfer(
+					RuntimeOrigin::signed(1),
+					BLOCKED_JURISDICTION_ACCOUNT,
+					CurrencyId::AUSD,
+					100
+				),
+				JURISDICTION_ERROR
+			);
+			assert_noop!(
+				<pallet_restricted_tokens::Pallet::<Runtime> as fungibles::Mutate<AccountId>>::transfer(
+					CurrencyId::AUSD,
+					&1,
+					&BLOCKED_JURISDICTION_ACCOUNT,
+					100,
+					Preservation::Expendable
+				),
+				JURISDICTION_ERROR
+			);
+
+			// The hook does not apply to other currencies
+			assert_ok!(pallet_restricted_tokens::Pallet::<Runtime>::transfer(
+				RuntimeOrigin::signed(1),
+				BLOCKED_JURISDICTION_ACCOUNT,
+				CurrencyId::Cfg,
+				100
+			));
+		})
+}
+
 #[test]
 fn transfer_keep_alive_fails() {
 	TestExternalitiesBuilder::default()
@@ -29,6 +29,7 @@
 //! - Deposit and withdraw stake associated to a currency.
 //! - Claim the reward given to a staked currency.
 //! - Associate currencies to groups and moving them from one group to another.
+//! - Lock stake for a period to boost the reward it receives.
 //!
 //! ### Terminology
 //!
@@ -43,11 +44,23 @@
 //!   certain currency.
 //! - **Currency movement**: The action on moving a currency from one group to
 //!   another.
+//! - **Stake lock**: A part of the stake of an account locked for one of the
+//!   configured lock-up options. While locked, it can not be withdrawn and
+//!   weights the reward of the account by the multiplier of the option. The
+//!   extra stake given by the multiplier is called the boost.
+//! - **Early unlock**: Unlocking a stake before its lock expires. A part of the
+//!   pending reward of the account is forfeited and distributed to the stakers
+//!   of the group along with its next reward.
 //!
 //! ### Implementations
 //!
 //! The Rewards pallet provides implementations for the Rewards trait.
 //!
+//! The boosts of the locked stakes are part of the stake known by the reward
+//! mechanism, so they are included by `AccountRewards::account_stake()` and
+//! `AccountRewards::currency_stake()`. Stakes deposited before stake locks
+//! were supported are unlocked, so they do not need any migration.
+//!
 //! ### Assumptions
 //!
 //! Each consuming reward system must have its unique instance of this pallet
@@ -76,7 +89,9 @@ pub mod migrations {
 	pub mod new_instance;
 }
 
-use cfg_traits::rewards::{AccountRewards, CurrencyGroupChange, GroupRewards, RewardIssuance};
+use cfg_traits::rewards::{
+	AccountRewards, CurrencyGroupChange, GroupRewards, RewardIssuance, StakeLocks,
+};
 use frame_support::{
 	pallet_prelude::*,
 	traits::{
@@ -85,16 +100,35 @@ use frame_support::{
 	},
 	DefaultNoBound, PalletId,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 use mechanism::{MoveCurrencyError, RewardMechanism};
 pub use pallet::*;
 use parity_scale_codec::FullCodec;
-use sp_runtime::{traits::AccountIdConversion, TokenError};
-use sp_std::fmt::Debug;
+use sp_runtime::{
+	traits::{AccountIdConversion, EnsureAdd, EnsureAddAssign, Zero},
+	FixedPointNumber, FixedPointOperand, FixedU128, Perbill, TokenError,
+};
+use sp_std::{fmt::Debug, vec::Vec};
 
 type RewardCurrencyOf<T, I> = <<T as Config<I>>::RewardMechanism as RewardMechanism>::Currency;
 type RewardGroupOf<T, I> = <<T as Config<I>>::RewardMechanism as RewardMechanism>::Group;
 type RewardAccountOf<T, I> = <<T as Config<I>>::RewardMechanism as RewardMechanism>::Account;
 type BalanceOf<T, I> = <<T as Config<I>>::RewardMechanism as RewardMechanism>::Balance;
+type LockedStakeOf<T, I> = LockedStake<BalanceOf<T, I>, BlockNumberFor<T>>;
+
+/// A part of the stake of an account locked to boost its reward.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LockedStake<Balance, BlockNumber> {
+	/// The amount of the stake locked.
+	pub amount: Balance,
+
+	/// The stake added to the account to weight the reward of the locked
+	/// amount by the multiplier of its lock-up option.
+	pub boost: Balance,
+
+	/// The block from which the stake can be unlocked without penalty.
+	pub until: BlockNumber,
+}
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -141,6 +175,17 @@ pub mod pallet {
 			CurrencyId = Self::CurrencyId,
 			Balance = BalanceOf<Self, I>,
 		>;
+
+		/// Lock-up options of the stakes, as pairs of the lock duration in
+		/// blocks and the multiplier applied to the locked stake. Empty if
+		/// stakes can not be locked.
+		#[pallet::constant]
+		type LockOptions: Get<Vec<(BlockNumberFor<Self>, FixedU128)>>;
+
+		/// Part of the pending reward of an account forfeited when it unlocks
+		/// its stake before the lock expires.
+		#[pallet::constant]
+		type EarlyUnlockPenalty: Get<Perbill>;
 	}
 
 	#[pallet::pallet]
@@ -201,6 +246,22 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	#[pallet::storage]
+	pub(super) type StakeLock<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::CurrencyId,
+		LockedStakeOf<T, I>,
+	>;
+
+	/// Reward forfeited by early unlocks of the stakes of a group, distributed
+	/// along with the next reward of the group.
+	#[pallet::storage]
+	pub(super) type ForfeitedReward<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::GroupId, BalanceOf<T, I>, ValueQuery>;
+
 	// --------------------------
 
 	#[pallet::event]
@@ -233,6 +294,21 @@ pub mod pallet {
 			from: Option<T::GroupId>,
 			to: T::GroupId,
 		},
+		StakeLocked {
+			group_id: T::GroupId,
+			currency_id: T::CurrencyId,
+			account_id: T::AccountId,
+			amount: BalanceOf<T, I>,
+			boost: BalanceOf<T, I>,
+			until: BlockNumberFor<T>,
+		},
+		StakeUnlocked {
+			group_id: T::GroupId,
+			currency_id: T::CurrencyId,
+			account_id: T::AccountId,
+			amount: BalanceOf<T, I>,
+			forfeited: BalanceOf<T, I>,
+		},
 	}
 
 	#[pallet::error]
@@ -245,6 +321,15 @@ pub mod pallet {
 
 		// Emits when a currency is moved more than the mechanism allows.
 		CurrencyMaxMovementsReached,
+
+		// Emits when a stake is locked with an option that is not configured.
+		LockOptionNotFound,
+
+		// Emits when a stake is locked while it already has a lock.
+		StakeAlreadyLocked,
+
+		// Emits when a stake without lock is unlocked.
+		StakeNotLocked,
 	}
 
 	impl<T: Config<I>, I: 'static> GroupRewards for Pallet<T, I>
@@ -264,7 +349,19 @@ pub mod pallet {
 			reward: Self::Balance,
 		) -> Result<Self::Balance, DispatchError> {
 			Group::<T, I>::try_mutate(group_id, |group| {
-				let reward_to_mint = T::RewardMechanism::reward_group(group, reward)?;
+				let forfeited = ForfeitedReward::<T, I>::take(group_id);
+				let reward_used =
+					T::RewardMechanism::reward_group(group, reward.ensure_add(forfeited)?)?;
+
+				// The forfeited reward is already in the pallet account.
+				let reward_to_mint = reward_used.saturating_sub(forfeited);
+				if reward_used < forfeited {
+					ForfeitedReward::<T, I>::insert(
+						group_id,
+						forfeited.saturating_sub(reward_used),
+					);
+				}
+
 				T::RewardIssuance::issue_reward(
 					T::RewardCurrency::get(),
 					&T::PalletId::get().into_account_truncating(),
@@ -336,7 +433,13 @@ pub mod pallet {
 
 				Group::<T, I>::try_mutate(group_id, |group| {
 					StakeAccount::<T, I>::try_mutate(account_id, currency_id.clone(), |account| {
-						if T::RewardMechanism::account_stake(account) < amount {
+						let locked = StakeLock::<T, I>::get(account_id, currency_id.clone())
+							.map(|lock| lock.amount.saturating_add(lock.boost))
+							.unwrap_or_default();
+
+						if T::RewardMechanism::account_stake(account).saturating_sub(locked)
+							< amount
+						{
 							Err(TokenError::FundsUnavailable)?;
 						}
 
@@ -415,6 +518,136 @@ pub mod pallet {
 			let account = StakeAccount::<T, I>::get(account_id, currency_id);
 			T::RewardMechanism::account_stake(&account)
 		}
+
+		fn currency_stake(currency_id: Self::CurrencyId) -> Self::Balance {
+			let (_, currency) = Currency::<T, I>::get(currency_id);
+			T::RewardMechanism::currency_stake(&currency)
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> StakeLocks<T::AccountId> for Pallet<T, I>
+	where
+		RewardGroupOf<T, I>: FullCodec + Default,
+		RewardAccountOf<T, I>: FullCodec + Default,
+		RewardCurrencyOf<T, I>: FullCodec + Default,
+		BalanceOf<T, I>: FixedPointOperand,
+	{
+		fn lock_stake(
+			currency_id: Self::CurrencyId,
+			account_id: &T::AccountId,
+			amount: Self::Balance,
+			option: u32,
+		) -> DispatchResult {
+			let (duration, multiplier) = T::LockOptions::get()
+				.get(option as usize)
+				.copied()
+				.ok_or(Error::<T, I>::LockOptionNotFound)?;
+
+			if StakeLock::<T, I>::contains_key(account_id, currency_id.clone()) {
+				Err(Error::<T, I>::StakeAlreadyLocked)?;
+			}
+
+			Currency::<T, I>::try_mutate(currency_id.clone(), |(group_id, currency)| {
+				let group_id = group_id.ok_or(Error::<T, I>::CurrencyWithoutGroup)?;
+
+				Group::<T, I>::try_mutate(group_id, |group| {
+					StakeAccount::<T, I>::try_mutate(account_id, currency_id.clone(), |account| {
+						if T::RewardMechanism::account_stake(account) < amount {
+							Err(TokenError::FundsUnavailable)?;
+						}
+
+						let boost = multiplier.saturating_mul_int(amount).saturating_sub(amount);
+						let until =
+							frame_system::Pallet::<T>::block_number().ensure_add(duration)?;
+
+						T::RewardMechanism::deposit_stake(account, currency, group, boost)?;
+
+						StakeLock::<T, I>::insert(
+							account_id,
+							currency_id.clone(),
+							LockedStake {
+								amount,
+								boost,
+								until,
+							},
+						);
+
+						Self::deposit_event(Event::StakeLocked {
+							group_id,
+							currency_id,
+							account_id: account_id.clone(),
+							amount,
+							boost,
+							until,
+						});
+
+						Ok(())
+					})
+				})
+			})
+		}
+
+		fn unlock_stake(
+			currency_id: Self::CurrencyId,
+			account_id: &T::AccountId,
+		) -> Result<Self::Balance, DispatchError> {
+			let lock = StakeLock::<T, I>::get(account_id, currency_id.clone())
+				.ok_or(Error::<T, I>::StakeNotLocked)?;
+
+			Currency::<T, I>::try_mutate(currency_id.clone(), |(group_id, currency)| {
+				let group_id = group_id.ok_or(Error::<T, I>::CurrencyWithoutGroup)?;
+
+				Group::<T, I>::try_mutate(group_id, |group| {
+					StakeAccount::<T, I>::try_mutate(account_id, currency_id.clone(), |account| {
+						let mut forfeited = Zero::zero();
+
+						if frame_system::Pallet::<T>::block_number() < lock.until {
+							let reward =
+								T::RewardMechanism::claim_reward(account, currency, group)?;
+							forfeited = T::EarlyUnlockPenalty::get().mul_floor(reward);
+							let claimed = reward.saturating_sub(forfeited);
+
+							T::Currency::transfer(
+								T::RewardCurrency::get(),
+								&T::PalletId::get().into_account_truncating(),
+								account_id,
+								claimed,
+								Preservation::Protect,
+							)?;
+							ForfeitedReward::<T, I>::try_mutate(group_id, |reward| {
+								reward.ensure_add_assign(forfeited)
+							})?;
+
+							Self::deposit_event(Event::RewardClaimed {
+								group_id,
+								currency_id: currency_id.clone(),
+								account_id: account_id.clone(),
+								amount: claimed,
+							});
+						}
+
+						T::RewardMechanism::withdraw_stake(account, currency, group, lock.boost)?;
+
+						StakeLock::<T, I>::remove(account_id, currency_id.clone());
+
+						Self::deposit_event(Event::StakeUnlocked {
+							group_id,
+							currency_id,
+							account_id: account_id.clone(),
+							amount: lock.amount,
+							forfeited,
+						});
+
+						Ok(forfeited)
+					})
+				})
+			})
+		}
+
+		fn is_lock_expired(currency_id: Self::CurrencyId, account_id: &T::AccountId) -> bool {
+			StakeLock::<T, I>::get(account_id, currency_id)
+				.is_some_and(|lock| frame_system::Pallet::<T>::block_number() >= lock.until)
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> CurrencyGroupChange for Pallet<T, I>
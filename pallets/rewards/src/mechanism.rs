@@ -69,6 +69,9 @@ pub trait RewardMechanism {
 
 	/// Returns the balance of a group
 	fn group_stake(group: &Self::Group) -> Self::Balance;
+
+	/// Returns the balance of a currency
+	fn currency_stake(currency: &Self::Currency) -> Self::Balance;
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -215,4 +215,8 @@ where
 	fn group_stake(group: &Self::Group) -> Self::Balance {
 		group.total_stake
 	}
+
+	fn currency_stake(currency: &Self::Currency) -> Self::Balance {
+		currency.total_stake
+	}
 }
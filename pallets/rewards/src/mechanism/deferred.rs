@@ -321,5 +321,9 @@ pub mod pallet {
 		fn group_stake(group: &Self::Group) -> Self::Balance {
 			group.base.total_stake
 		}
+
+		fn currency_stake(currency: &Self::Currency) -> Self::Balance {
+			currency.base.total_stake
+		}
 	}
 }
@@ -355,5 +355,9 @@ pub mod pallet {
 		fn group_stake(group: &Self::Group) -> Self::Balance {
 			group.total_stake.saturating_add(group.pending_total_stake)
 		}
+
+		fn currency_stake(currency: &Self::Currency) -> Self::Balance {
+			currency.total_stake
+		}
 	}
 }
@@ -1,6 +1,6 @@
 use frame_support::{derive_impl, pallet_prelude::*, PalletId};
 use serde::{Deserialize, Serialize};
-use sp_runtime::{BuildStorage, FixedI64};
+use sp_runtime::{BuildStorage, FixedI64, FixedU128, Perbill};
 
 use super::mechanism::{self};
 use crate::{
@@ -14,6 +14,8 @@ pub const REWARD_SOURCE: u64 = 1337;
 
 pub const USER_INITIAL_BALANCE: u64 = 100000;
 
+pub const LOCK_DURATION: u64 = 10;
+
 frame_support::construct_runtime!(
 	pub enum Runtime {
 		System: frame_system,
@@ -87,6 +89,11 @@ frame_support::parameter_types! {
 	#[derive(scale_info::TypeInfo, Default, RuntimeDebug)]
 	pub const MaxCurrencyMovements: u32 = 3;
 	pub const RewardSource: u64 = REWARD_SOURCE;
+	pub LockOptions: Vec<(u64, FixedU128)> = vec![
+		(LOCK_DURATION, FixedU128::from_rational(3, 2)),
+		(LOCK_DURATION * 2, FixedU128::from_rational(2, 1)),
+	];
+	pub const EarlyUnlockPenalty: Perbill = Perbill::from_percent(50);
 }
 
 impl mechanism::gap::Config for Runtime {
@@ -110,7 +117,9 @@ macro_rules! pallet_rewards_config {
 		impl pallet_rewards::Config<pallet_rewards::$instance> for Runtime {
 			type Currency = Tokens;
 			type CurrencyId = CurrencyId;
+			type EarlyUnlockPenalty = EarlyUnlockPenalty;
 			type GroupId = u32;
+			type LockOptions = LockOptions;
 			type PalletId = RewardsPalletId;
 			type RewardCurrency = RewardCurrency;
 			type RewardIssuance = $issuance;
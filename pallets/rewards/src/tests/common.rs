@@ -58,6 +58,23 @@ macro_rules! stake_common_tests {
 					);
 				});
 			}
+
+			#[test]
+			fn currency_stake() {
+				const STAKE_1: u64 = 5000;
+				const STAKE_2: u64 = 1000;
+
+				new_test_ext().execute_with(|| {
+					assert_ok!($pallet::attach_currency(CURRENCY_X, GROUP_1));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_A, STAKE_1));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_B, STAKE_2));
+					assert_eq!($pallet::currency_stake(CURRENCY_X), STAKE_1 + STAKE_2);
+
+					assert_ok!($pallet::withdraw_stake(CURRENCY_X, &USER_B, STAKE_2));
+					assert_eq!($pallet::currency_stake(CURRENCY_X), STAKE_1);
+					assert_eq!($pallet::currency_stake(CURRENCY_Y), 0);
+				});
+			}
 		}
 	};
 }
@@ -429,6 +446,138 @@ macro_rules! claim_common_tests {
 	};
 }
 
+#[macro_export]
+macro_rules! lock_common_tests {
+	($pallet:ident, $instance:ident, $kind:expr) => {
+		mod lock {
+			use super::*;
+
+			#[test]
+			fn boosts_reward() {
+				new_test_ext().execute_with(|| {
+					assert_ok!($pallet::attach_currency(CURRENCY_X, GROUP_1));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_A, STAKE_A));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_B, STAKE_A));
+
+					// Locked with a multiplier of 1.5x
+					assert_ok!($pallet::lock_stake(CURRENCY_X, &USER_A, STAKE_A, 0));
+					assert_eq!($pallet::account_stake(CURRENCY_X, &USER_A), STAKE_A * 3 / 2);
+
+					if $kind != MechanismKind::Base {
+						assert_ok!($pallet::distribute_reward(REWARD, [GROUP_1]));
+					}
+					assert_ok!($pallet::distribute_reward(REWARD, [GROUP_1]));
+
+					assert_ok!($pallet::claim_reward(CURRENCY_X, &USER_A), REWARD * 3 / 5);
+					assert_ok!($pallet::claim_reward(CURRENCY_X, &USER_B), REWARD * 2 / 5);
+				});
+			}
+
+			#[test]
+			fn locked_stake_can_not_be_withdrawn() {
+				new_test_ext().execute_with(|| {
+					assert_ok!($pallet::attach_currency(CURRENCY_X, GROUP_1));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_A, STAKE_A));
+					assert_ok!($pallet::lock_stake(CURRENCY_X, &USER_A, STAKE_A / 2, 1));
+
+					assert_noop!(
+						$pallet::withdraw_stake(CURRENCY_X, &USER_A, STAKE_A / 2 + 1),
+						TokenError::FundsUnavailable
+					);
+					assert_ok!($pallet::withdraw_stake(CURRENCY_X, &USER_A, STAKE_A / 2));
+				});
+			}
+
+			#[test]
+			fn invalid_locks() {
+				new_test_ext().execute_with(|| {
+					assert_ok!($pallet::attach_currency(CURRENCY_X, GROUP_1));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_A, STAKE_A));
+
+					assert_noop!(
+						$pallet::lock_stake(CURRENCY_X, &USER_A, STAKE_A, 2),
+						Error::<Runtime, $instance>::LockOptionNotFound
+					);
+					assert_noop!(
+						$pallet::lock_stake(CURRENCY_X, &USER_A, STAKE_A + 1, 0),
+						TokenError::FundsUnavailable
+					);
+					assert_noop!(
+						$pallet::unlock_stake(CURRENCY_X, &USER_A),
+						Error::<Runtime, $instance>::StakeNotLocked
+					);
+
+					assert_ok!($pallet::lock_stake(CURRENCY_X, &USER_A, STAKE_A, 0));
+					assert_noop!(
+						$pallet::lock_stake(CURRENCY_X, &USER_A, STAKE_A, 0),
+						Error::<Runtime, $instance>::StakeAlreadyLocked
+					);
+				});
+			}
+
+			#[test]
+			fn early_unlock_forfeits_reward() {
+				new_test_ext().execute_with(|| {
+					assert_ok!($pallet::attach_currency(CURRENCY_X, GROUP_1));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_A, STAKE_A));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_B, STAKE_A));
+					assert_ok!($pallet::lock_stake(CURRENCY_X, &USER_A, STAKE_A, 0));
+
+					if $kind != MechanismKind::Base {
+						assert_ok!($pallet::distribute_reward(REWARD, [GROUP_1]));
+					}
+					assert_ok!($pallet::distribute_reward(REWARD, [GROUP_1]));
+
+					assert!(!$pallet::is_lock_expired(CURRENCY_X, &USER_A));
+
+					// Half of the reward is forfeited
+					let reward = REWARD * 3 / 5;
+					assert_ok!($pallet::unlock_stake(CURRENCY_X, &USER_A), reward / 2);
+					assert_eq!(free_balance(CurrencyId::Reward, &USER_A), reward / 2);
+					assert_eq!($pallet::account_stake(CURRENCY_X, &USER_A), STAKE_A);
+					assert_eq!(
+						ForfeitedReward::<Runtime, $instance>::get(GROUP_1),
+						reward / 2
+					);
+
+					assert_ok!($pallet::distribute_reward(REWARD, [GROUP_1]));
+					assert_eq!(ForfeitedReward::<Runtime, $instance>::get(GROUP_1), 0);
+
+					if $kind == MechanismKind::Base {
+						let distributed = (REWARD + reward / 2) / 2;
+						assert_ok!($pallet::claim_reward(CURRENCY_X, &USER_A), distributed);
+						assert_ok!(
+							$pallet::claim_reward(CURRENCY_X, &USER_B),
+							REWARD * 2 / 5 + distributed
+						);
+					}
+				});
+			}
+
+			#[test]
+			fn expired_unlock_keeps_reward() {
+				new_test_ext().execute_with(|| {
+					assert_ok!($pallet::attach_currency(CURRENCY_X, GROUP_1));
+					assert_ok!($pallet::deposit_stake(CURRENCY_X, &USER_A, STAKE_A));
+					assert_ok!($pallet::lock_stake(CURRENCY_X, &USER_A, STAKE_A, 0));
+
+					if $kind != MechanismKind::Base {
+						assert_ok!($pallet::distribute_reward(REWARD, [GROUP_1]));
+					}
+					assert_ok!($pallet::distribute_reward(REWARD, [GROUP_1]));
+
+					System::set_block_number(LOCK_DURATION);
+					assert!($pallet::is_lock_expired(CURRENCY_X, &USER_A));
+
+					assert_ok!($pallet::unlock_stake(CURRENCY_X, &USER_A), 0);
+					assert_ok!($pallet::claim_reward(CURRENCY_X, &USER_A), REWARD);
+					assert_ok!($pallet::withdraw_stake(CURRENCY_X, &USER_A, STAKE_A));
+				});
+			}
+		}
+	};
+}
+
 #[macro_export]
 macro_rules! common_tests {
 	($pallet:ident, $instance:ident, $kind:expr) => {
@@ -436,5 +585,6 @@ macro_rules! common_tests {
 		unstake_common_tests!($pallet, $instance);
 		currency_common_tests!($pallet, $instance);
 		claim_common_tests!($pallet, $instance, $kind);
+		lock_common_tests!($pallet, $instance, $kind);
 	};
 }
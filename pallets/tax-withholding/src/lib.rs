@@ -0,0 +1,495 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+//! # Tax Withholding Pallet
+//!
+//! Optional withholding of taxes on the interest earned by pool investors,
+//! applied when they collect their redemptions.
+//!
+//! A pool admin configures the withholding of a pool with a rate per
+//! jurisdiction class and the account receiving the withheld amounts, and
+//! assigns a class to each investor subject to withholding. Governance bounds
+//! the rates by a maximum rate, which also caps the rates already configured
+//! when lowered.
+//!
+//! The pallet keeps the cost basis of each investor per tranche, i.e. the
+//! tranche tokens collected from investments and the pool currency paid for
+//! them. When a redemption is collected, the gain is the payout of the
+//! redeemed tokens minus their average cost. The rate of the investor class is
+//! applied to the gain, and the withheld amount is transferred in the pool
+//! currency from the investor to the withholding account. Tokens without a cost
+//! basis, e.g. received by transfer, are considered to have no gain.
+//!
+//! The amounts withheld are accumulated per investor for reporting.
+//!
+//! The pallet is hooked into the investments pallet by wrapping its collected
+//! investment and redemption hooks, see [`CollectedInvestmentHook`] and
+//! [`CollectedRedemptionHook`].
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use cfg_traits::{investments::TrancheCurrency, StatusNotificationHook};
+use cfg_types::investments::CollectedAmount;
+use frame_support::{pallet_prelude::*, CloneNoBound, PartialEqNoBound, RuntimeDebugNoBound};
+pub use pallet::*;
+use sp_runtime::Perbill;
+use sp_std::marker::PhantomData;
+pub use weights::WeightInfo;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+/// The withholding of a pool.
+#[derive(
+	Encode, Decode, CloneNoBound, PartialEqNoBound, Eq, RuntimeDebugNoBound, TypeInfo, MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct WithholdingConfig<T: Config> {
+	/// The account receiving the withheld amounts.
+	pub account: T::AccountId,
+
+	/// The rate withheld per jurisdiction class. Investors of a class without
+	/// rate are not subject to withholding.
+	pub rates: BoundedBTreeMap<T::JurisdictionClass, Perbill, T::MaxClasses>,
+}
+
+/// The tranche tokens of an investor collected from investments and the pool
+/// currency paid for them.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct CostBasis<Balance> {
+	/// The tranche tokens with a known cost.
+	pub tokens: Balance,
+
+	/// The pool currency paid for the tokens.
+	pub cost: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use cfg_traits::{PoolInspect, PreConditions};
+	use frame_support::traits::{
+		fungibles,
+		tokens::{self, Preservation},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_arithmetic::{MultiplyRational, Rounding};
+	use sp_runtime::{
+		traits::{EnsureAddAssign, EnsureSubAssign, Zero},
+		ArithmeticError,
+	};
+
+	use super::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		type Balance: tokens::Balance + MultiplyRational;
+
+		type PoolId: Member + Parameter + Copy + MaxEncodedLen;
+
+		type TrancheId: Member + Parameter + Copy + MaxEncodedLen;
+
+		type CurrencyId: Member + Parameter + Copy + MaxEncodedLen;
+
+		type InvestmentId: Member
+			+ Parameter
+			+ Copy
+			+ MaxEncodedLen
+			+ TrancheCurrency<Self::PoolId, Self::TrancheId>;
+
+		/// The class of the jurisdiction of an investor, determining its
+		/// withholding rate.
+		type JurisdictionClass: Member + Parameter + Copy + Ord + MaxEncodedLen;
+
+		/// The tokens used to transfer the withheld amounts.
+		type Tokens: fungibles::Mutate<
+			Self::AccountId,
+			AssetId = Self::CurrencyId,
+			Balance = Self::Balance,
+		>;
+
+		/// The source of truth for pool existence and currency.
+		type Pools: PoolInspect<
+			Self::AccountId,
+			Self::CurrencyId,
+			PoolId = Self::PoolId,
+			TrancheId = Self::TrancheId,
+		>;
+
+		/// Used to verify pool admin permissions.
+		type IsPoolAdmin: PreConditions<(Self::AccountId, Self::PoolId), Result = bool>;
+
+		/// The origin allowed to set the maximum withholding rate.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum number of jurisdiction classes with a rate per pool.
+		#[pallet::constant]
+		type MaxClasses: Get<u32>;
+
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The maximum rate withheld, capping the rates of every pool.
+	#[pallet::storage]
+	pub type MaxRate<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	#[pallet::storage]
+	pub type Configs<T: Config> = StorageMap<_, Blake2_128Concat, T::PoolId, WithholdingConfig<T>>;
+
+	/// The jurisdiction class of the investors of a pool subject to
+	/// withholding.
+	#[pallet::storage]
+	pub type InvestorClasses<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::JurisdictionClass,
+	>;
+
+	#[pallet::storage]
+	pub type CostBases<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::InvestmentId,
+		CostBasis<T::Balance>,
+		ValueQuery,
+	>;
+
+	/// The total amount withheld from an investor of a pool, in the pool
+	/// currency.
+	#[pallet::storage]
+	pub type Withheld<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The maximum withholding rate was set.
+		MaxRateSet { rate: Perbill },
+
+		/// The withholding of a pool was set.
+		WithholdingSet {
+			pool_id: T::PoolId,
+			account: T::AccountId,
+			rates: BoundedBTreeMap<T::JurisdictionClass, Perbill, T::MaxClasses>,
+		},
+
+		/// The withholding of a pool was removed.
+		WithholdingRemoved { pool_id: T::PoolId },
+
+		/// The jurisdiction class of an investor was set or cleared.
+		InvestorClassSet {
+			pool_id: T::PoolId,
+			investor: T::AccountId,
+			class: Option<T::JurisdictionClass>,
+		},
+
+		/// An amount was withheld from the gain of a collected redemption.
+		Withheld {
+			investment_id: T::InvestmentId,
+			investor: T::AccountId,
+			gain: T::Balance,
+			amount: T::Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The pool does not exist.
+		PoolNotFound,
+
+		/// The pool has no withholding.
+		WithholdingNotFound,
+
+		/// A rate is greater than the maximum rate.
+		RateTooHigh,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Sets the maximum rate withheld. Configured rates greater than it
+		/// are capped to it.
+		#[pallet::weight(T::WeightInfo::set_max_rate())]
+		#[pallet::call_index(0)]
+		pub fn set_max_rate(origin: OriginFor<T>, rate: Perbill) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			MaxRate::<T>::put(rate);
+
+			Self::deposit_event(Event::MaxRateSet { rate });
+
+			Ok(())
+		}
+
+		/// Sets the withholding of a pool, replacing the previous one.
+		///
+		/// The caller must be admin of the pool.
+		#[pallet::weight(T::WeightInfo::set_withholding())]
+		#[pallet::call_index(1)]
+		pub fn set_withholding(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			account: T::AccountId,
+			rates: BoundedBTreeMap<T::JurisdictionClass, Perbill, T::MaxClasses>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				DispatchError::BadOrigin
+			);
+			ensure!(T::Pools::pool_exists(pool_id), Error::<T>::PoolNotFound);
+
+			let max_rate = MaxRate::<T>::get();
+			ensure!(
+				rates.values().all(|rate| *rate <= max_rate),
+				Error::<T>::RateTooHigh
+			);
+
+			Configs::<T>::insert(
+				pool_id,
+				WithholdingConfig {
+					account: account.clone(),
+					rates: rates.clone(),
+				},
+			);
+
+			Self::deposit_event(Event::WithholdingSet {
+				pool_id,
+				account,
+				rates,
+			});
+
+			Ok(())
+		}
+
+		/// Removes the withholding of a pool.
+		///
+		/// The caller must be admin of the pool.
+		#[pallet::weight(T::WeightInfo::remove_withholding())]
+		#[pallet::call_index(2)]
+		pub fn remove_withholding(origin: OriginFor<T>, pool_id: T::PoolId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				DispatchError::BadOrigin
+			);
+
+			Configs::<T>::take(pool_id).ok_or(Error::<T>::WithholdingNotFound)?;
+
+			Self::deposit_event(Event::WithholdingRemoved { pool_id });
+
+			Ok(())
+		}
+
+		/// Sets the jurisdiction class of an investor of a pool, or clears it
+		/// if `None`.
+		///
+		/// The caller must be admin of the pool.
+		#[pallet::weight(T::WeightInfo::set_investor_class())]
+		#[pallet::call_index(3)]
+		pub fn set_investor_class(
+			origin: OriginFor<T>,
+			pool_id: T::PoolId,
+			investor: T::AccountId,
+			class: Option<T::JurisdictionClass>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				T::IsPoolAdmin::check((who, pool_id)),
+				DispatchError::BadOrigin
+			);
+			ensure!(T::Pools::pool_exists(pool_id), Error::<T>::PoolNotFound);
+
+			InvestorClasses::<T>::set(pool_id, &investor, class);
+
+			Self::deposit_event(Event::InvestorClassSet {
+				pool_id,
+				investor,
+				class,
+			});
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account receiving the amounts withheld from an investor of a
+		/// pool and the rate withheld, if the investor is subject to
+		/// withholding.
+		pub fn withholding(
+			pool_id: T::PoolId,
+			investor: &T::AccountId,
+		) -> Option<(T::AccountId, Perbill)> {
+			let config = Configs::<T>::get(pool_id)?;
+			let class = InvestorClasses::<T>::get(pool_id, investor)?;
+			let rate = *config.rates.get(&class)?;
+
+			Some((config.account, rate.min(MaxRate::<T>::get())))
+		}
+
+		/// Adds the tranche tokens collected from an investment to the cost
+		/// basis of the investor.
+		pub(crate) fn record_investment(
+			investor: &T::AccountId,
+			investment_id: T::InvestmentId,
+			collected: &CollectedAmount<T::Balance, T::Balance>,
+		) -> DispatchResult {
+			CostBases::<T>::try_mutate(investor, investment_id, |basis| {
+				basis.tokens.ensure_add_assign(collected.amount_collected)?;
+				basis.cost.ensure_add_assign(collected.amount_payment)?;
+
+				Ok(())
+			})
+		}
+
+		/// Removes the redeemed tranche tokens from the cost basis of the
+		/// investor and withholds the rate of the investor from the gain of
+		/// the redemption. Returns the amount withheld.
+		pub(crate) fn withhold_redemption(
+			investor: &T::AccountId,
+			investment_id: T::InvestmentId,
+			collected: &CollectedAmount<T::Balance, T::Balance>,
+		) -> Result<T::Balance, DispatchError> {
+			let gain = CostBases::<T>::try_mutate_exists(investor, investment_id, |maybe_basis| {
+				let Some(mut basis) = maybe_basis.take() else {
+					return Ok::<_, DispatchError>(Zero::zero());
+				};
+
+				// Only the tokens with a known cost are considered to have a gain.
+				let redeemed = collected.amount_payment.min(basis.tokens);
+				let cost = basis
+					.cost
+					.multiply_rational(redeemed, basis.tokens, Rounding::Up)
+					.ok_or(ArithmeticError::Overflow)?;
+				let payout = collected
+					.amount_collected
+					.multiply_rational(redeemed, collected.amount_payment, Rounding::Down)
+					.ok_or(ArithmeticError::Overflow)?;
+
+				basis.tokens.ensure_sub_assign(redeemed)?;
+				basis.cost.ensure_sub_assign(cost)?;
+				if !basis.tokens.is_zero() {
+					*maybe_basis = Some(basis);
+				}
+
+				Ok(payout.saturating_sub(cost))
+			})?;
+
+			let pool_id = investment_id.of_pool();
+			let Some((account, rate)) = Self::withholding(pool_id, investor) else {
+				return Ok(Zero::zero());
+			};
+
+			let amount = rate.mul_floor(gain);
+			if amount.is_zero() {
+				return Ok(Zero::zero());
+			}
+
+			let currency = T::Pools::currency_for(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			T::Tokens::transfer(
+				currency,
+				investor,
+				&account,
+				amount,
+				Preservation::Expendable,
+			)?;
+
+			Withheld::<T>::try_mutate(pool_id, investor, |withheld| {
+				withheld.ensure_add_assign(amount)
+			})?;
+
+			Self::deposit_event(Event::Withheld {
+				investment_id,
+				investor: investor.clone(),
+				gain,
+				amount,
+			});
+
+			Ok(amount)
+		}
+	}
+}
+
+/// Hook recording the cost basis of collected investments before notifying
+/// `Next`.
+pub struct CollectedInvestmentHook<T, Next>(PhantomData<(T, Next)>);
+
+impl<T, Next> StatusNotificationHook for CollectedInvestmentHook<T, Next>
+where
+	T: Config,
+	Next: StatusNotificationHook<
+		Id = (T::AccountId, T::InvestmentId),
+		Status = CollectedAmount<T::Balance, T::Balance>,
+		Error = DispatchError,
+	>,
+{
+	type Error = DispatchError;
+	type Id = (T::AccountId, T::InvestmentId);
+	type Status = CollectedAmount<T::Balance, T::Balance>;
+
+	fn notify_status_change(
+		(investor, investment_id): Self::Id,
+		collected: Self::Status,
+	) -> DispatchResult {
+		Pallet::<T>::record_investment(&investor, investment_id, &collected)?;
+
+		Next::notify_status_change((investor, investment_id), collected)
+	}
+}
+
+/// Hook withholding taxes on collected redemptions before notifying `Next`
+/// with the payout net of the amount withheld.
+pub struct CollectedRedemptionHook<T, Next>(PhantomData<(T, Next)>);
+
+impl<T, Next> StatusNotificationHook for CollectedRedemptionHook<T, Next>
+where
+	T: Config,
+	Next: StatusNotificationHook<
+		Id = (T::AccountId, T::InvestmentId),
+		Status = CollectedAmount<T::Balance, T::Balance>,
+		Error = DispatchError,
+	>,
+{
+	type Error = DispatchError;
+	type Id = (T::AccountId, T::InvestmentId);
+	type Status = CollectedAmount<T::Balance, T::Balance>;
+
+	fn notify_status_change(
+		(investor, investment_id): Self::Id,
+		mut collected: Self::Status,
+	) -> DispatchResult {
+		let withheld = Pallet::<T>::withhold_redemption(&investor, investment_id, &collected)?;
+		collected.amount_collected = collected.amount_collected.saturating_sub(withheld);
+
+		Next::notify_status_change((investor, investment_id), collected)
+	}
+}
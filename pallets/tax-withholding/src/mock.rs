@@ -0,0 +1,112 @@
+use cfg_mocks::{
+	pallet_mock_pools, pallet_mock_status_notification,
+	pre_conditions::pallet as pallet_mock_pre_conditions,
+};
+use cfg_primitives::{Balance, PoolId, TrancheId};
+use cfg_types::{fixed_point::Ratio, investments::CollectedAmount, tokens::CurrencyId};
+use frame_support::{derive_impl, traits::ConstU32};
+use frame_system::EnsureRoot;
+use orml_traits::MultiCurrency;
+use sp_io::TestExternalities;
+
+use crate::pallet as pallet_tax_withholding;
+
+pub type AccountId = u64;
+pub type InvestmentId = (PoolId, TrancheId);
+
+pub const ADMIN: AccountId = 1;
+pub const INVESTOR: AccountId = 2;
+pub const TAX_ACCOUNT: AccountId = 3;
+
+pub const POOL_A: PoolId = 1;
+pub const TRANCHE_A: TrancheId = [1; 16];
+pub const INVESTMENT_A: InvestmentId = (POOL_A, TRANCHE_A);
+pub const POOL_CURRENCY: CurrencyId = CurrencyId::ForeignAsset(1);
+
+pub const CLASS_A: u8 = 1;
+pub const CLASS_B: u8 = 2;
+
+pub const INITIAL_BALANCE: Balance = 1_000_000;
+
+frame_support::construct_runtime!(
+	pub enum Runtime {
+		System: frame_system,
+		MockPools: pallet_mock_pools,
+		MockIsAdmin: pallet_mock_pre_conditions,
+		MockNext: pallet_mock_status_notification,
+		OrmlTokens: orml_tokens,
+		TaxWithholding: pallet_tax_withholding,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Runtime {
+	type Block = frame_system::mocking::MockBlock<Runtime>;
+}
+
+impl pallet_mock_pools::Config for Runtime {
+	type Balance = Balance;
+	type BalanceRatio = Ratio;
+	type CurrencyId = CurrencyId;
+	type PoolId = PoolId;
+	type TrancheId = TrancheId;
+}
+
+impl pallet_mock_pre_conditions::Config for Runtime {
+	type Conditions = (AccountId, PoolId);
+	type Result = bool;
+}
+
+impl pallet_mock_status_notification::Config for Runtime {
+	type Id = (AccountId, InvestmentId);
+	type Status = CollectedAmount<Balance, Balance>;
+}
+
+orml_traits::parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		1
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type Amount = i64;
+	type Balance = Balance;
+	type CurrencyHooks = ();
+	type CurrencyId = CurrencyId;
+	type DustRemovalWhitelist = frame_support::traits::Nothing;
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ConstU32<10>;
+	type MaxReserves = ConstU32<10>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+}
+
+impl pallet_tax_withholding::Config for Runtime {
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+	type InvestmentId = InvestmentId;
+	type IsPoolAdmin = MockIsAdmin;
+	type JurisdictionClass = u8;
+	type MaxClasses = ConstU32<2>;
+	type PoolId = PoolId;
+	type Pools = MockPools;
+	type RuntimeEvent = RuntimeEvent;
+	type Tokens = OrmlTokens;
+	type TrancheId = TrancheId;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut ext = System::externalities();
+	ext.execute_with(|| {
+		MockIsAdmin::mock_check(|(who, pool_id)| who == ADMIN && pool_id == POOL_A);
+		MockPools::mock_pool_exists(|pool_id| pool_id == POOL_A);
+		MockPools::mock_currency_for(|pool_id| (pool_id == POOL_A).then_some(POOL_CURRENCY));
+		MockNext::mock_notify_status_change(|_, _| Ok(()));
+
+		OrmlTokens::deposit(POOL_CURRENCY, &INVESTOR, INITIAL_BALANCE).unwrap();
+	});
+	ext
+}
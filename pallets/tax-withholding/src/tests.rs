@@ -0,0 +1,328 @@
+use cfg_primitives::Balance;
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::{BoundedBTreeMap, DispatchError, Perbill};
+
+use super::*;
+use crate::mock::*;
+
+type InvestmentHook = CollectedInvestmentHook<Runtime, MockNext>;
+type RedemptionHook = CollectedRedemptionHook<Runtime, MockNext>;
+
+fn rates(rates: &[(u8, Perbill)]) -> BoundedBTreeMap<u8, Perbill, ConstU32<2>> {
+	rates
+		.iter()
+		.copied()
+		.collect::<sp_std::collections::btree_map::BTreeMap<_, _>>()
+		.try_into()
+		.unwrap()
+}
+
+fn config_withholding(rate: Perbill) {
+	assert_ok!(TaxWithholding::set_max_rate(
+		RuntimeOrigin::root(),
+		Perbill::from_percent(50)
+	));
+	assert_ok!(TaxWithholding::set_withholding(
+		RuntimeOrigin::signed(ADMIN),
+		POOL_A,
+		TAX_ACCOUNT,
+		rates(&[(CLASS_A, rate)]),
+	));
+	assert_ok!(TaxWithholding::set_investor_class(
+		RuntimeOrigin::signed(ADMIN),
+		POOL_A,
+		INVESTOR,
+		Some(CLASS_A),
+	));
+}
+
+fn collect_investment(tokens: Balance, cost: Balance) {
+	assert_ok!(InvestmentHook::notify_status_change(
+		(INVESTOR, INVESTMENT_A),
+		CollectedAmount {
+			amount_collected: tokens,
+			amount_payment: cost,
+		},
+	));
+}
+
+fn collect_redemption(tokens: Balance, payout: Balance) {
+	assert_ok!(RedemptionHook::notify_status_change(
+		(INVESTOR, INVESTMENT_A),
+		CollectedAmount {
+			amount_collected: payout,
+			amount_payment: tokens,
+		},
+	));
+}
+
+fn pool_balance(who: AccountId) -> Balance {
+	OrmlTokens::free_balance(POOL_CURRENCY, &who)
+}
+
+mod set_max_rate {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			let rate = Perbill::from_percent(30);
+			assert_ok!(TaxWithholding::set_max_rate(RuntimeOrigin::root(), rate));
+
+			assert_eq!(MaxRate::<Runtime>::get(), rate);
+			System::assert_last_event(Event::<Runtime>::MaxRateSet { rate }.into());
+		});
+	}
+
+	#[test]
+	fn not_governance() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				TaxWithholding::set_max_rate(
+					RuntimeOrigin::signed(ADMIN),
+					Perbill::from_percent(30)
+				),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+}
+
+mod set_withholding {
+	use super::*;
+
+	#[test]
+	fn success() {
+		new_test_ext().execute_with(|| {
+			config_withholding(Perbill::from_percent(20));
+
+			assert_eq!(
+				TaxWithholding::withholding(POOL_A, &INVESTOR),
+				Some((TAX_ACCOUNT, Perbill::from_percent(20)))
+			);
+		});
+	}
+
+	#[test]
+	fn not_admin() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				TaxWithholding::set_withholding(
+					RuntimeOrigin::signed(INVESTOR),
+					POOL_A,
+					TAX_ACCOUNT,
+					rates(&[]),
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				TaxWithholding::set_investor_class(
+					RuntimeOrigin::signed(INVESTOR),
+					POOL_A,
+					INVESTOR,
+					Some(CLASS_A),
+				),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				TaxWithholding::remove_withholding(RuntimeOrigin::signed(INVESTOR), POOL_A),
+				DispatchError::BadOrigin
+			);
+		});
+	}
+
+	#[test]
+	fn pool_not_found() {
+		new_test_ext().execute_with(|| {
+			MockIsAdmin::mock_check(|_| true);
+
+			assert_noop!(
+				TaxWithholding::set_withholding(
+					RuntimeOrigin::signed(ADMIN),
+					POOL_A + 1,
+					TAX_ACCOUNT,
+					rates(&[]),
+				),
+				Error::<Runtime>::PoolNotFound
+			);
+		});
+	}
+
+	#[test]
+	fn rate_too_high() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(TaxWithholding::set_max_rate(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(30)
+			));
+
+			assert_noop!(
+				TaxWithholding::set_withholding(
+					RuntimeOrigin::signed(ADMIN),
+					POOL_A,
+					TAX_ACCOUNT,
+					rates(&[
+						(CLASS_A, Perbill::from_percent(30)),
+						(CLASS_B, Perbill::from_percent(31))
+					]),
+				),
+				Error::<Runtime>::RateTooHigh
+			);
+		});
+	}
+
+	#[test]
+	fn capped_by_lowered_max_rate() {
+		new_test_ext().execute_with(|| {
+			config_withholding(Perbill::from_percent(20));
+
+			assert_ok!(TaxWithholding::set_max_rate(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(10)
+			));
+
+			assert_eq!(
+				TaxWithholding::withholding(POOL_A, &INVESTOR),
+				Some((TAX_ACCOUNT, Perbill::from_percent(10)))
+			);
+		});
+	}
+
+	#[test]
+	fn removed() {
+		new_test_ext().execute_with(|| {
+			config_withholding(Perbill::from_percent(20));
+
+			assert_ok!(TaxWithholding::remove_withholding(
+				RuntimeOrigin::signed(ADMIN),
+				POOL_A
+			));
+
+			assert_eq!(TaxWithholding::withholding(POOL_A, &INVESTOR), None);
+			assert_noop!(
+				TaxWithholding::remove_withholding(RuntimeOrigin::signed(ADMIN), POOL_A),
+				Error::<Runtime>::WithholdingNotFound
+			);
+		});
+	}
+}
+
+mod hooks {
+	use super::*;
+
+	#[test]
+	fn investment_records_cost_basis() {
+		new_test_ext().execute_with(|| {
+			collect_investment(1000, 800);
+			collect_investment(1000, 1200);
+
+			assert_eq!(
+				CostBases::<Runtime>::get(INVESTOR, INVESTMENT_A),
+				CostBasis {
+					tokens: 2000,
+					cost: 2000,
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn redemption_withholds_gain() {
+		new_test_ext().execute_with(|| {
+			config_withholding(Perbill::from_percent(20));
+			collect_investment(1000, 1000);
+
+			MockNext::mock_notify_status_change(|(who, investment_id), collected| {
+				assert_eq!(who, INVESTOR);
+				assert_eq!(investment_id, INVESTMENT_A);
+				assert_eq!(
+					collected,
+					CollectedAmount {
+						amount_collected: 600 - 20,
+						amount_payment: 500,
+					}
+				);
+				Ok(())
+			});
+
+			// Half of the tokens redeemed for a gain of 100
+			collect_redemption(500, 600);
+
+			assert_eq!(pool_balance(TAX_ACCOUNT), 20);
+			assert_eq!(pool_balance(INVESTOR), INITIAL_BALANCE - 20);
+			assert_eq!(Withheld::<Runtime>::get(POOL_A, INVESTOR), 20);
+			assert_eq!(
+				CostBases::<Runtime>::get(INVESTOR, INVESTMENT_A),
+				CostBasis {
+					tokens: 500,
+					cost: 500,
+				}
+			);
+
+			System::assert_last_event(
+				Event::<Runtime>::Withheld {
+					investment_id: INVESTMENT_A,
+					investor: INVESTOR,
+					gain: 100,
+					amount: 20,
+				}
+				.into(),
+			);
+		});
+	}
+
+	#[test]
+	fn redemption_with_loss() {
+		new_test_ext().execute_with(|| {
+			config_withholding(Perbill::from_percent(20));
+			collect_investment(1000, 1000);
+
+			collect_redemption(1000, 900);
+
+			assert_eq!(pool_balance(TAX_ACCOUNT), 0);
+			assert_eq!(Withheld::<Runtime>::get(POOL_A, INVESTOR), 0);
+			assert!(!CostBases::<Runtime>::contains_key(INVESTOR, INVESTMENT_A));
+		});
+	}
+
+	#[test]
+	fn redemption_of_tokens_without_cost_basis() {
+		new_test_ext().execute_with(|| {
+			config_withholding(Perbill::from_percent(20));
+			collect_investment(1000, 1000);
+
+			// Half of the redeemed tokens were received by transfer
+			collect_redemption(2000, 2400);
+
+			// Only the gain of the tokens with a cost basis is withheld
+			assert_eq!(pool_balance(TAX_ACCOUNT), 40);
+			assert!(!CostBases::<Runtime>::contains_key(INVESTOR, INVESTMENT_A));
+		});
+	}
+
+	#[test]
+	fn redemption_without_withholding() {
+		new_test_ext().execute_with(|| {
+			config_withholding(Perbill::from_percent(20));
+			assert_ok!(TaxWithholding::set_investor_class(
+				RuntimeOrigin::signed(ADMIN),
+				POOL_A,
+				INVESTOR,
+				None,
+			));
+			collect_investment(1000, 1000);
+
+			collect_redemption(500, 600);
+
+			assert_eq!(pool_balance(TAX_ACCOUNT), 0);
+			assert_eq!(
+				CostBases::<Runtime>::get(INVESTOR, INVESTMENT_A),
+				CostBasis {
+					tokens: 500,
+					cost: 500,
+				}
+			);
+		});
+	}
+}
@@ -0,0 +1,38 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::weights::Weight;
+
+pub trait WeightInfo {
+	fn set_max_rate() -> Weight;
+	fn set_withholding() -> Weight;
+	fn remove_withholding() -> Weight;
+	fn set_investor_class() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn set_max_rate() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_withholding() -> Weight {
+		Weight::zero()
+	}
+
+	fn remove_withholding() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_investor_class() -> Weight {
+		Weight::zero()
+	}
+}
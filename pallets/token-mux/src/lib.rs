@@ -240,14 +240,16 @@ pub mod pallet {
 				T::OrderBook::get_order_details(order_id).ok_or(Error::<T>::SwapNotFound)?;
 
 			let ratio = match order.ratio {
-				OrderRatio::Market => T::BalanceRatio::ensure_from_rational(
-					amount,
-					T::OrderBook::convert_by_market(
-						order.swap.currency_in,
-						order.swap.currency_out,
+				OrderRatio::Market | OrderRatio::MarketWithSlippage { .. } => {
+					T::BalanceRatio::ensure_from_rational(
 						amount,
-					)?,
-				)?,
+						T::OrderBook::convert_by_market(
+							order.swap.currency_in,
+							order.swap.currency_out,
+							amount,
+						)?,
+					)?
+				}
 				OrderRatio::Custom(ratio) => ratio,
 			};
 
@@ -23,6 +23,11 @@
 //! for that currency to:
 //! - the account(s) for which allowances have been made
 //! - the block range specified in the allowance
+//!
+//! An allowance can also be made for a wildcard location, which covers every
+//! location converted into it by `LocationWildcard`, i.e. any address of a
+//! destination domain. And an allowance can be made to expire by itself after
+//! a given block, so it does not need to be removed afterwards.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 #[cfg(test)]
@@ -57,7 +62,7 @@ pub mod pallet {
 	use parity_scale_codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 	use scale_info::TypeInfo;
 	use sp_runtime::{
-		traits::{AtLeast32BitUnsigned, EnsureAdd, EnsureSub},
+		traits::{AtLeast32BitUnsigned, Convert, EnsureAdd, EnsureSub},
 		Saturating,
 	};
 
@@ -111,6 +116,11 @@ pub mod pallet {
 		/// Type containing the locations a transfer can be sent to.
 		type Location: Member + TypeInfo + Encode + EncodeLike + Decode + MaxEncodedLen;
 
+		/// Converts a location into the wildcard location covering it, if any.
+		/// An allowance for the wildcard location allows transfers to any of
+		/// the locations it covers.
+		type LocationWildcard: Convert<Self::Location, Option<Self::Location>>;
+
 		/// Type for pallet weights
 		type WeightInfo: WeightInfo;
 	}
@@ -254,6 +264,8 @@ pub mod pallet {
 		/// Transfer from sending account and currency not allowed to
 		/// destination
 		NoAllowanceForDestination,
+		/// Attempted to create an allowance expiring before it starts
+		AllowanceExpiresBeforeStart,
 	}
 
 	#[pallet::event]
@@ -328,51 +340,14 @@ pub mod pallet {
 		) -> DispatchResult {
 			let account_id = ensure_signed(origin)?;
 
-			let allowance_details = match Self::get_account_currency_restriction_count_delay(
-				&account_id,
-				currency_id,
-			) {
-				Some(AllowanceMetadata {
-					current_delay: Some(delay),
-					..
-				}) => AllowanceDetails {
-					allowed_at: <frame_system::Pallet<T>>::block_number().saturating_add(delay),
-					..AllowanceDetails::default()
-				},
-				_ => AllowanceDetails::default(),
-			};
-
-			if !<AccountCurrencyTransferAllowance<T>>::contains_key((
-				&account_id,
-				&currency_id,
-				&receiver,
-			)) {
-				Self::increment_or_create_allowance_count(&account_id, &currency_id)?;
-				T::ReserveCurrency::hold(
-					&HoldReason::TransferAllowance.into(),
-					&account_id,
-					T::Deposit::get(),
-				)?;
-			};
-			<AccountCurrencyTransferAllowance<T>>::insert(
-				(&account_id, &currency_id, &receiver),
-				&allowance_details,
-			);
-
-			Self::deposit_event(Event::TransferAllowanceCreated {
-				sender_account_id: account_id,
-				currency_id,
-				receiver,
-				allowed_at: allowance_details.allowed_at,
-				blocked_at: allowance_details.blocked_at,
-			});
-			Ok(())
+			Self::do_add_transfer_allowance(account_id, currency_id, receiver, None)
 		}
 
 		/// Restricts a transfer allowance for a sending
 		/// account/currency/receiver location to:
 		/// - either the current block + delay if a delay is set
 		/// - or the current block if no delay is set
+		/// unless the allowance already expires before.
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::remove_transfer_allowance_delay_present().max(T::WeightInfo::remove_transfer_allowance_no_delay()))]
 		pub fn remove_transfer_allowance(
@@ -396,7 +371,7 @@ pub mod pallet {
 			{
 				Some(existing_allowance) => {
 					let allowance_details = AllowanceDetails {
-						blocked_at,
+						blocked_at: blocked_at.min(existing_allowance.blocked_at),
 						..existing_allowance
 					};
 					<AccountCurrencyTransferAllowance<T>>::insert(
@@ -648,9 +623,104 @@ pub mod pallet {
 				_ => Err(DispatchError::from(Error::<T>::DelayUnmodifiable)),
 			}
 		}
+
+		/// Adds a transfer allowance for a sending Account/Currency like
+		/// [`Pallet::add_transfer_allowance()`], but the allowance expires by
+		/// itself at the `expires_at` block, after which it can be purged.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::add_transfer_allowance_no_existing_metadata().max(T::WeightInfo::add_transfer_allowance_existing_metadata()))]
+		pub fn add_expiring_transfer_allowance(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			receiver: T::Location,
+			expires_at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			let account_id = ensure_signed(origin)?;
+
+			Self::do_add_transfer_allowance(account_id, currency_id, receiver, Some(expires_at))
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
+		/// Adds or renews a transfer allowance, which starts after the delay
+		/// set for the sending account/currency, if any, and is blocked at
+		/// `expires_at`, if given.
+		fn do_add_transfer_allowance(
+			account_id: T::AccountId,
+			currency_id: T::CurrencyId,
+			receiver: T::Location,
+			expires_at: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let mut allowance_details = match Self::get_account_currency_restriction_count_delay(
+				&account_id,
+				currency_id,
+			) {
+				Some(AllowanceMetadata {
+					current_delay: Some(delay),
+					..
+				}) => AllowanceDetails {
+					allowed_at: <frame_system::Pallet<T>>::block_number().saturating_add(delay),
+					..AllowanceDetails::default()
+				},
+				_ => AllowanceDetails::default(),
+			};
+
+			if let Some(expires_at) = expires_at {
+				let starts_at = allowance_details
+					.allowed_at
+					.max(<frame_system::Pallet<T>>::block_number());
+				ensure!(
+					expires_at > starts_at,
+					Error::<T>::AllowanceExpiresBeforeStart
+				);
+				allowance_details.blocked_at = expires_at;
+			}
+
+			if !<AccountCurrencyTransferAllowance<T>>::contains_key((
+				&account_id,
+				&currency_id,
+				&receiver,
+			)) {
+				Self::increment_or_create_allowance_count(&account_id, &currency_id)?;
+				T::ReserveCurrency::hold(
+					&HoldReason::TransferAllowance.into(),
+					&account_id,
+					T::Deposit::get(),
+				)?;
+			};
+			<AccountCurrencyTransferAllowance<T>>::insert(
+				(&account_id, &currency_id, &receiver),
+				&allowance_details,
+			);
+
+			Self::deposit_event(Event::TransferAllowanceCreated {
+				sender_account_id: account_id,
+				currency_id,
+				receiver,
+				allowed_at: allowance_details.allowed_at,
+				blocked_at: allowance_details.blocked_at,
+			});
+			Ok(())
+		}
+
+		/// Checks whether there is an allowance for the receiving location
+		/// active in the current block.
+		fn is_allowed(
+			send: &T::AccountId,
+			currency: &T::CurrencyId,
+			receive: &T::Location,
+		) -> bool {
+			let current_block = <frame_system::Pallet<T>>::block_number();
+
+			matches!(
+				<AccountCurrencyTransferAllowance<T>>::get((send, currency, receive)),
+				Some(AllowanceDetails {
+					allowed_at,
+					blocked_at,
+				}) if current_block >= allowed_at && current_block < blocked_at
+			)
+		}
+
 		/// Increments number of allowances present for a sending
 		/// account/currency set. If no allowances set, an entry with 1 added,
 		/// if entry already present, it is then incremented.
@@ -757,6 +827,8 @@ pub mod pallet {
 		/// account and currency, and there's an allowance present:
 		/// then we check whether the current block is between the `allowed_at`
 		/// and `blocked_at` blocks in the allowance.
+		/// If the destination is not allowed, the same check is done for the
+		/// wildcard location covering it, if any.
 		fn allowance(
 			send: T::AccountId,
 			receive: Self::Location,
@@ -767,17 +839,14 @@ pub mod pallet {
 					allowance_count: count,
 					..
 				}) if count > 0 => {
-					let current_block = <frame_system::Pallet<T>>::block_number();
-					match <AccountCurrencyTransferAllowance<T>>::get((
-						&send,
-						&currency,
-						receive.clone(),
-					)) {
-						Some(AllowanceDetails {
-							allowed_at,
-							blocked_at,
-						}) if current_block >= allowed_at && current_block < blocked_at => Ok(Some(receive)),
-						_ => Err(DispatchError::from(Error::<T>::NoAllowanceForDestination)),
+					let allowed = Self::is_allowed(&send, &currency, &receive)
+						|| T::LocationWildcard::convert(receive.clone())
+							.is_some_and(|wildcard| Self::is_allowed(&send, &currency, &wildcard));
+
+					if allowed {
+						Ok(Some(receive))
+					} else {
+						Err(DispatchError::from(Error::<T>::NoAllowanceForDestination))
 					}
 				}
 				// In this case no allowances are set for the sending account & currency,
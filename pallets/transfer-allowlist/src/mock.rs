@@ -17,7 +17,7 @@ use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_core::crypto::AccountId32;
 use sp_runtime::{
-	traits::{CheckedAdd, IdentityLookup},
+	traits::{CheckedAdd, Convert, IdentityLookup},
 	BuildStorage,
 };
 
@@ -56,6 +56,7 @@ impl transfer_allowlist::Config for Runtime {
 	type CurrencyId = FilterCurrency;
 	type Deposit = ConstU64<10>;
 	type Location = Location;
+	type LocationWildcard = LocalWildcard;
 	type ReserveCurrency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -78,6 +79,18 @@ impl transfer_allowlist::Config for Runtime {
 )]
 pub enum Location {
 	TestLocal(AccountId32),
+	TestAnyLocal,
+}
+
+pub struct LocalWildcard;
+
+impl Convert<Location, Option<Location>> for LocalWildcard {
+	fn convert(location: Location) -> Option<Location> {
+		match location {
+			Location::TestLocal(_) => Some(Location::TestAnyLocal),
+			Location::TestAnyLocal => None,
+		}
+	}
 }
 
 impl From<AccountId32> for Location {
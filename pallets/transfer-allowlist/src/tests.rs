@@ -204,6 +204,143 @@ fn transfer_allowance_blocks_correctly_when_after_blocked_at_block() {
 	})
 }
 
+#[test]
+fn transfer_allowance_allows_any_location_with_wildcard_set() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransferAllowList::add_transfer_allowance(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			Location::TestAnyLocal
+		));
+		assert_eq!(
+			TransferAllowList::allowance(
+				SENDER.into(),
+				local_location(ACCOUNT_RECEIVER),
+				TEST_CURRENCY_ID
+			),
+			Ok(Some(local_location(ACCOUNT_RECEIVER)))
+		);
+		assert_eq!(
+			TransferAllowList::allowance(
+				SENDER.into(),
+				local_location(OTHER_RECEIVER),
+				TEST_CURRENCY_ID
+			),
+			Ok(Some(local_location(OTHER_RECEIVER)))
+		);
+
+		// Once the wildcard is removed, transfers are blocked again
+		assert_ok!(TransferAllowList::remove_transfer_allowance(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			Location::TestAnyLocal
+		));
+		assert_err!(
+			TransferAllowList::allowance(
+				SENDER.into(),
+				local_location(ACCOUNT_RECEIVER),
+				TEST_CURRENCY_ID
+			),
+			Error::<Runtime>::NoAllowanceForDestination,
+		);
+	})
+}
+
+#[test]
+fn add_expiring_transfer_allowance_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TransferAllowList::add_expiring_transfer_allowance(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			local_location(ACCOUNT_RECEIVER),
+			STARTING_BLOCK + 10
+		));
+		assert_eq!(
+			TransferAllowList::get_account_currency_transfer_allowance((
+				SENDER,
+				TEST_CURRENCY_ID,
+				local_location(ACCOUNT_RECEIVER)
+			))
+			.unwrap(),
+			AllowanceDetails {
+				allowed_at: 0u64,
+				blocked_at: STARTING_BLOCK + 10,
+			}
+		);
+		assert_eq!(Balances::reserved_balance(&SENDER), 10);
+
+		advance_n_blocks::<Runtime>(9);
+		assert_eq!(
+			TransferAllowList::allowance(
+				SENDER.into(),
+				local_location(ACCOUNT_RECEIVER),
+				TEST_CURRENCY_ID
+			),
+			Ok(Some(local_location(ACCOUNT_RECEIVER)))
+		);
+
+		// Removing it does not postpone its expiration
+		assert_ok!(TransferAllowList::add_allowance_delay(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			200
+		));
+		assert_ok!(TransferAllowList::remove_transfer_allowance(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			local_location(ACCOUNT_RECEIVER)
+		));
+
+		advance_n_blocks::<Runtime>(1);
+		assert_err!(
+			TransferAllowList::allowance(
+				SENDER.into(),
+				local_location(ACCOUNT_RECEIVER),
+				TEST_CURRENCY_ID
+			),
+			Error::<Runtime>::NoAllowanceForDestination,
+		);
+
+		advance_n_blocks::<Runtime>(1);
+		assert_ok!(TransferAllowList::purge_transfer_allowance(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			local_location(ACCOUNT_RECEIVER)
+		));
+		assert_eq!(Balances::reserved_balance(&SENDER), 0);
+	})
+}
+
+#[test]
+fn add_expiring_transfer_allowance_fails_if_expires_before_start() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			TransferAllowList::add_expiring_transfer_allowance(
+				RuntimeOrigin::signed(SENDER),
+				TEST_CURRENCY_ID,
+				local_location(ACCOUNT_RECEIVER),
+				STARTING_BLOCK
+			),
+			Error::<Runtime>::AllowanceExpiresBeforeStart,
+		);
+
+		assert_ok!(TransferAllowList::add_allowance_delay(
+			RuntimeOrigin::signed(SENDER),
+			TEST_CURRENCY_ID,
+			10
+		));
+		assert_noop!(
+			TransferAllowList::add_expiring_transfer_allowance(
+				RuntimeOrigin::signed(SENDER),
+				TEST_CURRENCY_ID,
+				local_location(ACCOUNT_RECEIVER),
+				STARTING_BLOCK + 10
+			),
+			Error::<Runtime>::AllowanceExpiresBeforeStart,
+		);
+	})
+}
+
 #[test]
 fn remove_transfer_allowance_works() {
 	new_test_ext().execute_with(|| {
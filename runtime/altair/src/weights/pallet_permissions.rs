@@ -111,4 +111,46 @@ impl<T: frame_system::Config> pallet_permissions::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn purge_expired() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `remove_as_admin`, which also
+		//       removes a permission and updates the permission count
+		Self::remove_as_admin()
+	}
+	fn delegate() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `add_as_editor`, which also checks
+		//       the caller role before writing a single item
+		Self::add_as_editor()
+	}
+	fn revoke_delegation() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `purge`, which also mutates a
+		//       single item of the caller
+		Self::purge()
+	}
+	fn admin_purge_delegations() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `admin_purge`, which also removes
+		//       a single item
+		Self::admin_purge()
+	}
+	fn batch_add(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `add_as_admin` for each of the
+		//       `n` added permissions
+		Self::add_as_admin().saturating_mul(n.into())
+	}
+	fn batch_remove(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `remove_as_admin` for each of the
+		//       `n` removed permissions
+		Self::remove_as_admin().saturating_mul(n.into())
+	}
 }
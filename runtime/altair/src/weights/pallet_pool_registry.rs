@@ -197,4 +197,33 @@ impl<T: frame_system::Config> pallet_pool_registry::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn publish_notice() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_metadata`, which also checks
+		//       the pool admin, plus the pool lookup and the notice writes
+		Self::set_metadata(0, 0).saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+	fn acknowledge_notice() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_metadata`, which also writes a
+		//       single item, plus the read of the notice
+		Self::set_metadata(0, 0).saturating_add(T::DbWeight::get().reads(1))
+	}
+	fn set_investor_allowlist_root() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_metadata`, which also writes a
+		//       single item, plus the read of the pool
+		Self::set_metadata(0, 0).saturating_add(T::DbWeight::get().reads(1))
+	}
+	fn register_investor(_n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_metadata`, plus the read of the
+		//       allowlist root and the permission written for the investor. The
+		//       hashing of the `n` proof items is not accounted yet
+		Self::set_metadata(0, 0).saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
 }
@@ -25,7 +25,7 @@ use runtime_common::{
 	transfer_filter::PreXcmTransfer,
 	xcm::{
 		AccountIdToLocation, Barrier, CanonicalNativePerSecond, FixedConversionRateProvider,
-		LocalOriginToLocation, ToTreasury,
+		LocalOriginToLocation, NoXcmRestrictedAssets, ToTreasury,
 	},
 };
 use sp_core::ConstU32;
@@ -136,9 +136,9 @@ impl pallet_xcm::Config for Runtime {
 	type WeightInfo = crate::weights::pallet_xcm::WeightInfo<Runtime>;
 	type XcmExecuteFilter = Nothing;
 	type XcmExecutor = XcmExecutor<XcmConfig>;
-	type XcmReserveTransferFilter = Everything;
+	type XcmReserveTransferFilter = NoXcmRestrictedAssets<Runtime>;
 	type XcmRouter = XcmRouter;
-	type XcmTeleportFilter = Everything;
+	type XcmTeleportFilter = NoXcmRestrictedAssets<Runtime>;
 
 	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
 }
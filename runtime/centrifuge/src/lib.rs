@@ -30,15 +30,15 @@ use cfg_primitives::{
 	LPGatewayQueueMessageNonce, LPGatewaySessionId,
 };
 use cfg_traits::{
-	investments::OrderManager, Millis, Permissions as PermissionsT, PoolUpdateGuard, PreConditions,
-	Seconds,
+	interest::InterestRate, investments::OrderManager, Millis, Permissions as PermissionsT,
+	PoolUpdateGuard, PreConditions, Seconds,
 };
 use cfg_types::{
-	domain_address::DomainAddress,
+	domain_address::{Domain, DomainAddress},
 	fee_keys::{Fee, FeeKey},
 	fixed_point::{Quantity, Rate, Ratio},
-	investments::InvestmentPortfolio,
-	locations::RestrictedTransferLocation,
+	investments::{AcceptedCurrency, CurrencyExposure, InvestmentPortfolio},
+	locations::{DomainWildcard, RestrictedTransferLocation},
 	oracles::OracleKey,
 	permissions::{
 		PermissionRoles, PermissionScope, PermissionedCurrencyRole, PoolRole, Role, UNION,
@@ -110,12 +110,18 @@ use runtime_common::{
 	gateway, instances,
 	instances::CouncilCollective,
 	message_queue::{NarrowOriginToSibling, ParaIdToSibling},
-	oracle::{DigestedOracleRatioProvider, Feeder, OracleConverterBridge, OracleRatioProvider},
+	oracle::{
+		DigestedOracleRatioProvider, Feeder, OracleConverterBridge, OracleRatioProvider,
+		OracleReferenceRateProvider,
+	},
 	origin::EnsureAccountOrRootOr,
 	origins::gov::types::{
 		AllOfCouncil, EnsureRootOr, HalfOfCouncil, ThreeFourthOfCouncil, TwoThirdOfCouncil,
 	},
-	permissions::{IsUnfrozenTrancheInvestor, PoolAdminCheck},
+	parameters,
+	permissions::{
+		DelegableRoles, HasAcknowledgedNotices, IsUnfrozenTrancheInvestor, PoolAdminCheck,
+	},
 	rewards::SingleCurrencyMovement,
 	routing::{
 		EvmAccountCodeChecker, LPGatewayRouterProvider, MessageSerializer, RouterDispatcher,
@@ -136,7 +142,7 @@ use sp_runtime::{
 		Dispatchable, IdentityLookup, PostDispatchInfoOf, UniqueSaturatedInto, Verify, Zero,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
-	ApplyExtrinsicResult, FixedI128, Perbill, Permill, Perquintill,
+	ApplyExtrinsicResult, FixedI128, FixedU128, Perbill, Permill, Perquintill,
 };
 use sp_staking::currency_to_vote::U128CurrencyToVote;
 use sp_std::{marker::PhantomData, prelude::*, vec::Vec};
@@ -384,6 +390,7 @@ impl pallet_restricted_tokens::Config for Runtime {
 	type PreReservableCurrency = cfg_traits::Always;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TransferHooks = ();
 	type WeightInfo = weights::pallet_restricted_tokens::WeightInfo<Runtime>;
 }
 
@@ -1134,6 +1141,7 @@ impl pallet_anchors::Config for Runtime {
 	type Currency = Balances;
 	type Fees = Fees;
 	type PreCommitDepositFeeKey = PreCommitDepositFeeKey;
+	type Time = Timestamp;
 	type WeightInfo = weights::pallet_anchors::WeightInfo<Self>;
 }
 
@@ -1258,7 +1266,10 @@ parameter_types! {
 impl pallet_rewards::Config<instances::BlockRewards> for Runtime {
 	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
+	// Block rewards stakes can not be locked
+	type EarlyUnlockPenalty = ();
 	type GroupId = u32;
+	type LockOptions = ();
 	type PalletId = BlockRewardsPalletId;
 	type RewardCurrency = RewardCurrency;
 	// Must not change this to ensure block rewards are minted
@@ -1277,6 +1288,7 @@ parameter_types! {
 	pub const BlockRewardCurrency: CurrencyId = CurrencyId::Staking(StakingCurrency::BlockRewards);
 	pub const StakeAmount: Balance = cfg_types::consts::rewards::DEFAULT_COLLATOR_STAKE;
 	pub const CollatorGroupId: u32 = cfg_types::ids::COLLATOR_GROUP_ID;
+	pub const RewardVestingPeriod: BlockNumber = 90 * DAYS;
 }
 
 impl pallet_block_rewards::Config for Runtime {
@@ -1285,7 +1297,9 @@ impl pallet_block_rewards::Config for Runtime {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type MaxCollators = MaxAuthorities;
+	type NativeCurrency = Balances;
 	type Rate = Rate;
+	type RewardVestingPeriod = RewardVestingPeriod;
 	type Rewards = BlockRewardsBase;
 	type RuntimeEvent = RuntimeEvent;
 	type StakeAmount = StakeAmount;
@@ -1295,6 +1309,7 @@ impl pallet_block_rewards::Config for Runtime {
 	type Tokens = Tokens;
 	// Must not change this as long as we want to mint rewards into the treasury
 	type TreasuryPalletId = TreasuryPalletId;
+	type Vesting = Vesting;
 	type Weight = u64;
 	type WeightInfo = weights::pallet_block_rewards::WeightInfo<Runtime>;
 }
@@ -1308,6 +1323,12 @@ parameter_types! {
 	pub const MaxGroups: u32 = 20;
 	pub const LiquidityRewardsPalletId: PalletId = cfg_types::ids::LIQUIDITY_REWARDS_PALLET_ID;
 	pub const InitialEpochDuration: Millis = SECONDS_PER_MINUTE * 1000; // 1 min in milliseconds
+	pub const LiquidityUnstakeDelay: Millis = SECONDS_PER_WEEK * 1000; // 1 week in milliseconds
+	pub const MaxUnstakeRequests: u32 = 10;
+	pub const MaxCoIncentives: u32 = 10;
+	pub const LiquidityCoIncentivesPalletId: PalletId = cfg_types::ids::LIQUIDITY_CO_INCENTIVES_PALLET_ID;
+	pub LiquidityLockOptions: Vec<(BlockNumber, FixedU128)> = cfg_types::consts::rewards::LIQUIDITY_LOCK_OPTIONS.to_vec();
+	pub const LiquidityEarlyUnlockPenalty: Perbill = cfg_types::consts::rewards::LIQUIDITY_EARLY_UNLOCK_PENALTY;
 }
 
 impl pallet_rewards::mechanism::gap::Config for Runtime {
@@ -1321,14 +1342,19 @@ impl pallet_rewards::mechanism::gap::Config for Runtime {
 impl pallet_liquidity_rewards::Config for Runtime {
 	type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
 	type Balance = Balance;
+	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
 	type GroupId = u32;
 	type InitialEpochDuration = InitialEpochDuration;
 	type MaxChangesPerEpoch = MaxChangesPerEpoch;
+	type MaxCoIncentives = MaxCoIncentives;
 	type MaxGroups = MaxGroups;
+	type MaxUnstakeRequests = MaxUnstakeRequests;
+	type PalletId = LiquidityCoIncentivesPalletId;
 	type Rewards = LiquidityRewardsBase;
 	type RuntimeEvent = RuntimeEvent;
 	type Timer = Timestamp;
+	type UnstakeDelay = LiquidityUnstakeDelay;
 	type Weight = u64;
 	type WeightInfo = weights::pallet_liquidity_rewards::WeightInfo<Runtime>;
 }
@@ -1336,7 +1362,9 @@ impl pallet_liquidity_rewards::Config for Runtime {
 impl pallet_rewards::Config<pallet_rewards::Instance2> for Runtime {
 	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
+	type EarlyUnlockPenalty = LiquidityEarlyUnlockPenalty;
 	type GroupId = u32;
+	type LockOptions = LiquidityLockOptions;
 	type PalletId = LiquidityRewardsPalletId;
 	type RewardCurrency = RewardCurrency;
 	type RewardIssuance =
@@ -1364,6 +1392,21 @@ parameter_types! {
 		30 * MINUTES // half an hour to challenge solutions
 	};
 
+	// Runtime-defined constraints for the challenge time of a pool
+	pub const ChallengeTimeLowerBound: BlockNumber = if cfg!(feature = "runtime-benchmarks") {
+		0 // Disable challenge time in benchmarks
+	} else {
+		5 * MINUTES
+	};
+	pub const ChallengeTimeUpperBound: BlockNumber = 1 * DAYS;
+
+	// Max number of accounts allowed to submit solutions for a pool
+	#[derive(scale_info::TypeInfo, Eq, PartialEq, Debug, Clone, Copy )]
+	pub const MaxSolvers: u32 = 20;
+
+	// Max number of changes of a pool awaiting dual approval at the same time
+	pub const MaxPendingApprovals: u32 = 50;
+
 	// Defaults for pool parameters
 	pub const DefaultMinEpochTime: u64 = if cfg!(feature = "runtime-benchmarks") {
 		0 // Allow short epoch time in benchmarks and multiple close in one block
@@ -1477,6 +1520,7 @@ impl pallet_pool_registry::Config for Runtime {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type InterestRate = Rate;
+	type MaxAllowlistProofLength = parameters::pool_registry::MaxAllowlistProofLength;
 	type MaxSizeMetadata = MaxSizeMetadata;
 	type MaxTranches = MaxTranches;
 	type ModifyPool = pallet_pool_system::Pallet<Self>;
@@ -1491,12 +1535,15 @@ impl pallet_pool_registry::Config for Runtime {
 }
 
 impl pallet_pool_system::Config for Runtime {
+	type ActivityRecorder = ();
 	type AdminOrigin = runtime_common::pool::LiquidityAndPoolAdminOrRoot<Runtime>;
 	type AssetRegistry = OrmlAssetRegistry;
 	type AssetsUnderManagementNAV = Loans;
 	type Balance = Balance;
 	type BalanceRatio = Quantity;
 	type ChallengeTime = ChallengeTime;
+	type ChallengeTimeLowerBound = ChallengeTimeLowerBound;
+	type ChallengeTimeUpperBound = ChallengeTimeUpperBound;
 	type Currency = Balances;
 	type CurrencyId = CurrencyId;
 	type DefaultMaxNAVAge = DefaultMaxNAVAge;
@@ -1504,6 +1551,8 @@ impl pallet_pool_system::Config for Runtime {
 	type EpochId = PoolEpochId;
 	type Investments = Investments;
 	type MaxNAVAgeUpperBound = MaxNAVAgeUpperBound;
+	type MaxPendingApprovals = MaxPendingApprovals;
+	type MaxSolvers = MaxSolvers;
 	type MaxTranches = MaxTranches;
 	type MinEpochTimeLowerBound = MinEpochTimeLowerBound;
 	type MinEpochTimeUpperBound = MinEpochTimeUpperBound;
@@ -1614,12 +1663,18 @@ impl pallet_pool_fees::Config for Runtime {
 
 impl pallet_permissions::Config for Runtime {
 	type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
+	type DelegableRoles = DelegableRoles;
 	type Editors = Editors;
+	type MaxBatchSize = parameters::permissions::MaxBatchSize;
+	type MaxChangesPerScope = parameters::permissions::MaxChangesPerScope;
+	type MaxDelegationPeriod = parameters::permissions::MaxDelegationPeriod;
+	type MaxDelegations = parameters::permissions::MaxDelegations;
 	type MaxRolesPerScope = MaxRolesPerPool;
 	type Role = Role<TrancheId>;
 	type RuntimeEvent = RuntimeEvent;
 	type Scope = PermissionScope<PoolId, CurrencyId>;
 	type Storage = PermissionRoles<TimeProvider<Timestamp>, MinDelay, TrancheId, MaxTranches>;
+	type Time = Timestamp;
 	type WeightInfo = weights::pallet_permissions::WeightInfo<Runtime>;
 }
 
@@ -1635,23 +1690,23 @@ impl pallet_investments::Config for Runtime {
 	type CollectedRedemptionHook = pallet_foreign_investments::CollectedRedemptionHook<Runtime>;
 	type InvestmentId = InvestmentId;
 	type MaxOutstandingCollects = MaxOutstandingCollects;
-	type PreConditions = IsUnfrozenTrancheInvestor<Permissions, Timestamp>;
+	type PreConditions =
+		HasAcknowledgedNotices<PoolRegistry, IsUnfrozenTrancheInvestor<Permissions, Timestamp>>;
 	type RuntimeEvent = RuntimeEvent;
 	type Tokens = Tokens;
 	type WeightInfo = weights::pallet_investments::WeightInfo<Runtime>;
 }
 
 parameter_types! {
-	pub const MaxActiveLoansPerPool: u32 = 1000;
-	pub const MaxRegisteredPricesPerPool: u32 = 100;
-	pub const MaxRateCount: u32 = 1000; // See #1024
 	pub FirstValueFee: Fee = Fee::Balance(deposit(1, pallet_oracle_feed::util::size_of_feed::<Runtime>()));
 
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
-	pub const MaxWriteOffPolicySize: u32 = 100;
+	pub const MaxFeedersPerKey: u32 = 5;
 
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
-	pub const MaxFeedersPerKey: u32 = 5;
+	pub const MaxSourcesPerKey: u32 = 3;
+
+	pub const MaxFeederDeviation: Perquintill = Perquintill::from_percent(5);
 }
 
 impl pallet_oracle_feed::Config for Runtime {
@@ -1668,16 +1723,22 @@ impl pallet_oracle_collection::Config for Runtime {
 	type AggregationProvider = pallet_oracle_collection::util::MedianAggregation;
 	type ChangeGuard = PoolSystem;
 	type CollectionId = PoolId;
+	type Currency = Balances;
+	type DisputeOrigin = EnsureRootOr<HalfOfCouncil>;
+	type FeederBond = parameters::oracle_collection::FeederBond;
 	type FeederId = Feeder<RuntimeOrigin>;
 	type IsAdmin = PoolAdminCheck<Permissions>;
-	type MaxCollectionSize = MaxRegisteredPricesPerPool;
+	type MaxCollectionSize = parameters::loans::MaxRegisteredPricesPerPool;
+	type MaxFeederDeviation = MaxFeederDeviation;
 	type MaxFeedersPerKey = MaxFeedersPerKey;
+	type MaxSourcesPerKey = MaxSourcesPerKey;
 	type OracleKey = OracleKey;
 	type OracleProvider =
 		OracleConverterBridge<RuntimeOrigin, OraclePriceFeed, PoolSystem, OrmlAssetRegistry>;
 	type OracleValue = Balance;
 	type RuntimeChange = runtime_common::changes::RuntimeChange<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
+	type Slash = Treasury;
 	type Time = Timestamp;
 	type Timestamp = Millis;
 	type WeightInfo = weights::pallet_oracle_collection::WeightInfo<Self>;
@@ -1685,25 +1746,38 @@ impl pallet_oracle_collection::Config for Runtime {
 
 impl pallet_interest_accrual::Config for Runtime {
 	type Balance = Balance;
-	type MaxRateCount = MaxRateCount;
+	type MaxRateCount = parameters::loans::MaxRateCount;
+	type MaxRateSnapshots = parameters::interest_accrual::MaxRateSnapshots;
 	type Rate = Rate;
+	type ReferenceRates = OracleReferenceRateProvider<RuntimeOrigin, OraclePriceFeed>;
 	type RuntimeEvent = RuntimeEvent;
+	type SnapshotPeriod = parameters::interest_accrual::SnapshotPeriod;
 	type Time = Timestamp;
 	type Weights = weights::pallet_interest_accrual::WeightInfo<Self>;
 }
 
+pub type KeeperRewards = runtime_common::keeper::KeeperRewardPot<
+	Runtime,
+	parameters::keeper::KeeperPotId,
+	parameters::keeper::Rewards,
+>;
+
 impl pallet_loans::Config for Runtime {
+	type ActivityRecorder = ();
 	type Balance = Balance;
 	type ChangeGuard = PoolSystem;
 	type CollectionId = CollectionId;
 	type CurrencyId = CurrencyId;
 	type InterestAccrual = InterestAccrual;
 	type ItemId = ItemId;
+	type KeeperRewards = KeeperRewards;
 	type LoanId = LoanId;
-	type MaxActiveLoansPerPool = MaxActiveLoansPerPool;
-	type MaxWriteOffPolicySize = MaxWriteOffPolicySize;
+	type MaxActiveLoansPerPool = parameters::loans::MaxActiveLoansPerPool;
+	type MaxRestructurings = parameters::loans::MaxRestructurings;
+	type MaxWriteOffPolicySize = parameters::loans::MaxWriteOffPolicySize;
 	type Moment = Millis;
 	type NonFungible = Uniques;
+	type OrderId = OrderId;
 	type PerThing = Perquintill;
 	type Permissions = Permissions;
 	type Pool = PoolSystem;
@@ -1715,6 +1789,7 @@ impl pallet_loans::Config for Runtime {
 	type RuntimeChange = runtime_common::changes::RuntimeChange<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Timestamp;
+	type TokenSwaps = OrderBook;
 	type WeightInfo = weights::pallet_loans::WeightInfo<Self>;
 }
 
@@ -1770,11 +1845,6 @@ impl pallet_uniques::Config for Runtime {
 	type WeightInfo = weights::pallet_uniques::WeightInfo<Runtime>;
 }
 
-parameter_types! {
-	pub MinFulfillmentAmountNative: Balance = 10 * CFG;
-	pub NativeDecimals: u32 = cfg_primitives::currency_decimals::NATIVE;
-}
-
 impl pallet_order_book::Config for Runtime {
 	type AdminOrigin = EnsureAccountOrRootOr<LpAdminAccount, TwoThirdOfCouncil>;
 	type AssetRegistry = OrmlAssetRegistry;
@@ -1784,8 +1854,11 @@ impl pallet_order_book::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type FeederId = Feeder<RuntimeOrigin>;
 	type FulfilledOrderHook = ForeignInvestments;
-	type MinFulfillmentAmountNative = MinFulfillmentAmountNative;
-	type NativeDecimals = NativeDecimals;
+	type KeeperRewards = KeeperRewards;
+	type MaxOrderHistory = parameters::order_book::MaxOrderHistory;
+	type MaxOrdersPerUser = parameters::order_book::MaxOrdersPerUser;
+	type MinFulfillmentAmountNative = parameters::order_book::MinFulfillmentAmountNative;
+	type NativeDecimals = parameters::order_book::NativeDecimals;
 	type OrderIdNonce = u64;
 	type Ratio = Ratio;
 	type RatioProvider = DigestedOracleRatioProvider<
@@ -1794,6 +1867,7 @@ impl pallet_order_book::Config for Runtime {
 		OrmlAssetRegistry,
 	>;
 	type RuntimeEvent = RuntimeEvent;
+	type TreasuryAccount = TreasuryAccount;
 	type Weights = weights::pallet_order_book::WeightInfo<Runtime>;
 }
 
@@ -1811,6 +1885,7 @@ impl pallet_foreign_investments::Config for Runtime {
 	type SwapBalance = Balance;
 	type SwapRatio = Ratio;
 	type TrancheBalance = Balance;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -1830,7 +1905,9 @@ impl pallet_liquidity_pools::Config for Runtime {
 	type Permission = Permissions;
 	type PoolId = PoolId;
 	type PoolInspect = PoolSystem;
+	type PositionSnapshotInterval = parameters::liquidity_pools::PositionSnapshotInterval;
 	type PreTransferFilter = PreLpTransfer<TransferAllowList>;
+	type PriceUpdateHandler = ();
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Timestamp;
 	type Tokens = Tokens;
@@ -1842,15 +1919,17 @@ impl pallet_liquidity_pools::Config for Runtime {
 
 parameter_types! {
 	pub Sender: DomainAddress = gateway::get_gateway_domain_address::<Runtime>();
-	pub const MaxIncomingMessageSize: u32 = 1024;
-	pub const MaxRouterCount: u32 = 8;
 }
 
 impl pallet_liquidity_pools_forwarder::Config for Runtime {
 	type AdminOrigin = EnsureAccountOrRootOr<LpAdminAccount, TwoThirdOfCouncil>;
 	type Message = pallet_liquidity_pools::Message;
 	type MessageReceiver = LiquidityPoolsGateway;
-	type MessageSender = MessageSerializer<RouterDispatcher<Runtime>, LiquidityPoolsForwarder>;
+	type MessageSender = MessageSerializer<
+		RouterDispatcher<Runtime>,
+		LiquidityPoolsForwarder,
+		LiquidityPoolsGateway,
+	>;
 	type RouterId = RouterId;
 	type RuntimeEvent = RuntimeEvent;
 }
@@ -1872,18 +1951,27 @@ parameter_types! {
 }
 
 impl pallet_liquidity_pools_gateway::Config for Runtime {
+	type ActivityRecorder = ();
 	type AdminOrigin = EnsureAccountOrRootOr<LpAdminAccount, TwoThirdOfCouncil>;
+	type Currency = Balances;
+	type EthereumTransactions = EthereumTransaction;
+	type FeePotId = parameters::gateway::FeePotId;
 	type InboundMessageHandler = LiquidityPools;
-	type MaxIncomingMessageSize = MaxIncomingMessageSize;
-	type MaxRouterCount = MaxRouterCount;
+	type MaxInboundNonceGap = parameters::gateway::MaxInboundNonceGap;
+	type MaxIncomingMessageSize = parameters::gateway::MaxIncomingMessageSize;
+	type MaxRouterCount = parameters::gateway::MaxRouterCount;
 	type Message = pallet_liquidity_pools::Message;
 	type MessageQueue = LiquidityPoolsGatewayQueue;
 	type MessageSender = LiquidityPoolsForwarder;
+	type OutboundMessageTracker = LiquidityPools;
+	type RelayerBond = parameters::gateway::RelayerBond;
+	type RelayerId = cfg_primitives::liquidity_pools::RelayerId;
 	type RouterId = RouterId;
 	type RouterProvider = LPGatewayRouterProvider;
 	type RuntimeEvent = RuntimeEvent;
 	type Sender = Sender;
 	type SessionId = LPGatewaySessionId;
+	type UnsignedPriority = parameters::gateway::RelayerUnsignedPriority;
 	type WeightInfo = ();
 }
 
@@ -1917,6 +2005,7 @@ impl pallet_transfer_allowlist::Config for Runtime {
 	type CurrencyId = FilterCurrency;
 	type Deposit = AllowanceDeposit<Fees>;
 	type Location = RestrictedTransferLocation;
+	type LocationWildcard = DomainWildcard;
 	type ReserveCurrency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
@@ -2000,13 +2089,65 @@ impl pallet_ethereum_transaction::Config for Runtime {}
 
 impl pallet_axelar_router::Config for Runtime {
 	type AdminOrigin = EnsureAccountOrRootOr<LpAdminAccount, TwoThirdOfCouncil>;
+	type BaseFee = runtime_common::evm::EvmBaseFee<Runtime>;
 	type EvmAccountCodeChecker = EvmAccountCodeChecker<Runtime>;
+	type MaxPayloadSize = parameters::axelar_router::MaxPayloadSize;
 	type Middleware = RouterId;
-	type Receiver = MessageSerializer<RouterDispatcher<Runtime>, LiquidityPoolsForwarder>;
+	type Receiver = MessageSerializer<
+		RouterDispatcher<Runtime>,
+		LiquidityPoolsForwarder,
+		LiquidityPoolsGateway,
+	>;
 	type RuntimeEvent = RuntimeEvent;
 	type Transactor = EthereumTransaction;
 }
 
+/// Privileged calls recorded by the admin action log when dispatched
+/// successfully.
+pub struct PrivilegedCalls;
+impl Contains<RuntimeCall> for PrivilegedCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Council(method) => matches!(
+				method,
+				pallet_collective::Call::execute { .. } | pallet_collective::Call::close { .. }
+			),
+			RuntimeCall::PoolRegistry(method) => matches!(
+				method,
+				pallet_pool_registry::Call::update { .. }
+					| pallet_pool_registry::Call::execute_update { .. }
+					| pallet_pool_registry::Call::set_metadata { .. }
+					| pallet_pool_registry::Call::set_investor_allowlist_root { .. }
+			),
+			RuntimeCall::PoolSystem(method) => matches!(
+				method,
+				pallet_pool_system::Call::set_max_reserve { .. }
+					| pallet_pool_system::Call::set_pool_mode { .. }
+					| pallet_pool_system::Call::set_change_approver { .. }
+					| pallet_pool_system::Call::approve_change { .. }
+			),
+			RuntimeCall::Permissions(method) => matches!(
+				method,
+				pallet_permissions::Call::add { .. }
+					| pallet_permissions::Call::remove { .. }
+					| pallet_permissions::Call::admin_purge { .. }
+					| pallet_permissions::Call::admin_purge_delegations { .. }
+					| pallet_permissions::Call::batch_add { .. }
+					| pallet_permissions::Call::batch_remove { .. }
+					| pallet_permissions::Call::add_with_reason { .. }
+					| pallet_permissions::Call::remove_with_reason { .. }
+			),
+			_ => false,
+		}
+	}
+}
+
+impl pallet_admin_action_log::Config for Runtime {
+	type MaxActions = parameters::admin_action_log::MaxActions;
+	type PrivilegedCalls = PrivilegedCalls;
+	type RuntimeEvent = RuntimeEvent;
+}
+
 /// Block type as expected by this runtime.
 pub type Block = generic::Block<Header, UncheckedExtrinsic>;
 /// A Block signed with a Justification
@@ -2025,6 +2166,8 @@ pub type SignedExtra = (
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	runtime_common::transfer_filter::PreBalanceTransferExtension<Runtime>,
+	pallet_admin_action_log::RecordAdminActions<Runtime>,
+	runtime_common::transfer_filter::PreTokensTransferExtension<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -2097,7 +2240,7 @@ construct_runtime!(
 		LiquidityRewardsBase: pallet_rewards::<Instance2>::{Pallet, Storage, Event<T>, Config<T>} = 104,
 		LiquidityRewards: pallet_liquidity_rewards::{Pallet, Call, Storage, Event<T>} = 105,
 		GapRewardMechanism: pallet_rewards::mechanism::gap = 106,
-		LiquidityPoolsGateway: pallet_liquidity_pools_gateway::{Pallet, Call, Storage, Event<T> } = 107,
+		LiquidityPoolsGateway: pallet_liquidity_pools_gateway::{Pallet, Call, Storage, Event<T>, ValidateUnsigned } = 107,
 		OrderBook: pallet_order_book::{Pallet, Call, Storage, Event<T>} = 108,
 		ForeignInvestments: pallet_foreign_investments::{Pallet, Storage, Event<T>} = 109,
 		TransferAllowList: pallet_transfer_allowlist::{Pallet, Call, Storage, Event<T>, HoldReason} = 110,
@@ -2107,6 +2250,7 @@ construct_runtime!(
 		PoolFees: pallet_pool_fees::{Pallet, Call, Storage, Event<T>} = 114,
 		LiquidityPoolsGatewayQueue: pallet_liquidity_pools_gateway_queue::{Pallet, Call, Storage, Event<T>} = 115,
 		LiquidityPoolsForwarder: pallet_liquidity_pools_forwarder::{Pallet, Call, Storage, Event<T>} = 116,
+		AdminActionLog: pallet_admin_action_log::{Pallet, Storage, Event<T>} = 117,
 
 		// XCM
 		XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 120,
@@ -2287,6 +2431,10 @@ impl_runtime_apis! {
 		fn get_anchor_by_id(id: Hash) -> Option<AnchorData<Hash, BlockNumber>> {
 			Anchor::get_anchor_by_id(id)
 		}
+
+		fn verify_batch_membership(anchor_id: Hash, document_hash: Hash, proof: Vec<Hash>) -> bool {
+			Anchor::verify_batch_membership(anchor_id, document_hash, proof)
+		}
 	}
 
 	// PoolsAPI
@@ -2338,6 +2486,18 @@ impl_runtime_apis! {
 		fn nav(pool_id: PoolId) -> Option<PoolNav<Balance>> {
 			runtime_common::update_nav::<Runtime>(pool_id).ok()
 		}
+
+		fn currency_exposure(pool_id: PoolId) -> Option<Vec<CurrencyExposure<Balance, CurrencyId>>> {
+			runtime_common::currency_exposure::get_pool_exposure::<Runtime>(pool_id).ok()
+		}
+
+		fn preview_epoch(pool_id: PoolId) -> Result<pallet_pool_system::pool_types::EpochPreview<Balance, Quantity>, DispatchError> {
+			PoolSystem::preview_epoch(pool_id)
+		}
+
+		fn accepted_investment_currencies(pool_id: PoolId) -> Option<Vec<AcceptedCurrency<Balance, CurrencyId, Quantity>>> {
+			runtime_common::accepted_currencies::get_accepted_currencies::<Runtime>(pool_id).ok()
+		}
 	}
 
 
@@ -2346,18 +2506,49 @@ impl_runtime_apis! {
 		fn list_currencies(domain: runtime_common::apis::RewardDomain, account_id: AccountId) -> Vec<CurrencyId> {
 			match domain {
 				runtime_common::apis::RewardDomain::Block => pallet_rewards::Pallet::<Runtime, instances::BlockRewards>::list_currencies(&account_id),
-				_ => vec![],
+				runtime_common::apis::RewardDomain::Liquidity => pallet_rewards::Pallet::<Runtime, pallet_rewards::Instance2>::list_currencies(&account_id),
 			}
 		}
 
 		fn compute_reward(domain: runtime_common::apis::RewardDomain, currency_id: CurrencyId, account_id: AccountId) -> Option<Balance> {
 			match domain {
 				runtime_common::apis::RewardDomain::Block => <pallet_rewards::Pallet::<Runtime, instances::BlockRewards> as cfg_traits::rewards::AccountRewards<AccountId>>::compute_reward(currency_id, &account_id).ok(),
-				_ => None,
+				runtime_common::apis::RewardDomain::Liquidity => <pallet_rewards::Pallet::<Runtime, pallet_rewards::Instance2> as cfg_traits::rewards::AccountRewards<AccountId>>::compute_reward(currency_id, &account_id).ok(),
 			}
 		}
 	}
 
+	// LiquidityRewardsApi
+	impl runtime_common::apis::LiquidityRewardsApi<
+		Block,
+		AccountId,
+		Balance,
+		CurrencyId,
+		pallet_liquidity_rewards::UnstakeRequestOf<Runtime>,
+	> for Runtime {
+		fn unstake_requests(account_id: AccountId, currency_id: CurrencyId) -> Vec<pallet_liquidity_rewards::UnstakeRequestOf<Runtime>> {
+			LiquidityRewards::unstake_requests(&account_id, &currency_id)
+		}
+
+		fn queued_unstake(account_id: AccountId, currency_id: CurrencyId) -> Option<Balance> {
+			LiquidityRewards::queued_unstake(&account_id, &currency_id).ok()
+		}
+
+		fn pending_rewards(account_id: AccountId, currency_id: CurrencyId) -> Option<Vec<(CurrencyId, Balance)>> {
+			let reward = <LiquidityRewardsBase as cfg_traits::rewards::AccountRewards<AccountId>>::compute_reward(currency_id, &account_id).ok()?;
+			let co_incentives = LiquidityRewards::pending_co_incentives(&account_id, &currency_id).ok()?;
+
+			Some(sp_std::iter::once((RewardCurrency::get(), reward)).chain(co_incentives).collect())
+		}
+	}
+
+	// InterestAccrualApi
+	impl runtime_common::apis::InterestAccrualApi<Block, Rate> for Runtime {
+		fn rate_at(rate: InterestRate<Rate>, timestamp: Seconds) -> Result<Rate, DispatchError> {
+			InterestAccrual::rate_at(&rate, timestamp)
+		}
+	}
+
 	// LoansApi
 	impl runtime_common::apis::LoansApi<
 		Block,
@@ -2390,6 +2581,26 @@ impl_runtime_apis! {
 		fn expected_cashflows(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<CashflowPayment<Balance>>, DispatchError> {
 			Loans::expected_cashflows(pool_id, loan_id)
 		}
+
+		fn expected_loss(pool_id: PoolId, loan_id: LoanId, horizon: Seconds) -> Result<Balance, DispatchError> {
+			Loans::expected_loss(pool_id, loan_id, horizon)
+		}
+
+		fn portfolio_expected_loss(pool_id: PoolId, horizon: Seconds) -> Result<Balance, DispatchError> {
+			Loans::portfolio_expected_loss(pool_id, horizon)
+		}
+
+		fn write_off_policy(pool_id: PoolId) -> pallet_loans::types::policy::WriteOffPolicyInfo<Rate, Hash> {
+			Loans::write_off_policy(pool_id)
+		}
+
+		fn attestation_health(pool_id: PoolId) -> Vec<(LoanId, pallet_loans::types::AttestationStatus)> {
+			Loans::attestation_health(pool_id)
+		}
+
+		fn restructuring_history(pool_id: PoolId, loan_id: LoanId) -> Vec<pallet_loans::entities::changes::RestructuringRecord<Rate>> {
+			Loans::restructuring_history(pool_id, loan_id)
+		}
 	}
 
 	// Investment Runtime APIs
@@ -2407,10 +2618,52 @@ impl_runtime_apis! {
 	}
 
 	// OrderBookApi
-	impl runtime_common::apis::OrderBookApi<Block, CurrencyId, Balance> for Runtime {
+	impl runtime_common::apis::OrderBookApi<Block, CurrencyId, Balance, Ratio, AccountId, u64, BlockNumber> for Runtime {
 		fn min_fulfillment_amount(currency_id: CurrencyId) -> Option<Balance> {
 			OrderBook::min_fulfillment_amount(currency_id).ok()
 		}
+
+		fn best_ask(currency_out: CurrencyId, currency_in: CurrencyId) -> Option<pallet_order_book::PriceLevel<Ratio, Balance>> {
+			OrderBook::best_ask(currency_out, currency_in)
+		}
+
+		fn depth(currency_out: CurrencyId, currency_in: CurrencyId, levels: u32) -> Vec<pallet_order_book::PriceLevel<Ratio, Balance>> {
+			OrderBook::depth(currency_out, currency_in, levels)
+		}
+
+		fn estimate_fill(currency_out: CurrencyId, currency_in: CurrencyId, amount_out: Balance) -> Option<Balance> {
+			OrderBook::estimate_fill(currency_out, currency_in, amount_out)
+		}
+
+		fn order_history(account: AccountId) -> Vec<pallet_order_book::ClosedOrder<u64, CurrencyId, Balance, Balance, Ratio, BlockNumber>> {
+			OrderBook::order_history(&account)
+		}
+	}
+
+	// LiquidityPoolsGatewayApi
+	impl runtime_common::apis::LiquidityPoolsGatewayApi<Block, Domain, DomainAddress> for Runtime {
+		fn outbound_fees_reserved(domain: Domain) -> U256 {
+			runtime_common::routing::outbound_fees_reserved::<Runtime>(domain)
+		}
+
+		fn inbound_nonce_gaps(domain: Domain) -> Vec<u64> {
+			LiquidityPoolsGateway::inbound_nonce_gaps(domain)
+		}
+
+		fn simulate_inbound(domain_address: DomainAddress, payload: Vec<u8>) -> sp_runtime::DispatchResult {
+			LiquidityPoolsGateway::simulate_inbound_message(domain_address, payload)
+		}
+
+		fn estimate_outbound_fee(domain: Domain, payload: Vec<u8>) -> Result<U256, sp_runtime::DispatchError> {
+			runtime_common::routing::estimate_outbound_fee::<Runtime>(domain, payload)
+		}
+
+		fn outbound_evm_transactions(message_hash: [u8; 32]) -> Vec<pallet_liquidity_pools_gateway::OutboundEvmTransaction<BlockNumber>> {
+			LiquidityPoolsGateway::outbound_evm_transactions(message_hash)
+				.into_iter()
+				.map(|(_, transaction)| transaction)
+				.collect()
+		}
 	}
 
 	// PoolFeesApi
@@ -2419,6 +2672,70 @@ impl_runtime_apis! {
 			runtime_common::update_nav::<Runtime>(pool_id).ok()?;
 			Some(PoolFees::get_pool_fees(pool_id))
 		}
+
+		fn waived_amounts(pool_id: PoolId) -> Vec<(PoolFeeId, Balance)> {
+			PoolFees::get_waived_amounts(pool_id)
+		}
+	}
+
+	// PermissionsApi
+	impl runtime_common::apis::PermissionsApi<Block, AccountId, PermissionScope<PoolId, CurrencyId>, Role<TrancheId>> for Runtime {
+		fn account_roles(account_id: AccountId) -> Vec<(PermissionScope<PoolId, CurrencyId>, Role<TrancheId>)> {
+			Permissions::account_roles(account_id)
+		}
+
+		fn permission_changes(scope: PermissionScope<PoolId, CurrencyId>, count: u32) -> Vec<pallet_permissions::PermissionChange<AccountId, Role<TrancheId>, BlockNumber, Hash>> {
+			Permissions::permission_changes(scope, count)
+		}
+	}
+
+	// AdminActionLogApi
+	impl runtime_common::apis::AdminActionLogApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn admin_actions(from: u64) -> Vec<pallet_admin_action_log::AdminAction<AccountId, Hash, BlockNumber>> {
+			AdminActionLog::actions(from)
+		}
+	}
+
+	// TransferAllowlistApi
+	impl runtime_common::apis::TransferAllowlistApi<Block, AccountId, CurrencyId, RestrictedTransferLocation> for Runtime {
+		fn is_allowed(sender: AccountId, currency: CurrencyId, destination: RestrictedTransferLocation) -> bool {
+			runtime_common::transfer_filter::ensure_transfer_allowed::<TransferAllowList>(
+				sender,
+				destination,
+				currency,
+			)
+			.is_ok()
+		}
+	}
+
+	// ChainHealthApi
+	impl runtime_common::apis::ChainHealthApi<Block> for Runtime {
+		fn chain_health() -> runtime_common::apis::ChainHealth {
+			runtime_common::apis::ChainHealth {
+				gateway_backlog: runtime_common::gateway::queued_messages_per_domain::<Runtime>(),
+				failed_messages: pallet_liquidity_pools_gateway_queue::FailedMessageQueue::<Runtime>::iter_keys()
+					.count() as u32,
+				stale_oracle_feeds: OraclePriceCollection::stale_keys_count(),
+				overdue_epochs: PoolSystem::overdue_epochs_count(),
+				pending_migrations: frame_system::LastRuntimeUpgrade::<Runtime>::get()
+					.map_or(true, |last| last.was_upgraded(&VERSION)),
+			}
+		}
+	}
+
+	// HookWeightsApi
+	impl runtime_common::apis::HookWeightsApi<Block> for Runtime {
+		fn hook_weights() -> Vec<(runtime_common::apis::HookSubsystem, Weight)> {
+			use runtime_common::apis::HookSubsystem;
+
+			sp_std::vec![
+				(HookSubsystem::GatewayOutboundBatches, LiquidityPoolsGateway::hook_weight()),
+				(HookSubsystem::GatewayQueue, LiquidityPoolsGatewayQueue::hook_weight()),
+				(HookSubsystem::LiquidityRewardsEpochs, LiquidityRewards::hook_weight()),
+				(HookSubsystem::OrderExpiry, OrderBook::hook_weight()),
+				(HookSubsystem::PermissionsExpiry, Permissions::hook_weight()),
+			]
+		}
 	}
 
 	// Frontier APIs
@@ -2771,6 +3088,7 @@ mod benches {
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_order_book, OrderBook]
 		[pallet_investments, Investments]
+		[pallet_foreign_investments, ForeignInvestments]
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
 		[pallet_liquidity_rewards, LiquidityRewards]
 		[pallet_transfer_allowlist, TransferAllowList]
@@ -51,4 +51,48 @@ pub type UpgradeCentrifuge1403 = (
 		pallet_permissions::Pallet<Runtime>,
 		<Runtime as frame_system::Config>::DbWeight,
 	>,
+	// Add an optional expiry to order book orders
+	VersionedMigration<
+		1,
+		2,
+		pallet_order_book::migrations::v2::Migration<Runtime>,
+		pallet_order_book::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Index order book orders by currency pair
+	VersionedMigration<
+		2,
+		3,
+		pallet_order_book::migrations::v3::Migration<Runtime>,
+		pallet_order_book::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Prune the order book indices and count the open orders of each account
+	VersionedMigration<
+		3,
+		4,
+		pallet_order_book::migrations::v4::Migration<Runtime>,
+		pallet_order_book::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Add the compounding schedule to the interest accrual rates
+	VersionedMigration<
+		3,
+		4,
+		pallet_interest_accrual::migrations::v4::Migration<Runtime>,
+		pallet_interest_accrual::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Store the last update moment of each interest rate
+	VersionedMigration<
+		4,
+		5,
+		pallet_interest_accrual::migrations::v5::Migration<Runtime>,
+		pallet_interest_accrual::Pallet<Runtime>,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+	// Add the xcm_restricted flag to the asset metadata
+	runtime_common::migrations::asset_registry_xcm_restricted::Migration<Runtime>,
+	// Deploy the revert bytecode at the pools router precompile address
+	runtime_common::migrations::precompile_account_codes::Migration<Runtime>,
 );
@@ -75,6 +75,14 @@ impl<T: frame_system::Config> pallet_anchors::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(7))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
+	fn commit_batch() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `commit`, which also pays the rent
+		//       and stores the anchor. A batch does not remove a pre-commit, so this
+		//       overestimates it by a single write
+		Self::commit()
+	}
 	/// Storage: `Anchor::PreCommits` (r:100 w:100)
 	/// Proof: `Anchor::PreCommits` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
 	fn evict_pre_commits() -> Weight {
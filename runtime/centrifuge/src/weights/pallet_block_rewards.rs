@@ -48,6 +48,11 @@ impl<T: frame_system::Config> pallet_block_rewards::WeightInfo for WeightInfo<T>
 			.saturating_add(Weight::from_parts(0, 6196))
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(3))
+			// TODO: BENCHMARK CORRECTLY
+			//
+			// NOTE: Not measured yet, the read of the vested reward ratio and the
+			//       vesting schedule and lock of the vested part of the reward
+			.saturating_add(T::DbWeight::get().reads_writes(3, 2))
 	}
 	/// Storage: `BlockRewards::NextSessionChanges` (r:1 w:1)
 	/// Proof: `BlockRewards::NextSessionChanges` (`max_values`: Some(1), `max_size`: Some(2097), added: 2592, mode: `MaxEncodedLen`)
@@ -73,4 +78,11 @@ impl<T: frame_system::Config> pallet_block_rewards::WeightInfo for WeightInfo<T>
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn set_vested_reward_ratio() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_collator_reward_per_session`,
+		//       which also writes a single item
+		Self::set_collator_reward_per_session()
+	}
 }
@@ -88,6 +88,11 @@ impl<T: frame_system::Config> pallet_liquidity_rewards::WeightInfo for WeightInf
 			.saturating_add(Weight::from_parts(0, 4328))
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(3))
+			// TODO: BENCHMARK CORRECTLY
+			//
+			// NOTE: Not measured yet, the reads of the current time and the exit
+			//       queue, and the hold of the unstaked amount in the queue
+			.saturating_add(T::DbWeight::get().reads_writes(3, 2))
 	}
 	/// Storage: `LiquidityRewardsBase::Currency` (r:1 w:0)
 	/// Proof: `LiquidityRewardsBase::Currency` (`max_values`: None, `max_size`: Some(863), added: 3338, mode: `MaxEncodedLen`)
@@ -155,4 +160,56 @@ impl<T: frame_system::Config> pallet_liquidity_rewards::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn withdraw_unstaked() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `unstake`, which also mutates the
+		//       account stake and releases its tokens
+		Self::unstake()
+	}
+	fn set_group_emission_curve() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_distributed_reward`, which also
+		//       mutates a single item
+		Self::set_distributed_reward()
+	}
+	fn set_co_incentive() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_distributed_reward`, which also
+		//       mutates a single item
+		Self::set_distributed_reward()
+	}
+	fn fund_co_incentive() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `claim_reward`, which also transfers
+		//       from the rewards account, plus the co-incentive and the transfer of
+		//       the funded currency
+		Self::claim_reward().saturating_add(T::DbWeight::get().reads_writes(1, 2))
+	}
+	fn withdraw_co_incentive() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `claim_reward`, which also transfers
+		//       from the rewards account, plus the co-incentive and the transfer of
+		//       the withdrawn currency
+		Self::claim_reward().saturating_add(T::DbWeight::get().reads_writes(1, 2))
+	}
+	fn lock_stake() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `stake`, which also mutates the
+		//       currency, group and account stake, plus the stake lock
+		Self::stake().saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn unlock_stake() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `stake` and `claim_reward`, which
+		//       together mutate the account stake and transfer the rewards, plus the
+		//       stake lock and the forfeited reward
+		Self::stake().saturating_add(Self::claim_reward()).saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
 }
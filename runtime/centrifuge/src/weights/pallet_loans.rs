@@ -91,6 +91,11 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(757_913, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(11))
 			.saturating_add(T::DbWeight::get().writes(7))
+			// TODO: BENCHMARK CORRECTLY
+			//
+			// NOTE: Not measured yet, the reads and writes of the origination fee,
+			//       its charged amount and the fee destination account
+			.saturating_add(T::DbWeight::get().reads_writes(3, 2))
 	}
 	/// Storage: `Loans::PortfolioValuation` (r:1 w:1)
 	/// Proof: `Loans::PortfolioValuation` (`max_values`: None, `max_size`: Some(24050), added: 26525, mode: `MaxEncodedLen`)
@@ -394,4 +399,74 @@ impl<T: frame_system::Config> pallet_loans::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(4))
 	}
+	fn set_rate_quote_settings() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_write_off_policy`, which
+		//       also checks the caller permissions and writes a single item
+		Self::propose_write_off_policy()
+	}
+	fn request_rate_quote() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_write_off_policy`, which
+		//       also checks the caller permissions and writes a single item
+		Self::propose_write_off_policy()
+	}
+	fn create_with_quote() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `create`, plus the consumption of
+		//       the rate quote
+		Self::create().saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn set_settlement_currency() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_write_off_policy`, which
+		//       also checks the caller permissions and writes a single item
+		Self::propose_write_off_policy()
+	}
+	fn repay_with_settlement(n: u32, ) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `repay`, plus the reads and writes
+		//       of filling the settlement order in the order book
+		Self::repay(n).saturating_add(T::DbWeight::get().reads_writes(8, 5))
+	}
+	fn set_custodian() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_write_off_policy`, which
+		//       also checks the caller permissions and writes a single item
+		Self::propose_write_off_policy()
+	}
+	fn set_attestation_period() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_write_off_policy`, which
+		//       also checks the caller permissions and writes a single item
+		Self::propose_write_off_policy()
+	}
+	fn attest_collateral(n: u32, ) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_loan_mutation`, which also
+		//       checks the caller, reads the active loans and writes a single item
+		Self::propose_loan_mutation(n)
+	}
+	fn set_origination_fee() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_write_off_policy`, which
+		//       also checks the caller permissions and writes a single item
+		Self::propose_write_off_policy()
+	}
+	fn set_repayment_allocation() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_write_off_policy`, which
+		//       also checks the caller permissions and writes a single item
+		Self::propose_write_off_policy()
+	}
 }
@@ -105,4 +105,63 @@ impl<T: frame_system::Config> pallet_oracle_collection::WeightInfo for WeightInf
 			.saturating_add(Weight::from_parts(0, 6039).saturating_mul(m.into()))
 			.saturating_add(Weight::from_parts(0, 100600).saturating_mul(n.into()))
 	}
+	fn propose_update_key_sources(n: u32, ) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_update_collection_info`,
+		//       which also notes a change with `n` items
+		Self::propose_update_collection_info(n)
+	}
+	fn apply_update_key_sources(n: u32, ) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `apply_update_collection_info`,
+		//       which also applies a noted change with `n` items
+		Self::apply_update_collection_info(n)
+	}
+	fn set_admin_value() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_update_collection_info`,
+		//       which also checks the caller and writes a single item
+		Self::propose_update_collection_info(1)
+	}
+	fn propose_update_key_breaker() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `propose_update_collection_info`,
+		//       which also checks the caller and notes a single change
+		Self::propose_update_collection_info(1)
+	}
+	fn apply_update_key_breaker() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `apply_update_collection_info`,
+		//       which also applies a single noted change
+		Self::apply_update_collection_info(1)
+	}
+	fn bond_feeder() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `apply_update_collection_info`, plus
+		//       the reads of the key sources and the bonded feeders, and the reserve
+		//       of the bond
+		Self::apply_update_collection_info(1).saturating_add(T::DbWeight::get().reads(2))
+	}
+	fn unbond_feeder() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `apply_update_collection_info`, plus
+		//       the reads of the disputes and the bonded feeders, and the unreserve
+		//       of the bond
+		Self::apply_update_collection_info(1).saturating_add(T::DbWeight::get().reads(2))
+	}
+	fn resolve_feeder_dispute() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `apply_update_collection_info`, plus
+		//       the dispute, the bonded feeders and the slash of the bond to the
+		//       treasury
+		Self::apply_update_collection_info(1).saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
 }
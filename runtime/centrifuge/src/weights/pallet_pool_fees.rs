@@ -150,4 +150,11 @@ impl<T: frame_system::Config> pallet_pool_fees::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	fn set_fee_waiver() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `charge_fee`, which also looks up
+		//       the fee by id and writes a single item
+		Self::charge_fee(1)
+	}
 }
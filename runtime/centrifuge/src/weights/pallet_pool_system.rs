@@ -286,4 +286,60 @@ impl<T: frame_system::Config> pallet_pool_system::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((6_u64).saturating_mul(n.into())))
 			.saturating_add(Weight::from_parts(0, 2604).saturating_mul(n.into()))
 	}
+	fn propose_epoch_settings(n: u32, ) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_max_reserve`, which also checks
+		//       the pool admin and writes a single item
+		Self::set_max_reserve(n)
+	}
+	fn apply_epoch_settings(n: u32, ) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_max_reserve`, plus the read of
+		//       the epoch execution and the removal of the scheduled settings
+		Self::set_max_reserve(n).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn set_pool_mode() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_max_reserve`, which also checks
+		//       the pool admin and writes a single item
+		Self::set_max_reserve(0)
+	}
+	fn set_change_approver(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_max_reserve`, plus the checks of
+		//       the new approver permissions and the removal of the `n` pending
+		//       approvals
+		Self::set_max_reserve(0)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+			.saturating_add(T::DbWeight::get().writes(n.into()))
+	}
+	fn approve_change(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_max_reserve`, plus the reads
+		//       of the noted change and the current approver, and the count of
+		//       the `n` pending approvals
+		Self::set_max_reserve(0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads(n.into()))
+	}
+	fn propose_tranche_fees() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_max_reserve`, plus the read of
+		//       the current time and the write of the pending approvals
+		Self::set_max_reserve(0).saturating_add(T::DbWeight::get().reads_writes(1, 1))
+	}
+	fn apply_tranche_fees() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_max_reserve`, plus the reads of
+		//       the noted change and the approver, and the writes of the approvals
+		//       and the tranche fees
+		Self::set_max_reserve(0).saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
 }
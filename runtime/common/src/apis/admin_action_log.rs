@@ -0,0 +1,31 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use pallet_admin_action_log::AdminAction;
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
+
+decl_runtime_apis! {
+	/// Runtime API for the admin action log pallet.
+	pub trait AdminActionLogApi<AccountId, Hash, BlockNumber>
+	where
+		AccountId: Codec,
+		Hash: Codec,
+		BlockNumber: Codec,
+	{
+		/// Privileged calls still in the log with an index equal or greater
+		/// than `from`, oldest first.
+		fn admin_actions(from: u64) -> Vec<AdminAction<AccountId, Hash, BlockNumber>>;
+	}
+}
@@ -12,15 +12,22 @@
 use pallet_anchors::AnchorData;
 use parity_scale_codec::Codec;
 use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
 
 decl_runtime_apis! {
 	/// Runtime Api for the pallet-anchors, to be implemented
 	/// by and for a specific runtime that uses that pallet.
+	#[api_version(2)]
 	pub trait AnchorApi<Hash, BlockNumber>
 	where
 		Hash: Codec,
 		BlockNumber: Codec
 	{
 		fn get_anchor_by_id(id: Hash) -> Option<AnchorData<Hash, BlockNumber>>;
+
+		/// Checks if `document_hash` belongs to the batch anchored by
+		/// `anchor_id`, given its merkle proof.
+		#[api_version(2)]
+		fn verify_batch_membership(anchor_id: Hash, document_hash: Hash, proof: Vec<Hash>) -> bool;
 	}
 }
@@ -0,0 +1,48 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_types::domain_address::Domain;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_api::decl_runtime_apis;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Liveness indicators of the chain subsystems.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub struct ChainHealth {
+	/// Messages queued in the gateway and not processed yet, per domain.
+	pub gateway_backlog: Vec<(Domain, u32)>,
+
+	/// Messages whose processing failed in the gateway queue.
+	pub failed_messages: u32,
+
+	/// Oracle keys whose value is missing, outdated or flagged.
+	pub stale_oracle_feeds: u32,
+
+	/// Pools in epoch mode whose minimum epoch time has passed without their
+	/// epoch being closed.
+	pub overdue_epochs: u32,
+
+	/// Whether the migrations of the current runtime version have not been
+	/// executed yet.
+	pub pending_migrations: bool,
+}
+
+decl_runtime_apis! {
+	/// Runtime API for monitoring the liveness of the chain.
+	pub trait ChainHealthApi {
+		/// Key liveness indicators of the chain, aggregated in a single call.
+		fn chain_health() -> ChainHealth;
+	}
+}
@@ -0,0 +1,47 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use frame_support::weights::Weight;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_api::decl_runtime_apis;
+use sp_core::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// Subsystems running custom logic in the `on_initialize` or `on_idle` hooks.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, TypeInfo, RuntimeDebug)]
+pub enum HookSubsystem {
+	/// Queueing of the outbound gateway batches.
+	GatewayOutboundBatches,
+
+	/// Draining of the gateway message queue.
+	GatewayQueue,
+
+	/// Finalization of the liquidity rewards epochs.
+	LiquidityRewardsEpochs,
+
+	/// Removal of the expired orders.
+	OrderExpiry,
+
+	/// Sweep of the expired permissions.
+	PermissionsExpiry,
+}
+
+decl_runtime_apis! {
+	/// Runtime API for the weight consumed by the custom hooks.
+	pub trait HookWeightsApi {
+		/// Weight consumed by each subsystem in the hooks of the current
+		/// block.
+		fn hook_weights() -> Vec<(HookSubsystem, Weight)>;
+	}
+}
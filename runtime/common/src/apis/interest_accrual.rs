@@ -0,0 +1,29 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_traits::{interest::InterestRate, Seconds};
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+use sp_runtime::DispatchError;
+
+decl_runtime_apis! {
+	/// Runtime API for the interest accrual pallet.
+	pub trait InterestAccrualApi<Rate>
+	where
+		Rate: Codec,
+	{
+		/// Returns the accumulated rate of an interest rate at a given
+		/// timestamp, reconstructed from the stored rate snapshots when the
+		/// timestamp lies in the past.
+		fn rate_at(rate: InterestRate<Rate>, timestamp: Seconds) -> Result<Rate, DispatchError>;
+	}
+}
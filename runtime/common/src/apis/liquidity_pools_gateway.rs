@@ -0,0 +1,59 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_primitives::BlockNumber;
+use cfg_traits::liquidity_pools::MessageHash;
+use pallet_liquidity_pools_gateway::OutboundEvmTransaction;
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+use sp_core::U256;
+use sp_runtime::{DispatchError, DispatchResult};
+use sp_std::vec::Vec;
+
+decl_runtime_apis! {
+	/// Runtime API for the liquidity pools gateway.
+	#[api_version(5)]
+	pub trait LiquidityPoolsGatewayApi<Domain, DomainAddress>
+	where
+		Domain: Codec,
+		DomainAddress: Codec,
+	{
+		/// Cumulative amount of native currency reserved for outbound messages
+		/// sent to the given domain, an upper bound of the fees spent on them.
+		/// Each EVM call is accounted at its gas limit and maximum fee per gas.
+		fn outbound_fees_reserved(domain: Domain) -> U256;
+
+		/// Sequence numbers of the given domain not received yet that block
+		/// the execution of already received inbound messages.
+		#[api_version(2)]
+		fn inbound_nonce_gaps(domain: Domain) -> Vec<u64>;
+
+		/// Decodes the payload of an inbound message sent by the given source
+		/// and dry-runs it against the current state, returning the result its
+		/// dispatch would have.
+		#[api_version(3)]
+		fn simulate_inbound(domain_address: DomainAddress, payload: Vec<u8>) -> DispatchResult;
+
+		/// Estimated amount of native currency spent on sending the serialized
+		/// message to the given domain through all its routers.
+		#[api_version(4)]
+		fn estimate_outbound_fee(domain: Domain, payload: Vec<u8>) -> Result<U256, DispatchError>;
+
+		/// EVM transactions executed by the routers to send the outbound
+		/// message with the given hash.
+		#[api_version(5)]
+		fn outbound_evm_transactions(
+			message_hash: MessageHash,
+		) -> Vec<OutboundEvmTransaction<BlockNumber>>;
+	}
+}
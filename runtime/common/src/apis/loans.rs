@@ -11,7 +11,13 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use pallet_loans::types::cashflow::CashflowPayment;
+use cfg_primitives::Hash;
+use cfg_traits::Seconds;
+use cfg_types::fixed_point::Rate;
+use pallet_loans::{
+	entities::changes::RestructuringRecord,
+	types::{cashflow::CashflowPayment, policy::WriteOffPolicyInfo, AttestationStatus},
+};
 use parity_scale_codec::Codec;
 use sp_api::decl_runtime_apis;
 use sp_runtime::DispatchError;
@@ -19,7 +25,7 @@ use sp_std::vec::Vec;
 
 decl_runtime_apis! {
 	/// Runtime API for the rewards pallet.
-	#[api_version(3)]
+	#[api_version(7)]
 	pub trait LoansApi<PoolId, LoanId, Loan, Balance, PriceCollectionInput>
 	where
 		PoolId: Codec,
@@ -32,5 +38,15 @@ decl_runtime_apis! {
 		fn portfolio_loan(pool_id: PoolId, loan_id: LoanId) -> Option<Loan>;
 		fn portfolio_valuation(pool_id: PoolId, input_prices: PriceCollectionInput) -> Result<Balance, DispatchError>;
 		fn expected_cashflows(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<CashflowPayment<Balance>>, DispatchError>;
+		#[api_version(4)]
+		fn expected_loss(pool_id: PoolId, loan_id: LoanId, horizon: Seconds) -> Result<Balance, DispatchError>;
+		#[api_version(4)]
+		fn portfolio_expected_loss(pool_id: PoolId, horizon: Seconds) -> Result<Balance, DispatchError>;
+		#[api_version(5)]
+		fn write_off_policy(pool_id: PoolId) -> WriteOffPolicyInfo<Rate, Hash>;
+		#[api_version(6)]
+		fn attestation_health(pool_id: PoolId) -> Vec<(LoanId, AttestationStatus)>;
+		#[api_version(7)]
+		fn restructuring_history(pool_id: PoolId, loan_id: LoanId) -> Vec<RestructuringRecord<Rate>>;
 	}
 }
@@ -12,19 +12,35 @@
 
 //! Runtime apis useful in the Centrifuge ecosystem
 pub use account_conversion::*;
+pub use activity_bloom::*;
+pub use admin_action_log::*;
 pub use anchors::*;
+pub use chain_health::*;
+pub use hook_weights::*;
+pub use interest_accrual::*;
 pub use investments::*;
+pub use liquidity_pools_gateway::*;
 pub use loans::*;
 pub use order_book::*;
+pub use permissions::*;
 pub use pool_fees::*;
 pub use pools::*;
 pub use rewards::*;
+pub use transfer_allowlist::*;
 
 mod account_conversion;
+mod activity_bloom;
+mod admin_action_log;
 mod anchors;
+mod chain_health;
+mod hook_weights;
+mod interest_accrual;
 mod investments;
+mod liquidity_pools_gateway;
 mod loans;
 mod order_book;
+mod permissions;
 mod pool_fees;
 mod pools;
 mod rewards;
+mod transfer_allowlist;
@@ -11,16 +11,31 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
+use pallet_order_book::{ClosedOrder, PriceLevel};
 use parity_scale_codec::Codec;
 use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
 
 decl_runtime_apis! {
 	/// Runtime API for the order book pallet.
-	pub trait OrderBookApi<CurrencyId, Balance>
+	#[api_version(3)]
+	pub trait OrderBookApi<CurrencyId, Balance, Ratio, AccountId, OrderId, BlockNumber>
 	where
 		CurrencyId: Codec,
 		Balance: Codec,
+		Ratio: Codec,
+		AccountId: Codec,
+		OrderId: Codec,
+		BlockNumber: Codec,
 	{
 		fn min_fulfillment_amount(currency: CurrencyId) -> Option<Balance>;
+		#[api_version(2)]
+		fn best_ask(currency_out: CurrencyId, currency_in: CurrencyId) -> Option<PriceLevel<Ratio, Balance>>;
+		#[api_version(2)]
+		fn depth(currency_out: CurrencyId, currency_in: CurrencyId, levels: u32) -> Vec<PriceLevel<Ratio, Balance>>;
+		#[api_version(2)]
+		fn estimate_fill(currency_out: CurrencyId, currency_in: CurrencyId, amount_out: Balance) -> Option<Balance>;
+		#[api_version(3)]
+		fn order_history(account: AccountId) -> Vec<ClosedOrder<OrderId, CurrencyId, Balance, Balance, Ratio, BlockNumber>>;
 	}
 }
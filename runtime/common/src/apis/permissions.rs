@@ -0,0 +1,36 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_primitives::{BlockNumber, Hash};
+use pallet_permissions::PermissionChange;
+use parity_scale_codec::Codec;
+use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
+
+decl_runtime_apis! {
+	/// Runtime Api for the pallet-permissions, to be implemented
+	/// by and for a specific runtime that uses that pallet.
+	#[api_version(2)]
+	pub trait PermissionsApi<AccountId, Scope, Role>
+	where
+		AccountId: Codec,
+		Scope: Codec,
+		Role: Codec,
+	{
+		/// Returns all currently valid roles of an account across scopes
+		fn account_roles(account_id: AccountId) -> Vec<(Scope, Role)>;
+
+		/// Returns the last `count` permission changes of a scope, oldest first
+		#[api_version(2)]
+		fn permission_changes(scope: Scope, count: u32) -> Vec<PermissionChange<AccountId, Role, BlockNumber, Hash>>;
+	}
+}
@@ -13,12 +13,14 @@
 use cfg_types::pools::PoolFeesList;
 use parity_scale_codec::Codec;
 use sp_api::decl_runtime_apis;
+use sp_std::vec::Vec;
 
 decl_runtime_apis! {
 	/// Runtime for pallet-pool-fees.
 	///
 	/// Note: The runtime api is pallet specific, while the RPC methods
 	///       are more focused on domain-specific logic
+	#[api_version(2)]
 	pub trait PoolFeesApi<PoolId, FeeId, AccountId, Balance, Rate>
 	where
 		PoolId: Codec,
@@ -29,5 +31,9 @@ decl_runtime_apis! {
 	{
 		/// Simulate update of active fees and returns as list divided by buckets
 		fn list_fees(pool_id: PoolId) -> Option<PoolFeesList<FeeId, AccountId, Balance, Rate>>;
+
+		/// Returns the total amount each fee of the pool did not accrue because of its waiver
+		#[api_version(2)]
+		fn waived_amounts(pool_id: PoolId) -> Vec<(FeeId, Balance)>;
 	}
 }
@@ -10,14 +10,18 @@
 // MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
 // GNU General Public License for more details.
 
-use cfg_types::pools::PoolNav;
+use cfg_types::{
+	investments::{AcceptedCurrency, CurrencyExposure},
+	pools::PoolNav,
+};
 use pallet_pool_system::{
+	pool_types::EpochPreview,
 	tranches::{TrancheIndex, TrancheLoc, TrancheSolution},
 	EpochSolution,
 };
 use parity_scale_codec::Codec;
 use sp_api::decl_runtime_apis;
-use sp_runtime::traits::Get;
+use sp_runtime::{traits::Get, DispatchError};
 use sp_std::vec::Vec;
 
 decl_runtime_apis! {
@@ -25,6 +29,7 @@ decl_runtime_apis! {
 	///
 	/// Note: The runtime api is pallet specific, while the RPC methods
 	///       are more focused on domain-specific logic
+	#[api_version(4)]
 	pub trait PoolsApi<PoolId, TrancheId, Balance, Currency, BalanceRatio, MaxTranches>
 	where
 		PoolId: Codec,
@@ -49,5 +54,21 @@ decl_runtime_apis! {
 		fn tranche_currency(pool_id: PoolId, tranche_loc: TrancheLoc<TrancheId>) -> Option<Currency>;
 
 		fn nav(pool_id: PoolId) -> Option<PoolNav<Balance>>;
+
+		/// Exposure of the pool to currencies other than its pool currency,
+		/// from its reserve and from pending foreign investment swaps.
+		#[api_version(2)]
+		fn currency_exposure(pool_id: PoolId) -> Option<Vec<CurrencyExposure<Balance, Currency>>>;
+
+		/// Tranche prices, fulfillments and reserve that closing the current
+		/// epoch would result in, given the current orders and NAV.
+		#[api_version(3)]
+		fn preview_epoch(pool_id: PoolId) -> Result<EpochPreview<Balance, BalanceRatio>, DispatchError>;
+
+		/// Currencies accepted to invest in the pool, with their min amounts,
+		/// domain restrictions and the market ratio used to swap them into the
+		/// pool currency.
+		#[api_version(4)]
+		fn accepted_investment_currencies(pool_id: PoolId) -> Option<Vec<AcceptedCurrency<Balance, Currency, BalanceRatio>>>;
 	}
 }
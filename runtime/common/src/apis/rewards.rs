@@ -35,4 +35,24 @@ decl_runtime_apis! {
 
 		fn compute_reward(domain: RewardDomain, currency_id: CurrencyId, account_id: AccountId) -> Option<Balance>;
 	}
+
+	/// Runtime API for the liquidity rewards exit queue and pending rewards.
+	pub trait LiquidityRewardsApi<AccountId, Balance, CurrencyId, UnstakeRequest>
+	where
+		AccountId: Codec,
+		Balance: Codec,
+		CurrencyId: Codec,
+		UnstakeRequest: Codec,
+	{
+		/// Unstake requests waiting in the exit queue, where the index of each
+		/// request is its position in the queue.
+		fn unstake_requests(account_id: AccountId, currency_id: CurrencyId) -> Vec<UnstakeRequest>;
+
+		/// Total amount waiting in the exit queue.
+		fn queued_unstake(account_id: AccountId, currency_id: CurrencyId) -> Option<Balance>;
+
+		/// Rewards not claimed yet by reward currency, starting with the
+		/// native reward and followed by the co-incentive rewards.
+		fn pending_rewards(account_id: AccountId, currency_id: CurrencyId) -> Option<Vec<(CurrencyId, Balance)>>;
+	}
 }
@@ -2,7 +2,9 @@ use frame_support::pallet_prelude::RuntimeDebug;
 use pallet_loans::entities::changes::Change as LoansChange;
 use pallet_oracle_collection::types::Change as OracleCollectionChange;
 use pallet_pool_fees::types::Change as PoolFeesChange;
-use pallet_pool_system::pool_types::changes::{PoolChangeProposal, Requirement};
+use pallet_pool_system::pool_types::changes::{
+	Change as PoolSystemChange, PoolChangeProposal, Requirement,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
@@ -10,11 +12,18 @@ use sp_std::{marker::PhantomData, vec::Vec};
 
 /// Auxiliar type to carry all pallets bounds used by RuntimeChange
 pub trait Changeable:
-	pallet_loans::Config + pallet_oracle_collection::Config + pallet_pool_fees::Config
+	pallet_loans::Config
+	+ pallet_oracle_collection::Config
+	+ pallet_pool_fees::Config
+	+ pallet_pool_system::Config
 {
 }
-impl<T: pallet_loans::Config + pallet_oracle_collection::Config + pallet_pool_fees::Config>
-	Changeable for T
+impl<
+		T: pallet_loans::Config
+			+ pallet_oracle_collection::Config
+			+ pallet_pool_fees::Config
+			+ pallet_pool_system::Config,
+	> Changeable for T
 {
 }
 
@@ -24,6 +33,7 @@ pub enum RuntimeChange<T: Changeable, Options: Clone = ()> {
 	Loans(LoansChange<T>),
 	OracleCollection(OracleCollectionChange<T>),
 	PoolFee(PoolFeesChange<T>),
+	PoolSystem(PoolSystemChange<T>),
 	_Unreachable(PhantomData<Options>),
 }
 
@@ -42,32 +52,39 @@ impl<T: Changeable, Options: Clone> RuntimeChange<T, Options> {
 		let epoch = Requirement::NextEpoch;
 		let week = Requirement::DelayTime(SECONDS_PER_WEEK as u32);
 		let blocked = Requirement::BlockedByLockedRedemptions;
+		let dual = Requirement::DualApproval;
 
 		match self {
 			RuntimeChange::Loans(change) => match change {
 				// Requirements gathered from
 				// <https://docs.google.com/spreadsheets/d/1RJ5RLobAdumXUK7k_ugxy2eDAwI5akvtuqUM2Tyn5ts>
-				LoansChange::<T>::Loan(_, loan_mutation) => match loan_mutation {
+				LoansChange::<T>::Loan(_, loan_mutation)
+				| LoansChange::<T>::Restructuring(_, loan_mutation, _) => match loan_mutation {
 					LoanMutation::Maturity(_) => vec![week, blocked],
 					LoanMutation::MaturityExtension(_) => vec![],
 					LoanMutation::InterestPayments(_) => vec![week, blocked],
 					LoanMutation::PayDownSchedule(_) => vec![week, blocked],
-					LoanMutation::InterestRate(_) => vec![epoch],
+					LoanMutation::InterestRate(_) => vec![epoch, dual],
 					LoanMutation::Internal(mutation) => match mutation {
-						InternalMutation::ValuationMethod(_) => vec![week, blocked],
+						InternalMutation::ValuationMethod(_) => vec![week, blocked, dual],
 						InternalMutation::ProbabilityOfDefault(_) => vec![epoch],
 						InternalMutation::LossGivenDefault(_) => vec![epoch],
-						InternalMutation::DiscountRate(_) => vec![epoch],
+						InternalMutation::DiscountRate(_) => vec![epoch, dual],
 					},
 				},
-				LoansChange::<T>::Policy(_) => vec![week, blocked],
+				LoansChange::<T>::Policy(_) => vec![week, blocked, dual],
 				LoansChange::<T>::TransferDebt(_, _, _, _) => vec![],
 			},
 			RuntimeChange::OracleCollection(change) => match change {
 				OracleCollectionChange::CollectionInfo(_) => vec![],
+				OracleCollectionChange::KeySources(_, _) => vec![],
+				OracleCollectionChange::KeyBreaker(_, _) => vec![],
 			},
 			RuntimeChange::PoolFee(pool_fees_change) => match pool_fees_change {
-				PoolFeesChange::AppendFee(_, _, _) => vec![week],
+				PoolFeesChange::AppendFee(_, _, _) => vec![week, dual],
+			},
+			RuntimeChange::PoolSystem(pool_system_change) => match pool_system_change {
+				PoolSystemChange::TrancheFees(_, _) => vec![week, blocked, dual],
 			},
 			RuntimeChange::_Unreachable(_) => vec![],
 		}
@@ -125,3 +142,4 @@ macro_rules! runtime_change_support {
 runtime_change_support!(LoansChange, Loans);
 runtime_change_support!(OracleCollectionChange, OracleCollection);
 runtime_change_support!(PoolFeesChange, PoolFee);
+runtime_change_support!(PoolSystemChange, PoolSystem);
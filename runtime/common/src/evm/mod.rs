@@ -11,16 +11,21 @@
 // GNU General Public License for more details.
 
 use cfg_primitives::AuraId;
-use frame_support::{traits::FindAuthor, weights::constants::WEIGHT_REF_TIME_PER_SECOND};
+use frame_support::{
+	traits::{FindAuthor, Get},
+	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
+};
 use pallet_ethereum::{Transaction, TransactionAction};
+use pallet_evm::FeeCalculator;
 #[cfg(feature = "std")]
 use sp_core::Hasher;
 #[cfg(feature = "std")]
 use sp_core::KeccakHasher;
-use sp_core::{crypto::ByteArray, H160};
+use sp_core::{crypto::ByteArray, H160, U256};
 use sp_runtime::{ConsensusEngineId, Permill};
 use sp_std::marker::PhantomData;
 
+pub mod pools_router;
 pub mod precompile;
 
 // From Moonbeam:
@@ -76,6 +81,15 @@ impl pallet_base_fee::BaseFeeThreshold for BaseFeeThreshold {
 	}
 }
 
+/// The current base fee of the EVM, as computed by its fee calculator.
+pub struct EvmBaseFee<T>(PhantomData<T>);
+impl<T: pallet_evm::Config> Get<U256> for EvmBaseFee<T> {
+	fn get() -> U256 {
+		let (base_fee, _) = T::FeeCalculator::min_gas_price();
+		base_fee
+	}
+}
+
 /// Retrieve the "action" of an ethereum transaction
 ///
 /// The action is somethinglike "call" or "create".
@@ -0,0 +1,360 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! Precompile exposing the order book and the pool investments to EVM
+//! contracts.
+//!
+//! Every action is dispatched as a signed extrinsic of the caller's mapped
+//! Substrate account, so the permission checks of the underlying pallets
+//! (i.e. tranche investor roles and frozen investors) apply unchanged.
+//! Several actions can be submitted atomically through `batch`.
+
+use core::marker::PhantomData;
+
+use cfg_primitives::{
+	liquidity_pools::GeneralCurrencyPrefix, Balance, InvestmentId, OrderId, PoolId, TrancheId,
+};
+use cfg_traits::swaps::OrderRatio;
+use cfg_types::{
+	fixed_point::Ratio,
+	tokens::{CurrencyId, GeneralCurrencyIndex},
+};
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::ConstU32,
+};
+use pallet_evm::AddressMapping;
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_runtime::{traits::Dispatchable, FixedPointNumber};
+use sp_std::vec::Vec;
+
+/// Maximum number of actions allowed in a single `batch` call.
+pub const MAX_BATCH_ACTIONS: u32 = 16;
+
+/// Maximum size of a single encoded action of a `batch` call.
+pub const MAX_ACTION_BYTES: u32 = 1024;
+
+const PLACE_ORDER: &[u8] = b"placeOrder(uint128,uint128,uint128,uint128)";
+const CANCEL_ORDER: &[u8] = b"cancelOrder(uint64)";
+const UPDATE_INVEST_ORDER: &[u8] = b"updateInvestOrder(uint64,bytes16,uint128)";
+const UPDATE_REDEEM_ORDER: &[u8] = b"updateRedeemOrder(uint64,bytes16,uint128)";
+const COLLECT_INVESTMENTS: &[u8] = b"collectInvestments(uint64,bytes16)";
+const COLLECT_REDEMPTIONS: &[u8] = b"collectRedemptions(uint64,bytes16)";
+
+/// An action the router can perform on behalf of the caller.
+enum Action {
+	PlaceOrder {
+		currency_in: u128,
+		currency_out: u128,
+		amount_out: u128,
+		ratio: u128,
+	},
+	CancelOrder {
+		order_id: u64,
+	},
+	UpdateInvestOrder {
+		investment_id: InvestmentId,
+		amount: u128,
+	},
+	UpdateRedeemOrder {
+		investment_id: InvestmentId,
+		amount: u128,
+	},
+	CollectInvestments {
+		investment_id: InvestmentId,
+	},
+	CollectRedemptions {
+		investment_id: InvestmentId,
+	},
+}
+
+fn selector(signature: &[u8]) -> u32 {
+	let hash = sp_io::hashing::keccak_256(signature);
+	u32::from_be_bytes([hash[0], hash[1], hash[2], hash[3]])
+}
+
+/// Solidity `bytes16` values are left aligned in their 32 bytes word.
+fn tranche_id(word: H256) -> TrancheId {
+	let mut tranche_id = TrancheId::default();
+	tranche_id.copy_from_slice(&word.as_bytes()[..16]);
+	tranche_id
+}
+
+fn currency(index: u128) -> EvmResult<CurrencyId> {
+	CurrencyId::try_from(GeneralCurrencyIndex::<u128, GeneralCurrencyPrefix>::from(
+		index,
+	))
+	.map_err(|_| revert("Unknown currency index"))
+}
+
+impl Action {
+	/// Decodes an action from its Solidity call data, selector included.
+	fn decode(input: &[u8]) -> EvmResult<Self> {
+		if input.len() < 4 {
+			return Err(revert("Action without selector"));
+		}
+
+		let (selector_bytes, args) = input.split_at(4);
+		let action_selector = u32::from_be_bytes([
+			selector_bytes[0],
+			selector_bytes[1],
+			selector_bytes[2],
+			selector_bytes[3],
+		]);
+		let mut reader = Reader::new(args);
+
+		let action = if action_selector == selector(PLACE_ORDER) {
+			Action::PlaceOrder {
+				currency_in: reader.read().in_field("currencyIn")?,
+				currency_out: reader.read().in_field("currencyOut")?,
+				amount_out: reader.read().in_field("amountOut")?,
+				ratio: reader.read().in_field("ratio")?,
+			}
+		} else if action_selector == selector(CANCEL_ORDER) {
+			Action::CancelOrder {
+				order_id: reader.read().in_field("orderId")?,
+			}
+		} else if action_selector == selector(UPDATE_INVEST_ORDER) {
+			Action::UpdateInvestOrder {
+				investment_id: (
+					reader.read::<PoolId>().in_field("poolId")?,
+					tranche_id(reader.read().in_field("trancheId")?),
+				),
+				amount: reader.read().in_field("amount")?,
+			}
+		} else if action_selector == selector(UPDATE_REDEEM_ORDER) {
+			Action::UpdateRedeemOrder {
+				investment_id: (
+					reader.read::<PoolId>().in_field("poolId")?,
+					tranche_id(reader.read().in_field("trancheId")?),
+				),
+				amount: reader.read().in_field("amount")?,
+			}
+		} else if action_selector == selector(COLLECT_INVESTMENTS) {
+			Action::CollectInvestments {
+				investment_id: (
+					reader.read::<PoolId>().in_field("poolId")?,
+					tranche_id(reader.read().in_field("trancheId")?),
+				),
+			}
+		} else if action_selector == selector(COLLECT_REDEMPTIONS) {
+			Action::CollectRedemptions {
+				investment_id: (
+					reader.read::<PoolId>().in_field("poolId")?,
+					tranche_id(reader.read().in_field("trancheId")?),
+				),
+			}
+		} else {
+			return Err(revert("Unknown action selector"));
+		};
+
+		Ok(action)
+	}
+}
+
+pub struct PoolsRouterPrecompile<R>(PhantomData<R>);
+
+impl<R> PoolsRouterPrecompile<R>
+where
+	R: pallet_evm::Config
+		+ pallet_order_book::Config<
+			CurrencyId = CurrencyId,
+			BalanceOut = Balance,
+			Ratio = Ratio,
+			OrderIdNonce = OrderId,
+		> + pallet_investments::Config<InvestmentId = InvestmentId, Amount = Balance>,
+	R::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_order_book::Call<R>>
+		+ From<pallet_investments::Call<R>>,
+	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
+{
+	fn call(action: Action) -> EvmResult<R::RuntimeCall> {
+		let call = match action {
+			Action::PlaceOrder {
+				currency_in,
+				currency_out,
+				amount_out,
+				ratio,
+			} => pallet_order_book::Call::<R>::place_order {
+				currency_in: currency(currency_in)?,
+				currency_out: currency(currency_out)?,
+				amount_out,
+				ratio: match ratio {
+					0 => OrderRatio::Market,
+					ratio => OrderRatio::Custom(Ratio::from_inner(ratio)),
+				},
+			}
+			.into(),
+			Action::CancelOrder { order_id } => {
+				pallet_order_book::Call::<R>::cancel_order { order_id }.into()
+			}
+			Action::UpdateInvestOrder {
+				investment_id,
+				amount,
+			} => pallet_investments::Call::<R>::update_invest_order {
+				investment_id,
+				amount,
+			}
+			.into(),
+			Action::UpdateRedeemOrder {
+				investment_id,
+				amount,
+			} => pallet_investments::Call::<R>::update_redeem_order {
+				investment_id,
+				amount,
+			}
+			.into(),
+			Action::CollectInvestments { investment_id } => {
+				pallet_investments::Call::<R>::collect_investments { investment_id }.into()
+			}
+			Action::CollectRedemptions { investment_id } => {
+				pallet_investments::Call::<R>::collect_redemptions { investment_id }.into()
+			}
+		};
+
+		Ok(call)
+	}
+
+	/// Dispatches the actions in order as the caller's mapped account.
+	/// Any failing action reverts the whole precompile call.
+	fn dispatch(handle: &mut impl PrecompileHandle, actions: Vec<Action>) -> EvmResult {
+		let who = R::AddressMapping::into_account_id(handle.context().caller);
+
+		for action in actions {
+			let call = Self::call(action)?;
+			RuntimeHelper::<R>::try_dispatch(handle, Some(who.clone()).into(), call, 0)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[precompile_utils::precompile]
+impl<R> PoolsRouterPrecompile<R>
+where
+	R: pallet_evm::Config
+		+ pallet_order_book::Config<
+			CurrencyId = CurrencyId,
+			BalanceOut = Balance,
+			Ratio = Ratio,
+			OrderIdNonce = OrderId,
+		> + pallet_investments::Config<InvestmentId = InvestmentId, Amount = Balance>,
+	R::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo>
+		+ GetDispatchInfo
+		+ From<pallet_order_book::Call<R>>
+		+ From<pallet_investments::Call<R>>,
+	<R::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<R::AccountId>>,
+{
+	/// Place an order book order. Currencies are given by their general
+	/// currency index and a `ratio` of zero places a market order.
+	#[precompile::public("placeOrder(uint128,uint128,uint128,uint128)")]
+	fn place_order(
+		handle: &mut impl PrecompileHandle,
+		currency_in: u128,
+		currency_out: u128,
+		amount_out: u128,
+		ratio: u128,
+	) -> EvmResult {
+		Self::dispatch(
+			handle,
+			sp_std::vec![Action::PlaceOrder {
+				currency_in,
+				currency_out,
+				amount_out,
+				ratio,
+			}],
+		)
+	}
+
+	#[precompile::public("cancelOrder(uint64)")]
+	fn cancel_order(handle: &mut impl PrecompileHandle, order_id: u64) -> EvmResult {
+		Self::dispatch(handle, sp_std::vec![Action::CancelOrder { order_id }])
+	}
+
+	#[precompile::public("updateInvestOrder(uint64,bytes16,uint128)")]
+	fn update_invest_order(
+		handle: &mut impl PrecompileHandle,
+		pool_id: u64,
+		tranche: H256,
+		amount: u128,
+	) -> EvmResult {
+		Self::dispatch(
+			handle,
+			sp_std::vec![Action::UpdateInvestOrder {
+				investment_id: (pool_id, tranche_id(tranche)),
+				amount,
+			}],
+		)
+	}
+
+	#[precompile::public("updateRedeemOrder(uint64,bytes16,uint128)")]
+	fn update_redeem_order(
+		handle: &mut impl PrecompileHandle,
+		pool_id: u64,
+		tranche: H256,
+		amount: u128,
+	) -> EvmResult {
+		Self::dispatch(
+			handle,
+			sp_std::vec![Action::UpdateRedeemOrder {
+				investment_id: (pool_id, tranche_id(tranche)),
+				amount,
+			}],
+		)
+	}
+
+	#[precompile::public("collectInvestments(uint64,bytes16)")]
+	fn collect_investments(
+		handle: &mut impl PrecompileHandle,
+		pool_id: u64,
+		tranche: H256,
+	) -> EvmResult {
+		Self::dispatch(
+			handle,
+			sp_std::vec![Action::CollectInvestments {
+				investment_id: (pool_id, tranche_id(tranche)),
+			}],
+		)
+	}
+
+	#[precompile::public("collectRedemptions(uint64,bytes16)")]
+	fn collect_redemptions(
+		handle: &mut impl PrecompileHandle,
+		pool_id: u64,
+		tranche: H256,
+	) -> EvmResult {
+		Self::dispatch(
+			handle,
+			sp_std::vec![Action::CollectRedemptions {
+				investment_id: (pool_id, tranche_id(tranche)),
+			}],
+		)
+	}
+
+	/// Executes several actions atomically. Each entry is the Solidity call
+	/// data of one of the single action functions of this precompile.
+	#[precompile::public("batch(bytes[])")]
+	fn batch(
+		handle: &mut impl PrecompileHandle,
+		actions: BoundedVec<BoundedBytes<ConstU32<MAX_ACTION_BYTES>>, ConstU32<MAX_BATCH_ACTIONS>>,
+	) -> EvmResult {
+		let actions = Vec::from(actions)
+			.into_iter()
+			.map(|action| Action::decode(action.as_bytes()))
+			.collect::<EvmResult<Vec<_>>>()?;
+
+		Self::dispatch(handle, actions)
+	}
+}
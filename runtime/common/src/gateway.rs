@@ -11,9 +11,14 @@
 // GNU General Public License for more details.
 
 use cfg_types::domain_address::{Domain, DomainAddress};
+use pallet_liquidity_pools::Message;
+use pallet_liquidity_pools_gateway::message::GatewayMessage;
 use polkadot_parachain_primitives::primitives::Sibling;
 use sp_core::crypto::AccountId32;
-use sp_runtime::traits::{AccountIdConversion, Get};
+use sp_runtime::traits::{AccountIdConversion, Get, Saturating};
+use sp_std::vec::Vec;
+
+use crate::routing::RouterId;
 
 pub fn get_gateway_domain_address<T>() -> DomainAddress
 where
@@ -25,3 +30,26 @@ where
 
 	DomainAddress::new(Domain::Evm(chain_id), sender_account.into())
 }
+
+/// Number of messages queued in the gateway and not processed yet, per domain
+/// they are received from or sent to.
+pub fn queued_messages_per_domain<T>() -> Vec<(Domain, u32)>
+where
+	T: pallet_liquidity_pools_gateway_queue::Config<Message = GatewayMessage<Message, RouterId>>,
+{
+	let mut backlog = Vec::<(Domain, u32)>::new();
+
+	for message in pallet_liquidity_pools_gateway_queue::MessageQueue::<T>::iter_values() {
+		let domain = match message {
+			GatewayMessage::Inbound { domain_address, .. } => domain_address.domain(),
+			GatewayMessage::Outbound { router_id, .. } => router_id.into(),
+		};
+
+		match backlog.iter_mut().find(|(queued, _)| *queued == domain) {
+			Some((_, count)) => count.saturating_inc(),
+			None => backlog.push((domain, 1)),
+		}
+	}
+
+	backlog
+}
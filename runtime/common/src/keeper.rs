@@ -0,0 +1,98 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! Rewards paid from a pot to keepers performing permissionless work.
+//!
+//! Each action is rewarded with a fixed amount of native currency, capped by
+//! the funds available in the pot. To avoid draining the pot by spamming
+//! cheap actions, only a limited number of actions of each kind are rewarded
+//! per block.
+
+use cfg_primitives::Balance;
+use cfg_traits::keeper::{KeeperAction, KeeperRewards};
+use frame_support::{
+	storage_alias,
+	traits::{
+		fungible::{Inspect, Mutate},
+		tokens::{Fortitude, Preservation},
+		Get,
+	},
+	PalletId, Twox64Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::traits::{AccountIdConversion, Convert, Zero};
+use sp_std::marker::PhantomData;
+
+/// Reward configuration of a keeper action
+pub struct KeeperReward {
+	/// Amount paid for each performed action
+	pub amount: Balance,
+
+	/// Maximum number of actions rewarded per block
+	pub max_per_block: u32,
+}
+
+/// Number of actions of each kind rewarded in the given block
+#[storage_alias(verbatim)]
+type RewardedActions<T: frame_system::Config> =
+	StorageMap<KeeperRewards, Twox64Concat, KeeperAction, (BlockNumberFor<T>, u32)>;
+
+/// Pays keeper rewards from the account of the `Pot` pallet id, using the
+/// rewards configured by `Rewards` for each action.
+pub struct KeeperRewardPot<T, Pot, Rewards>(PhantomData<(T, Pot, Rewards)>);
+
+impl<T, Pot, Rewards> KeeperRewards for KeeperRewardPot<T, Pot, Rewards>
+where
+	T: pallet_balances::Config<Balance = Balance>,
+	Pot: Get<PalletId>,
+	Rewards: Convert<KeeperAction, KeeperReward>,
+{
+	type AccountId = T::AccountId;
+	type Action = KeeperAction;
+	type Balance = Balance;
+
+	fn reward(keeper: &T::AccountId, action: KeeperAction) -> Balance {
+		let KeeperReward {
+			amount,
+			max_per_block,
+		} = Rewards::convert(action);
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let rewarded = match RewardedActions::<T>::get(action) {
+			Some((block, count)) if block == now => count,
+			_ => 0,
+		};
+
+		if rewarded >= max_per_block {
+			return Zero::zero();
+		}
+
+		let pot: T::AccountId = Pot::get().into_account_truncating();
+		let amount = amount.min(pallet_balances::Pallet::<T>::reducible_balance(
+			&pot,
+			Preservation::Preserve,
+			Fortitude::Polite,
+		));
+
+		if amount.is_zero()
+			|| pallet_balances::Pallet::<T>::transfer(&pot, keeper, amount, Preservation::Preserve)
+				.is_err()
+		{
+			return Zero::zero();
+		}
+
+		RewardedActions::<T>::insert(action, (now, rewarded.saturating_add(1)));
+
+		amount
+	}
+}
@@ -37,9 +37,11 @@ pub mod changes;
 pub mod evm;
 pub mod fees;
 pub mod gateway;
+pub mod keeper;
 pub mod migrations;
 pub mod oracle;
 pub mod origins;
+pub mod parameters;
 pub mod permissions;
 pub mod pool;
 pub mod remarks;
@@ -440,6 +442,153 @@ pub mod investment_portfolios {
 	}
 }
 
+/// Module for the currency exposure of pools common to all runtimes
+pub mod currency_exposure {
+	use cfg_primitives::{AccountId, Balance, InvestmentId, PoolId};
+	use cfg_traits::{swaps::TokenSwaps, PoolInspect};
+	use cfg_types::{investments::CurrencyExposure, tokens::CurrencyId};
+	use sp_runtime::{traits::EnsureAddAssign, DispatchError};
+	use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+	/// Get the exposure of a pool to each currency other than its pool
+	/// currency, arising from its reserve balances and from the in-flight swaps
+	/// of foreign investments into its tranches.
+	///
+	/// NOTE: All foreign investment swaps are iterated, so this is only meant
+	/// to be used off-chain.
+	pub fn get_pool_exposure<T>(
+		pool_id: PoolId,
+	) -> Result<Vec<CurrencyExposure<Balance, CurrencyId>>, DispatchError>
+	where
+		T: frame_system::Config<AccountId = AccountId>
+			+ orml_tokens::Config<Balance = Balance, CurrencyId = CurrencyId>
+			+ pallet_pool_system::Config<PoolId = PoolId, CurrencyId = CurrencyId>
+			+ pallet_foreign_investments::Config<
+				InvestmentId = InvestmentId,
+				CurrencyId = CurrencyId,
+				SwapBalance = Balance,
+			>,
+	{
+		let pool_currency = pallet_pool_system::Pallet::<T>::currency_for(pool_id)
+			.ok_or(DispatchError::Other("Pool must exist; qed"))?;
+		let pool_account = pallet_pool_system::Pallet::<T>::account_for(pool_id);
+
+		let mut exposures = BTreeMap::<CurrencyId, CurrencyExposure<Balance, CurrencyId>>::new();
+
+		for (currency, account) in orml_tokens::Accounts::<T>::iter_prefix(&pool_account) {
+			if currency == pool_currency || matches!(currency, CurrencyId::Tranche(..)) {
+				continue;
+			}
+
+			exposures
+				.entry(currency)
+				.or_insert_with(|| CurrencyExposure::new(currency))
+				.reserve = account.free.saturating_add(account.reserved);
+		}
+
+		for (order_id, (_, (investment_id, _))) in
+			pallet_foreign_investments::OrderIdToSwapId::<T>::iter()
+		{
+			if investment_id.0 != pool_id {
+				continue;
+			}
+
+			let Some(order) =
+				<T as pallet_foreign_investments::Config>::OrderBook::get_order_details(order_id)
+			else {
+				continue;
+			};
+			let swap = order.swap;
+
+			if swap.currency_in == pool_currency {
+				exposures
+					.entry(swap.currency_out)
+					.or_insert_with(|| CurrencyExposure::new(swap.currency_out))
+					.pending_to_pool_currency
+					.ensure_add_assign(swap.amount_out)?;
+			} else if swap.currency_out == pool_currency {
+				exposures
+					.entry(swap.currency_in)
+					.or_insert_with(|| CurrencyExposure::new(swap.currency_in))
+					.pending_from_pool_currency
+					.ensure_add_assign(swap.amount_out)?;
+			}
+		}
+
+		Ok(exposures.into_values().collect())
+	}
+}
+
+/// Module for the currencies accepted to invest in pools common to all runtimes
+pub mod accepted_currencies {
+	use cfg_primitives::{Balance, PoolId};
+	use cfg_types::{
+		domain_address::Domain,
+		fixed_point::Ratio,
+		investments::AcceptedCurrency,
+		tokens::{CurrencyId, CustomMetadata},
+	};
+	use sp_runtime::DispatchError;
+	use sp_std::vec::Vec;
+
+	/// Get the currencies accepted to invest in a pool: its pool currency,
+	/// and every currency allowed as pool currency in the asset registry,
+	/// which foreign investments swap into the pool currency.
+	///
+	/// NOTE: All registered assets are iterated, so this is only meant to be
+	/// used off-chain.
+	pub fn get_accepted_currencies<T>(
+		pool_id: PoolId,
+	) -> Result<Vec<AcceptedCurrency<Balance, CurrencyId, Ratio>>, DispatchError>
+	where
+		T: pallet_pool_system::Config<PoolId = PoolId, CurrencyId = CurrencyId>
+			+ orml_asset_registry::module::Config<
+				AssetId = CurrencyId,
+				CustomMetadata = CustomMetadata,
+			> + pallet_order_book::Config<CurrencyId = CurrencyId, BalanceOut = Balance, Ratio = Ratio>
+			+ pallet_liquidity_pools::Config<CurrencyId = CurrencyId>,
+	{
+		let pool_currency = pallet_pool_system::Pallet::<T>::currency_for(pool_id)
+			.ok_or(DispatchError::Other("Pool must exist; qed"))?;
+
+		orml_asset_registry::module::Metadata::<T>::iter()
+			.filter(|(currency, metadata)| {
+				*currency == pool_currency || metadata.additional.pool_currency
+			})
+			.map(|(currency, metadata)| {
+				// Variants of a local representation are unified 1:1 with it
+				let requires_swap = currency != pool_currency
+					&& metadata
+						.additional
+						.local_representation
+						.map(CurrencyId::LocalAsset)
+						!= Some(pool_currency);
+
+				let (min_amount, market_ratio) = if requires_swap {
+					(
+						pallet_order_book::Pallet::<T>::min_fulfillment_amount(currency)?,
+						pallet_order_book::Pallet::<T>::market_ratio(currency, pool_currency).ok(),
+					)
+				} else {
+					(0, None)
+				};
+
+				let domain = pallet_liquidity_pools::Pallet::<T>::try_get_wrapped_token(&currency)
+					.ok()
+					.map(|(chain_id, _)| Domain::Evm(chain_id));
+
+				Ok(AcceptedCurrency {
+					currency_id: currency,
+					domain,
+					requires_swap,
+					min_amount,
+					market_ratio,
+				})
+			})
+			.collect()
+	}
+}
+
 pub mod xcm_transactor {
 	use parity_scale_codec::{Decode, Encode};
 	use scale_info::TypeInfo;
@@ -0,0 +1,117 @@
+// Copyright 2024 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+use cfg_primitives::Balance;
+use cfg_types::tokens::{AssetStringLimit, CurrencyId, CustomMetadata};
+use frame_support::{
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use orml_traits::asset_registry::AssetMetadata;
+use sp_arithmetic::traits::Saturating;
+#[cfg(feature = "try-runtime")]
+use sp_runtime::SaturatedConversion;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+mod old {
+	use cfg_types::tokens::{CrossChainTransferability, LocalAssetId};
+	use parity_scale_codec::Decode;
+
+	#[derive(Decode)]
+	pub struct CustomMetadata {
+		pub transferability: CrossChainTransferability,
+		pub mintable: bool,
+		pub permissioned: bool,
+		pub pool_currency: bool,
+		pub local_representation: Option<LocalAssetId>,
+	}
+}
+
+const LOG_PREFIX: &str = "AssetRegistryXcmRestricted";
+
+/// Adds the `xcm_restricted` flag to the custom metadata of all registered
+/// assets. Tranche tokens are restricted, any other asset keeps being
+/// transferable as before.
+pub struct Migration<T>(sp_std::marker::PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for Migration<T>
+where
+	T: orml_asset_registry::module::Config<
+		AssetId = CurrencyId,
+		Balance = Balance,
+		CustomMetadata = CustomMetadata,
+		StringLimit = AssetStringLimit,
+	>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let mut count: u64 = 0;
+
+		orml_asset_registry::module::Metadata::<T>::translate::<
+			AssetMetadata<Balance, old::CustomMetadata, AssetStringLimit>,
+			_,
+		>(|currency_id, old| {
+			count.saturating_inc();
+
+			Some(AssetMetadata {
+				decimals: old.decimals,
+				name: old.name,
+				symbol: old.symbol,
+				existential_deposit: old.existential_deposit,
+				location: old.location,
+				additional: CustomMetadata {
+					transferability: old.additional.transferability,
+					mintable: old.additional.mintable,
+					permissioned: old.additional.permissioned,
+					pool_currency: old.additional.pool_currency,
+					local_representation: old.additional.local_representation,
+					xcm_restricted: matches!(currency_id, CurrencyId::Tranche(..)),
+				},
+			})
+		});
+
+		log::info!("{LOG_PREFIX}: Migrated {count} assets!");
+
+		T::DbWeight::get().reads_writes(count, count)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::TryRuntimeError> {
+		use parity_scale_codec::Encode;
+
+		let count: u64 = orml_asset_registry::module::Metadata::<T>::iter_keys()
+			.count()
+			.saturated_into();
+
+		Ok(count.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(pre_state: Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use parity_scale_codec::Decode;
+
+		let pre_count: u64 = Decode::decode(&mut pre_state.as_slice())
+			.expect("pre_upgrade provides a valid state; qed");
+		let post_count: u64 = orml_asset_registry::module::Metadata::<T>::iter_values()
+			.count()
+			.saturated_into();
+
+		assert_eq!(
+			pre_count, post_count,
+			"{LOG_PREFIX}: Mismatching number of assets after migration!"
+		);
+
+		log::info!("{LOG_PREFIX}: Post checks done!");
+
+		Ok(())
+	}
+}
@@ -12,6 +12,7 @@
 
 //! Centrifuge Runtime-Common Migrations
 
+pub mod asset_registry_xcm_restricted;
 pub mod foreign_investments_v2;
 pub mod increase_storage_version;
 pub mod liquidity_pools_v2;
@@ -2,9 +2,11 @@ use cfg_primitives::{
 	conversion::fixed_point_to_balance,
 	types::{AccountId, Balance, PoolId},
 };
-use cfg_traits::{HasLocalAssetRepresentation, Millis, PoolInspect, ValueProvider};
+use cfg_traits::{
+	interest::ReferenceRateId, HasLocalAssetRepresentation, Millis, PoolInspect, ValueProvider,
+};
 use cfg_types::{
-	fixed_point::{Quantity, Ratio},
+	fixed_point::{Quantity, Rate, Ratio},
 	oracles::OracleKey,
 	tokens::{CurrencyId, CustomMetadata},
 };
@@ -13,7 +15,10 @@ use orml_traits::asset_registry;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_arithmetic::traits::One;
-use sp_runtime::{traits::EnsureInto, DispatchError};
+use sp_runtime::{
+	traits::{EnsureFixedPointNumber, EnsureInto},
+	DispatchError, FixedPointNumber,
+};
 use sp_std::marker::PhantomData;
 
 #[derive(Clone, RuntimeDebugNoBound, TypeInfo, Encode, Decode, MaxEncodedLen)]
@@ -34,6 +39,12 @@ impl<O: OriginTrait<AccountId = AccountId>> Feeder<O> {
 	}
 }
 
+impl<O: OriginTrait<AccountId = AccountId>> From<AccountId> for Feeder<O> {
+	fn from(account: AccountId) -> Self {
+		Self::signed(account)
+	}
+}
+
 // There is an issue with clippy in rust-1.78 that detect this as an error:
 // https://github.com/rust-lang/rust-clippy/issues/12245
 #[allow(clippy::unconditional_recursion)]
@@ -166,6 +177,40 @@ where
 	}
 }
 
+/// A provider to get the value per year of the reference rates used by floating
+/// interest rates. Only the values fed by root are used.
+pub struct OracleReferenceRateProvider<Origin, Provider>(PhantomData<(Origin, Provider)>);
+
+impl<Origin, Provider> ValueProvider<(), ReferenceRateId>
+	for OracleReferenceRateProvider<Origin, Provider>
+where
+	Origin: OriginTrait,
+	Provider: ValueProvider<Origin, OracleKey, Value = (Ratio, Millis)>,
+{
+	type Value = Rate;
+
+	fn get(_: &(), reference: &ReferenceRateId) -> Result<Option<Self::Value>, DispatchError> {
+		Provider::get(&Origin::root(), &OracleKey::ReferenceRate(*reference))?
+			.map(|(ratio, _)| {
+				Rate::ensure_from_rational(ratio.into_inner(), Ratio::accuracy())
+					.map_err(Into::into)
+			})
+			.transpose()
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn set(_: &(), reference: &ReferenceRateId, rate: Rate) {
+		Provider::set(
+			&Origin::root(),
+			&OracleKey::ReferenceRate(*reference),
+			(
+				Ratio::ensure_from_rational(rate.into_inner(), Rate::accuracy()).unwrap(),
+				0,
+			),
+		);
+	}
+}
+
 /// An extension of the [OracleRatioProvider] which performs a pre-check when
 /// querying a feeder key value pair.
 pub struct DigestedOracleRatioProvider<Origin, Provider, AssetInspect>(
@@ -0,0 +1,155 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+// This file is part of Centrifuge chain project.
+
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! Pallet parameters shared by all runtimes.
+//!
+//! Runtimes reference these values in their pallet configurations. A runtime
+//! that must diverge defines its own parameter next to the pallet
+//! configuration, with a comment pointing to the shared value it overrides.
+
+/// Parameters for `pallet_order_book`
+pub mod order_book {
+	use cfg_primitives::{Balance, CFG};
+
+	frame_support::parameter_types! {
+		pub MinFulfillmentAmountNative: Balance = 10 * CFG;
+		pub NativeDecimals: u32 = cfg_primitives::currency_decimals::NATIVE;
+		pub const MaxOrderHistory: u32 = 100;
+		pub const MaxOrdersPerUser: u32 = 100;
+	}
+}
+
+/// Parameters for the keeper rewards paid by `crate::keeper::KeeperRewardPot`
+pub mod keeper {
+	use cfg_primitives::CFG;
+	use cfg_traits::keeper::KeeperAction;
+	use frame_support::PalletId;
+	use sp_runtime::traits::Convert;
+
+	use crate::keeper::KeeperReward;
+
+	frame_support::parameter_types! {
+		pub const KeeperPotId: PalletId = cfg_types::ids::KEEPER_REWARDS_PALLET_ID;
+	}
+
+	pub struct Rewards;
+	impl Convert<KeeperAction, KeeperReward> for Rewards {
+		fn convert(action: KeeperAction) -> KeeperReward {
+			match action {
+				KeeperAction::MatchOrders => KeeperReward {
+					amount: CFG / 2,
+					max_per_block: 20,
+				},
+				KeeperAction::WriteOffLoan => KeeperReward {
+					amount: CFG,
+					max_per_block: 10,
+				},
+			}
+		}
+	}
+}
+
+/// Parameters for `pallet_interest_accrual`
+pub mod interest_accrual {
+	use cfg_primitives::SECONDS_PER_DAY;
+
+	frame_support::parameter_types! {
+		pub const SnapshotPeriod: u64 = SECONDS_PER_DAY;
+		pub const MaxRateSnapshots: u32 = 3 * 365;
+	}
+}
+
+/// Parameters for `pallet_loans` and the pallets it relies on
+pub mod loans {
+	use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+	use scale_info::TypeInfo;
+
+	frame_support::parameter_types! {
+		pub const MaxActiveLoansPerPool: u32 = 1000;
+		pub const MaxRegisteredPricesPerPool: u32 = 100;
+		pub const MaxRateCount: u32 = 1000; // See #1024
+
+		#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+		pub const MaxWriteOffPolicySize: u32 = 100;
+
+		pub const MaxRestructurings: u32 = 50;
+	}
+}
+
+/// Parameters for `pallet_oracle_collection`
+pub mod oracle_collection {
+	use cfg_primitives::{Balance, CFG};
+
+	frame_support::parameter_types! {
+		pub const FeederBond: Balance = 1000 * CFG;
+	}
+}
+
+/// Parameters for `pallet_permissions`
+pub mod permissions {
+	use cfg_primitives::SECONDS_PER_DAY;
+	use cfg_traits::Seconds;
+
+	frame_support::parameter_types! {
+		pub const MaxBatchSize: u32 = 100;
+		pub const MaxDelegations: u32 = 20;
+		pub const MaxDelegationPeriod: Seconds = 90 * SECONDS_PER_DAY;
+		pub const MaxChangesPerScope: u32 = 100;
+	}
+}
+
+/// Parameters for `pallet_pool_registry`
+pub mod pool_registry {
+	frame_support::parameter_types! {
+		pub const MaxAllowlistProofLength: u32 = 32;
+	}
+}
+
+/// Parameters for `pallet_liquidity_pools`
+pub mod liquidity_pools {
+	use cfg_primitives::SECONDS_PER_HOUR;
+
+	frame_support::parameter_types! {
+		pub const PositionSnapshotInterval: u64 = SECONDS_PER_HOUR;
+	}
+}
+
+/// Parameters for `pallet_liquidity_pools_gateway`
+pub mod gateway {
+	use cfg_primitives::{Balance, CFG};
+	use frame_support::PalletId;
+	use sp_runtime::transaction_validity::TransactionPriority;
+
+	frame_support::parameter_types! {
+		pub const MaxIncomingMessageSize: u32 = 1024;
+		pub const MaxRouterCount: u32 = 8;
+		pub const MaxInboundNonceGap: u64 = 32;
+		pub const RelayerUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
+		pub const RelayerBond: Balance = 1000 * CFG;
+		pub const FeePotId: PalletId = cfg_types::ids::LP_GATEWAY_FEE_POT_PALLET_ID;
+	}
+}
+
+/// Parameters for `pallet_admin_action_log`
+pub mod admin_action_log {
+	frame_support::parameter_types! {
+		pub const MaxActions: u32 = 1024;
+	}
+}
+
+/// Parameters for `pallet_axelar_router`
+pub mod axelar_router {
+	frame_support::parameter_types! {
+		pub const MaxPayloadSize: u32 = 16 * 1024;
+	}
+}
@@ -11,12 +11,15 @@
 // GNU General Public License for more details.
 
 use cfg_primitives::{AccountId, Balance, InvestmentId, PoolId};
-use cfg_traits::{Permissions, PreConditions};
+use cfg_traits::{Permissions, PoolNotices, PreConditions};
 use cfg_types::{
-	permissions::{PermissionScope, PoolRole, Role},
+	permissions::{PermissionScope, PermissionedCurrencyRole, PoolRole, Role},
 	tokens::CurrencyId,
 };
-use frame_support::{dispatch::DispatchResult, traits::UnixTime};
+use frame_support::{
+	dispatch::DispatchResult,
+	traits::{Contains, UnixTime},
+};
 use pallet_investments::OrderType;
 use sp_runtime::DispatchError;
 use sp_std::marker::PhantomData;
@@ -49,6 +52,29 @@ where
 	}
 }
 
+/// Roles that can be delegated by their holders. Only admin roles are
+/// delegable, investor and holder roles are not.
+pub struct DelegableRoles;
+
+impl Contains<Role> for DelegableRoles {
+	fn contains(role: &Role) -> bool {
+		matches!(
+			role,
+			Role::PoolRole(
+				PoolRole::PoolAdmin
+					| PoolRole::Borrower
+					| PoolRole::LoanAdmin
+					| PoolRole::PricingAdmin
+					| PoolRole::LiquidityAdmin
+					| PoolRole::InvestorAdmin
+					| PoolRole::PODReadAccess
+			) | Role::PermissionedCurrencyRole(
+				PermissionedCurrencyRole::Manager | PermissionedCurrencyRole::Issuer
+			)
+		)
+	}
+}
+
 /// Checks whether the given `who` has the role
 /// of a `TrancheInvestor` without having `FrozenInvestor` for the given pool
 /// and tranche.
@@ -106,3 +132,37 @@ impl<
 		}
 	}
 }
+
+/// Checks that investors acknowledged the notices of the pool requiring it
+/// before placing investment orders, on top of the `Inner` preconditions.
+/// Redemptions and cancellations are not restricted, so investors can always
+/// leave a pool whose changes they don't accept.
+pub struct HasAcknowledgedNotices<N, Inner>(PhantomData<(N, Inner)>);
+impl<N, Inner> PreConditions<OrderType<AccountId, InvestmentId, Balance>>
+	for HasAcknowledgedNotices<N, Inner>
+where
+	N: PoolNotices<AccountId, PoolId>,
+	Inner: PreConditions<OrderType<AccountId, InvestmentId, Balance>, Result = DispatchResult>,
+{
+	type Result = DispatchResult;
+
+	fn check(order: OrderType<AccountId, InvestmentId, Balance>) -> Self::Result {
+		if let OrderType::Investment {
+			who,
+			investment_id: (pool_id, _),
+			amount,
+		} = &order
+		{
+			if *amount > 0
+				&& N::pending_acknowledgement(*pool_id, who).is_some()
+				&& !cfg!(feature = "runtime-benchmarks")
+			{
+				return Err(DispatchError::Other(
+					"Account has not acknowledged the required pool notice.",
+				));
+			}
+		}
+
+		Inner::check(order)
+	}
+}
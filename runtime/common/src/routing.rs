@@ -1,16 +1,22 @@
 use cfg_traits::{
-	liquidity_pools::{LpMessageSerializer, MessageReceiver, MessageSender, RouterProvider},
+	liquidity_pools::{
+		LpMessageProof, LpMessageSerializer, MessageFeeEstimator, MessageReceiver, MessageSender,
+		MessageVersions, RouterProvider,
+	},
 	PreConditions,
 };
 use cfg_types::domain_address::{Domain, DomainAddress};
 use frame_support::{
-	dispatch::DispatchResult,
+	dispatch::{DispatchResult, DispatchResultWithPostInfo},
 	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
 };
 pub use pallet_axelar_router::AxelarId;
 use pallet_liquidity_pools::Message;
-use sp_core::{H160, H256};
-use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_core::{H160, H256, U256};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	DispatchError,
+};
 use sp_std::{marker::PhantomData, vec, vec::Vec};
 
 /// Identification of the router where the messages are sent and received.
@@ -52,6 +58,49 @@ impl RouterProvider<Domain> for LPGatewayRouterProvider {
 	}
 }
 
+/// Cumulative amount of native currency reserved for outbound messages sent
+/// through all routers available for the given domain. This is an upper bound
+/// of the fees spent, see `pallet_axelar_router::FeesReserved`.
+pub fn outbound_fees_reserved<Routers>(domain: Domain) -> U256
+where
+	Routers: pallet_axelar_router::Config,
+{
+	LPGatewayRouterProvider::routers_for_domain(domain)
+		.into_iter()
+		.fold(U256::zero(), |total, router_id| match router_id {
+			RouterId::Axelar(axelar_id) => total.saturating_add(
+				pallet_axelar_router::FeesReserved::<Routers>::get(axelar_id),
+			),
+		})
+}
+
+/// Estimated amount of native currency spent on sending the serialized
+/// message to the given domain. As in the gateway, the message is sent
+/// through the first router and its proof through the remaining ones.
+pub fn estimate_outbound_fee<Routers>(
+	domain: Domain,
+	payload: Vec<u8>,
+) -> Result<U256, DispatchError>
+where
+	Routers: pallet_axelar_router::Config,
+{
+	let message = Message::deserialize(&payload)?;
+	let proof = message.to_proof_message();
+	let mut message = Some(message);
+
+	LPGatewayRouterProvider::routers_for_domain(domain)
+		.into_iter()
+		.try_fold(U256::zero(), |total, router_id| {
+			let router_message = message.take().unwrap_or_else(|| proof.clone());
+			let fee = MessageSerializer::<RouterDispatcher<Routers>, ()>::estimate_fee(
+				router_id,
+				&router_message,
+			)?;
+
+			Ok(total.saturating_add(fee))
+		})
+}
+
 /// This type choose the correct router implementation given a router id
 pub struct RouterDispatcher<Routers>(PhantomData<Routers>);
 impl<Routers> MessageSender for RouterDispatcher<Routers>
@@ -62,13 +111,42 @@ where
 	type Middleware = RouterId;
 	type Origin = DomainAddress;
 
-	fn send(router_id: RouterId, origin: Self::Origin, message: Self::Message) -> DispatchResult {
+	fn send(
+		router_id: RouterId,
+		origin: Self::Origin,
+		message: Self::Message,
+	) -> DispatchResultWithPostInfo {
 		match router_id {
 			RouterId::Axelar(axelar_id) => {
 				pallet_axelar_router::Pallet::<Routers>::send(axelar_id, origin, message)
 			}
 		}
 	}
+
+	fn max_payload_size(router_id: &RouterId) -> Option<u32> {
+		match router_id {
+			RouterId::Axelar(axelar_id) => {
+				pallet_axelar_router::Pallet::<Routers>::max_payload_size(axelar_id)
+			}
+		}
+	}
+}
+
+impl<Routers> MessageFeeEstimator for RouterDispatcher<Routers>
+where
+	Routers: pallet_axelar_router::Config,
+{
+	type Fee = U256;
+	type Message = Vec<u8>;
+	type Middleware = RouterId;
+
+	fn estimate_fee(router_id: RouterId, message: &Self::Message) -> Result<U256, DispatchError> {
+		match router_id {
+			RouterId::Axelar(axelar_id) => {
+				pallet_axelar_router::Pallet::<Routers>::estimate_fee(axelar_id, message)
+			}
+		}
+	}
 }
 
 /// A precondition to ensure an evm account code is configured for a contract
@@ -82,12 +160,19 @@ impl<Runtime: pallet_evm::Config> PreConditions<(H160, H256)> for EvmAccountCode
 	}
 }
 
-/// Entity in charge of serializing and deserializing messages
-pub struct MessageSerializer<Sender, Receiver>(PhantomData<(Sender, Receiver)>);
+/// Entity in charge of serializing and deserializing messages.
+///
+/// Messages are serialized with the version `Versions` negotiated with the
+/// destination domain. The version of each received message is reported back
+/// to `Versions`.
+pub struct MessageSerializer<Sender, Receiver, Versions = ()>(
+	PhantomData<(Sender, Receiver, Versions)>,
+);
 
-impl<Sender, Receiver> MessageSender for MessageSerializer<Sender, Receiver>
+impl<Sender, Receiver, Versions> MessageSender for MessageSerializer<Sender, Receiver, Versions>
 where
 	Sender: MessageSender<Message = Vec<u8>, Middleware = RouterId, Origin = DomainAddress>,
+	Versions: MessageVersions<Domain>,
 {
 	type Message = Message;
 	type Middleware = RouterId;
@@ -97,14 +182,39 @@ where
 		middleware: Self::Middleware,
 		origin: Self::Origin,
 		message: Self::Message,
-	) -> DispatchResult {
-		Sender::send(middleware, origin, message.serialize())
+	) -> DispatchResultWithPostInfo {
+		let version = Versions::outbound_version(&middleware.clone().into());
+		Sender::send(middleware, origin, message.serialize_versioned(version))
+	}
+
+	fn max_payload_size(middleware: &Self::Middleware) -> Option<u32> {
+		Sender::max_payload_size(middleware)
+	}
+}
+
+impl<Sender, Receiver, Versions> MessageFeeEstimator
+	for MessageSerializer<Sender, Receiver, Versions>
+where
+	Sender: MessageFeeEstimator<Message = Vec<u8>, Middleware = RouterId>,
+	Versions: MessageVersions<Domain>,
+{
+	type Fee = Sender::Fee;
+	type Message = Message;
+	type Middleware = RouterId;
+
+	fn estimate_fee(
+		middleware: Self::Middleware,
+		message: &Self::Message,
+	) -> Result<Self::Fee, DispatchError> {
+		let version = Versions::outbound_version(&middleware.clone().into());
+		Sender::estimate_fee(middleware, &message.serialize_versioned(version))
 	}
 }
 
-impl<Sender, Receiver> MessageReceiver for MessageSerializer<Sender, Receiver>
+impl<Sender, Receiver, Versions> MessageReceiver for MessageSerializer<Sender, Receiver, Versions>
 where
 	Receiver: MessageReceiver<Middleware = RouterId, Origin = DomainAddress, Message = Message>,
+	Versions: MessageVersions<Domain>,
 {
 	type Message = Vec<u8>;
 	type Middleware = RouterId;
@@ -115,7 +225,12 @@ where
 		origin: Self::Origin,
 		payload: Self::Message,
 	) -> DispatchResult {
-		let message = Message::deserialize(&payload)?;
-		Receiver::receive(middleware, origin, message)
+		let (message, version) = Message::deserialize_versioned(&payload)?;
+		let domain = origin.domain();
+
+		Receiver::receive(middleware, origin, message)?;
+		Versions::note_inbound_version(&domain, version);
+
+		Ok(())
 	}
 }
@@ -24,7 +24,9 @@ use parity_scale_codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{Convert, DispatchInfoOf, SignedExtension, StaticLookup},
-	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
 	DispatchError, DispatchResult, TokenError,
 };
 use sp_std::{boxed::Box, vec::Vec};
@@ -48,17 +50,10 @@ impl<
 
 	fn check(t: TransferEffects<AccountId, CurrencyId, Balance>) -> Self::Result {
 		let currency_based_check = |sender: AccountId, destination: VersionedLocation, currency| {
-			amalgamate_allowance(
-				T::allowance(
-					sender.clone(),
-					RestrictedTransferLocation::Xcm(Box::new(destination.clone())),
-					FilterCurrency::Specific(currency),
-				),
-				T::allowance(
-					sender,
-					RestrictedTransferLocation::Xcm(Box::new(destination)),
-					FilterCurrency::All,
-				),
+			ensure_transfer_allowed::<T>(
+				sender,
+				RestrictedTransferLocation::Xcm(Box::new(destination)),
+				currency,
 			)
 		};
 
@@ -153,19 +148,8 @@ impl<
 	type Result = bool;
 
 	fn check(t: TransferDetails<AccountId, CurrencyId, Balance>) -> Self::Result {
-		amalgamate_allowance(
-			T::allowance(
-				t.send.clone(),
-				RestrictedTransferLocation::Local(t.recv.clone()),
-				FilterCurrency::Specific(t.id),
-			),
-			T::allowance(
-				t.send.clone(),
-				RestrictedTransferLocation::Local(t.recv.clone()),
-				FilterCurrency::All,
-			),
-		)
-		.is_ok()
+		ensure_transfer_allowed::<T>(t.send, RestrictedTransferLocation::Local(t.recv), t.id)
+			.is_ok()
 	}
 }
 pub struct PreLpTransfer<T>(sp_std::marker::PhantomData<T>);
@@ -182,18 +166,10 @@ impl<
 
 	fn check(t: (AccountId, DomainAddress, CurrencyId)) -> Self::Result {
 		let (sender, receiver, currency) = t;
-		// NOTE: The order of the allowance check here is
-		amalgamate_allowance(
-			T::allowance(
-				sender.clone(),
-				RestrictedTransferLocation::Address(receiver.clone()),
-				FilterCurrency::Specific(currency),
-			),
-			T::allowance(
-				sender,
-				RestrictedTransferLocation::Address(receiver),
-				FilterCurrency::All,
-			),
+		ensure_transfer_allowed::<T>(
+			sender,
+			RestrictedTransferLocation::Address(receiver),
+			currency,
 		)
 	}
 }
@@ -365,6 +341,133 @@ where
 	}
 }
 
+/// Rejects the restricted token transfers not allowed for the sender, so they
+/// fail when validated instead of when dispatched. Transfers nested in other
+/// calls are still checked by the restricted tokens pallet when dispatched.
+#[derive(
+	Clone, Copy, PartialOrd, Ord, PartialEq, Eq, RuntimeDebugNoBound, Encode, Decode, TypeInfo,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct PreTokensTransferExtension<T: frame_system::Config>(sp_std::marker::PhantomData<T>);
+
+#[allow(clippy::new_without_default)]
+impl<T> PreTokensTransferExtension<T>
+where
+	T: frame_system::Config<AccountId = AccountId>
+		+ pallet_restricted_tokens::Config<CurrencyId = CurrencyId>
+		+ pallet_transfer_allowlist::Config<
+			CurrencyId = FilterCurrency,
+			Location = RestrictedTransferLocation,
+		> + Sync
+		+ Send,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<pallet_restricted_tokens::Call<T>>,
+{
+	pub fn new() -> Self {
+		Self(sp_std::marker::PhantomData)
+	}
+
+	fn check(
+		who: &T::AccountId,
+		call: &<T as frame_system::Config>::RuntimeCall,
+	) -> Result<(), TransactionValidityError> {
+		let (dest, currency_id) =
+			match IsSubType::<pallet_restricted_tokens::Call<T>>::is_sub_type(call) {
+				Some(pallet_restricted_tokens::Call::transfer {
+					dest, currency_id, ..
+				})
+				| Some(pallet_restricted_tokens::Call::transfer_all {
+					dest, currency_id, ..
+				})
+				| Some(pallet_restricted_tokens::Call::transfer_keep_alive {
+					dest,
+					currency_id,
+					..
+				}) => (dest, currency_id),
+
+				// If the call is not a transfer we are fine with it to go through without
+				// further checks
+				_ => return Ok(()),
+			};
+
+		let recv = <T as frame_system::Config>::Lookup::lookup(dest.clone())
+			.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Call))?;
+
+		ensure_transfer_allowed::<pallet_transfer_allowlist::pallet::Pallet<T>>(
+			who.clone(),
+			RestrictedTransferLocation::Local(recv),
+			*currency_id,
+		)
+		.map_err(|_| TransactionValidityError::Invalid(InvalidTransaction::Custom(255)))
+	}
+}
+
+impl<T> SignedExtension for PreTokensTransferExtension<T>
+where
+	T: frame_system::Config<AccountId = AccountId>
+		+ pallet_restricted_tokens::Config<CurrencyId = CurrencyId>
+		+ pallet_transfer_allowlist::Config<
+			CurrencyId = FilterCurrency,
+			Location = RestrictedTransferLocation,
+		> + Sync
+		+ Send,
+	<T as frame_system::Config>::RuntimeCall: IsSubType<pallet_restricted_tokens::Call<T>>,
+{
+	type AccountId = T::AccountId;
+	type AdditionalSigned = ();
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type Pre = ();
+
+	const IDENTIFIER: &'static str = "PreTokensTransferExtension";
+
+	fn additional_signed(&self) -> Result<Self::AdditionalSigned, TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_: &DispatchInfoOf<Self::Call>,
+		_: usize,
+	) -> TransactionValidity {
+		Self::check(who, call).map(|_| ValidTransaction::default())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		_: &DispatchInfoOf<Self::Call>,
+		_: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		Self::check(who, call)
+	}
+}
+
+/// Checks the allowances of `sender` for transferring `currency` to
+/// `destination`, either set for that currency or for all currencies.
+pub fn ensure_transfer_allowed<T>(
+	sender: AccountId,
+	destination: RestrictedTransferLocation,
+	currency: CurrencyId,
+) -> DispatchResult
+where
+	T: TransferAllowance<
+		AccountId,
+		CurrencyId = FilterCurrency,
+		Location = RestrictedTransferLocation,
+	>,
+{
+	amalgamate_allowance(
+		T::allowance(
+			sender.clone(),
+			destination.clone(),
+			FilterCurrency::Specific(currency),
+		),
+		T::allowance(sender, destination, FilterCurrency::All),
+	)
+}
+
 fn amalgamate_allowance(
 	first: Result<Option<RestrictedTransferLocation>, DispatchError>,
 	second: Result<Option<RestrictedTransferLocation>, DispatchError>,
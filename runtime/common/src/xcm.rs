@@ -12,12 +12,12 @@
 // GNU General Public License for more details.
 use cfg_primitives::types::{AccountId, Balance};
 use cfg_types::tokens::{CrossChainTransferability, CurrencyId, CustomMetadata};
-use frame_support::traits::{fungibles::Mutate, Everything, Get};
+use frame_support::traits::{fungibles::Mutate, Contains, Everything, Get};
 use frame_system::pallet_prelude::BlockNumberFor;
 use orml_traits::asset_registry::Inspect;
 use polkadot_parachain_primitives::primitives::Sibling;
 use sp_runtime::traits::{AccountIdConversion, Convert, MaybeEquivalence, Zero};
-use sp_std::marker::PhantomData;
+use sp_std::{marker::PhantomData, vec::Vec};
 use staging_xcm::v4::{
 	Asset, AssetId,
 	Fungibility::Fungible,
@@ -143,7 +143,7 @@ where
 
 	fn convert_back(id: &CurrencyId) -> Option<Location> {
 		orml_asset_registry::module::Pallet::<T>::metadata(id)
-			.filter(|m| m.additional.transferability.includes_xcm())
+			.filter(|m| m.additional.transferability.includes_xcm() && !m.additional.xcm_restricted)
 			.and_then(|m| m.location)
 			.and_then(|l| l.try_into().ok())
 	}
@@ -176,6 +176,26 @@ where
 	}
 }
 
+/// Filter for the reserve transfers and teleports of `pallet_xcm`.
+/// Rejects the transfer if any of the assets is registered as
+/// `xcm_restricted` in the AssetRegistry. Assets unknown to the registry are
+/// not restricted.
+pub struct NoXcmRestrictedAssets<T>(PhantomData<T>);
+
+impl<T> Contains<(Location, Vec<Asset>)> for NoXcmRestrictedAssets<T>
+where
+	T: orml_asset_registry::module::Config<AssetId = CurrencyId, CustomMetadata = CustomMetadata>
+		+ staging_parachain_info::Config,
+{
+	fn contains((_, assets): &(Location, Vec<Asset>)) -> bool {
+		assets.iter().all(|asset| {
+			<CurrencyIdConvert<T> as MaybeEquivalence<_, _>>::convert(&asset.id.0)
+				.and_then(|id| orml_asset_registry::module::Pallet::<T>::metadata(&id))
+				.map_or(true, |metadata| !metadata.additional.xcm_restricted)
+		})
+	}
+}
+
 pub struct ToTreasury<T>(PhantomData<T>);
 impl<T> TakeRevenue for ToTreasury<T>
 where
@@ -30,15 +30,15 @@ use cfg_primitives::{
 	LPGatewayQueueMessageNonce, LPGatewaySessionId,
 };
 use cfg_traits::{
-	investments::OrderManager, Millis, Permissions as PermissionsT, PoolUpdateGuard, PreConditions,
-	Seconds,
+	interest::InterestRate, investments::OrderManager, Millis, Permissions as PermissionsT,
+	PoolUpdateGuard, PreConditions, Seconds,
 };
 use cfg_types::{
-	domain_address::DomainAddress,
+	domain_address::{Domain, DomainAddress},
 	fee_keys::{Fee, FeeKey},
 	fixed_point::{Quantity, Rate, Ratio},
-	investments::InvestmentPortfolio,
-	locations::RestrictedTransferLocation,
+	investments::{AcceptedCurrency, CurrencyExposure, InvestmentPortfolio},
+	locations::{DomainWildcard, RestrictedTransferLocation},
 	oracles::OracleKey,
 	permissions::{
 		PermissionRoles, PermissionScope, PermissionedCurrencyRole, PoolRole, Role, UNION,
@@ -113,7 +113,10 @@ use runtime_common::{
 	gateway, instances,
 	instances::{CouncilCollective, TechnicalCollective, TechnicalMembership},
 	message_queue::{NarrowOriginToSibling, ParaIdToSibling},
-	oracle::{DigestedOracleRatioProvider, Feeder, OracleConverterBridge, OracleRatioProvider},
+	oracle::{
+		DigestedOracleRatioProvider, Feeder, OracleConverterBridge, OracleRatioProvider,
+		OracleReferenceRateProvider,
+	},
 	origins::gov::{
 		pallet_custom_origins,
 		types::{
@@ -122,7 +125,10 @@ use runtime_common::{
 		},
 		PoolAdmin, Treasurer,
 	},
-	permissions::{IsUnfrozenTrancheInvestor, PoolAdminCheck},
+	parameters,
+	permissions::{
+		DelegableRoles, HasAcknowledgedNotices, IsUnfrozenTrancheInvestor, PoolAdminCheck,
+	},
 	remarks::Remark,
 	rewards::SingleCurrencyMovement,
 	routing::{
@@ -144,7 +150,7 @@ use sp_runtime::{
 		Dispatchable, IdentityLookup, PostDispatchInfoOf, UniqueSaturatedInto, Verify, Zero,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
-	ApplyExtrinsicResult, FixedI128, Perbill, Permill, Perquintill,
+	ApplyExtrinsicResult, FixedI128, FixedU128, Perbill, Permill, Perquintill,
 };
 use sp_staking::currency_to_vote::U128CurrencyToVote;
 use sp_std::{marker::PhantomData, prelude::*, vec::Vec};
@@ -1087,6 +1093,7 @@ impl pallet_anchors::Config for Runtime {
 	type Currency = Balances;
 	type Fees = Fees;
 	type PreCommitDepositFeeKey = PreCommitDepositFeeKey;
+	type Time = Timestamp;
 	type WeightInfo = weights::pallet_anchors::WeightInfo<Runtime>;
 }
 
@@ -1119,6 +1126,17 @@ parameter_types! {
 		2 * MINUTES
 	};
 
+	// Runtime-defined constraints for the challenge time of a pool
+	pub const ChallengeTimeLowerBound: BlockNumber = 0;
+	pub const ChallengeTimeUpperBound: BlockNumber = 1 * DAYS;
+
+	// Max number of accounts allowed to submit solutions for a pool
+	#[derive(scale_info::TypeInfo, Eq, PartialEq, Debug, Clone, Copy )]
+	pub const MaxSolvers: u32 = 20;
+
+	// Max number of changes of a pool awaiting dual approval at the same time
+	pub const MaxPendingApprovals: u32 = 50;
+
 	// Defaults for pool parameters
 	pub const DefaultMinEpochTime: u64 = 0; // No minimum epoch time
 	pub const DefaultMaxNAVAge: u64 = 1 * SECONDS_PER_MINUTE; // 1 minute
@@ -1137,12 +1155,15 @@ parameter_types! {
 }
 
 impl pallet_pool_system::Config for Runtime {
+	type ActivityRecorder = ActivityBloom;
 	type AdminOrigin = runtime_common::pool::LiquidityAndPoolAdminOrRoot<Runtime>;
 	type AssetRegistry = OrmlAssetRegistry;
 	type AssetsUnderManagementNAV = Loans;
 	type Balance = Balance;
 	type BalanceRatio = Quantity;
 	type ChallengeTime = ChallengeTime;
+	type ChallengeTimeLowerBound = ChallengeTimeLowerBound;
+	type ChallengeTimeUpperBound = ChallengeTimeUpperBound;
 	type Currency = Balances;
 	type CurrencyId = CurrencyId;
 	type DefaultMaxNAVAge = DefaultMaxNAVAge;
@@ -1150,6 +1171,8 @@ impl pallet_pool_system::Config for Runtime {
 	type EpochId = PoolEpochId;
 	type Investments = Investments;
 	type MaxNAVAgeUpperBound = MaxNAVAgeUpperBound;
+	type MaxPendingApprovals = MaxPendingApprovals;
+	type MaxSolvers = MaxSolvers;
 	type MaxTranches = MaxTranches;
 	type MinEpochTimeLowerBound = MinEpochTimeLowerBound;
 	type MinEpochTimeUpperBound = MinEpochTimeUpperBound;
@@ -1182,6 +1205,7 @@ impl pallet_pool_registry::Config for Runtime {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type InterestRate = Rate;
+	type MaxAllowlistProofLength = parameters::pool_registry::MaxAllowlistProofLength;
 	type MaxSizeMetadata = MaxSizeMetadata;
 	type MaxTranches = MaxTranches;
 	type ModifyPool = pallet_pool_system::Pallet<Self>;
@@ -1356,16 +1380,19 @@ impl pallet_xcm_transactor::Config for Runtime {
 }
 
 parameter_types! {
-	pub const MaxActiveLoansPerPool: u32 = 1000;
-	pub const MaxRegisteredPricesPerPool: u32 = 100;
-	pub const MaxRateCount: u32 = 1000; // See #1024
 	pub FirstValueFee: Fee = Fee::Balance(deposit(1, pallet_oracle_feed::util::size_of_feed::<Runtime>()));
 
+	// Overrides `parameters::loans::MaxWriteOffPolicySize`
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxWriteOffPolicySize: u32 = 10;
 
 	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
 	pub const MaxFeedersPerKey: u32 = 5;
+
+	#[derive(Clone, PartialEq, Eq, Debug, TypeInfo, Encode, Decode, MaxEncodedLen)]
+	pub const MaxSourcesPerKey: u32 = 3;
+
+	pub const MaxFeederDeviation: Perquintill = Perquintill::from_percent(5);
 }
 
 impl pallet_oracle_feed::Config for Runtime {
@@ -1382,42 +1409,76 @@ impl pallet_oracle_collection::Config for Runtime {
 	type AggregationProvider = pallet_oracle_collection::util::MedianAggregation;
 	type ChangeGuard = PoolSystem;
 	type CollectionId = PoolId;
+	type Currency = Balances;
+	type DisputeOrigin = EnsureRootOr<HalfOfCouncil>;
+	type FeederBond = parameters::oracle_collection::FeederBond;
 	type FeederId = Feeder<RuntimeOrigin>;
 	type IsAdmin = PoolAdminCheck<Permissions>;
-	type MaxCollectionSize = MaxRegisteredPricesPerPool;
+	type MaxCollectionSize = parameters::loans::MaxRegisteredPricesPerPool;
+	type MaxFeederDeviation = MaxFeederDeviation;
 	type MaxFeedersPerKey = MaxFeedersPerKey;
+	type MaxSourcesPerKey = MaxSourcesPerKey;
 	type OracleKey = OracleKey;
 	type OracleProvider =
 		OracleConverterBridge<RuntimeOrigin, OraclePriceFeed, PoolSystem, OrmlAssetRegistry>;
 	type OracleValue = Balance;
 	type RuntimeChange = runtime_common::changes::RuntimeChange<Runtime, FastDelay>;
 	type RuntimeEvent = RuntimeEvent;
+	type Slash = Treasury;
 	type Time = Timestamp;
 	type Timestamp = Millis;
 	type WeightInfo = weights::pallet_oracle_collection::WeightInfo<Self>;
 }
 
+parameter_types! {
+	pub const MaxOraclePriceAge: Seconds = SECONDS_PER_HOUR;
+}
+
+impl pallet_oracle_adapter::Config for Runtime {
+	type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
+	type MaxPriceAge = MaxOraclePriceAge;
+	type OracleFeeder = OraclePriceFeed;
+	type OracleKey = OracleKey;
+	type OracleValue = Ratio;
+	type RuntimeEvent = RuntimeEvent;
+	type Time = Timestamp;
+	type WeightInfo = ();
+}
+
 impl pallet_interest_accrual::Config for Runtime {
 	type Balance = Balance;
-	type MaxRateCount = MaxRateCount;
+	type MaxRateCount = parameters::loans::MaxRateCount;
+	type MaxRateSnapshots = parameters::interest_accrual::MaxRateSnapshots;
 	type Rate = Rate;
+	type ReferenceRates = OracleReferenceRateProvider<RuntimeOrigin, OraclePriceFeed>;
 	type RuntimeEvent = RuntimeEvent;
+	type SnapshotPeriod = parameters::interest_accrual::SnapshotPeriod;
 	type Time = Timestamp;
 	type Weights = weights::pallet_interest_accrual::WeightInfo<Self>;
 }
 
+pub type KeeperRewards = runtime_common::keeper::KeeperRewardPot<
+	Runtime,
+	parameters::keeper::KeeperPotId,
+	parameters::keeper::Rewards,
+>;
+
 impl pallet_loans::Config for Runtime {
+	type ActivityRecorder = ActivityBloom;
 	type Balance = Balance;
 	type ChangeGuard = PoolSystem;
 	type CollectionId = CollectionId;
 	type CurrencyId = CurrencyId;
 	type InterestAccrual = InterestAccrual;
 	type ItemId = ItemId;
+	type KeeperRewards = KeeperRewards;
 	type LoanId = LoanId;
-	type MaxActiveLoansPerPool = MaxActiveLoansPerPool;
+	type MaxActiveLoansPerPool = parameters::loans::MaxActiveLoansPerPool;
+	type MaxRestructurings = parameters::loans::MaxRestructurings;
 	type MaxWriteOffPolicySize = MaxWriteOffPolicySize;
 	type Moment = Millis;
 	type NonFungible = Uniques;
+	type OrderId = OrderId;
 	type PerThing = Perquintill;
 	type Permissions = Permissions;
 	type Pool = PoolSystem;
@@ -1429,6 +1490,7 @@ impl pallet_loans::Config for Runtime {
 	type RuntimeChange = runtime_common::changes::RuntimeChange<Runtime, FastDelay>;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Timestamp;
+	type TokenSwaps = OrderBook;
 	type WeightInfo = weights::pallet_loans::WeightInfo<Self>;
 }
 
@@ -1447,12 +1509,18 @@ parameter_types! {
 
 impl pallet_permissions::Config for Runtime {
 	type AdminOrigin = EnsureRootOr<EitherOf<HalfOfCouncil, PoolAdmin>>;
+	type DelegableRoles = DelegableRoles;
 	type Editors = Editors;
+	type MaxBatchSize = parameters::permissions::MaxBatchSize;
+	type MaxChangesPerScope = parameters::permissions::MaxChangesPerScope;
+	type MaxDelegationPeriod = parameters::permissions::MaxDelegationPeriod;
+	type MaxDelegations = parameters::permissions::MaxDelegations;
 	type MaxRolesPerScope = MaxRolesPerPool;
 	type Role = Role<TrancheId>;
 	type RuntimeEvent = RuntimeEvent;
 	type Scope = PermissionScope<PoolId, CurrencyId>;
 	type Storage = PermissionRoles<TimeProvider<Timestamp>, MinDelay, TrancheId, MaxTranches>;
+	type Time = Timestamp;
 	type WeightInfo = weights::pallet_permissions::WeightInfo<Runtime>;
 }
 
@@ -1560,6 +1628,7 @@ impl pallet_restricted_tokens::Config for Runtime {
 	type PreReservableCurrency = cfg_traits::Always;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
+	type TransferHooks = ();
 	type WeightInfo = weights::pallet_restricted_tokens::WeightInfo<Self>;
 }
 
@@ -1676,6 +1745,97 @@ impl pallet_anchors_v2::pallet::Config for Runtime {
 	type WeightInfo = weights::pallet_anchors_v2::WeightInfo<Runtime>;
 }
 
+impl pallet_activity_bloom::Config for Runtime {}
+
+/// Privileged calls recorded by the admin action log when dispatched
+/// successfully.
+pub struct PrivilegedCalls;
+impl Contains<RuntimeCall> for PrivilegedCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		match call {
+			RuntimeCall::Sudo(_) => true,
+			RuntimeCall::Council(method) => matches!(
+				method,
+				pallet_collective::Call::execute { .. } | pallet_collective::Call::close { .. }
+			),
+			RuntimeCall::TechnicalCommittee(method) => matches!(
+				method,
+				pallet_collective::Call::execute { .. } | pallet_collective::Call::close { .. }
+			),
+			RuntimeCall::PoolRegistry(method) => matches!(
+				method,
+				pallet_pool_registry::Call::update { .. }
+					| pallet_pool_registry::Call::execute_update { .. }
+					| pallet_pool_registry::Call::set_metadata { .. }
+					| pallet_pool_registry::Call::set_investor_allowlist_root { .. }
+			),
+			RuntimeCall::PoolSystem(method) => matches!(
+				method,
+				pallet_pool_system::Call::set_max_reserve { .. }
+					| pallet_pool_system::Call::set_pool_mode { .. }
+					| pallet_pool_system::Call::set_change_approver { .. }
+					| pallet_pool_system::Call::approve_change { .. }
+			),
+			RuntimeCall::Permissions(method) => matches!(
+				method,
+				pallet_permissions::Call::add { .. }
+					| pallet_permissions::Call::remove { .. }
+					| pallet_permissions::Call::admin_purge { .. }
+					| pallet_permissions::Call::admin_purge_delegations { .. }
+					| pallet_permissions::Call::batch_add { .. }
+					| pallet_permissions::Call::batch_remove { .. }
+					| pallet_permissions::Call::add_with_reason { .. }
+					| pallet_permissions::Call::remove_with_reason { .. }
+			),
+			_ => false,
+		}
+	}
+}
+
+impl pallet_admin_action_log::Config for Runtime {
+	type MaxActions = parameters::admin_action_log::MaxActions;
+	type PrivilegedCalls = PrivilegedCalls;
+	type RuntimeEvent = RuntimeEvent;
+}
+
+parameter_types! {
+	pub const InvestorRewardsPalletId: PalletId = cfg_types::ids::INVESTOR_REWARDS_PALLET_ID;
+}
+
+impl pallet_investor_rewards::Config for Runtime {
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+	type IsPoolAdmin = PoolAdminCheck<Permissions>;
+	type PalletId = InvestorRewardsPalletId;
+	type PoolId = PoolId;
+	type Pools = PoolSystem;
+	type RuntimeEvent = RuntimeEvent;
+	type Time = Timestamp;
+	type Tokens = OrmlTokens;
+	type TrancheId = TrancheId;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MaxWithholdingClasses: u32 = 16;
+}
+
+impl pallet_tax_withholding::Config for Runtime {
+	type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
+	type Balance = Balance;
+	type CurrencyId = CurrencyId;
+	type InvestmentId = InvestmentId;
+	type IsPoolAdmin = PoolAdminCheck<Permissions>;
+	type JurisdictionClass = u8;
+	type MaxClasses = MaxWithholdingClasses;
+	type PoolId = PoolId;
+	type Pools = PoolSystem;
+	type RuntimeEvent = RuntimeEvent;
+	type Tokens = Tokens;
+	type TrancheId = TrancheId;
+	type WeightInfo = ();
+}
+
 parameter_types! {
 	pub const MaxOutstandingCollects: u32 = 10;
 }
@@ -1683,11 +1843,18 @@ impl pallet_investments::Config for Runtime {
 	type Accountant = PoolSystem;
 	type Amount = Balance;
 	type BalanceRatio = Quantity;
-	type CollectedInvestmentHook = pallet_foreign_investments::CollectedInvestmentHook<Runtime>;
-	type CollectedRedemptionHook = pallet_foreign_investments::CollectedRedemptionHook<Runtime>;
+	type CollectedInvestmentHook = pallet_tax_withholding::CollectedInvestmentHook<
+		Runtime,
+		pallet_foreign_investments::CollectedInvestmentHook<Runtime>,
+	>;
+	type CollectedRedemptionHook = pallet_tax_withholding::CollectedRedemptionHook<
+		Runtime,
+		pallet_foreign_investments::CollectedRedemptionHook<Runtime>,
+	>;
 	type InvestmentId = InvestmentId;
 	type MaxOutstandingCollects = MaxOutstandingCollects;
-	type PreConditions = IsUnfrozenTrancheInvestor<Permissions, Timestamp>;
+	type PreConditions =
+		HasAcknowledgedNotices<PoolRegistry, IsUnfrozenTrancheInvestor<Permissions, Timestamp>>;
 	type RuntimeEvent = RuntimeEvent;
 	type Tokens = Tokens;
 	type WeightInfo = ();
@@ -1703,6 +1870,12 @@ parameter_types! {
 	#[derive(scale_info::TypeInfo, Debug, PartialEq, Eq, Clone)]
 	pub const MaxChangesPerEpoch: u32 = 50;
 	pub const InitialEpochDuration: Millis = SECONDS_PER_MINUTE * 1000; // 1 min in milliseconds
+	pub const LiquidityUnstakeDelay: Millis = SECONDS_PER_HOUR * 1000; // 1 hour in milliseconds
+	pub const MaxUnstakeRequests: u32 = 10;
+	pub const MaxCoIncentives: u32 = 10;
+	pub const LiquidityCoIncentivesPalletId: PalletId = cfg_types::ids::LIQUIDITY_CO_INCENTIVES_PALLET_ID;
+	pub LiquidityLockOptions: Vec<(BlockNumber, FixedU128)> = cfg_types::consts::rewards::LIQUIDITY_LOCK_OPTIONS.to_vec();
+	pub const LiquidityEarlyUnlockPenalty: Perbill = cfg_types::consts::rewards::LIQUIDITY_EARLY_UNLOCK_PENALTY;
 }
 
 impl pallet_rewards::mechanism::gap::Config for Runtime {
@@ -1716,7 +1889,9 @@ impl pallet_rewards::mechanism::gap::Config for Runtime {
 impl pallet_rewards::Config<pallet_rewards::Instance2> for Runtime {
 	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
+	type EarlyUnlockPenalty = LiquidityEarlyUnlockPenalty;
 	type GroupId = u32;
+	type LockOptions = LiquidityLockOptions;
 	type PalletId = RewardsPalletId;
 	type RewardCurrency = RewardCurrency;
 	type RewardIssuance =
@@ -1728,14 +1903,19 @@ impl pallet_rewards::Config<pallet_rewards::Instance2> for Runtime {
 impl pallet_liquidity_rewards::Config for Runtime {
 	type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
 	type Balance = Balance;
+	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
 	type GroupId = u32;
 	type InitialEpochDuration = InitialEpochDuration;
 	type MaxChangesPerEpoch = MaxChangesPerEpoch;
+	type MaxCoIncentives = MaxCoIncentives;
 	type MaxGroups = MaxGroups;
+	type MaxUnstakeRequests = MaxUnstakeRequests;
+	type PalletId = LiquidityCoIncentivesPalletId;
 	type Rewards = LiquidityRewardsBase;
 	type RuntimeEvent = RuntimeEvent;
 	type Timer = Timestamp;
+	type UnstakeDelay = LiquidityUnstakeDelay;
 	type Weight = u64;
 	type WeightInfo = ();
 }
@@ -1743,7 +1923,10 @@ impl pallet_liquidity_rewards::Config for Runtime {
 impl pallet_rewards::Config<instances::BlockRewards> for Runtime {
 	type Currency = Tokens;
 	type CurrencyId = CurrencyId;
+	// Block rewards stakes can not be locked
+	type EarlyUnlockPenalty = ();
 	type GroupId = u32;
+	type LockOptions = ();
 	type PalletId = RewardsPalletId;
 	type RewardCurrency = RewardCurrency;
 	type RewardIssuance =
@@ -1761,6 +1944,7 @@ parameter_types! {
 	pub const BlockRewardCurrency: CurrencyId = CurrencyId::Staking(BlockRewardsCurrency);
 	pub const StakeAmount: Balance = cfg_types::consts::rewards::DEFAULT_COLLATOR_STAKE;
 	pub const CollatorGroupId: u32 = cfg_types::ids::COLLATOR_GROUP_ID;
+	pub const RewardVestingPeriod: BlockNumber = HOURS;
 }
 
 impl pallet_block_rewards::Config for Runtime {
@@ -1769,7 +1953,9 @@ impl pallet_block_rewards::Config for Runtime {
 	type Balance = Balance;
 	type CurrencyId = CurrencyId;
 	type MaxCollators = MaxAuthorities;
+	type NativeCurrency = Balances;
 	type Rate = Rate;
+	type RewardVestingPeriod = RewardVestingPeriod;
 	type Rewards = BlockRewardsBase;
 	type RuntimeEvent = RuntimeEvent;
 	type StakeAmount = StakeAmount;
@@ -1778,6 +1964,7 @@ impl pallet_block_rewards::Config for Runtime {
 	type Time = Timestamp;
 	type Tokens = Tokens;
 	type TreasuryPalletId = TreasuryPalletId;
+	type Vesting = Vesting;
 	type Weight = u64;
 	type WeightInfo = weights::pallet_block_rewards::WeightInfo<Runtime>;
 }
@@ -1786,17 +1973,13 @@ impl pallet_transfer_allowlist::Config for Runtime {
 	type CurrencyId = FilterCurrency;
 	type Deposit = AllowanceDeposit<Fees>;
 	type Location = RestrictedTransferLocation;
+	type LocationWildcard = DomainWildcard;
 	type ReserveCurrency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeHoldReason = RuntimeHoldReason;
 	type WeightInfo = weights::pallet_transfer_allowlist::WeightInfo<Runtime>;
 }
 
-parameter_types! {
-	pub MinFulfillmentAmountNative: Balance = 10 * CFG;
-	pub NativeDecimals: u32 = cfg_primitives::currency_decimals::NATIVE;
-}
-
 impl pallet_order_book::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
 	type AssetRegistry = OrmlAssetRegistry;
@@ -1806,8 +1989,11 @@ impl pallet_order_book::Config for Runtime {
 	type CurrencyId = CurrencyId;
 	type FeederId = Feeder<RuntimeOrigin>;
 	type FulfilledOrderHook = ForeignInvestments;
-	type MinFulfillmentAmountNative = MinFulfillmentAmountNative;
-	type NativeDecimals = NativeDecimals;
+	type KeeperRewards = KeeperRewards;
+	type MaxOrderHistory = parameters::order_book::MaxOrderHistory;
+	type MaxOrdersPerUser = parameters::order_book::MaxOrdersPerUser;
+	type MinFulfillmentAmountNative = parameters::order_book::MinFulfillmentAmountNative;
+	type NativeDecimals = parameters::order_book::NativeDecimals;
 	type OrderIdNonce = u64;
 	type Ratio = Ratio;
 	type RatioProvider = DigestedOracleRatioProvider<
@@ -1816,6 +2002,7 @@ impl pallet_order_book::Config for Runtime {
 		OrmlAssetRegistry,
 	>;
 	type RuntimeEvent = RuntimeEvent;
+	type TreasuryAccount = TreasuryAccount;
 	type Weights = weights::pallet_order_book::WeightInfo<Runtime>;
 }
 
@@ -1833,6 +2020,7 @@ impl pallet_foreign_investments::Config for Runtime {
 	type SwapBalance = Balance;
 	type SwapRatio = Ratio;
 	type TrancheBalance = Balance;
+	type WeightInfo = ();
 }
 
 parameter_types! {
@@ -1852,7 +2040,9 @@ impl pallet_liquidity_pools::Config for Runtime {
 	type Permission = Permissions;
 	type PoolId = PoolId;
 	type PoolInspect = PoolSystem;
+	type PositionSnapshotInterval = parameters::liquidity_pools::PositionSnapshotInterval;
 	type PreTransferFilter = PreLpTransfer<TransferAllowList>;
+	type PriceUpdateHandler = OracleAdapter;
 	type RuntimeEvent = RuntimeEvent;
 	type Time = Timestamp;
 	type Tokens = Tokens;
@@ -1866,30 +2056,41 @@ impl pallet_liquidity_pools_forwarder::Config for Runtime {
 	type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
 	type Message = pallet_liquidity_pools::Message;
 	type MessageReceiver = LiquidityPoolsGateway;
-	type MessageSender = MessageSerializer<RouterDispatcher<Runtime>, LiquidityPoolsForwarder>;
+	type MessageSender = MessageSerializer<
+		RouterDispatcher<Runtime>,
+		LiquidityPoolsForwarder,
+		LiquidityPoolsGateway,
+	>;
 	type RouterId = RouterId;
 	type RuntimeEvent = RuntimeEvent;
 }
 
 parameter_types! {
 	pub Sender: DomainAddress = gateway::get_gateway_domain_address::<Runtime>();
-	pub const MaxIncomingMessageSize: u32 = 1024;
-	pub const MaxRouterCount: u32 = 8;
 }
 
 impl pallet_liquidity_pools_gateway::Config for Runtime {
+	type ActivityRecorder = ActivityBloom;
 	type AdminOrigin = EnsureRootOr<HalfOfCouncil>;
+	type Currency = Balances;
+	type EthereumTransactions = EthereumTransaction;
+	type FeePotId = parameters::gateway::FeePotId;
 	type InboundMessageHandler = LiquidityPools;
-	type MaxIncomingMessageSize = MaxIncomingMessageSize;
-	type MaxRouterCount = MaxRouterCount;
+	type MaxInboundNonceGap = parameters::gateway::MaxInboundNonceGap;
+	type MaxIncomingMessageSize = parameters::gateway::MaxIncomingMessageSize;
+	type MaxRouterCount = parameters::gateway::MaxRouterCount;
 	type Message = pallet_liquidity_pools::Message;
 	type MessageQueue = LiquidityPoolsGatewayQueue;
 	type MessageSender = LiquidityPoolsForwarder;
+	type OutboundMessageTracker = LiquidityPools;
+	type RelayerBond = parameters::gateway::RelayerBond;
+	type RelayerId = cfg_primitives::liquidity_pools::RelayerId;
 	type RouterId = RouterId;
 	type RouterProvider = LPGatewayRouterProvider;
 	type RuntimeEvent = RuntimeEvent;
 	type Sender = Sender;
 	type SessionId = LPGatewaySessionId;
+	type UnsignedPriority = parameters::gateway::RelayerUnsignedPriority;
 	type WeightInfo = ();
 }
 
@@ -2007,9 +2208,15 @@ impl pallet_ethereum_transaction::Config for Runtime {}
 
 impl pallet_axelar_router::Config for Runtime {
 	type AdminOrigin = EnsureRoot<AccountId>;
+	type BaseFee = runtime_common::evm::EvmBaseFee<Runtime>;
 	type EvmAccountCodeChecker = EvmAccountCodeChecker<Runtime>;
+	type MaxPayloadSize = parameters::axelar_router::MaxPayloadSize;
 	type Middleware = RouterId;
-	type Receiver = MessageSerializer<RouterDispatcher<Runtime>, LiquidityPoolsForwarder>;
+	type Receiver = MessageSerializer<
+		RouterDispatcher<Runtime>,
+		LiquidityPoolsForwarder,
+		LiquidityPoolsGateway,
+	>;
 	type RuntimeEvent = RuntimeEvent;
 	type Transactor = EthereumTransaction;
 }
@@ -2117,6 +2324,8 @@ pub type SignedExtra = (
 	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
 	frame_metadata_hash_extension::CheckMetadataHash<Runtime>,
 	runtime_common::transfer_filter::PreBalanceTransferExtension<Runtime>,
+	pallet_admin_action_log::RecordAdminActions<Runtime>,
+	runtime_common::transfer_filter::PreTokensTransferExtension<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -2205,7 +2414,7 @@ construct_runtime!(
 		BlockRewards: pallet_block_rewards::{Pallet, Call, Storage, Event<T>, Config<T>} = 111,
 		TransferAllowList: pallet_transfer_allowlist::{Pallet, Call, Storage, Event<T>, HoldReason} = 112,
 		GapRewardMechanism: pallet_rewards::mechanism::gap = 114,
-		LiquidityPoolsGateway: pallet_liquidity_pools_gateway::{Pallet, Call, Storage, Event<T> } = 115,
+		LiquidityPoolsGateway: pallet_liquidity_pools_gateway::{Pallet, Call, Storage, Event<T>, ValidateUnsigned } = 115,
 		OrderBook: pallet_order_book::{Pallet, Call, Storage, Event<T>} = 116,
 		ForeignInvestments: pallet_foreign_investments::{Pallet, Storage, Event<T>} = 117,
 		OraclePriceFeed: pallet_oracle_feed::{Pallet, Call, Storage, Event<T>} = 118,
@@ -2215,6 +2424,11 @@ construct_runtime!(
 		AnchorsV2: pallet_anchors_v2::{Pallet, Call, Storage, Event<T>} = 130,
 		LiquidityPoolsGatewayQueue: pallet_liquidity_pools_gateway_queue::{Pallet, Call, Storage, Event<T>} = 131,
 		LiquidityPoolsForwarder: pallet_liquidity_pools_forwarder::{Pallet, Call, Storage, Event<T>} = 132,
+		ActivityBloom: pallet_activity_bloom::{Pallet, Storage} = 133,
+		InvestorRewards: pallet_investor_rewards::{Pallet, Call, Storage, Event<T>} = 134,
+		OracleAdapter: pallet_oracle_adapter::{Pallet, Call, Storage, Event<T>} = 136,
+		AdminActionLog: pallet_admin_action_log::{Pallet, Storage, Event<T>} = 137,
+		TaxWithholding: pallet_tax_withholding::{Pallet, Call, Storage, Event<T>} = 138,
 
 		// XCM
 		XcmpQueue: cumulus_pallet_xcmp_queue::{Pallet, Call, Storage, Event<T>} = 120,
@@ -2451,6 +2665,10 @@ impl_runtime_apis! {
 		fn get_anchor_by_id(id: Hash) -> Option<AnchorData<Hash, BlockNumber>> {
 			Anchor::get_anchor_by_id(id)
 		}
+
+		fn verify_batch_membership(anchor_id: Hash, document_hash: Hash, proof: Vec<Hash>) -> bool {
+			Anchor::verify_batch_membership(anchor_id, document_hash, proof)
+		}
 	}
 
 	// PoolsAPI
@@ -2502,6 +2720,18 @@ impl_runtime_apis! {
 		fn nav(pool_id: PoolId) -> Option<PoolNav<Balance>> {
 			runtime_common::update_nav::<Runtime>(pool_id).ok()
 		}
+
+		fn currency_exposure(pool_id: PoolId) -> Option<Vec<CurrencyExposure<Balance, CurrencyId>>> {
+			runtime_common::currency_exposure::get_pool_exposure::<Runtime>(pool_id).ok()
+		}
+
+		fn preview_epoch(pool_id: PoolId) -> Result<pallet_pool_system::pool_types::EpochPreview<Balance, Quantity>, DispatchError> {
+			PoolSystem::preview_epoch(pool_id)
+		}
+
+		fn accepted_investment_currencies(pool_id: PoolId) -> Option<Vec<AcceptedCurrency<Balance, CurrencyId, Quantity>>> {
+			runtime_common::accepted_currencies::get_accepted_currencies::<Runtime>(pool_id).ok()
+		}
 	}
 
 
@@ -2522,6 +2752,37 @@ impl_runtime_apis! {
 		}
 	}
 
+	// LiquidityRewardsApi
+	impl runtime_common::apis::LiquidityRewardsApi<
+		Block,
+		AccountId,
+		Balance,
+		CurrencyId,
+		pallet_liquidity_rewards::UnstakeRequestOf<Runtime>,
+	> for Runtime {
+		fn unstake_requests(account_id: AccountId, currency_id: CurrencyId) -> Vec<pallet_liquidity_rewards::UnstakeRequestOf<Runtime>> {
+			LiquidityRewards::unstake_requests(&account_id, &currency_id)
+		}
+
+		fn queued_unstake(account_id: AccountId, currency_id: CurrencyId) -> Option<Balance> {
+			LiquidityRewards::queued_unstake(&account_id, &currency_id).ok()
+		}
+
+		fn pending_rewards(account_id: AccountId, currency_id: CurrencyId) -> Option<Vec<(CurrencyId, Balance)>> {
+			let reward = <LiquidityRewardsBase as cfg_traits::rewards::AccountRewards<AccountId>>::compute_reward(currency_id, &account_id).ok()?;
+			let co_incentives = LiquidityRewards::pending_co_incentives(&account_id, &currency_id).ok()?;
+
+			Some(sp_std::iter::once((RewardCurrency::get(), reward)).chain(co_incentives).collect())
+		}
+	}
+
+	// InterestAccrualApi
+	impl runtime_common::apis::InterestAccrualApi<Block, Rate> for Runtime {
+		fn rate_at(rate: InterestRate<Rate>, timestamp: Seconds) -> Result<Rate, DispatchError> {
+			InterestAccrual::rate_at(&rate, timestamp)
+		}
+	}
+
 	// LoansApi
 	impl runtime_common::apis::LoansApi<
 		Block,
@@ -2554,6 +2815,26 @@ impl_runtime_apis! {
 		fn expected_cashflows(pool_id: PoolId, loan_id: LoanId) -> Result<Vec<CashflowPayment<Balance>>, DispatchError> {
 			Loans::expected_cashflows(pool_id, loan_id)
 		}
+
+		fn expected_loss(pool_id: PoolId, loan_id: LoanId, horizon: Seconds) -> Result<Balance, DispatchError> {
+			Loans::expected_loss(pool_id, loan_id, horizon)
+		}
+
+		fn portfolio_expected_loss(pool_id: PoolId, horizon: Seconds) -> Result<Balance, DispatchError> {
+			Loans::portfolio_expected_loss(pool_id, horizon)
+		}
+
+		fn write_off_policy(pool_id: PoolId) -> pallet_loans::types::policy::WriteOffPolicyInfo<Rate, Hash> {
+			Loans::write_off_policy(pool_id)
+		}
+
+		fn attestation_health(pool_id: PoolId) -> Vec<(LoanId, pallet_loans::types::AttestationStatus)> {
+			Loans::attestation_health(pool_id)
+		}
+
+		fn restructuring_history(pool_id: PoolId, loan_id: LoanId) -> Vec<pallet_loans::entities::changes::RestructuringRecord<Rate>> {
+			Loans::restructuring_history(pool_id, loan_id)
+		}
 	}
 
 	// Investment Runtime APIs
@@ -2571,10 +2852,59 @@ impl_runtime_apis! {
 	}
 
 	// OrderBookApi
-	impl runtime_common::apis::OrderBookApi<Block, CurrencyId, Balance> for Runtime {
+	impl runtime_common::apis::OrderBookApi<Block, CurrencyId, Balance, Ratio, AccountId, u64, BlockNumber> for Runtime {
 		fn min_fulfillment_amount(currency_id: CurrencyId) -> Option<Balance> {
 			OrderBook::min_fulfillment_amount(currency_id).ok()
 		}
+
+		fn best_ask(currency_out: CurrencyId, currency_in: CurrencyId) -> Option<pallet_order_book::PriceLevel<Ratio, Balance>> {
+			OrderBook::best_ask(currency_out, currency_in)
+		}
+
+		fn depth(currency_out: CurrencyId, currency_in: CurrencyId, levels: u32) -> Vec<pallet_order_book::PriceLevel<Ratio, Balance>> {
+			OrderBook::depth(currency_out, currency_in, levels)
+		}
+
+		fn estimate_fill(currency_out: CurrencyId, currency_in: CurrencyId, amount_out: Balance) -> Option<Balance> {
+			OrderBook::estimate_fill(currency_out, currency_in, amount_out)
+		}
+
+		fn order_history(account: AccountId) -> Vec<pallet_order_book::ClosedOrder<u64, CurrencyId, Balance, Balance, Ratio, BlockNumber>> {
+			OrderBook::order_history(&account)
+		}
+	}
+
+	// ActivityBloomApi
+	impl runtime_common::apis::ActivityBloomApi<Block> for Runtime {
+		fn block_bloom() -> pallet_activity_bloom::ActivityBloom {
+			ActivityBloom::block_bloom()
+		}
+	}
+
+	// LiquidityPoolsGatewayApi
+	impl runtime_common::apis::LiquidityPoolsGatewayApi<Block, Domain, DomainAddress> for Runtime {
+		fn outbound_fees_reserved(domain: Domain) -> U256 {
+			runtime_common::routing::outbound_fees_reserved::<Runtime>(domain)
+		}
+
+		fn inbound_nonce_gaps(domain: Domain) -> Vec<u64> {
+			LiquidityPoolsGateway::inbound_nonce_gaps(domain)
+		}
+
+		fn simulate_inbound(domain_address: DomainAddress, payload: Vec<u8>) -> sp_runtime::DispatchResult {
+			LiquidityPoolsGateway::simulate_inbound_message(domain_address, payload)
+		}
+
+		fn estimate_outbound_fee(domain: Domain, payload: Vec<u8>) -> Result<U256, sp_runtime::DispatchError> {
+			runtime_common::routing::estimate_outbound_fee::<Runtime>(domain, payload)
+		}
+
+		fn outbound_evm_transactions(message_hash: [u8; 32]) -> Vec<pallet_liquidity_pools_gateway::OutboundEvmTransaction<BlockNumber>> {
+			LiquidityPoolsGateway::outbound_evm_transactions(message_hash)
+				.into_iter()
+				.map(|(_, transaction)| transaction)
+				.collect()
+		}
 	}
 
 	// PoolFeesApi
@@ -2583,6 +2913,70 @@ impl_runtime_apis! {
 			runtime_common::update_nav::<Runtime>(pool_id).ok()?;
 			Some(PoolFees::get_pool_fees(pool_id))
 		}
+
+		fn waived_amounts(pool_id: PoolId) -> Vec<(PoolFeeId, Balance)> {
+			PoolFees::get_waived_amounts(pool_id)
+		}
+	}
+
+	// PermissionsApi
+	impl runtime_common::apis::PermissionsApi<Block, AccountId, PermissionScope<PoolId, CurrencyId>, Role<TrancheId>> for Runtime {
+		fn account_roles(account_id: AccountId) -> Vec<(PermissionScope<PoolId, CurrencyId>, Role<TrancheId>)> {
+			Permissions::account_roles(account_id)
+		}
+
+		fn permission_changes(scope: PermissionScope<PoolId, CurrencyId>, count: u32) -> Vec<pallet_permissions::PermissionChange<AccountId, Role<TrancheId>, BlockNumber, Hash>> {
+			Permissions::permission_changes(scope, count)
+		}
+	}
+
+	// AdminActionLogApi
+	impl runtime_common::apis::AdminActionLogApi<Block, AccountId, Hash, BlockNumber> for Runtime {
+		fn admin_actions(from: u64) -> Vec<pallet_admin_action_log::AdminAction<AccountId, Hash, BlockNumber>> {
+			AdminActionLog::actions(from)
+		}
+	}
+
+	// TransferAllowlistApi
+	impl runtime_common::apis::TransferAllowlistApi<Block, AccountId, CurrencyId, RestrictedTransferLocation> for Runtime {
+		fn is_allowed(sender: AccountId, currency: CurrencyId, destination: RestrictedTransferLocation) -> bool {
+			runtime_common::transfer_filter::ensure_transfer_allowed::<TransferAllowList>(
+				sender,
+				destination,
+				currency,
+			)
+			.is_ok()
+		}
+	}
+
+	// ChainHealthApi
+	impl runtime_common::apis::ChainHealthApi<Block> for Runtime {
+		fn chain_health() -> runtime_common::apis::ChainHealth {
+			runtime_common::apis::ChainHealth {
+				gateway_backlog: runtime_common::gateway::queued_messages_per_domain::<Runtime>(),
+				failed_messages: pallet_liquidity_pools_gateway_queue::FailedMessageQueue::<Runtime>::iter_keys()
+					.count() as u32,
+				stale_oracle_feeds: OraclePriceCollection::stale_keys_count(),
+				overdue_epochs: PoolSystem::overdue_epochs_count(),
+				pending_migrations: frame_system::LastRuntimeUpgrade::<Runtime>::get()
+					.map_or(true, |last| last.was_upgraded(&VERSION)),
+			}
+		}
+	}
+
+	// HookWeightsApi
+	impl runtime_common::apis::HookWeightsApi<Block> for Runtime {
+		fn hook_weights() -> Vec<(runtime_common::apis::HookSubsystem, Weight)> {
+			use runtime_common::apis::HookSubsystem;
+
+			sp_std::vec![
+				(HookSubsystem::GatewayOutboundBatches, LiquidityPoolsGateway::hook_weight()),
+				(HookSubsystem::GatewayQueue, LiquidityPoolsGatewayQueue::hook_weight()),
+				(HookSubsystem::LiquidityRewardsEpochs, LiquidityRewards::hook_weight()),
+				(HookSubsystem::OrderExpiry, OrderBook::hook_weight()),
+				(HookSubsystem::PermissionsExpiry, Permissions::hook_weight()),
+			]
+		}
 	}
 
 	// Frontier APIs
@@ -2937,6 +3331,7 @@ mod benches {
 		[pallet_transfer_allowlist, TransferAllowList]
 		[pallet_order_book, OrderBook]
 		[pallet_investments, Investments]
+		[pallet_foreign_investments, ForeignInvestments]
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
 		[pallet_oracle_feed, OraclePriceFeed]
 		[pallet_oracle_collection, OraclePriceCollection]
@@ -42,4 +42,32 @@ impl<T: frame_system::Config> pallet_interest_accrual::WeightInfo for WeightInfo
 			// Standard Error: 717
 			.saturating_add(Weight::from_parts(733_327, 0).saturating_mul(n.into()))
 	}
+	fn calculate_periodic_accumulated_rate(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `calculate_accumulated_rate`, which
+		//       compounds the same number of periods
+		Self::calculate_accumulated_rate(n)
+	}
+	fn get_rate(_n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `calculate_accumulated_rate` for a
+		//       single period, plus the reads of the stored rates and the current
+		//       time. The search through the `n` stored rates is not accounted yet
+		Self::calculate_accumulated_rate(1).saturating_add(T::DbWeight::get().reads(2))
+	}
+	fn refresh_floating_rates(n: u32) -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `calculate_accumulated_rate` for
+		//       the largest benchmarked delay, plus the read of the reference
+		//       and the write of a snapshot for each of the `n` rates. The
+		//       current time and the floating rates are read once, the latter
+		//       being written once
+		Self::calculate_accumulated_rate(25)
+			.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+			.saturating_mul(n.into())
+			.saturating_add(T::DbWeight::get().reads_writes(2, 1))
+	}
 }
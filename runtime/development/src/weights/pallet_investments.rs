@@ -145,4 +145,12 @@ impl<T: frame_system::Config> pallet_investments::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(4))
 			.saturating_add(Weight::from_parts(0, 2555).saturating_mul(n.into()))
 	}
+	fn transfer_orders() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `update_invest_order`, which also
+		//       checks the investor and mutates its order, plus the permission of the
+		//       recipient and its invest and redeem orders
+		Self::update_invest_order().saturating_add(T::DbWeight::get().reads_writes(3, 2))
+	}
 }
@@ -122,4 +122,46 @@ impl<T: frame_system::Config> pallet_order_book::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	fn place_order_with_expiry() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `place_order`, plus the write of
+		//       the order into `ExpiringOrders`
+		Self::place_order().saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn cancel_expired_order() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `cancel_order`, plus the removal
+		//       of the order from `ExpiringOrders`
+		Self::cancel_order().saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn place_order_with_constraints() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `place_order`, plus the write of
+		//       the fill constraints of the order
+		Self::place_order().saturating_add(T::DbWeight::get().writes(1))
+	}
+	fn set_fees() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_market_feeder`, which also
+		//       writes a single value
+		Self::set_market_feeder()
+	}
+	fn match_orders() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `fill_order`, once for each of the
+		//       two matched orders
+		Self::fill_order().saturating_mul(2)
+	}
+	fn set_pair_order_limit() -> Weight {
+		// TODO: BENCHMARK CORRECTLY
+		//
+		// NOTE: Reasonable weight taken from `set_market_feeder`, which also
+		//       writes a single item
+		Self::set_market_feeder()
+	}
 }
@@ -9,6 +9,8 @@ mod investments;
 mod loans;
 mod lp;
 mod oracles;
+mod parameters;
+mod pools_router;
 mod proxy;
 mod queue;
 mod restricted_transfers;
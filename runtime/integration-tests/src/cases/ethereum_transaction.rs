@@ -1,5 +1,5 @@
 use cfg_primitives::CFG;
-use cfg_traits::ethereum::EthereumTransactor;
+use cfg_traits::ethereum::{EthereumTransactor, GasPrice};
 use ethereum::{LegacyTransaction, TransactionAction, TransactionV2};
 use frame_support::{assert_err, assert_ok};
 use pallet_evm::{ExitReason, ExitSucceed};
@@ -52,7 +52,7 @@ fn call<T: Runtime>() {
 				contract_address,
 				bar.as_slice(),
 				U256::zero(),
-				U256::from(1),
+				GasPrice::Legacy(U256::from(1)),
 				U256::from(0x100000),
 			),
 			pallet_ethereum_transaction::Error::<T>::EvmExecutionFailed,
@@ -77,7 +77,7 @@ fn call<T: Runtime>() {
 			contract_address,
 			foo.as_slice(),
 			U256::zero(),
-			U256::from(1),
+			GasPrice::Legacy(U256::from(1)),
 			U256::from(0x100000),
 		));
 
@@ -1,5 +1,4 @@
-use cfg_primitives::{Balance, CFG, SECONDS_PER_YEAR};
-use cfg_traits::IntoSeconds;
+use cfg_primitives::{Balance, CFG, SECONDS_PER_MONTH, SECONDS_PER_YEAR};
 use frame_support::traits::Get;
 use sp_api::runtime_decl_for_core::CoreV4;
 
@@ -10,7 +9,7 @@ use crate::{
 		fudge_env::{FudgeEnv, FudgeSupport},
 		runtime_env::RuntimeEnv,
 	},
-	utils::{accounts::Keyring, genesis::Genesis},
+	utils::{self, accounts::Keyring, genesis::Genesis},
 };
 
 #[test_runtimes([development, altair, centrifuge], ignore = "uncomment to run the example")]
@@ -166,12 +165,31 @@ fn fudge_call_api<T: Runtime + FudgeSupport>() {
 fn pass_time_one_block<T: Runtime>() {
 	let mut env = RuntimeEnv::<T>::default();
 
-	let before = env.parachain_state(|| pallet_timestamp::Pallet::<T>::get());
+	let before = env.parachain_state(|| utils::now_secs::<T>());
 
 	// Not supported in fudge
 	env.pass(Blocks::JumpBySeconds(SECONDS_PER_YEAR));
 
-	let after = env.parachain_state(|| pallet_timestamp::Pallet::<T>::get());
+	let after = env.parachain_state(|| utils::now_secs::<T>());
 
-	assert_eq!((after - before).into_seconds(), SECONDS_PER_YEAR)
+	assert_eq!(after - before, SECONDS_PER_YEAR)
+}
+
+#[test_runtimes(all, ignore = "uncomment to run the example")]
+fn pass_time_one_block_every_month<T: Runtime>() {
+	let mut env = RuntimeEnv::<T>::default();
+
+	let before = env.parachain_state(|| utils::now_secs::<T>());
+
+	// Not supported in fudge.
+	// A block is created each month, so any periodical action can be performed
+	// in between, i.e. repaying an amortizing loan.
+	env.pass(Blocks::JumpBySecondsEvery {
+		seconds: 3 * SECONDS_PER_YEAR,
+		every: SECONDS_PER_MONTH,
+	});
+
+	let after = env.parachain_state(|| utils::now_secs::<T>());
+
+	assert_eq!(after - before, 3 * SECONDS_PER_YEAR)
 }
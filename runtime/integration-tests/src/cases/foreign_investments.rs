@@ -979,6 +979,10 @@ mod same_currencies {
 					== pallet_investments::Event::<T>::InvestOrdersCollected {
 						investment_id: default_investment_id::<T>(),
 						processed_orders: vec![0],
+						fulfillments: vec![FulfillmentWithPrice {
+							of_amount: Perquintill::one(),
+							price: Ratio::one(),
+						}],
 						who: investor.clone(),
 						collection: InvestCollection::<Balance> {
 							payout_investment_invest: amount,
@@ -1076,6 +1080,10 @@ mod same_currencies {
 					== pallet_investments::Event::<T>::InvestOrdersCollected {
 						investment_id: default_investment_id::<T>(),
 						processed_orders: vec![0],
+						fulfillments: vec![FulfillmentWithPrice {
+							of_amount: Perquintill::from_percent(50),
+							price: Ratio::checked_from_rational(1, 4).unwrap(),
+						}],
 						who: investor.clone(),
 						collection: InvestCollection::<Balance> {
 							payout_investment_invest: invest_amount * 2,
@@ -1163,6 +1171,10 @@ mod same_currencies {
 					== pallet_investments::Event::<T>::InvestOrdersCollected {
 						investment_id: default_investment_id::<T>(),
 						processed_orders: vec![1],
+						fulfillments: vec![FulfillmentWithPrice {
+							of_amount: Perquintill::one(),
+							price: Ratio::checked_from_rational(1, 2).unwrap(),
+						}],
 						who: investor.clone(),
 						collection: InvestCollection::<Balance> {
 							payout_investment_invest: invest_amount,
@@ -1434,6 +1446,10 @@ mod same_currencies {
 					== pallet_investments::Event::<T>::RedeemOrdersCollected {
 						investment_id: default_investment_id::<T>(),
 						processed_orders: vec![0],
+						fulfillments: vec![FulfillmentWithPrice {
+							of_amount: Perquintill::from_percent(50),
+							price: Ratio::checked_from_rational(1, 4).unwrap(),
+						}],
 						who: investor.clone(),
 						collection: RedeemCollection::<Balance> {
 							payout_investment_redeem: redeem_amount / 8,
@@ -1513,6 +1529,10 @@ mod same_currencies {
 					== pallet_investments::Event::<T>::RedeemOrdersCollected {
 						investment_id: default_investment_id::<T>(),
 						processed_orders: vec![1],
+						fulfillments: vec![FulfillmentWithPrice {
+							of_amount: Perquintill::one(),
+							price: Ratio::checked_from_rational(1, 2).unwrap(),
+						}],
 						who: investor.clone(),
 						collection: RedeemCollection::<Balance> {
 							payout_investment_redeem: redeem_amount / 4,
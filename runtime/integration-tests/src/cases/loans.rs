@@ -51,6 +51,7 @@ const INVESTOR: Keyring = Keyring::Alice;
 const BORROWER: Keyring = Keyring::Bob;
 const LOAN_ADMIN: Keyring = Keyring::Charlie;
 const ANY: Keyring = Keyring::Dave;
+const CHANGE_APPROVER: Keyring = Keyring::Eve;
 
 const POOL_A: PoolId = 23;
 const NFT_A: (CollectionId, ItemId) = (1, ItemId(10));
@@ -461,6 +462,66 @@ fn update_maturity_extension<T: Runtime>() {
 		.unwrap();
 }
 
+#[test_runtimes(all)]
+fn update_interest_rate_with_dual_approval<T: Runtime>() {
+	let mut env = common::initialize_state_for_loans::<RuntimeEnv<T>, T>();
+
+	let info = env.parachain_state(|| {
+		common::default_loan_info::<T>(utils::now_secs::<T>(), common::default_internal_pricing())
+	});
+	env.submit_now(BORROWER, call::create(&info)).unwrap();
+
+	let loan_id = common::last_loan_id(&env);
+
+	env.submit_now(BORROWER, call::borrow_internal(loan_id))
+		.unwrap();
+
+	env.submit_now(
+		POOL_ADMIN,
+		pallet_pool_system::Call::set_change_approver {
+			pool_id: POOL_A,
+			approver: Some(CHANGE_APPROVER.id()),
+		},
+	)
+	.unwrap();
+
+	let mutation = LoanMutation::InterestRate(InterestRate::Fixed {
+		rate_per_year: common::rate_from_percent(10),
+		compounding: CompoundingSchedule::Secondly,
+	});
+	env.submit_now(LOAN_ADMIN, call::propose_loan_mutation(loan_id, mutation))
+		.unwrap();
+
+	let change_id = common::last_change_id(&env);
+
+	// Closing an epoch fulfills the `NextEpoch` requirement of the change
+	env.pass(Blocks::BySeconds(POOL_MIN_EPOCH_TIME));
+	env.submit_now(ANY, call::update_portfolio_valuation())
+		.unwrap();
+	env.parachain_state_mut(|| {
+		utils::pool::close_epoch::<T>(POOL_ADMIN.id(), POOL_A);
+	});
+
+	for approver in [POOL_ADMIN, CHANGE_APPROVER] {
+		assert_err!(
+			env.submit_now(ANY, call::apply_loan_mutation(change_id)),
+			pallet_pool_system::Error::<T>::ChangeNotReady,
+		);
+
+		env.submit_now(
+			approver,
+			pallet_pool_system::Call::approve_change {
+				pool_id: POOL_A,
+				change_id,
+			},
+		)
+		.unwrap();
+	}
+
+	env.submit_now(ANY, call::apply_loan_mutation(change_id))
+		.unwrap();
+}
+
 #[test_runtimes(all)]
 fn fake_oracle_portfolio_api<T: Runtime>() {
 	let mut env = common::initialize_state_for_loans::<RuntimeEnv<T>, T>();
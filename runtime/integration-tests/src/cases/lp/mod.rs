@@ -185,6 +185,7 @@ impl CurrencyInfo for USDC {
 			permissioned: false,
 			mintable: false,
 			local_representation: Some(LOCAL_ASSET_ID),
+			xcm_restricted: false,
 		}
 	}
 
@@ -215,6 +216,7 @@ impl CurrencyInfo for DAI {
 			permissioned: false,
 			mintable: false,
 			local_representation: None,
+			xcm_restricted: false,
 		}
 	}
 
@@ -245,6 +247,7 @@ impl CurrencyInfo for FRAX {
 			permissioned: false,
 			mintable: false,
 			local_representation: None,
+			xcm_restricted: false,
 		}
 	}
 
@@ -275,6 +278,7 @@ impl CurrencyInfo for LocalUSDC {
 			permissioned: false,
 			mintable: false,
 			local_representation: None,
+			xcm_restricted: false,
 		}
 	}
 
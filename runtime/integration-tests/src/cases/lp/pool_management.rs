@@ -55,6 +55,7 @@ fn add_currency<T: Runtime>() {
 				permissioned: false,
 				mintable: false,
 				local_representation: None,
+				xcm_restricted: false,
 			}
 		}
 
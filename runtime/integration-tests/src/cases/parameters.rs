@@ -0,0 +1,82 @@
+// Copyright 2021 Centrifuge Foundation (centrifuge.io).
+//
+// This file is part of the Centrifuge chain project.
+// Centrifuge is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version (see http://www.gnu.org/licenses).
+// Centrifuge is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+//! Testing that runtimes only diverge from the shared pallet parameters where
+//! intended
+
+use runtime_common::parameters;
+use sp_runtime::traits::Get;
+
+use crate::config::Runtime;
+
+#[test_runtimes(all)]
+fn shared_parameters<T: Runtime>() {
+	assert_eq!(
+		<T as pallet_order_book::Config>::MinFulfillmentAmountNative::get(),
+		parameters::order_book::MinFulfillmentAmountNative::get()
+	);
+	assert_eq!(
+		<T as pallet_order_book::Config>::NativeDecimals::get(),
+		parameters::order_book::NativeDecimals::get()
+	);
+	assert_eq!(
+		<T as pallet_loans::Config>::MaxActiveLoansPerPool::get(),
+		parameters::loans::MaxActiveLoansPerPool::get()
+	);
+	assert_eq!(
+		<T as pallet_liquidity_pools_gateway::Config>::MaxIncomingMessageSize::get(),
+		parameters::gateway::MaxIncomingMessageSize::get()
+	);
+	assert_eq!(
+		<T as pallet_liquidity_pools_gateway::Config>::MaxRouterCount::get(),
+		parameters::gateway::MaxRouterCount::get()
+	);
+	assert_eq!(
+		<T as pallet_liquidity_pools_gateway::Config>::MaxInboundNonceGap::get(),
+		parameters::gateway::MaxInboundNonceGap::get()
+	);
+}
+
+#[test]
+fn overridden_parameters() {
+	let max_rate_count = parameters::loans::MaxRateCount::get();
+
+	// Altair supports fewer interest rates
+	assert_ne!(
+		<altair_runtime::Runtime as pallet_interest_accrual::Config>::MaxRateCount::get(),
+		max_rate_count
+	);
+	assert_eq!(
+		<centrifuge_runtime::Runtime as pallet_interest_accrual::Config>::MaxRateCount::get(),
+		max_rate_count
+	);
+	assert_eq!(
+		<development_runtime::Runtime as pallet_interest_accrual::Config>::MaxRateCount::get(),
+		max_rate_count
+	);
+
+	let max_write_off_policy_size = parameters::loans::MaxWriteOffPolicySize::get();
+
+	// Development uses smaller write-off policies
+	assert_ne!(
+		<development_runtime::Runtime as pallet_loans::Config>::MaxWriteOffPolicySize::get(),
+		max_write_off_policy_size
+	);
+	assert_eq!(
+		<altair_runtime::Runtime as pallet_loans::Config>::MaxWriteOffPolicySize::get(),
+		max_write_off_policy_size
+	);
+	assert_eq!(
+		<centrifuge_runtime::Runtime as pallet_loans::Config>::MaxWriteOffPolicySize::get(),
+		max_write_off_policy_size
+	);
+}
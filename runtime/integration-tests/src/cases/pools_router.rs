@@ -0,0 +1,139 @@
+use cfg_primitives::Balance;
+use ethabi::{Function, Param, ParamType, Token};
+use frame_support::{assert_ok, dispatch::RawOrigin};
+use orml_traits::MultiCurrency;
+use runtime_common::{account_conversion::AccountConverter, evm::precompile::POOLS_ROUTER};
+use sp_core::{H160, U256};
+
+use crate::{
+	config::Runtime,
+	env::Env,
+	envs::runtime_env::RuntimeEnv,
+	utils::{
+		self,
+		currency::{cfg, usd12, CurrencyInfo, Usd12, Usd6},
+		genesis,
+		genesis::Genesis,
+	},
+};
+
+const CALLER: H160 = H160::repeat_byte(7);
+const AMOUNT_OUT: Balance = usd12(100);
+const ORDER_ID: u64 = 1;
+/// One to one ratio, with 18 decimals
+const RATIO: u128 = 1_000_000_000_000_000_000;
+
+#[allow(deprecated)] // Due `constant` field. Can be remove in future ethabi
+fn function(name: &str, inputs: &[ParamType]) -> Function {
+	Function {
+		name: name.into(),
+		inputs: inputs
+			.iter()
+			.map(|kind| Param {
+				name: Default::default(),
+				kind: kind.clone(),
+				internal_type: None,
+			})
+			.collect(),
+		outputs: vec![],
+		constant: Some(false),
+		state_mutability: Default::default(),
+	}
+}
+
+fn place_order_input() -> Vec<u8> {
+	function("placeOrder", &[ParamType::Uint(128); 4])
+		.encode_input(&[
+			Token::Uint(Usd6.general_currency_index().unwrap().index.into()),
+			Token::Uint(Usd12.general_currency_index().unwrap().index.into()),
+			Token::Uint(AMOUNT_OUT.into()),
+			Token::Uint(RATIO.into()),
+		])
+		.unwrap()
+}
+
+fn cancel_order_input() -> Vec<u8> {
+	function("cancelOrder", &[ParamType::Uint(64)])
+		.encode_input(&[Token::Uint(ORDER_ID.into())])
+		.unwrap()
+}
+
+fn call_router<T: Runtime>(input: Vec<u8>) {
+	assert_ok!(pallet_evm::Pallet::<T>::call(
+		RawOrigin::Root.into(),
+		CALLER,
+		H160::from_low_u64_be(POOLS_ROUTER),
+		input,
+		U256::from(0),
+		0x100000,
+		U256::from(1_000_000_000),
+		None,
+		Some(U256::from(0)),
+		Vec::new(),
+	));
+}
+
+fn setup<T: Runtime>() -> (RuntimeEnv<T>, cfg_primitives::AccountId) {
+	let mut env = RuntimeEnv::<T>::from_parachain_storage(
+		Genesis::default()
+			.add(genesis::assets::<T>([
+				(Usd6.id(), &Usd6.metadata()),
+				(Usd12.id(), &Usd12.metadata()),
+			]))
+			.storage(),
+	);
+
+	let caller = env.parachain_state_mut(|| {
+		let caller = AccountConverter::evm_address_to_account::<T>(CALLER);
+		utils::evm::mint_balance_into_derived_account::<T>(CALLER, cfg(1));
+		assert_ok!(orml_tokens::Pallet::<T>::deposit(
+			Usd12.id(),
+			&caller,
+			AMOUNT_OUT
+		));
+		caller
+	});
+
+	(env, caller)
+}
+
+#[test_runtimes(all)]
+fn place_order<T: Runtime>() {
+	let (mut env, caller) = setup::<T>();
+
+	env.parachain_state_mut(|| {
+		call_router::<T>(place_order_input());
+
+		assert!(pallet_order_book::UserOrders::<T>::contains_key(
+			&caller, ORDER_ID
+		));
+		assert_eq!(
+			orml_tokens::Pallet::<T>::free_balance(Usd12.id(), &caller),
+			0
+		);
+	});
+}
+
+#[test_runtimes(all)]
+fn batch<T: Runtime>() {
+	let (mut env, caller) = setup::<T>();
+
+	env.parachain_state_mut(|| {
+		let input = function("batch", &[ParamType::Array(Box::new(ParamType::Bytes))])
+			.encode_input(&[Token::Array(vec![
+				Token::Bytes(place_order_input()),
+				Token::Bytes(cancel_order_input()),
+			])])
+			.unwrap();
+
+		call_router::<T>(input);
+
+		assert!(!pallet_order_book::UserOrders::<T>::contains_key(
+			&caller, ORDER_ID
+		));
+		assert_eq!(
+			orml_tokens::Pallet::<T>::free_balance(Usd12.id(), &caller),
+			AMOUNT_OUT
+		);
+	});
+}
@@ -166,6 +166,18 @@ mod local {
 		validate_fail::<T>(Keyring::Alice, transfer_to(Keyring::Charlie));
 	}
 
+	#[test_runtimes(all)]
+	fn tokens_transfer<T: Runtime>() {
+		let tokens_transfer_to = |dest: Keyring| pallet_restricted_tokens::Call::<T>::transfer {
+			dest: dest.into(),
+			currency_id: CurrencyId::Native,
+			amount: cfg(TRANSFER_AMOUNT),
+		};
+
+		validate_ok::<T>(Keyring::Alice, tokens_transfer_to(Keyring::Bob));
+		validate_fail::<T>(Keyring::Alice, tokens_transfer_to(Keyring::Charlie));
+	}
+
 	#[test_runtimes(all)]
 	fn proxy_transfer<T: Runtime>() {
 		validate_ok::<T>(
@@ -1,7 +1,7 @@
-use cfg_types::tokens::{AssetMetadata, CurrencyId, CurrencyId::Native};
-use frame_support::{assert_ok, dispatch::RawOrigin};
+use cfg_types::tokens::{AssetMetadata, CurrencyId, CurrencyId::Native, CustomMetadata};
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin};
 use orml_traits::MultiCurrency;
-use staging_xcm::v4::{Junction::*, Junctions::Here, WeightLimit};
+use staging_xcm::v4::{Junction::*, Junctions::Here, Location, WeightLimit};
 
 use crate::{
 	config::Runtime,
@@ -233,3 +233,57 @@ fn para_from_to_relay_using_relay_native_tokens<T: Runtime + FudgeSupport>() {
 		);
 	});
 }
+
+#[test_runtimes(all)]
+fn restricted_currencies_are_not_transferable<T: Runtime + FudgeSupport>() {
+	let metadata = transferable_metadata(Some(PARA_ID));
+	let curr = CustomCurrency(
+		CurrencyId::ForeignAsset(1),
+		AssetMetadata {
+			decimals: 6,
+			additional: CustomMetadata {
+				xcm_restricted: true,
+				..metadata.additional
+			},
+			..metadata
+		},
+	);
+
+	let mut env = FudgeEnv::<T>::from_storage(
+		Default::default(),
+		Genesis::default()
+			.add(genesis::tokens::<T>([(curr.id(), curr.val(INITIAL))]))
+			.add(genesis::assets::<T>([(curr.id(), curr.metadata())]))
+			.storage(),
+		Genesis::default()
+			.add(genesis::assets::<T>([(curr.id(), curr.metadata())]))
+			.storage(),
+	);
+
+	enable_para_to_sibling_communication::<T>(&mut env);
+
+	env.parachain_state_mut(|| {
+		assert_noop!(
+			orml_xtokens::Pallet::<T>::transfer(
+				RawOrigin::Signed(Keyring::Alice.id()).into(),
+				curr.id(),
+				curr.val(TRANSFER),
+				account_location(1, Some(SIBLING_ID), Keyring::Bob.id()),
+				WeightLimit::Unlimited,
+			),
+			orml_xtokens::Error::<T>::NotCrossChainTransferableCurrency
+		);
+
+		let asset_location: Location = curr.metadata().location.unwrap().try_into().unwrap();
+		assert_noop!(
+			pallet_xcm::Pallet::<T>::reserve_transfer_assets(
+				RawOrigin::Signed(Keyring::Alice.id()).into(),
+				Box::new(Location::new(1, Parachain(SIBLING_ID)).into()),
+				account_location(0, None, Keyring::Bob.id()),
+				Box::new((asset_location, curr.val(TRANSFER)).into()),
+				0,
+			),
+			pallet_xcm::Error::<T>::Filtered
+		);
+	});
+}
@@ -183,6 +183,7 @@ pub trait Runtime:
 		Balance = Balance,
 		Rewards = pallet_rewards::Pallet<Self, instances::BlockRewards>,
 	> + pallet_axelar_router::Config
+	+ pallet_admin_action_log::Config
 	+ pallet_token_mux::Config<
 		BalanceIn = Balance,
 		BalanceOut = Balance,
@@ -226,7 +227,8 @@ pub trait Runtime:
 		+ IsSubType<pallet_balances::Call<Self>>
 		+ IsSubType<pallet_remarks::Call<Self>>
 		+ IsSubType<pallet_proxy::Call<Self>>
-		+ IsSubType<pallet_utility::Call<Self>>;
+		+ IsSubType<pallet_utility::Call<Self>>
+		+ IsSubType<pallet_restricted_tokens::Call<Self>>;
 
 	/// Just the RuntimeEvent type, but redefined with extra bounds.
 	/// You can add `TryInto` and `From` bounds in order to convert pallet
@@ -298,6 +300,8 @@ pub trait Runtime:
 				pallet_transaction_payment::ChargeTransactionPayment<Self>,
 				frame_metadata_hash_extension::CheckMetadataHash<Self>,
 				runtime_common::transfer_filter::PreBalanceTransferExtension<Self>,
+				pallet_admin_action_log::RecordAdminActions<Self>,
+				runtime_common::transfer_filter::PreTokensTransferExtension<Self>,
 			),
 		>,
 	>;
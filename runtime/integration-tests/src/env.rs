@@ -9,7 +9,6 @@ use sp_runtime::{
 	traits::{Block, Extrinsic},
 	DispatchError, MultiSignature, Storage,
 };
-use sp_std::ops::Range;
 
 use crate::{
 	config::Runtime,
@@ -47,31 +46,46 @@ pub enum Blocks<Event> {
 	/// computationally very fast.
 	/// (i.e. years)
 	JumpByNumber(BlockNumber),
+
+	/// Jumps to a block in the future to reach the requested time, creating
+	/// one real block each time `every` seconds have passed.
+	/// As the time is given by the block number, loans, pools and interest
+	/// accrual observe the same time in each of these blocks.
+	/// This can be used to emulate multi-year scenarios where something
+	/// must happen periodically, computationally very fast.
+	/// (i.e. a monthly repayment of an amortizing loan)
+	JumpBySecondsEvery { seconds: Seconds, every: Seconds },
 }
 
 impl<Event> Blocks<Event> {
-	fn range_for(&self, current: BlockNumber, slot_duration: Seconds) -> Range<BlockNumber> {
+	fn blocks_for(&self, current: BlockNumber, slot_duration: Seconds) -> Vec<BlockNumber> {
+		let blocks_for_secs = |secs: Seconds| {
+			let mut blocks = (secs / slot_duration) as BlockNumber;
+			if secs % slot_duration != 0 {
+				blocks += 1
+			};
+			blocks
+		};
+
 		let next = current + 1;
-		let (from, to) = match self {
-			Blocks::ByNumber(n) => (next, next + *n),
-			Blocks::BySeconds(secs) => {
-				let mut blocks = (secs / slot_duration) as BlockNumber;
-				if secs % slot_duration != 0 {
-					blocks += 1
-				};
-				(next, next + blocks)
-			}
-			Blocks::UntilEvent { limit, .. } => (next, next + *limit),
+		match self {
+			Blocks::ByNumber(n) => (next..next + *n).collect(),
+			Blocks::BySeconds(secs) => (next..next + blocks_for_secs(*secs)).collect(),
+			Blocks::UntilEvent { limit, .. } => (next..next + *limit).collect(),
 			Blocks::JumpBySeconds(secs) => {
-				let mut blocks = (secs / slot_duration) as BlockNumber;
-				if secs % slot_duration != 0 {
-					blocks += 1
-				};
-				(next + blocks.saturating_sub(1), next + blocks)
+				let blocks = blocks_for_secs(*secs);
+				(next + blocks.saturating_sub(1)..next + blocks).collect()
 			}
-			Blocks::JumpByNumber(n) => (next + (*n - 1), next + *n),
-		};
-		from..to
+			Blocks::JumpByNumber(n) => (next + (*n - 1)..next + *n).collect(),
+			Blocks::JumpBySecondsEvery { seconds, every } => {
+				let blocks = blocks_for_secs(*seconds);
+				let step = blocks_for_secs(*every).max(1);
+				(1..=blocks)
+					.filter(|block| block % step == 0 || *block == blocks)
+					.map(|block| current + block)
+					.collect()
+			}
+		}
 	}
 }
 
@@ -112,7 +126,7 @@ pub trait Env<T: Runtime>: Default {
 		});
 
 		let mut found_event = false;
-		for i in blocks.range_for(current, slot) {
+		for i in blocks.blocks_for(current, slot) {
 			self.__priv_build_block(i);
 
 			if let Blocks::UntilEvent { event, .. } = blocks.clone() {
@@ -258,6 +272,8 @@ pub mod utils {
 			pallet_transaction_payment::ChargeTransactionPayment::<T>::from(0),
 			frame_metadata_hash_extension::CheckMetadataHash::<T>::new(false),
 			runtime_common::transfer_filter::PreBalanceTransferExtension::<T>::new(),
+			pallet_admin_action_log::RecordAdminActions::<T>::new(),
+			runtime_common::transfer_filter::PreTokensTransferExtension::<T>::new(),
 		);
 
 		let raw_payload = SignedPayload::new(runtime_call.clone(), signed_extra.clone()).unwrap();
@@ -277,10 +293,7 @@ mod tests {
 	const EMPTY: [BlockNumber; 0] = [];
 
 	fn blocks_from(current: BlockNumber, blocks: Blocks<()>) -> Vec<BlockNumber> {
-		blocks
-			.range_for(current, SLOT_DURATION)
-			.into_iter()
-			.collect()
+		blocks.blocks_for(current, SLOT_DURATION)
 	}
 
 	#[test]
@@ -302,4 +315,15 @@ mod tests {
 		assert_eq!(blocks_from(5, Blocks::JumpBySeconds(12)), [6]);
 		assert_eq!(blocks_from(5, Blocks::JumpBySeconds(60)), [10]);
 	}
+
+	#[test]
+	fn by_seconds_every() {
+		let jump = |seconds, every| Blocks::JumpBySecondsEvery { seconds, every };
+
+		assert_eq!(blocks_from(0, jump(0, 12)), EMPTY);
+		assert_eq!(blocks_from(0, jump(12, 12)), [1]);
+		assert_eq!(blocks_from(5, jump(60, 24)), [7, 9, 10]);
+		assert_eq!(blocks_from(5, jump(72, 24)), [7, 9, 11]);
+		assert_eq!(blocks_from(5, jump(60, 0)), [6, 7, 8, 9, 10]);
+	}
 }